@@ -0,0 +1,82 @@
+//! Main-Camera Culling Benchmarks
+//!
+//! Renders a 200k-object scene headlessly with CPU frustum culling and with
+//! `RendererSettings::gpu_culling`, measuring whole frames (extract, cull,
+//! bake, encode, submit and wait).
+//!
+//! Requires a GPU adapter; the benchmark is skipped when headless
+//! initialisation fails. Only about a third of the grid is inside the
+//! frustum, so the CPU path both tests and rejects a large share of objects.
+//!
+//! Every object shares one box and one Unlit material, so on devices with
+//! GPU-driven draws the GPU path encodes the grid as a single multi-draw.
+//! The CPU still bakes one draw per object in range on both paths.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use myth::prelude::*;
+
+/// Objects per side of the square grid (447² ≈ 200k).
+const GRID_SIDE: i32 = 447;
+
+fn build_engine() -> Option<Engine> {
+    let mut engine = Engine::default();
+    pollster::block_on(engine.init_headless(256, 256, None)).ok()?;
+
+    let geometry = engine
+        .assets
+        .geometries
+        .add(Geometry::new_box(0.5, 0.5, 0.5));
+    let material = engine.assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
+    let scene = engine.scene_manager.create_active();
+    let half = GRID_SIDE / 2;
+    for x in -half..=half {
+        for z in -half..=half {
+            let node = scene.add_mesh(Mesh::new(geometry, material));
+            scene.node(&node).set_position(x as f32, 0.0, z as f32);
+        }
+    }
+
+    let cam = scene.add_camera(Camera::new_perspective(60.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 20.0, 0.0)
+        .look_at(Vec3::new(0.0, 0.0, -100.0));
+    scene.active_camera = Some(cam);
+    Some(engine)
+}
+
+fn render_frame(engine: &mut Engine) {
+    engine.update(1.0 / 60.0);
+    engine.render_active_scene();
+    black_box(engine.readback_pixels().ok());
+}
+
+fn bench_main_camera_culling(c: &mut Criterion) {
+    let Some(mut engine) = build_engine() else {
+        eprintln!("culling_bench: no GPU adapter, skipping");
+        return;
+    };
+
+    let mut group = c.benchmark_group("culling_200k");
+    group.sample_size(10);
+
+    for gpu_culling in [false, true] {
+        engine.renderer.update_settings(RendererSettings {
+            gpu_culling,
+            ..engine.renderer.settings().clone()
+        });
+        // Warm up pipelines and buffers before measuring.
+        render_frame(&mut engine);
+
+        let name = if gpu_culling { "gpu" } else { "cpu" };
+        group.bench_function(name, |b| b.iter(|| render_frame(&mut engine)));
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_main_camera_culling);
+criterion_main!(benches);
@@ -0,0 +1,88 @@
+//! Many-Light Benchmarks
+//!
+//! Renders a floor lit by 256 point lights headlessly with the plain
+//! all-lights loop and with `RendererSettings::clustered_lighting`,
+//! measuring whole frames (extract, cluster assignment, encode, submit and
+//! wait).
+//!
+//! Requires a GPU adapter; the benchmark is skipped when headless
+//! initialisation fails. Each light reaches only a few metres, so most
+//! fragments are touched by a handful of the 256 lights.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use myth::prelude::*;
+
+/// Lights per side of the square grid (16² = 256).
+const GRID_SIDE: u32 = 16;
+
+fn build_engine() -> Option<Engine> {
+    let mut engine = Engine::default();
+    pollster::block_on(engine.init_headless(1280, 720, None)).ok()?;
+
+    let scene = engine.scene_manager.create_active();
+    let floor = scene.spawn_box(
+        60.0,
+        0.2,
+        60.0,
+        PhysicalMaterial::new(Vec4::ONE).with_roughness(0.6),
+        &engine.assets,
+    );
+    scene.node(&floor).set_position(0.0, -0.1, 0.0);
+
+    let half = GRID_SIDE as f32 / 2.0;
+    for i in 0..GRID_SIDE * GRID_SIDE {
+        let (x, z) = ((i % GRID_SIDE) as f32, (i / GRID_SIDE) as f32);
+        let color = Vec3::new(x / GRID_SIDE as f32, 1.0 - z / GRID_SIDE as f32, 0.6);
+        let light = scene.add_light(Light::new_point(color, 4.0, 3.0));
+        scene
+            .node(&light)
+            .set_position((x - half) * 3.0, 0.5, (z - half) * 3.0);
+    }
+
+    let cam = scene.add_camera(Camera::new_perspective(60.0, 1280.0 / 720.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 12.0, 30.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+    Some(engine)
+}
+
+fn render_frame(engine: &mut Engine) {
+    engine.update(1.0 / 60.0);
+    engine.render_active_scene();
+    black_box(engine.readback_pixels().ok());
+}
+
+fn bench_many_point_lights(c: &mut Criterion) {
+    let Some(mut engine) = build_engine() else {
+        eprintln!("lighting_bench: no GPU adapter, skipping");
+        return;
+    };
+
+    let mut group = c.benchmark_group("point_lights_256");
+    group.sample_size(10);
+
+    for clustered_lighting in [false, true] {
+        engine.renderer.update_settings(RendererSettings {
+            clustered_lighting,
+            ..engine.renderer.settings().clone()
+        });
+        // Warm up pipelines and buffers before measuring.
+        render_frame(&mut engine);
+
+        let name = if clustered_lighting {
+            "clustered"
+        } else {
+            "all_lights"
+        };
+        group.bench_function(name, |b| b.iter(|| render_frame(&mut engine)));
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_many_point_lights);
+criterion_main!(benches);
@@ -1233,7 +1233,9 @@ class AntiAliasing:
 
     @staticmethod
     def taa(
-        feedback_weight: float = 0.9, sharpen_intensity: float = 0.5
+        feedback_weight: float = 0.9,
+        sharpen_intensity: float = 0.5,
+        jitter_scale: float = 1.0,
     ) -> AntiAliasing:
         """Temporal Anti-Aliasing (Recommended for PBR).
 
@@ -1241,6 +1243,7 @@ class AntiAliasing:
             feedback_weight: History frame blend weight (0.0 - 1.0).
                 Higher values produce smoother results but increase ghosting.
             sharpen_intensity: Contrast Adaptive Sharpening intensity (0.0 - 1.0).
+            jitter_scale: Sub-pixel jitter amplitude; ``0.0`` disables jitter.
         """
         ...
 
@@ -1249,8 +1252,12 @@ class AntiAliasing:
         feedback_weight: float = 0.9,
         sharpen_intensity: float = 0.5,
         quality: Optional[str] = None,
+        jitter_scale: float = 1.0,
     ) -> AntiAliasing:
-        """TAA + FXAA. TAA handles temporal aliasing, FXAA provides extra smoothing."""
+        """TAA + FXAA. TAA handles temporal aliasing, FXAA provides extra smoothing.
+
+        Takes the same arguments as :meth:`taa`, plus the FXAA ``quality``.
+        """
         ...
 
 class PerspectiveCamera:
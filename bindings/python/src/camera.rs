@@ -69,26 +69,33 @@ impl PyAntiAliasing {
 
     /// Temporal Anti-Aliasing (Recommended for PBR).
     #[staticmethod]
-    #[pyo3(signature = (feedback_weight=0.9, sharpen_intensity=0.5))]
-    pub fn taa(feedback_weight: f32, sharpen_intensity: f32) -> Self {
+    #[pyo3(signature = (feedback_weight=0.9, sharpen_intensity=0.5, jitter_scale=1.0))]
+    pub fn taa(feedback_weight: f32, sharpen_intensity: f32, jitter_scale: f32) -> Self {
         Self {
             mode: AntiAliasingMode::TAA(TaaSettings {
                 feedback_weight,
                 sharpen_intensity,
+                jitter_scale,
             }),
         }
     }
 
     /// TAA + FXAA.
     #[staticmethod]
-    #[pyo3(signature = (feedback_weight=0.9, sharpen_intensity=0.5, quality=None))]
-    pub fn taa_fxaa(feedback_weight: f32, sharpen_intensity: f32, quality: Option<&str>) -> Self {
+    #[pyo3(signature = (feedback_weight=0.9, sharpen_intensity=0.5, quality=None, jitter_scale=1.0))]
+    pub fn taa_fxaa(
+        feedback_weight: f32,
+        sharpen_intensity: f32,
+        quality: Option<&str>,
+        jitter_scale: f32,
+    ) -> Self {
         let q = parse_fxaa_quality(quality);
         Self {
             mode: AntiAliasingMode::TAA_FXAA(
                 TaaSettings {
                     feedback_weight,
                     sharpen_intensity,
+                    jitter_scale,
                 },
                 FxaaSettings { quality: q },
             ),
@@ -14,6 +14,27 @@ pub enum LoopMode {
     PingPong,
 }
 
+/// How an action's sampled pose is combined with other active actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnimationBlendMode {
+    /// Weighted average with the other normal actions. The rest pose fills
+    /// in when their total weight is below 1.
+    #[default]
+    Normal,
+    /// The difference between the sampled pose and the rest pose, scaled by
+    /// the action's weight and layered on top of the normal result.
+    Additive,
+}
+
+/// An in-progress weight ramp started by [`AnimationAction::fade_to`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeightFade {
+    from: f32,
+    to: f32,
+    duration: f32,
+    elapsed: f32,
+}
+
 #[derive(Debug, Clone)]
 pub struct AnimationAction {
     pub(crate) clip: Arc<AnimationClip>,
@@ -21,6 +42,7 @@ pub struct AnimationAction {
     pub time: f32,
     pub time_scale: f32,
     pub weight: f32,
+    pub blend_mode: AnimationBlendMode,
     pub loop_mode: LoopMode,
     pub paused: bool,
     pub enabled: bool,
@@ -31,6 +53,8 @@ pub struct AnimationAction {
     pub(crate) track_cursors: Vec<KeyframeCursor>,
 
     pub(crate) ping_pong_reverse: bool,
+
+    pub(crate) fade: Option<WeightFade>,
 }
 
 impl AnimationAction {
@@ -42,6 +66,7 @@ impl AnimationAction {
             time: 0.0,
             time_scale: 1.0,
             weight: 1.0,
+            blend_mode: AnimationBlendMode::Normal,
             loop_mode: LoopMode::Loop,
             paused: false,
             enabled: true,
@@ -51,6 +76,7 @@ impl AnimationAction {
             },
             track_cursors: vec![KeyframeCursor::default(); track_count],
             ping_pong_reverse: false,
+            fade: None,
         }
     }
 
@@ -70,6 +96,65 @@ impl AnimationAction {
         }
     }
 
+    /// Length of the clip in seconds.
+    #[inline]
+    #[must_use]
+    pub fn duration(&self) -> f32 {
+        self.clip.duration
+    }
+
+    /// Playhead position in seconds, within `0..=duration()`.
+    #[inline]
+    #[must_use]
+    pub fn current_time(&self) -> f32 {
+        self.time
+    }
+
+    /// Moves the playhead to `time` without advancing playback or firing
+    /// events.
+    ///
+    /// Times outside the clip are resolved by [`loop_mode`](Self::loop_mode):
+    /// clamped for `Once`, wrapped for `Loop`, and reflected for `PingPong`
+    /// (which also sets the playback direction). The paused state is left
+    /// unchanged, so a paused action shows the new pose on the next mixer
+    /// update.
+    pub fn seek(&mut self, time: f32) {
+        let duration = self.clip.duration;
+        if duration <= 0.0 {
+            self.time = 0.0;
+            return;
+        }
+
+        let in_range = (0.0..=duration).contains(&time);
+        match self.loop_mode {
+            LoopMode::Once => self.time = time.clamp(0.0, duration),
+            LoopMode::Loop => {
+                self.time = if in_range {
+                    time
+                } else {
+                    time.rem_euclid(duration)
+                };
+            }
+            LoopMode::PingPong => {
+                let phase = if in_range {
+                    time
+                } else {
+                    time.rem_euclid(2.0 * duration)
+                };
+                self.ping_pong_reverse = phase > duration;
+                self.time = if self.ping_pong_reverse {
+                    2.0 * duration - phase
+                } else {
+                    phase
+                };
+            }
+        }
+
+        for cursor in &mut self.track_cursors {
+            *cursor = KeyframeCursor::default();
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn is_finished(&self) -> bool {
@@ -92,6 +177,49 @@ impl AnimationAction {
     pub fn stop(&mut self) {
         self.reset();
         self.enabled = false;
+        self.fade = None;
+    }
+
+    /// Ramps [`weight`](Self::weight) linearly to `weight` over `duration`
+    /// seconds of mixer time. A non-positive duration applies it at once.
+    ///
+    /// When an action fades to zero, the mixer stops it.
+    pub fn fade_to(&mut self, weight: f32, duration: f32) {
+        if duration > 0.0 {
+            self.fade = Some(WeightFade {
+                from: self.weight,
+                to: weight,
+                duration,
+                elapsed: 0.0,
+            });
+        } else {
+            self.weight = weight;
+            self.fade = None;
+        }
+    }
+
+    /// Returns `true` while a [`fade_to`](Self::fade_to) ramp is running.
+    #[inline]
+    #[must_use]
+    pub fn is_fading(&self) -> bool {
+        self.fade.is_some()
+    }
+
+    /// Advances the weight ramp. Returns `true` when the action has just
+    /// faded out to zero weight.
+    pub(crate) fn update_fade(&mut self, dt: f32) -> bool {
+        let Some(fade) = &mut self.fade else {
+            return false;
+        };
+        fade.elapsed += dt.abs();
+        let t = (fade.elapsed / fade.duration).min(1.0);
+        self.weight = fade.from + (fade.to - fade.from) * t;
+        if t < 1.0 {
+            return false;
+        }
+        let faded_out = fade.to <= 0.0;
+        self.fade = None;
+        faded_out
     }
 
     #[inline]
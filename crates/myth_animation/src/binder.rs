@@ -1,5 +1,5 @@
 use crate::binding::{ClipBinding, Rig, TrackBinding};
-use crate::clip::AnimationClip;
+use crate::clip::{AnimationClip, TrackData};
 use crate::target::AnimationTarget;
 use myth_core::NodeHandle;
 
@@ -92,6 +92,18 @@ impl Binder {
                     target.store_rest_transform(node_handle, transform);
                 }
 
+                if let TrackData::MorphWeights(morph) = &track.data
+                    && let Some(clip_targets) = morph.values.first().map(|v| v.weights.len())
+                    && let Some(mesh_targets) = target.morph_target_count(node_handle)
+                    && clip_targets > mesh_targets
+                {
+                    log::warn!(
+                        "Animation '{}' drives {clip_targets} morph targets on '{}', but the mesh only has {mesh_targets}; extra weights are ignored",
+                        clip.name,
+                        track.meta.path.join("/"),
+                    );
+                }
+
                 bindings.push(TrackBinding {
                     track_index: track_idx,
                     bone_index,
@@ -8,12 +8,27 @@
 //!
 //! For each animated property, the accumulator performs weighted blending:
 //!
-//! - **Translation / Scale / Morph Weights**: Weighted linear interpolation (lerp)
+//! - **Translation / Scale**: Weighted linear interpolation (lerp)
 //! - **Rotation**: Weighted spherical interpolation (nlerp with sign correction)
+//! - **Morph Weights**: Weighted sum. Morph targets rest at zero, so this
+//!   matches a lerp while the total weight is at most 1.0, and lets several
+//!   full-weight actions (e.g. "blink" and "smile") drive the same mesh
+//!   without halving each other.
 //!
 //! When total accumulated weight is less than 1.0, the remainder is filled
 //! by the node's original (rest pose) value, preserving correct behavior
 //! for partially-weighted animations.
+//!
+//! # Additive Layer
+//!
+//! [`Additive`](crate::AnimationBlendMode::Additive) actions accumulate
+//! their difference from the rest pose separately and are applied on top of
+//! the normal result:
+//!
+//! - **Translation / Morph Weights**: weighted deltas are summed
+//! - **Rotation**: `rest⁻¹ · value`, slerped from identity by weight and
+//!   multiplied together
+//! - **Scale**: `value / rest`, lerped from one by weight and multiplied
 
 use glam::{Quat, Vec3};
 use rustc_hash::FxHashMap;
@@ -48,12 +63,16 @@ pub(crate) enum BlendEntry {
 pub(crate) struct FrameBlendState {
     /// Outer map: NodeHandle -> inner map of TargetPath -> BlendEntry
     entries: FxHashMap<NodeHandle, FxHashMap<TargetPath, BlendEntry>>,
+    /// Additive deltas, same layout as `entries`. `weight` fields hold the
+    /// summed weights and are informational only.
+    additive: FxHashMap<NodeHandle, FxHashMap<TargetPath, BlendEntry>>,
 }
 
 impl FrameBlendState {
     pub fn new() -> Self {
         Self {
             entries: FxHashMap::default(),
+            additive: FxHashMap::default(),
         }
     }
 
@@ -62,6 +81,9 @@ impl FrameBlendState {
         for props in self.entries.values_mut() {
             props.clear();
         }
+        for props in self.additive.values_mut() {
+            props.clear();
+        }
     }
 
     /// Accumulates a translation value with the given weight.
@@ -142,20 +164,19 @@ impl FrameBlendState {
                 weights: acc,
                 total_weight: w,
             }) => {
-                let new_total = *w + weight;
-                let mix = weight / new_total;
-                let len = acc.len().max(data.weights.len());
-                acc.resize(len, 0.0);
-                for (i, item) in acc.iter_mut().enumerate().take(data.weights.len()) {
-                    *item = *item * (1.0 - mix) + data.weights[i] * mix;
+                if acc.len() < data.weights.len() {
+                    acc.resize(data.weights.len(), 0.0);
                 }
-                *w = new_total;
+                for (item, &src) in acc.iter_mut().zip(data.weights.iter()) {
+                    *item += src * weight;
+                }
+                *w += weight;
             }
             _ => {
                 props.insert(
                     TargetPath::Weights,
                     BlendEntry::MorphWeights {
-                        weights: data.weights.to_vec(),
+                        weights: data.weights.iter().map(|&w| w * weight).collect(),
                         total_weight: weight,
                     },
                 );
@@ -163,6 +184,111 @@ impl FrameBlendState {
         }
     }
 
+    /// Adds a weighted translation delta to the additive layer.
+    pub fn accumulate_additive_translation(&mut self, node: NodeHandle, delta: Vec3, weight: f32) {
+        let props = self.additive.entry(node).or_default();
+        match props.get_mut(&TargetPath::Translation) {
+            Some(BlendEntry::Translation { value, weight: w }) => {
+                *value += delta * weight;
+                *w += weight;
+            }
+            _ => {
+                props.insert(
+                    TargetPath::Translation,
+                    BlendEntry::Translation {
+                        value: delta * weight,
+                        weight,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Adds a weighted rotation delta to the additive layer.
+    pub fn accumulate_additive_rotation(&mut self, node: NodeHandle, delta: Quat, weight: f32) {
+        let scaled = Quat::IDENTITY.slerp(delta, weight);
+        let props = self.additive.entry(node).or_default();
+        match props.get_mut(&TargetPath::Rotation) {
+            Some(BlendEntry::Rotation { value, weight: w }) => {
+                *value = (*value * scaled).normalize();
+                *w += weight;
+            }
+            _ => {
+                props.insert(
+                    TargetPath::Rotation,
+                    BlendEntry::Rotation {
+                        value: scaled,
+                        weight,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Adds a weighted scale ratio to the additive layer.
+    pub fn accumulate_additive_scale(&mut self, node: NodeHandle, ratio: Vec3, weight: f32) {
+        let scaled = Vec3::ONE.lerp(ratio, weight);
+        let props = self.additive.entry(node).or_default();
+        match props.get_mut(&TargetPath::Scale) {
+            Some(BlendEntry::Scale { value, weight: w }) => {
+                *value *= scaled;
+                *w += weight;
+            }
+            _ => {
+                props.insert(
+                    TargetPath::Scale,
+                    BlendEntry::Scale {
+                        value: scaled,
+                        weight,
+                    },
+                );
+            }
+        }
+    }
+
+    /// Adds weighted morph target weights to the additive layer.
+    pub fn accumulate_additive_morph_weights(
+        &mut self,
+        node: NodeHandle,
+        data: &MorphWeightData,
+        weight: f32,
+    ) {
+        let props = self.additive.entry(node).or_default();
+        let entry = props
+            .entry(TargetPath::Weights)
+            .or_insert_with(|| BlendEntry::MorphWeights {
+                weights: Vec::new(),
+                total_weight: 0.0,
+            });
+        if let BlendEntry::MorphWeights {
+            weights: acc,
+            total_weight,
+        } = entry
+        {
+            if acc.len() < data.weights.len() {
+                acc.resize(data.weights.len(), 0.0);
+            }
+            for (item, &src) in acc.iter_mut().zip(data.weights.iter()) {
+                *item += src * weight;
+            }
+            *total_weight += weight;
+        }
+    }
+
+    /// Returns `true` if normal (non-additive) actions animated `node` this frame.
+    pub fn has_node(&self, node: NodeHandle) -> bool {
+        self.entries
+            .get(&node)
+            .is_some_and(|props| !props.is_empty())
+    }
+
+    /// Returns an iterator over the additive layer of each node.
+    pub fn iter_additive_nodes(
+        &self,
+    ) -> impl Iterator<Item = (&NodeHandle, &FxHashMap<TargetPath, BlendEntry>)> {
+        self.additive.iter().filter(|(_, props)| !props.is_empty())
+    }
+
     /// Returns an iterator over all nodes that have accumulated blend data.
     pub fn iter_nodes(
         &self,
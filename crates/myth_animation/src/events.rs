@@ -14,6 +14,9 @@
 //! clip.events.push(AnimationEvent::new(0.5, "footstep_left"));
 //! clip.events.push(AnimationEvent::new(1.2, "footstep_right"));
 //!
+//! // ...or add them to a clip already loaded into a mixer
+//! mixer.add_event("Walk", 0.5, "footstep_left");
+//!
 //! // After mixer.update(), consume fired events
 //! for event in mixer.drain_events() {
 //!     match event.name.as_str() {
@@ -23,6 +26,8 @@
 //! }
 //! ```
 
+use crate::action::LoopMode;
+
 /// A single animation event defined at a specific time within a clip.
 #[derive(Debug, Clone)]
 pub struct AnimationEvent {
@@ -49,53 +54,99 @@ pub struct FiredEvent {
     pub name: String,
     /// The clip name that produced this event.
     pub clip_name: String,
+    /// Clip time (in seconds) at which the event is defined.
+    pub time: f32,
 }
 
+/// Upper bound on loop wraps / ping-pong bounces walked in one update.
+///
+/// Only reached when a single step covers hundreds of clip lengths; events
+/// of the remaining passes are dropped rather than stalling the frame.
+const MAX_PASSES_PER_UPDATE: u32 = 256;
+
 /// Collects events fired during a single frame of animation evaluation.
 ///
-/// Events are detected by checking whether the playback time range `[t_prev, t_curr]`
-/// crosses any event timestamp. Handles forward playback, loops, and wrapping.
+/// Walks the playback path from `t_prev` over the signed clip-time step
+/// `delta` (already adjusted for `time_scale` and ping-pong direction),
+/// following the same wrap / bounce / clamp rules as
+/// [`AnimationAction::update`](crate::action::AnimationAction::update).
+/// Each event fires once per crossing, so a step spanning several loops
+/// fires it several times, in playback order.
 pub(crate) fn collect_events(
     events: &[AnimationEvent],
     t_prev: f32,
-    t_curr: f32,
+    delta: f32,
     duration: f32,
-    is_forward: bool,
+    loop_mode: LoopMode,
     clip_name: &str,
     out: &mut Vec<FiredEvent>,
 ) {
-    if events.is_empty() || duration <= 0.0 || (t_prev - t_curr).abs() < f32::EPSILON {
+    if events.is_empty() || duration <= 0.0 || delta.abs() < f32::EPSILON {
         return;
     }
 
-    for ev in events {
-        let fires;
+    let mut pos = t_prev.clamp(0.0, duration);
+    let mut remaining = delta.abs();
+    let mut forward = delta > 0.0;
+    // After a loop wrap the segment starts exactly on an event-able time
+    // (0 or `duration`), which has not been crossed yet.
+    let mut include_start = false;
 
-        if is_forward {
-            // Forward playback: trigger if we cross the event time in the forward direction
-            if t_curr >= t_prev {
-                fires = ev.time > t_prev && ev.time <= t_curr;
-            } else {
-                // Looping forward: trigger if we cross the event time either before or after the loop point
-                fires = (ev.time > t_prev && ev.time <= duration)
-                    || (ev.time >= 0.0 && ev.time <= t_curr);
-            }
+    for _ in 0..MAX_PASSES_PER_UPDATE {
+        let (end, reached_boundary) = if forward {
+            let end = (pos + remaining).min(duration);
+            (end, pos + remaining >= duration)
         } else {
-            if t_curr <= t_prev {
-                // Reverse playback: trigger if we cross the event time in the backward direction
-                fires = ev.time >= t_curr && ev.time < t_prev;
+            let end = (pos - remaining).max(0.0);
+            (
+                end,
+                pos - remaining < 0.0 || (loop_mode == LoopMode::PingPong && end <= 0.0),
+            )
+        };
+
+        let segment_start = out.len();
+        for ev in events {
+            let fires = if forward {
+                (ev.time > pos || (include_start && ev.time >= pos)) && ev.time <= end
             } else {
-                // Looping backward: trigger if we cross the event time either before or after the loop point
-                fires = (ev.time >= 0.0 && ev.time < t_prev)
-                    || (ev.time >= t_curr && ev.time <= duration);
+                (ev.time < pos || (include_start && ev.time <= pos)) && ev.time >= end
+            };
+            if fires {
+                out.push(FiredEvent {
+                    name: ev.name.clone(),
+                    clip_name: clip_name.to_string(),
+                    time: ev.time,
+                });
             }
         }
 
-        if fires {
-            out.push(FiredEvent {
-                name: ev.name.clone(),
-                clip_name: clip_name.to_string(),
-            });
+        // Clip events are not required to be sorted.
+        let segment = &mut out[segment_start..];
+        if forward {
+            segment.sort_by(|a, b| a.time.total_cmp(&b.time));
+        } else {
+            segment.sort_by(|a, b| b.time.total_cmp(&a.time));
+        }
+
+        remaining -= (end - pos).abs();
+        if !reached_boundary {
+            return;
+        }
+
+        match loop_mode {
+            LoopMode::Once => return,
+            LoopMode::Loop => {
+                pos = if forward { 0.0 } else { duration };
+                include_start = true;
+            }
+            LoopMode::PingPong => {
+                if remaining <= 0.0 {
+                    return;
+                }
+                pos = end;
+                forward = !forward;
+                include_start = false;
+            }
         }
     }
 }
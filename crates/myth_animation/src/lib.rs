@@ -19,7 +19,7 @@ pub mod target;
 pub mod tracks;
 pub mod values;
 
-pub use action::{AnimationAction, LoopMode};
+pub use action::{AnimationAction, AnimationBlendMode, LoopMode};
 pub use binder::Binder;
 pub use binding::{ClipBinding, Rig, TargetPath, TrackBinding};
 pub use clip::{AnimationClip, Track, TrackData, TrackMeta};
@@ -1,13 +1,16 @@
+use std::sync::Arc;
+
 use rustc_hash::FxHashMap;
 use slotmap::{SlotMap, new_key_type};
 
-use crate::action::AnimationAction;
+use crate::action::{AnimationAction, AnimationBlendMode};
 use crate::binding::{Rig, TargetPath};
 use crate::blending::{BlendEntry, FrameBlendState};
 use crate::clip::TrackData;
-use crate::events::{self, FiredEvent};
+use crate::events::{self, AnimationEvent, FiredEvent};
 use crate::target::AnimationTarget;
-use myth_core::NodeHandle;
+use glam::Vec3;
+use myth_core::{MAX_MORPH_TARGETS, NodeHandle};
 
 new_key_type! {
     pub struct ActionHandle;
@@ -30,7 +33,13 @@ new_key_type! {
 /// When multiple actions are active simultaneously, their contributions
 /// are combined using weight-based accumulation.
 /// If the total accumulated weight for a property is less than 1.0, the
-/// rest pose value fills the remainder.
+/// rest pose value fills the remainder. Actions in
+/// [`AnimationBlendMode::Additive`] are layered on top of that result as
+/// offsets from the rest pose.
+///
+/// Weights can be ramped over time with [`crossfade`](Self::crossfade),
+/// [`ActionControl::fade_in`] / [`ActionControl::fade_out`] or
+/// [`AnimationAction::fade_to`]; an action that fades out to zero is stopped.
 ///
 /// # Events
 ///
@@ -90,6 +99,38 @@ impl AnimationMixer {
         }
     }
 
+    /// Returns a copy of this mixer that drives different nodes.
+    ///
+    /// Actions, playback state and the rig are copied, with every node
+    /// handle passed through `remap`; handles it returns `None` for are
+    /// kept as they are. Per-frame state (fired events, blend buffers) is
+    /// not copied.
+    #[must_use]
+    pub fn clone_remapped(&self, remap: impl Fn(NodeHandle) -> Option<NodeHandle>) -> Self {
+        let remap = |handle| remap(handle).unwrap_or(handle);
+        Self {
+            actions: self.actions.clone(),
+            name_map: self.name_map.clone(),
+            active_handles: self.active_handles.clone(),
+            rig: Rig {
+                bones: self.rig.bones.iter().copied().map(remap).collect(),
+                bone_paths: self.rig.bone_paths.clone(),
+            },
+            time: self.time,
+            time_scale: self.time_scale,
+            blend_state: FrameBlendState::new(),
+            fired_events: Vec::new(),
+            animated_last_frame: self
+                .animated_last_frame
+                .iter()
+                .copied()
+                .map(remap)
+                .collect(),
+            morph_buffer: crate::values::MorphWeightData::default(),
+            enabled: self.enabled,
+        }
+    }
+
     /// Sets the logical skeleton used for bone-index → node-handle lookup.
     pub fn set_rig(&mut self, rig: Rig) {
         self.rig = rig;
@@ -176,12 +217,49 @@ impl AnimationMixer {
                 action.enabled = true;
                 action.weight = 1.0;
                 action.paused = false;
+                action.fade = None;
             }
         } else {
             log::warn!("Animation not found: {name}");
         }
     }
 
+    /// Cross-fades from the `from` action to the `to` action over `duration`
+    /// seconds.
+    ///
+    /// `to` starts from the beginning (unless it is already playing) and its
+    /// weight ramps from its current value to 1; `from` ramps to 0 and is
+    /// stopped once the fade completes. Both names must exist.
+    pub fn crossfade(&mut self, from: &str, to: &str, duration: f32) {
+        let (Some(&from_handle), Some(&to_handle)) =
+            (self.name_map.get(from), self.name_map.get(to))
+        else {
+            log::warn!("Crossfade: animation not found ({from} -> {to})");
+            return;
+        };
+
+        if let Some(action) = self.actions.get_mut(from_handle) {
+            action.fade_to(0.0, duration);
+        }
+
+        let to_active = self.active_handles.contains(&to_handle);
+        if let Some(action) = self.actions.get_mut(to_handle) {
+            if !to_active || !action.enabled {
+                action.reset();
+                action.weight = 0.0;
+            }
+            action.enabled = true;
+            action.paused = false;
+            action.fade_to(1.0, duration);
+        }
+        if !to_active {
+            self.active_handles.push(to_handle);
+        }
+        if duration <= 0.0 {
+            self.stop(from);
+        }
+    }
+
     /// Stops the named animation and removes it from the active set.
     pub fn stop(&mut self, name: &str) {
         if let Some(&handle) = self.name_map.get(name) {
@@ -202,6 +280,110 @@ impl AnimationMixer {
         self.active_handles.clear();
     }
 
+    /// Moves the named animation's playhead to `time` seconds.
+    ///
+    /// See [`AnimationAction::seek`] for how out-of-range times are resolved.
+    /// The pose is applied on the next [`update`](Self::update), including
+    /// when the action is paused or the mixer's `time_scale` is zero; use
+    /// [`seek`](Self::seek) to apply it right away.
+    pub fn set_time(&mut self, name: &str, time: f32) {
+        if let Some(action) = self
+            .name_map
+            .get(name)
+            .and_then(|&handle| self.actions.get_mut(handle))
+        {
+            action.seek(time);
+        } else {
+            log::warn!("Animation not found: {name}");
+        }
+    }
+
+    /// Moves the named animation's playhead to `time` seconds and writes the
+    /// resulting pose to `target` immediately.
+    ///
+    /// Out-of-range times follow the action's loop mode, as in
+    /// [`AnimationAction::seek`]. Playing, paused and stopped state are left
+    /// unchanged, so a stopped action contributes nothing; use
+    /// [`sample_at`](Self::sample_at) to show a pose from a stopped action.
+    /// No events fire, and events from the last update that have not been
+    /// drained are kept. Returns `false` if no animation with that name
+    /// exists.
+    pub fn seek(&mut self, name: &str, time: f32, target: &mut dyn AnimationTarget) -> bool {
+        let Some(action) = self
+            .name_map
+            .get(name)
+            .and_then(|&handle| self.actions.get_mut(handle))
+        else {
+            log::warn!("Animation not found: {name}");
+            return false;
+        };
+        action.seek(time);
+        self.apply_pose(target);
+        true
+    }
+
+    /// Applies the current pose of all active actions without advancing time.
+    pub fn evaluate(&mut self, target: &mut dyn AnimationTarget) {
+        self.update(0.0, target);
+    }
+
+    /// Like [`evaluate`](Self::evaluate), but keeps undrained events.
+    fn apply_pose(&mut self, target: &mut dyn AnimationTarget) {
+        let pending = std::mem::take(&mut self.fired_events);
+        self.update(0.0, target);
+        self.fired_events = pending;
+    }
+
+    /// Scrubs the named animation to `time` seconds and writes the
+    /// resulting pose to `target` immediately.
+    ///
+    /// `time` is clamped to `0..=duration` regardless of the loop mode.
+    /// A stopped action is activated paused at full weight so the sampled
+    /// pose stays visible; an active action keeps its paused state and
+    /// weight. No events fire, and events from the last update that have
+    /// not been drained are kept. Returns `false` if no animation with that
+    /// name exists.
+    pub fn sample_at(&mut self, name: &str, time: f32, target: &mut dyn AnimationTarget) -> bool {
+        let Some(&handle) = self.name_map.get(name) else {
+            log::warn!("Animation not found: {name}");
+            return false;
+        };
+        let Some(action) = self.actions.get_mut(handle) else {
+            return false;
+        };
+
+        if !self.active_handles.contains(&handle) {
+            self.active_handles.push(handle);
+            action.enabled = true;
+            action.paused = true;
+            action.weight = 1.0;
+            action.fade = None;
+        }
+        action.seek(time.clamp(0.0, action.duration().max(0.0)));
+        self.apply_pose(target);
+        true
+    }
+
+    /// Adds an event at `time` seconds to the named animation's clip.
+    ///
+    /// The clip is copied first if it is shared with other actions, so the
+    /// event only fires for this mixer. Returns `false` if no animation
+    /// with that name exists.
+    pub fn add_event(&mut self, animation: &str, time: f32, name: impl Into<String>) -> bool {
+        let Some(action) = self
+            .name_map
+            .get(animation)
+            .and_then(|&handle| self.actions.get_mut(handle))
+        else {
+            log::warn!("Animation not found: {animation}");
+            return false;
+        };
+        Arc::make_mut(&mut action.clip)
+            .events
+            .push(AnimationEvent::new(time, name));
+        true
+    }
+
     /// Drains all events fired during the most recent update.
     pub fn drain_events(&mut self) -> Vec<FiredEvent> {
         std::mem::take(&mut self.fired_events)
@@ -250,10 +432,16 @@ impl AnimationMixer {
 
         self.animated_last_frame.clear();
 
-        // Phase 1: Advance time and collect events
+        // Phase 1: Advance time and weight fades, collect events
+        let mut faded_out = Vec::new();
         for &handle in &self.active_handles {
             if let Some(action) = self.actions.get_mut(handle) {
+                if action.update_fade(dt) {
+                    faded_out.push(handle);
+                }
+
                 let t_prev = action.time;
+                let advances = action.enabled && !action.paused;
 
                 let mut real_time_scale = action.time_scale;
                 if action.loop_mode == crate::action::LoopMode::PingPong && action.ping_pong_reverse
@@ -261,28 +449,37 @@ impl AnimationMixer {
                     real_time_scale = -real_time_scale;
                 }
 
-                let is_forward = (dt * real_time_scale) >= 0.0;
-
                 action.update(dt);
-                let t_curr = action.time;
+
+                if !advances {
+                    continue;
+                }
 
                 let clip = action.clip();
                 events::collect_events(
                     &clip.events,
                     t_prev,
-                    t_curr,
+                    dt * real_time_scale,
                     clip.duration,
-                    is_forward,
+                    action.loop_mode,
                     &clip.name,
                     &mut self.fired_events,
                 );
             }
         }
 
+        for handle in faded_out {
+            if let Some(action) = self.actions.get_mut(handle) {
+                action.stop();
+            }
+            self.active_handles.retain(|&h| h != handle);
+        }
+
         // Phase 2: Sample tracks and accumulate into blend buffer (O(1) per track)
         for &handle in &self.active_handles {
             let action = match self.actions.get_mut(handle) {
-                Some(a) if a.enabled && !a.paused && a.weight > 0.0 => a,
+                // Paused actions still hold their pose.
+                Some(a) if a.enabled && a.weight > 0.0 => a,
                 _ => continue,
             };
 
@@ -292,6 +489,7 @@ impl AnimationMixer {
                 clip_binding,
                 time,
                 weight,
+                blend_mode,
                 ..
             } = action;
 
@@ -300,6 +498,48 @@ impl AnimationMixer {
                 let cursor = &mut track_cursors[tb.track_index];
                 let node_handle = self.rig.bones[tb.bone_index];
 
+                if *blend_mode == AnimationBlendMode::Additive {
+                    let rest = target.rest_transform(node_handle).unwrap_or_default();
+                    match (&track.data, tb.target) {
+                        (TrackData::Vector3(t), TargetPath::Translation) => {
+                            let val = t.sample_with_cursor(*time, cursor);
+                            self.blend_state.accumulate_additive_translation(
+                                node_handle,
+                                val - rest.position,
+                                *weight,
+                            );
+                        }
+                        (TrackData::Vector3(t), TargetPath::Scale) => {
+                            let val = t.sample_with_cursor(*time, cursor);
+                            let ratio = Vec3::select(
+                                rest.scale.cmpeq(Vec3::ZERO),
+                                Vec3::ONE,
+                                val / rest.scale,
+                            );
+                            self.blend_state
+                                .accumulate_additive_scale(node_handle, ratio, *weight);
+                        }
+                        (TrackData::Quaternion(t), TargetPath::Rotation) => {
+                            let val = t.sample_with_cursor(*time, cursor);
+                            self.blend_state.accumulate_additive_rotation(
+                                node_handle,
+                                rest.rotation.inverse() * val,
+                                *weight,
+                            );
+                        }
+                        (TrackData::MorphWeights(t), TargetPath::Weights) => {
+                            t.sample_with_cursor_into(*time, cursor, &mut self.morph_buffer);
+                            self.blend_state.accumulate_additive_morph_weights(
+                                node_handle,
+                                &self.morph_buffer,
+                                *weight,
+                            );
+                        }
+                        _ => {}
+                    }
+                    continue;
+                }
+
                 match (&track.data, tb.target) {
                     (TrackData::Vector3(t), TargetPath::Translation) => {
                         let val = t.sample_with_cursor(*time, cursor);
@@ -374,41 +614,74 @@ impl AnimationMixer {
                         }
                         target.mark_node_dirty(node_handle);
                     }
-                    (
-                        TargetPath::Weights,
-                        BlendEntry::MorphWeights {
-                            weights,
-                            total_weight,
-                        },
-                    ) => {
-                        apply_morph_weights(target, node_handle, weights, *total_weight);
+                    (TargetPath::Weights, BlendEntry::MorphWeights { weights, .. }) => {
+                        apply_morph_weights(target, node_handle, weights);
                     }
                     _ => {}
                 }
             }
         }
+
+        // Phase 3b: Layer additive deltas on top of the normal result
+        for (&node_handle, props) in self.blend_state.iter_additive_nodes() {
+            if !self.blend_state.has_node(node_handle) {
+                self.animated_last_frame.push(node_handle);
+            }
+
+            let Some(base) = target.node_transform(node_handle) else {
+                continue;
+            };
+
+            for (t, entry) in props {
+                match (t, entry) {
+                    (TargetPath::Translation, BlendEntry::Translation { value, .. }) => {
+                        target.set_node_position(node_handle, base.position + *value);
+                    }
+                    (TargetPath::Rotation, BlendEntry::Rotation { value, .. }) => {
+                        target.set_node_rotation(node_handle, (base.rotation * *value).normalize());
+                    }
+                    (TargetPath::Scale, BlendEntry::Scale { value, .. }) => {
+                        target.set_node_scale(node_handle, base.scale * *value);
+                    }
+                    (TargetPath::Weights, BlendEntry::MorphWeights { weights, .. }) => {
+                        let dst = morph_weights_for(target, node_handle, weights.len());
+                        for (d, &delta) in dst.iter_mut().zip(weights.iter()) {
+                            *d += delta;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            target.mark_node_dirty(node_handle);
+        }
     }
 }
 
-/// Applies blended morph weights to the target, mixing with the rest pose
-/// when the total accumulated weight is below 1.0.
-fn apply_morph_weights(
+/// Applies blended morph weights to the target.
+///
+/// The accumulated weights are already a weighted sum, and the rest value
+/// of every morph target is zero, so the sum is written as-is.
+fn apply_morph_weights(target: &mut dyn AnimationTarget, node: NodeHandle, weights: &[f32]) {
+    let dst = morph_weights_for(target, node, weights.len());
+    let n = dst.len().min(weights.len());
+    dst[..n].copy_from_slice(&weights[..n]);
+}
+
+/// Returns the node's morph weight buffer, grown to hold `len` entries.
+///
+/// Growth is limited to [`MAX_MORPH_TARGETS`], and a buffer that is already
+/// sized (from the mesh's target count) is never grown by a clip that
+/// references more targets than the mesh has.
+fn morph_weights_for(
     target: &mut dyn AnimationTarget,
     node: NodeHandle,
-    weights: &[f32],
-    total_weight: f32,
-) {
+    len: usize,
+) -> &mut Vec<f32> {
     let dst = target.morph_weights_mut(node);
-    if dst.len() < weights.len() {
-        dst.resize(weights.len(), 0.0);
-    }
-    if total_weight >= 1.0 {
-        dst[..weights.len()].copy_from_slice(weights);
-    } else {
-        for (d, &src) in dst.iter_mut().zip(weights.iter()) {
-            *d = src * total_weight;
-        }
+    if dst.is_empty() {
+        dst.resize(len.min(MAX_MORPH_TARGETS), 0.0);
     }
+    dst
 }
 
 // ============================================================================
@@ -437,6 +710,7 @@ impl ActionControl<'_> {
             action.paused = false;
             action.weight = 1.0;
             action.time = 0.0;
+            action.fade = None;
         }
         self
     }
@@ -456,16 +730,26 @@ impl ActionControl<'_> {
         self
     }
 
+    /// Sets the weight immediately, cancelling any running fade.
     pub fn set_weight(self, weight: f32) -> Self {
         if let Some(action) = self.mixer.actions.get_mut(self.handle) {
             action.weight = weight;
+            action.fade = None;
         }
         self
     }
 
+    pub fn set_blend_mode(self, mode: AnimationBlendMode) -> Self {
+        if let Some(action) = self.mixer.actions.get_mut(self.handle) {
+            action.blend_mode = mode;
+        }
+        self
+    }
+
+    /// Moves the playhead; see [`AnimationAction::seek`].
     pub fn set_time(self, time: f32) -> Self {
         if let Some(action) = self.mixer.actions.get_mut(self.handle) {
-            action.time = time;
+            action.seek(time);
         }
         self
     }
@@ -494,10 +778,26 @@ impl ActionControl<'_> {
         self
     }
 
-    /// Starts playback with a fade-in effect over the given duration.
-    pub fn fade_in(self, _duration: f32) -> Self {
-        // TODO: Implement gradual weight interpolation
-        self.play()
+    /// Starts playback from the beginning, ramping the weight from 0 to 1
+    /// over `duration` seconds.
+    pub fn fade_in(self, duration: f32) -> Self {
+        let control = self.play();
+        if let Some(action) = control.mixer.actions.get_mut(control.handle) {
+            action.weight = 0.0;
+            action.fade_to(1.0, duration);
+        }
+        control
+    }
+
+    /// Ramps the weight to 0 over `duration` seconds, then stops the action.
+    pub fn fade_out(self, duration: f32) -> Self {
+        if duration <= 0.0 {
+            return self.stop();
+        }
+        if let Some(action) = self.mixer.actions.get_mut(self.handle) {
+            action.fade_to(0.0, duration);
+        }
+        self
     }
 }
 
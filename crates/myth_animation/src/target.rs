@@ -38,4 +38,11 @@ pub trait AnimationTarget {
 
     /// Get or create a mutable morph weights vector for a node.
     fn morph_weights_mut(&mut self, handle: NodeHandle) -> &mut Vec<f32>;
+
+    /// Number of morph targets on the mesh driven by a node, if known.
+    ///
+    /// Used to warn about clips that animate more targets than the mesh has.
+    fn morph_target_count(&self, _handle: NodeHandle) -> Option<usize> {
+        None
+    }
 }
@@ -24,8 +24,10 @@ use crate::window::Window;
 /// # Lifecycle
 ///
 /// 1. [`init`](Self::init) - Called once when the window and renderer are ready
-/// 2. [`update`](Self::update) - Called each frame before rendering
-/// 3. [`compose_frame`](Self::compose_frame) - Called to configure the render pipeline
+/// 2. [`fixed_update`](Self::fixed_update) - Called zero or more times per frame
+///    at a fixed rate, if a fixed timestep is configured
+/// 3. [`update`](Self::update) - Called each frame before rendering
+/// 4. [`render`](Self::render) - Called to render the frame
 ///
 /// # Input Handling
 ///
@@ -62,6 +64,17 @@ pub trait AppHandler: Sized + 'static {
         false
     }
 
+    /// Advances fixed-rate state such as physics or deterministic game logic.
+    ///
+    /// Only called when the app was built with a fixed timestep (e.g.
+    /// `App::with_fixed_timestep(1.0 / 60.0)`). It runs a whole number of
+    /// times per frame, before [`update`](Self::update), with `frame.dt`
+    /// equal to the fixed step and `frame.time` the simulation time at the
+    /// end of the step. Interpolate rendered state in `update` using
+    /// [`FrameState::alpha`].
+    #[allow(unused_variables)]
+    fn fixed_update(&mut self, engine: &mut Engine, frame: &FrameState) {}
+
     /// Updates application state.
     ///
     /// Called once per frame before rendering. Use this for game logic,
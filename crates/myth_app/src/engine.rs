@@ -37,8 +37,8 @@ use myth_render::graph::FrameComposer;
 use myth_render::renderer::FrameTime;
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
 
-use myth_assets::AssetServer;
 use myth_assets::manager::SceneManager;
+use myth_assets::{AssetServer, SceneExt};
 use myth_render::Renderer;
 use myth_render::settings::{RendererInitConfig, RendererSettings};
 use myth_resources::input::Input;
@@ -61,6 +61,9 @@ use myth_resources::input::Input;
 /// 2. Initialize GPU with [`Engine::init`]
 /// 3. Update each frame with [`Engine::update`]
 /// 4. Render using [`Renderer::begin_frame`]
+///
+/// Hosts that own their event loop (an egui app, a test harness) can instead
+/// call [`Engine::render_once`] per frame with their own timing.
 pub struct Engine {
     pub renderer: Renderer,
     pub scene_manager: SceneManager,
@@ -68,6 +71,8 @@ pub struct Engine {
     pub input: Input,
 
     frame_time: FrameTime,
+    time_scale: f32,
+    paused: bool,
 }
 
 impl Engine {
@@ -89,6 +94,8 @@ impl Engine {
             assets,
             input: Input::new(),
             frame_time: FrameTime::default(),
+            time_scale: 1.0,
+            paused: false,
         }
     }
 
@@ -235,6 +242,85 @@ impl Engine {
         self.frame_time.time
     }
 
+    /// Sets the total elapsed time in seconds.
+    ///
+    /// For hosts that manage the clock themselves (lockstep simulation,
+    /// deterministic tests). The frame counter is unchanged, and the next
+    /// [`update`](Self::update) advances from this value.
+    #[inline]
+    pub fn set_time(&mut self, time: f32) {
+        self.frame_time.time = time;
+    }
+
+    /// Returns the multiplier applied to frame time.
+    #[inline]
+    #[must_use]
+    pub fn time_scale(&self) -> f32 {
+        self.time_scale
+    }
+
+    /// Scales the engine clock, e.g. `0.5` for slow motion.
+    ///
+    /// Affects the `dt` and `time` seen by [`update`](Self::update), scene
+    /// logic, animation mixers and the app's update callbacks. Rendering is
+    /// unaffected. Negative values are treated as `0.0`.
+    #[inline]
+    pub fn set_time_scale(&mut self, scale: f32) {
+        self.time_scale = scale.max(0.0);
+    }
+
+    /// Freezes the engine clock while the render loop keeps running.
+    ///
+    /// Equivalent to a time scale of zero, but the scale set by
+    /// [`set_time_scale`](Self::set_time_scale) is restored on
+    /// [`resume`](Self::resume).
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Resumes the engine clock after [`pause`](Self::pause).
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the engine clock is paused.
+    #[inline]
+    #[must_use]
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Converts a real (wall-clock) delta into engine time, applying the
+    /// time scale and pause state.
+    #[inline]
+    #[must_use]
+    pub fn scaled_dt(&self, real_dt: f32) -> f32 {
+        if self.paused {
+            0.0
+        } else {
+            real_dt * self.time_scale
+        }
+    }
+
+    /// Builds the [`FrameState`] for a frame that took `real_dt` seconds.
+    ///
+    /// `dt` is scaled by [`scaled_dt`](Self::scaled_dt) and `time` is the
+    /// engine time after the frame's [`update`](Self::update). `real_dt`
+    /// is passed through unchanged.
+    #[must_use]
+    pub fn frame_state(&self, real_dt: f32) -> FrameState {
+        let dt = self.scaled_dt(real_dt);
+        FrameState {
+            time: self.frame_time.time + dt,
+            dt,
+            real_dt,
+            frame_count: self.frame_time.frame_count,
+            alpha: 0.0,
+        }
+    }
+
     #[inline]
     #[must_use]
     pub fn frame_time(&self) -> FrameTime {
@@ -281,24 +367,47 @@ impl Engine {
     /// - Runs scene logic and animations
     /// - Resets per-frame input state
     ///
+    /// The time scale and pause state are applied here, so scene logic and
+    /// animations see the scaled delta.
+    ///
     /// # Arguments
     ///
-    /// * `dt` - Delta time since the last frame in seconds
-    pub fn update(&mut self, dt: f32) {
+    /// * `real_dt` - Wall-clock time since the last frame in seconds
+    pub fn update(&mut self, real_dt: f32) {
         // Promote any assets that finished loading in the background.
         self.assets.process_loading_events();
 
+        let dt = self.scaled_dt(real_dt);
+
         self.frame_time.time += dt;
         self.frame_time.frame_count += 1;
         self.frame_time.delta_time = dt;
 
         if let Some(scene) = self.scene_manager.active_scene_mut() {
             scene.update(&self.input, dt);
+            scene.sync_particles(&self.assets);
         }
 
         self.input.start_frame();
     }
 
+    /// Advances the engine by `dt` and renders the active scene exactly once.
+    ///
+    /// One iteration of the windowed main loop without winit:
+    /// [`update`](Self::update), then
+    /// [`render_active_scene`](Self::render_active_scene), then
+    /// [`maybe_prune`](Self::maybe_prune). Call [`resize`](Self::resize) when
+    /// the host's viewport changes.
+    ///
+    /// Returns `true` if a frame was rendered, `false` if rendering was
+    /// skipped (not initialised, no active scene or camera, zero size).
+    pub fn render_once(&mut self, dt: f32) -> bool {
+        self.update(dt);
+        let rendered = self.render_active_scene();
+        self.maybe_prune();
+        rendered
+    }
+
     /// Performs periodic resource cleanup.
     ///
     /// This method should be called after each frame to release unused GPU
@@ -383,10 +492,104 @@ impl Default for Engine {
 /// providing essential timing information for animations and logic.
 #[derive(Debug, Clone, Copy)]
 pub struct FrameState {
-    /// Total elapsed time since the application started (in seconds).
+    /// Engine time (in seconds): the sum of all scaled deltas so far.
     pub time: f32,
-    /// Delta time since the last frame (in seconds).
+    /// Delta time since the last frame (in seconds), scaled by
+    /// [`Engine::time_scale`] and zero while the engine is paused.
     pub dt: f32,
+    /// Unscaled wall-clock delta (in seconds), for UI and profiling that
+    /// must keep running while the engine clock is paused or slowed.
+    pub real_dt: f32,
     /// Total number of frames rendered since startup.
     pub frame_count: u64,
+    /// How far the current frame lies between the last two fixed steps, in
+    /// `0.0..1.0`.
+    ///
+    /// Use it to interpolate state advanced in
+    /// [`AppHandler::fixed_update`](crate::AppHandler::fixed_update) for
+    /// smooth rendering. Always `0.0` inside `fixed_update` itself and when
+    /// no fixed timestep is configured.
+    pub alpha: f32,
+}
+
+/// Fixed-timestep accumulator.
+///
+/// Each frame, [`advance`](Self::advance) adds the real frame time and
+/// returns how many whole steps of [`step`](Self::step) seconds to run.
+/// Leftover time carries over to the next frame and is exposed as
+/// [`alpha`](Self::alpha).
+///
+/// # Catch-up Clamping
+///
+/// If a frame is so long that more than [`max_steps`](Self::max_steps)
+/// steps are due, only `max_steps` run and the excess time is dropped.
+/// Without this limit a slow frame schedules more steps, which makes the
+/// next frame slower still (the "spiral of death"). Simulation time then
+/// runs slower than real time until the load drops.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedTimestep {
+    step: f32,
+    max_steps: u32,
+    accumulator: f32,
+}
+
+impl FixedTimestep {
+    /// Default for [`max_steps`](Self::max_steps).
+    pub const DEFAULT_MAX_STEPS: u32 = 8;
+
+    /// Creates an accumulator running steps of `step` seconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    #[must_use]
+    pub fn new(step: f32) -> Self {
+        assert!(step > 0.0, "fixed timestep must be positive, got {step}");
+        Self {
+            step,
+            max_steps: Self::DEFAULT_MAX_STEPS,
+            accumulator: 0.0,
+        }
+    }
+
+    /// Sets the maximum number of steps run per frame (at least 1).
+    #[must_use]
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    /// Duration of one step in seconds.
+    #[inline]
+    #[must_use]
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    /// Maximum number of steps run per frame.
+    #[inline]
+    #[must_use]
+    pub fn max_steps(&self) -> u32 {
+        self.max_steps
+    }
+
+    /// Accumulates `dt` seconds and returns the number of steps to run now.
+    pub fn advance(&mut self, dt: f32) -> u32 {
+        self.accumulator += dt.max(0.0);
+        let due = (self.accumulator / self.step).floor();
+        let steps = (due as u32).min(self.max_steps);
+        self.accumulator -= steps as f32 * self.step;
+        if steps == self.max_steps {
+            // Drop the whole steps that could not be caught up this frame.
+            self.accumulator = self.accumulator.rem_euclid(self.step);
+        }
+        steps
+    }
+
+    /// Fraction of a step accumulated but not yet run, in `0.0..1.0`.
+    #[inline]
+    #[must_use]
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
 }
@@ -7,14 +7,16 @@
 pub mod app;
 pub mod engine;
 pub mod orbit_controls;
+pub mod transform_gizmo;
 pub mod window;
 
 #[cfg(feature = "winit")]
 pub mod winit;
 
 pub use app::{AppHandler, DefaultHandler};
-pub use engine::{Engine, FrameState};
+pub use engine::{Engine, FixedTimestep, FrameState};
 pub use orbit_controls::OrbitControls;
+pub use transform_gizmo::{GizmoAxis, GizmoMode, GizmoSnap, TransformGizmo};
 pub use window::Window;
 
 #[doc(hidden)]
@@ -1,6 +1,7 @@
 //! Orbit Camera Controller
 //!
-//! Provides intuitive mouse-based camera control for 3D scene navigation.
+//! Provides intuitive mouse- and touch-based camera control for 3D scene
+//! navigation.
 //!
 //! # Example
 //!
@@ -66,10 +67,10 @@ impl Spherical {
     }
 }
 
-/// Mouse-based orbit camera controller.
+/// Mouse- and touch-based orbit camera controller.
 ///
 /// Allows users to rotate, zoom, and pan the camera around a target point
-/// using mouse input.
+/// using mouse or touch input.
 ///
 /// # Controls
 ///
@@ -78,6 +79,9 @@ impl Spherical {
 /// | Left Mouse + Drag | Rotate camera around target |
 /// | Right Mouse + Drag | Pan camera and target |
 /// | Scroll Wheel | Zoom in/out |
+/// | One-Finger Drag | Rotate camera around target |
+/// | Two-Finger Drag | Pan camera and target |
+/// | Pinch | Zoom in/out |
 ///
 /// # Features
 ///
@@ -111,17 +115,17 @@ pub struct OrbitControls {
     /// Damping factor for zoom (0.0 = instant, 1.0 = no movement).
     pub zoom_damping_factor: f32,
 
-    /// Enable mouse wheel zoom.
+    /// Enable mouse wheel and pinch zoom.
     pub enable_zoom: bool,
     /// Zoom speed multiplier.
     pub zoom_speed: f32,
 
-    /// Enable left-click rotation.
+    /// Enable left-click / one-finger rotation.
     pub enable_rotate: bool,
     /// Rotation speed multiplier.
     pub rotate_speed: f32,
 
-    /// Enable right-click panning.
+    /// Enable right-click / two-finger panning.
     pub enable_pan: bool,
     /// Pan speed multiplier.
     pub pan_speed: f32,
@@ -208,8 +212,16 @@ impl OrbitControls {
         }
 
         // Zoom
+        // A pinch also emits a synthetic scroll; prefer the proportional
+        // pinch scale so the content tracks the fingers.
+        let pinch = input.pinch_scale();
         let scroll = input.scroll_delta();
-        if self.enable_zoom && scroll.y != 0.0 {
+        if self.enable_zoom && (pinch - 1.0).abs() > f32::EPSILON {
+            self.target_radius /= pinch.powf(self.zoom_speed);
+            self.target_radius = self
+                .target_radius
+                .clamp(self.min_distance, self.max_distance);
+        } else if self.enable_zoom && scroll.y != 0.0 {
             let zoom_scale = 0.95f32.powf(self.zoom_speed);
 
             if scroll.y > 0.0 {
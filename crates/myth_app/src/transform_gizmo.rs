@@ -0,0 +1,530 @@
+//! Transform Gizmo
+//!
+//! Provides viewport handles for translating, rotating and scaling a scene
+//! node with the mouse.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use myth::prelude::*;
+//!
+//! struct MyApp {
+//!     gizmo: TransformGizmo,
+//!     orbit: OrbitControls,
+//! }
+//!
+//! impl AppHandler for MyApp {
+//!     fn update(&mut self, engine: &mut Engine, _: &dyn Window, frame: &FrameState) {
+//!         let Some(scene) = engine.scene_manager.active_scene_mut() else {
+//!             return;
+//!         };
+//!
+//!         // The gizmo consumes the mouse while hovered or dragged, so the
+//!         // camera only orbits when the user is not manipulating the node.
+//!         let captured = self.gizmo.update(scene, &engine.input);
+//!         if !captured {
+//!             if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+//!                 self.orbit.update(transform, &engine.input, camera.fov(), frame.dt);
+//!             }
+//!         }
+//!     }
+//! }
+//! ```
+
+use glam::{Affine3A, Mat4, Quat, Vec2, Vec3, Vec4};
+use myth_resources::BoundingBox;
+use myth_resources::input::{Input, MouseButton};
+use myth_scene::{NodeHandle, ProjectionType, Scene};
+
+/// Fraction of the handle length within which the mouse ray picks a handle.
+const PICK_TOLERANCE: f32 = 0.08;
+
+/// Manipulation performed by a [`TransformGizmo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GizmoMode {
+    /// Move the node along a world axis.
+    #[default]
+    Translate,
+    /// Rotate the node around a world axis.
+    Rotate,
+    /// Scale the node along one of its local axes.
+    Scale,
+}
+
+/// Handle axis of a [`TransformGizmo`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GizmoAxis {
+    X,
+    Y,
+    Z,
+}
+
+impl GizmoAxis {
+    /// All axes, in handle order.
+    pub const ALL: [Self; 3] = [Self::X, Self::Y, Self::Z];
+
+    /// Index of the axis (0 = X, 1 = Y, 2 = Z).
+    #[must_use]
+    pub fn index(self) -> usize {
+        match self {
+            Self::X => 0,
+            Self::Y => 1,
+            Self::Z => 2,
+        }
+    }
+
+    /// Unit vector of the axis.
+    #[must_use]
+    pub fn unit(self) -> Vec3 {
+        match self {
+            Self::X => Vec3::X,
+            Self::Y => Vec3::Y,
+            Self::Z => Vec3::Z,
+        }
+    }
+
+    fn color(self) -> Vec4 {
+        match self {
+            Self::X => Vec4::new(0.9, 0.2, 0.2, 1.0),
+            Self::Y => Vec4::new(0.2, 0.85, 0.2, 1.0),
+            Self::Z => Vec4::new(0.25, 0.4, 1.0, 1.0),
+        }
+    }
+}
+
+/// Snap increments applied while dragging. `None` disables snapping for
+/// that mode.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct GizmoSnap {
+    /// Translation step in world units.
+    pub translate: Option<f32>,
+    /// Rotation step in radians.
+    pub rotate: Option<f32>,
+    /// Scale step (the resulting scale is a multiple of it).
+    pub scale: Option<f32>,
+}
+
+/// Rounds `value` to the nearest multiple of `step`; non-positive steps
+/// leave it unchanged.
+fn snap(value: f32, step: Option<f32>) -> f32 {
+    match step {
+        Some(step) if step > 0.0 => (value / step).round() * step,
+        _ => value,
+    }
+}
+
+/// World-space mouse ray.
+#[derive(Debug, Clone, Copy)]
+struct Ray {
+    origin: Vec3,
+    direction: Vec3,
+}
+
+impl Ray {
+    /// Unprojects a normalized device coordinate through the inverse
+    /// view-projection matrix (reverse-Z: 1.0 is the near plane).
+    fn from_ndc(ndc: Vec2, inverse_view_projection: Mat4) -> Option<Self> {
+        let near = inverse_view_projection.project_point3(ndc.extend(1.0));
+        let far = inverse_view_projection.project_point3(ndc.extend(0.5));
+        Some(Self {
+            origin: near,
+            direction: (far - near).try_normalize()?,
+        })
+    }
+
+    fn at(&self, t: f32) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Parameter along the infinite line `point + axis * t` (with a unit
+    /// `axis`) closest to this ray, or `None` if they are parallel.
+    fn closest_on_line(&self, point: Vec3, axis: Vec3) -> Option<f32> {
+        let offset = self.origin - point;
+        let cos = self.direction.dot(axis);
+        let denom = 1.0 - cos * cos;
+        if denom < 1e-6 {
+            return None;
+        }
+        let along_ray = self.direction.dot(offset);
+        let along_axis = axis.dot(offset);
+        Some((along_axis - cos * along_ray) / denom)
+    }
+
+    /// Distance between the ray and the segment `point .. point + axis * len`.
+    fn distance_to_segment(&self, point: Vec3, axis: Vec3, len: f32) -> Option<f32> {
+        let closest = point + axis * self.closest_on_line(point, axis)?.clamp(0.0, len);
+        let t = self.direction.dot(closest - self.origin);
+        (t > 0.0).then(|| (self.at(t) - closest).length())
+    }
+
+    /// Intersection with the plane through `point` with normal `normal`.
+    fn intersect_plane(&self, point: Vec3, normal: Vec3) -> Option<Vec3> {
+        let denom = self.direction.dot(normal);
+        if denom.abs() < 1e-6 {
+            return None;
+        }
+        let t = (point - self.origin).dot(normal) / denom;
+        (t > 0.0).then(|| self.at(t))
+    }
+}
+
+/// Per-frame view of the gizmo in world space.
+struct GizmoFrame {
+    ray: Ray,
+    origin: Vec3,
+    /// World-space handle directions, indexed by [`GizmoAxis::index`].
+    axes: [Vec3; 3],
+    /// World-space handle length.
+    length: f32,
+}
+
+/// State captured when a drag starts.
+#[derive(Debug, Clone, Copy)]
+struct DragState {
+    mode: GizmoMode,
+    axis: GizmoAxis,
+    /// Axis direction at drag start.
+    direction: Vec3,
+    origin: Vec3,
+    /// Axis line parameter under the mouse (translate / scale).
+    start_param: f32,
+    /// Direction from the origin to the ring plane hit (rotate).
+    start_vector: Vec3,
+    start_rotation: Quat,
+    start_scale: Vec3,
+}
+
+/// Mouse-driven translate / rotate / scale handles for a single node.
+///
+/// The gizmo is drawn through the scene's [`DebugDraw`](myth_scene::DebugDraw)
+/// buffer at a constant size on screen and picks its handles by casting the
+/// mouse ray of the active camera. Dragging a handle with the left mouse
+/// button edits the node's [`Transform`](myth_core::Transform):
+///
+/// | Mode | Handles | Effect |
+/// |------|---------|--------|
+/// | [`Translate`](GizmoMode::Translate) | World-axis arrows | Moves along the axis |
+/// | [`Rotate`](GizmoMode::Rotate) | World-axis rings | Rotates around the axis |
+/// | [`Scale`](GizmoMode::Scale) | Local-axis boxes | Scales along the axis |
+///
+/// Debug lines are depth-tested by default; set
+/// `scene.debug().depth_test = false` to keep the gizmo visible through
+/// geometry.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut gizmo = TransformGizmo::new();
+/// gizmo.set_target(Some(node));
+/// gizmo.mode = GizmoMode::Rotate;
+/// gizmo.snap.rotate = Some(15f32.to_radians());
+///
+/// // In update loop
+/// if !gizmo.update(scene, &input) {
+///     orbit.update(camera_transform, &input, fov, dt);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TransformGizmo {
+    /// Manipulation performed by the handles.
+    pub mode: GizmoMode,
+    /// Snap increments applied while dragging.
+    pub snap: GizmoSnap,
+    /// Handle length as a fraction of the viewport height (default 0.15).
+    pub size: f32,
+
+    // Internal state
+    target: Option<NodeHandle>,
+    hovered: Option<GizmoAxis>,
+    drag: Option<DragState>,
+}
+
+impl Default for TransformGizmo {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransformGizmo {
+    /// Creates a translate gizmo without a target.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            mode: GizmoMode::Translate,
+            snap: GizmoSnap::default(),
+            size: 0.15,
+            target: None,
+            hovered: None,
+            drag: None,
+        }
+    }
+
+    /// Selects the node to manipulate (`None` hides the gizmo).
+    ///
+    /// Changing the target cancels an ongoing drag.
+    pub fn set_target(&mut self, target: Option<NodeHandle>) {
+        if self.target != target {
+            self.drag = None;
+            self.hovered = None;
+        }
+        self.target = target;
+    }
+
+    /// Returns the node being manipulated.
+    #[must_use]
+    pub fn target(&self) -> Option<NodeHandle> {
+        self.target
+    }
+
+    /// Returns the axis being dragged.
+    #[must_use]
+    pub fn active_axis(&self) -> Option<GizmoAxis> {
+        self.drag.map(|drag| drag.axis)
+    }
+
+    /// Returns the axis under the mouse cursor.
+    #[must_use]
+    pub fn hovered_axis(&self) -> Option<GizmoAxis> {
+        self.hovered
+    }
+
+    /// Returns `true` while a handle is being dragged.
+    #[must_use]
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Picks, drags and draws the handles for this frame.
+    ///
+    /// Call this once per frame, before other mouse-driven controllers.
+    /// Returns `true` when the gizmo uses the mouse (a handle is dragged, or
+    /// hovered with the button up), in which case camera controls should
+    /// ignore it.
+    pub fn update(&mut self, scene: &mut Scene, input: &Input) -> bool {
+        let Some(target) = self.target else {
+            return false;
+        };
+        let Some(frame) = self.frame(scene, target, input) else {
+            self.hovered = None;
+            self.drag = None;
+            return false;
+        };
+
+        if !input.get_mouse_button(MouseButton::Left) {
+            self.drag = None;
+        }
+
+        if self.drag.is_none() {
+            self.hovered = self.pick(&frame);
+            if let Some(axis) = self.hovered
+                && input.get_mouse_button_down(MouseButton::Left)
+            {
+                self.drag = self.begin_drag(scene, target, &frame, axis);
+            }
+        }
+
+        if let Some(drag) = self.drag {
+            self.apply_drag(scene, target, &frame, &drag);
+        }
+
+        self.draw(scene, target, frame.length);
+        // A drag that started elsewhere (e.g. an orbit) keeps the mouse even
+        // when the cursor crosses a handle.
+        self.drag.is_some()
+            || (self.hovered.is_some() && !input.get_mouse_button(MouseButton::Left))
+    }
+
+    /// Builds the mouse ray and the handle layout for the active camera.
+    fn frame(&self, scene: &Scene, target: NodeHandle, input: &Input) -> Option<GizmoFrame> {
+        let node_world = scene.compute_world_matrix(target)?;
+        let camera_node = scene.active_camera?;
+        let camera_world = scene.compute_world_matrix(camera_node)?;
+        let camera = scene.get_camera(camera_node)?;
+
+        let screen = input.screen_size();
+        if screen.x <= 0.0 || screen.y <= 0.0 {
+            return None;
+        }
+        let mouse = input.mouse_position() / screen;
+        let ndc = Vec2::new(mouse.x * 2.0 - 1.0, 1.0 - mouse.y * 2.0);
+
+        let view = Mat4::from(camera_world).inverse();
+        let projection = camera.extract_render_camera().unjittered_projection;
+        let ray = Ray::from_ndc(ndc, (projection * view).inverse())?;
+
+        // Keep the handles at a constant size on screen.
+        let origin = Vec3::from(node_world.translation);
+        let half_height = match camera.projection_type() {
+            ProjectionType::Perspective => {
+                let forward = -Vec3::from(camera_world.matrix3.z_axis).normalize_or_zero();
+                let depth = (origin - Vec3::from(camera_world.translation)).dot(forward);
+                depth.max(camera.near()) * (camera.fov() * 0.5).tan()
+            }
+            ProjectionType::Orthographic => camera.ortho_size(),
+        };
+
+        Some(GizmoFrame {
+            ray,
+            origin,
+            axes: self.axes(&node_world),
+            length: 2.0 * half_height * self.size,
+        })
+    }
+
+    /// Handle directions: local axes for scaling, world axes otherwise.
+    fn axes(&self, node_world: &Affine3A) -> [Vec3; 3] {
+        match self.mode {
+            GizmoMode::Scale => {
+                let (_, rotation, _) = node_world.to_scale_rotation_translation();
+                GizmoAxis::ALL.map(|axis| rotation * axis.unit())
+            }
+            GizmoMode::Translate | GizmoMode::Rotate => GizmoAxis::ALL.map(GizmoAxis::unit),
+        }
+    }
+
+    /// Returns the handle closest to the mouse ray within the pick tolerance.
+    fn pick(&self, frame: &GizmoFrame) -> Option<GizmoAxis> {
+        let tolerance = frame.length * PICK_TOLERANCE;
+        GizmoAxis::ALL
+            .into_iter()
+            .filter_map(|axis| {
+                let dir = frame.axes[axis.index()];
+                let distance = match self.mode {
+                    GizmoMode::Translate | GizmoMode::Scale => {
+                        frame
+                            .ray
+                            .distance_to_segment(frame.origin, dir, frame.length)?
+                    }
+                    GizmoMode::Rotate => {
+                        let hit = frame.ray.intersect_plane(frame.origin, dir)?;
+                        ((hit - frame.origin).length() - frame.length).abs()
+                    }
+                };
+                (distance <= tolerance).then_some((axis, distance))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(axis, _)| axis)
+    }
+
+    fn begin_drag(
+        &self,
+        scene: &Scene,
+        target: NodeHandle,
+        frame: &GizmoFrame,
+        axis: GizmoAxis,
+    ) -> Option<DragState> {
+        let node = scene.get_node(target)?;
+        let world = scene.compute_world_matrix(target)?;
+        let direction = frame.axes[axis.index()];
+
+        let mut drag = DragState {
+            mode: self.mode,
+            axis,
+            direction,
+            origin: frame.origin,
+            start_param: 0.0,
+            start_vector: Vec3::ZERO,
+            start_rotation: world.to_scale_rotation_translation().1,
+            start_scale: node.transform.scale,
+        };
+        match self.mode {
+            GizmoMode::Translate | GizmoMode::Scale => {
+                drag.start_param = frame.ray.closest_on_line(frame.origin, direction)?;
+                if self.mode == GizmoMode::Scale && drag.start_param.abs() < 1e-4 {
+                    return None;
+                }
+            }
+            GizmoMode::Rotate => {
+                let hit = frame.ray.intersect_plane(frame.origin, direction)?;
+                drag.start_vector = (hit - frame.origin).try_normalize()?;
+            }
+        }
+        Some(drag)
+    }
+
+    fn apply_drag(
+        &self,
+        scene: &mut Scene,
+        target: NodeHandle,
+        frame: &GizmoFrame,
+        drag: &DragState,
+    ) {
+        match drag.mode {
+            GizmoMode::Translate => {
+                let Some(param) = frame.ray.closest_on_line(drag.origin, drag.direction) else {
+                    return;
+                };
+                let delta = snap(param - drag.start_param, self.snap.translate);
+                scene.set_world_position(target, drag.origin + drag.direction * delta);
+            }
+            GizmoMode::Rotate => {
+                let Some(hit) = frame.ray.intersect_plane(drag.origin, drag.direction) else {
+                    return;
+                };
+                let Some(current) = (hit - drag.origin).try_normalize() else {
+                    return;
+                };
+                let angle = drag
+                    .direction
+                    .dot(drag.start_vector.cross(current))
+                    .atan2(drag.start_vector.dot(current));
+                let angle = snap(angle, self.snap.rotate);
+                let rotation = Quat::from_axis_angle(drag.direction, angle) * drag.start_rotation;
+                scene.set_world_rotation(target, rotation.normalize());
+            }
+            GizmoMode::Scale => {
+                let Some(param) = frame.ray.closest_on_line(drag.origin, drag.direction) else {
+                    return;
+                };
+                let index = drag.axis.index();
+                let value = drag.start_scale[index] * param / drag.start_param;
+                let Some(node) = scene.get_node_mut(target) else {
+                    return;
+                };
+                node.transform.scale[index] = snap(value, self.snap.scale);
+            }
+        }
+    }
+
+    fn draw(&self, scene: &mut Scene, target: NodeHandle, length: f32) {
+        // Re-read the node after this frame's edits so the handles follow it
+        // without a one-frame lag.
+        let Some(world) = scene.compute_world_matrix(target) else {
+            return;
+        };
+        let origin = Vec3::from(world.translation);
+        let axes = self.axes(&world);
+        let highlight = self.active_axis().or(self.hovered);
+
+        let debug = scene.debug();
+        for axis in GizmoAxis::ALL {
+            let color = if highlight == Some(axis) {
+                Vec4::new(1.0, 0.85, 0.1, 1.0)
+            } else {
+                axis.color()
+            };
+            let dir = axes[axis.index()];
+            match self.mode {
+                GizmoMode::Translate => {
+                    debug.arrow(origin, origin + dir * length, color);
+                }
+                GizmoMode::Rotate => {
+                    let u = dir.any_orthonormal_vector();
+                    let v = dir.cross(u);
+                    debug.circle(origin, u * length, v * length, color);
+                }
+                GizmoMode::Scale => {
+                    let tip = origin + dir * length;
+                    let half = Vec3::splat(length * 0.05);
+                    debug.line(origin, tip, color).aabb(
+                        &BoundingBox {
+                            min: tip - half,
+                            max: tip + half,
+                        },
+                        color,
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! Gilrs gamepad adapter.
+//!
+//! Polls [gilrs](https://crates.io/crates/gilrs) (which uses the browser
+//! Gamepad API on wasm) and translates its events into the engine's
+//! platform-agnostic gamepad input types.
+
+use gilrs::{Axis, Button, EventType, Gilrs};
+
+use myth_resources::input::{ButtonState, GamepadAxis, GamepadButton, GamepadId, Input};
+
+/// Analog trigger value above which the trigger counts as pressed.
+const TRIGGER_PRESS_THRESHOLD: f32 = 0.5;
+
+/// Translates a gilrs button to the engine's `GamepadButton`.
+///
+/// Gilrs names the bumpers `LeftTrigger` / `RightTrigger` and the analog
+/// triggers `LeftTrigger2` / `RightTrigger2`.
+#[must_use]
+pub fn translate_button(button: Button) -> GamepadButton {
+    match button {
+        Button::South => GamepadButton::South,
+        Button::East => GamepadButton::East,
+        Button::North => GamepadButton::North,
+        Button::West => GamepadButton::West,
+        Button::LeftTrigger => GamepadButton::LeftBumper,
+        Button::RightTrigger => GamepadButton::RightBumper,
+        Button::LeftTrigger2 => GamepadButton::LeftTrigger,
+        Button::RightTrigger2 => GamepadButton::RightTrigger,
+        Button::Select => GamepadButton::Select,
+        Button::Start => GamepadButton::Start,
+        Button::Mode => GamepadButton::Mode,
+        Button::LeftThumb => GamepadButton::LeftStick,
+        Button::RightThumb => GamepadButton::RightStick,
+        Button::DPadUp => GamepadButton::DPadUp,
+        Button::DPadDown => GamepadButton::DPadDown,
+        Button::DPadLeft => GamepadButton::DPadLeft,
+        Button::DPadRight => GamepadButton::DPadRight,
+        other => GamepadButton::Other(other as u16),
+    }
+}
+
+/// Translates a gilrs stick axis to the engine's `GamepadAxis`.
+///
+/// Returns `None` for axes without an engine equivalent (the D-pad is
+/// reported as buttons instead).
+#[must_use]
+pub fn translate_axis(axis: Axis) -> Option<GamepadAxis> {
+    match axis {
+        Axis::LeftStickX => Some(GamepadAxis::LeftStickX),
+        Axis::LeftStickY => Some(GamepadAxis::LeftStickY),
+        Axis::RightStickX => Some(GamepadAxis::RightStickX),
+        Axis::RightStickY => Some(GamepadAxis::RightStickY),
+        Axis::LeftZ => Some(GamepadAxis::LeftTrigger),
+        Axis::RightZ => Some(GamepadAxis::RightTrigger),
+        _ => None,
+    }
+}
+
+/// Owns the gilrs context and feeds gamepad events into [`Input`].
+pub struct GamepadAdapter {
+    gilrs: Option<Gilrs>,
+    announced: bool,
+}
+
+impl GamepadAdapter {
+    /// Creates the adapter. Gamepad input is disabled (with a warning) if
+    /// the platform backend can't be initialised.
+    #[must_use]
+    pub fn new() -> Self {
+        let gilrs = match Gilrs::new() {
+            Ok(gilrs) => Some(gilrs),
+            Err(gilrs::Error::NotImplemented(gilrs)) => {
+                log::warn!("Gamepad input is not supported on this platform");
+                Some(gilrs)
+            }
+            Err(e) => {
+                log::warn!("Failed to initialize gamepad input: {e}");
+                None
+            }
+        };
+        Self {
+            gilrs,
+            announced: false,
+        }
+    }
+
+    /// Drains pending gamepad events into `input`.
+    ///
+    /// Call once per frame before user update logic.
+    pub fn poll(&mut self, input: &mut Input) {
+        let Some(gilrs) = &mut self.gilrs else {
+            return;
+        };
+
+        // Pads that were plugged in before startup don't send `Connected`.
+        if !self.announced {
+            self.announced = true;
+            for (id, pad) in gilrs.gamepads() {
+                input.inject_gamepad_connected(GamepadId(id.into()), pad.name());
+            }
+        }
+
+        while let Some(event) = gilrs.next_event() {
+            let id = GamepadId(event.id.into());
+            match event.event {
+                EventType::Connected => {
+                    let name = gilrs.gamepad(event.id).name().to_string();
+                    input.inject_gamepad_connected(id, name);
+                }
+                EventType::Disconnected => input.inject_gamepad_disconnected(id),
+                EventType::ButtonPressed(button, _) => {
+                    input.inject_gamepad_button(id, translate_button(button), ButtonState::Pressed);
+                }
+                EventType::ButtonReleased(button, _) => {
+                    input.inject_gamepad_button(
+                        id,
+                        translate_button(button),
+                        ButtonState::Released,
+                    );
+                }
+                EventType::ButtonChanged(button, value, _) => {
+                    let axis = match button {
+                        Button::LeftTrigger2 => GamepadAxis::LeftTrigger,
+                        Button::RightTrigger2 => GamepadAxis::RightTrigger,
+                        _ => continue,
+                    };
+                    input.inject_gamepad_axis(id, axis, value);
+                    let state = if value > TRIGGER_PRESS_THRESHOLD {
+                        ButtonState::Pressed
+                    } else {
+                        ButtonState::Released
+                    };
+                    input.inject_gamepad_button(id, translate_button(button), state);
+                }
+                EventType::AxisChanged(axis, value, _) => {
+                    if let Some(axis) = translate_axis(axis) {
+                        input.inject_gamepad_axis(id, axis, value);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Default for GamepadAdapter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -27,11 +27,13 @@ pub use winit::window::Window as WinitWindow;
 use winit::window::{Window, WindowId};
 
 use crate::app::AppHandler;
-use crate::engine::{Engine, FrameState};
+use crate::engine::{Engine, FixedTimestep, FrameState};
 use crate::window::Window as WindowTrait;
 use myth_core::{Error, PlatformError};
 use myth_render::settings::{RendererInitConfig, RendererSettings};
 
+#[cfg(feature = "gamepad")]
+pub mod gamepad_adapter;
 pub mod input_adapter;
 
 // ============================================================================
@@ -89,6 +91,7 @@ pub struct App {
     title: String,
     init_config: RendererInitConfig,
     render_settings: RendererSettings,
+    fixed_timestep: Option<FixedTimestep>,
     #[cfg(not(target_arch = "wasm32"))]
     window_size: Option<(u32, u32)>,
     #[cfg(target_arch = "wasm32")]
@@ -103,6 +106,7 @@ impl App {
             title: "Myth Engine".into(),
             init_config: RendererInitConfig::default(),
             render_settings: RendererSettings::default(),
+            fixed_timestep: None,
             #[cfg(not(target_arch = "wasm32"))]
             window_size: None,
             #[cfg(target_arch = "wasm32")]
@@ -130,6 +134,29 @@ impl App {
         self
     }
 
+    /// Enables [`AppHandler::fixed_update`], called every `step` seconds of
+    /// real time.
+    ///
+    /// At most [`FixedTimestep::DEFAULT_MAX_STEPS`] steps run per frame;
+    /// use [`with_fixed_timestep_config`](Self::with_fixed_timestep_config)
+    /// to change the limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is not positive.
+    #[must_use]
+    pub fn with_fixed_timestep(mut self, step: f32) -> Self {
+        self.fixed_timestep = Some(FixedTimestep::new(step));
+        self
+    }
+
+    /// Enables [`AppHandler::fixed_update`] with a custom [`FixedTimestep`].
+    #[must_use]
+    pub fn with_fixed_timestep_config(mut self, timestep: FixedTimestep) -> Self {
+        self.fixed_timestep = Some(timestep);
+        self
+    }
+
     #[cfg(not(target_arch = "wasm32"))]
     /// Sets the initial logical size of the window (only effective for native, WASM is controlled by CSS).
     #[must_use]
@@ -168,6 +195,7 @@ impl App {
             self.title,
             self.init_config,
             self.render_settings,
+            self.fixed_timestep,
             self.window_size,
         );
         event_loop
@@ -191,6 +219,7 @@ impl App {
             self.title,
             self.init_config,
             self.render_settings,
+            self.fixed_timestep,
             self.canvas_id,
         );
         event_loop.spawn_app(runner);
@@ -214,6 +243,7 @@ struct AppRunner<H: AppHandler> {
     title: String,
     init_config: RendererInitConfig,
     render_settings: RendererSettings,
+    fixed_timestep: Option<FixedTimestep>,
 
     #[cfg(not(target_arch = "wasm32"))]
     window_size: Option<(u32, u32)>,
@@ -225,8 +255,12 @@ struct AppRunner<H: AppHandler> {
     engine: Option<Engine>,
     user_state: Option<H>,
 
-    start_time: Instant,
     last_loop_time: Instant,
+    /// Simulation time advanced by fixed steps (in seconds).
+    fixed_time: f32,
+
+    #[cfg(feature = "gamepad")]
+    gamepads: gamepad_adapter::GamepadAdapter,
 
     /// WASM async initialization state
     #[cfg(target_arch = "wasm32")]
@@ -262,6 +296,7 @@ impl<H: AppHandler> AppRunner<H> {
         title: String,
         init_config: RendererInitConfig,
         render_settings: RendererSettings,
+        fixed_timestep: Option<FixedTimestep>,
         #[cfg(not(target_arch = "wasm32"))] window_size: Option<(u32, u32)>,
         #[cfg(target_arch = "wasm32")] canvas_id: Option<String>,
     ) -> Self {
@@ -270,6 +305,7 @@ impl<H: AppHandler> AppRunner<H> {
             title,
             init_config,
             render_settings,
+            fixed_timestep,
             #[cfg(not(target_arch = "wasm32"))]
             window_size,
             #[cfg(target_arch = "wasm32")]
@@ -278,8 +314,10 @@ impl<H: AppHandler> AppRunner<H> {
             window: None,
             engine: None,
             user_state: None,
-            start_time: now,
             last_loop_time: now,
+            fixed_time: 0.0,
+            #[cfg(feature = "gamepad")]
+            gamepads: gamepad_adapter::GamepadAdapter::new(),
             #[cfg(target_arch = "wasm32")]
             init_state: std::rc::Rc::new(std::cell::RefCell::new(WasmInitState::default())),
         }
@@ -287,11 +325,10 @@ impl<H: AppHandler> AppRunner<H> {
 
     fn update_logic(&mut self) {
         let now = Instant::now();
-        let total_time = now.duration_since(self.start_time).as_secs_f32();
 
         // Limiting max dt to 100ms to prevent death spiral from window dragging or tab switching
         let raw_dt = now.duration_since(self.last_loop_time).as_secs_f32();
-        let dt = raw_dt.min(0.1);
+        let real_dt = raw_dt.min(0.1);
 
         self.last_loop_time = now;
 
@@ -301,15 +338,29 @@ impl<H: AppHandler> AppRunner<H> {
             return;
         };
 
-        let frame_state = FrameState {
-            time: total_time,
-            dt,
-            frame_count: engine.frame_count(),
-        };
+        #[cfg(feature = "gamepad")]
+        self.gamepads.poll(&mut engine.input);
+
+        let mut frame_state = engine.frame_state(real_dt);
+        if let Some(timestep) = &mut self.fixed_timestep {
+            let step = timestep.step();
+            for _ in 0..timestep.advance(frame_state.dt) {
+                self.fixed_time += step;
+                let fixed_state = FrameState {
+                    time: self.fixed_time,
+                    dt: step,
+                    real_dt,
+                    frame_count: frame_state.frame_count,
+                    alpha: 0.0,
+                };
+                user_state.fixed_update(engine, &fixed_state);
+            }
+            frame_state.alpha = timestep.alpha();
+        }
 
         // Pass &dyn WindowTrait (winit::Window implements our Window trait)
         user_state.update(engine, window.as_ref(), &frame_state);
-        engine.update(dt);
+        engine.update(real_dt);
     }
 }
 
@@ -345,9 +396,7 @@ impl<H: AppHandler> ApplicationHandler for AppRunner<H> {
         self.user_state = Some(H::init(&mut engine, window.as_ref()));
         self.engine = Some(engine);
 
-        let now = Instant::now();
-        self.start_time = now;
-        self.last_loop_time = now;
+        self.last_loop_time = Instant::now();
     }
 
     #[cfg(target_arch = "wasm32")]
@@ -427,9 +476,7 @@ impl<H: AppHandler> ApplicationHandler for AppRunner<H> {
 
         self.init_state.borrow_mut().pending = true;
 
-        let now = Instant::now();
-        self.start_time = now;
-        self.last_loop_time = now;
+        self.last_loop_time = Instant::now();
     }
 
     fn window_event(
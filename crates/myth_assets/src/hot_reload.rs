@@ -0,0 +1,425 @@
+//! File-watch based hot reloading for [`AssetServer`](crate::AssetServer).
+//!
+//! Available behind the `hot-reload` feature. The server records the local
+//! source path of every file-backed image and prefab it loads; once
+//! [`AssetServer::enable_hot_reload`](crate::AssetServer::enable_hot_reload)
+//! is called, a [`notify`] watcher observes the directories containing those
+//! files and reports each edited file after it has been quiet for
+//! [`DEBOUNCE`]. The server then re-runs the original loader and swaps the
+//! result into the **existing** handles, so scene references stay valid.
+//!
+//! Native only: on `wasm32` there is no file system to watch.
+
+use std::path::{Path, PathBuf};
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use flume::{Receiver, Sender, unbounded};
+use parking_lot::Mutex;
+use rustc_hash::FxHashMap;
+#[cfg(not(target_arch = "wasm32"))]
+use rustc_hash::FxHashSet;
+
+use myth_resources::ImageHandle;
+#[cfg(any(feature = "gltf", feature = "ply"))]
+use myth_resources::PrefabHandle;
+
+/// How often the debounce thread checks for settled changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long a file must stay unchanged before it is reloaded.
+///
+/// Editors often write a file in several steps (truncate, write, rename);
+/// waiting for file events to settle avoids decoding half-written
+/// data and collapses a burst of saves into a single reload.
+pub const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Decoder used to rebuild an image from its source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ImageKind {
+    Ldr,
+    Hdr,
+    Lut,
+}
+
+/// Loader used to rebuild a prefab from its source file.
+#[cfg(any(feature = "gltf", feature = "ply"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrefabKind {
+    #[cfg(feature = "gltf")]
+    Gltf,
+    #[cfg(feature = "ply")]
+    Ply,
+}
+
+/// An asset that must be rebuilt when its source file changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReloadTarget {
+    Image(ImageHandle, ImageKind),
+    #[cfg(any(feature = "gltf", feature = "ply"))]
+    Prefab(PrefabHandle, PrefabKind),
+}
+
+/// An asset that was rebuilt from its edited source file.
+///
+/// Reported by [`AssetServer::drain_reloaded`](crate::AssetServer::drain_reloaded)
+/// once the new data is in place under the existing handle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssetReloaded {
+    /// An image loaded through `load_texture`, `load_hdr_texture` or
+    /// `load_lut_texture`.
+    Image(ImageHandle),
+    /// A prefab loaded through `load_gltf` or `load_ply`, with its source.
+    #[cfg(any(feature = "gltf", feature = "ply"))]
+    Prefab(PrefabHandle, String),
+}
+
+/// Shared hot-reload state (one per [`AssetServer`](crate::AssetServer) and
+/// its clones).
+pub(crate) struct HotReload {
+    /// Tracked source files and the assets built from each of them.
+    targets: Mutex<FxHashMap<PathBuf, Vec<ReloadTarget>>>,
+    changed_tx: Sender<PathBuf>,
+    changed_rx: Receiver<PathBuf>,
+    reloaded_tx: Sender<AssetReloaded>,
+    reloaded_rx: Receiver<AssetReloaded>,
+    /// File system watcher, present once [`start`](Self::start) succeeded.
+    #[cfg(not(target_arch = "wasm32"))]
+    watcher: Mutex<Option<Watcher>>,
+}
+
+impl HotReload {
+    pub(crate) fn new() -> Self {
+        let (changed_tx, changed_rx) = unbounded();
+        let (reloaded_tx, reloaded_rx) = unbounded();
+        Self {
+            targets: Mutex::new(FxHashMap::default()),
+            changed_tx,
+            changed_rx,
+            reloaded_tx,
+            reloaded_rx,
+            #[cfg(not(target_arch = "wasm32"))]
+            watcher: Mutex::new(None),
+        }
+    }
+
+    /// Records that `target` was built from `uri`.
+    ///
+    /// URIs that do not name an existing local file (HTTP URLs, embedded
+    /// data) are ignored. Paths are canonicalized so they match the
+    /// absolute paths reported by the watcher.
+    pub(crate) fn track(&self, uri: &str, target: ReloadTarget) {
+        let path = Path::new(uri);
+        if !path.is_file() {
+            return;
+        }
+        let path = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(watcher) = self.watcher.lock().as_mut() {
+            watcher.watch_parent(&path);
+        }
+
+        let mut targets = self.targets.lock();
+        let entry = targets.entry(path).or_default();
+        if !entry.contains(&target) {
+            entry.push(target);
+        }
+    }
+
+    /// Queues the debounced `settled` paths that belong to tracked assets
+    /// for [`drain_changes`](Self::drain_changes).
+    #[cfg(not(target_arch = "wasm32"))]
+    fn report_settled(&self, settled: Vec<PathBuf>) {
+        let targets = self.targets.lock();
+        for path in settled.into_iter().filter(|p| targets.contains_key(p)) {
+            log::info!("Hot reload: {} changed", path.display());
+            let _ = self.changed_tx.send(path);
+        }
+    }
+
+    /// Returns the reload targets for every file reported as changed since
+    /// the last call, paired with the file path.
+    pub(crate) fn drain_changes(&self) -> Vec<(PathBuf, ReloadTarget)> {
+        let mut changes = Vec::new();
+        let targets = self.targets.lock();
+        while let Ok(path) = self.changed_rx.try_recv() {
+            if let Some(list) = targets.get(&path) {
+                changes.extend(list.iter().map(|target| (path.clone(), *target)));
+            }
+        }
+        changes
+    }
+
+    /// Records a finished reload for [`drain_reloaded`](Self::drain_reloaded).
+    pub(crate) fn notify_reloaded(&self, event: AssetReloaded) {
+        let _ = self.reloaded_tx.send(event);
+    }
+
+    /// Returns the reloads applied since the last call.
+    pub(crate) fn drain_reloaded(&self) -> Vec<AssetReloaded> {
+        self.reloaded_rx.try_iter().collect()
+    }
+
+    /// Starts the file system watcher unless it is already running.
+    ///
+    /// Raw events are forwarded to a debounce thread that only holds a weak
+    /// reference; it exits once the last [`AssetServer`](crate::AssetServer)
+    /// clone, and with it the watcher, is dropped.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn start(self: &Arc<Self>) {
+        let mut slot = self.watcher.lock();
+        if slot.is_some() {
+            return;
+        }
+
+        let (event_tx, event_rx) = unbounded::<PathBuf>();
+        let inner =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+                Ok(event) if event.kind.is_modify() || event.kind.is_create() => {
+                    for path in event.paths {
+                        let _ = event_tx.send(path);
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => log::warn!("Hot reload: watch error: {e}"),
+            });
+        let mut watcher = match inner {
+            Ok(inner) => Watcher {
+                inner,
+                directories: FxHashSet::default(),
+            },
+            Err(e) => {
+                log::error!("Failed to start hot-reload watcher: {e}");
+                return;
+            }
+        };
+
+        for path in self.targets.lock().keys() {
+            watcher.watch_parent(path);
+        }
+
+        let weak = Arc::downgrade(self);
+        let spawned = std::thread::Builder::new()
+            .name("myth-hot-reload".into())
+            .spawn(move || {
+                let mut debouncer = Debouncer::default();
+                loop {
+                    match event_rx.recv_timeout(POLL_INTERVAL) {
+                        Ok(path) => debouncer.record(path, Instant::now()),
+                        Err(flume::RecvTimeoutError::Timeout) => {}
+                        Err(flume::RecvTimeoutError::Disconnected) => break,
+                    }
+
+                    let settled = debouncer.settled(Instant::now());
+                    if settled.is_empty() {
+                        continue;
+                    }
+                    let Some(state) = weak.upgrade() else { break };
+                    state.report_settled(settled);
+                }
+            });
+
+        match spawned {
+            Ok(_) => *slot = Some(watcher),
+            Err(e) => log::error!("Failed to start hot-reload watcher: {e}"),
+        }
+    }
+}
+
+/// A [`notify`] watcher plus the directories it observes.
+///
+/// Directories rather than files are watched, so editors that save by
+/// writing a temporary file and renaming it over the original keep being
+/// observed.
+#[cfg(not(target_arch = "wasm32"))]
+struct Watcher {
+    inner: notify::RecommendedWatcher,
+    directories: FxHashSet<PathBuf>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Watcher {
+    fn watch_parent(&mut self, path: &Path) {
+        use notify::Watcher as _;
+
+        let Some(dir) = path.parent() else {
+            return;
+        };
+        if self.directories.contains(dir) {
+            return;
+        }
+        match self.inner.watch(dir, notify::RecursiveMode::NonRecursive) {
+            Ok(()) => {
+                self.directories.insert(dir.to_path_buf());
+            }
+            Err(e) => log::warn!("Hot reload: cannot watch {}: {e}", dir.display()),
+        }
+    }
+}
+
+/// Collapses bursts of file events into one change per path.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+struct Debouncer {
+    /// Time of the latest unreported event for each path.
+    pending: FxHashMap<PathBuf, Instant>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Debouncer {
+    fn record(&mut self, path: PathBuf, now: Instant) {
+        self.pending.insert(path, now);
+    }
+
+    /// Returns the paths that have been quiet for [`DEBOUNCE`].
+    fn settled(&mut self, now: Instant) -> Vec<PathBuf> {
+        let mut settled = Vec::new();
+        self.pending.retain(|path, last| {
+            let quiet = now.duration_since(*last) >= DEBOUNCE;
+            if quiet {
+                settled.push(path.clone());
+            }
+            !quiet
+        });
+        settled
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Creates an empty directory unique to this test process and call, so
+    /// parallel and repeated runs never share files.
+    fn unique_temp_dir(name: &str) -> PathBuf {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join(format!(
+            "myth_hot_reload_{name}_{}_{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::canonicalize(dir).unwrap()
+    }
+
+    #[test]
+    fn reports_change_once_after_debounce() {
+        let path = PathBuf::from("a.png");
+        let start = Instant::now();
+        let mut debouncer = Debouncer::default();
+
+        debouncer.record(path.clone(), start);
+        assert!(debouncer.settled(start).is_empty());
+        assert!(debouncer.settled(start + DEBOUNCE / 2).is_empty());
+        assert_eq!(debouncer.settled(start + DEBOUNCE), vec![path]);
+        assert!(debouncer.settled(start + DEBOUNCE * 2).is_empty());
+    }
+
+    #[test]
+    fn rapid_edits_restart_the_debounce() {
+        let path = PathBuf::from("a.png");
+        let start = Instant::now();
+        let mut debouncer = Debouncer::default();
+
+        debouncer.record(path.clone(), start);
+        debouncer.record(path.clone(), start + DEBOUNCE / 2);
+        assert!(debouncer.settled(start + DEBOUNCE).is_empty());
+        assert_eq!(debouncer.settled(start + DEBOUNCE * 2), vec![path]);
+    }
+
+    #[test]
+    fn only_local_files_are_tracked() {
+        let dir = unique_temp_dir("track");
+        let path = dir.join("a.png");
+        std::fs::write(&path, b"v1").unwrap();
+        let state = HotReload::new();
+        let handle = ImageHandle::default();
+
+        state.track(
+            "https://example.com/a.png",
+            ReloadTarget::Image(handle, ImageKind::Ldr),
+        );
+        state.track(
+            path.to_str().unwrap(),
+            ReloadTarget::Image(handle, ImageKind::Ldr),
+        );
+        state.track(
+            path.to_str().unwrap(),
+            ReloadTarget::Image(handle, ImageKind::Ldr),
+        );
+
+        state.changed_tx.send(path.clone()).unwrap();
+        let changes = state.drain_changes();
+        assert_eq!(
+            changes,
+            vec![(path.clone(), ReloadTarget::Image(handle, ImageKind::Ldr))]
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reloaded_events_drain_once() {
+        let state = HotReload::new();
+        let handle = ImageHandle::default();
+
+        assert!(state.drain_reloaded().is_empty());
+        state.notify_reloaded(AssetReloaded::Image(handle));
+        assert_eq!(state.drain_reloaded(), vec![AssetReloaded::Image(handle)]);
+        assert!(state.drain_reloaded().is_empty());
+    }
+
+    #[test]
+    fn debounced_edit_reloads_texture_in_place() {
+        use crate::AssetServer;
+        use myth_resources::image::ColorSpace;
+
+        let write_png = |path: &Path, rgba: [u8; 4]| {
+            image::RgbaImage::from_pixel(1, 1, image::Rgba(rgba))
+                .save_with_format(path, image::ImageFormat::Png)
+                .unwrap();
+        };
+        let dir = unique_temp_dir("png");
+        let path = dir.join("swatch.png");
+        write_png(&path, [255, 0, 0, 255]);
+
+        let assets = AssetServer::new();
+        let texture = assets
+            .load_texture_blocking(path.as_path(), ColorSpace::Srgb, false)
+            .unwrap();
+        let image = assets.textures.get(texture).unwrap().image;
+
+        // Feed the debouncer a burst of synthetic events instead of waiting
+        // on the file system watcher.
+        write_png(&path, [0, 0, 255, 255]);
+        let start = Instant::now();
+        let mut debouncer = Debouncer::default();
+        debouncer.record(path.clone(), start);
+        debouncer.record(path.clone(), start + POLL_INTERVAL);
+        assert!(debouncer.settled(start + DEBOUNCE).is_empty());
+        assets
+            .hot_reload
+            .report_settled(debouncer.settled(start + POLL_INTERVAL + DEBOUNCE));
+
+        // The rebuild itself still decodes on the asset runtime.
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut reloaded = Vec::new();
+        while reloaded.is_empty() && Instant::now() < deadline {
+            assets.process_loading_events();
+            reloaded = assets.drain_reloaded();
+            std::thread::yield_now();
+        }
+        assert_eq!(reloaded, vec![AssetReloaded::Image(image)]);
+
+        let pixels = assets.images.get(image).unwrap().data.clone().unwrap();
+        assert_eq!(&pixels[..4], &[0, 0, 255, 255]);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -3,10 +3,12 @@
 //! Asset loading and management for the Myth engine.
 //!
 //! Provides [`AssetServer`] for centralised resource storage, loaders for
-//! various formats (glTF, textures, HDR), and scene prefab/instantiation
+//! various formats (glTF, PLY, textures, HDR), and scene prefab/instantiation
 //! helpers.
 
 pub mod handle;
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
 pub mod io;
 pub mod loaders;
 pub mod manager;
@@ -23,11 +25,15 @@ pub use myth_resources::{
 pub use server::AssetServer;
 
 pub use handle::{AssetTracker, StrongHandle, TrackedAsset, WeakHandle};
+#[cfg(feature = "hot-reload")]
+pub use hot_reload::AssetReloaded;
 pub use io::{AssetReader, AssetReaderVariant, AssetSource};
 #[cfg(feature = "gltf")]
 pub use loaders::GltfLoader;
+#[cfg(feature = "ply")]
+pub use loaders::PlyLoader;
 pub use manager::{SceneHandle, SceneManager};
-pub use myth_scene::GeometryQuery;
+pub use myth_scene::{AssetIdResolver, GeometryQuery};
 pub use prefab::{Prefab, PrefabNode, PrefabSkeleton, SharedPrefab};
 pub use resolve::{ResolveGeometry, ResolveMaterial};
 pub use scene_ext::SceneExt;
@@ -163,12 +169,13 @@ pub fn load_cube_texture_from_files(
     Ok((image, TextureSampler::default(), false))
 }
 
-/// Loads a 3D LUT texture from a .cube file.
+/// Loads a 3D LUT texture from a `.cube`, `.bin` or strip image file.
 /// Returns `(Image, TextureSampler, generate_mipmaps)`.
 pub fn load_lut_texture_from_file(path: impl AsRef<Path>) -> Result<(Image, TextureSampler, bool)> {
     let bytes = std::fs::read(&path)?;
+    let filename = path.as_ref().to_string_lossy();
 
-    let image = server::AssetServer::decode_cube_cpu(&bytes)?;
+    let image = server::AssetServer::decode_lut_cpu(&bytes, &filename)?;
 
     let sampler = TextureSampler {
         address_mode_u: wgpu::AddressMode::ClampToEdge,
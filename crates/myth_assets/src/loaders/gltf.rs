@@ -16,11 +16,14 @@ use myth_resources::buffer::BufferRef;
 use myth_resources::geometry::{Attribute, Geometry};
 use myth_resources::image::{ColorSpace, Image, ImageDimension, PixelFormat};
 use myth_resources::material::AlphaMode;
+use myth_resources::mesh::Submesh;
 use myth_resources::texture::Texture;
-use myth_resources::{GeometryHandle, ImageHandle, MaterialHandle, TextureHandle};
+use myth_resources::{ImageHandle, MaterialHandle, TextureHandle};
 use myth_resources::{
-    Material, PhysicalFeatures, PhysicalMaterial, TextureSampler, TextureSlot, TextureTransform,
+    Material, PhysicalFeatures, PhysicalMaterial, PointsMaterial, TextureSampler, TextureSlot,
+    TextureTransform,
 };
+use myth_scene::{Light, LightUnits};
 use serde_json::Value;
 use smallvec::SmallVec;
 use std::borrow::Cow;
@@ -242,6 +245,7 @@ fn patch_json_value(root: &mut Value) -> bool {
 /// - A meshopt-compressed buffer view is found but the `gltf-meshopt` feature is
 ///   disabled (graceful degradation).
 /// - A meshopt-compressed buffer view references an out-of-range source buffer.
+#[cfg_attr(feature = "gltf-meshopt", allow(clippy::unnecessary_wraps))]
 fn build_logical_buffers(gltf: &gltf::Gltf, raw_buffers: &[Vec<u8>]) -> Result<Vec<Vec<u8>>> {
     #[allow(unused_mut)] // mut is only needed when gltf-meshopt feature is enabled
     let mut logical = raw_buffers.to_vec();
@@ -746,6 +750,8 @@ pub struct GltfLoader {
     created_textures: HashMap<TextureCacheKey, TextureHandle>,
     material_map: Vec<MaterialHandle>,
     default_material: Option<MaterialHandle>,
+    /// Point-sprite materials for `POINTS` primitives, keyed by glTF material index.
+    points_material_map: HashMap<Option<usize>, MaterialHandle>,
     extensions: HashMap<String, Box<dyn GltfExtensionParser + Send>>,
 
     prefab_nodes: Vec<PrefabNode>,
@@ -763,6 +769,7 @@ impl GltfLoader {
             material_map: Vec::new(),
             extensions: HashMap::new(),
             default_material: None,
+            points_material_map: HashMap::new(),
             prefab_nodes: Vec::with_capacity(gltf.nodes().count()),
             prefab_skeletons: Vec::new(),
         };
@@ -787,6 +794,7 @@ impl GltfLoader {
             "KHR_mesh_quantization".to_string(),
             "EXT_meshopt_compression".to_string(),
             "EXT_texture_webp".to_string(),
+            "KHR_lights_punctual".to_string(),
         ]);
 
         let require_not_supported: Vec<_> = gltf
@@ -924,6 +932,26 @@ impl GltfLoader {
         }
     }
 
+    /// Returns a 1-pixel [`PointsMaterial`] carrying the base color and
+    /// blend mode of `material`, so `POINTS` primitives render as sprites
+    /// whose size can be adjusted after loading.
+    fn get_points_material(&mut self, material: &gltf::Material) -> MaterialHandle {
+        let key = material.index();
+        if let Some(mat) = self.points_material_map.get(&key) {
+            return *mat;
+        }
+
+        let base_color = Vec4::from_array(material.pbr_metallic_roughness().base_color_factor());
+        let points = PointsMaterial::new(base_color).with_size(1.0);
+        if material.alpha_mode() == gltf::material::AlphaMode::Blend {
+            points.set_alpha_mode(AlphaMode::Blend);
+        }
+
+        let mat = self.assets.materials.add(Material::from(points));
+        self.points_material_map.insert(key, mat);
+        mat
+    }
+
     /// Load buffers asynchronously
     async fn load_buffers_async(
         gltf: &gltf::Gltf,
@@ -1461,9 +1489,31 @@ impl GltfLoader {
         prefab_node.transform.rotation = Quat::from_array(r);
         prefab_node.transform.scale = Vec3::from_array(s);
 
+        prefab_node.light = node.light().map(|light| Self::create_light(&light));
+
         prefab_node
     }
 
+    /// Converts a `KHR_lights_punctual` light. Its intensities are already
+    /// photometric (lux / candela), so they are kept as-is.
+    fn create_light(light: &gltf::khr_lights_punctual::Light) -> Light {
+        use gltf::khr_lights_punctual::Kind;
+
+        let color = Vec3::from_array(light.color());
+        let intensity = light.intensity();
+        // glTF leaves range undefined for an unlimited light; 0 disables the cutoff.
+        let range = light.range().unwrap_or(0.0);
+        let light = match light.kind() {
+            Kind::Directional => Light::new_directional(color, intensity),
+            Kind::Point => Light::new_point(color, intensity, range),
+            Kind::Spot {
+                inner_cone_angle,
+                outer_cone_angle,
+            } => Light::new_spot(color, intensity, range, inner_cone_angle, outer_cone_angle),
+        };
+        light.with_units(LightUnits::PhysicalUnits)
+    }
+
     fn load_skins(&mut self, gltf: &gltf::Gltf, buffers: &[Vec<u8>]) {
         for skin in gltf.skins() {
             let name = skin.name().unwrap_or("Skeleton").to_string();
@@ -1521,27 +1571,40 @@ impl GltfLoader {
         }
     }
 
+    fn primitive_material(&mut self, primitive: &gltf::Primitive) -> MaterialHandle {
+        if primitive.mode() == gltf::mesh::Mode::Points {
+            self.get_points_material(&primitive.material())
+        } else if let Some(idx) = primitive.material().index() {
+            self.material_map[idx]
+        } else {
+            self.get_default_material()
+        }
+    }
+
     fn build_engine_mesh(
         &mut self,
         primitive: &gltf::Primitive,
         buffers: &[Vec<u8>],
     ) -> myth_resources::mesh::Mesh {
-        let geo_handle = self.load_primitive_geometry(primitive, buffers);
+        let geometry = self.build_primitive_geometry(primitive, buffers);
+        self.engine_mesh_from_geometry(primitive, geometry)
+    }
 
-        let mat_idx = primitive.material().index();
-        let mat_handle = if let Some(idx) = mat_idx {
-            self.material_map[idx]
-        } else {
-            self.get_default_material()
-        };
+    fn engine_mesh_from_geometry(
+        &mut self,
+        primitive: &gltf::Primitive,
+        geometry: Geometry,
+    ) -> myth_resources::mesh::Mesh {
+        let morph_counts = geometry
+            .has_morph_targets()
+            .then(|| (geometry.morph_target_count(), geometry.morph_vertex_count()));
+        let geo_handle = self.assets.geometries.add(geometry);
+        let mat_handle = self.primitive_material(primitive);
 
         let mut engine_mesh = myth_resources::mesh::Mesh::new(geo_handle, mat_handle);
 
-        if let Some(geometry) = self.assets.geometries.get(geo_handle)
-            && geometry.has_morph_targets()
-        {
-            engine_mesh
-                .init_morph_targets(geometry.morph_target_count(), geometry.morph_vertex_count());
+        if let Some((target_count, vertex_count)) = morph_counts {
+            engine_mesh.init_morph_targets(target_count, vertex_count);
         }
 
         engine_mesh
@@ -1573,11 +1636,32 @@ impl GltfLoader {
                     self.prefab_nodes[node_idx].mesh = Some(engine_mesh);
                 }
                 _ => {
+                    // Primitives sharing a vertex layout become submeshes of a
+                    // single mesh; anything else is split into child nodes.
+                    let geometries: Vec<Geometry> = primitives
+                        .iter()
+                        .map(|p| self.build_primitive_geometry(p, buffers))
+                        .collect();
+                    if let Some((merged, ranges)) = Geometry::merge(&geometries) {
+                        let submeshes = primitives
+                            .iter()
+                            .zip(ranges)
+                            .map(|(p, range)| Submesh::new(range, self.primitive_material(p)))
+                            .collect::<Vec<_>>();
+                        let geo_handle = self.assets.geometries.add(merged);
+                        let engine_mesh =
+                            myth_resources::mesh::Mesh::new(geo_handle, submeshes[0].material)
+                                .with_submeshes(submeshes);
+                        self.prefab_nodes[node_idx].mesh = Some(engine_mesh);
+                        return;
+                    }
+
                     let base_idx = self.prefab_nodes.len();
                     let parent_name = self.prefab_nodes[node_idx].name.clone();
 
-                    for (i, primitive) in primitives.iter().enumerate() {
-                        let engine_mesh = self.build_engine_mesh(primitive, buffers);
+                    for (i, (primitive, geometry)) in primitives.iter().zip(geometries).enumerate()
+                    {
+                        let engine_mesh = self.engine_mesh_from_geometry(primitive, geometry);
 
                         let mut sub_node = PrefabNode::new();
                         sub_node.name = Some(format!(
@@ -1678,20 +1762,20 @@ impl GltfLoader {
     /// **Classic path** (f32 鈫?GPU): For standard `Float32` accessors, the `gltf::Reader`
     /// is used to decode all attributes.
     #[allow(clippy::too_many_lines)]
-    fn load_primitive_geometry(
+    fn build_primitive_geometry(
         &mut self,
         primitive: &gltf::Primitive,
         buffers: &[Vec<u8>],
-    ) -> GeometryHandle {
+    ) -> Geometry {
         let mut geometry = Geometry::new();
 
         let Some(pos_accessor) = primitive.get(&gltf::Semantic::Positions) else {
-            return self.assets.geometries.add(geometry);
+            return geometry;
         };
 
         let vertex_count = pos_accessor.count();
         if vertex_count == 0 {
-            return self.assets.geometries.add(geometry);
+            return geometry;
         }
 
         let quantized_positions = is_quantized(&pos_accessor);
@@ -1758,6 +1842,24 @@ impl GltfLoader {
             gltf::mesh::Mode::TriangleStrip => PrimitiveTopology::TriangleStrip,
         };
 
+        // glTF asks clients to generate tangents for normal-mapped
+        // primitives that don't ship them.
+        if geometry.get_attribute("tangent").is_none()
+            && let Some(normal_texture) = primitive.material().normal_texture()
+        {
+            let uv_name = match normal_texture.tex_coord() {
+                0 => "uv".to_string(),
+                i => format!("uv{i}"),
+            };
+            if !geometry.compute_tangents_for(&uv_name) {
+                log::warn!(
+                    "Normal-mapped primitive has no tangents and they could not be generated \
+                     (needs float positions, normals and '{uv_name}'); falling back to \
+                     screen-space derivatives"
+                );
+            }
+        }
+
         geometry.build_morph_storage_buffers();
 
         // Only compute bounding volume from positions if we used the classic f32 path.
@@ -1765,7 +1867,7 @@ impl GltfLoader {
             geometry.compute_bounding_volume();
         }
 
-        self.assets.geometries.add(geometry)
+        geometry
     }
 
     /// Loads normal, tangent, UV, and color attributes for a primitive.
@@ -8,6 +8,11 @@ pub mod ply;
 #[cfg(feature = "3dgs")]
 pub use ply::load_gaussian_ply;
 
+#[cfg(feature = "ply")]
+pub mod ply_mesh;
+#[cfg(feature = "ply")]
+pub use ply_mesh::{PlyLoader, parse_ply_geometry};
+
 #[cfg(feature = "gaussian-npz")]
 pub mod npz;
 #[cfg(feature = "gaussian-npz")]
@@ -0,0 +1,765 @@
+//! PLY loader for meshes and plain point clouds.
+//!
+//! Parses ASCII and binary little-endian PLY files, as produced by 3D
+//! scanners, photogrammetry tools and scientific software, into a
+//! [`Geometry`]. 3D Gaussian Splatting exports are handled separately by
+//! `load_gaussian_ply` (feature `3dgs`).
+//!
+//! # Supported Properties
+//!
+//! | PLY element / property           | Interpretation |
+//! |-----------------------------------|----------------|
+//! | `vertex`: `x`, `y`, `z`           | Position (required) |
+//! | `vertex`: `nx`, `ny`, `nz`        | Normal |
+//! | `vertex`: `red`, `green`, `blue`, `alpha` | Vertex color (integers are normalized) |
+//! | `face`: `vertex_indices` list     | Polygons, fan-triangulated into indices |
+//!
+//! Other elements and properties are skipped. Files without faces produce a
+//! [`PrimitiveTopology::PointList`](wgpu::PrimitiveTopology::PointList) geometry.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use glam::{Vec3, Vec4};
+use myth_core::{AssetError, Error, Result};
+use myth_resources::geometry::{Attribute, Geometry};
+use myth_resources::material::{Material, PhongMaterial, PointsMaterial};
+use myth_resources::mesh::Mesh;
+use wgpu::{PrimitiveTopology, VertexFormat};
+
+use crate::io::{AssetReaderVariant, AssetSource};
+use crate::prefab::{Prefab, PrefabNode};
+use crate::server::AssetServer;
+
+fn format_error(msg: impl Into<String>) -> Error {
+    Error::Asset(AssetError::Format(msg.into()))
+}
+
+// ─── PLY Header Parsing ────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PlyEncoding {
+    Ascii,
+    BinaryLittleEndian,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScalarType {
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    F32,
+    F64,
+}
+
+impl ScalarType {
+    fn parse(name: &str) -> Result<Self> {
+        Ok(match name {
+            "char" | "int8" => Self::I8,
+            "uchar" | "uint8" => Self::U8,
+            "short" | "int16" => Self::I16,
+            "ushort" | "uint16" => Self::U16,
+            "int" | "int32" => Self::I32,
+            "uint" | "uint32" => Self::U32,
+            "float" | "float32" => Self::F32,
+            "double" | "float64" => Self::F64,
+            _ => return Err(format_error(format!("unknown PLY property type '{name}'"))),
+        })
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 => 2,
+            Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::F64 => 8,
+        }
+    }
+
+    /// Scale that maps the full integer range to `[0, 1]` (1 for floats).
+    fn normalization(self) -> f64 {
+        match self {
+            Self::I8 => f64::from(i8::MAX),
+            Self::U8 => f64::from(u8::MAX),
+            Self::I16 => f64::from(i16::MAX),
+            Self::U16 => f64::from(u16::MAX),
+            Self::I32 => f64::from(i32::MAX),
+            Self::U32 => f64::from(u32::MAX),
+            Self::F32 | Self::F64 => 1.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum PropertyKind {
+    Scalar(ScalarType),
+    List { count: ScalarType, item: ScalarType },
+}
+
+#[derive(Debug)]
+struct PlyProperty {
+    name: String,
+    kind: PropertyKind,
+}
+
+#[derive(Debug)]
+struct PlyElement {
+    name: String,
+    count: usize,
+    properties: Vec<PlyProperty>,
+}
+
+impl PlyElement {
+    fn find(&self, names: &[&str]) -> Option<usize> {
+        self.properties
+            .iter()
+            .position(|p| names.contains(&p.name.as_str()))
+    }
+
+    /// Smallest number of body bytes one record of this element occupies.
+    fn min_record_size(&self, encoding: PlyEncoding) -> usize {
+        let size: usize = self
+            .properties
+            .iter()
+            .map(|p| match (encoding, p.kind) {
+                // At least one character and one separator per value.
+                (PlyEncoding::Ascii, _) => 2,
+                (_, PropertyKind::Scalar(ty)) => ty.size(),
+                (_, PropertyKind::List { count, .. }) => count.size(),
+            })
+            .sum();
+        size.max(1)
+    }
+}
+
+struct PlyHeader {
+    encoding: PlyEncoding,
+    elements: Vec<PlyElement>,
+    /// Byte offset of the element data following `end_header`.
+    body_offset: usize,
+}
+
+fn parse_header(bytes: &[u8]) -> Result<PlyHeader> {
+    let mut encoding = None;
+    let mut elements: Vec<PlyElement> = Vec::new();
+    let mut offset = 0;
+    let mut first = true;
+
+    loop {
+        let rest = &bytes[offset..];
+        let line_len = rest
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or_else(|| format_error("PLY header is missing 'end_header'"))?;
+        let line = std::str::from_utf8(&rest[..line_len])
+            .map_err(|_| format_error("PLY header is not valid ASCII"))?
+            .trim();
+        offset += line_len + 1;
+
+        if first {
+            if line != "ply" {
+                return Err(format_error("not a PLY file"));
+            }
+            first = false;
+            continue;
+        }
+
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        match parts.as_slice() {
+            ["end_header"] => break,
+            ["format", format, ..] => {
+                encoding = Some(match *format {
+                    "ascii" => PlyEncoding::Ascii,
+                    "binary_little_endian" => PlyEncoding::BinaryLittleEndian,
+                    _ => {
+                        return Err(format_error(format!(
+                            "unsupported PLY format '{format}' (expected ascii or binary_little_endian)"
+                        )));
+                    }
+                });
+            }
+            ["element", name, count] => {
+                let count = count
+                    .parse()
+                    .map_err(|_| format_error(format!("bad count for PLY element '{name}'")))?;
+                elements.push(PlyElement {
+                    name: (*name).to_string(),
+                    count,
+                    properties: Vec::new(),
+                });
+            }
+            ["property", "list", count, item, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| format_error("PLY property declared before any element"))?;
+                element.properties.push(PlyProperty {
+                    name: (*name).to_string(),
+                    kind: PropertyKind::List {
+                        count: ScalarType::parse(count)?,
+                        item: ScalarType::parse(item)?,
+                    },
+                });
+            }
+            ["property", ty, name] => {
+                let element = elements
+                    .last_mut()
+                    .ok_or_else(|| format_error("PLY property declared before any element"))?;
+                element.properties.push(PlyProperty {
+                    name: (*name).to_string(),
+                    kind: PropertyKind::Scalar(ScalarType::parse(ty)?),
+                });
+            }
+            // `comment`, `obj_info` and blank lines.
+            _ => {}
+        }
+    }
+
+    Ok(PlyHeader {
+        encoding: encoding.ok_or_else(|| format_error("PLY header is missing 'format'"))?,
+        elements,
+        body_offset: offset,
+    })
+}
+
+// ─── Element Data ──────────────────────────────────────────────────────────
+
+/// Sequential reader over the element data, in either encoding.
+enum PlyBody<'a> {
+    Ascii {
+        tokens: std::str::SplitAsciiWhitespace<'a>,
+        len: usize,
+    },
+    Binary {
+        data: &'a [u8],
+        pos: usize,
+    },
+}
+
+impl<'a> PlyBody<'a> {
+    fn new(encoding: PlyEncoding, data: &'a [u8]) -> Result<Self> {
+        Ok(match encoding {
+            PlyEncoding::Ascii => Self::Ascii {
+                tokens: std::str::from_utf8(data)
+                    .map_err(|_| format_error("ASCII PLY body is not valid UTF-8"))?
+                    .split_ascii_whitespace(),
+                len: data.len(),
+            },
+            PlyEncoding::BinaryLittleEndian => Self::Binary { data, pos: 0 },
+        })
+    }
+
+    /// Upper bound on the number of body bytes left to read.
+    fn remaining(&self) -> usize {
+        match self {
+            Self::Ascii { len, .. } => *len,
+            Self::Binary { data, pos } => data.len() - *pos,
+        }
+    }
+
+    fn read(&mut self, ty: ScalarType) -> Result<f64> {
+        match self {
+            Self::Ascii { tokens, .. } => {
+                let token = tokens
+                    .next()
+                    .ok_or_else(|| format_error("unexpected end of PLY data"))?;
+                token
+                    .parse()
+                    .map_err(|_| format_error(format!("bad PLY value '{token}'")))
+            }
+            Self::Binary { data, pos } => {
+                let size = ty.size();
+                let bytes = data
+                    .get(*pos..*pos + size)
+                    .ok_or_else(|| format_error("unexpected end of PLY data"))?;
+                *pos += size;
+                Ok(match ty {
+                    ScalarType::I8 => f64::from(i8::from_le_bytes([bytes[0]])),
+                    ScalarType::U8 => f64::from(bytes[0]),
+                    ScalarType::I16 => f64::from(i16::from_le_bytes([bytes[0], bytes[1]])),
+                    ScalarType::U16 => f64::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+                    ScalarType::I32 => f64::from(i32::from_le_bytes(bytes.try_into().unwrap())),
+                    ScalarType::U32 => f64::from(u32::from_le_bytes(bytes.try_into().unwrap())),
+                    ScalarType::F32 => f64::from(f32::from_le_bytes(bytes.try_into().unwrap())),
+                    ScalarType::F64 => f64::from_le_bytes(bytes.try_into().unwrap()),
+                })
+            }
+        }
+    }
+
+    fn read_count(&mut self, ty: ScalarType) -> Result<usize> {
+        let count = self.read(ty)?;
+        if count < 0.0 {
+            return Err(format_error("negative PLY list length"));
+        }
+        Ok(count as usize)
+    }
+
+    fn skip(&mut self, kind: PropertyKind) -> Result<()> {
+        match kind {
+            PropertyKind::Scalar(ty) => {
+                self.read(ty)?;
+            }
+            PropertyKind::List { count, item } => {
+                for _ in 0..self.read_count(count)? {
+                    self.read(item)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Indices of the recognised vertex properties.
+struct VertexLayout {
+    position: [usize; 3],
+    normal: Option<[usize; 3]>,
+    color: Option<[usize; 3]>,
+    alpha: Option<usize>,
+}
+
+impl VertexLayout {
+    fn new(element: &PlyElement) -> Result<Self> {
+        let find3 = |names: [&[&str]; 3]| -> Option<[usize; 3]> {
+            Some([
+                element.find(names[0])?,
+                element.find(names[1])?,
+                element.find(names[2])?,
+            ])
+        };
+
+        let position = find3([&["x"], &["y"], &["z"]])
+            .ok_or_else(|| format_error("PLY vertex element is missing x/y/z"))?;
+
+        // Positions and normals must be scalars; lists are only valid for faces.
+        for index in position {
+            if matches!(element.properties[index].kind, PropertyKind::List { .. }) {
+                return Err(format_error(
+                    "PLY vertex position must be a scalar property",
+                ));
+            }
+        }
+
+        Ok(Self {
+            position,
+            normal: find3([&["nx"], &["ny"], &["nz"]]),
+            color: find3([
+                &["red", "diffuse_red", "r"],
+                &["green", "diffuse_green", "g"],
+                &["blue", "diffuse_blue", "b"],
+            ]),
+            alpha: element.find(&["alpha", "diffuse_alpha", "a"]),
+        })
+    }
+}
+
+/// Parses a PLY file into a [`Geometry`].
+///
+/// Faces are fan-triangulated into a `u32` index buffer. Without faces, or
+/// when no face has three or more vertices, the geometry uses
+/// [`PrimitiveTopology::PointList`]. Meshes without normals get smooth
+/// vertex normals computed from the faces.
+///
+/// # Errors
+///
+/// Returns an error for malformed or truncated files, big-endian encoding,
+/// a vertex element without `x`/`y`/`z`, or negative or out-of-range face
+/// indices.
+pub fn parse_ply_geometry(bytes: &[u8]) -> Result<Geometry> {
+    let header = parse_header(bytes)?;
+    let mut body = PlyBody::new(header.encoding, &bytes[header.body_offset..])?;
+
+    let mut positions: Vec<Vec3> = Vec::new();
+    let mut normals: Vec<Vec3> = Vec::new();
+    let mut colors: Vec<[f32; 4]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut face: Vec<u32> = Vec::new();
+    let mut face_count = 0;
+    let vertex_count = header
+        .elements
+        .iter()
+        .find(|e| e.name == "vertex")
+        .map_or(0, |e| e.count);
+
+    let mut values = Vec::new();
+    for element in &header.elements {
+        match element.name.as_str() {
+            "vertex" => {
+                let layout = VertexLayout::new(element)?;
+                // The header count is untrusted; never reserve more records
+                // than the remaining bytes can hold.
+                positions.reserve(
+                    element
+                        .count
+                        .min(body.remaining() / element.min_record_size(header.encoding)),
+                );
+
+                for _ in 0..element.count {
+                    values.clear();
+                    for property in &element.properties {
+                        match property.kind {
+                            PropertyKind::Scalar(ty) => values.push(body.read(ty)?),
+                            PropertyKind::List { .. } => {
+                                body.skip(property.kind)?;
+                                values.push(0.0);
+                            }
+                        }
+                    }
+
+                    let vec3 = |idx: [usize; 3]| {
+                        Vec3::new(
+                            values[idx[0]] as f32,
+                            values[idx[1]] as f32,
+                            values[idx[2]] as f32,
+                        )
+                    };
+                    positions.push(vec3(layout.position));
+                    if let Some(normal) = layout.normal {
+                        normals.push(vec3(normal));
+                    }
+                    if let Some(color) = layout.color {
+                        let channel = |idx: usize| {
+                            let scale = match element.properties[idx].kind {
+                                PropertyKind::Scalar(ty) => ty.normalization(),
+                                PropertyKind::List { .. } => 1.0,
+                            };
+                            (values[idx] / scale) as f32
+                        };
+                        let alpha = layout.alpha.map_or(1.0, channel);
+                        colors.push([
+                            channel(color[0]),
+                            channel(color[1]),
+                            channel(color[2]),
+                            alpha,
+                        ]);
+                    }
+                }
+            }
+            "face" => {
+                face_count += element.count;
+                let list = element.find(&["vertex_indices", "vertex_index"]);
+
+                for _ in 0..element.count {
+                    for (i, property) in element.properties.iter().enumerate() {
+                        match (Some(i) == list, property.kind) {
+                            (true, PropertyKind::List { count, item }) => {
+                                let n = body.read_count(count)?;
+                                face.clear();
+                                for _ in 0..n {
+                                    let index = body.read(item)?;
+                                    if index < 0.0 || index >= vertex_count as f64 {
+                                        return Err(format_error(format!(
+                                            "PLY face index {index} is out of range \
+                                             ({vertex_count} vertices)"
+                                        )));
+                                    }
+                                    face.push(index as u32);
+                                }
+                                // Fan triangulation: (0, k, k + 1).
+                                for k in 1..n.saturating_sub(1) {
+                                    indices.extend([face[0], face[k], face[k + 1]]);
+                                }
+                            }
+                            _ => body.skip(property.kind)?,
+                        }
+                    }
+                }
+            }
+            _ => {
+                for _ in 0..element.count {
+                    for property in &element.properties {
+                        body.skip(property.kind)?;
+                    }
+                }
+            }
+        }
+    }
+
+    if positions.is_empty() {
+        return Err(format_error("PLY file contains no vertices"));
+    }
+    if face_count > 0 && indices.is_empty() {
+        log::warn!("PLY faces have fewer than three vertices each; loading as points");
+    }
+
+    let mut geometry = Geometry::new();
+    geometry.set_attribute(
+        "position",
+        Attribute::new_planar(&positions, VertexFormat::Float32x3),
+    );
+    if !colors.is_empty() {
+        geometry.set_attribute(
+            "color",
+            Attribute::new_planar(&colors, VertexFormat::Float32x4),
+        );
+    }
+
+    if !indices.is_empty() {
+        geometry.set_indices_u32(&indices);
+        if normals.is_empty() {
+            geometry.compute_vertex_normals();
+        }
+    } else {
+        geometry.topology = PrimitiveTopology::PointList;
+    }
+    if !normals.is_empty() {
+        geometry.set_attribute(
+            "normal",
+            Attribute::new_planar(&normals, VertexFormat::Float32x3),
+        );
+    }
+
+    geometry.compute_bounding_volume();
+    Ok(geometry)
+}
+
+// ─── PLY Loader ────────────────────────────────────────────────────────────
+
+/// PLY mesh / point-cloud loader.
+///
+/// Produces a [`Prefab`] with a single mesh node: a [`PhongMaterial`] for
+/// files with faces, or a [`PointsMaterial`] for point-only data. Vertex
+/// colors, when present, tint either material.
+pub struct PlyLoader;
+
+impl PlyLoader {
+    /// Synchronous load (creates runtime internally) - Native only
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(
+        source: impl AssetSource,
+        assets: impl Into<Arc<AssetServer>>,
+    ) -> Result<Arc<Prefab>> {
+        crate::server::get_asset_runtime().block_on(Self::load_async(source, assets))
+    }
+
+    /// Load asynchronously from a source URI (File path or HTTP URL)
+    pub async fn load_async(
+        source: impl AssetSource,
+        assets: impl Into<Arc<AssetServer>>,
+    ) -> Result<Arc<Prefab>> {
+        let reader = AssetReaderVariant::new(&source)?;
+        let filename = source.filename().unwrap_or(Cow::Borrowed("unknown"));
+
+        let bytes = reader.read_bytes(&filename).await.map_err(|e| {
+            format_error(format!("Failed to read PLY file '{}': {e}", source.uri()))
+        })?;
+
+        let name = std::path::Path::new(filename.as_ref())
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned());
+        Self::load_from_bytes(&bytes, name, assets)
+    }
+
+    /// Load from in-memory PLY bytes.
+    ///
+    /// # Errors
+    ///
+    /// See [`parse_ply_geometry`].
+    pub fn load_from_bytes(
+        bytes: &[u8],
+        name: Option<String>,
+        assets: impl Into<Arc<AssetServer>>,
+    ) -> Result<Arc<Prefab>> {
+        let assets = assets.into();
+        let geometry = parse_ply_geometry(bytes)?;
+
+        let material = if geometry.topology == PrimitiveTopology::PointList {
+            Material::from(PointsMaterial::new(Vec4::ONE))
+        } else {
+            Material::from(PhongMaterial::new(Vec4::ONE))
+        };
+
+        let mesh = Mesh::new(
+            assets.geometries.add(geometry),
+            assets.materials.add(material),
+        );
+
+        let mut node = PrefabNode::new();
+        node.name = name;
+        node.mesh = Some(mesh);
+
+        let mut prefab = Prefab::new();
+        prefab.nodes.push(node);
+        prefab.root_indices.push(0);
+        Ok(Arc::new(prefab))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::*;
+    use myth_resources::IndexFormat;
+
+    /// Writes a binary little-endian PLY with `uchar` colors and an optional
+    /// quad face list.
+    fn write_binary_ply(positions: &[Vec3], colors: &[[u8; 3]], quads: &[[i32; 4]]) -> Vec<u8> {
+        let mut out = format!(
+            "ply\nformat binary_little_endian 1.0\ncomment test\n\
+             element vertex {}\n\
+             property float x\nproperty float y\nproperty float z\n\
+             property uchar red\nproperty uchar green\nproperty uchar blue\n",
+            positions.len()
+        );
+        if !quads.is_empty() {
+            writeln!(out, "element face {}", quads.len()).unwrap();
+            out.push_str("property list uchar int vertex_indices\n");
+        }
+        out.push_str("end_header\n");
+
+        let mut bytes = out.into_bytes();
+        for (p, c) in positions.iter().zip(colors) {
+            for v in p.to_array() {
+                bytes.extend_from_slice(&v.to_le_bytes());
+            }
+            bytes.extend_from_slice(c);
+        }
+        for quad in quads {
+            bytes.push(4);
+            for i in quad {
+                bytes.extend_from_slice(&i.to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    fn read_indices(geometry: &Geometry) -> Vec<u32> {
+        let index = geometry.index_attribute().expect("index buffer");
+        assert_eq!(index.format, IndexFormat::Uint32);
+        bytemuck::cast_slice(index.data.as_ref().unwrap()).to_vec()
+    }
+
+    #[test]
+    fn test_binary_round_trip() {
+        let positions = [
+            Vec3::new(0.0, 0.0, 0.0),
+            Vec3::new(1.0, 0.0, 0.0),
+            Vec3::new(1.0, 1.0, 0.0),
+            Vec3::new(0.0, 1.0, 0.0),
+        ];
+        let colors = [[255, 0, 0], [0, 255, 0], [0, 0, 255], [255, 255, 255]];
+        let bytes = write_binary_ply(&positions, &colors, &[[0, 1, 2, 3]]);
+
+        let geometry = parse_ply_geometry(&bytes).unwrap();
+        assert_eq!(geometry.topology, PrimitiveTopology::TriangleList);
+
+        let pos = geometry.get_attribute("position").unwrap();
+        assert_eq!(pos.count, 4);
+        for (i, expected) in positions.iter().enumerate() {
+            assert!(
+                pos.read_vec3(i as u32)
+                    .unwrap()
+                    .abs_diff_eq(*expected, 1e-6)
+            );
+        }
+
+        let color = geometry.get_attribute("color").unwrap();
+        assert!(
+            color
+                .read_vec4(1)
+                .unwrap()
+                .abs_diff_eq(Vec4::new(0.0, 1.0, 0.0, 1.0), 1e-6)
+        );
+
+        assert_eq!(read_indices(&geometry), vec![0, 1, 2, 0, 2, 3]);
+        assert!(geometry.get_attribute("normal").is_some());
+    }
+
+    #[test]
+    fn test_binary_points_without_faces() {
+        let positions = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.5, 0.0)];
+        let bytes = write_binary_ply(&positions, &[[10, 20, 30], [0, 0, 0]], &[]);
+
+        let geometry = parse_ply_geometry(&bytes).unwrap();
+        assert_eq!(geometry.topology, PrimitiveTopology::PointList);
+        assert!(geometry.index_attribute().is_none());
+        assert!(
+            geometry
+                .get_attribute("position")
+                .unwrap()
+                .read_vec3(1)
+                .unwrap()
+                .abs_diff_eq(positions[1], 1e-6)
+        );
+    }
+
+    #[test]
+    fn test_ascii_with_normals_and_extra_element() {
+        let text = "ply\nformat ascii 1.0\n\
+                    element vertex 3\n\
+                    property double x\nproperty double y\nproperty double z\n\
+                    property float nx\nproperty float ny\nproperty float nz\n\
+                    element face 1\nproperty uchar flags\nproperty list uchar uint vertex_index\n\
+                    element edge 1\nproperty int vertex1\nproperty int vertex2\n\
+                    end_header\n\
+                    0 0 0 0 0 1\n1 0 0 0 0 1\n0 1 0 0 0 1\n\
+                    7 3 0 1 2\n\
+                    0 1\n";
+
+        let geometry = parse_ply_geometry(text.as_bytes()).unwrap();
+        assert!(geometry.get_attribute("color").is_none());
+        assert!(
+            geometry
+                .get_attribute("normal")
+                .unwrap()
+                .read_vec3(2)
+                .unwrap()
+                .abs_diff_eq(Vec3::Z, 1e-6)
+        );
+        assert_eq!(read_indices(&geometry), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_face() {
+        let bytes = write_binary_ply(&[Vec3::ZERO], &[[0, 0, 0]], &[[0, 1, 2, 3]]);
+        assert!(parse_ply_geometry(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_rejects_negative_face_index() {
+        let positions = [Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::ONE];
+        let bytes = write_binary_ply(&positions, &[[0, 0, 0]; 4], &[[0, 1, 2, -1]]);
+        assert!(parse_ply_geometry(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_huge_vertex_count_fails_without_allocating() {
+        let text = "ply\nformat binary_little_endian 1.0\n\
+                    element vertex 4000000000\n\
+                    property float x\nproperty float y\nproperty float z\n\
+                    end_header\n";
+        let mut bytes = text.as_bytes().to_vec();
+        bytes.extend_from_slice(bytemuck::cast_slice(&[0.0f32; 3]));
+        assert!(parse_ply_geometry(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_degenerate_faces_load_as_points() {
+        let text = "ply\nformat ascii 1.0\n\
+                    element vertex 3\n\
+                    property float x\nproperty float y\nproperty float z\n\
+                    element face 2\nproperty list uchar int vertex_indices\n\
+                    end_header\n\
+                    0 0 0\n1 0 0\n0 1 0\n\
+                    2 0 1\n2 1 2\n";
+
+        let geometry = parse_ply_geometry(text.as_bytes()).unwrap();
+        assert_eq!(geometry.topology, PrimitiveTopology::PointList);
+        assert!(geometry.index_attribute().is_none());
+    }
+
+    #[test]
+    fn test_load_from_bytes_picks_material() {
+        let assets = AssetServer::new();
+        let points = write_binary_ply(&[Vec3::ZERO], &[[0, 0, 0]], &[]);
+        let prefab = PlyLoader::load_from_bytes(&points, None, assets.clone()).unwrap();
+
+        assert_eq!(prefab.root_indices, vec![0]);
+        let mesh = prefab.nodes[0].mesh.as_ref().unwrap();
+        let material = assets.materials.get(mesh.material).unwrap();
+        assert!(material.as_points().is_some());
+    }
+}
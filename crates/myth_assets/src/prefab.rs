@@ -5,6 +5,7 @@ use glam::Affine3A;
 use myth_animation::AnimationClip;
 use myth_core::Transform;
 use myth_resources::Mesh;
+use myth_scene::Light;
 
 /// Prefab node: contains only data, uses indices to reference child nodes
 #[derive(Debug, Clone)]
@@ -19,6 +20,8 @@ pub struct PrefabNode {
     pub skin_index: Option<usize>,
     /// Morph weights (if any)
     pub morph_weights: Option<Vec<f32>>,
+    /// Light component (if any)
+    pub light: Option<Light>,
 
     /// Indicates if this node is a split primitive
     pub is_split_primitive: bool,
@@ -34,6 +37,7 @@ impl PrefabNode {
             mesh: None,
             skin_index: None,
             morph_weights: None,
+            light: None,
             is_split_primitive: false,
         }
     }
@@ -14,7 +14,10 @@
 
 use crate::AssetServer;
 use myth_resources::geometry::Geometry;
-use myth_resources::material::{Material, PhongMaterial, PhysicalMaterial, UnlitMaterial};
+use myth_resources::material::{
+    LineMaterial, Material, PhongMaterial, PhysicalMaterial, PointsMaterial, SpriteMaterial,
+    TextMaterial, UnlitMaterial,
+};
 use myth_resources::{GeometryHandle, MaterialHandle};
 
 // ---------------------------------------------------------------------------
@@ -26,8 +29,9 @@ use myth_resources::{GeometryHandle, MaterialHandle};
 /// Implemented for:
 /// - `MaterialHandle` — returned as-is (zero cost).
 /// - `Material` — auto-registered in `AssetServer`.
-/// - `UnlitMaterial`, `PhongMaterial`, `PhysicalMaterial` — converted
-///   to `Material` and auto-registered.
+/// - `UnlitMaterial`, `PhongMaterial`, `PhysicalMaterial`, `LineMaterial`,
+///   `PointsMaterial`, `SpriteMaterial`, `TextMaterial` — converted to
+///   `Material` and auto-registered.
 pub trait ResolveMaterial {
     fn resolve(self, assets: &AssetServer) -> MaterialHandle;
 }
@@ -67,6 +71,34 @@ impl ResolveMaterial for PhysicalMaterial {
     }
 }
 
+impl ResolveMaterial for LineMaterial {
+    #[inline]
+    fn resolve(self, assets: &AssetServer) -> MaterialHandle {
+        assets.materials.add(Material::from(self))
+    }
+}
+
+impl ResolveMaterial for PointsMaterial {
+    #[inline]
+    fn resolve(self, assets: &AssetServer) -> MaterialHandle {
+        assets.materials.add(Material::from(self))
+    }
+}
+
+impl ResolveMaterial for SpriteMaterial {
+    #[inline]
+    fn resolve(self, assets: &AssetServer) -> MaterialHandle {
+        assets.materials.add(Material::from(self))
+    }
+}
+
+impl ResolveMaterial for TextMaterial {
+    #[inline]
+    fn resolve(self, assets: &AssetServer) -> MaterialHandle {
+        assets.materials.add(Material::from(self))
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Geometry resolution
 // ---------------------------------------------------------------------------
@@ -5,13 +5,18 @@
 
 use std::sync::Arc;
 
+use glam::Vec2;
+use rustc_hash::FxHashMap;
+
 use myth_animation::mixer::AnimationMixer;
 use myth_animation::{AnimationAction, Binder};
-use myth_core::{NodeHandle, SkeletonKey};
+use myth_core::{NodeHandle, SkeletonKey, Transform};
 use myth_resources::geometry::Geometry;
 use myth_resources::mesh::Mesh;
-use myth_scene::Scene;
+use myth_resources::text::{Font, TextMesh};
+use myth_resources::{MaterialHandle, TextMaterial};
 use myth_scene::skeleton::{BindMode, Skeleton};
+use myth_scene::{BillboardMode, ParticleSystem, Scene};
 
 use crate::AssetServer;
 use crate::prefab::Prefab;
@@ -58,6 +63,82 @@ pub trait SceneExt {
         material: impl ResolveMaterial,
         assets: &AssetServer,
     ) -> NodeHandle;
+
+    /// Spawns a camera-facing sprite on the shared unit quad
+    /// ([`AssetServer::quad_geometry`]).
+    ///
+    /// The node gets a [`BillboardMode::Full`] billboard; use
+    /// [`Scene::set_billboard`] or [`Scene::clear_billboard`] to change that.
+    /// Scale the node to size world-space sprites.
+    fn spawn_sprite(&mut self, material: impl ResolveMaterial, assets: &AssetServer) -> NodeHandle;
+
+    /// Like [`spawn_sprite`](Self::spawn_sprite), with the quad scaled to
+    /// `size` world units. The sprite's pivot
+    /// ([`SpriteMaterial::with_center`](myth_resources::SpriteMaterial::with_center))
+    /// stays at the node position.
+    fn spawn_sprite_sized(
+        &mut self,
+        material: impl ResolveMaterial,
+        size: Vec2,
+        assets: &AssetServer,
+    ) -> NodeHandle;
+
+    /// Spawns a particle effect: a point-sprite mesh drawing `system`'s
+    /// particles with `material` (typically a blended `PointsMaterial`).
+    ///
+    /// The system is stepped by [`Scene::update`]; call
+    /// [`sync_particles`](Self::sync_particles) afterwards to copy the
+    /// particles into the geometry (`Engine::update` does this for the
+    /// active scene).
+    fn spawn_particles(
+        &mut self,
+        system: ParticleSystem,
+        material: impl ResolveMaterial,
+        assets: &AssetServer,
+    ) -> NodeHandle;
+
+    /// Spawns a text label: `text` laid out with `font` and drawn with a
+    /// default [`TextMaterial`], placed at `transform`.
+    ///
+    /// One text unit is one world unit at scale 1 and the label is centred
+    /// on the node. Use [`spawn_text`](Self::spawn_text) for layout or
+    /// material options.
+    fn add_text(
+        &mut self,
+        text: &str,
+        font: &Font,
+        transform: Transform,
+        assets: &AssetServer,
+    ) -> NodeHandle;
+
+    /// Spawns a mesh drawing `text`'s glyph quads, laid out with `font`'s
+    /// atlas, with `material` (typically a [`TextMaterial`] reading
+    /// `font.texture`).
+    fn spawn_text(
+        &mut self,
+        text: &TextMesh,
+        font: &Font,
+        material: impl ResolveMaterial,
+        assets: &AssetServer,
+    ) -> NodeHandle;
+
+    /// Writes the live particles of every particle system with a mesh into
+    /// its geometry.
+    ///
+    /// Geometries still borrowed elsewhere are skipped for this frame.
+    fn sync_particles(&self, assets: &AssetServer);
+
+    /// Like [`Scene::clone_subtree`], but also gives the copy its own
+    /// materials, so editing them does not affect the original.
+    ///
+    /// Each distinct material is duplicated once and shared among the
+    /// copied meshes that used it. Custom materials cannot be duplicated
+    /// and stay shared. Returns `None` if `root` does not exist.
+    fn clone_subtree_with_materials(
+        &mut self,
+        root: NodeHandle,
+        assets: &AssetServer,
+    ) -> Option<NodeHandle>;
 }
 
 impl SceneExt for Scene {
@@ -85,6 +166,11 @@ impl SceneExt for Scene {
                 self.set_morph_weights(handle, weights.clone());
             }
 
+            // Each instance needs its own light id (shadow maps are keyed by it).
+            if let Some(light) = &p_node.light {
+                self.set_light(handle, light.duplicate());
+            }
+
             if p_node.is_split_primitive {
                 self.mark_as_split_primitive(handle);
             }
@@ -229,4 +315,108 @@ impl SceneExt for Scene {
     ) -> NodeHandle {
         self.spawn(Geometry::new_plane(width, height), material, assets)
     }
+
+    fn spawn_sprite(&mut self, material: impl ResolveMaterial, assets: &AssetServer) -> NodeHandle {
+        let node = self.spawn(assets.quad_geometry(), material, assets);
+        self.set_billboard(node, BillboardMode::Full);
+        node
+    }
+
+    fn spawn_sprite_sized(
+        &mut self,
+        material: impl ResolveMaterial,
+        size: Vec2,
+        assets: &AssetServer,
+    ) -> NodeHandle {
+        let node = self.spawn_sprite(material, assets);
+        if let Some(n) = self.get_node_mut(node) {
+            n.transform.scale = size.extend(1.0);
+        }
+        node
+    }
+
+    fn spawn_particles(
+        &mut self,
+        system: ParticleSystem,
+        material: impl ResolveMaterial,
+        assets: &AssetServer,
+    ) -> NodeHandle {
+        let node = self.spawn(system.build_geometry(), material, assets);
+        self.set_particle_system(node, system);
+        node
+    }
+
+    fn add_text(
+        &mut self,
+        text: &str,
+        font: &Font,
+        transform: Transform,
+        assets: &AssetServer,
+    ) -> NodeHandle {
+        let node = self.spawn_text(
+            &TextMesh::new(text),
+            font,
+            TextMaterial::new(font.texture),
+            assets,
+        );
+        if let Some(n) = self.get_node_mut(node) {
+            n.transform = transform;
+        }
+        node
+    }
+
+    fn spawn_text(
+        &mut self,
+        text: &TextMesh,
+        font: &Font,
+        material: impl ResolveMaterial,
+        assets: &AssetServer,
+    ) -> NodeHandle {
+        self.spawn(text.build_geometry(&font.atlas), material, assets)
+    }
+
+    fn sync_particles(&self, assets: &AssetServer) {
+        for (handle, system) in &self.particle_systems {
+            let Some(mesh) = self.get_mesh(handle) else {
+                continue;
+            };
+            assets
+                .geometries
+                .modify(mesh.geometry, |geometry| system.write_geometry(geometry));
+        }
+    }
+
+    fn clone_subtree_with_materials(
+        &mut self,
+        root: NodeHandle,
+        assets: &AssetServer,
+    ) -> Option<NodeHandle> {
+        let new_root = self.clone_subtree(root)?;
+
+        let mut duplicated: FxHashMap<MaterialHandle, MaterialHandle> = FxHashMap::default();
+        let mut stack = vec![new_root];
+        while let Some(handle) = stack.pop() {
+            if let Some(node) = self.get_node(handle) {
+                stack.extend_from_slice(node.children());
+            }
+            let Some(mesh) = self.get_mesh_mut(handle) else {
+                continue;
+            };
+            let mut duplicate = |source: MaterialHandle| {
+                *duplicated.entry(source).or_insert_with(|| {
+                    assets
+                        .materials
+                        .get(source)
+                        .and_then(|material| material.duplicate())
+                        .map_or(source, |copy| assets.materials.add(copy))
+                })
+            };
+            mesh.material = duplicate(mesh.material);
+            for submesh in &mut mesh.submeshes {
+                submesh.material = duplicate(submesh.material);
+            }
+        }
+
+        Some(new_root)
+    }
 }
@@ -1,8 +1,13 @@
 use flume::{Receiver, Sender, unbounded};
-use parking_lot::RwLock;
+use glam::Vec4;
+use parking_lot::{Mutex, RwLock};
 use std::sync::Arc;
 use uuid::Uuid;
 
+#[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+use crate::hot_reload::PrefabKind;
+#[cfg(feature = "hot-reload")]
+use crate::hot_reload::{AssetReloaded, HotReload, ImageKind, ReloadTarget};
 use crate::io::{AssetReaderVariant, AssetSource};
 use crate::prefab::SharedPrefab;
 use crate::storage::AssetStorage;
@@ -14,7 +19,9 @@ use myth_resources::gaussian_splat::GaussianCloud;
 use myth_resources::geometry::Geometry;
 use myth_resources::image::{ColorSpace, Image, ImageDimension, PixelFormat};
 use myth_resources::material::Material;
+use myth_resources::procedural::{self, NoiseParams};
 use myth_resources::screen_space::SssRegistry;
+use myth_resources::text::{Font, FontAtlas};
 use myth_resources::texture::Texture;
 use myth_resources::{GeometryHandle, ImageHandle, MaterialHandle, PrefabHandle, TextureHandle};
 
@@ -74,6 +81,13 @@ struct GaussianLoadEvent {
     result: std::result::Result<GaussianCloud, String>,
 }
 
+/// Equirectangular → cube conversion waiting for its source image.
+struct CubeConversion {
+    source: ImageHandle,
+    target: ImageHandle,
+    face_size: u32,
+}
+
 /// Internal channel pair for background → main thread communication.
 struct LoadingChannel<T> {
     tx: Sender<T>,
@@ -97,6 +111,14 @@ struct LoadingPipeline {
     prefab_channel: LoadingChannel<PrefabLoadEvent>,
     #[cfg(feature = "3dgs")]
     gaussian_channel: LoadingChannel<GaussianLoadEvent>,
+    /// Cube conversions run once their source image has loaded.
+    cube_conversions: Mutex<Vec<CubeConversion>>,
+    /// Rebuilt images for already-loaded handles (hot reload).
+    #[cfg(feature = "hot-reload")]
+    image_reloads: LoadingChannel<ImageLoadEvent>,
+    /// Rebuilt prefabs for already-loaded handles (hot reload).
+    #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+    prefab_reloads: LoadingChannel<PrefabLoadEvent>,
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -151,6 +173,10 @@ pub struct AssetServer {
     /// Internal background-loading infrastructure (shared across clones).
     loading: Arc<LoadingPipeline>,
 
+    /// Source-file tracking for [`enable_hot_reload`](Self::enable_hot_reload).
+    #[cfg(feature = "hot-reload")]
+    pub(crate) hot_reload: Arc<HotReload>,
+
     /// 1×1 white RGBA texture, used as fallback for albedo maps.
     pub default_white_texture: TextureHandle,
     /// 1×1 black RGBA texture, used as fallback for emission / AO maps.
@@ -202,8 +228,16 @@ impl AssetServer {
                 prefab_channel: LoadingChannel::new(),
                 #[cfg(feature = "3dgs")]
                 gaussian_channel: LoadingChannel::new(),
+                cube_conversions: Mutex::new(Vec::new()),
+                #[cfg(feature = "hot-reload")]
+                image_reloads: LoadingChannel::new(),
+                #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+                prefab_reloads: LoadingChannel::new(),
             }),
 
+            #[cfg(feature = "hot-reload")]
+            hot_reload: Arc::new(HotReload::new()),
+
             default_white_texture,
             default_black_texture,
             default_normal_texture,
@@ -237,6 +271,10 @@ impl AssetServer {
             return handle;
         }
 
+        #[cfg(feature = "hot-reload")]
+        self.hot_reload
+            .track(uri, ReloadTarget::Image(handle, ImageKind::Ldr));
+
         let tx = self.loading.image_channel.sender();
         let uri_owned = uri.to_string();
         let filename_owned = filename.to_string();
@@ -268,6 +306,10 @@ impl AssetServer {
             return handle;
         }
 
+        #[cfg(feature = "hot-reload")]
+        self.hot_reload
+            .track(uri, ReloadTarget::Image(handle, ImageKind::Hdr));
+
         let tx = self.loading.image_channel.sender();
         let uri_owned = uri.to_string();
         let filename_owned = filename.to_string();
@@ -299,6 +341,10 @@ impl AssetServer {
             return handle;
         }
 
+        #[cfg(feature = "hot-reload")]
+        self.hot_reload
+            .track(uri, ReloadTarget::Image(handle, ImageKind::Lut));
+
         let tx = self.loading.image_channel.sender();
         let uri_owned = uri.to_string();
         let filename_owned = filename.to_string();
@@ -393,7 +439,7 @@ impl AssetServer {
         tex_handle
     }
 
-    /// Loads a 3D LUT from a `.cube` or `.bin` file, returning a handle immediately.
+    /// Loads a 3D LUT from a `.cube`, `.bin` or `N²×N` strip image, returning a handle immediately.
     ///
     /// Deduplicated by URI.
     #[allow(clippy::needless_pass_by_value)]
@@ -563,6 +609,10 @@ impl AssetServer {
             return handle;
         }
 
+        #[cfg(feature = "hot-reload")]
+        self.hot_reload
+            .track(&uri, ReloadTarget::Prefab(handle, PrefabKind::Gltf));
+
         let tx = self.loading.prefab_channel.sender();
         let assets = self.clone();
 
@@ -580,6 +630,42 @@ impl AssetServer {
         handle
     }
 
+    /// Loads a PLY mesh or point cloud, returning a [`PrefabHandle`] immediately.
+    ///
+    /// See [`PlyLoader`](crate::loaders::PlyLoader) for the supported
+    /// properties. Completion and URI deduplication behave as in
+    /// [`load_gltf`](Self::load_gltf).
+    #[cfg(feature = "ply")]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn load_ply(&self, source: impl AssetSource) -> PrefabHandle {
+        let uri = source.uri().to_string();
+        let uuid = Self::generate_asset_uuid("PLY", &uri, "");
+        let (handle, is_new) = self.prefabs.reserve_with_uuid(uuid);
+        if !is_new {
+            return handle;
+        }
+
+        #[cfg(feature = "hot-reload")]
+        self.hot_reload
+            .track(&uri, ReloadTarget::Prefab(handle, PrefabKind::Ply));
+
+        let tx = self.loading.prefab_channel.sender();
+        let assets = self.clone();
+
+        spawn_asset_task(async move {
+            let source_str = uri.clone();
+            let result = crate::loaders::PlyLoader::load_async(uri, assets).await;
+            let event = PrefabLoadEvent {
+                handle,
+                source: source_str,
+                result: result.map_err(|e| e.to_string()),
+            };
+            let _ = tx.send(event);
+        });
+
+        handle
+    }
+
     // ========================================================================
     // Event Processing (called once per frame by Engine)
     // ========================================================================
@@ -589,6 +675,9 @@ impl AssetServer {
     ///
     /// This is called automatically by [`Engine::update`] each frame.
     pub fn process_loading_events(&self) {
+        #[cfg(feature = "hot-reload")]
+        self.process_hot_reload_events();
+
         // Drain image completions.
         while let Ok(event) = self.loading.image_channel.rx.try_recv() {
             match event.result {
@@ -602,6 +691,8 @@ impl AssetServer {
             }
         }
 
+        self.process_cube_conversions();
+
         // Drain prefab completions into unified AssetStorage.
         while let Ok(event) = self.loading.prefab_channel.rx.try_recv() {
             match event.result {
@@ -610,7 +701,7 @@ impl AssetServer {
                     log::info!("Prefab loaded: {}", event.source);
                 }
                 Err(ref msg) => {
-                    log::error!("glTF load failed ({}): {msg}", event.source);
+                    log::error!("Prefab load failed ({}): {msg}", event.source);
                     self.prefabs.mark_failed(event.handle, msg.clone());
                 }
             }
@@ -631,6 +722,308 @@ impl AssetServer {
         }
     }
 
+    // ========================================================================
+    // Hot Reload (feature "hot-reload")
+    // ========================================================================
+
+    /// Starts watching the source files of loaded assets for changes.
+    ///
+    /// Every image loaded through [`load_texture`](Self::load_texture),
+    /// [`load_hdr_texture`](Self::load_hdr_texture) or
+    /// [`load_lut_texture`](Self::load_lut_texture), and every prefab loaded
+    /// through `load_gltf` / `load_ply`, is tracked by its local file path.
+    /// When such a file is modified, it is re-read and decoded in the
+    /// background and the result replaces the asset **under the same handle**
+    /// with a bumped version, so GPU resources and bind groups refresh on the
+    /// next frame and existing scene references stay valid.
+    ///
+    /// For prefabs, geometries and materials are matched to the previous
+    /// load by node order and updated in place, so already-instantiated
+    /// meshes pick up the edits. Textures and images that only the replaced
+    /// materials used are freed.
+    ///
+    /// File changes are observed with [`notify`] and applied by
+    /// [`process_loading_events`](Self::process_loading_events), which the
+    /// engine calls once per frame. Rapid successive writes are debounced
+    /// into one reload. Calling this more than once is a no-op.
+    ///
+    /// Native only — there is no file system to watch on `wasm32`.
+    #[cfg(all(feature = "hot-reload", not(target_arch = "wasm32")))]
+    pub fn enable_hot_reload(&self) {
+        self.hot_reload.start();
+    }
+
+    /// Returns the assets that hot reload rebuilt since the last call.
+    ///
+    /// Events are queued by [`process_loading_events`](Self::process_loading_events)
+    /// after the new data has replaced the old one, so apps can react to a
+    /// reload (re-instantiate a prefab, refresh a UI thumbnail, ...). Events
+    /// accumulate until drained.
+    #[cfg(feature = "hot-reload")]
+    #[must_use]
+    pub fn drain_reloaded(&self) -> Vec<AssetReloaded> {
+        self.hot_reload.drain_reloaded()
+    }
+
+    /// Schedules background rebuilds for changed source files and applies
+    /// the ones that have finished.
+    #[cfg(feature = "hot-reload")]
+    fn process_hot_reload_events(&self) {
+        for (path, target) in self.hot_reload.drain_changes() {
+            self.schedule_reload(path.to_string_lossy().into_owned(), target);
+        }
+
+        while let Ok(event) = self.loading.image_reloads.rx.try_recv() {
+            match event.result {
+                Ok(image) => {
+                    if self.images.update(event.handle, image).is_none() {
+                        log::warn!("Hot reload: image slot is no longer loaded");
+                    } else {
+                        self.hot_reload
+                            .notify_reloaded(AssetReloaded::Image(event.handle));
+                    }
+                }
+                Err(msg) => log::error!("Hot reload: image decode failed: {msg}"),
+            }
+        }
+
+        #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+        while let Ok(event) = self.loading.prefab_reloads.rx.try_recv() {
+            match event.result {
+                Ok(prefab) => {
+                    self.replace_prefab(event.handle, &event.source, prefab);
+                    log::info!("Prefab reloaded: {}", event.source);
+                    self.hot_reload
+                        .notify_reloaded(AssetReloaded::Prefab(event.handle, event.source));
+                }
+                Err(msg) => log::error!("Hot reload failed ({}): {msg}", event.source),
+            }
+        }
+    }
+
+    /// Re-runs the loader that originally produced `target`.
+    #[cfg(feature = "hot-reload")]
+    fn schedule_reload(&self, uri: String, target: ReloadTarget) {
+        match target {
+            ReloadTarget::Image(handle, kind) => {
+                let tx = self.loading.image_reloads.sender();
+                let filename = uri
+                    .as_str()
+                    .filename()
+                    .map_or_else(|| "unknown".to_string(), |c| c.to_string());
+
+                spawn_asset_task(async move {
+                    let result = match kind {
+                        ImageKind::Ldr => {
+                            Self::load_image_task(&uri, &filename, PixelFormat::Rgba8Unorm).await
+                        }
+                        ImageKind::Hdr => Self::load_hdr_image_task(&uri, &filename).await,
+                        ImageKind::Lut => Self::load_lut_image_task(&uri, &filename).await,
+                    };
+                    let _ = tx.send(ImageLoadEvent {
+                        handle,
+                        result: result.map_err(|e| e.to_string()),
+                    });
+                });
+            }
+            #[cfg(any(feature = "gltf", feature = "ply"))]
+            ReloadTarget::Prefab(handle, kind) => {
+                let tx = self.loading.prefab_reloads.sender();
+                let assets = self.clone();
+
+                spawn_asset_task(async move {
+                    let source = uri.clone();
+                    let result = match kind {
+                        #[cfg(feature = "gltf")]
+                        PrefabKind::Gltf => {
+                            crate::loaders::GltfLoader::load_async(uri, assets).await
+                        }
+                        #[cfg(feature = "ply")]
+                        PrefabKind::Ply => crate::loaders::PlyLoader::load_async(uri, assets).await,
+                    };
+                    let _ = tx.send(PrefabLoadEvent {
+                        handle,
+                        source,
+                        result: result.map_err(|e| e.to_string()),
+                    });
+                });
+            }
+        }
+    }
+
+    /// Replaces the prefab at `handle`, moving each rebuilt geometry and
+    /// material into the handle used by the matching node of the previous
+    /// load, then frees the textures and images only the previous
+    /// materials referenced.
+    #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+    fn replace_prefab(&self, handle: PrefabHandle, source: &str, prefab: SharedPrefab) {
+        let Some(previous) = self.prefabs.get(handle) else {
+            self.prefabs.insert_ready(handle, prefab);
+            return;
+        };
+        let mut prefab = Arc::unwrap_or_clone(prefab);
+
+        if previous.nodes.len() != prefab.nodes.len() {
+            log::warn!(
+                "Hot reload ({source}): node count changed ({} -> {}); \
+                 existing instances only receive data for matching nodes",
+                previous.nodes.len(),
+                prefab.nodes.len()
+            );
+        }
+
+        let mut stale_textures = rustc_hash::FxHashSet::default();
+        let mut material_targets: rustc_hash::FxHashMap<MaterialHandle, MaterialHandle> =
+            rustc_hash::FxHashMap::default();
+        let mut claimed_materials = rustc_hash::FxHashSet::default();
+
+        for (old_node, new_node) in previous.nodes.iter().zip(prefab.nodes.iter_mut()) {
+            let (Some(old_mesh), Some(new_mesh)) = (&old_node.mesh, &mut new_node.mesh) else {
+                continue;
+            };
+
+            let old_materials = std::iter::once(old_mesh.material)
+                .chain(old_mesh.submeshes.iter().map(|s| s.material));
+            let new_materials = std::iter::once(new_mesh.material)
+                .chain(new_mesh.submeshes.iter().map(|s| s.material));
+            for (old_material, new_material) in old_materials.zip(new_materials) {
+                if material_targets.contains_key(&new_material)
+                    || !claimed_materials.insert(old_material)
+                {
+                    continue;
+                }
+                if self.supersede_material(old_material, new_material, source, &mut stale_textures)
+                {
+                    material_targets.insert(new_material, old_material);
+                }
+            }
+
+            let Some(old_geometry) = self.geometries.get(old_mesh.geometry) else {
+                continue;
+            };
+            let Some(crate::storage::AssetSlot::Loaded(entry)) =
+                self.geometries.remove(new_mesh.geometry)
+            else {
+                continue;
+            };
+
+            if let Ok(mut geometry) = Arc::try_unwrap(entry.asset) {
+                geometry.supersede(&old_geometry);
+                self.geometries.update(old_mesh.geometry, geometry);
+            } else {
+                // Another owner still holds the rebuilt geometry; keep the
+                // previous one rather than leave a dangling handle.
+                log::warn!("Hot reload ({source}): rebuilt geometry is shared, skipped");
+            }
+            new_mesh.geometry = old_mesh.geometry;
+        }
+
+        for mesh in prefab.nodes.iter_mut().filter_map(|n| n.mesh.as_mut()) {
+            let retarget = |material: &mut MaterialHandle| {
+                if let Some(&old) = material_targets.get(material) {
+                    *material = old;
+                }
+            };
+            retarget(&mut mesh.material);
+            mesh.submeshes
+                .iter_mut()
+                .for_each(|submesh| retarget(&mut submesh.material));
+        }
+
+        // The loaders create fresh textures and images for every load, so
+        // the ones only the replaced material data referenced are unused now.
+        let mut live_textures = rustc_hash::FxHashSet::default();
+        for &material in material_targets.values() {
+            self.collect_material_textures(material, &mut live_textures);
+        }
+        self.free_textures(&stale_textures, &live_textures);
+
+        self.prefabs.update(handle, prefab);
+    }
+    /// Moves the material at `new` into the `old` handle, recording the
+    /// textures the old data referenced in `stale_textures`.
+    ///
+    /// Returns `false`, leaving `new` in place, when the previous material
+    /// is no longer loaded.
+    #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+    fn supersede_material(
+        &self,
+        old: MaterialHandle,
+        new: MaterialHandle,
+        source: &str,
+        stale_textures: &mut rustc_hash::FxHashSet<TextureHandle>,
+    ) -> bool {
+        let Some(old_material) = self.materials.get(old) else {
+            return false;
+        };
+        if !self.materials.is_loaded(new) {
+            return false;
+        }
+        let Some(crate::storage::AssetSlot::Loaded(entry)) = self.materials.remove(new) else {
+            return false;
+        };
+
+        if let Ok(material) = Arc::try_unwrap(entry.asset) {
+            self.collect_material_textures(old, stale_textures);
+            material.supersede(&old_material);
+            self.materials.update(old, material);
+        } else {
+            // Same as for geometries: keep the previous material.
+            log::warn!("Hot reload ({source}): rebuilt material is shared, skipped");
+        }
+        true
+    }
+
+    /// Adds the asset textures referenced by `material` to `out`.
+    #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+    fn collect_material_textures(
+        &self,
+        material: MaterialHandle,
+        out: &mut rustc_hash::FxHashSet<TextureHandle>,
+    ) {
+        let Some(material) = self.materials.get(material) else {
+            return;
+        };
+        material.as_renderable().visit_textures(&mut |source| {
+            if let myth_resources::texture::TextureSource::Asset(texture) = source {
+                out.insert(*texture);
+            }
+        });
+    }
+
+    /// Removes the `stale` textures that are not `live`, together with
+    /// their images when no live texture samples them.
+    #[cfg(all(feature = "hot-reload", any(feature = "gltf", feature = "ply")))]
+    fn free_textures(
+        &self,
+        stale: &rustc_hash::FxHashSet<TextureHandle>,
+        live: &rustc_hash::FxHashSet<TextureHandle>,
+    ) {
+        let defaults = [
+            self.default_white_texture,
+            self.default_black_texture,
+            self.default_normal_texture,
+        ];
+        let live_images: rustc_hash::FxHashSet<ImageHandle> = live
+            .iter()
+            .chain(&defaults)
+            .filter_map(|&t| self.textures.get(t).map(|texture| texture.image))
+            .collect();
+
+        for &texture in stale {
+            if live.contains(&texture) || defaults.contains(&texture) {
+                continue;
+            }
+            let Some(crate::storage::AssetSlot::Loaded(entry)) = self.textures.remove(texture)
+            else {
+                continue;
+            };
+            if !live_images.contains(&entry.asset.image) {
+                self.images.remove(entry.asset.image);
+            }
+        }
+    }
+
     // ========================================================================
     // Blocking (Synchronous) Loading — Native Only
     // ========================================================================
@@ -870,6 +1263,159 @@ impl AssetServer {
         self.textures.add(texture)
     }
 
+    /// Returns a 1×1 texture of the given linear RGBA colour.
+    ///
+    /// Textures are cached by their 8-bit sRGB encoding, so repeated calls
+    /// with the same colour return the same handle. The texture is shared;
+    /// don't modify it in place.
+    #[must_use]
+    pub fn create_color_texture(&self, color: Vec4) -> TextureHandle {
+        let rgba = procedural::encode_srgb8(color);
+        let uuid = Self::generate_asset_uuid("ColorTexture", "", &format!("{rgba:?}"));
+        let (handle, is_new) = self.textures.reserve_with_uuid(uuid);
+        if is_new {
+            let image_handle = self.images.add(Image::solid_color(rgba));
+            let mut texture = Texture::new_2d(Some("Color"), image_handle);
+            texture.color_space = ColorSpace::Srgb;
+            self.textures.insert_ready(handle, texture);
+        }
+        handle
+    }
+
+    /// Creates a vertical gradient texture, 1 texel wide and `size` tall.
+    ///
+    /// See [`procedural::gradient`] for how `stops` are interpreted. The
+    /// texture clamps at its edges so the ends don't bleed into each other.
+    #[must_use]
+    pub fn create_gradient_texture(&self, stops: &[(f32, Vec4)], size: u32) -> TextureHandle {
+        let image_handle = self.images.add(procedural::gradient(stops, size));
+        let mut texture = Texture::new_2d(Some("Gradient"), image_handle);
+        texture.color_space = ColorSpace::Srgb;
+        texture.sampler.address_mode_u = wgpu::AddressMode::ClampToEdge;
+        texture.sampler.address_mode_v = wgpu::AddressMode::ClampToEdge;
+        self.textures.add(texture)
+    }
+
+    /// Uploads a distance-field font atlas and returns it as a [`Font`],
+    /// ready for [`TextMaterial::new`](myth_resources::TextMaterial::new)
+    /// and `SceneExt::add_text`.
+    ///
+    /// The atlas texture is linear and clamps at its edges.
+    #[must_use]
+    pub fn create_font(&self, atlas: FontAtlas) -> Font {
+        let image_handle = self.images.add(atlas.to_image());
+        let mut texture = Texture::new_2d(Some("FontAtlas"), image_handle);
+        texture.color_space = ColorSpace::Linear;
+        texture.sampler.address_mode_u = wgpu::AddressMode::ClampToEdge;
+        texture.sampler.address_mode_v = wgpu::AddressMode::ClampToEdge;
+        Font {
+            atlas: Arc::new(atlas),
+            texture: self.textures.add(texture),
+        }
+    }
+
+    /// Converts an equirectangular texture (e.g. from
+    /// [`load_hdr_texture`](Self::load_hdr_texture)) into a cube texture
+    /// with `face_size`×`face_size` faces, returning a handle immediately.
+    ///
+    /// The panorama is resampled on the CPU by [`Image::equirect_to_cube`],
+    /// with the face layout used by the renderer's environment conversion,
+    /// so the result can be shown with `BackgroundMode::cubemap` or set as
+    /// the environment map. The cube keeps the source colour space and
+    /// generates mipmaps for filtered sampling. A source that is still
+    /// loading is converted by
+    /// [`process_loading_events`](Self::process_loading_events) once it
+    /// arrives; if it fails to load, so does the cube.
+    ///
+    /// Deduplicated by source texture and face size.
+    #[must_use]
+    pub fn equirect_to_cubemap(&self, source: TextureHandle, face_size: u32) -> TextureHandle {
+        let face_size = face_size.max(1);
+        let Some(source_texture) = self.textures.get(source) else {
+            let image_handle = self.images.reserve();
+            self.images.mark_failed(
+                image_handle,
+                "equirect_to_cubemap: unknown source texture".to_string(),
+            );
+            return self
+                .textures
+                .add(Texture::new_cube(Some("EquirectToCube"), image_handle));
+        };
+
+        let tex_uuid = Self::generate_asset_uuid(
+            "EquirectToCube",
+            &source_texture.uuid().to_string(),
+            &face_size.to_string(),
+        );
+        let (tex_handle, is_new) = self.textures.reserve_with_uuid(tex_uuid);
+        if !is_new {
+            return tex_handle;
+        }
+
+        let image_handle = self.images.reserve();
+        self.loading.cube_conversions.lock().push(CubeConversion {
+            source: source_texture.image,
+            target: image_handle,
+            face_size,
+        });
+        self.process_cube_conversions();
+
+        let mut texture = Texture::new_cube(source_texture.name(), image_handle);
+        texture.color_space = source_texture.color_space;
+        texture.generate_mipmaps = true;
+        self.textures.insert_ready(tex_handle, texture);
+
+        tex_handle
+    }
+
+    /// Runs the cube conversions whose source image is no longer loading.
+    fn process_cube_conversions(&self) {
+        self.loading.cube_conversions.lock().retain(|job| {
+            if self.images.is_loading(job.source) {
+                return true;
+            }
+            match self
+                .images
+                .get(job.source)
+                .and_then(|image| image.equirect_to_cube(job.face_size))
+            {
+                Some(cube) => self.images.insert_ready(job.target, cube),
+                None => self.images.mark_failed(
+                    job.target,
+                    "equirect_to_cubemap: source is not a loaded 2D image".to_string(),
+                ),
+            }
+            false
+        });
+    }
+
+    /// Returns the shared unit quad geometry (see [`Geometry::new_quad`]).
+    ///
+    /// The geometry is created once and reused, so many sprites can share a
+    /// single vertex buffer. Don't modify it in place.
+    #[must_use]
+    pub fn quad_geometry(&self) -> GeometryHandle {
+        let uuid = Self::generate_asset_uuid("QuadGeometry", "", "");
+        let (handle, is_new) = self.geometries.reserve_with_uuid(uuid);
+        if is_new {
+            self.geometries.insert_ready(handle, Geometry::new_quad());
+        }
+        handle
+    }
+
+    /// Creates a tileable procedural noise texture.
+    ///
+    /// See [`procedural::noise_texture`] for the generated data. The texture
+    /// uses linear colour space and repeat addressing.
+    #[must_use]
+    pub fn noise_texture(&self, width: u32, height: u32, params: NoiseParams) -> TextureHandle {
+        let image = procedural::noise_texture(width, height, params);
+        let image_handle = self.images.add(image);
+        let mut texture = Texture::new_2d(Some("Noise"), image_handle);
+        texture.color_space = ColorSpace::Linear;
+        self.textures.add(texture)
+    }
+
     // ========================================================================
     // Cache Invalidation
     // ========================================================================
@@ -1085,20 +1631,24 @@ impl AssetServer {
         let reader = AssetReaderVariant::new(&uri)?;
         let bytes = reader.read_bytes(filename).await?;
 
-        if std::path::Path::new(filename)
-            .extension()
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("bin"))
-        {
-            Self::decode_cube_bin_cpu(&bytes)
-        } else if std::path::Path::new(filename)
+        Self::decode_lut_cpu(&bytes, filename)
+    }
+
+    /// Decodes a LUT by file extension: `.cube` text, pre-baked `.bin`, or a
+    /// horizontal strip image (`.png`, `.jpg`, ...).
+    pub(crate) fn decode_lut_cpu(bytes: &[u8], filename: &str) -> Result<Image> {
+        let extension = std::path::Path::new(filename)
             .extension()
-            .is_some_and(|ext| ext.eq_ignore_ascii_case("cube"))
-        {
-            Self::decode_cube_cpu(&bytes)
-        } else {
-            Err(Error::Asset(AssetError::Format(format!(
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+
+        match extension.as_deref() {
+            Some("bin") => Self::decode_cube_bin_cpu(bytes),
+            Some("cube") => Self::decode_cube_cpu(bytes),
+            Some("png" | "jpg" | "jpeg" | "webp") => Self::decode_lut_strip_cpu(bytes, filename),
+            _ => Err(Error::Asset(AssetError::Format(format!(
                 "Unsupported LUT file extension: {filename}",
-            ))))
+            )))),
         }
     }
 
@@ -1363,6 +1913,53 @@ impl AssetServer {
         ))
     }
 
+    /// CPU strip LUT decoding logic.
+    ///
+    /// Expects an `N² × N` image made of `N` square slices laid out left to
+    /// right: red increases along X inside each slice, green along Y (top to
+    /// bottom), and blue selects the slice. Pixel values are used as-is and
+    /// converted to the same `PixelFormat::Rgba16Float` 3D layout as `.cube`.
+    pub(crate) fn decode_lut_strip_cpu(bytes: &[u8], label: &str) -> Result<Image> {
+        let img = image::load_from_memory(bytes).map_err(|e| {
+            Error::Asset(AssetError::Format(format!(
+                "Failed to decode LUT strip {label}: {e}"
+            )))
+        })?;
+        let rgba = img.to_rgba8();
+        let size = rgba.height();
+
+        if size < 2 || rgba.width() != size * size {
+            return Err(Error::Asset(AssetError::Format(format!(
+                "LUT strip {label} must be N²×N pixels, got {}×{}",
+                rgba.width(),
+                size
+            ))));
+        }
+
+        let mut rgba_f16_data = Vec::with_capacity((size * size * size * 4) as usize * 2);
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    let pixel = rgba.get_pixel(b * size + r, g);
+                    for channel in 0..3 {
+                        let value = half::f16::from_f32(f32::from(pixel[channel]) / 255.0);
+                        rgba_f16_data.extend_from_slice(&value.to_le_bytes());
+                    }
+                    rgba_f16_data.extend_from_slice(&half::f16::ONE.to_le_bytes());
+                }
+            }
+        }
+
+        Ok(Image::new(
+            size,
+            size,
+            size,
+            ImageDimension::D3,
+            PixelFormat::Rgba16Float,
+            Some(rgba_f16_data),
+        ))
+    }
+
     /// CPU .cube file decoding logic (parses text, converts to `PixelFormat::Rgba16Float` 3D image).
     pub(crate) fn decode_cube_cpu(bytes: &[u8]) -> Result<Image> {
         let raw_text = std::str::from_utf8(bytes).map_err(|e| {
@@ -1464,3 +2061,32 @@ impl myth_scene::GeometryQuery for AssetServer {
         self.geometries.get(handle).map(|g| g.bounding_box)
     }
 }
+
+impl myth_scene::AssetIdResolver for AssetServer {
+    fn geometry_id(&self, handle: GeometryHandle) -> Option<String> {
+        self.geometries.get(handle).map(|g| g.uuid().to_string())
+    }
+
+    fn material_id(&self, handle: MaterialHandle) -> Option<String> {
+        self.materials.get(handle).map(|m| m.uuid().to_string())
+    }
+
+    fn texture_id(&self, handle: TextureHandle) -> Option<String> {
+        self.textures.get(handle).map(|t| t.uuid().to_string())
+    }
+
+    fn resolve_geometry(&self, id: &str) -> Option<GeometryHandle> {
+        let uuid = Uuid::parse_str(id).ok()?;
+        self.geometries.find_handle(|g| g.uuid() == uuid)
+    }
+
+    fn resolve_material(&self, id: &str) -> Option<MaterialHandle> {
+        let uuid = Uuid::parse_str(id).ok()?;
+        self.materials.find_handle(|m| m.uuid() == uuid)
+    }
+
+    fn resolve_texture(&self, id: &str) -> Option<TextureHandle> {
+        let uuid = Uuid::parse_str(id).ok()?;
+        self.textures.find_handle(|t| t.uuid() == uuid)
+    }
+}
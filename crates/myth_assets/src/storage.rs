@@ -251,6 +251,23 @@ impl<H: Key, T> AssetStorage<H, T> {
         }
     }
 
+    /// Mutates the loaded asset at `handle` in place, incrementing its
+    /// version.
+    ///
+    /// Returns `None` without calling `f` if the handle is invalid, not in
+    /// the `Loaded` state, or another owner still holds the asset's `Arc`
+    /// (e.g. a clone returned by [`get`](Self::get)).
+    pub fn modify<R>(&self, handle: H, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let mut guard = self.inner.write();
+        let Some(AssetSlot::Loaded(entry)) = guard.map.get_mut(handle) else {
+            return None;
+        };
+        let result = f(Arc::get_mut(&mut entry.asset)?);
+        entry.version += 1;
+        self.global_version.fetch_add(1, Ordering::Relaxed);
+        Some(result)
+    }
+
     /// Removes the slot (any state) and returns the previous value.
     pub fn remove(&self, handle: H) -> Option<AssetSlot<T>> {
         let mut guard = self.inner.write();
@@ -347,6 +364,19 @@ impl<H: Key, T> AssetStorage<H, T> {
         guard.lookup.get(uuid).copied()
     }
 
+    /// Returns the handle of the first loaded asset matching `predicate`.
+    ///
+    /// This is a linear scan; use it for infrequent lookups such as
+    /// resolving serialized references.
+    pub fn find_handle(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<H> {
+        let guard = self.inner.read();
+        guard.map.iter().find_map(|(handle, slot)| {
+            slot.as_loaded()
+                .filter(|entry| predicate(&entry.asset))
+                .map(|_| handle)
+        })
+    }
+
     /// Acquires a read-lock guard for batch access.
     ///
     /// Use [`StorageInner::get_loaded`] on the returned guard to access
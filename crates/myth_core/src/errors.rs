@@ -6,6 +6,7 @@
 //!   - [`PlatformError`] — window system and adapter errors
 //!   - [`AssetError`] — I/O, network, parsing errors
 //!   - [`RenderError`] — GPU device and shader errors
+//!   - [`SceneError`] — invalid scene graph operations
 
 use thiserror::Error;
 
@@ -28,6 +29,10 @@ pub enum Error {
     #[error("Render error: {0}")]
     Render(#[from] RenderError),
 
+    /// Scene graph errors.
+    #[error("Scene error: {0}")]
+    Scene(#[from] SceneError),
+
     /// General engine error.
     #[error("Engine error: {0}")]
     General(String),
@@ -140,6 +145,22 @@ pub enum RenderError {
     NoHeadlessTarget,
 }
 
+// ============================================================================
+// Scene Errors
+// ============================================================================
+
+/// Errors related to scene graph operations.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SceneError {
+    /// The referenced node does not exist in the scene.
+    #[error("Node not found")]
+    NodeNotFound,
+
+    /// The operation would make a node its own ancestor.
+    #[error("Operation would create a cycle in the node hierarchy")]
+    HierarchyCycle,
+}
+
 // ============================================================================
 // Convenient conversion: std::io::Error → Error (via AssetError)
 // ============================================================================
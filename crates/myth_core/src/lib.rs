@@ -13,9 +13,9 @@ pub mod handles;
 pub mod transform;
 pub mod utils;
 
-pub use errors::{AssetError, Error, PlatformError, RenderError, Result};
+pub use errors::{AssetError, Error, PlatformError, RenderError, Result, SceneError};
 pub use handles::{NodeHandle, SkeletonKey};
-pub use transform::Transform;
+pub use transform::{Transform, TransformBuilder};
 pub use utils::interner::Symbol;
 
 /// Maximum number of morph targets supported per mesh.
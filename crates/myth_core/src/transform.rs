@@ -28,6 +28,12 @@ use glam::{Affine3A, EulerRot, Mat3, Mat4, Quat, Vec3};
 /// transform.position = Vec3::new(1.0, 2.0, 3.0);
 /// transform.rotation = Quat::from_rotation_y(std::f32::consts::FRAC_PI_2);
 /// transform.scale = Vec3::splat(2.0);
+///
+/// // Or fluently:
+/// let transform = Transform::builder()
+///     .position(Vec3::new(0.0, 5.0, 10.0))
+///     .look_at(Vec3::ZERO, Vec3::Y)
+///     .build();
 /// ```
 #[derive(Debug, Copy, Clone)]
 pub struct Transform {
@@ -76,6 +82,24 @@ impl Transform {
         }
     }
 
+    /// Returns a builder for a transform with the given TRS values.
+    #[must_use]
+    pub fn builder() -> TransformBuilder {
+        TransformBuilder::default()
+    }
+
+    /// Creates a transform at `position` facing `target` (in parent space).
+    ///
+    /// If `target` lies on the `up` line through `position`, the rotation
+    /// stays the identity (see [`look_at`](Self::look_at)).
+    #[must_use]
+    pub fn from_position_target(position: Vec3, target: Vec3, up: Vec3) -> Self {
+        let mut transform = Self::new();
+        transform.position = position;
+        transform.look_at(target, up);
+        transform
+    }
+
     /// Checks and updates the local matrix if TRS values changed.
     ///
     /// Returns `true` if the matrix was updated, `false` otherwise.
@@ -186,6 +210,21 @@ impl Transform {
         self.rotation = Quat::from_mat3(&rot_mat);
     }
 
+    /// Rotates the transform by `angle` radians around the line through
+    /// `point` along `axis` (both in parent space).
+    ///
+    /// Both position and orientation are rotated, so repeated calls orbit
+    /// the point while keeping the same side facing it. A zero `axis` is
+    /// ignored.
+    pub fn rotate_around(&mut self, point: Vec3, axis: Vec3, angle: f32) {
+        let Some(axis) = axis.try_normalize() else {
+            return;
+        };
+        let rotation = Quat::from_axis_angle(axis, angle);
+        self.position = point + rotation * (self.position - point);
+        self.rotation = (rotation * self.rotation).normalize();
+    }
+
     /// Marks the transform as dirty, forcing a matrix recompute next frame.
     #[inline]
     pub fn mark_dirty(&mut self) {
@@ -209,3 +248,70 @@ impl Default for Transform {
         Self::new()
     }
 }
+
+/// Fluent builder for a [`Transform`], created with [`Transform::builder`].
+///
+/// Values not set keep their identity defaults. A [`look_at`](Self::look_at)
+/// target is resolved in [`build`](Self::build) from the final position and
+/// overrides any explicit rotation, so the calls may come in any order.
+#[derive(Debug, Clone, Copy)]
+pub struct TransformBuilder {
+    position: Vec3,
+    rotation: Quat,
+    scale: Vec3,
+    look_at: Option<(Vec3, Vec3)>,
+}
+
+impl Default for TransformBuilder {
+    fn default() -> Self {
+        Self {
+            position: Vec3::ZERO,
+            rotation: Quat::IDENTITY,
+            scale: Vec3::ONE,
+            look_at: None,
+        }
+    }
+}
+
+impl TransformBuilder {
+    /// Sets the local position.
+    #[must_use]
+    pub fn position(mut self, position: Vec3) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the local rotation.
+    #[must_use]
+    pub fn rotation(mut self, rotation: Quat) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Sets the local scale.
+    #[must_use]
+    pub fn scale(mut self, scale: Vec3) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Orients the transform to face `target` (in parent space).
+    #[must_use]
+    pub fn look_at(mut self, target: Vec3, up: Vec3) -> Self {
+        self.look_at = Some((target, up));
+        self
+    }
+
+    /// Builds the transform.
+    #[must_use]
+    pub fn build(self) -> Transform {
+        let mut transform = Transform::new();
+        transform.position = self.position;
+        transform.rotation = self.rotation;
+        transform.scale = self.scale;
+        if let Some((target, up)) = self.look_at {
+            transform.look_at(target, up);
+        }
+        transform
+    }
+}
@@ -338,7 +338,7 @@ fn gen_constructor(def: &MaterialDef) -> TokenStream {
 // Settings API
 // ============================================================================
 
-/// Generates settings accessor methods (alpha_mode, side, depth_test, depth_write).
+/// Generates settings accessor methods (alpha_mode, side, depth_test, depth_write, wireframe, flat_shading, stencil).
 fn gen_settings_api(def: &MaterialDef) -> TokenStream {
     let cr = &def.crate_path;
 
@@ -400,6 +400,50 @@ fn gen_settings_api(def: &MaterialDef) -> TokenStream {
         pub fn depth_write(&self) -> bool {
             self.settings.read().depth_write
         }
+
+        /// Enables or disables wireframe rendering (triangle edges only).
+        ///
+        /// Devices without line polygon mode (e.g. WebGPU) draw wireframes
+        /// with a barycentric fallback that only supports triangle lists;
+        /// triangle strips stay filled there.
+        pub fn set_wireframe(&self, wireframe: bool) {
+            self.settings_mut().wireframe = wireframe;
+        }
+
+        /// Returns whether wireframe rendering is enabled.
+        pub fn wireframe(&self) -> bool {
+            self.settings.read().wireframe
+        }
+
+        /// Enables or disables flat (per-face) shading.
+        pub fn set_flat_shading(&self, flat_shading: bool) {
+            self.settings_mut().flat_shading = flat_shading;
+        }
+
+        /// Returns whether flat shading is enabled.
+        pub fn flat_shading(&self) -> bool {
+            self.settings.read().flat_shading
+        }
+
+        /// Sets the stencil test and write state (`None` disables it).
+        pub fn set_stencil(&self, stencil: Option<#cr::material::StencilSettings>) {
+            self.settings_mut().stencil = stencil;
+        }
+
+        /// Returns the current stencil state.
+        pub fn stencil(&self) -> Option<#cr::material::StencilSettings> {
+            self.settings.read().stencil
+        }
+
+        /// Enables or disables additive blending (blended alpha modes only).
+        pub fn set_additive_blending(&self, additive: bool) {
+            self.settings_mut().additive_blending = additive;
+        }
+
+        /// Returns whether additive blending is enabled.
+        pub fn additive_blending(&self) -> bool {
+            self.settings.read().additive_blending
+        }
     }
 }
 
@@ -446,8 +490,11 @@ fn gen_uniform_accessors(def: &MaterialDef) -> TokenStream {
     quote! {
         /// Returns a write guard for batch-modifying uniform parameters.
         ///
-        /// The guard automatically marks data as dirty on drop,
-        /// triggering GPU buffer synchronization.
+        /// On drop the guard compares the data with its previous contents
+        /// and bumps the buffer version once if anything changed, so any
+        /// number of field writes through one guard cost a single upload.
+        /// Uniform changes never touch the material version: the bind group
+        /// and pipeline are reused.
         pub fn uniforms_mut(&self) -> #cr::buffer::CheckedBufferGuard<'_, #uniforms_type> {
             self.uniforms.write_checked()
         }
@@ -71,7 +71,7 @@ mod parse;
 /// 2. **TextureSet struct** — `{Name}TextureSet` containing all texture slots
 /// 3. **Material struct** — Rewritten with `CpuBuffer`, `RwLock`, `AtomicU64` internals
 /// 4. **Constructor** — `from_uniforms(uniforms) -> Self`
-/// 5. **Settings API** — `set_alpha_mode`, `set_side`, `set_depth_test`, `set_depth_write`
+/// 5. **Settings API** — `set_alpha_mode`, `set_side`, `set_depth_test`, `set_depth_write`, `set_wireframe`, `set_flat_shading`
 /// 6. **Uniform accessors** — Per-field `set_xxx` / `xxx` with double-check locking
 /// 7. **Texture accessors** — Per-slot `set_xxx`, `xxx`, `configure_xxx`
 /// 8. **Clone impl** — Deep clone with atomic version snapshot
@@ -11,6 +11,7 @@
 
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
 
+use crate::core::GpuProfiler;
 use crate::settings::{RenderPath, RendererInitConfig, RendererSettings};
 use myth_core::{Error, PlatformError, Result};
 
@@ -55,11 +56,44 @@ pub struct WgpuContext {
 
     pub msaa_samples: u32,
 
+    /// Multi-sampling features of the attachment formats (HDR color, surface
+    /// color and depth): sample counts all of them support, plus
+    /// `MULTISAMPLE_RESOLVE` when every color format can be resolved. Used
+    /// to clamp requested MSAA sample counts to what the adapter supports.
+    pub msaa_format_features: wgpu::TextureFormatFeatureFlags,
+
+    /// Downlevel capabilities of the adapter (compute, indirect draws, ...).
+    pub downlevel_flags: wgpu::DownlevelFlags,
+
+    /// Adapter and driver identity, used to key the on-disk pipeline cache.
+    pub adapter_info: wgpu::AdapterInfo,
+
     pub anisotropy_clamp: u16,
 
     /// The active render path. Stored for runtime branching in the frame graph.
     pub render_path: RenderPath,
 
+    /// Global wireframe override, mirrored from [`RendererSettings::wireframe`].
+    pub wireframe: bool,
+
+    /// Barycentric wireframe override, mirrored from
+    /// [`RendererSettings::barycentric_wireframe`]. Check
+    /// [`uses_polygon_mode_line`](Self::uses_polygon_mode_line) before use.
+    pub barycentric_wireframe: bool,
+
+    /// GPU frustum culling request, mirrored from [`RendererSettings::gpu_culling`].
+    /// Check [`gpu_culling_active`](Self::gpu_culling_active) before use.
+    pub gpu_culling: bool,
+
+    /// Clustered lighting request, mirrored from
+    /// [`RendererSettings::clustered_lighting`]. Check
+    /// [`clustered_lighting_active`](Self::clustered_lighting_active) before use.
+    pub clustered_lighting: bool,
+
+    /// Background material pipeline compilation, mirrored from
+    /// [`RendererSettings::async_pipeline_compilation`].
+    pub async_pipelines: bool,
+
     /// Version counter for pipeline-affecting settings (HDR, MSAA, RenderPath).
     /// Incremented when these settings change, used to invalidate L1 pipeline cache.
     pub pipeline_settings_version: u64,
@@ -79,6 +113,170 @@ impl WgpuContext {
         required_limits
     }
 
+    /// Adds optional features the renderer uses when the adapter offers
+    /// them (line polygon mode for wireframes, timestamp queries for pass
+    /// profiling, non-zero first instances for GPU-culled indirect draws,
+    /// copyable depth-stencil for stencil materials, driver pipeline caches)
+    /// to the user-required set.
+    fn requested_features_for_adapter(
+        init_config: &RendererInitConfig,
+        adapter: &wgpu::Adapter,
+    ) -> wgpu::Features {
+        let optional = wgpu::Features::POLYGON_MODE_LINE
+            | wgpu::Features::INDIRECT_FIRST_INSTANCE
+            | wgpu::Features::MULTI_DRAW_INDIRECT_COUNT
+            | wgpu::Features::DEPTH32FLOAT_STENCIL8
+            | wgpu::Features::PIPELINE_CACHE
+            | GpuProfiler::REQUIRED_FEATURES;
+        init_config.required_features | (adapter.features() & optional)
+    }
+
+    /// Returns `true` if the device can rasterize polygons as lines.
+    ///
+    /// Without it, wireframes are drawn by a barycentric-coordinate shader
+    /// on a de-indexed copy of the geometry.
+    #[inline]
+    #[must_use]
+    pub fn supports_polygon_mode_line(&self) -> bool {
+        self.device
+            .features()
+            .contains(wgpu::Features::POLYGON_MODE_LINE)
+    }
+
+    /// Returns `true` if wireframes are drawn with line polygon mode, i.e.
+    /// the device supports it and the barycentric fallback isn't forced.
+    #[inline]
+    #[must_use]
+    pub fn uses_polygon_mode_line(&self) -> bool {
+        self.supports_polygon_mode_line() && !self.barycentric_wireframe
+    }
+
+    /// Returns `true` if the backend can hand out its compiled pipeline
+    /// state for persisting between runs (currently Vulkan only).
+    #[inline]
+    #[must_use]
+    pub fn supports_pipeline_cache(&self) -> bool {
+        self.device
+            .features()
+            .contains(wgpu::Features::PIPELINE_CACHE)
+    }
+
+    /// Depth format with a stencil aspect to switch to when a material
+    /// uses stencil state.
+    ///
+    /// Keeps the current format if it already has one, and prefers
+    /// `Depth32FloatStencil8` (same precision, copyable depth) over the
+    /// universally available `Depth24PlusStencil8`.
+    #[must_use]
+    pub fn stencil_depth_format(&self) -> wgpu::TextureFormat {
+        if self.depth_format.has_stencil_aspect() {
+            self.depth_format
+        } else if self
+            .device
+            .features()
+            .contains(wgpu::Features::DEPTH32FLOAT_STENCIL8)
+        {
+            wgpu::TextureFormat::Depth32FloatStencil8
+        } else {
+            wgpu::TextureFormat::Depth24PlusStencil8
+        }
+    }
+
+    /// Returns `true` if indirect draws may start at a non-zero instance.
+    ///
+    /// Without it, GPU culling leaves draws with such an instance range to
+    /// direct (unculled) drawing.
+    #[inline]
+    #[must_use]
+    pub fn supports_indirect_first_instance(&self) -> bool {
+        self.device
+            .features()
+            .contains(wgpu::Features::INDIRECT_FIRST_INSTANCE)
+    }
+
+    /// Returns `true` if GPU culling is requested and the device can run
+    /// compute shaders and indirect draws.
+    #[inline]
+    #[must_use]
+    pub fn gpu_culling_active(&self) -> bool {
+        self.gpu_culling
+            && self.downlevel_flags.contains(
+                wgpu::DownlevelFlags::COMPUTE_SHADERS | wgpu::DownlevelFlags::INDIRECT_EXECUTION,
+            )
+    }
+
+    /// Returns `true` if multi-draws can read their draw count from a GPU
+    /// buffer.
+    ///
+    /// Without it, draws merged by GPU culling issue every slot of the
+    /// merged run, leaving culled slots empty.
+    #[inline]
+    #[must_use]
+    pub fn supports_multi_draw_indirect_count(&self) -> bool {
+        self.device
+            .features()
+            .contains(wgpu::Features::MULTI_DRAW_INDIRECT_COUNT)
+    }
+
+    /// Returns `true` if the device can run GPU-driven draws: vertex shaders
+    /// read object data from storage buffers, indexed by an instance that
+    /// indirect draws may start at a non-zero offset.
+    #[inline]
+    #[must_use]
+    pub fn supports_gpu_driven_draws(&self) -> bool {
+        self.supports_indirect_first_instance()
+            && self
+                .downlevel_flags
+                .contains(wgpu::DownlevelFlags::VERTEX_STORAGE)
+    }
+
+    /// Returns `true` if GPU-culled draws read their object data by
+    /// instance instead of a per-draw dynamic offset.
+    #[inline]
+    #[must_use]
+    pub fn gpu_driven_active(&self) -> bool {
+        self.gpu_culling_active() && self.supports_gpu_driven_draws()
+    }
+
+    /// Returns `true` if clustered lighting is requested and the device can
+    /// run compute shaders.
+    #[inline]
+    #[must_use]
+    pub fn clustered_lighting_active(&self) -> bool {
+        self.clustered_lighting
+            && self
+                .downlevel_flags
+                .contains(wgpu::DownlevelFlags::COMPUTE_SHADERS)
+    }
+
+    /// Combines the format features of the attachments that take part in
+    /// multi-sampled rendering; see [`combine_msaa_features`].
+    fn msaa_format_features_for_adapter(
+        adapter: &wgpu::Adapter,
+        color_formats: &[wgpu::TextureFormat],
+        depth_format: wgpu::TextureFormat,
+    ) -> wgpu::TextureFormatFeatureFlags {
+        let color: Vec<_> = color_formats
+            .iter()
+            .map(|&format| adapter.get_texture_format_features(format).flags)
+            .collect();
+        combine_msaa_features(
+            &color,
+            adapter.get_texture_format_features(depth_format).flags,
+        )
+    }
+
+    /// Clamps a requested MSAA sample count to the largest count supported
+    /// by every multi-sampled attachment format, falling back to `1`.
+    ///
+    /// Switching to the returned count at runtime bumps
+    /// [`pipeline_settings_version`](Self::pipeline_settings_version) in
+    /// the renderer so that pipelines keyed on sample count are rebuilt.
+    #[must_use]
+    pub fn supported_msaa_samples(&self, requested: u32) -> u32 {
+        clamp_msaa_samples(self.msaa_format_features, requested)
+    }
+
     pub async fn new<W>(
         window: W,
         init_config: &RendererInitConfig,
@@ -135,11 +333,16 @@ impl WgpuContext {
         log::debug!("Selected Surface Format: {surface_format:?}");
 
         let required_limits = Self::requested_limits_for_adapter(init_config, &adapter);
+        let msaa_format_features = Self::msaa_format_features_for_adapter(
+            &adapter,
+            &[crate::HDR_TEXTURE_FORMAT, surface_format.add_srgb_suffix()],
+            init_config.depth_format,
+        );
 
         let (device, queue) = adapter
             .request_device(&wgpu::DeviceDescriptor {
                 label: None,
-                required_features: init_config.required_features,
+                required_features: Self::requested_features_for_adapter(init_config, &adapter),
                 required_limits,
                 memory_hints: wgpu::MemoryHints::Performance,
                 ..Default::default()
@@ -181,8 +384,16 @@ impl WgpuContext {
             depth_format: init_config.depth_format,
             surface_view_format: view_format,
             msaa_samples: 1,
+            msaa_format_features,
+            downlevel_flags: adapter.get_downlevel_capabilities().flags,
+            adapter_info: info,
             anisotropy_clamp: settings.anisotropy_clamp,
             render_path: settings.path,
+            wireframe: settings.wireframe,
+            barycentric_wireframe: settings.barycentric_wireframe,
+            gpu_culling: settings.gpu_culling,
+            clustered_lighting: settings.clustered_lighting,
+            async_pipelines: settings.async_pipeline_compilation,
             pipeline_settings_version: 0,
         })
     }
@@ -230,11 +441,17 @@ impl WgpuContext {
         log::debug!("Device: {}", info.name);
 
         let required_limits = Self::requested_limits_for_adapter(init_config, &adapter);
+        let view_format = target_format.unwrap_or(wgpu::TextureFormat::Rgba8UnormSrgb);
+        let msaa_format_features = Self::msaa_format_features_for_adapter(
+            &adapter,
+            &[crate::HDR_TEXTURE_FORMAT, view_format],
+            init_config.depth_format,
+        );
 
         let (device, queue) = adapter
             .request_device(&wgpu::DeviceDescriptor {
                 label: Some("Headless Device"),
-                required_features: init_config.required_features,
+                required_features: Self::requested_features_for_adapter(init_config, &adapter),
                 required_limits,
                 memory_hints: wgpu::MemoryHints::Performance,
                 ..Default::default()
@@ -244,8 +461,6 @@ impl WgpuContext {
                 Error::Render(myth_core::RenderError::RequestDeviceFailed(e.to_string()))
             })?;
 
-        let view_format = target_format.unwrap_or(wgpu::TextureFormat::Rgba8UnormSrgb);
-
         let headless_texture = Self::create_headless_texture(&device, width, height, view_format);
 
         Ok(Self {
@@ -259,8 +474,16 @@ impl WgpuContext {
             depth_format: init_config.depth_format,
             surface_view_format: view_format,
             msaa_samples: 1,
+            msaa_format_features,
+            downlevel_flags: adapter.get_downlevel_capabilities().flags,
+            adapter_info: info,
             anisotropy_clamp: settings.anisotropy_clamp,
             render_path: settings.path,
+            wireframe: settings.wireframe,
+            barycentric_wireframe: settings.barycentric_wireframe,
+            gpu_culling: settings.gpu_culling,
+            clustered_lighting: settings.clustered_lighting,
+            async_pipelines: settings.async_pipeline_compilation,
             pipeline_settings_version: 0,
         })
     }
@@ -376,3 +599,67 @@ impl WgpuContext {
         self.surface.is_none()
     }
 }
+
+/// Intersects the sample counts of the color formats and the depth format.
+///
+/// Only the color attachments are resolved, so `MULTISAMPLE_RESOLVE` comes
+/// from them alone: depth formats never report it (e.g. on WebGPU and
+/// DX12), and requiring it there would disable MSAA entirely.
+pub(crate) fn combine_msaa_features(
+    color: &[wgpu::TextureFormatFeatureFlags],
+    depth: wgpu::TextureFormatFeatureFlags,
+) -> wgpu::TextureFormatFeatureFlags {
+    let color = color
+        .iter()
+        .fold(wgpu::TextureFormatFeatureFlags::all(), |acc, &flags| {
+            acc & flags
+        });
+    color & (depth | wgpu::TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE)
+}
+
+/// Returns the largest sample count `<= requested` that `features` allows
+/// for both rendering and resolving, or `1` when multi-sampling is
+/// unavailable.
+pub(crate) fn clamp_msaa_samples(features: wgpu::TextureFormatFeatureFlags, requested: u32) -> u32 {
+    if requested <= 1 || !features.contains(wgpu::TextureFormatFeatureFlags::MULTISAMPLE_RESOLVE) {
+        return 1;
+    }
+    [16, 8, 4, 2]
+        .into_iter()
+        .find(|&count| count <= requested && features.sample_count_supported(count))
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clamp_msaa_samples, combine_msaa_features};
+    use wgpu::TextureFormatFeatureFlags as Flags;
+
+    #[test]
+    fn msaa_samples_clamp_to_supported_counts() {
+        let four_x = Flags::MULTISAMPLE_X4 | Flags::MULTISAMPLE_RESOLVE;
+        assert_eq!(clamp_msaa_samples(four_x, 1), 1);
+        assert_eq!(clamp_msaa_samples(four_x, 4), 4);
+        assert_eq!(clamp_msaa_samples(four_x, 8), 4);
+        assert_eq!(clamp_msaa_samples(four_x, 3), 1);
+        assert_eq!(clamp_msaa_samples(Flags::all(), 6), 4);
+    }
+
+    #[test]
+    fn msaa_requires_resolve_support() {
+        assert_eq!(clamp_msaa_samples(Flags::MULTISAMPLE_X4, 4), 1);
+    }
+
+    #[test]
+    fn depth_format_only_limits_sample_counts() {
+        let color = Flags::MULTISAMPLE_X4 | Flags::MULTISAMPLE_X8 | Flags::MULTISAMPLE_RESOLVE;
+        // Depth formats support multi-sampling but never resolve.
+        let depth = Flags::MULTISAMPLE_X4;
+        let combined = combine_msaa_features(&[color, color], depth);
+        assert_eq!(clamp_msaa_samples(combined, 8), 4);
+
+        // A color format that cannot resolve still disables MSAA.
+        let combined = combine_msaa_features(&[color, Flags::MULTISAMPLE_X4], depth);
+        assert_eq!(clamp_msaa_samples(combined, 4), 1);
+    }
+}
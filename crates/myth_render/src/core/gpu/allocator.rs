@@ -4,6 +4,13 @@
 //! staging vector and a [`GpuBufferHandle`] pointing directly into the
 //! `ResourceManager`'s SlotMap arena, bypassing the `CpuBuffer` + `RwLock`
 //! intermediary for zero-overhead GPU uploads.
+//!
+//! On devices that can read storage buffers from vertex shaders, the model
+//! buffer is also bound as a storage array, next to an instance table that
+//! maps `instance_index` to a model slot. GPU-driven draws read their
+//! object data through the table (see `core/object_data.wgsl`) instead of a
+//! per-draw dynamic offset; the table itself is written by the GPU culling
+//! pass.
 
 use std::num::NonZero;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -26,22 +33,40 @@ pub struct ModelBufferAllocator {
     capacity: usize,
     logical_ref: BufferRef,
     cached_gpu_handle: AtomicU64,
+    /// Instance table of GPU-driven draws, `None` without storage access.
+    instance_ref: Option<BufferRef>,
+    cached_instance_handle: AtomicU64,
 }
 
 impl ModelBufferAllocator {
+    /// Instance table entries per model slot. An object is drawn at most
+    /// twice on the main camera: in the depth prepass and its colour pass.
+    pub const INSTANCES_PER_MODEL: usize = 2;
+
+    /// Creates the allocator. With `storage`, the model buffer can also be
+    /// bound as a storage buffer and an instance table is allocated.
     #[must_use]
-    pub fn new() -> Self {
+    pub fn new(storage: bool) -> Self {
         let initial_capacity = 4096;
-        let logical_ref = BufferRef::empty(
-            wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-            Some("GlobalModelBuffer"),
-        );
+        let mut usage = wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST;
+        if storage {
+            usage |= wgpu::BufferUsages::STORAGE;
+        }
+        let logical_ref = BufferRef::empty(usage, Some("GlobalModelBuffer"));
+        let instance_ref = storage.then(|| {
+            BufferRef::empty(
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+                Some("GlobalInstanceTable"),
+            )
+        });
 
         Self {
             host_data: Vec::with_capacity(initial_capacity),
             capacity: initial_capacity,
             logical_ref,
             cached_gpu_handle: AtomicU64::new(0),
+            instance_ref,
+            cached_instance_handle: AtomicU64::new(0),
         }
     }
 
@@ -54,6 +79,16 @@ impl ModelBufferAllocator {
         &self.logical_ref
     }
 
+    /// The instance table of GPU-driven draws, when supported.
+    pub fn instance_handle(&self) -> Option<&BufferRef> {
+        self.instance_ref.as_ref()
+    }
+
+    /// Number of `u32` entries the instance table holds.
+    pub fn instance_capacity(&self) -> usize {
+        self.capacity * Self::INSTANCES_PER_MODEL
+    }
+
     pub fn uniform_stride() -> NonZero<u64> {
         std::mem::size_of::<DynamicModelUniforms>()
             .try_into()
@@ -89,52 +124,82 @@ impl ModelBufferAllocator {
         buffer_index: &mut rustc_hash::FxHashMap<u64, GpuBufferHandle>,
         frame_index: u64,
     ) -> bool {
-        let mut was_resized = false;
-
         let target_size = (self.capacity * std::mem::size_of::<DynamicModelUniforms>()) as u64;
         let active_data = bytemuck::cast_slice(&self.host_data);
 
-        let handle_bits = self.cached_gpu_handle.load(Ordering::Acquire);
+        let (handle, mut was_resized) = Self::ensure_gpu_buffer(
+            device,
+            gpu_buffers,
+            buffer_index,
+            &self.cached_gpu_handle,
+            &self.logical_ref,
+            target_size,
+            frame_index,
+        );
+        if !active_data.is_empty()
+            && let Some(gpu_buf) = gpu_buffers.get(handle)
+        {
+            queue.write_buffer(&gpu_buf.buffer, 0, active_data);
+        }
 
-        let handle = if handle_bits == 0 {
-            was_resized = true;
+        // The table is filled by the GPU culling pass; only its size
+        // follows the model capacity.
+        if let Some(instance_ref) = &self.instance_ref {
+            let (_, resized) = Self::ensure_gpu_buffer(
+                device,
+                gpu_buffers,
+                buffer_index,
+                &self.cached_instance_handle,
+                instance_ref,
+                (self.instance_capacity() * std::mem::size_of::<u32>()) as u64,
+                frame_index,
+            );
+            was_resized |= resized;
+        }
+
+        was_resized
+    }
+
+    /// Creates the GPU buffer behind `logical_ref` on first use, or grows it
+    /// to `target_size`. Returns its handle and whether it was (re)allocated.
+    fn ensure_gpu_buffer(
+        device: &wgpu::Device,
+        gpu_buffers: &mut slotmap::SlotMap<GpuBufferHandle, GpuBuffer>,
+        buffer_index: &mut rustc_hash::FxHashMap<u64, GpuBufferHandle>,
+        cached_handle: &AtomicU64,
+        logical_ref: &BufferRef,
+        target_size: u64,
+        frame_index: u64,
+    ) -> (GpuBufferHandle, bool) {
+        let handle_bits = cached_handle.load(Ordering::Acquire);
+
+        if handle_bits == 0 {
             // First: allocate a new GPU buffer and cache the handle
             let mut gpu_buf = GpuBuffer::with_capacity(
                 device,
                 target_size,
-                wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
-                Some("GlobalModelBuffer"),
+                logical_ref.usage,
+                logical_ref.label(),
             );
-
-            // If there is data in the first frame, upload it immediately
-            if !active_data.is_empty() {
-                queue.write_buffer(&gpu_buf.buffer, 0, active_data);
-            }
-
             gpu_buf.last_used_frame = frame_index;
             let h = gpu_buffers.insert(gpu_buf);
-            buffer_index.insert(self.logical_ref.id(), h);
-            self.cached_gpu_handle.store(h.to_bits(), Ordering::Release);
-            h
-        } else {
-            GpuBufferHandle::from_bits(handle_bits).unwrap()
-        };
+            buffer_index.insert(logical_ref.id(), h);
+            cached_handle.store(h.to_bits(), Ordering::Release);
+            return (h, true);
+        }
+
+        let handle = GpuBufferHandle::from_bits(handle_bits).unwrap();
+        let mut was_resized = false;
 
         // Check if the existing buffer needs to be resized (capacity expansion)
-        if handle_bits != 0
-            && let Some(gpu_buf) = gpu_buffers.get_mut(handle)
-        {
+        if let Some(gpu_buf) = gpu_buffers.get_mut(handle) {
             if gpu_buf.size < target_size {
                 gpu_buf.resize(device, target_size);
                 was_resized = true;
             }
-
-            if !active_data.is_empty() {
-                queue.write_buffer(&gpu_buf.buffer, 0, active_data);
-            }
             gpu_buf.last_used_frame = frame_index;
         }
-        was_resized
+        (handle, was_resized)
     }
 
     pub fn host_data(&self) -> &[DynamicModelUniforms] {
@@ -162,6 +227,6 @@ impl ModelBufferAllocator {
 
 impl Default for ModelBufferAllocator {
     fn default() -> Self {
-        Self::new()
+        Self::new(false)
     }
 }
@@ -8,6 +8,7 @@ use wgpu::ShaderStages;
 
 use myth_assets::{AssetServer, TextureHandle};
 use myth_resources::Mesh;
+use myth_resources::buffer::BufferRef;
 use myth_resources::geometry::Geometry;
 use myth_resources::texture::TextureSource;
 use myth_resources::uniforms::DynamicModelUniforms;
@@ -150,13 +151,16 @@ impl ResourceManager {
         mesh.update_morph_uniforms();
         let (_, morph_result) = self.ensure_buffer(&mesh.morph_uniforms);
         self.prepare_geometry(assets, mesh.geometry);
-        self.prepare_material(assets, mesh.material);
+        for material in mesh.materials() {
+            self.prepare_material(assets, material);
+        }
 
         let geometry = assets.geometries.get(mesh.geometry)?;
 
         // === Collect phase: gather all resource IDs ===
         let mut current_ids = super::ResourceIdSet::with_capacity(6);
         current_ids.push(self.model_allocator.buffer_handle().id());
+        current_ids.push_optional(self.model_allocator.instance_handle().map(BufferRef::id));
         current_ids.push(morph_result.resource_id);
         current_ids.push_optional(skeleton.map(|s| s.joint_matrices.handle().id));
         current_ids.push_optional(skeleton.map(|s| s.prev_joint_matrices.handle().id));
@@ -194,6 +198,26 @@ impl ResourceManager {
             min_binding_size,
             ShaderStages::VERTEX | ShaderStages::FRAGMENT,
         );
+        // GPU-driven draws read the same model data by instance, see
+        // `core/object_data.wgsl`.
+        if let Some(instance_ref) = self.model_allocator.instance_handle() {
+            builder.add_storage_buffer(
+                "object_models",
+                model_buffer_ref,
+                None,
+                true,
+                ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+                Some(WgslStructName::Name("vec4<f32>".into())),
+            );
+            builder.add_storage_buffer(
+                "object_instances",
+                instance_ref,
+                None,
+                true,
+                ShaderStages::VERTEX | ShaderStages::FRAGMENT,
+                Some(WgslStructName::Name("u32".into())),
+            );
+        }
         mesh.define_bindings(&mut builder);
         geometry.define_bindings(&mut builder);
 
@@ -449,6 +473,8 @@ impl ResourceManager {
         let (_, camera_result) = self.ensure_buffer(render_state.uniforms());
         let (_, env_result) = self.ensure_buffer(&scene.uniforms_buffer);
         let (_, light_result) = self.ensure_buffer(&scene.light_storage_buffer);
+        let (_, light_probe_result) = self.ensure_buffer(&scene.light_probe_buffer);
+        let (_, light_cluster_result) = self.ensure_buffer(&scene.light_cluster_buffer);
         let (_, scene_uniform_result) = self.ensure_buffer(&scene.uniforms_buffer);
 
         // Resolve environment texture IDs from GpuEnvironment cache.
@@ -473,15 +499,25 @@ impl ResourceManager {
             .brdf_lut_view_id
             .unwrap_or(self.system_textures.black_2d.id());
 
+        // The probe chosen by resolve_reflection_probe, if any.
+        let probe_map_id = self
+            .bound_reflection_probe(scene.id())
+            .map_or(self.system_textures.black_cube.id(), |gpu_probe| {
+                gpu_probe.pmrem_view.id()
+            });
+
         // === Collect: gather all resource IDs ===
-        let mut current_ids = super::ResourceIdSet::with_capacity(8);
+        let mut current_ids = super::ResourceIdSet::with_capacity(10);
         current_ids.push(camera_result.resource_id);
         current_ids.push(env_result.resource_id);
         current_ids.push(light_result.resource_id);
+        current_ids.push(light_probe_result.resource_id);
+        current_ids.push(light_cluster_result.resource_id);
         current_ids.push(scene_uniform_result.resource_id);
         current_ids.push(processed_env_map_id);
         current_ids.push(pmrem_map_id);
         current_ids.push(brdf_lut_id);
+        current_ids.push(probe_map_id);
 
         let state_id = Self::compute_global_state_key(render_state.id, scene.id());
 
@@ -552,7 +588,7 @@ impl ResourceManager {
         scene: &'a Scene,
     ) {
         use myth_resources::WgslStructName;
-        use myth_resources::uniforms::{EnvironmentUniforms, GpuLightStorage};
+        use myth_resources::uniforms::{EnvironmentUniforms, GpuLightProbe, GpuLightStorage};
 
         // Environment Uniforms
         builder.add_uniform_buffer(
@@ -577,6 +613,26 @@ impl ResourceManager {
             Some(WgslStructName::Generator(GpuLightStorage::wgsl_struct_def)),
         );
 
+        // Baked light probe SH coefficients
+        builder.add_storage_buffer(
+            "light_probes",
+            &scene.light_probe_buffer.handle(),
+            None,
+            true,
+            wgpu::ShaderStages::FRAGMENT,
+            Some(WgslStructName::Generator(GpuLightProbe::wgsl_struct_def)),
+        );
+
+        // Per-cluster light bit masks, written by the light cluster pass
+        builder.add_storage_buffer(
+            "light_clusters",
+            &scene.light_cluster_buffer.handle(),
+            None,
+            true,
+            wgpu::ShaderStages::FRAGMENT,
+            Some(WgslStructName::Name("u32".into())),
+        );
+
         // Resolve env_map from GpuEnvironment cache
         let env_map_source = if matches!(
             scene.background.mode,
@@ -625,6 +681,19 @@ impl ResourceManager {
             wgpu::ShaderStages::FRAGMENT,
         );
 
+        // Resolve probe_map from the bound reflection probe
+        let probe_source = self.bound_reflection_probe(scene.id()).map(|gpu_probe| {
+            TextureSource::Attachment(gpu_probe.pmrem_view.id(), wgpu::TextureViewDimension::Cube)
+        });
+
+        builder.add_texture(
+            "probe_map",
+            probe_source,
+            wgpu::TextureSampleType::Float { filterable: true },
+            wgpu::TextureViewDimension::Cube,
+            wgpu::ShaderStages::FRAGMENT,
+        );
+
         // Resolve brdf_lut from ResourceManager
         let brdf_lut_source = self
             .brdf_lut_view_id
@@ -108,7 +108,7 @@ fn resolved_procedural_starbox_hash(
                         binding.view_id.hash(&mut hasher);
                     }
                 }
-                ResourceState::Pending | ResourceState::Unknown => {
+                ResourceState::Pending | ResourceState::Streaming | ResourceState::Unknown => {
                     0u8.hash(&mut hasher);
                 }
             }
@@ -262,7 +262,10 @@ impl ResourceManager {
 
             if let TextureSource::Asset(handle) = &source {
                 let state = self.prepare_texture(assets, *handle);
-                source_ready = !matches!(state, ResourceState::Pending | ResourceState::Unknown);
+                source_ready = !matches!(
+                    state,
+                    ResourceState::Pending | ResourceState::Streaming | ResourceState::Unknown
+                );
 
                 if source_ready && let Some(tex) = assets.textures.get(*handle) {
                     source_version = source_version.wrapping_shl(32)
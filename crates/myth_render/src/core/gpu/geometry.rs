@@ -15,6 +15,10 @@ use super::ResourceManager;
 pub struct GpuGeometry {
     pub layout_info: GeneratedVertexLayout,
     pub layout_id: u64,
+    /// Same buffers as `layout_info`, but stepped per instance so that each
+    /// vertex can be expanded into a quad (point sprites).
+    pub point_sprite_layout_info: GeneratedVertexLayout,
+    pub point_sprite_layout_id: u64,
     pub vertex_buffers: Vec<wgpu::Buffer>,
     pub vertex_buffer_ids: Vec<u64>,
     pub index_buffer: Option<(wgpu::Buffer, wgpu::IndexFormat, u32, u64)>,
@@ -25,6 +29,15 @@ pub struct GpuGeometry {
     pub last_used_frame: u64,
 }
 
+/// De-indexed copy of a geometry with a `barycentric` attribute, used to
+/// draw wireframes on devices without `POLYGON_MODE_LINE`.
+pub struct WireframeGeometry {
+    pub geometry: Geometry,
+    pub gpu: GpuGeometry,
+    source_structure_version: u64,
+    source_data_version: u64,
+}
+
 /// Geometry preparation result
 ///
 /// Vertex Buffer IDs are used for Pipeline cache validation and do not affect Object `BindGroup`
@@ -111,11 +124,68 @@ impl ResourceManager {
         }
     }
 
+    /// Prepares the barycentric wireframe copy of `geometry`, rebuilding it
+    /// when the source geometry changed.
+    ///
+    /// Returns `false` if the geometry cannot be drawn this way (not a
+    /// triangle list, or no CPU-side vertex data).
+    pub(crate) fn prepare_wireframe_geometry(
+        &mut self,
+        geometry: &Geometry,
+        handle: GeometryHandle,
+    ) -> bool {
+        let up_to_date = self.wireframe_geometries.get(handle).is_some_and(|w| {
+            w.source_structure_version == geometry.structure_version()
+                && w.source_data_version == geometry.data_version()
+        });
+
+        if !up_to_date {
+            let Some(wireframe) = geometry.to_barycentric_wireframe() else {
+                self.wireframe_geometries.remove(handle);
+                return false;
+            };
+            for attr in wireframe.attributes().values() {
+                self.prepare_attribute(attr);
+            }
+            let gpu = self.build_gpu_geometry(&wireframe);
+            self.wireframe_geometries.insert(
+                handle,
+                WireframeGeometry {
+                    geometry: wireframe,
+                    gpu,
+                    source_structure_version: geometry.structure_version(),
+                    source_data_version: geometry.data_version(),
+                },
+            );
+            return true;
+        }
+
+        if let Some(wireframe) = self.wireframe_geometries.get_mut(handle) {
+            wireframe.gpu.last_used_frame = self.frame_index;
+        }
+        true
+    }
+
+    pub fn get_wireframe_geometry(&self, handle: GeometryHandle) -> Option<&WireframeGeometry> {
+        self.wireframe_geometries.get(handle)
+    }
+
     fn create_gpu_geometry(&mut self, geometry: &Geometry, handle: GeometryHandle) {
+        let gpu_geo = self.build_gpu_geometry(geometry);
+        self.gpu_geometries.insert(handle, gpu_geo);
+    }
+
+    fn build_gpu_geometry(&mut self, geometry: &Geometry) -> GpuGeometry {
         let layout_info = crate::pipeline::vertex::generate_vertex_layout(geometry);
 
         let layout_id = self.get_or_create_vertex_layout_id(&layout_info);
 
+        let mut point_sprite_layout_info = layout_info.clone();
+        for buffer in &mut point_sprite_layout_info.buffers {
+            buffer.step_mode = wgpu::VertexStepMode::Instance;
+        }
+        let point_sprite_layout_id = self.get_or_create_vertex_layout_id(&point_sprite_layout_info);
+
         let mut vertex_buffers = Vec::new();
         let mut vertex_buffer_ids = Vec::new();
 
@@ -152,9 +222,11 @@ impl ResourceManager {
             }
         }
 
-        let gpu_geo = GpuGeometry {
+        GpuGeometry {
             layout_info,
             layout_id,
+            point_sprite_layout_info,
+            point_sprite_layout_id,
             vertex_buffers,
             vertex_buffer_ids,
             index_buffer,
@@ -163,9 +235,7 @@ impl ResourceManager {
             version: geometry.structure_version(),
             last_data_version: geometry.data_version(),
             last_used_frame: self.frame_index,
-        };
-
-        self.gpu_geometries.insert(handle, gpu_geo);
+        }
     }
 
     pub fn get_geometry(&self, handle: GeometryHandle) -> Option<&GpuGeometry> {
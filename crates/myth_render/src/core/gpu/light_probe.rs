@@ -0,0 +1,132 @@
+use glam::UVec4;
+use wgpu::TextureViewDimension;
+
+use myth_scene::Scene;
+use myth_scene::light_probe::LightProbeVolume;
+
+use crate::core::gpu::Tracked;
+
+use super::ResourceManager;
+
+/// Persistent GPU state of a scene's light probe grid.
+///
+/// Each probe's capture frames render into the six faces of
+/// `capture_texture`, which is reused from probe to probe; the frame
+/// capturing the last face projects it onto the probe's entry in the
+/// scene's light probe buffer.
+#[derive(Debug)]
+pub struct GpuLightProbeVolume {
+    pub capture_texture: wgpu::Texture,
+    pub capture_view: Tracked<wgpu::TextureView>,
+    /// [`LightProbeVolume::bake_version`] of the last completed bake, 0
+    /// before the first.
+    pub baked_version: u64,
+    pub last_used_frame: u64,
+}
+
+impl ResourceManager {
+    /// Returns `true` if the scene's probes have no up-to-date bake.
+    #[must_use]
+    pub fn light_probes_need_bake(&self, scene_id: u32, volume: &LightProbeVolume) -> bool {
+        self.light_probe_volumes
+            .get(&scene_id)
+            .is_none_or(|gpu_volume| gpu_volume.baked_version != volume.bake_version())
+    }
+
+    /// Creates the scene's capture cubemap, or recreates it after a
+    /// resolution change.
+    pub fn ensure_light_probe_capture(&mut self, scene_id: u32, resolution: u32) {
+        let resolution = resolution.max(1);
+        if let Some(gpu_volume) = self.light_probe_volumes.get_mut(&scene_id) {
+            gpu_volume.last_used_frame = self.frame_index;
+            if gpu_volume.capture_texture.width() == resolution {
+                return;
+            }
+        }
+
+        let capture_texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Light Probe Capture Cube"),
+            size: wgpu::Extent3d {
+                width: resolution,
+                height: resolution,
+                depth_or_array_layers: 6,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: crate::HDR_TEXTURE_FORMAT,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let capture_view =
+            Tracked::new(capture_texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("Light Probe Capture Cube View"),
+                dimension: Some(TextureViewDimension::Cube),
+                ..Default::default()
+            }));
+
+        let baked_version = self
+            .light_probe_volumes
+            .get(&scene_id)
+            .map_or(0, |old| old.baked_version);
+        self.light_probe_volumes.insert(
+            scene_id,
+            GpuLightProbeVolume {
+                capture_texture,
+                capture_view,
+                baked_version,
+                last_used_frame: self.frame_index,
+            },
+        );
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn gpu_light_probe_volume(&self, scene_id: u32) -> Option<&GpuLightProbeVolume> {
+        self.light_probe_volumes.get(&scene_id)
+    }
+
+    /// Records that the bake for `version` has been submitted.
+    pub fn mark_light_probes_baked(&mut self, scene_id: u32, version: u64) {
+        if let Some(gpu_volume) = self.light_probe_volumes.get_mut(&scene_id) {
+            gpu_volume.baked_version = version;
+        }
+    }
+
+    /// Writes the parameters of the scene's baked probe grid into the
+    /// environment uniforms.
+    ///
+    /// The grid is bound only while its bake is current, as changing the
+    /// grid clears the scene's coefficient buffer. With
+    /// `allow_binding` false, as while a probe is being captured, no grid
+    /// is bound.
+    pub fn resolve_light_probes(&mut self, scene: &Scene, allow_binding: bool) {
+        let mut bound = None;
+        if let Some(volume) = scene.light_probes()
+            && let Some(gpu_volume) = self.light_probe_volumes.get_mut(&scene.id())
+        {
+            gpu_volume.last_used_frame = self.frame_index;
+            if allow_binding && gpu_volume.baked_version == volume.bake_version() {
+                bound = Some(volume);
+            }
+        }
+
+        let mut uniforms = *scene.uniforms_buffer.read();
+        if let Some(volume) = bound {
+            let bounds = volume.bounds();
+            uniforms.light_probe_min = bounds.min;
+            uniforms.light_probe_max = bounds.max;
+            uniforms.light_probe_intensity = volume.intensity;
+            uniforms.light_probe_blend_distance = volume.blend_distance.max(0.0);
+            uniforms.light_probe_grid = volume.resolution().extend(1);
+        } else {
+            uniforms.light_probe_grid = UVec4::ZERO;
+        }
+
+        if *scene.uniforms_buffer.read() != uniforms {
+            *scene.uniforms_buffer.write() = uniforms;
+        }
+    }
+}
@@ -127,9 +127,21 @@ impl ResourceManager {
     ) -> (ResourceIdSet, bool) {
         let mut uniform_result = EnsureResult::existing(0);
 
+        // Uniform edits only bump the buffer version: the bytes are
+        // re-uploaded in place and the bind group is kept.
+        let uniform_ref = material.data.uniform_buffer();
+        let needs_upload = self
+            .buffer_index
+            .get(&uniform_ref.id())
+            .and_then(|&handle| self.gpu_buffers.get(handle))
+            .is_none_or(|gpu_buf| uniform_ref.version > gpu_buf.last_uploaded_version);
+        if needs_upload {
+            self.material_uniform_uploads += 1;
+        }
+
         // 2. Call with_uniform_bytes, passing a closure
         material.data.with_uniform_bytes(&mut |bytes| {
-            (_, uniform_result) = self.ensure_buffer_ref(&material.data.uniform_buffer(), bytes);
+            (_, uniform_result) = self.ensure_buffer_ref(&uniform_ref, bytes);
         });
 
         // Collect resource IDs
@@ -172,6 +184,8 @@ impl ResourceManager {
         material: &Material,
         resource_ids: ResourceIdSet,
     ) {
+        self.material_bind_group_rebuilds += 1;
+
         let mut builder = ResourceBuilder::new();
         material.define_bindings(&mut builder);
 
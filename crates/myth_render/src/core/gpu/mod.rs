@@ -9,6 +9,8 @@
 //! - material.rs: Material operations
 //! - binding.rs: `BindGroup` operations
 //! - allocator.rs: `ModelBufferAllocator`
+//! - `reflection_probe.rs`: Reflection probe cubemaps
+//! - `light_probe.rs`: Light probe capture and binding
 //! - `resource_ids.rs`: Resource ID tracking and change detection
 //!
 //! # Resource Management Architecture
@@ -24,8 +26,10 @@ mod binding;
 mod buffer;
 mod environment;
 mod geometry;
+mod light_probe;
 mod material;
 mod mipmap;
+mod reflection_probe;
 mod resource_ids;
 mod sampler_registry;
 mod system_textures;
@@ -39,14 +43,19 @@ use rustc_hash::FxHashMap;
 use slotmap::SecondaryMap;
 
 use myth_assets::{GeometryHandle, ImageHandle, MaterialHandle, TextureHandle};
+use myth_core::NodeHandle;
 
 pub(crate) use crate::core::gpu::buffer::GpuBuffer;
 pub use crate::core::gpu::buffer::GpuBufferHandle;
 pub(crate) use crate::core::gpu::environment::GpuEnvironment;
 pub(crate) use crate::core::gpu::environment::{BRDF_LUT_SIZE, CubeSourceType};
-pub(crate) use crate::core::gpu::geometry::GpuGeometry;
+pub(crate) use crate::core::gpu::geometry::{GpuGeometry, WireframeGeometry};
+pub(crate) use crate::core::gpu::light_probe::GpuLightProbeVolume;
 pub(crate) use crate::core::gpu::material::GpuMaterial;
-pub(crate) use crate::core::gpu::texture::{GpuImage, ResourceState, TextureBinding};
+pub(crate) use crate::core::gpu::reflection_probe::GpuReflectionProbe;
+pub(crate) use crate::core::gpu::texture::{
+    GpuImage, ResourceState, StreamingUpload, TextureBinding,
+};
 use crate::pipeline::vertex::VertexLayoutSignature;
 
 pub use crate::core::gpu::mipmap::MipmapGenerator;
@@ -108,8 +117,21 @@ pub struct ResourceManager {
     pub(crate) frame_index: u64,
 
     pub(crate) gpu_geometries: SecondaryMap<GeometryHandle, GpuGeometry>,
+    /// Barycentric wireframe copies, built on demand when line polygon mode
+    /// is unavailable.
+    pub(crate) wireframe_geometries: SecondaryMap<GeometryHandle, WireframeGeometry>,
     pub(crate) gpu_materials: SecondaryMap<MaterialHandle, GpuMaterial>,
     pub(crate) gpu_images: SecondaryMap<ImageHandle, GpuImage>,
+    /// Streaming textures whose base level is still being uploaded.
+    pub(crate) streaming_uploads: SecondaryMap<ImageHandle, StreamingUpload>,
+    /// Streaming upload budget in bytes per frame, and what is left of it.
+    pub(crate) texture_upload_budget: u64,
+    pub(crate) texture_upload_remaining: u64,
+
+    /// Material uniform buffers uploaded this frame.
+    pub(crate) material_uniform_uploads: u32,
+    /// Material bind groups built this frame.
+    pub(crate) material_bind_group_rebuilds: u32,
 
     pub(crate) global_states: FxHashMap<u64, GpuGlobalState>,
 
@@ -146,6 +168,14 @@ pub struct ResourceManager {
     pub(crate) brdf_lut_view_id: Option<u64>,
     pub(crate) needs_brdf_compute: bool,
 
+    // === Reflection Probes ===
+    pub(crate) reflection_probes: FxHashMap<(u32, NodeHandle), GpuReflectionProbe>,
+    /// Probe bound to each scene's global bind group.
+    pub(crate) bound_reflection_probes: FxHashMap<u32, NodeHandle>,
+
+    // === Light Probes ===
+    pub(crate) light_probe_volumes: FxHashMap<u32, GpuLightProbeVolume>,
+
     /// Stores internally generated texture views (Render Targets / Attachments)
     /// Key: Resource ID (u64)
     /// Value: `wgpu::TextureView`
@@ -162,11 +192,19 @@ pub struct ResourceManager {
 }
 
 impl ResourceManager {
+    /// Creates the resource manager. `object_storage` binds the model
+    /// buffer and an instance table as vertex storage for GPU-driven draws,
+    /// see [`WgpuContext::supports_gpu_driven_draws`](crate::core::WgpuContext::supports_gpu_driven_draws).
     #[must_use]
     #[allow(clippy::too_many_lines)]
-    pub fn new(device: wgpu::Device, queue: wgpu::Queue, anisotropy_clamp: u16) -> Self {
+    pub fn new(
+        device: wgpu::Device,
+        queue: wgpu::Queue,
+        anisotropy_clamp: u16,
+        object_storage: bool,
+    ) -> Self {
         let mipmap_generator = MipmapGenerator::new(&device);
-        let model_allocator = ModelBufferAllocator::new();
+        let model_allocator = ModelBufferAllocator::new(object_storage);
 
         let mut gpu_buffers = slotmap::SlotMap::with_key();
         let mut buffer_index = rustc_hash::FxHashMap::default();
@@ -183,8 +221,14 @@ impl ResourceManager {
             queue,
             frame_index: 0,
             gpu_geometries: SecondaryMap::new(),
+            wireframe_geometries: SecondaryMap::new(),
             gpu_materials: SecondaryMap::new(),
             gpu_images: SecondaryMap::new(),
+            streaming_uploads: SecondaryMap::new(),
+            texture_upload_budget: Self::DEFAULT_TEXTURE_UPLOAD_BUDGET,
+            texture_upload_remaining: Self::DEFAULT_TEXTURE_UPLOAD_BUDGET,
+            material_uniform_uploads: 0,
+            material_bind_group_rebuilds: 0,
             sampler_registry,
             texture_bindings: SecondaryMap::new(),
             global_states: FxHashMap::default(),
@@ -200,6 +244,9 @@ impl ResourceManager {
             brdf_lut_texture: None,
             brdf_lut_view_id: None,
             needs_brdf_compute: false,
+            reflection_probes: FxHashMap::default(),
+            bound_reflection_probes: FxHashMap::default(),
+            light_probe_volumes: FxHashMap::default(),
             internal_resources: FxHashMap::default(),
             internal_name_lookup: FxHashMap::default(),
             system_textures,
@@ -209,6 +256,9 @@ impl ResourceManager {
     pub fn next_frame(&mut self) {
         self.frame_index += 1;
         self.model_allocator.reset();
+        self.texture_upload_remaining = self.texture_upload_budget;
+        self.material_uniform_uploads = 0;
+        self.material_bind_group_rebuilds = 0;
     }
 
     pub fn frame_index(&self) -> u64 {
@@ -246,6 +296,14 @@ impl ResourceManager {
         self.model_allocator.buffer_handle().id()
     }
 
+    /// The instance table read by GPU-driven draws, with its capacity in
+    /// entries. `None` when the device lacks vertex storage access.
+    pub fn object_instance_table(&self) -> Option<(&wgpu::Buffer, usize)> {
+        let instance_ref = self.model_allocator.instance_handle()?;
+        let gpu_buf = self.get_gpu_buffer_by_cpu_id(instance_ref.id())?;
+        Some((&gpu_buf.buffer, self.model_allocator.instance_capacity()))
+    }
+
     /// Quickly retrieve `BindGroup` data by cached ID
     #[inline]
     pub fn get_cached_bind_group(&self, cached_bind_group_id: u64) -> Option<&BindGroupContext> {
@@ -273,8 +331,26 @@ impl ResourceManager {
             }
         }
 
+        let stale_probes: Vec<(u32, NodeHandle)> = self
+            .reflection_probes
+            .iter()
+            .filter_map(|(key, gpu_probe)| (gpu_probe.last_used_frame < cutoff).then_some(*key))
+            .collect();
+
+        for key in stale_probes {
+            if let Some(gpu_probe) = self.reflection_probes.remove(&key) {
+                self.release_reflection_probe(&gpu_probe);
+            }
+        }
+        self.bound_reflection_probes
+            .retain(|scene_id, node| self.reflection_probes.contains_key(&(*scene_id, *node)));
+        self.light_probe_volumes
+            .retain(|_, gpu_volume| gpu_volume.last_used_frame >= cutoff);
+
         self.gpu_geometries
             .retain(|_, v| v.last_used_frame >= cutoff);
+        self.wireframe_geometries
+            .retain(|_, v| v.gpu.last_used_frame >= cutoff);
         self.gpu_materials
             .retain(|_, v| v.last_used_frame >= cutoff);
         // Sampler cache uses a global cache; no per-Texture cleanup needed
@@ -283,6 +359,8 @@ impl ResourceManager {
         self.buffer_index
             .retain(|_, h| self.gpu_buffers.contains_key(*h));
         self.gpu_images.retain(|_, v| v.last_used_frame >= cutoff);
+        self.streaming_uploads
+            .retain(|_, v| v.last_used_frame >= cutoff);
         self.global_states
             .retain(|_, v| v.last_used_frame >= cutoff);
         // texture_bindings are cleaned up following gpu_images
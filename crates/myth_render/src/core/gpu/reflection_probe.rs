@@ -0,0 +1,232 @@
+use glam::Vec3;
+use wgpu::TextureViewDimension;
+
+use myth_core::NodeHandle;
+use myth_scene::Scene;
+use myth_scene::reflection_probe::ReflectionProbe;
+
+use crate::core::gpu::Tracked;
+
+use super::ResourceManager;
+
+/// Persistent GPU resources of one reflection probe.
+///
+/// The capture frames write the six faces of `base_cube_texture`; the last
+/// one generates its mips and prefilters it into `pmrem_texture`, which
+/// the global bind group exposes as `probe_map`.
+#[derive(Debug)]
+pub struct GpuReflectionProbe {
+    pub base_cube_texture: wgpu::Texture,
+    pub pmrem_texture: wgpu::Texture,
+    pub base_cube_view: Tracked<wgpu::TextureView>,
+    pub pmrem_view: Tracked<wgpu::TextureView>,
+    pub pmrem_storage_views: Vec<Tracked<wgpu::TextureView>>,
+    /// [`ReflectionProbe::capture_version`] of the last completed capture,
+    /// 0 before the first.
+    pub captured_version: u64,
+    pub last_used_frame: u64,
+}
+
+impl GpuReflectionProbe {
+    #[inline]
+    #[must_use]
+    pub fn max_mip_level(&self) -> f32 {
+        (self.pmrem_texture.mip_level_count() - 1) as f32
+    }
+}
+
+impl ResourceManager {
+    /// Returns `true` if the probe has no up-to-date capture at its current
+    /// resolution.
+    #[must_use]
+    pub fn reflection_probe_needs_capture(
+        &self,
+        scene_id: u32,
+        node: NodeHandle,
+        probe: &ReflectionProbe,
+    ) -> bool {
+        self.reflection_probes
+            .get(&(scene_id, node))
+            .is_none_or(|gpu_probe| {
+                gpu_probe.captured_version != probe.capture_version()
+                    || gpu_probe.base_cube_texture.width() != probe.resolution
+            })
+    }
+
+    /// Creates the probe's textures, or recreates them after a resolution
+    /// change.
+    pub fn ensure_reflection_probe(&mut self, scene_id: u32, node: NodeHandle, resolution: u32) {
+        let key = (scene_id, node);
+        if let Some(gpu_probe) = self.reflection_probes.get_mut(&key)
+            && gpu_probe.base_cube_texture.width() == resolution
+        {
+            gpu_probe.last_used_frame = self.frame_index;
+            return;
+        }
+        if let Some(old) = self.reflection_probes.remove(&key) {
+            self.release_reflection_probe(&old);
+        }
+
+        let mips = resolution.max(1).ilog2() + 1;
+        let base_cube_texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Reflection Probe Base Cube"),
+            size: wgpu::Extent3d {
+                width: resolution,
+                height: resolution,
+                depth_or_array_layers: 6,
+            },
+            mip_level_count: mips,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: crate::HDR_TEXTURE_FORMAT,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING
+                | wgpu::TextureUsages::RENDER_ATTACHMENT
+                | wgpu::TextureUsages::COPY_DST,
+            view_formats: &[],
+        });
+        let base_cube_view =
+            Tracked::new(base_cube_texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("Reflection Probe Base Cube View"),
+                dimension: Some(TextureViewDimension::Cube),
+                ..Default::default()
+            }));
+        self.internal_resources
+            .insert(base_cube_view.id(), (*base_cube_view).clone());
+
+        let pmrem_texture = self.device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("Reflection Probe PMREM"),
+            size: wgpu::Extent3d {
+                width: resolution,
+                height: resolution,
+                depth_or_array_layers: 6,
+            },
+            mip_level_count: mips,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: crate::HDR_TEXTURE_FORMAT,
+            usage: wgpu::TextureUsages::STORAGE_BINDING | wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        });
+        let pmrem_view = Tracked::new(pmrem_texture.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("Reflection Probe PMREM View"),
+            dimension: Some(TextureViewDimension::Cube),
+            ..Default::default()
+        }));
+        let pmrem_storage_views = (0..mips)
+            .map(|mip| {
+                Tracked::new(pmrem_texture.create_view(&wgpu::TextureViewDescriptor {
+                    label: Some("Reflection Probe PMREM Mip"),
+                    format: Some(crate::HDR_TEXTURE_FORMAT),
+                    dimension: Some(TextureViewDimension::D2Array),
+                    aspect: wgpu::TextureAspect::All,
+                    base_mip_level: mip,
+                    mip_level_count: Some(1),
+                    base_array_layer: 0,
+                    array_layer_count: Some(6),
+                    usage: Some(wgpu::TextureUsages::STORAGE_BINDING),
+                }))
+            })
+            .collect();
+        self.internal_resources
+            .insert(pmrem_view.id(), (*pmrem_view).clone());
+
+        self.reflection_probes.insert(
+            key,
+            GpuReflectionProbe {
+                base_cube_texture,
+                pmrem_texture,
+                base_cube_view,
+                pmrem_view,
+                pmrem_storage_views,
+                captured_version: 0,
+                last_used_frame: self.frame_index,
+            },
+        );
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn gpu_reflection_probe(
+        &self,
+        scene_id: u32,
+        node: NodeHandle,
+    ) -> Option<&GpuReflectionProbe> {
+        self.reflection_probes.get(&(scene_id, node))
+    }
+
+    /// Records that the probe's capture for `version` has been submitted.
+    pub fn mark_reflection_probe_captured(
+        &mut self,
+        scene_id: u32,
+        node: NodeHandle,
+        version: u64,
+    ) {
+        if let Some(gpu_probe) = self.reflection_probes.get_mut(&(scene_id, node)) {
+            gpu_probe.captured_version = version;
+        }
+    }
+
+    /// Picks the probe the scene's materials sample this frame and writes
+    /// its parameters into the environment uniforms.
+    ///
+    /// Among the visible, captured probes, the one whose sphere of
+    /// influence is closest to the camera wins (a sphere containing the
+    /// camera is closer than any other). With `allow_binding` false, as
+    /// while a probe is being captured, no probe is bound.
+    pub fn resolve_reflection_probe(
+        &mut self,
+        scene: &Scene,
+        camera_position: Vec3,
+        allow_binding: bool,
+    ) {
+        let scene_id = scene.id();
+        let mut best: Option<(f32, NodeHandle, &ReflectionProbe, Vec3)> = None;
+
+        for (node, probe, position) in scene.iter_active_reflection_probes() {
+            let Some(gpu_probe) = self.reflection_probes.get_mut(&(scene_id, node)) else {
+                continue;
+            };
+            gpu_probe.last_used_frame = self.frame_index;
+            if !allow_binding || gpu_probe.captured_version == 0 || probe.radius <= 0.0 {
+                continue;
+            }
+
+            let distance = camera_position.distance(position) - probe.radius;
+            if best.is_none_or(|(best_distance, ..)| distance < best_distance) {
+                best = Some((distance, node, probe, position));
+            }
+        }
+
+        let mut uniforms = *scene.uniforms_buffer.read();
+        if let Some((_, node, probe, position)) = best {
+            self.bound_reflection_probes.insert(scene_id, node);
+            uniforms.probe_position = position;
+            uniforms.probe_radius = probe.radius;
+            uniforms.probe_blend_distance = probe.blend_distance.clamp(0.0, probe.radius);
+            uniforms.probe_intensity = probe.intensity;
+            uniforms.probe_max_mip_level =
+                self.reflection_probes[&(scene_id, node)].max_mip_level();
+        } else {
+            self.bound_reflection_probes.remove(&scene_id);
+            uniforms.probe_radius = 0.0;
+        }
+
+        if *scene.uniforms_buffer.read() != uniforms {
+            *scene.uniforms_buffer.write() = uniforms;
+        }
+    }
+
+    /// Returns the probe bound for `scene_id` by the last
+    /// [`resolve_reflection_probe`](Self::resolve_reflection_probe).
+    #[must_use]
+    pub fn bound_reflection_probe(&self, scene_id: u32) -> Option<&GpuReflectionProbe> {
+        let node = self.bound_reflection_probes.get(&scene_id)?;
+        self.reflection_probes.get(&(scene_id, *node))
+    }
+
+    pub(crate) fn release_reflection_probe(&mut self, gpu_probe: &GpuReflectionProbe) {
+        self.internal_resources
+            .remove(&gpu_probe.base_cube_view.id());
+        self.internal_resources.remove(&gpu_probe.pmrem_view.id());
+    }
+}
@@ -31,6 +31,9 @@ pub enum ResourceState {
     Ready,
     /// Underlying data is still loading
     Pending,
+    /// Image data is available but its GPU upload is still being streamed
+    /// across frames; the previous image (or a fallback) stays bound
+    Streaming,
     /// Resource is missing or failed to load (e.g. image decoding failed)
     Unknown,
 }
@@ -62,6 +65,37 @@ impl GpuImage {
         view_dimension: wgpu::TextureViewDimension,
         mip_level_count: u32,
         usage: wgpu::TextureUsages,
+    ) -> Self {
+        let gpu_image = Self::allocate(
+            device,
+            image,
+            resolved_format,
+            view_dimension,
+            mip_level_count,
+            usage,
+        );
+
+        Self::upload_data(
+            queue,
+            &gpu_image.texture,
+            image,
+            image.width,
+            image.height,
+            image.depth,
+            resolved_format,
+        );
+
+        gpu_image
+    }
+
+    /// Creates the texture and default view without uploading any data.
+    fn allocate(
+        device: &wgpu::Device,
+        image: &Image,
+        resolved_format: wgpu::TextureFormat,
+        view_dimension: wgpu::TextureViewDimension,
+        mip_level_count: u32,
+        usage: wgpu::TextureUsages,
     ) -> Self {
         let size = wgpu::Extent3d {
             width: image.width,
@@ -80,16 +114,6 @@ impl GpuImage {
             view_formats: &[],
         });
 
-        Self::upload_data(
-            queue,
-            &texture,
-            image,
-            image.width,
-            image.height,
-            image.depth,
-            resolved_format,
-        );
-
         let default_view = texture.create_view(&wgpu::TextureViewDescriptor {
             label: None,
             format: Some(resolved_format),
@@ -164,6 +188,94 @@ impl GpuImage {
         }
     }
 
+    /// Returns whether this image can serve `image` at `image_version`
+    /// without being rebuilt or re-uploaded.
+    fn is_current(
+        &self,
+        image: &Image,
+        image_version: u32,
+        resolved_format: wgpu::TextureFormat,
+        required_mip_count: u32,
+        required_usage: wgpu::TextureUsages,
+    ) -> bool {
+        self.version >= image_version
+            && self.size.width == image.width
+            && self.size.height == image.height
+            && self.size.depth_or_array_layers == image.depth
+            && self.format == resolved_format
+            && self.mip_level_count >= required_mip_count
+            && self.usage.contains(required_usage)
+    }
+
+    /// Uploads up to `max_rows` block rows of the base mip level, starting at
+    /// `first_row` counted across all layers / depth slices.
+    ///
+    /// Rows are rows of texel blocks, i.e. texel rows for uncompressed
+    /// formats. Never crosses a layer boundary; returns the number of block
+    /// rows written.
+    fn upload_rows(
+        queue: &wgpu::Queue,
+        texture: &wgpu::Texture,
+        image: &Image,
+        data: &[u8],
+        bytes_per_row: u32,
+        first_row: u32,
+        max_rows: u32,
+    ) -> u32 {
+        let format = texture.format();
+        let (_, block_height) = format.block_dimensions();
+        let layer_rows = Self::block_rows(format, image.height);
+        let layer = first_row / layer_rows;
+        let block_row = first_row % layer_rows;
+        let rows = max_rows.min(layer_rows - block_row);
+        let y = block_row * block_height;
+
+        let offset = (u64::from(first_row) * u64::from(bytes_per_row)) as usize;
+        let len = (u64::from(rows) * u64::from(bytes_per_row)) as usize;
+
+        let Some(bytes) = data.get(offset..offset + len) else {
+            log::warn!("Streaming texture data is shorter than its declared size; skipping rows");
+            return rows;
+        };
+
+        queue.write_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d { x: 0, y, z: layer },
+                aspect: wgpu::TextureAspect::All,
+            },
+            bytes,
+            wgpu::TexelCopyBufferLayout {
+                offset: 0,
+                bytes_per_row: Some(bytes_per_row),
+                rows_per_image: Some(rows),
+            },
+            wgpu::Extent3d {
+                width: image.width,
+                height: (rows * block_height).min(image.height - y),
+                depth_or_array_layers: 1,
+            },
+        );
+
+        rows
+    }
+
+    /// Bytes in one row of texel blocks, or `None` for formats without a
+    /// single copy size (combined depth-stencil, multi-planar).
+    #[inline]
+    fn bytes_per_row(format: wgpu::TextureFormat, width: u32) -> Option<u32> {
+        let (block_width, _) = format.block_dimensions();
+        let block_size = format.block_copy_size(None)?;
+        Some(width.div_ceil(block_width) * block_size)
+    }
+
+    /// Number of block rows covering `height` texel rows.
+    #[inline]
+    fn block_rows(format: wgpu::TextureFormat, height: u32) -> u32 {
+        height.div_ceil(format.block_dimensions().1)
+    }
+
     fn upload_data(
         queue: &wgpu::Queue,
         texture: &wgpu::Texture,
@@ -174,8 +286,10 @@ impl GpuImage {
         src_format: wgpu::TextureFormat,
     ) {
         if let Some(data) = &image.data {
-            let block_size = src_format.block_copy_size(None).unwrap_or(4);
-            let bytes_per_row = src_width * block_size;
+            let Some(bytes_per_row) = Self::bytes_per_row(src_format, src_width) else {
+                log::warn!("Cannot upload texture data in format {src_format:?}");
+                return;
+            };
 
             queue.write_texture(
                 wgpu::TexelCopyTextureInfo {
@@ -188,7 +302,7 @@ impl GpuImage {
                 wgpu::TexelCopyBufferLayout {
                     offset: 0,
                     bytes_per_row: Some(bytes_per_row),
-                    rows_per_image: Some(src_height),
+                    rows_per_image: Some(Self::block_rows(src_format, src_height)),
                 },
                 wgpu::Extent3d {
                     width: src_width,
@@ -200,7 +314,144 @@ impl GpuImage {
     }
 }
 
+/// In-flight progressive upload of a streaming texture (see
+/// [`Texture::streaming`](myth_resources::texture::Texture::streaming)).
+///
+/// The target image stays out of `gpu_images`, and is therefore never
+/// bound, until every row of the base level is resident.  Mipmaps are
+/// generated after that, before the texture binding is swapped.
+pub(crate) struct StreamingUpload {
+    image: GpuImage,
+    /// Next base-level row to upload, counted across all layers.
+    next_row: u32,
+    pub(crate) last_used_frame: u64,
+}
+
 impl ResourceManager {
+    /// Default [`texture_upload_budget`](Self::texture_upload_budget): 4 MiB,
+    /// i.e. one 1024×1024 RGBA8 image per frame.
+    pub const DEFAULT_TEXTURE_UPLOAD_BUDGET: u64 = 4 * 1024 * 1024;
+
+    /// Bytes of streaming-texture data uploaded per frame, at most.
+    #[inline]
+    #[must_use]
+    pub fn texture_upload_budget(&self) -> u64 {
+        self.texture_upload_budget
+    }
+
+    /// Sets the per-frame upload budget for streaming textures.
+    ///
+    /// At least one row is uploaded per frame while any streaming texture
+    /// is in flight, so even a budget of `1` makes progress.  Textures
+    /// without the streaming flag are always uploaded in full.
+    pub fn set_texture_upload_budget(&mut self, bytes_per_frame: u64) {
+        self.texture_upload_budget = bytes_per_frame;
+    }
+
+    /// Number of streaming textures whose upload has not completed yet.
+    #[inline]
+    #[must_use]
+    pub fn pending_texture_uploads(&self) -> usize {
+        self.streaming_uploads.len()
+    }
+
+    /// Advances the progressive upload of `image`, spending this frame's
+    /// remaining budget.
+    ///
+    /// Returns `true` once the upload is complete and the image has been
+    /// moved into `gpu_images`; the caller then binds it as usual.
+    #[allow(clippy::too_many_arguments)]
+    fn stream_image(
+        &mut self,
+        image: &Image,
+        image_handle: ImageHandle,
+        image_version: u32,
+        resolved_format: wgpu::TextureFormat,
+        view_dimension: wgpu::TextureViewDimension,
+        required_mip_count: u32,
+        required_usage: wgpu::TextureUsages,
+    ) -> bool {
+        let restart = self
+            .streaming_uploads
+            .get(image_handle)
+            .is_none_or(|upload| {
+                !upload.image.is_current(
+                    image,
+                    image_version,
+                    resolved_format,
+                    required_mip_count,
+                    required_usage,
+                )
+            });
+
+        if restart {
+            let mut gpu_img = GpuImage::allocate(
+                &self.device,
+                image,
+                resolved_format,
+                view_dimension,
+                required_mip_count,
+                required_usage,
+            );
+            gpu_img.version = image_version;
+            self.streaming_uploads.insert(
+                image_handle,
+                StreamingUpload {
+                    image: gpu_img,
+                    next_row: 0,
+                    last_used_frame: self.frame_index,
+                },
+            );
+        }
+
+        let Some(upload) = self.streaming_uploads.get_mut(image_handle) else {
+            return false;
+        };
+        upload.last_used_frame = self.frame_index;
+
+        let total_rows = GpuImage::block_rows(resolved_format, image.height) * image.depth;
+        let bytes_per_row = GpuImage::bytes_per_row(resolved_format, image.width);
+        if let (Some(data), Some(bytes_per_row)) = (&image.data, bytes_per_row) {
+            let row_cost = u64::from(bytes_per_row).max(1);
+
+            while upload.next_row < total_rows && self.texture_upload_remaining > 0 {
+                let affordable = (self.texture_upload_remaining / row_cost).max(1);
+                let max_rows = u32::try_from(affordable).unwrap_or(u32::MAX);
+                let rows = GpuImage::upload_rows(
+                    &self.queue,
+                    &upload.image.texture,
+                    image,
+                    data,
+                    bytes_per_row,
+                    upload.next_row,
+                    max_rows,
+                );
+                upload.next_row += rows;
+                self.texture_upload_remaining = self
+                    .texture_upload_remaining
+                    .saturating_sub(u64::from(rows) * row_cost);
+            }
+        } else {
+            if image.data.is_some() {
+                log::warn!("Cannot stream texture data in format {resolved_format:?}");
+            }
+            // Placeholder image without data (or an uncopyable format): nothing to stream.
+            upload.next_row = total_rows;
+        }
+
+        if upload.next_row < total_rows {
+            return false;
+        }
+
+        let Some(upload) = self.streaming_uploads.remove(image_handle) else {
+            return false;
+        };
+        let mut gpu_img = upload.image;
+        gpu_img.last_used_frame = self.frame_index;
+        self.gpu_images.insert(image_handle, gpu_img);
+        true
+    }
+
     /// Ensure a GPU image exists for the given CPU `Image`, creating or
     /// updating as needed.  Returns the physical GPU image ID.
     pub(crate) fn prepare_image(
@@ -330,6 +581,31 @@ impl ResourceManager {
             usage |= wgpu::TextureUsages::RENDER_ATTACHMENT;
         }
 
+        // Streaming textures keep their current binding (or the fallback)
+        // until the new image is fully resident.
+        if texture_asset.streaming
+            && !self.gpu_images.get(image_handle).is_some_and(|gpu_img| {
+                gpu_img.is_current(
+                    &image_arc,
+                    image_version,
+                    resolved_format,
+                    final_mip_count,
+                    usage,
+                )
+            })
+            && !self.stream_image(
+                &image_arc,
+                image_handle,
+                image_version,
+                resolved_format,
+                texture_asset.view_dimension,
+                final_mip_count,
+                usage,
+            )
+        {
+            return ResourceState::Streaming;
+        }
+
         let gpu_image_id = self.prepare_image(
             &image_arc,
             image_handle,
@@ -14,12 +14,14 @@
 pub mod binding;
 pub mod context;
 pub mod gpu;
+pub mod profiler;
 pub mod readback;
 pub mod view;
 
 pub use binding::{Bindings, GlobalBindGroupCache};
 pub use context::WgpuContext;
 pub use gpu::{BindGroupContext, ResourceManager};
+pub use profiler::GpuProfiler;
 pub use readback::{ReadbackError, ReadbackFrame, ReadbackStream};
 pub use view::{RenderView, ViewTarget};
 
@@ -0,0 +1,231 @@
+//! GPU timestamp profiler.
+//!
+//! [`GpuProfiler`] brackets every render-graph pass with encoder-level
+//! timestamp writes and reads the results back asynchronously, so the
+//! timings returned by [`Renderer::pass_timings`] lag the current frame by
+//! one or two frames but never stall the CPU.
+//!
+//! Requires both `TIMESTAMP_QUERY` and `TIMESTAMP_QUERY_INSIDE_ENCODERS`.
+//! The renderer requests them when the adapter offers them and skips
+//! profiling entirely otherwise.
+//!
+//! # Frame flow
+//!
+//! 1. [`begin_frame`](GpuProfiler::begin_frame) — collect finished readbacks
+//!    and pick a free ring slot (profiling is skipped if all are in flight).
+//! 2. [`begin_pass`](GpuProfiler::begin_pass) /
+//!    [`end_pass`](GpuProfiler::end_pass) — write two timestamps per pass.
+//! 3. [`resolve`](GpuProfiler::resolve) — resolve queries into the slot's
+//!    readback buffer (same encoder, before submit).
+//! 4. [`after_submit`](GpuProfiler::after_submit) — request the async map.
+//!
+//! [`Renderer::pass_timings`]: crate::Renderer::pass_timings
+
+/// Number of frames that may be in flight before profiling pauses.
+const SLOT_COUNT: usize = 3;
+
+/// Timestamp queries per frame (two per pass).
+const MAX_QUERIES: u32 = 256;
+
+const TIMESTAMP_SIZE: u64 = size_of::<u64>() as u64;
+
+struct ProfilerSlot {
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    pass_names: Vec<&'static str>,
+    in_flight: bool,
+}
+
+/// Payload sent from the `map_async` callback.
+struct ReadySlot {
+    slot: usize,
+    result: Result<(), wgpu::BufferAsyncError>,
+}
+
+/// Per-pass GPU timing collector based on timestamp queries.
+pub struct GpuProfiler {
+    query_set: wgpu::QuerySet,
+    slots: Vec<ProfilerSlot>,
+    write_idx: usize,
+    /// Slot recording the current frame, `None` when skipped.
+    current: Option<usize>,
+    query_count: u32,
+    /// Nanoseconds per timestamp tick.
+    timestamp_period: f32,
+
+    sender: flume::Sender<ReadySlot>,
+    receiver: flume::Receiver<ReadySlot>,
+
+    latest: Vec<(String, f32)>,
+}
+
+impl GpuProfiler {
+    /// Features the profiler needs from the device.
+    pub const REQUIRED_FEATURES: wgpu::Features =
+        wgpu::Features::TIMESTAMP_QUERY.union(wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS);
+
+    /// Creates a profiler, or returns `None` if the device lacks
+    /// [`REQUIRED_FEATURES`](Self::REQUIRED_FEATURES).
+    #[must_use]
+    pub fn new(device: &wgpu::Device, queue: &wgpu::Queue) -> Option<Self> {
+        if !device.features().contains(Self::REQUIRED_FEATURES) {
+            return None;
+        }
+
+        let query_set = device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("GpuProfiler QuerySet"),
+            ty: wgpu::QueryType::Timestamp,
+            count: MAX_QUERIES,
+        });
+
+        let size = u64::from(MAX_QUERIES) * TIMESTAMP_SIZE;
+        let slots = (0..SLOT_COUNT)
+            .map(|_| ProfilerSlot {
+                resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("GpuProfiler Resolve"),
+                    size,
+                    usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                    mapped_at_creation: false,
+                }),
+                readback_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("GpuProfiler Readback"),
+                    size,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                pass_names: Vec::new(),
+                in_flight: false,
+            })
+            .collect();
+
+        let (sender, receiver) = flume::bounded(SLOT_COUNT);
+
+        Some(Self {
+            query_set,
+            slots,
+            write_idx: 0,
+            current: None,
+            query_count: 0,
+            timestamp_period: queue.get_timestamp_period(),
+            sender,
+            receiver,
+            latest: Vec::new(),
+        })
+    }
+
+    /// Returns the most recently resolved `(pass name, milliseconds)` list.
+    #[must_use]
+    pub fn latest(&self) -> &[(String, f32)] {
+        &self.latest
+    }
+
+    /// Collects finished readbacks and reserves a slot for this frame.
+    pub fn begin_frame(&mut self, device: &wgpu::Device) {
+        let _ = device.poll(wgpu::PollType::Poll);
+        while let Ok(ready) = self.receiver.try_recv() {
+            self.collect_slot(ready.slot, ready.result.is_ok());
+        }
+
+        self.query_count = 0;
+        let slot = &mut self.slots[self.write_idx];
+        if slot.in_flight {
+            self.current = None;
+        } else {
+            slot.pass_names.clear();
+            self.current = Some(self.write_idx);
+        }
+    }
+
+    /// Writes the start timestamp of a pass.
+    pub fn begin_pass(&mut self, encoder: &mut wgpu::CommandEncoder, name: &'static str) {
+        let Some(slot) = self.current else {
+            return;
+        };
+        if self.query_count + 2 > MAX_QUERIES {
+            return;
+        }
+        encoder.write_timestamp(&self.query_set, self.query_count);
+        self.slots[slot].pass_names.push(name);
+    }
+
+    /// Writes the end timestamp of the pass opened by [`begin_pass`](Self::begin_pass).
+    pub fn end_pass(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        let Some(slot) = self.current else {
+            return;
+        };
+        if self.query_count / 2 >= self.slots[slot].pass_names.len() as u32 {
+            return;
+        }
+        encoder.write_timestamp(&self.query_set, self.query_count + 1);
+        self.query_count += 2;
+    }
+
+    /// Resolves this frame's queries into the slot's readback buffer.
+    pub fn resolve(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        let Some(slot) = self.current else {
+            return;
+        };
+        if self.query_count == 0 {
+            self.current = None;
+            return;
+        }
+        let slot = &self.slots[slot];
+        encoder.resolve_query_set(
+            &self.query_set,
+            0..self.query_count,
+            &slot.resolve_buffer,
+            0,
+        );
+        encoder.copy_buffer_to_buffer(
+            &slot.resolve_buffer,
+            0,
+            &slot.readback_buffer,
+            0,
+            u64::from(self.query_count) * TIMESTAMP_SIZE,
+        );
+    }
+
+    /// Requests the async map of this frame's results. Call after the
+    /// encoder passed to [`resolve`](Self::resolve) has been submitted.
+    pub fn after_submit(&mut self) {
+        let Some(slot_idx) = self.current.take() else {
+            return;
+        };
+        let size = u64::from(self.query_count) * TIMESTAMP_SIZE;
+        let tx = self.sender.clone();
+        let slot = &mut self.slots[slot_idx];
+        slot.in_flight = true;
+        slot.readback_buffer
+            .slice(..size)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(ReadySlot {
+                    slot: slot_idx,
+                    result,
+                });
+            });
+        self.write_idx = (self.write_idx + 1) % self.slots.len();
+    }
+
+    fn collect_slot(&mut self, slot: usize, mapped: bool) {
+        let slot = &mut self.slots[slot];
+        slot.in_flight = false;
+        if !mapped {
+            return;
+        }
+
+        {
+            let size = slot.pass_names.len() as u64 * 2 * TIMESTAMP_SIZE;
+            let mapped = slot.readback_buffer.slice(..size).get_mapped_range();
+            let timestamps: &[u64] = bytemuck::cast_slice(&mapped);
+            let period_ms = f64::from(self.timestamp_period) / 1_000_000.0;
+
+            self.latest.clear();
+            for (name, pair) in slot.pass_names.iter().zip(timestamps.chunks_exact(2)) {
+                let ticks = pair[1].saturating_sub(pair[0]);
+                self.latest
+                    .push(((*name).to_string(), (ticks as f64 * period_ms) as f32));
+            }
+        }
+        slot.readback_buffer.unmap();
+    }
+}
@@ -14,13 +14,16 @@
 //! [`FrameComposer::render()`](super::composer::FrameComposer::render) after
 //! the RDG prepare phase and before the execute loop.
 
+use std::ops::Range;
+
 use rustc_hash::FxHashMap;
 
 use crate::core::ResourceManager;
+use crate::core::gpu::GpuGeometry;
 use crate::pipeline::{PipelineCache, RenderPipelineId};
 
 use super::frame::{
-    BakedRenderLists, DrawCommand, RenderCommand, RenderLists, ShadowRenderCommand,
+    BakedRenderLists, DrawCommand, RenderCommand, RenderLists, ShadowRenderCommand, WireframeDraw,
 };
 
 // ============================================================================
@@ -57,6 +60,8 @@ pub fn bake_render_lists<'a>(
     let opaque = bake_main_commands(&render_lists.opaque, resource_manager, pipeline_cache);
     let transparent =
         bake_main_commands(&render_lists.transparent, resource_manager, pipeline_cache);
+    let weighted_oit =
+        bake_main_commands(&render_lists.weighted_oit, resource_manager, pipeline_cache);
 
     let prepass = if let Some(cfg) = prepass_config {
         bake_prepass_commands(&render_lists.opaque, resource_manager, pipeline_cache, cfg)
@@ -78,6 +83,7 @@ pub fn bake_render_lists<'a>(
     BakedRenderLists {
         opaque,
         transparent,
+        weighted_oit,
         prepass,
         shadow_queues,
         global_bind_group,
@@ -88,6 +94,34 @@ pub fn bake_render_lists<'a>(
 // Internals
 // ============================================================================
 
+/// Index buffer binding of a draw, narrowed to a submesh range.
+type IndexBinding<'a> = Option<(&'a wgpu::Buffer, wgpu::IndexFormat, Range<u32>)>;
+
+/// Resolves the index buffer and vertex range of a draw.
+///
+/// A submesh `draw_range` narrows the index range of indexed geometry, or
+/// the vertex range of non-indexed geometry (including barycentric
+/// wireframe copies, whose vertices follow the original index order).
+fn draw_ranges<'a>(
+    gpu_geom: &'a GpuGeometry,
+    draw_range: Option<&Range<u32>>,
+) -> (IndexBinding<'a>, Range<u32>) {
+    let narrow = |full: Range<u32>| match draw_range {
+        Some(range) => {
+            let end = range.end.min(full.end);
+            range.start.clamp(full.start, end)..end
+        }
+        None => full,
+    };
+    match &gpu_geom.index_buffer {
+        Some((buf, fmt, count, _id)) => (
+            Some((buf as &wgpu::Buffer, *fmt, narrow(0..*count))),
+            gpu_geom.draw_range.clone(),
+        ),
+        None => (None, narrow(gpu_geom.draw_range.clone())),
+    }
+}
+
 /// Bake a slice of main-camera [`RenderCommand`]s into [`DrawCommand`]s.
 ///
 /// The output preserves the input order (already sorted by
@@ -105,16 +139,27 @@ fn bake_main_commands<'a>(
         let Some(gpu_mat) = rm.get_material(cmd.material_handle) else {
             continue;
         };
-        let Some(gpu_geom) = rm.get_geometry(cmd.geometry_handle) else {
+        // Barycentric wireframes draw their de-indexed copy instead.
+        let gpu_geom = if cmd.wireframe == WireframeDraw::Barycentric {
+            rm.get_wireframe_geometry(cmd.geometry_handle)
+                .map(|wire| &wire.gpu)
+        } else {
+            rm.get_geometry(cmd.geometry_handle)
+        };
+        let Some(gpu_geom) = gpu_geom else {
             continue;
         };
 
         let vertex_buffers: Vec<&wgpu::Buffer> = gpu_geom.vertex_buffers.iter().collect();
 
-        let index_buffer = gpu_geom
-            .index_buffer
-            .as_ref()
-            .map(|(buf, fmt, count, _id)| (buf as &wgpu::Buffer, *fmt, *count));
+        // Point sprites: one 6-vertex quad per point, points stepped per
+        // instance. Index buffers do not apply.
+        let (index_buffer, vertex_range) = draw_ranges(gpu_geom, cmd.draw_range.as_ref());
+        let (index_buffer, vertex_range, instance_range) = if cmd.point_sprites {
+            (None, 0..6, vertex_range)
+        } else {
+            (index_buffer, vertex_range, gpu_geom.instance_range.clone())
+        };
 
         out.push(DrawCommand {
             sort_key: cmd.sort_key.bits(),
@@ -124,9 +169,13 @@ fn bake_main_commands<'a>(
             bind_group_1: Some(&gpu_mat.bind_group),
             bind_group_2: (&cmd.object_bind_group.bind_group, cmd.dynamic_offset),
             bind_group_3: None, // Set at the pass level
-            stencil_reference: None,
-            vertex_range: gpu_geom.draw_range.clone(),
-            instance_range: gpu_geom.instance_range.clone(),
+            stencil_reference: cmd.stencil_reference,
+            vertex_range,
+            instance_range,
+            cull_object: cmd.cull_object,
+            gpu_driven: cmd.gpu_driven,
+            mergeable: cmd.mergeable,
+            indirect: None,
         });
     }
 
@@ -167,11 +216,7 @@ fn bake_prepass_commands<'a>(
         };
 
         let vertex_buffers: Vec<&wgpu::Buffer> = gpu_geom.vertex_buffers.iter().collect();
-
-        let index_buffer = gpu_geom
-            .index_buffer
-            .as_ref()
-            .map(|(buf, fmt, count, _id)| (buf as &wgpu::Buffer, *fmt, *count));
+        let (index_buffer, vertex_range) = draw_ranges(gpu_geom, cmd.draw_range.as_ref());
 
         out.push(DrawCommand {
             sort_key: cmd.sort_key.bits(),
@@ -182,8 +227,12 @@ fn bake_prepass_commands<'a>(
             bind_group_2: (&cmd.object_bind_group.bind_group, cmd.dynamic_offset),
             bind_group_3: None,
             stencil_reference: None,
-            vertex_range: gpu_geom.draw_range.clone(),
+            vertex_range,
             instance_range: gpu_geom.instance_range.clone(),
+            cull_object: cmd.cull_object,
+            gpu_driven: cmd.gpu_driven,
+            mergeable: cmd.mergeable,
+            indirect: None,
         });
     }
 
@@ -212,11 +261,7 @@ fn bake_shadow_queues<'a>(
             };
 
             let vertex_buffers: Vec<&wgpu::Buffer> = gpu_geom.vertex_buffers.iter().collect();
-
-            let index_buffer = gpu_geom
-                .index_buffer
-                .as_ref()
-                .map(|(buf, fmt, count, _id)| (buf as &wgpu::Buffer, *fmt, *count));
+            let (index_buffer, vertex_range) = draw_ranges(gpu_geom, cmd.draw_range.as_ref());
 
             // Shadow sort key: pipeline ID in high bits, material bind group
             // in low bits — minimises both pipeline and bind-group switches.
@@ -232,8 +277,12 @@ fn bake_shadow_queues<'a>(
                 bind_group_2: (&cmd.object_bind_group.bind_group, cmd.dynamic_offset),
                 bind_group_3: None,
                 stencil_reference: None,
-                vertex_range: gpu_geom.draw_range.clone(),
+                vertex_range,
                 instance_range: gpu_geom.instance_range.clone(),
+                cull_object: None,
+                gpu_driven: false,
+                mergeable: false,
+                indirect: None,
             });
         }
 
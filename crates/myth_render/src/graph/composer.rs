@@ -47,27 +47,34 @@
 //!     .render();
 //! ```
 
+use std::cell::Cell;
+
 use crate::core::binding::GlobalBindGroupCache;
 use crate::core::gpu::{CubeSourceType, Tracked};
-use crate::core::{ResourceManager, WgpuContext};
+use crate::core::{GpuProfiler, ResourceManager, WgpuContext};
 use crate::graph::ExtractedScene;
 use crate::graph::RenderState;
 use crate::graph::core::GraphStorage;
 use crate::graph::core::graph::FrameConfig;
 use crate::graph::core::{
-    ExecuteContext, FrameArena, GraphBlackboard, HookStage, PrepareContext, RenderGraph,
-    TextureDesc, TransientPool, ViewResolver,
+    ExecuteContext, FrameArena, GraphBlackboard, HookAnchorWarnings, HookKey, HookOrder, HookStage,
+    PrepareContext, RenderGraph, TextureDesc, TransientPool, ViewResolver, resolve_hook_order,
 };
 use crate::graph::frame::{PreparedSkyboxDraw, RenderLists};
 #[cfg(feature = "3dgs")]
 use crate::graph::passes::GaussianSplattingFeature;
-use crate::graph::passes::utils::add_msaa_resolve_pass;
+use crate::graph::passes::reflection_probe::add_probe_face_copy_pass;
+use crate::graph::passes::utils::{add_generate_mipmap_pass, add_msaa_resolve_pass};
 use crate::graph::passes::{
-    AtmosphereFeature, BloomFeature, BrdfLutFeature, CasFeature, EquirectToCubeFeature,
-    FxaaFeature, IblComputeFeature, MsaaSyncFeature, OpaqueFeature, PrepassFeature, ShadowFeature,
-    SimpleForwardFeature, SkyboxFeature, SsaoFeature, SsssFeature, TaaFeature, ToneMappingFeature,
-    TransmissionCopyFeature, TransparentFeature,
+    AtmosphereFeature, AutoExposureFeature, BloomFeature, BrdfLutFeature, CasFeature,
+    DebugLinesFeature, DecalFeature, EquirectToCubeFeature, FxaaFeature, GpuCullingFeature,
+    GridFeature, GroundShadowFeature, IblComputeFeature, IblTarget, LightClusterFeature,
+    LightProbeFeature, MsaaSyncFeature, OcclusionCullingFeature, OpaqueFeature, PrepassFeature,
+    ProbeCaptureKind, ProbeCaptureTarget, ShadowFeature, SimpleForwardFeature, SkyboxFeature,
+    SsaoFeature, SsssFeature, TaaFeature, ToneMappingFeature, TransmissionCopyFeature,
+    TransparentFeature, WeightedOitFeature,
 };
+use crate::graph::stats::RenderStats;
 use crate::pipeline::PipelineCache;
 use crate::pipeline::ShaderManager;
 use crate::renderer::FrameTime;
@@ -89,13 +96,30 @@ pub struct ComposerContext<'a> {
     /// Render lists (populated by `SceneCullPass`)
     pub render_lists: &'a mut RenderLists,
 
+    /// Receives this frame's statistics after execution.
+    pub frame_stats: &'a mut RenderStats,
+    /// Render pipeline count before the frame's culling, for
+    /// [`RenderStats::pipelines_compiled`].
+    pub pipelines_at_frame_start: usize,
+
+    /// Per-pass GPU timestamp profiler (`None` when unsupported).
+    pub gpu_profiler: Option<&'a mut GpuProfiler>,
+
     // External scene data
     pub scene: &'a mut Scene,
     pub camera: RenderCamera,
     pub assets: &'a AssetServer,
     pub frame_time: FrameTime,
+    /// Render scene normals for custom passes (see [`crate::RendererSettings::scene_normals`]).
+    pub scene_normals: bool,
+    /// Set for the frames capturing a reflection or light probe face. The
+    /// frame renders into the probe instead of the surface and skips
+    /// post-processing and hooks.
+    pub probe_capture: Option<ProbeCaptureTarget>,
 
     pub graph_storage: &'a mut GraphStorage,
+    /// Hook anchor problems already logged in earlier frames.
+    pub hook_anchor_warnings: &'a mut HookAnchorWarnings,
     pub transient_pool: &'a mut TransientPool,
     // pub sampler_registry: &'a mut SamplerRegistry,
     pub frame_arena: &'a FrameArena,
@@ -106,13 +130,19 @@ pub struct ComposerContext<'a> {
     pub taa_pass: &'a mut TaaFeature,
     pub cas_pass: &'a mut CasFeature,
     pub tone_map_pass: &'a mut ToneMappingFeature,
+    pub auto_exposure_pass: &'a mut AutoExposureFeature,
     pub bloom_pass: &'a mut BloomFeature,
     pub ssao_pass: &'a mut SsaoFeature,
     // Scene rendering
     pub prepass: &'a mut PrepassFeature,
     pub opaque_pass: &'a mut OpaqueFeature,
     pub skybox_pass: &'a mut SkyboxFeature,
+    pub debug_lines_pass: &'a mut DebugLinesFeature,
+    pub grid_pass: &'a mut GridFeature,
+    pub ground_shadow_pass: &'a mut GroundShadowFeature,
+    pub decal_pass: &'a mut DecalFeature,
     pub transparent_pass: &'a mut TransparentFeature,
+    pub weighted_oit_pass: &'a mut WeightedOitFeature,
     pub transmission_copy_pass: &'a mut TransmissionCopyFeature,
     pub simple_forward_pass: &'a mut SimpleForwardFeature,
     pub ssss_pass: &'a mut SsssFeature,
@@ -121,8 +151,12 @@ pub struct ComposerContext<'a> {
     // Shadow + Compute
     pub shadow_pass: &'a mut ShadowFeature,
     pub brdf_pass: &'a mut BrdfLutFeature,
+    pub gpu_culling_pass: &'a mut GpuCullingFeature,
+    pub light_cluster_pass: &'a mut LightClusterFeature,
+    pub occlusion_pass: &'a mut OcclusionCullingFeature,
     pub equirect_to_cube_pass: &'a mut EquirectToCubeFeature,
     pub ibl_pass: &'a mut IblComputeFeature,
+    pub light_probe_pass: &'a mut LightProbeFeature,
     pub atmosphere_pass: &'a mut AtmosphereFeature,
 
     #[cfg(feature = "3dgs")]
@@ -186,6 +220,21 @@ fn base_cube_desc(texture: &wgpu::Texture) -> TextureDesc {
     )
 }
 
+fn probe_base_cube_desc(texture: &wgpu::Texture) -> TextureDesc {
+    TextureDesc::new(
+        texture.width(),
+        texture.height(),
+        6,
+        texture.mip_level_count(),
+        1,
+        wgpu::TextureDimension::D2,
+        crate::HDR_TEXTURE_FORMAT,
+        wgpu::TextureUsages::TEXTURE_BINDING
+            | wgpu::TextureUsages::RENDER_ATTACHMENT
+            | wgpu::TextureUsages::COPY_DST,
+    )
+}
+
 fn pmrem_desc(texture: &wgpu::Texture) -> TextureDesc {
     TextureDesc::new(
         texture.width(),
@@ -199,6 +248,21 @@ fn pmrem_desc(texture: &wgpu::Texture) -> TextureDesc {
     )
 }
 
+type BoxedHook<'a> = Box<dyn FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a>;
+
+/// Takes the hooks of `stage` out of `hooks`, in resolved order.
+fn take_hooks<'a>(
+    hooks: &mut [(HookKey, Option<BoxedHook<'a>>)],
+    stage: HookStage,
+    warned: &mut HookAnchorWarnings,
+) -> smallvec::SmallVec<[BoxedHook<'a>; 4]> {
+    let keys: smallvec::SmallVec<[HookKey; 4]> = hooks.iter().map(|(k, _)| *k).collect();
+    resolve_hook_order(&keys, stage, warned)
+        .into_iter()
+        .filter_map(|i| hooks[i].1.take())
+        .collect()
+}
+
 /// Frame Composer
 ///
 /// Holds all context references needed to render a single frame and provides
@@ -207,13 +271,7 @@ fn pmrem_desc(texture: &wgpu::Texture) -> TextureDesc {
 pub struct FrameComposer<'a> {
     ctx: ComposerContext<'a>,
     frame_config: FrameConfig,
-    #[allow(clippy::type_complexity)]
-    hooks: smallvec::SmallVec<
-        [(
-            HookStage,
-            Option<Box<dyn FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a>>,
-        ); 4],
-    >,
+    hooks: smallvec::SmallVec<[(HookKey, Option<BoxedHook<'a>>); 4]>,
 }
 
 impl<'a> FrameComposer<'a> {
@@ -224,7 +282,11 @@ impl<'a> FrameComposer<'a> {
             height: size.1,
             depth_format: ctx.wgpu_ctx.depth_format,
             msaa_samples: ctx.wgpu_ctx.msaa_samples,
-            surface_format: ctx.wgpu_ctx.surface_view_format,
+            surface_format: if ctx.probe_capture.is_some() {
+                crate::HDR_TEXTURE_FORMAT
+            } else {
+                ctx.wgpu_ctx.surface_view_format
+            },
             hdr_format: crate::HDR_TEXTURE_FORMAT,
         };
 
@@ -278,11 +340,87 @@ impl<'a> FrameComposer<'a> {
     /// ```
     #[inline]
     #[must_use]
-    pub fn add_custom_pass<F>(mut self, stage: HookStage, hook: F) -> Self
+    pub fn add_custom_pass<F>(self, stage: HookStage, hook: F) -> Self
+    where
+        F: FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a,
+    {
+        self.push_hook(stage, None, HookOrder::Append, hook)
+    }
+
+    /// Registers a custom pass hook under `name`, so that other hooks can be
+    /// anchored to it with [`add_custom_pass_before`](Self::add_custom_pass_before)
+    /// or [`add_custom_pass_after`](Self::add_custom_pass_after).
+    ///
+    /// Otherwise behaves exactly like [`add_custom_pass`](Self::add_custom_pass).
+    #[inline]
+    #[must_use]
+    pub fn add_named_custom_pass<F>(self, stage: HookStage, name: &'static str, hook: F) -> Self
     where
         F: FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a,
     {
-        self.hooks.push((stage, Some(Box::new(hook))));
+        self.push_hook(stage, Some(name), HookOrder::Append, hook)
+    }
+
+    /// Registers a named hook that runs strictly before the hook named `anchor`.
+    ///
+    /// This lets plugins position themselves relative to each other without
+    /// depending on registration order — e.g. a colour-grading overlay that
+    /// must land after tone mapping but before the UI:
+    ///
+    /// ```ignore
+    /// composer
+    ///     .add_named_custom_pass(HookStage::AfterPostProcess, "ui", ui_hook)
+    ///     .add_custom_pass_before(HookStage::AfterPostProcess, "ui", "overlay", overlay_hook)
+    ///     .render();
+    /// ```
+    ///
+    /// An anchor that is not registered this frame is ignored; the warning is
+    /// logged once per hook rather than every frame.
+    #[inline]
+    #[must_use]
+    pub fn add_custom_pass_before<F>(
+        self,
+        stage: HookStage,
+        anchor: &'static str,
+        name: &'static str,
+        hook: F,
+    ) -> Self
+    where
+        F: FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a,
+    {
+        self.push_hook(stage, Some(name), HookOrder::Before(anchor), hook)
+    }
+
+    /// Registers a named hook that runs strictly after the hook named `anchor`.
+    ///
+    /// See [`add_custom_pass_before`](Self::add_custom_pass_before).
+    #[inline]
+    #[must_use]
+    pub fn add_custom_pass_after<F>(
+        self,
+        stage: HookStage,
+        anchor: &'static str,
+        name: &'static str,
+        hook: F,
+    ) -> Self
+    where
+        F: FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a,
+    {
+        self.push_hook(stage, Some(name), HookOrder::After(anchor), hook)
+    }
+
+    fn push_hook<F>(
+        mut self,
+        stage: HookStage,
+        name: Option<&'static str>,
+        order: HookOrder,
+        hook: F,
+    ) -> Self
+    where
+        F: FnOnce(&mut RenderGraph<'a>, GraphBlackboard) -> GraphBlackboard + 'a,
+    {
+        let key = HookKey { stage, name, order };
+        self.hooks.push((key, Some(Box::new(hook))));
         self
     }
 
@@ -305,14 +443,25 @@ impl<'a> FrameComposer<'a> {
     pub fn render(mut self) {
         // ━━━ 1. Acquire Render Target ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 
-        let view_format = self.ctx.wgpu_ctx.surface_view_format;
+        let view_format = self.frame_config.surface_format;
+        let probe_capture = self.ctx.probe_capture;
 
         // Acquire either the swap-chain back buffer or the headless texture view.
         // `surface_output` is `Some` only in windowed mode and holds the
         // `SurfaceTexture` that must be `.present()`ed after submission.
+        // A probe capture frame targets the probe face instead.
         let (surface_view, width, height, surface_output);
 
-        if let Some(surface) = &self.ctx.wgpu_ctx.surface {
+        if let Some(capture) = probe_capture {
+            let Some((cube_texture, _)) = capture.cube(self.ctx.resource_manager) else {
+                log::error!("Probe capture target is missing");
+                return;
+            };
+            surface_view = capture.face_view(cube_texture);
+            width = cube_texture.width();
+            height = cube_texture.height();
+            surface_output = None;
+        } else if let Some(surface) = &self.ctx.wgpu_ctx.surface {
             let output = match surface.get_current_texture() {
                 wgpu::CurrentSurfaceTexture::Success(frame) => frame,
                 wgpu::CurrentSurfaceTexture::Suboptimal(frame) => {
@@ -349,6 +498,17 @@ impl<'a> FrameComposer<'a> {
 
         let resource_manager_ptr = self.ctx.resource_manager as *mut ResourceManager;
 
+        let before_post_hooks = take_hooks(
+            &mut self.hooks,
+            HookStage::BeforePostProcess,
+            self.ctx.hook_anchor_warnings,
+        );
+        let after_post_hooks = take_hooks(
+            &mut self.hooks,
+            HookStage::AfterPostProcess,
+            self.ctx.hook_anchor_warnings,
+        );
+
         // ━━━ 2. Build Unified RDG ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 
         let mut graph = RenderGraph::new(self.ctx.graph_storage, self.ctx.frame_arena);
@@ -399,14 +559,18 @@ impl<'a> FrameComposer<'a> {
         let (dbg_needs_normal, dbg_needs_velocity) = (false, false);
 
         let taa_enabled = self.ctx.camera.aa_mode.is_taa();
-        let needs_normal = ssao_enabled || needs_feature_id || dbg_needs_normal;
+        let needs_normal =
+            ssao_enabled || needs_feature_id || dbg_needs_normal || self.ctx.scene_normals;
         let needs_velocity = taa_enabled || dbg_needs_velocity;
 
         // let needs_normal = ssao_enabled || needs_feature_id;
         let needs_skybox = self.ctx.scene.background.needs_skybox_pass();
         let ssss_enabled = self.ctx.scene.screen_space.enable_sss;
         let has_transmission = self.ctx.render_lists.use_transmission;
+        let has_weighted_oit = !self.ctx.render_lists.weighted_oit.is_empty();
         let bloom_enabled = self.ctx.scene.bloom.enabled && is_high_fidelity;
+        let auto_exposure_enabled =
+            self.ctx.scene.tone_mapping.auto_exposure.enabled && is_high_fidelity;
         let has_active_environment = matches!(
             self.ctx.scene.background.mode,
             myth_scene::background::BackgroundMode::Procedural(_)
@@ -473,7 +637,7 @@ impl<'a> FrameComposer<'a> {
                                 if bake_environment {
                                     self.ctx.ibl_pass.add_to_graph(
                                         c,
-                                        scene_id,
+                                        IblTarget::Scene(scene_id),
                                         env.base_cube,
                                         env.pmrem,
                                     );
@@ -493,7 +657,7 @@ impl<'a> FrameComposer<'a> {
                                 );
                                 self.ctx.ibl_pass.add_to_graph(
                                     c,
-                                    scene_id,
+                                    IblTarget::Scene(scene_id),
                                     env.base_cube,
                                     env.pmrem,
                                 );
@@ -525,6 +689,7 @@ impl<'a> FrameComposer<'a> {
             // Track scene_color / scene_depth for the GraphBlackboard (hooks).
             let mut bb_scene_color = None;
             let mut bb_scene_depth = None;
+            let mut bb_scene_normals = None;
 
             // Debug view: capture intermediate texture IDs for safe resolution.
             #[cfg(feature = "debug_view")]
@@ -573,6 +738,8 @@ impl<'a> FrameComposer<'a> {
                                 prepass_out
                                     .scene_normals
                                     .expect("SSAO requires scene normals from Prepass"),
+                                self.ctx.scene.ssao.half_resolution,
+                                self.ctx.scene.ssao.blur_passes,
                             ),
                         )
                     } else {
@@ -594,6 +761,12 @@ impl<'a> FrameComposer<'a> {
 
                     let mut active_color = opaque_out.active_color;
 
+                    // Bounding-box occlusion queries against the opaque depth;
+                    // results drive next frame's culling.
+                    self.ctx
+                        .occlusion_pass
+                        .add_to_graph(c, opaque_out.active_depth);
+
                     // 4. SSSS
                     if ssss_enabled {
                         if is_msaa {
@@ -625,6 +798,13 @@ impl<'a> FrameComposer<'a> {
                         }
                     }
 
+                    // Decals sample the prepass depth and blend over the lit
+                    // opaque surfaces.
+                    active_color =
+                        self.ctx
+                            .decal_pass
+                            .add_to_graph(c, active_color, prepass_out.scene_depth);
+
                     // 5. Skybox
                     if needs_skybox {
                         active_color = self.ctx.skybox_pass.add_to_graph(
@@ -635,6 +815,27 @@ impl<'a> FrameComposer<'a> {
                         );
                     }
 
+                    // The ground shadow is drawn like the grid, and below it.
+                    active_color = self.ctx.ground_shadow_pass.add_to_graph(
+                        c,
+                        active_color,
+                        opaque_out.active_depth,
+                    );
+
+                    // The ground grid writes its own depth per pixel, so it is
+                    // depth-tested against the opaque geometry.
+                    active_color =
+                        self.ctx
+                            .grid_pass
+                            .add_to_graph(c, active_color, opaque_out.active_depth);
+
+                    // Debug lines share the scene depth so they can be occluded.
+                    active_color = self.ctx.debug_lines_pass.add_to_graph(
+                        c,
+                        active_color,
+                        opaque_out.active_depth,
+                    );
+
                     // ── 6. TAA Resolve ────────────────────────────────────────────
                     // Resolve temporal anti-aliasing before bloom/tone-mapping.
                     // The resolved colour replaces post_transparent_color for
@@ -679,6 +880,25 @@ impl<'a> FrameComposer<'a> {
                         None
                     };
 
+                    // 8a. Weighted blended OIT (accumulate + composite)
+                    if has_weighted_oit {
+                        c.with_group("Weighted_OIT", |c| {
+                            let oit_targets = self.ctx.transparent_pass.add_weighted_oit_to_graph(
+                                c,
+                                opaque_out.active_depth,
+                                transmission_tex,
+                                ssao_output,
+                                shadow_output.shadow_2d,
+                                shadow_output.shadow_cube,
+                            );
+                            active_color = self.ctx.weighted_oit_pass.add_to_graph(
+                                c,
+                                active_color,
+                                oit_targets,
+                            );
+                        });
+                    }
+
                     // 8. Transparent
                     let active_color = self.ctx.transparent_pass.add_to_graph(
                         c,
@@ -690,6 +910,8 @@ impl<'a> FrameComposer<'a> {
                         shadow_output.shadow_cube,
                     );
 
+                    bb_scene_normals = prepass_out.scene_normals;
+
                     // Capture intermediate IDs for debug view resolution.
                     #[cfg(feature = "debug_view")]
                     {
@@ -701,93 +923,161 @@ impl<'a> FrameComposer<'a> {
                     (active_color, scene_depth)
                 });
 
-                // ── Before-Post-Process Hooks ──────────────────────────────
+                if let Some(capture) = probe_capture
+                    && let ProbeCaptureKind::LightProbe(_) = capture.kind
                 {
-                    let mut blackboard = GraphBlackboard {
-                        scene_color: Some(active_color),
-                        scene_depth: Some(scene_depth),
-                        surface_out,
-                    };
-                    for (stage, hook_opt) in &mut self.hooks {
-                        if *stage == HookStage::BeforePostProcess
-                            && let Some(hook) = hook_opt.take()
-                        {
+                    // ── Light Probe Capture ────────────────────────────────
+                    // The last face projects the capture onto the probe's
+                    // spherical harmonics.
+                    let (cube_texture, cube_view) = capture
+                        .cube(self.ctx.resource_manager)
+                        .expect("probe capture target checked above");
+                    graph_ctx.with_group("Light_Probe", |c| {
+                        let capture_cube = c.graph.import_external_resource(
+                            "LightProbe_Capture",
+                            probe_base_cube_desc(cube_texture),
+                            cube_view,
+                        );
+                        let capture_cube =
+                            add_probe_face_copy_pass(c, active_color, capture_cube, capture.face);
+
+                        if capture.is_last_face() {
+                            self.ctx.light_probe_pass.add_to_graph(c, capture_cube);
+                        }
+                    });
+                } else if let Some(capture) = probe_capture
+                    && let ProbeCaptureKind::Reflection(node) = capture.kind
+                {
+                    // ── Reflection Probe Capture ───────────────────────────
+                    // The linear HDR colour goes straight into the probe
+                    // face; the last face also prefilters the cubemap.
+                    let gpu_probe = self
+                        .ctx
+                        .resource_manager
+                        .gpu_reflection_probe(capture.scene_id, node)
+                        .expect("probe capture target checked above");
+                    graph_ctx.with_group("Reflection_Probe", |c| {
+                        let base_cube = c.graph.import_external_resource(
+                            "Probe_BaseCube",
+                            probe_base_cube_desc(&gpu_probe.base_cube_texture),
+                            &gpu_probe.base_cube_view,
+                        );
+                        let base_cube =
+                            add_probe_face_copy_pass(c, active_color, base_cube, capture.face);
+
+                        if capture.is_last_face() {
+                            let pmrem = c.graph.import_external_resource(
+                                "Probe_PMREM",
+                                pmrem_desc(&gpu_probe.pmrem_texture),
+                                &gpu_probe.pmrem_view,
+                            );
+                            let base_cube =
+                                add_generate_mipmap_pass(c, base_cube, "Probe_BaseCube_Mipped");
+                            self.ctx.ibl_pass.add_to_graph(
+                                c,
+                                IblTarget::ReflectionProbe(capture.scene_id, node),
+                                base_cube,
+                                pmrem,
+                            );
+                        }
+                    });
+                } else {
+                    // ── Before-Post-Process Hooks ──────────────────────────────
+                    {
+                        let mut blackboard = GraphBlackboard {
+                            scene_color: Some(active_color),
+                            scene_depth: Some(scene_depth),
+                            scene_normals: bb_scene_normals,
+                            surface_out,
+                        };
+                        for hook in before_post_hooks {
                             blackboard = hook(graph_ctx.graph, blackboard);
                         }
-                    }
 
-                    active_color = blackboard.scene_color.unwrap_or(active_color);
-                    scene_depth = blackboard.scene_depth.unwrap_or(scene_depth);
-                }
-
-                // ── Post-Processing Group ──────────────────────────────────
-                current_surface = graph_ctx.with_group("PostProcess", |ctx| {
-                    // Bloom (internally flattened into Bloom_System subgroup)
-                    if bloom_enabled {
-                        active_color = self.ctx.bloom_pass.add_to_graph(
-                            ctx,
-                            active_color,
-                            self.ctx.scene.bloom.karis_average,
-                            self.ctx.scene.bloom.max_mip_levels(),
-                        );
+                        active_color = blackboard.scene_color.unwrap_or(active_color);
+                        scene_depth = blackboard.scene_depth.unwrap_or(scene_depth);
                     }
 
-                    // ToneMapping: HDR → LDR
-                    let mut surface = if fxaa_enabled {
-                        // Route through an intermediate LDR texture for FXAA input
-                        let ldr =
-                            ctx.graph
-                                .register_texture("LDR_Intermediate", surface_desc, false);
-                        self.ctx.tone_map_pass.add_to_graph(ctx, active_color, ldr)
-                    } else {
-                        self.ctx
-                            .tone_map_pass
-                            .add_to_graph(ctx, active_color, current_surface)
-                    };
-
-                    // FXAA: anti-alias the LDR result onto the surface
-                    if fxaa_enabled {
-                        let ldr_intermediate = surface;
-                        surface =
-                            self.ctx
-                                .fxaa_pass
-                                .add_to_graph(ctx, ldr_intermediate, current_surface);
-                    }
+                    // ── Post-Processing Group ──────────────────────────────────
+                    current_surface = graph_ctx.with_group("PostProcess", |ctx| {
+                        // Bloom (internally flattened into Bloom_System subgroup)
+                        if bloom_enabled {
+                            active_color = self.ctx.bloom_pass.add_to_graph(
+                                ctx,
+                                active_color,
+                                self.ctx.scene.bloom.karis_average,
+                                self.ctx.scene.bloom.max_mip_levels(),
+                            );
+                        }
 
-                    bb_scene_color = Some(active_color);
-                    bb_scene_depth = Some(scene_depth);
+                        // Auto exposure: measure the HDR result and adapt
+                        let exposure = auto_exposure_enabled
+                            .then(|| self.ctx.auto_exposure_pass.add_to_graph(ctx, active_color));
 
-                    surface
-                });
+                        // ToneMapping: HDR → LDR
+                        let mut surface = if fxaa_enabled {
+                            // Route through an intermediate LDR texture for FXAA input
+                            let ldr =
+                                ctx.graph
+                                    .register_texture("LDR_Intermediate", surface_desc, false);
+                            self.ctx
+                                .tone_map_pass
+                                .add_to_graph(ctx, active_color, ldr, exposure)
+                        } else {
+                            self.ctx.tone_map_pass.add_to_graph(
+                                ctx,
+                                active_color,
+                                current_surface,
+                                exposure,
+                            )
+                        };
+
+                        // FXAA: anti-alias the LDR result onto the surface
+                        if fxaa_enabled {
+                            let ldr_intermediate = surface;
+                            surface = self.ctx.fxaa_pass.add_to_graph(
+                                ctx,
+                                ldr_intermediate,
+                                current_surface,
+                            );
+                        }
 
-                // ── Debug View Override ────────────────────────────────────
-                // Resolve the semantic DebugViewMode to a concrete
-                // TextureNodeId, then blit it onto the surface.  Targets
-                // whose producer was disabled (e.g. SSAO off) safely
-                // resolve to None — no pass is injected.
-                // Material-override modes are handled separately via shader
-                // defines and do not use this post-process path.
-                #[cfg(feature = "debug_view")]
-                {
-                    use crate::graph::render_state::DebugViewTarget;
-                    let target = DebugViewTarget::from_mode(self.ctx.render_state.debug_view_mode);
-                    let source: Option<crate::graph::core::TextureNodeId> = match target {
-                        DebugViewTarget::SceneNormal => dbg_normals,
-                        DebugViewTarget::Velocity => dbg_velocity,
-                        DebugViewTarget::SsaoRaw => dbg_ssao,
-                        DebugViewTarget::SceneDepth => Some(scene_depth),
-                        _ => None,
-                    };
+                        bb_scene_color = Some(active_color);
+                        bb_scene_depth = Some(scene_depth);
 
-                    let is_depth = target == DebugViewTarget::SceneDepth;
+                        surface
+                    });
 
-                    if let Some(src) = source {
-                        current_surface = self.ctx.debug_view_pass.add_to_graph(
-                            &mut graph_ctx,
-                            src,
-                            current_surface,
-                            is_depth,
-                        );
+                    // ── Debug View Override ────────────────────────────────────
+                    // Resolve the semantic DebugViewMode to a concrete
+                    // TextureNodeId, then blit it onto the surface.  Targets
+                    // whose producer was disabled (e.g. SSAO off) safely
+                    // resolve to None — no pass is injected.
+                    // Material-override modes are handled separately via shader
+                    // defines and do not use this post-process path.
+                    #[cfg(feature = "debug_view")]
+                    {
+                        use crate::graph::render_state::DebugViewTarget;
+                        let target =
+                            DebugViewTarget::from_mode(self.ctx.render_state.debug_view_mode);
+                        let source: Option<crate::graph::core::TextureNodeId> = match target {
+                            DebugViewTarget::SceneNormal => dbg_normals,
+                            DebugViewTarget::Velocity => dbg_velocity,
+                            DebugViewTarget::SsaoRaw => dbg_ssao,
+                            DebugViewTarget::SceneDepth => Some(scene_depth),
+                            _ => None,
+                        };
+
+                        let is_depth = target == DebugViewTarget::SceneDepth;
+
+                        if let Some(src) = source {
+                            current_surface = self.ctx.debug_view_pass.add_to_graph(
+                                &mut graph_ctx,
+                                src,
+                                current_surface,
+                                is_depth,
+                            );
+                        }
                     }
                 }
             } else {
@@ -816,6 +1106,10 @@ impl<'a> FrameComposer<'a> {
                         surface_out,
                         self.ctx.extracted_scene.background.clear_color(),
                         prepared_skybox,
+                        self.ctx.grid_pass.prepared_draw(self.ctx.pipeline_cache),
+                        self.ctx
+                            .debug_lines_pass
+                            .prepared_draw(self.ctx.pipeline_cache),
                         shadow_output.shadow_2d,
                         shadow_output.shadow_cube,
                         env_dependency_base,
@@ -827,18 +1121,15 @@ impl<'a> FrameComposer<'a> {
             // drop(graph_ctx);
 
             // ── After-Post-Process Hooks (UI, debug overlays) ──────────────
-            {
+            if probe_capture.is_none() {
                 let mut blackboard = GraphBlackboard {
                     scene_color: bb_scene_color,
                     scene_depth: bb_scene_depth,
+                    scene_normals: bb_scene_normals,
                     surface_out: current_surface,
                 };
-                for (stage, hook_opt) in &mut self.hooks {
-                    if *stage == HookStage::AfterPostProcess
-                        && let Some(hook) = hook_opt.take()
-                    {
-                        blackboard = hook(&mut graph, blackboard);
-                    }
+                for hook in after_post_hooks {
+                    blackboard = hook(&mut graph, blackboard);
                 }
             }
 
@@ -884,15 +1175,25 @@ impl<'a> FrameComposer<'a> {
                 None
             };
 
-            let baked_lists = crate::graph::bake::bake_render_lists(
+            let mut baked_lists = crate::graph::bake::bake_render_lists(
                 self.ctx.render_lists,
                 self.ctx.resource_manager,
                 self.ctx.pipeline_cache,
                 &prepass_config,
             );
 
+            // Main-camera draws left to GPU culling become indirect draws.
+            self.ctx.gpu_culling_pass.write_draws(
+                &self.ctx.wgpu_ctx.device,
+                &self.ctx.wgpu_ctx.queue,
+                &baked_lists,
+                self.ctx.resource_manager.object_instance_table(),
+            );
+            self.ctx.gpu_culling_pass.attach(&mut baked_lists);
+
             // ─── 3d. Execute ───────────────────────────────────────────────
 
+            let draw_stats = Cell::new(RenderStats::default());
             let mut execute_ctx = ExecuteContext {
                 resources: &graph.storage.resources,
                 pool: self.ctx.transient_pool,
@@ -904,6 +1205,7 @@ impl<'a> FrameComposer<'a> {
                 baked_lists: &baked_lists,
                 wgpu_ctx: &*self.ctx.wgpu_ctx,
                 current_timeline_index: 0,
+                stats: &draw_stats,
             };
 
             let mut encoder =
@@ -914,20 +1216,79 @@ impl<'a> FrameComposer<'a> {
                         label: Some("Unified Encoder"),
                     });
 
+            if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                profiler.begin_frame(&self.ctx.wgpu_ctx.device);
+            }
+
+            // Not an RDG pass: the indirect buffers are untracked, so the
+            // dispatch is recorded ahead of every graph pass.
+            if self.ctx.gpu_culling_pass.has_draws() {
+                if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                    profiler.begin_pass(&mut encoder, "GPU_Culling");
+                }
+                self.ctx
+                    .gpu_culling_pass
+                    .dispatch(self.ctx.pipeline_cache, &mut encoder);
+                if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                    profiler.end_pass(&mut encoder);
+                }
+            }
+
+            // Likewise for the light clusters, read through the global bind
+            // group by every lit draw.
+            if self.ctx.light_cluster_pass.is_active() {
+                if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                    profiler.begin_pass(&mut encoder, "Light_Clusters");
+                }
+                self.ctx
+                    .light_cluster_pass
+                    .dispatch(self.ctx.pipeline_cache, &mut encoder);
+                if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                    profiler.end_pass(&mut encoder);
+                }
+            }
+
             for (timeline_index, &pass_idx) in graph.storage.execution_queue.iter().enumerate() {
                 execute_ctx.current_timeline_index = timeline_index;
+                let pass_name = graph.storage.passes[pass_idx].name;
                 #[cfg(debug_assertions)]
-                encoder.push_debug_group(graph.storage.passes[pass_idx].name);
+                encoder.push_debug_group(pass_name);
+                if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                    profiler.begin_pass(&mut encoder, pass_name);
+                }
                 graph.storage.passes[pass_idx]
                     .get_pass_mut()
                     .execute(&execute_ctx, &mut encoder);
+                if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                    profiler.end_pass(&mut encoder);
+                }
                 #[cfg(debug_assertions)]
                 encoder.pop_debug_group();
             }
 
+            self.ctx.occlusion_pass.resolve(&mut encoder);
+            if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                profiler.resolve(&mut encoder);
+            }
+
             // ━━━ 4. Submit & Present ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 
             self.ctx.wgpu_ctx.queue.submit(Some(encoder.finish()));
+            if let Some(profiler) = self.ctx.gpu_profiler.as_deref_mut() {
+                profiler.after_submit();
+            }
+
+            let resources = &*self.ctx.resource_manager;
+            *self.ctx.frame_stats = RenderStats {
+                visible_meshes: self.ctx.render_lists.visible_meshes,
+                culled_meshes: self.ctx.render_lists.culled_meshes,
+                material_uniform_uploads: resources.material_uniform_uploads,
+                material_bind_group_rebuilds: resources.material_bind_group_rebuilds,
+                pipelines_compiled: (self.ctx.pipeline_cache.render_pipeline_count()
+                    - self.ctx.pipelines_at_frame_start) as u32,
+                pipelines_pending: self.ctx.pipeline_cache.pending_count() as u32,
+                ..draw_stats.get()
+            };
             scene_environment_updated
         };
 
@@ -95,6 +95,9 @@ pub(crate) struct PhysicalTexture {
     pub(crate) default_view: Tracked<wgpu::TextureView>,
     /// Optional cached view for the first mip level.
     pub(crate) base_mip_view: Option<Tracked<wgpu::TextureView>>,
+    /// Depth-aspect view of depth-stencil formats, which cannot be sampled
+    /// through the combined default view.
+    pub(crate) depth_view: Option<Tracked<wgpu::TextureView>>,
     /// Lazily-populated sub-view cache.
     pub(crate) sub_views: FxHashMap<SubViewKey, Tracked<wgpu::TextureView>>,
     /// Frame index of the last access (acquire or sub-view retrieval). Used for eviction.
@@ -324,12 +327,23 @@ impl TransientPool {
             None
         };
 
+        let depth_view = (desc.format.has_stencil_aspect()
+            && desc.usage.contains(wgpu::TextureUsages::TEXTURE_BINDING))
+        .then(|| {
+            Tracked::new(texture.create_view(&wgpu::TextureViewDescriptor {
+                label: Some("Depth Aspect View"),
+                aspect: wgpu::TextureAspect::DepthOnly,
+                ..Default::default()
+            }))
+        });
+
         let physical_tex = PhysicalTexture {
             uid: self.uid_counter,
             desc: *desc,
             texture,
             default_view,
             base_mip_view: base_mip,
+            depth_view,
             sub_views: FxHashMap::default(),
             last_accessed_frame: self.current_frame_index,
         };
@@ -465,6 +479,15 @@ impl TransientPool {
         &self.get_tex(index).default_view
     }
 
+    /// Returns the tracked view used for sampling: the depth-aspect view of
+    /// depth-stencil formats, the default view otherwise.
+    #[inline]
+    #[must_use]
+    pub fn get_sample_view(&self, index: usize) -> &Tracked<wgpu::TextureView> {
+        let tex = self.get_tex(index);
+        tex.depth_view.as_ref().unwrap_or(&tex.default_view)
+    }
+
     /// Returns the raw `wgpu::Texture` handle.
     #[inline]
     #[must_use]
@@ -7,6 +7,16 @@
 //!
 //! [`CustomPassHook`] is a builder-time callback that lets external code
 //! inject arbitrary [`PassNode`]s into the graph at a chosen stage.
+//! Within a stage, hooks run in registration order unless a [`HookOrder`]
+//! anchors them before or after another named hook. Passes a hook adds are
+//! ordered by the resources they read and write, so a pass that mutates
+//! [`SceneAttachment::HdrColor`] in [`HookStage::BeforePostProcess`] runs
+//! after the scene passes and before Bloom and tone mapping.
+//!
+//! [`PassNode`]: super::node::PassNode
+
+use rustc_hash::FxHashSet;
+use smallvec::SmallVec;
 
 use super::types::TextureNodeId;
 
@@ -23,21 +33,76 @@ use super::types::TextureNodeId;
 /// |------|----------|------------------|
 /// | `scene_color` | HDR scene colour buffer | Custom post-FX |
 /// | `scene_depth` | Main depth buffer (reverse-Z) | Depth-aware FX |
+/// | `scene_normals` | View-space normals from the prepass | Edge / AO FX |
 /// | `surface_out` | Final swap-chain output | UI overlay |
+///
+/// The slots can also be looked up by a stable [`SceneAttachment`] key with
+/// [`attachment`](Self::attachment), which is what third-party passes
+/// should prefer.
 #[derive(Clone, Copy)]
 pub struct GraphBlackboard {
     /// HDR scene colour render target (written by Opaque / Skybox / Transparent).
     pub scene_color: Option<TextureNodeId>,
     /// Main depth buffer (reverse-Z, written by scene passes).
     pub scene_depth: Option<TextureNodeId>,
+    /// View-space normals packed to `[0, 1]` (`Rgba8Unorm`), written by the
+    /// prepass. Only present when a built-in effect needs them or
+    /// [`RendererSettings::scene_normals`](crate::RendererSettings::scene_normals)
+    /// is set.
+    pub scene_normals: Option<TextureNodeId>,
     /// Final swap-chain output target.  UI and overlays should write here.
     pub surface_out: TextureNodeId,
 }
 
+/// Stable keys for the attachments published on a [`GraphBlackboard`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SceneAttachment {
+    /// HDR scene colour ([`HDR_TEXTURE_FORMAT`](crate::HDR_TEXTURE_FORMAT)).
+    /// Only present on the `HighFidelity` path.
+    HdrColor,
+    /// Main depth buffer (reverse-Z). Only present on the `HighFidelity` path.
+    Depth,
+    /// View-space normals; see [`GraphBlackboard::scene_normals`].
+    Normal,
+    /// Final output target (swap-chain or headless texture).
+    SurfaceOut,
+}
+
+impl GraphBlackboard {
+    /// Returns the current version of the attachment `key`, or `None` if
+    /// this frame does not produce it.
+    #[must_use]
+    pub fn attachment(&self, key: SceneAttachment) -> Option<TextureNodeId> {
+        match key {
+            SceneAttachment::HdrColor => self.scene_color,
+            SceneAttachment::Depth => self.scene_depth,
+            SceneAttachment::Normal => self.scene_normals,
+            SceneAttachment::SurfaceOut => Some(self.surface_out),
+        }
+    }
+
+    /// Returns a copy with the attachment `key` replaced by `id`, typically
+    /// the output of a pass that mutated it.
+    ///
+    /// Downstream passes consume the new version: a hook in
+    /// [`HookStage::BeforePostProcess`] that replaces
+    /// [`SceneAttachment::HdrColor`] feeds Bloom and tone mapping.
+    #[must_use]
+    pub fn with_attachment(mut self, key: SceneAttachment, id: TextureNodeId) -> Self {
+        match key {
+            SceneAttachment::HdrColor => self.scene_color = Some(id),
+            SceneAttachment::Depth => self.scene_depth = Some(id),
+            SceneAttachment::Normal => self.scene_normals = Some(id),
+            SceneAttachment::SurfaceOut => self.surface_out = id,
+        }
+        self
+    }
+}
+
 /// Injection stage for custom pass hooks.
 ///
 /// Determines **when** in the pipeline the hook's passes are wired.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum HookStage {
     /// After scene rendering, before post-processing (Bloom, ToneMap, FXAA).
     BeforePostProcess,
@@ -46,6 +111,116 @@ pub enum HookStage {
     AfterPostProcess,
 }
 
+/// Placement of a custom pass hook relative to other hooks.
+///
+/// Anchors refer to the `name` a hook was registered with (see
+/// [`FrameComposer::add_named_custom_pass`](crate::graph::FrameComposer::add_named_custom_pass)).
+/// An anchor in a different [`HookStage`] is already ordered by the stage
+/// itself; unknown anchors fall back to [`HookOrder::Append`] and are
+/// reported once per hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookOrder {
+    /// Runs after all hooks registered earlier in the same stage.
+    Append,
+    /// Runs strictly before the hook with the given name.
+    Before(&'static str),
+    /// Runs strictly after the hook with the given name.
+    After(&'static str),
+}
+
+/// Stage, name and ordering constraint of a registered hook.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct HookKey {
+    pub stage: HookStage,
+    pub name: Option<&'static str>,
+    pub order: HookOrder,
+}
+
+/// `(hook, anchor)` pairs whose anchor problem (unknown, cross-stage or
+/// cyclic) has already been logged.
+///
+/// Hooks are re-registered every frame, so this lives with the renderer to
+/// keep a bad anchor from warning on every frame.
+pub(crate) type HookAnchorWarnings = FxHashSet<(&'static str, &'static str)>;
+
+/// Returns the indices of all hooks in `stage`, sorted so that every
+/// [`HookOrder`] constraint is respected.
+///
+/// Unconstrained hooks keep their registration order (stable topological
+/// sort). Cycles and contradictory cross-stage anchors are resolved by
+/// falling back to registration order. Anchor problems are logged once per
+/// hook and anchor, as recorded in `warned`.
+pub(crate) fn resolve_hook_order(
+    keys: &[HookKey],
+    stage: HookStage,
+    warned: &mut HookAnchorWarnings,
+) -> SmallVec<[usize; 4]> {
+    let find = |name: &str| keys.iter().position(|k| k.name == Some(name));
+
+    let members: SmallVec<[usize; 4]> = (0..keys.len())
+        .filter(|&i| keys[i].stage == stage)
+        .collect();
+
+    // `(before, after)` pairs between hooks of this stage.
+    let mut edges: SmallVec<[(usize, usize); 4]> = SmallVec::new();
+    for &i in &members {
+        let (anchor, self_first) = match keys[i].order {
+            HookOrder::Append => continue,
+            HookOrder::Before(anchor) => (anchor, true),
+            HookOrder::After(anchor) => (anchor, false),
+        };
+        let name = keys[i].name.unwrap_or_default();
+        let Some(j) = find(anchor) else {
+            if warned.insert((name, anchor)) {
+                log::warn!(
+                    "Custom pass hook '{name}' anchor '{anchor}' not found; appending instead"
+                );
+            }
+            continue;
+        };
+        if i == j {
+            continue;
+        }
+        let other_stage = keys[j].stage;
+        if other_stage == stage {
+            edges.push(if self_first { (i, j) } else { (j, i) });
+        } else if (other_stage < stage) == self_first && warned.insert((name, anchor)) {
+            log::warn!(
+                "Custom pass hook in {stage:?} cannot run {} '{anchor}' in {other_stage:?}",
+                if self_first { "before" } else { "after" },
+            );
+        }
+    }
+
+    let mut sorted: SmallVec<[usize; 4]> = SmallVec::with_capacity(members.len());
+    let mut pending = members;
+    while !pending.is_empty() {
+        let ready = pending.iter().position(|&i| {
+            !edges
+                .iter()
+                .any(|&(before, after)| after == i && pending.contains(&before))
+        });
+        let Some(pos) = ready else {
+            // Report the cycle once, when one of its anchors is first seen.
+            let mut first_report = false;
+            for &i in &pending {
+                if let HookOrder::Before(anchor) | HookOrder::After(anchor) = keys[i].order {
+                    first_report |= warned.insert((keys[i].name.unwrap_or_default(), anchor));
+                }
+            }
+            if first_report {
+                log::warn!(
+                    "Cyclic custom pass hook ordering in {stage:?}; using registration order"
+                );
+            }
+            sorted.extend(pending.drain(..));
+            break;
+        };
+        sorted.push(pending.remove(pos));
+    }
+    sorted
+}
+
 /// A builder-time callback that injects passes into the render graph.
 ///
 /// The closure **consumes** the current [`GraphBlackboard`] and **returns**
@@ -68,3 +243,127 @@ pub enum HookStage {
 pub type CustomPassHook<'a> = Box<
     dyn for<'g> FnMut(&mut super::graph::RenderGraph<'g>, GraphBlackboard) -> GraphBlackboard + 'a,
 >;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(keys: &[HookKey], stage: HookStage) -> SmallVec<[usize; 4]> {
+        resolve_hook_order(keys, stage, &mut HookAnchorWarnings::default())
+    }
+
+    fn key(stage: HookStage, name: &'static str, order: HookOrder) -> HookKey {
+        HookKey {
+            stage,
+            name: Some(name),
+            order,
+        }
+    }
+
+    #[test]
+    fn test_unconstrained_hooks_keep_registration_order() {
+        let keys = [
+            key(HookStage::AfterPostProcess, "a", HookOrder::Append),
+            key(HookStage::BeforePostProcess, "b", HookOrder::Append),
+            key(HookStage::AfterPostProcess, "c", HookOrder::Append),
+        ];
+        assert_eq!(
+            resolve(&keys, HookStage::AfterPostProcess).as_slice(),
+            &[0, 2]
+        );
+        assert_eq!(
+            resolve(&keys, HookStage::BeforePostProcess).as_slice(),
+            &[1]
+        );
+    }
+
+    #[test]
+    fn test_before_and_after_anchors() {
+        let keys = [
+            key(HookStage::AfterPostProcess, "ui", HookOrder::Append),
+            key(
+                HookStage::AfterPostProcess,
+                "overlay",
+                HookOrder::Before("ui"),
+            ),
+            key(
+                HookStage::AfterPostProcess,
+                "grade",
+                HookOrder::Before("overlay"),
+            ),
+            key(
+                HookStage::AfterPostProcess,
+                "cursor",
+                HookOrder::After("ui"),
+            ),
+            key(HookStage::AfterPostProcess, "late", HookOrder::Append),
+        ];
+        assert_eq!(
+            resolve(&keys, HookStage::AfterPostProcess).as_slice(),
+            &[2, 1, 0, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_cross_stage_and_unknown_anchors_are_ignored() {
+        let keys = [
+            key(HookStage::AfterPostProcess, "a", HookOrder::Append),
+            key(
+                HookStage::AfterPostProcess,
+                "b",
+                HookOrder::After("missing"),
+            ),
+            key(HookStage::BeforePostProcess, "c", HookOrder::Before("a")),
+        ];
+        let mut warned = HookAnchorWarnings::default();
+        for _ in 0..2 {
+            assert_eq!(
+                resolve_hook_order(&keys, HookStage::AfterPostProcess, &mut warned).as_slice(),
+                &[0, 1]
+            );
+        }
+        // The unknown anchor is recorded once; the later frame stays quiet.
+        assert_eq!(warned.len(), 1);
+        assert!(warned.contains(&("b", "missing")));
+    }
+
+    #[test]
+    fn test_attachment_keys_map_to_slots() {
+        let bb = GraphBlackboard {
+            scene_color: Some(TextureNodeId::from_index(1)),
+            scene_depth: Some(TextureNodeId::from_index(2)),
+            scene_normals: None,
+            surface_out: TextureNodeId::from_index(3),
+        };
+        assert_eq!(
+            bb.attachment(SceneAttachment::HdrColor),
+            Some(TextureNodeId::from_index(1))
+        );
+        assert_eq!(bb.attachment(SceneAttachment::Normal), None);
+
+        let bb = bb
+            .with_attachment(SceneAttachment::HdrColor, TextureNodeId::from_index(4))
+            .with_attachment(SceneAttachment::SurfaceOut, TextureNodeId::from_index(5));
+        assert_eq!(bb.scene_color, Some(TextureNodeId::from_index(4)));
+        assert_eq!(bb.scene_depth, Some(TextureNodeId::from_index(2)));
+        assert_eq!(bb.surface_out, TextureNodeId::from_index(5));
+    }
+
+    #[test]
+    fn test_cycle_falls_back_to_registration_order() {
+        let keys = [
+            key(HookStage::AfterPostProcess, "a", HookOrder::After("b")),
+            key(HookStage::AfterPostProcess, "b", HookOrder::After("a")),
+        ];
+        let mut warned = HookAnchorWarnings::default();
+        for _ in 0..2 {
+            assert_eq!(
+                resolve_hook_order(&keys, HookStage::AfterPostProcess, &mut warned).as_slice(),
+                &[0, 1]
+            );
+        }
+        // Both edges of the cycle are recorded, so later frames stay quiet.
+        assert_eq!(warned.len(), 2);
+        assert!(warned.contains(&("a", "b")) && warned.contains(&("b", "a")));
+    }
+}
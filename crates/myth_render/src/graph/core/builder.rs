@@ -2,8 +2,8 @@ use crate::core::gpu::Tracked;
 
 use super::graph::RenderGraph;
 use super::types::{
-    BufferDesc, BufferNodeId, GraphResourceType, ResourceClass, ResourceNodeId, TextureDesc,
-    TextureNodeId,
+    BufferDesc, BufferNodeId, ErasedResourceNodeId, GraphResourceType, ResourceClass,
+    ResourceNodeId, TextureDesc, TextureNodeId,
 };
 
 /// Builder for declaring a pass's resource dependencies during eager graph
@@ -19,6 +19,25 @@ pub struct PassBuilder<'graph, 'a> {
 }
 
 impl PassBuilder<'_, '_> {
+    /// Creates a transient texture produced by this pass.
+    ///
+    /// This is how custom passes obtain scratch render targets.  The
+    /// physical texture is taken from the [`TransientPool`] when the graph
+    /// is compiled and recycled automatically, subject to these rules:
+    ///
+    /// - **Frame lifetime** — the ID and the views resolved from it
+    ///   ([`ExecuteContext::get_texture_view`], [`PrepareContext`] bind
+    ///   groups) are valid for the current frame only.  Never cache them.
+    /// - **Aliasing** — the memory is only reserved between this pass and
+    ///   the last pass that reads the texture.  Outside that window it may
+    ///   be shared with other transients, so its initial contents are
+    ///   undefined: clear it or fully overwrite it on first use.
+    /// - **Culling** — if no live pass reads the texture (and this pass has
+    ///   no other consumed output or side effect), the pass is culled.
+    ///
+    /// [`TransientPool`]: super::TransientPool
+    /// [`ExecuteContext::get_texture_view`]: super::ExecuteContext::get_texture_view
+    /// [`PrepareContext`]: super::PrepareContext
     pub fn create_texture(&mut self, name: &'static str, desc: TextureDesc) -> TextureNodeId {
         let id = self.graph.register_texture(name, desc, false);
         self.graph.storage.passes[self.pass_index]
@@ -35,6 +54,13 @@ impl PassBuilder<'_, '_> {
         self.write(id)
     }
 
+    /// Returns the descriptor of a texture resource, so scratch targets can
+    /// be derived from an input (see [`RenderGraph::texture_desc`]).
+    #[must_use]
+    pub fn texture_desc(&self, id: TextureNodeId) -> TextureDesc {
+        self.graph.texture_desc(id)
+    }
+
     pub fn read_external_texture(
         &mut self,
         name: &'static str,
@@ -76,16 +102,23 @@ impl PassBuilder<'_, '_> {
     }
 
     pub fn read<T: GraphResourceType>(&mut self, id: ResourceNodeId<T>) -> ResourceNodeId<T> {
-        let raw = id.erase();
+        self.read_raw(id.erase());
+        id
+    }
+
+    pub fn write<T: GraphResourceType>(&mut self, id: ResourceNodeId<T>) -> ResourceNodeId<T> {
+        self.write_raw(id.erase());
+        id
+    }
+
+    fn read_raw(&mut self, raw: ErasedResourceNodeId) {
         self.graph.storage.passes[self.pass_index].reads.push(raw);
         self.graph.storage.resources[raw.index() as usize]
             .consumers
             .push(self.pass_index);
-        id
     }
 
-    pub fn write<T: GraphResourceType>(&mut self, id: ResourceNodeId<T>) -> ResourceNodeId<T> {
-        let raw = id.erase();
+    fn write_raw(&mut self, raw: ErasedResourceNodeId) {
         let res = &mut self.graph.storage.resources[raw.index() as usize];
         let resource_class = match res.class() {
             ResourceClass::Texture => "Texture",
@@ -105,7 +138,52 @@ impl PassBuilder<'_, '_> {
 
         self.graph.storage.passes[self.pass_index].writes.push(raw);
         res.producer = Some(self.pass_index);
-        id
+    }
+
+    /// Reconciles the node's self-declared [`PassNode::reads`] /
+    /// [`PassNode::writes`] with the dependencies wired in the setup closure.
+    ///
+    /// Writes are applied first so a node may read what it produces.
+    /// Declared reads of transient resources without a producer are
+    /// read-before-write hazards and panic.
+    ///
+    /// [`PassNode::reads`]: super::PassNode::reads
+    /// [`PassNode::writes`]: super::PassNode::writes
+    pub(crate) fn apply_node_declarations(
+        &mut self,
+        reads: &[ErasedResourceNodeId],
+        writes: &[ErasedResourceNodeId],
+    ) {
+        for &raw in writes {
+            if !self.graph.storage.passes[self.pass_index]
+                .writes
+                .contains(&raw)
+            {
+                self.write_raw(raw);
+            }
+        }
+
+        for &raw in reads {
+            let res = &self.graph.storage.resources[raw.index() as usize];
+            if !res.is_external && res.producer.is_none() {
+                let resource_class = match res.class() {
+                    ResourceClass::Texture => "Texture",
+                    ResourceClass::Buffer => "Buffer",
+                };
+                panic!(
+                    "Read-before-write hazard in Pass '{}': {} '{}' is read but no earlier pass \
+                     writes it. Add the producing pass first or import the resource as external.",
+                    self.graph.storage.passes[self.pass_index].name, resource_class, res.name,
+                );
+            }
+
+            if !self.graph.storage.passes[self.pass_index]
+                .reads
+                .contains(&raw)
+            {
+                self.read_raw(raw);
+            }
+        }
     }
 
     #[inline]
@@ -1,3 +1,4 @@
+use std::cell::Cell;
 use std::marker::PhantomData;
 
 use crate::core::ResourceManager;
@@ -5,7 +6,7 @@ use crate::core::WgpuContext;
 use crate::core::binding::{BindGroupKey, GlobalBindGroupCache};
 use crate::core::gpu::{CommonSampler, MipmapGenerator, SamplerRegistry, SystemTextures, Tracked};
 use crate::graph::frame::{BakedRenderLists, RenderLists};
-use crate::graph::{ExtractedScene, RenderState};
+use crate::graph::{ExtractedScene, RenderState, RenderStats};
 use crate::pipeline::{PipelineCache, ShaderManager};
 use myth_assets::AssetServer;
 use myth_scene::RenderCamera;
@@ -583,7 +584,8 @@ impl ViewResolver<'_> {
     /// Resolve a virtual [`TextureNodeId`] to its physical [`Tracked<TextureView>`].
     ///
     /// For external resources, the view is looked up in `external_resources`.
-    /// For transient resources, the **default** view is obtained from the pool.
+    /// For transient resources, the **sampling** view is obtained from the
+    /// pool (the default view, or its depth aspect for depth-stencil formats).
     #[must_use]
     pub fn get_texture_view(&self, id: TextureNodeId) -> &Tracked<wgpu::TextureView> {
         let (_, res) = resolve_texture_resource(self.resources, id);
@@ -595,7 +597,7 @@ impl ViewResolver<'_> {
             unsafe { &*ptr }
         } else {
             let physical_index = res.physical_index.expect("No physical memory!");
-            self.pool.get_tracked_view(physical_index)
+            self.pool.get_sample_view(physical_index)
         }
     }
 
@@ -816,9 +818,22 @@ pub struct ExecuteContext<'a> {
     /// queue timeline.  Used by [`get_color_attachment`] and
     /// [`get_depth_stencil_attachment`] to auto-deduce `LoadOp` / `StoreOp`.
     pub current_timeline_index: usize,
+
+    /// Frame-wide draw counters, accumulated through [`Self::record_draws`].
+    pub stats: &'a Cell<RenderStats>,
 }
 
 impl ExecuteContext<'_> {
+    /// Add the counters returned by
+    /// [`submit_draw_commands`](crate::graph::passes::draw::submit_draw_commands)
+    /// to the frame statistics.
+    #[inline]
+    pub fn record_draws(&self, stats: RenderStats) {
+        let mut total = self.stats.get();
+        total += stats;
+        self.stats.set(total);
+    }
+
     /// Resolve a virtual [`TextureNodeId`] to its physical [`TextureView`].
     ///
     /// For external resources, the view is looked up in `external_views`.
@@ -1082,6 +1097,9 @@ impl ExecuteContext<'_> {
     ///   `Clear(clear_depth)`, otherwise `Load`.
     /// - Last use on a non-external resource → `Discard`, otherwise `Store`.
     ///
+    /// Formats with a stencil aspect get the same rules for the stencil
+    /// buffer, which is cleared to `0`.
+    ///
     /// Returns `None` if the resource was culled.
     #[must_use]
     pub fn get_depth_stencil_attachment(
@@ -1105,10 +1123,19 @@ impl ExecuteContext<'_> {
             wgpu::StoreOp::Store
         };
 
+        let has_stencil = res.texture_desc().format.has_stencil_aspect();
+        let stencil_ops = has_stencil.then(|| wgpu::Operations {
+            load: match load {
+                wgpu::LoadOp::Clear(_) => wgpu::LoadOp::Clear(0),
+                _ => wgpu::LoadOp::Load,
+            },
+            store,
+        });
+
         Some(wgpu::RenderPassDepthStencilAttachment {
             view,
             depth_ops: Some(wgpu::Operations { load, store }),
-            stencil_ops: None,
+            stencil_ops,
         })
     }
 }
@@ -259,13 +259,6 @@ impl GraphStorage {
                     }
 
                     if !self.name.is_empty() {
-                        writeln!(out, "{indent}end").unwrap();
-
-                        let hash = self
-                            .name
-                            .bytes()
-                            .fold(0usize, |acc, b| acc.wrapping_add(b as usize));
-
                         const STYLES: &[(&str, &str)] = &[
                             ("#3b82f614", "#3b82f6"),
                             ("#10b98114", "#10b981"),
@@ -276,6 +269,13 @@ impl GraphStorage {
                             ("#06b6d414", "#06b6d4"),
                         ];
 
+                        writeln!(out, "{indent}end").unwrap();
+
+                        let hash = self
+                            .name
+                            .bytes()
+                            .fold(0usize, |acc, b| acc.wrapping_add(b as usize));
+
                         let (bg, stroke) = STYLES[hash % STYLES.len()];
 
                         writeln!(
@@ -485,6 +485,17 @@ impl<'a> RenderGraph<'a> {
         ))
     }
 
+    /// Returns the descriptor of a texture resource.
+    ///
+    /// Hooks use this to size scratch targets after a blackboard slot, e.g.
+    /// a half-resolution copy of [`GraphBlackboard::scene_color`].
+    ///
+    /// [`GraphBlackboard::scene_color`]: super::GraphBlackboard::scene_color
+    #[must_use]
+    pub fn texture_desc(&self, id: TextureNodeId) -> TextureDesc {
+        self.storage.resources[id.index() as usize].texture_desc()
+    }
+
     pub fn register_buffer(
         &mut self,
         name: &'static str,
@@ -622,7 +633,9 @@ impl<'a> RenderGraph<'a> {
                 graph: self,
                 pass_index,
             };
-            setup_fn(&mut builder)
+            let (node, output) = setup_fn(&mut builder);
+            builder.apply_node_declarations(node.reads(), node.writes());
+            (node, output)
         };
 
         // Phase 3: allocate the node on the frame arena (O(1) pointer bump).
@@ -890,7 +903,7 @@ impl<'a> RenderGraph<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::graph::core::types::ResourceClass;
+    use crate::graph::core::types::{ErasedResourceNodeId, ResourceClass};
     use crate::graph::{composer::GraphBuilderContext, core::context::ExecuteContext};
 
     fn dummy_config() -> FrameConfig {
@@ -1103,6 +1116,164 @@ mod tests {
         );
     }
 
+    /// Node that declares its own dependencies instead of wiring them in
+    /// the setup closure.
+    struct DeclaringExec {
+        reads: [ErasedResourceNodeId; 1],
+        writes: [ErasedResourceNodeId; 1],
+    }
+    impl PassNode<'_> for DeclaringExec {
+        fn execute(&self, _ctx: &ExecuteContext, _encoder: &mut wgpu::CommandEncoder) {}
+
+        fn reads(&self) -> &[ErasedResourceNodeId] {
+            &self.reads
+        }
+
+        fn writes(&self) -> &[ErasedResourceNodeId] {
+            &self.writes
+        }
+    }
+
+    #[test]
+    fn test_node_declared_dependencies() {
+        let mut storage = GraphStorage::new();
+        let arena = FrameArena::new();
+        let mut graph = begin_test_frame(&mut storage, &arena);
+
+        let backbuffer = graph.register_texture("Backbuffer", dummy_desc(), true);
+
+        let color = graph.add_pass("Opaque", |builder| {
+            let out = builder.create_texture("Color", dummy_desc());
+            (MockExec, out)
+        });
+
+        // Declared by the node only; the closure wires nothing.
+        graph.add_pass("Custom", |_builder| {
+            let node = DeclaringExec {
+                reads: [color.erase()],
+                writes: [backbuffer.erase()],
+            };
+            (node, ())
+        });
+
+        graph.compile_topology();
+
+        assert_eq!(graph.storage.execution_queue.len(), 2);
+        let custom = &graph.storage.passes[graph.storage.execution_queue[1]];
+        assert_eq!(custom.name, "Custom");
+        assert_eq!(custom.reads.as_slice(), &[color.erase()]);
+        assert_eq!(custom.writes.as_slice(), &[backbuffer.erase()]);
+        assert_eq!(
+            graph.storage.resources[backbuffer.index() as usize].producer,
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_node_declarations_do_not_duplicate_builder_wiring() {
+        let mut storage = GraphStorage::new();
+        let arena = FrameArena::new();
+        let mut graph = begin_test_frame(&mut storage, &arena);
+
+        let backbuffer = graph.register_texture("Backbuffer", dummy_desc(), true);
+        let color = graph.add_pass("Opaque", |builder| {
+            let out = builder.create_texture("Color", dummy_desc());
+            (MockExec, out)
+        });
+
+        graph.add_pass("Custom", |builder| {
+            builder.read_texture(color);
+            builder.write_texture(backbuffer);
+            let node = DeclaringExec {
+                reads: [color.erase()],
+                writes: [backbuffer.erase()],
+            };
+            (node, ())
+        });
+
+        let custom = &graph.storage.passes[1];
+        assert_eq!(custom.reads.len(), 1);
+        assert_eq!(custom.writes.len(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "Read-before-write hazard in Pass 'Custom'")]
+    fn test_node_declared_read_before_write_panics() {
+        let mut storage = GraphStorage::new();
+        let arena = FrameArena::new();
+        let mut graph = begin_test_frame(&mut storage, &arena);
+
+        let backbuffer = graph.register_texture("Backbuffer", dummy_desc(), true);
+        let history = graph.register_texture("History", dummy_desc(), false);
+
+        graph.add_pass("Custom", |_builder| {
+            let node = DeclaringExec {
+                reads: [history.erase()],
+                writes: [backbuffer.erase()],
+            };
+            (node, ())
+        });
+    }
+
+    #[test]
+    fn test_scratch_texture_from_input_desc() {
+        let mut storage = GraphStorage::new();
+        let arena = FrameArena::new();
+        let mut graph = begin_test_frame(&mut storage, &arena);
+
+        let surface_desc = TextureDesc::new_2d(
+            640,
+            360,
+            wgpu::TextureFormat::Bgra8UnormSrgb,
+            wgpu::TextureUsages::RENDER_ATTACHMENT,
+        );
+        let surface = graph.register_texture("Surface", surface_desc, true);
+        assert_eq!(graph.texture_desc(surface), surface_desc);
+
+        let scratch = graph.add_pass("Blur", |builder| {
+            builder.read_texture(surface);
+            let desc = builder.texture_desc(surface);
+            let out = builder.create_texture("Scratch", desc);
+            (MockExec, out)
+        });
+
+        // Never read: must be culled along with its producer.
+        graph.add_pass("Unused", |builder| {
+            let out = builder.create_texture("Unused_Scratch", half_res_desc(surface_desc));
+            (MockExec, out)
+        });
+
+        graph.add_pass("Composite", |builder| {
+            builder.read_texture(scratch);
+            let _ = builder.mutate_texture(surface, "Surface_Composited");
+            (MockExec, ())
+        });
+
+        graph.compile_topology();
+
+        assert_eq!(graph.texture_desc(scratch), surface_desc);
+        let names: Vec<_> = graph
+            .storage
+            .execution_queue
+            .iter()
+            .map(|&i| graph.storage.passes[i].name)
+            .collect();
+        assert_eq!(names, vec!["Blur", "Composite"]);
+
+        let scratch_res = &graph.storage.resources[scratch.index() as usize];
+        assert!(!scratch_res.is_external);
+        assert_eq!((scratch_res.first_use, scratch_res.last_use), (0, 1));
+    }
+
+    fn half_res_desc(desc: TextureDesc) -> TextureDesc {
+        TextureDesc::new_2d(
+            desc.size.width / 2,
+            desc.size.height / 2,
+            desc.format,
+            desc.usage,
+        )
+    }
+
     #[test]
     fn test_ssa_alias_relay_passes() {
         let mut storage = GraphStorage::new();
@@ -9,7 +9,8 @@ pub mod types;
 
 pub use allocator::{SubViewKey, TransientPool};
 pub use arena::FrameArena;
-pub use blackboard::{CustomPassHook, GraphBlackboard, HookStage};
+pub use blackboard::{CustomPassHook, GraphBlackboard, HookOrder, HookStage, SceneAttachment};
+pub(crate) use blackboard::{HookAnchorWarnings, HookKey, resolve_hook_order};
 pub use builder::PassBuilder;
 pub use context::{
     BindGroupBuilder, BindableResource, ExecuteContext, ExtractContext, GraphBinding,
@@ -19,6 +20,6 @@ pub use context::{
 pub use graph::{GraphStorage, RenderGraph};
 pub use node::PassNode;
 pub use types::{
-    Buffer, BufferDesc, BufferNodeId, GraphResourceType, RenderTargetOps, ResourceKind,
-    ResourceNodeId, ResourceRecord, Texture, TextureDesc, TextureNodeId,
+    Buffer, BufferDesc, BufferNodeId, ErasedResourceNodeId, GraphResourceType, RenderTargetOps,
+    ResourceKind, ResourceNodeId, ResourceRecord, Texture, TextureDesc, TextureNodeId,
 };
@@ -46,6 +46,28 @@ pub trait PassNode<'a>: Send + Sync {
 
     /// Record GPU commands into the shared encoder.
     fn execute(&self, ctx: &ExecuteContext, encoder: &mut CommandEncoder);
+
+    /// Resources this node samples or otherwise reads during `execute`.
+    ///
+    /// Optional self-description for nodes that carry their own resource
+    /// IDs (e.g. third-party passes).  [`RenderGraph::add_pass`] registers
+    /// any entry not already declared through the [`PassBuilder`], so the
+    /// graph orders the pass after the producer.  Reading a transient
+    /// resource that no earlier pass has written panics at build time.
+    ///
+    /// [`RenderGraph::add_pass`]: super::RenderGraph::add_pass
+    /// [`PassBuilder`]: super::PassBuilder
+    fn reads(&self) -> &[ErasedResourceNodeId] {
+        &[]
+    }
+
+    /// Resources this node renders to or otherwise writes during `execute`.
+    ///
+    /// Undeclared entries are registered as writes of this pass; a resource
+    /// that already has a producer panics with the usual SSA violation.
+    fn writes(&self) -> &[ErasedResourceNodeId] {
+        &[]
+    }
 }
 
 // ─── NodeSlot ──────────────────────────────────────────────────────────────
@@ -24,27 +24,52 @@
 
 use glam::Vec3A;
 use log::{error, warn};
+use rustc_hash::FxHashSet;
 use slotmap::Key;
 
 use crate::RenderPath;
 use crate::core::view::ViewTarget;
 use crate::core::{ResourceManager, WgpuContext};
 use crate::graph::extracted::{ExtractedScene, SceneFeatures};
-use crate::graph::frame::{RenderCommand, RenderKey, RenderLists, ShadowRenderCommand};
+use crate::graph::frame::{
+    RenderCommand, RenderKey, RenderLists, ShadowRenderCommand, WireframeDraw,
+};
 use crate::graph::render_state::RenderState;
 use crate::pipeline::pipeline_key::PipelineFlags;
 use crate::pipeline::shader_gen::ShaderCompilationOptions;
 use crate::pipeline::shader_manager::ShaderSource;
 use crate::pipeline::{
     BlendStateKey, DepthStencilKey, FastPipelineKey, FastShadowPipelineKey, GraphicsPipelineKey,
-    PipelineCache, ShaderManager, SimpleGeometryPipelineKey,
+    PipelineCache, PipelineLookup, ShaderManager, SimpleGeometryPipelineKey, StencilStateKey,
 };
 use myth_assets::AssetServer;
-use myth_resources::AntiAliasingMode;
 use myth_resources::material::{AlphaMode, Side};
+use myth_resources::shader_defines::ShaderDefines;
 use myth_resources::uniforms::{DynamicModelUniforms, Mat3Uniform};
+use myth_resources::{AntiAliasingMode, BoundingBox};
+use myth_scene::NodeHandle;
 use myth_scene::camera::RenderCamera;
 
+/// `src.rgb * src.a + dst.rgb`, keeping the target's alpha.
+const ADDITIVE_BLENDING: wgpu::BlendState = wgpu::BlendState {
+    color: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::SrcAlpha,
+        dst_factor: wgpu::BlendFactor::One,
+        operation: wgpu::BlendOperation::Add,
+    },
+    alpha: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::Zero,
+        dst_factor: wgpu::BlendFactor::One,
+        operation: wgpu::BlendOperation::Add,
+    },
+};
+
+/// Stand-in shader for material pipelines still compiling in the background.
+///
+/// It draws with the material's vertex layout, bind groups and pass state,
+/// so only the geometry and pass variants produce distinct pipelines.
+const FALLBACK_SHADER: &str = "entry/utility/pipeline_fallback";
+
 /// Shadow-only WGSL binding declaration, injected into shadow depth shaders.
 const SHADOW_BINDING_WGSL: &str = "
 struct Struct_shadow_light {
@@ -61,6 +86,8 @@ struct Struct_shadow_light {
 ///
 /// # Phases
 ///
+/// 0. **Background pipelines** — store material pipelines finished by the
+///    compile thread since the last frame.
 /// 1. **Main camera commands** — frustum cull render items against the main
 ///    camera, look up / create pipelines, produce sorted opaque + transparent
 ///    command lists.
@@ -68,7 +95,7 @@ struct Struct_shadow_light {
 ///    lookup → per-view `ShadowRenderCommand` queues.
 /// 3. **Dynamic uniform upload** — compute inverse/normal matrices, allocate
 ///    model-uniform slots, flush the model buffer to GPU.
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::implicit_hasher)]
 pub fn cull_and_sort(
     extracted_scene: &ExtractedScene,
     render_state: &RenderState,
@@ -79,7 +106,10 @@ pub fn cull_and_sort(
     render_lists: &mut RenderLists,
     camera: &RenderCamera,
     assets: &AssetServer,
+    occluded_nodes: &FxHashSet<NodeHandle>,
 ) {
+    pipeline_cache.poll_pending();
+
     prepare_main_camera_commands(
         extracted_scene,
         render_state,
@@ -90,6 +120,7 @@ pub fn cull_and_sort(
         render_lists,
         camera,
         assets,
+        occluded_nodes,
     );
 
     prepare_shadow_commands(
@@ -130,6 +161,7 @@ fn prepare_main_camera_commands(
     render_lists: &mut RenderLists,
     camera: &RenderCamera,
     assets: &AssetServer,
+    occluded_nodes: &FxHashSet<NodeHandle>,
 ) {
     let color_format = wgpu_ctx
         .render_path
@@ -142,6 +174,15 @@ fn prepare_main_camera_commands(
     let taa_enabled = matches!(camera.aa_mode, AntiAliasingMode::TAA { .. });
     let camera_frustum = camera.frustum;
     let camera_pos = camera.position;
+    let gpu_culling = wgpu_ctx.gpu_culling_active();
+    let gpu_driven = wgpu_ctx.gpu_driven_active();
+    // The query pass only exists in the HDR pipeline.
+    let occlusion_culling =
+        extracted_scene.occlusion_culling && wgpu_ctx.render_path.supports_post_processing();
+    let weighted_oit = extracted_scene
+        .scene_variants
+        .contains(SceneFeatures::WEIGHTED_OIT)
+        && wgpu_ctx.render_path.supports_post_processing();
 
     let use_depth_pre = sample_count == 1 && wgpu_ctx.render_path.requires_z_prepass();
 
@@ -162,20 +203,66 @@ fn prepare_main_camera_commands(
         let geo_guard = assets.geometries.read_lock();
         let mat_guard = assets.materials.read_lock();
 
-        for item_idx in 0..extracted_scene.render_items.len() {
-            let item = &extracted_scene.render_items[item_idx];
+        // Prewarm items follow the real ones. Their infinite bounds skip
+        // the frustum and occlusion tests; they resolve their pipelines like
+        // any other mesh but are never drawn.
+        let item_count = extracted_scene.render_items.len();
+        let items = extracted_scene
+            .render_items
+            .iter()
+            .chain(&extracted_scene.prewarm_items);
+
+        for (item_idx, item) in items.enumerate() {
+            let prewarm = item_idx >= item_count;
+
+            if item.layers & camera.layer_mask == 0 {
+                render_lists.culled_meshes += 1;
+                continue;
+            }
 
             // ========== Frustum Culling ==========
+            // With GPU culling, finite bounds are tested by the compute pass
+            // instead, so everything past the layer test counts as visible.
             let aabb = item.world_aabb;
-            if aabb.is_finite() && !camera_frustum.intersects_aabb(&aabb) {
-                continue;
+            let mut cull_object = None;
+            if aabb.is_finite() {
+                if gpu_culling {
+                    let center = aabb.center();
+                    let radius = aabb.size().length() * 0.5;
+                    cull_object = Some(render_lists.cull_spheres.len() as u32);
+                    render_lists.cull_spheres.push(center.extend(radius));
+                } else if !camera_frustum.intersects_aabb(&aabb) {
+                    render_lists.culled_meshes += 1;
+                    continue;
+                }
             }
 
-            let Some(gpu_world) = resource_manager.get_global_state(render_state_id, scene_id)
-            else {
-                error!("CRITICAL: GpuWorld missing during iteration");
-                continue;
-            };
+            // ========== Occlusion Culling ==========
+            // Every candidate is queried again this frame, including the ones
+            // skipped because last frame's query saw none of their box. A
+            // camera inside the box may clip it away entirely, so such nodes
+            // are always drawn.
+            if occlusion_culling
+                && aabb.is_finite()
+                && !contains_point(&aabb, camera_pos, camera.near)
+            {
+                if render_lists
+                    .occlusion_candidates
+                    .last()
+                    .is_none_or(|(node, _)| *node != item.node_handle)
+                {
+                    render_lists
+                        .occlusion_candidates
+                        .push((item.node_handle, aabb));
+                }
+                if occluded_nodes.contains(&item.node_handle) {
+                    render_lists.culled_meshes += 1;
+                    continue;
+                }
+            }
+            if !prewarm {
+                render_lists.visible_meshes += 1;
+            }
 
             let Some(geometry) = geo_guard.get_loaded(item.geometry) else {
                 warn!("Geometry {:?} missing during render prepare", item.geometry);
@@ -186,6 +273,31 @@ fn prepare_main_camera_commands(
                 continue;
             };
 
+            // Point sprites expand each vertex into an instanced quad, so they
+            // use the instance-stepped layout and never take the Z-prepass.
+            let point_sprites = material.renders_point_sprites();
+
+            let mut wireframe = WireframeDraw::None;
+            if (material.wireframe() || wgpu_ctx.wireframe)
+                && !point_sprites
+                && matches!(
+                    geometry.topology,
+                    wgpu::PrimitiveTopology::TriangleList | wgpu::PrimitiveTopology::TriangleStrip
+                )
+            {
+                if wgpu_ctx.uses_polygon_mode_line() {
+                    wireframe = WireframeDraw::Lines;
+                } else if resource_manager.prepare_wireframe_geometry(geometry, item.geometry) {
+                    wireframe = WireframeDraw::Barycentric;
+                }
+            }
+
+            let Some(gpu_world) = resource_manager.get_global_state(render_state_id, scene_id)
+            else {
+                error!("CRITICAL: GpuWorld missing during iteration");
+                continue;
+            };
+
             let object_bind_group = &item.object_bind_group;
 
             let Some(gpu_geometry) = resource_manager.get_geometry(item.geometry) else {
@@ -197,6 +309,65 @@ fn prepare_main_camera_commands(
                 continue;
             };
 
+            // The barycentric fallback draws a de-indexed copy of the geometry.
+            let wireframe_geometry = match wireframe {
+                WireframeDraw::Barycentric => {
+                    resource_manager.get_wireframe_geometry(item.geometry)
+                }
+                _ => None,
+            };
+            let (vertex_layout_info, vertex_layout_id, topology) = if point_sprites {
+                (
+                    &gpu_geometry.point_sprite_layout_info,
+                    gpu_geometry.point_sprite_layout_id,
+                    wgpu::PrimitiveTopology::TriangleList,
+                )
+            } else if let Some(wire) = wireframe_geometry {
+                (
+                    &wire.gpu.layout_info,
+                    wire.gpu.layout_id,
+                    wgpu::PrimitiveTopology::TriangleList,
+                )
+            } else {
+                (
+                    &gpu_geometry.layout_info,
+                    gpu_geometry.layout_id,
+                    geometry.topology,
+                )
+            };
+            // Stencil state only applies to depth buffers with a stencil
+            // aspect; the renderer switches to one when it sees such a
+            // material (see `Renderer::begin_frame`).
+            let stencil = material
+                .stencil()
+                .filter(|_| depth_format.has_stencil_aspect());
+            // Wireframe draws leave most of the surface uncovered, so they
+            // cannot rely on prepass depth either. Stencil-tested draws may
+            // be rejected after the prepass, so they keep their own depth.
+            let use_depth_pre = use_depth_pre
+                && !material.has_custom_vertex_placement()
+                && wireframe == WireframeDraw::None
+                && stencil.is_none();
+            // Transmission needs the sorted pass, which samples the opaque copy.
+            // Additive blending is order-independent already and needs its
+            // own blend state, so it stays in the sorted pass too.
+            let oit_item = weighted_oit
+                && material.is_transparent()
+                && !material.use_transmission()
+                && !material.additive_blending();
+            // GPU-culled draws of the built-in surface materials read their
+            // object data through the instance table instead of the
+            // per-draw dynamic offset.
+            let gpu_driven = gpu_driven
+                && cull_object.is_some()
+                && !point_sprites
+                && material.supports_gpu_driven_draws();
+            // Merged draws share the first draw's object bind group, so
+            // they must not read per-mesh data from it.
+            let mergeable = gpu_driven
+                && !geometry.has_morph_targets()
+                && !item.item_shader_defines.contains("HAS_SKINNING");
+
             let fast_key = FastPipelineKey {
                 material_handle: item.material,
                 material_version: gpu_material.version,
@@ -206,16 +377,27 @@ fn prepare_main_camera_commands(
                 global_state_id: gpu_world.id,
                 scene_variants: extracted_scene.scene_variants,
                 taa_enabled,
+                gpu_driven,
                 pipeline_settings_version,
                 #[cfg(feature = "debug_view")]
                 debug_view_mode: camera.debug_view.mode,
             };
 
             // ========== Hot-Path: L1 cache first ==========
-            let pipeline_id = if let Some(id) = pipeline_cache.get_pipeline_fast(fast_key) {
+            let lookup = pipeline_cache.lookup_pipeline_fast(fast_key);
+            let pipeline_id = if let PipelineLookup::Ready(id) = lookup {
                 id
+            } else if let PipelineLookup::Pending(fallback) = lookup {
+                // Still compiling in the background: draw the stand-in.
+                let Some(fallback) = fallback else {
+                    continue;
+                };
+                fallback
             } else {
-                let geo_defines = geometry.shader_defines();
+                let geo_defines = wireframe_geometry.map_or_else(
+                    || geometry.shader_defines(),
+                    |wire| wire.geometry.shader_defines(),
+                );
                 let mat_defines = material.shader_defines();
 
                 let mut flags = PipelineFlags::empty();
@@ -263,13 +445,25 @@ fn prepare_main_camera_commands(
                     options.add_define("HAS_MRT_SSSS", "1");
                 }
 
+                if oit_item {
+                    options.add_define("WEIGHTED_OIT", "1");
+                }
+
+                if gpu_driven {
+                    options.add_define("GPU_DRIVEN", "1");
+                }
+
                 let shader_hash = options.compute_hash();
 
                 if is_specular_split {
                     flags |= PipelineFlags::SPECULAR_SPLIT;
                 }
 
-                let depth_write = if is_opaque_item && use_depth_pre {
+                if oit_item {
+                    flags |= PipelineFlags::WEIGHTED_OIT;
+                }
+
+                let depth_write = if (is_opaque_item && use_depth_pre) || oit_item {
                     false
                 } else {
                     material.depth_write()
@@ -291,21 +485,41 @@ fn prepare_main_camera_commands(
 
                 let canonical_key = GraphicsPipelineKey {
                     shader_hash,
-                    vertex_layout_id: gpu_geometry.layout_id,
+                    vertex_layout_id,
                     bind_group_layout_ids: [
                         gpu_world.layout_id,
                         gpu_material.layout_id,
                         object_bind_group.layout_id,
                         resource_manager.system_textures.screen_layout.id(),
                     ],
-                    topology: geometry.topology,
+                    topology,
                     cull_mode: match material.side() {
+                        _ if point_sprites => None,
                         Side::Front => Some(wgpu::Face::Back),
                         Side::Back => Some(wgpu::Face::Front),
                         Side::Double => None,
                     },
                     depth_compare,
-                    blend_state: if material.is_transparent() {
+                    stencil: StencilStateKey::from(stencil.map_or_else(
+                        wgpu::StencilState::default,
+                        |st| {
+                            let face = wgpu::StencilFaceState {
+                                compare: st.compare,
+                                fail_op: st.fail_op,
+                                depth_fail_op: st.depth_fail_op,
+                                pass_op: st.pass_op,
+                            };
+                            wgpu::StencilState {
+                                front: face,
+                                back: face,
+                                read_mask: st.read_mask,
+                                write_mask: st.write_mask,
+                            }
+                        },
+                    )),
+                    blend_state: if material.additive_blending() {
+                        Some(BlendStateKey::from(ADDITIVE_BLENDING))
+                    } else if material.is_transparent() {
                         Some(BlendStateKey::from(wgpu::BlendState::ALPHA_BLENDING))
                     } else {
                         None
@@ -318,26 +532,101 @@ fn prepare_main_camera_commands(
                     } else {
                         wgpu::FrontFace::Ccw
                     },
+                    polygon_mode: if wireframe == WireframeDraw::Lines {
+                        wgpu::PolygonMode::Line
+                    } else {
+                        wgpu::PolygonMode::Fill
+                    },
                     flags,
                 };
 
-                let id = pipeline_cache.get_or_create_graphics(
-                    &wgpu_ctx.device,
-                    shader_manager,
-                    material.shader_name(),
-                    &canonical_key,
-                    &options,
-                    &gpu_geometry.layout_info,
-                    gpu_material,
-                    object_bind_group,
-                    gpu_world,
-                    &resource_manager.system_textures.screen_layout,
-                );
+                let id = if wgpu_ctx.async_pipelines {
+                    pipeline_cache.request_graphics(
+                        &wgpu_ctx.device,
+                        shader_manager,
+                        material.shader_name(),
+                        &canonical_key,
+                        &options,
+                        vertex_layout_info,
+                        gpu_material,
+                        object_bind_group,
+                        gpu_world,
+                        &resource_manager.system_textures.screen_layout,
+                    )
+                } else {
+                    Some(pipeline_cache.get_or_create_graphics(
+                        &wgpu_ctx.device,
+                        shader_manager,
+                        material.shader_name(),
+                        &canonical_key,
+                        &options,
+                        vertex_layout_info,
+                        gpu_material,
+                        object_bind_group,
+                        gpu_world,
+                        &resource_manager.system_textures.screen_layout,
+                    ))
+                };
 
+                let Some(id) = id else {
+                    // Point sprites, wireframes and custom vertex placement
+                    // need the material's own shader, so those draws wait.
+                    let fallback = (!point_sprites
+                        && wireframe == WireframeDraw::None
+                        && !material.has_custom_vertex_placement())
+                    .then(|| {
+                        let mut fallback_options = ShaderCompilationOptions::from_merged(
+                            &ShaderDefines::new(),
+                            geo_defines,
+                            &ShaderDefines::new(),
+                            &item.item_shader_defines,
+                        );
+                        for define in ["PIPELINE_FALLBACK", "IS_PREPASS", "OUTPUT_NORMAL"] {
+                            fallback_options.add_define(define, "1");
+                        }
+                        if !is_opaque_item {
+                            fallback_options.add_define("IN_TRANSPARENT_PASS", "1");
+                        }
+                        if is_specular_split {
+                            fallback_options.add_define("HAS_MRT_SSSS", "1");
+                        }
+                        if oit_item {
+                            fallback_options.add_define("WEIGHTED_OIT", "1");
+                        }
+                        if gpu_driven {
+                            fallback_options.add_define("GPU_DRIVEN", "1");
+                        }
+                        let fallback_key = GraphicsPipelineKey {
+                            shader_hash: fallback_options.compute_hash(),
+                            ..canonical_key.clone()
+                        };
+                        pipeline_cache.get_or_create_graphics(
+                            &wgpu_ctx.device,
+                            shader_manager,
+                            FALLBACK_SHADER,
+                            &fallback_key,
+                            &fallback_options,
+                            vertex_layout_info,
+                            gpu_material,
+                            object_bind_group,
+                            gpu_world,
+                            &resource_manager.system_textures.screen_layout,
+                        )
+                    });
+                    pipeline_cache.insert_pending_fast(fast_key, &canonical_key, fallback);
+                    let Some(fallback) = fallback else {
+                        continue;
+                    };
+                    fallback
+                };
                 pipeline_cache.insert_pipeline_fast(fast_key, id);
                 id
             };
 
+            if prewarm {
+                continue;
+            }
+
             let mat_id = item.material.data().as_ffi() as u32;
 
             let use_transmission = material.use_transmission();
@@ -351,27 +640,33 @@ fn prepare_main_camera_commands(
             let distance_sq = camera_pos.distance_squared(item_pos);
             let sort_key = RenderKey::new(pipeline_id, mat_id, distance_sq, is_transparent);
 
-            let world_matrix_inverse = item.world_matrix.inverse();
-            let normal_matrix = Mat3Uniform::from_mat4(world_matrix_inverse.transpose());
-
-            let dynamic_offset = resource_manager.allocate_model_uniform(DynamicModelUniforms {
-                world_matrix: item.world_matrix,
-                world_matrix_inverse,
-                normal_matrix,
-                previous_world_matrix: item.prev_world_matrix,
-                ..Default::default()
-            });
+            let dynamic_offset =
+                resource_manager.allocate_model_uniform(DynamicModelUniforms::new(
+                    item.world_matrix,
+                    item.prev_world_matrix,
+                    item.object_override.as_ref(),
+                ));
 
             let cmd = RenderCommand {
                 object_bind_group: object_bind_group.clone(),
                 geometry_handle: item.geometry,
                 material_handle: item.material,
+                draw_range: item.draw_range.clone(),
                 pipeline_id,
                 sort_key,
                 dynamic_offset,
+                point_sprites,
+                wireframe,
+                cull_object,
+                gpu_driven,
+                mergeable,
+                stencil_reference: stencil.map(|st| st.reference),
+                render_order: item.render_order,
             };
 
-            if is_transparent {
+            if oit_item {
+                render_lists.insert_weighted_oit(cmd);
+            } else if is_transparent {
                 render_lists.insert_transparent(cmd);
             } else {
                 render_lists.insert_opaque(cmd);
@@ -443,6 +738,11 @@ fn prepare_shadow_commands(
         };
 
         let view_frustum = view.frustum;
+        let shadow_layers = extracted_scene
+            .lights
+            .iter()
+            .find(|light| light.id == light_id)
+            .map_or(u32::MAX, |light| light.shadow_layers);
 
         let queue = render_lists
             .shadow_queues
@@ -450,7 +750,7 @@ fn prepare_shadow_commands(
             .or_default();
 
         for item in &extracted_scene.render_items {
-            if !item.cast_shadows {
+            if !item.cast_shadows || item.layers & shadow_layers == 0 {
                 continue;
             }
 
@@ -466,7 +766,7 @@ fn prepare_shadow_commands(
                 continue;
             };
 
-            if material.alpha_mode() == AlphaMode::Blend {
+            if material.alpha_mode() == AlphaMode::Blend || material.has_custom_vertex_placement() {
                 continue;
             }
 
@@ -495,7 +795,7 @@ fn prepare_shadow_commands(
                 let mut options = ShaderCompilationOptions::from_merged(
                     &mat_defines,
                     geo_defines,
-                    &myth_resources::shader_defines::ShaderDefines::new(),
+                    &ShaderDefines::new(),
                     &item.item_shader_defines,
                 );
                 options.add_define("SHADOW_PASS", "1");
@@ -580,11 +880,9 @@ fn prepare_shadow_commands(
                 id
             };
 
-            let world_matrix_inverse = item.world_matrix.inverse();
-            let normal_matrix = Mat3Uniform::from_mat4(world_matrix_inverse.transpose());
+            let normal_matrix = Mat3Uniform::from_mat4(item.world_matrix.inverse().transpose());
             let dynamic_offset = resource_manager.allocate_model_uniform(DynamicModelUniforms {
                 world_matrix: item.world_matrix,
-                world_matrix_inverse,
                 normal_matrix,
                 ..Default::default()
             });
@@ -593,9 +891,17 @@ fn prepare_shadow_commands(
                 object_bind_group: item.object_bind_group.clone(),
                 geometry_handle: item.geometry,
                 material_handle: item.material,
+                draw_range: item.draw_range.clone(),
                 pipeline_id,
                 dynamic_offset,
             });
         }
     }
 }
+
+/// Whether `point` lies within `aabb` grown by `margin` on every side.
+fn contains_point(aabb: &BoundingBox, point: Vec3A, margin: f32) -> bool {
+    let min = Vec3A::from(aabb.min) - margin;
+    let max = Vec3A::from(aabb.max) + margin;
+    (point.cmpge(min) & point.cmple(max)).all()
+}
@@ -11,6 +11,7 @@
 //! - Single source of truth: one `render_items` list consumed by all `RenderView`s
 
 use std::collections::HashSet;
+use std::ops::Range;
 
 use glam::{Mat4, Vec3};
 
@@ -18,13 +19,17 @@ use bitflags::{Flags, bitflags};
 
 use crate::core::{BindGroupContext, ResourceManager};
 use myth_assets::{AssetServer, GeometryHandle, MaterialHandle};
-use myth_resources::BoundingBox;
+use myth_resources::mesh::Mesh;
 use myth_resources::shader_defines::ShaderDefines;
+use myth_resources::{BoundingBox, TextureHandle};
 use myth_scene::background::BackgroundMode;
 use myth_scene::camera::RenderCamera;
 use myth_scene::environment::Environment;
 use myth_scene::light::{LightKind, ShadowConfig};
-use myth_scene::{NodeHandle, Scene, SkeletonKey};
+use myth_scene::{
+    ALL_LAYERS, DebugVertex, DecalOptions, GridOptions, GroundShadow, NodeHandle, ObjectOverride,
+    Scene, SkeletonKey, TransparencyMode,
+};
 
 /// Minimal render item, containing only data needed by GPU
 ///
@@ -40,11 +45,16 @@ pub struct ExtractedRenderItem {
     /// Previous frame's world transform matrix (64 bytes)
     pub prev_world_matrix: Mat4,
 
+    /// Per-node material overrides (`USE_OBJECT_OVERRIDE`)
+    pub object_override: Option<ObjectOverride>,
+
     pub object_bind_group: BindGroupContext,
     /// Geometry handle (8 bytes)
     pub geometry: GeometryHandle,
     /// Material handle (8 bytes)
     pub material: MaterialHandle,
+    /// Index (or vertex) range of a submesh, `None` for the whole geometry.
+    pub draw_range: Option<Range<u32>>,
 
     pub item_variant_flags: u32,
 
@@ -53,6 +63,12 @@ pub struct ExtractedRenderItem {
     pub cast_shadows: bool,
     pub receive_shadows: bool,
 
+    /// Layer bitmask of the owning node, tested against camera and shadow masks.
+    pub layers: u32,
+
+    /// Draw order override from `Mesh::render_order` (lower draws first).
+    pub render_order: i32,
+
     /// World-space axis-aligned bounding box.
     pub world_aabb: BoundingBox,
 }
@@ -61,12 +77,30 @@ pub struct ExtractedRenderItem {
 pub struct ExtractedLight {
     pub id: u64,
     pub cast_shadows: bool,
+    pub shadow_layers: u32,
     pub kind: LightKind,
     pub position: Vec3,
     pub direction: Vec3,
     pub shadow: Option<ShadowConfig>,
 }
 
+/// A decal projector of the current frame.
+#[derive(Clone, Copy)]
+pub struct ExtractedDecal {
+    pub texture: TextureHandle,
+    /// Maps the unit projector box to world space.
+    pub world_matrix: Mat4,
+    pub options: DecalOptions,
+}
+
+/// The ground shadow of the current frame.
+#[derive(Clone, Copy)]
+pub struct ExtractedGroundShadow {
+    pub settings: GroundShadow,
+    /// Combined world bounds of the shadow-casting meshes above the plane.
+    pub bounds: BoundingBox,
+}
+
 /// Extracted skeleton data
 #[derive(Clone)]
 pub struct ExtractedSkeleton {
@@ -80,6 +114,8 @@ bitflags! {
         const USE_SSAO = 1 << 1;
         const USE_SSS = 1 << 2;
         const USE_SSR = 1 << 3;
+        const WEIGHTED_OIT = 1 << 4;
+        const FOG = 1 << 5;
 
 
         const USE_SCREEN_SPACE_FEATURES = Self::USE_SSS.bits() | Self::USE_SSR.bits();
@@ -109,6 +145,21 @@ pub struct ExtractedScene {
     pub envvironment: Environment,
     pub has_transmission: bool,
     pub lights: Vec<ExtractedLight>,
+    /// Immediate-mode debug line vertices drained from the scene this frame.
+    pub debug_lines: Vec<DebugVertex>,
+    pub debug_lines_depth_test: bool,
+    /// Ground grid to draw this frame, if the scene shows one.
+    pub grid: Option<GridOptions>,
+    /// Decals of visible nodes, applied after the opaque pass.
+    pub decals: Vec<ExtractedDecal>,
+    /// Blob shadow to draw this frame, if enabled and anything casts it.
+    pub ground_shadow: Option<ExtractedGroundShadow>,
+    /// Whether the main camera applies last frame's occlusion query results.
+    pub occlusion_culling: bool,
+    /// Stand-in items for [`Renderer::prewarm`](crate::Renderer::prewarm)
+    /// requests. The main camera resolves their pipelines but never draws
+    /// them.
+    pub prewarm_items: Vec<ExtractedRenderItem>,
 
     collected_meshes: Vec<CollectedMesh>,
     collected_skeleton_keys: HashSet<SkeletonKey>,
@@ -120,10 +171,12 @@ struct CollectedMesh {
 
     pub world_matrix: Mat4,
     pub prev_world_matrix: Mat4,
+    pub object_override: Option<ObjectOverride>,
     pub world_aabb: BoundingBox,
     pub item_variant_flags: u32,
     pub cast_shadows: bool,
     pub receive_shadows: bool,
+    pub layers: u32,
 }
 
 impl ExtractedScene {
@@ -139,6 +192,13 @@ impl ExtractedScene {
             envvironment: Environment::default(),
             has_transmission: false,
             lights: Vec::new(),
+            debug_lines: Vec::new(),
+            debug_lines_depth_test: true,
+            grid: None,
+            decals: Vec::new(),
+            ground_shadow: None,
+            occlusion_culling: false,
+            prewarm_items: Vec::new(),
 
             collected_meshes: Vec::new(),
             collected_skeleton_keys: HashSet::default(),
@@ -157,6 +217,13 @@ impl ExtractedScene {
             envvironment: Environment::default(),
             has_transmission: false,
             lights: Vec::with_capacity(16),
+            debug_lines: Vec::new(),
+            debug_lines_depth_test: true,
+            grid: None,
+            decals: Vec::new(),
+            ground_shadow: None,
+            occlusion_culling: false,
+            prewarm_items: Vec::new(),
 
             collected_meshes: Vec::with_capacity(item_capacity),
             collected_skeleton_keys: HashSet::default(),
@@ -166,9 +233,11 @@ impl ExtractedScene {
     /// Clear data for reuse
     pub fn clear(&mut self) {
         self.render_items.clear();
+        self.prewarm_items.clear();
         self.scene_defines.clear();
         self.scene_id = 0;
         self.lights.clear();
+        self.decals.clear();
 
         self.collected_meshes.clear();
         self.collected_skeleton_keys.clear();
@@ -190,6 +259,11 @@ impl ExtractedScene {
         self.extract_lights(scene);
         self.extract_render_items(scene, camera, assets, resource_manager);
         self.extract_environment(scene);
+        self.extract_debug_lines(scene);
+        self.grid = scene.grid().copied();
+        self.extract_decals(scene);
+        self.extract_ground_shadow(scene);
+        self.occlusion_culling = scene.occlusion_culling_enabled();
 
         self.scene_variants.clear();
 
@@ -215,6 +289,16 @@ impl ExtractedScene {
             self.scene_variants.insert(SceneFeatures::USE_SSR);
         }
 
+        if scene.environment.fog.is_some() {
+            self.scene_defines.set("FOG", "1");
+            self.scene_variants.insert(SceneFeatures::FOG);
+        }
+
+        // Not a scene define: only blended materials compile the OIT variant.
+        if scene.transparency_mode == TransparencyMode::WeightedBlended {
+            self.scene_variants.insert(SceneFeatures::WEIGHTED_OIT);
+        }
+
         // Material-override debug view — inject shader defines so the PBR
         // fragment shader short-circuits lighting and outputs raw attributes.
         #[cfg(feature = "debug_view")]
@@ -229,6 +313,56 @@ impl ExtractedScene {
         }
     }
 
+    /// Turns prewarm requests into [`prewarm_items`](Self::prewarm_items):
+    /// one static, unskinned mesh per `(material, geometry)` pair that
+    /// receives shadows, like a freshly spawned mesh.
+    ///
+    /// Requests whose assets are still loading stay queued; those with
+    /// missing or failed assets are dropped.
+    pub fn extract_prewarm_items(
+        &mut self,
+        requests: &mut Vec<(MaterialHandle, GeometryHandle)>,
+        assets: &AssetServer,
+        resource_manager: &mut ResourceManager,
+    ) {
+        self.prewarm_items.clear();
+        requests.retain(|&(material, geometry)| {
+            if assets.materials.is_loading(material) || assets.geometries.is_loading(geometry) {
+                return true;
+            }
+            if !assets.materials.is_loaded(material) || !assets.geometries.is_loaded(geometry) {
+                return false;
+            }
+
+            let mut mesh = Mesh::new(geometry, material);
+            let Some(object_bind_group) = resource_manager.prepare_mesh(assets, &mut mesh, None)
+            else {
+                return false;
+            };
+            let mut item_shader_defines = ShaderDefines::with_capacity(1);
+            item_shader_defines.set("RECEIVE_SHADOWS", "1");
+
+            self.prewarm_items.push(ExtractedRenderItem {
+                node_handle: NodeHandle::default(),
+                world_matrix: Mat4::IDENTITY,
+                prev_world_matrix: Mat4::IDENTITY,
+                object_override: None,
+                object_bind_group,
+                geometry,
+                material,
+                draw_range: None,
+                item_variant_flags: 0,
+                item_shader_defines,
+                cast_shadows: mesh.cast_shadows,
+                receive_shadows: mesh.receive_shadows,
+                layers: ALL_LAYERS,
+                render_order: 0,
+                world_aabb: BoundingBox::infinite(),
+            });
+            false
+        });
+    }
+
     fn extract_lights(&mut self, scene: &Scene) {
         self.lights.reserve(scene.lights.len());
 
@@ -241,6 +375,7 @@ impl ExtractedScene {
             self.lights.push(ExtractedLight {
                 id: light.id(),
                 cast_shadows: light.cast_shadows,
+                shadow_layers: light.shadow_layers,
                 kind: light.kind.clone(),
                 position,
                 direction,
@@ -249,6 +384,32 @@ impl ExtractedScene {
         }
     }
 
+    fn extract_decals(&mut self, scene: &Scene) {
+        for (decal, world_matrix) in scene.iter_active_decals() {
+            self.decals.push(ExtractedDecal {
+                texture: decal.texture,
+                world_matrix: Mat4::from(*world_matrix),
+                options: decal.options,
+            });
+        }
+    }
+
+    /// Combines the bounds of this frame's shadow casters for the ground
+    /// shadow, skipping meshes centred on or below its plane and meshes
+    /// without finite bounds.
+    fn extract_ground_shadow(&mut self, scene: &Scene) {
+        self.ground_shadow = scene.ground_shadow().and_then(|&settings| {
+            let bounds = self
+                .render_items
+                .iter()
+                .filter(|item| item.cast_shadows && item.world_aabb.is_finite())
+                .map(|item| item.world_aabb)
+                .filter(|aabb| aabb.center().y > settings.plane_y)
+                .reduce(|a, b| a.union(&b))?;
+            Some(ExtractedGroundShadow { settings, bounds })
+        });
+    }
+
     #[must_use]
     pub fn has_shadow_casters(&self) -> bool {
         self.scene_variants.contains(SceneFeatures::HAS_SHADOWS)
@@ -257,7 +418,9 @@ impl ExtractedScene {
     /// Extract all active render items (no frustum culling).
     ///
     /// Only performs lightweight validity checks:
-    /// - `mesh.visible` and `node.visible` flags
+    /// - `mesh.visible` and the node's inherited visibility (hidden
+    ///   ancestors hide their whole subtree)
+    /// - LOD groups past their cull distance
     /// - Geometry asset exists
     ///
     /// World-space bounding spheres are pre-computed here so the Cull phase
@@ -267,10 +430,14 @@ impl ExtractedScene {
     fn extract_render_items(
         &mut self,
         scene: &mut Scene,
-        _camera: &RenderCamera,
+        camera: &RenderCamera,
         assets: &AssetServer,
         resource_manager: &mut ResourceManager,
     ) {
+        // LOD levels are picked once for the main camera so shadow views
+        // draw the same geometry.
+        scene.update_lods(camera.position);
+
         // =========================================================
         // Phase 1: Collect active meshes (holding read lock)
         // =========================================================
@@ -286,7 +453,15 @@ impl ExtractedScene {
                     continue;
                 };
 
-                if !node.visible {
+                if !node.is_visible_in_hierarchy() {
+                    continue;
+                }
+
+                if scene
+                    .lods
+                    .get(node_handle)
+                    .is_some_and(|lod| lod.current_level().is_none())
+                {
                     continue;
                 }
 
@@ -305,7 +480,10 @@ impl ExtractedScene {
                 let has_negative_scale = world_matrix.determinant() < 0.0;
                 let has_negative_scale_flag = u32::from(has_negative_scale);
                 let has_skeleton_flag = u32::from(skeleton_key.is_some()) << 1;
-                let item_variant_flags = has_negative_scale_flag | has_skeleton_flag;
+                let object_override = scene.node_override(node_handle).copied();
+                let has_override_flag = u32::from(object_override.is_some()) << 2;
+                let item_variant_flags =
+                    has_negative_scale_flag | has_skeleton_flag | has_override_flag;
 
                 // Pre-compute world-space axis-aligned bounding box for frustum culling in Cull phase.
                 // Priority: skeleton bounds > geometry AABB > geometry bounding sphere > infinite
@@ -325,10 +503,12 @@ impl ExtractedScene {
                     skeleton: skeleton_key,
                     world_matrix,
                     prev_world_matrix,
+                    object_override,
                     world_aabb,
                     item_variant_flags,
                     cast_shadows: mesh.cast_shadows,
                     receive_shadows: mesh.receive_shadows,
+                    layers: node.layers,
                 });
 
                 if let Some(key) = skeleton_key {
@@ -372,23 +552,50 @@ impl ExtractedScene {
             if mesh.receive_shadows {
                 item_shader_defines.set("RECEIVE_SHADOWS", "1");
             }
+            if item.object_override.is_some() {
+                item_shader_defines.set("USE_OBJECT_OVERRIDE", "1");
+            }
 
-            self.render_items.push(ExtractedRenderItem {
+            let base = ExtractedRenderItem {
                 node_handle: item.node_handle,
                 world_matrix: item.world_matrix,
                 prev_world_matrix: item.prev_world_matrix,
+                object_override: item.object_override,
                 object_bind_group,
                 geometry: mesh.geometry,
                 material: mesh.material,
+                draw_range: None,
                 item_variant_flags: item.item_variant_flags,
                 item_shader_defines,
                 cast_shadows: item.cast_shadows,
                 receive_shadows: item.receive_shadows,
+                layers: item.layers,
+                render_order: mesh.render_order,
                 world_aabb: item.world_aabb,
-            });
+            };
+
+            // Submeshes share the node's transform and bind group; each
+            // becomes its own item so it gets its own pipeline and sort key.
+            if mesh.submeshes.is_empty() {
+                self.render_items.push(base);
+            } else {
+                for submesh in &mesh.submeshes {
+                    self.render_items.push(ExtractedRenderItem {
+                        material: submesh.material,
+                        draw_range: Some(submesh.range.clone()),
+                        ..base.clone()
+                    });
+                }
+            }
         }
     }
 
+    /// Takes this frame's debug lines, leaving the scene's list empty.
+    fn extract_debug_lines(&mut self, scene: &mut Scene) {
+        self.debug_lines_depth_test = scene.debug_draw().depth_test;
+        scene.take_debug_lines(&mut self.debug_lines);
+    }
+
     /// Extract environment data
     fn extract_environment(&mut self, scene: &Scene) {
         self.background = scene.background.mode.clone();
@@ -13,7 +13,7 @@
 //! 3. **Execute**: `FrameComposer::render()` — acquire the surface and submit GPU commands
 //!
 
-use glam::Mat4;
+use glam::{Mat4, Vec4};
 use rustc_hash::FxHashMap;
 
 use crate::core::{BindGroupContext, RenderView, ResourceManager};
@@ -21,8 +21,9 @@ use crate::graph::core::TextureNodeId;
 use crate::pipeline::RenderPipelineId;
 use crate::renderer::FrameTime;
 use myth_assets::{AssetServer, GeometryHandle, MaterialHandle};
-use myth_scene::Scene;
+use myth_resources::BoundingBox;
 use myth_scene::camera::RenderCamera;
+use myth_scene::{NodeHandle, Scene};
 
 use super::extracted::ExtractedScene;
 use super::render_state::RenderState;
@@ -48,6 +49,8 @@ pub struct RenderCommand {
     pub geometry_handle: GeometryHandle,
     /// Material handle
     pub material_handle: MaterialHandle,
+    /// Submesh index (or vertex) range, `None` for the whole geometry.
+    pub draw_range: Option<std::ops::Range<u32>>,
     /// Pipeline handle (index into [`PipelineCache`] storage).
     ///
     /// Resolve to a `&wgpu::RenderPipeline` via
@@ -57,12 +60,44 @@ pub struct RenderCommand {
     pub sort_key: RenderKey,
     /// Dynamic uniform offset
     pub dynamic_offset: u32,
+    /// Draw each vertex as an instanced 6-vertex quad (point sprites).
+    pub point_sprites: bool,
+    /// Wireframe drawing mode.
+    pub wireframe: WireframeDraw,
+    /// Index into [`RenderLists::cull_spheres`] when the object is
+    /// frustum-culled on the GPU.
+    pub cull_object: Option<u32>,
+    /// The pipeline reads object data by instance (`GPU_DRIVEN`), so the
+    /// draw must be issued through [`GpuCullingFeature`](crate::graph::passes::GpuCullingFeature).
+    pub gpu_driven: bool,
+    /// GPU-driven and free of per-mesh object bindings (morph targets,
+    /// skins), so runs of such draws may be merged into one multi-draw.
+    pub mergeable: bool,
+    /// Stencil reference value of the material, `None` without stencil state.
+    pub stencil_reference: Option<u32>,
+    /// `Mesh::render_order`, sorted ahead of the sort key.
+    pub render_order: i32,
+}
+
+/// How a [`RenderCommand`] draws its wireframe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WireframeDraw {
+    /// Filled triangles.
+    None,
+    /// Line polygon mode on the original geometry.
+    Lines,
+    /// De-indexed geometry ([`ResourceManager::get_wireframe_geometry`])
+    /// drawn with the barycentric edge shader.
+    ///
+    /// [`ResourceManager::get_wireframe_geometry`]: crate::core::ResourceManager::get_wireframe_geometry
+    Barycentric,
 }
 
 pub struct ShadowRenderCommand {
     pub object_bind_group: BindGroupContext,
     pub geometry_handle: GeometryHandle,
     pub material_handle: MaterialHandle,
+    pub draw_range: Option<std::ops::Range<u32>>,
     /// Pipeline handle (index into [`PipelineCache`] storage).
     pub pipeline_id: RenderPipelineId,
     pub dynamic_offset: u32,
@@ -112,8 +147,8 @@ pub struct DrawCommand<'a> {
 
     /// Pre-resolved index buffer, or `None` for non-indexed draws.
     ///
-    /// Tuple: `(buffer, format, index_count)`.
-    pub index_buffer: Option<(&'a wgpu::Buffer, wgpu::IndexFormat, u32)>,
+    /// Tuple: `(buffer, format, index_range)`.
+    pub index_buffer: Option<(&'a wgpu::Buffer, wgpu::IndexFormat, std::ops::Range<u32>)>,
 
     /// Material bind group (Group 1), or `None` when unused.
     pub bind_group_1: Option<&'a wgpu::BindGroup>,
@@ -126,7 +161,7 @@ pub struct DrawCommand<'a> {
     /// Screen / transient bind group (Group 3), or `None` for shadow/prepass.
     pub bind_group_3: Option<&'a wgpu::BindGroup>,
 
-    /// Stencil reference value for feature-ID writing.  `None` when unused.
+    /// Stencil reference value from the material stencil state.  `None` when unused.
     pub stencil_reference: Option<u32>,
 
     /// Vertex range for non-indexed draws.
@@ -134,6 +169,48 @@ pub struct DrawCommand<'a> {
 
     /// Instance range.
     pub instance_range: std::ops::Range<u32>,
+
+    /// Index into [`RenderLists::cull_spheres`] for GPU-culled draws.
+    pub cull_object: Option<u32>,
+
+    /// The pipeline reads object data by instance; the GPU culling pass
+    /// points the draw's first instance at its model slot.
+    pub gpu_driven: bool,
+
+    /// See [`RenderCommand::mergeable`].
+    pub mergeable: bool,
+
+    /// Indirect arguments written by the GPU culling pass. When set, the
+    /// draw is issued indirectly and the ranges above only describe the
+    /// upper bound.
+    pub indirect: Option<IndirectDraw<'a>>,
+}
+
+/// How the GPU culling pass issues a [`DrawCommand`].
+#[derive(Clone, Copy)]
+pub enum IndirectDraw<'a> {
+    /// One indirect draw from `(buffer, offset)`.
+    Single(&'a wgpu::Buffer, u64),
+    /// The first draw of a merged run issues the whole run.
+    Multi(MultiDraw<'a>),
+    /// Issued by the preceding [`IndirectDraw::Multi`] draw.
+    Merged,
+}
+
+/// A run of consecutive draws merged into one multi-draw.
+///
+/// The compaction pass packs the arguments of the run's surviving draws at
+/// the front of `args`, and culled slots are left zeroed.
+#[derive(Clone, Copy)]
+pub struct MultiDraw<'a> {
+    /// Compacted indirect arguments `(buffer, offset)`.
+    pub args: (&'a wgpu::Buffer, u64),
+    /// Surviving draw count `(buffer, offset)`, with
+    /// `MULTI_DRAW_INDIRECT_COUNT`. Otherwise all `max_count` slots are
+    /// issued.
+    pub count: Option<(&'a wgpu::Buffer, u64)>,
+    /// Number of draws in the run.
+    pub max_count: u32,
 }
 
 /// Frame-scoped pre-baked render command lists.
@@ -148,6 +225,9 @@ pub struct BakedRenderLists<'a> {
     /// Baked transparent draw commands (sorted back-to-front).
     pub transparent: Vec<DrawCommand<'a>>,
 
+    /// Baked weighted blended OIT draw commands (unordered).
+    pub weighted_oit: Vec<DrawCommand<'a>>,
+
     /// Baked Z-prepass draw commands (prepass-specific pipelines).
     pub prepass: Vec<DrawCommand<'a>>,
 
@@ -195,6 +275,50 @@ impl<'a> PreparedSkyboxDraw<'a> {
     }
 }
 
+/// Pre-resolved debug line draw data.
+///
+/// Produced by [`DebugLinesFeature`](crate::graph::passes::DebugLinesFeature)
+/// and drawn either by its own pass or inside [`SimpleForwardPass::run()`].
+#[derive(Clone, Copy)]
+pub struct PreparedDebugLinesDraw<'a> {
+    /// Pre-resolved line-list render pipeline reference.
+    pub pipeline: &'a wgpu::RenderPipeline,
+    /// Per-frame vertex buffer holding [`myth_scene::DebugVertex`] data.
+    pub vertex_buffer: &'a wgpu::Buffer,
+    /// Number of vertices to draw (two per segment).
+    pub vertex_count: u32,
+}
+
+impl<'a> PreparedDebugLinesDraw<'a> {
+    pub fn draw(&self, pass: &mut wgpu::RenderPass<'a>, global_bind_group: &'a wgpu::BindGroup) {
+        pass.set_pipeline(self.pipeline);
+        pass.set_bind_group(0, global_bind_group, &[]);
+        pass.set_vertex_buffer(0, self.vertex_buffer.slice(..));
+        pass.draw(0..self.vertex_count, 0..1);
+    }
+}
+
+/// Pre-resolved ground grid draw data.
+///
+/// Produced by [`GridFeature`](crate::graph::passes::GridFeature) and drawn
+/// either by its own pass or inside [`SimpleForwardPass::run()`].
+#[derive(Clone, Copy)]
+pub struct PreparedGridDraw<'a> {
+    /// Pre-resolved fullscreen grid pipeline reference.
+    pub pipeline: &'a wgpu::RenderPipeline,
+    /// Grid parameters (group 1).
+    pub bind_group: &'a wgpu::BindGroup,
+}
+
+impl<'a> PreparedGridDraw<'a> {
+    pub fn draw(&self, pass: &mut wgpu::RenderPass<'a>, global_bind_group: &'a wgpu::BindGroup) {
+        pass.set_pipeline(self.pipeline);
+        pass.set_bind_group(0, global_bind_group, &[]);
+        pass.set_bind_group(1, self.bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+}
+
 /// Render lists.
 ///
 /// Stores culled and sorted render commands. Populated by `SceneCullPass`,
@@ -209,6 +333,8 @@ pub struct RenderLists {
     pub opaque: Vec<RenderCommand>,
     /// Transparent command list (back-to-front sorted)
     pub transparent: Vec<RenderCommand>,
+    /// Blended commands drawn by the weighted OIT pass (order-independent)
+    pub weighted_oit: Vec<RenderCommand>,
     /// Shadow command queues, keyed by `(light_id, layer_index)` for per-view culling.
     ///
     /// Each cascade of a directional light (or each spot light) gets its own queue.
@@ -226,6 +352,19 @@ pub struct RenderLists {
 
     /// Whether a transmission copy is needed this frame
     pub use_transmission: bool,
+
+    /// Render items that passed main-camera culling this frame.
+    pub visible_meshes: u32,
+    /// Render items rejected by the main camera's layer mask or frustum.
+    pub culled_meshes: u32,
+
+    /// World-space bounding spheres (`xyz` centre, `w` radius) of objects
+    /// left to the GPU culling pass.
+    pub cull_spheres: Vec<Vec4>,
+
+    /// Main-camera nodes (one entry per node) whose world-space bounds are
+    /// tested by the occlusion query pass this frame.
+    pub occlusion_candidates: Vec<(NodeHandle, BoundingBox)>,
 }
 
 impl RenderLists {
@@ -235,11 +374,16 @@ impl RenderLists {
         Self {
             opaque: Vec::with_capacity(512),
             transparent: Vec::with_capacity(128),
+            weighted_oit: Vec::new(),
             shadow_queues: FxHashMap::default(),
             shadow_lights: Vec::with_capacity(16),
             active_views: Vec::with_capacity(16),
             gpu_global_bind_group: None,
             use_transmission: false,
+            visible_meshes: 0,
+            culled_meshes: 0,
+            cull_spheres: Vec::new(),
+            occlusion_candidates: Vec::new(),
         }
     }
 
@@ -248,11 +392,16 @@ impl RenderLists {
     pub fn clear(&mut self) {
         self.opaque.clear();
         self.transparent.clear();
+        self.weighted_oit.clear();
         self.shadow_queues.clear();
         self.shadow_lights.clear();
         self.active_views.clear();
         self.gpu_global_bind_group = None;
         self.use_transmission = false;
+        self.visible_meshes = 0;
+        self.culled_meshes = 0;
+        self.cull_spheres.clear();
+        self.occlusion_candidates.clear();
     }
 
     /// Inserts an opaque render command.
@@ -267,20 +416,29 @@ impl RenderLists {
         self.transparent.push(cmd);
     }
 
+    /// Inserts a command for the weighted blended OIT pass.
+    #[inline]
+    pub fn insert_weighted_oit(&mut self, cmd: RenderCommand) {
+        self.weighted_oit.push(cmd);
+    }
+
     /// Sorts command lists.
     ///
+    /// Lower `render_order` values always draw first; within one order:
     /// - Opaque: by Pipeline > Material > Depth (front-to-back)
     /// - Transparent: by Depth (back-to-front) > Pipeline > Material
     pub fn sort(&mut self) {
-        self.opaque.sort_unstable_by_key(|a| a.sort_key);
-        self.transparent.sort_unstable_by_key(|a| a.sort_key);
+        self.opaque
+            .sort_unstable_by_key(|a| (a.render_order, a.sort_key));
+        self.transparent
+            .sort_unstable_by_key(|a| (a.render_order, a.sort_key));
     }
 
-    /// Returns `true` if both lists are empty.
+    /// Returns `true` if all main-camera lists are empty.
     #[inline]
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.opaque.is_empty() && self.transparent.is_empty()
+        self.opaque.is_empty() && self.transparent.is_empty() && self.weighted_oit.is_empty()
     }
 }
 
@@ -423,9 +581,16 @@ impl RenderFrame {
     /// 3. **Shadow Metadata** — Write per-light shadow layer indices,
     ///    cascade matrices and split distances into the light storage
     ///    buffer so the global bind group already contains correct data.
-    /// 4. **Global Prepare** — Upload camera / scene / light uniforms and
+    /// 4. **Light Clusters** — Size the light cluster grid to the lights
+    ///    in view (the compute pass fills it later).
+    /// 5. **Global Prepare** — Upload camera / scene / light uniforms and
     ///    create the global bind group (Group 0).
     ///
+    /// With `bind_probes` false, as in probe capture frames, materials use
+    /// the global environment only, without reflection or light probes.
+    /// `cluster_lights` enables the light cluster grid for scenes with many
+    /// lights.
+    ///
     /// # Note
     ///
     /// Surface acquisition is deferred to `FrameComposer::render()` to
@@ -439,6 +604,8 @@ impl RenderFrame {
         frame_time: FrameTime,
         render_lists: &mut RenderLists,
         surface_size: (u32, u32),
+        bind_probes: bool,
+        cluster_lights: bool,
     ) {
         use crate::core::view::RenderView;
 
@@ -463,6 +630,8 @@ impl RenderFrame {
                 scene.uniforms_buffer.write().env_map_max_mip_level = env_max_mip;
             }
         }
+        resource_manager.resolve_reflection_probe(scene, camera.position.into(), bind_probes);
+        resource_manager.resolve_light_probes(scene, bind_probes);
 
         // ── 3. Build shadow views (pure math) ──────────────────────────
         render_lists.clear();
@@ -493,7 +662,10 @@ impl RenderFrame {
             resource_manager,
         );
 
-        // ── 6. Global GPU resources ────────────────────────────────────
+        // ── 6. Light clusters ──────────────────────────────────────────
+        Self::resolve_light_clusters(scene, camera, cluster_lights);
+
+        // ── 7. Global GPU resources ────────────────────────────────────
         self.render_state.update(camera, frame_time, surface_size);
         resource_manager.prepare_global(assets, scene, &self.render_state);
     }
@@ -724,6 +896,62 @@ impl RenderFrame {
         resource_manager.ensure_buffer(&scene.light_storage_buffer);
     }
 
+    /// Write the light cluster grid of this frame's camera into the
+    /// environment uniforms and size the scene's cluster buffer.
+    ///
+    /// Each cluster holds one bit per light, set by the
+    /// [`LightClusterFeature`](crate::graph::passes::LightClusterFeature)
+    /// compute pass. The slices cover view depths up to the furthest reach
+    /// of any light with a finite range; an extra cluster after the grid
+    /// lists the lights reaching every point (directional lights and lights
+    /// without a range) for fragments beyond it. Without `enabled`, or with
+    /// few lights, the grid is cleared and materials loop over all lights.
+    fn resolve_light_clusters(scene: &Scene, camera: &RenderCamera, enabled: bool) {
+        use crate::graph::passes::light_cluster::{
+            CLUSTERED_LIGHTING_MIN_LIGHTS, LIGHT_CLUSTER_GRID,
+        };
+
+        let mut uniforms = *scene.uniforms_buffer.read();
+        let num_lights = uniforms.num_lights;
+
+        if enabled && num_lights > CLUSTERED_LIGHTING_MIN_LIGHTS {
+            let near = camera.near.max(1e-4);
+            let mut far = near;
+            for light in scene
+                .light_storage_buffer
+                .read()
+                .iter()
+                .take(num_lights as usize)
+            {
+                if light.light_type != 0 && light.range > 0.0 {
+                    let depth = -camera.view_matrix.transform_point3(light.position).z;
+                    far = far.max(depth + light.range);
+                }
+            }
+            let far = far.min(camera.far).max(near * 1.01);
+
+            let words = num_lights.div_ceil(32);
+            let len = ((LIGHT_CLUSTER_GRID.element_product() + 1) * words) as usize;
+            if scene.light_cluster_buffer.read().len() < len {
+                *scene.light_cluster_buffer.write() = vec![0; len];
+            }
+
+            uniforms.light_cluster_grid = LIGHT_CLUSTER_GRID.extend(words);
+            uniforms.light_cluster_depth = Vec4::new(
+                near,
+                far,
+                LIGHT_CLUSTER_GRID.z as f32 / (far / near).ln(),
+                0.0,
+            );
+        } else {
+            uniforms.light_cluster_grid = glam::UVec4::ZERO;
+        }
+
+        if *scene.uniforms_buffer.read() != uniforms {
+            *scene.uniforms_buffer.write() = uniforms;
+        }
+    }
+
     /// Periodically prune stale resources.
     pub fn maybe_prune(&self, resource_manager: &mut ResourceManager) {
         // Periodic cleanup (TODO: LRU eviction strategy)
@@ -7,6 +7,7 @@
 //! - [`ExtractedScene`]: Scene data extracted for GPU rendering
 //! - [`FrameComposer`]: Chainable API for frame composition (hook-based RDG)
 //! - [`RenderLists`]: Sorted render command lists
+//! - [`RenderStats`]: Per-frame culling and draw counters
 
 pub mod bake;
 pub mod composer;
@@ -17,12 +18,15 @@ pub mod frame;
 pub mod passes;
 pub mod render_state;
 pub mod shadow_utils;
+pub mod stats;
 
 pub use composer::FrameComposer;
 pub use extracted::{ExtractedRenderItem, ExtractedScene, ExtractedSkeleton};
 pub use frame::{
-    BakedRenderLists, DrawCommand, RenderCommand, RenderFrame, RenderKey, RenderLists,
+    BakedRenderLists, DrawCommand, IndirectDraw, MultiDraw, RenderCommand, RenderFrame, RenderKey,
+    RenderLists,
 };
 #[cfg(feature = "debug_view")]
 pub use render_state::DebugViewTarget;
 pub use render_state::RenderState;
+pub use stats::RenderStats;
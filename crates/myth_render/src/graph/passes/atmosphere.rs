@@ -118,6 +118,8 @@ struct GpuAtmosphereParams {
     sun_cos_angle: f32,
     sun_direction: [f32; 3],
     _pad1: f32,
+    ground_albedo: [f32; 3],
+    _pad2: f32,
 }
 
 impl GpuAtmosphereParams {
@@ -125,8 +127,8 @@ impl GpuAtmosphereParams {
         Self {
             rayleigh_scattering: params.rayleigh_scattering.into(),
             rayleigh_scale_height: params.rayleigh_scale_height,
-            mie_scattering: params.mie_scattering,
-            mie_absorption: params.mie_absorption,
+            mie_scattering: params.mie_scattering * params.turbidity,
+            mie_absorption: params.mie_absorption * params.turbidity,
             mie_scale_height: params.mie_scale_height,
             mie_anisotropy: params.mie_anisotropy,
             ozone_absorption: params.ozone_absorption.into(),
@@ -137,6 +139,8 @@ impl GpuAtmosphereParams {
             sun_cos_angle: params.sun_direction.y,
             sun_direction: params.sun_direction.into(),
             _pad1: 0.0,
+            ground_albedo: params.ground_albedo.into(),
+            _pad2: 0.0,
         }
     }
 }
@@ -234,6 +238,8 @@ fn physics_hash(params: &ProceduralSkyParams) -> u64 {
     hash_vec3(&mut hasher, params.ozone_absorption);
     hash_f32(&mut hasher, params.planet_radius);
     hash_f32(&mut hasher, params.atmosphere_radius);
+    hash_vec3(&mut hasher, params.ground_albedo);
+    hash_f32(&mut hasher, params.turbidity);
     hasher.finish()
 }
 
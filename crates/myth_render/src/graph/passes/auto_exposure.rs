@@ -0,0 +1,414 @@
+//! RDG Auto Exposure Feature (eye adaptation)
+//!
+//! Two compute passes over the HDR scene color, right before tone mapping:
+//!
+//! - `AutoExposure_Histogram`: bins the log luminance of every pixel into a
+//!   256-bin histogram.
+//! - `AutoExposure_Adapt`: reduces the histogram to the mean scene EV, eases
+//!   the persistent adapted EV toward it, clears the histogram, and copies
+//!   the resulting exposure into `ToneMappingUniforms::auto_exposure`.
+//!
+//! The histogram, adaptation state and parameters are persistent buffers
+//! imported into the graph. [`AutoExposureFeature::add_to_graph`] returns
+//! the written state buffer; the tone mapping pass reads it so the RDG
+//! orders the copy before the tone map draw.
+
+use crate::core::gpu::Tracked;
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    BufferDesc, BufferNodeId, ExecuteContext, ExtractContext, PassNode, PrepareContext,
+    TextureNodeId,
+};
+use crate::pipeline::{
+    ComputePipelineId, ComputePipelineKey, ShaderCompilationOptions, ShaderSource,
+};
+use myth_resources::buffer::CpuBuffer;
+use myth_resources::tone_mapping::{AutoExposure, ToneMappingUniforms};
+
+const HISTOGRAM_BINS: u64 = 256;
+const HISTOGRAM_WORKGROUP_SIZE: u32 = 16;
+
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct GpuAutoExposureParams {
+    min_ev: f32,
+    ev_range: f32,
+    speed_up: f32,
+    speed_down: f32,
+    delta_time: f32,
+    _pad: [f32; 3],
+}
+
+/// Adapted EV, exposure and an initialized flag (`ExposureState` in WGSL).
+const STATE_SIZE: u64 = 16;
+/// Byte offset of `ExposureState::exposure`.
+const STATE_EXPOSURE_OFFSET: u64 = 4;
+
+/// Long-lived auto exposure feature.
+///
+/// Owns the pipelines, layouts and persistent buffers. The adapted EV lives
+/// on the GPU only; the first frame after creation snaps to the measured
+/// scene EV instead of fading in.
+pub struct AutoExposureFeature {
+    histogram_pipeline: Option<ComputePipelineId>,
+    adapt_pipeline: Option<ComputePipelineId>,
+    histogram_layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    adapt_layout: Option<Tracked<wgpu::BindGroupLayout>>,
+
+    params_buffer: Option<Tracked<wgpu::Buffer>>,
+    histogram_buffer: Option<Tracked<wgpu::Buffer>>,
+    state_buffer: Option<Tracked<wgpu::Buffer>>,
+
+    /// Tone mapping uniform buffer receiving the adapted exposure.
+    tone_mapping_buffer: Option<wgpu::Buffer>,
+}
+
+impl Default for AutoExposureFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AutoExposureFeature {
+    /// Creates the feature. GPU resources are created on first use.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            histogram_pipeline: None,
+            adapt_pipeline: None,
+            histogram_layout: None,
+            adapt_layout: None,
+            params_buffer: None,
+            histogram_buffer: None,
+            state_buffer: None,
+            tone_mapping_buffer: None,
+        }
+    }
+
+    fn ensure_resources(&mut self, device: &wgpu::Device) {
+        if self.histogram_layout.is_some() {
+            return;
+        }
+
+        let params_entry = wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Uniform,
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let storage_entry = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: false },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+
+        self.histogram_layout = Some(Tracked::new(device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("AutoExposure Histogram BGL"),
+                entries: &[
+                    params_entry,
+                    storage_entry(1),
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                ],
+            },
+        )));
+        self.adapt_layout = Some(Tracked::new(device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("AutoExposure Adapt BGL"),
+                entries: &[params_entry, storage_entry(1), storage_entry(2)],
+            },
+        )));
+
+        self.params_buffer = Some(Tracked::new(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("AutoExposure Params"),
+                size: std::mem::size_of::<GpuAutoExposureParams>() as u64,
+                usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            },
+        )));
+        // Zero-initialized: an empty histogram and an uninitialized state.
+        self.histogram_buffer = Some(Tracked::new(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("AutoExposure Histogram"),
+                size: HISTOGRAM_BINS * 4,
+                usage: wgpu::BufferUsages::STORAGE,
+                mapped_at_creation: false,
+            },
+        )));
+        self.state_buffer = Some(Tracked::new(device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("AutoExposure State"),
+                size: STATE_SIZE,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            },
+        )));
+    }
+
+    fn ensure_pipelines(&mut self, ctx: &mut ExtractContext) {
+        if self.histogram_pipeline.is_some() {
+            return;
+        }
+
+        let opts = ShaderCompilationOptions::default();
+        let compilation_options = wgpu::PipelineCompilationOptions::default();
+
+        let mut create = |source: &'static str,
+                          layout: &wgpu::BindGroupLayout,
+                          label: &'static str| {
+            let (module, hash) =
+                ctx.shader_manager
+                    .get_or_compile(ctx.device, ShaderSource::File(source), &opts);
+            let pipeline_layout =
+                ctx.device
+                    .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                        label: Some(label),
+                        bind_group_layouts: &[Some(layout)],
+                        immediate_size: 0,
+                    });
+            ctx.pipeline_cache.get_or_create_compute(
+                ctx.device,
+                module,
+                &pipeline_layout,
+                &ComputePipelineKey::new(hash).with_compilation_options(&compilation_options),
+                &compilation_options,
+                label,
+            )
+        };
+
+        self.histogram_pipeline = Some(create(
+            "entry/post_process/auto_exposure/luminance_histogram",
+            self.histogram_layout.as_deref().unwrap(),
+            "AutoExposure Histogram Pipeline",
+        ));
+        self.adapt_pipeline = Some(create(
+            "entry/post_process/auto_exposure/exposure_adapt",
+            self.adapt_layout.as_deref().unwrap(),
+            "AutoExposure Adapt Pipeline",
+        ));
+    }
+
+    /// Pre-RDG preparation: creates resources, uploads this frame's
+    /// parameters and resolves the tone mapping uniform buffer that receives
+    /// the adapted exposure.
+    pub fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        settings: &AutoExposure,
+        tone_mapping_uniforms: &CpuBuffer<ToneMappingUniforms>,
+        delta_time: f32,
+    ) {
+        self.ensure_resources(ctx.device);
+        self.ensure_pipelines(ctx);
+
+        let params = GpuAutoExposureParams {
+            min_ev: settings.min_ev,
+            ev_range: (settings.max_ev - settings.min_ev).max(1e-3),
+            speed_up: settings.speed_up,
+            speed_down: settings.speed_down,
+            delta_time,
+            _pad: [0.0; 3],
+        };
+        ctx.queue.write_buffer(
+            self.params_buffer.as_ref().unwrap(),
+            0,
+            bytemuck::bytes_of(&params),
+        );
+
+        let (handle, _) = ctx.resource_manager.ensure_buffer(tone_mapping_uniforms);
+        self.tone_mapping_buffer = ctx
+            .resource_manager
+            .gpu_buffers
+            .get(handle)
+            .map(|gpu_buf| gpu_buf.buffer.clone());
+    }
+
+    /// Adds the histogram and adaptation passes reading `input_hdr`.
+    ///
+    /// Returns the adapted state buffer, which the tone mapping pass must
+    /// read so it runs after the exposure has been copied.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        input_hdr: TextureNodeId,
+    ) -> BufferNodeId {
+        let histogram_pipeline = self
+            .histogram_pipeline
+            .map(|id| ctx.pipeline_cache.get_compute_pipeline(id))
+            .expect("AutoExposureFeature not prepared");
+        let adapt_pipeline = self
+            .adapt_pipeline
+            .map(|id| ctx.pipeline_cache.get_compute_pipeline(id))
+            .expect("AutoExposureFeature not prepared");
+        let histogram_layout = self.histogram_layout.as_ref().unwrap();
+        let adapt_layout = self.adapt_layout.as_ref().unwrap();
+        let state_buffer = self.state_buffer.as_ref().unwrap();
+        let tone_mapping_buffer = self.tone_mapping_buffer.as_ref();
+        let (width, height) = (ctx.frame_config.width, ctx.frame_config.height);
+
+        let params = ctx.graph.import_external_buffer(
+            "AutoExposure_Params",
+            BufferDesc::new(
+                std::mem::size_of::<GpuAutoExposureParams>() as u64,
+                wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            ),
+            self.params_buffer.as_ref().unwrap(),
+        );
+        let histogram = ctx.graph.import_external_buffer(
+            "AutoExposure_Histogram",
+            BufferDesc::new(HISTOGRAM_BINS * 4, wgpu::BufferUsages::STORAGE),
+            self.histogram_buffer.as_ref().unwrap(),
+        );
+        let state = ctx.graph.import_external_buffer(
+            "AutoExposure_State",
+            BufferDesc::new(
+                STATE_SIZE,
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            ),
+            state_buffer,
+        );
+
+        ctx.with_group("AutoExposure_System", |ctx| {
+            let histogram = ctx.graph.add_pass("AutoExposure_Histogram", |builder| {
+                builder.read_texture(input_hdr);
+                builder.read_buffer(params);
+                let output = builder.write_buffer(histogram);
+                let node = HistogramPassNode {
+                    input_tex: input_hdr,
+                    params,
+                    histogram: output,
+                    width,
+                    height,
+                    pipeline: histogram_pipeline,
+                    layout: histogram_layout,
+                    bind_group: None,
+                };
+                (node, output)
+            });
+
+            ctx.graph.add_pass("AutoExposure_Adapt", |builder| {
+                builder.read_buffer(params);
+                builder.read_buffer(histogram);
+                let output = builder.write_buffer(state);
+                // The exposure copy targets a buffer the graph does not track.
+                builder.mark_side_effect();
+                let node = AdaptPassNode {
+                    params,
+                    histogram,
+                    state: output,
+                    state_buffer,
+                    tone_mapping_buffer,
+                    pipeline: adapt_pipeline,
+                    layout: adapt_layout,
+                    bind_group: None,
+                };
+                (node, output)
+            })
+        })
+    }
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// PassNodes (ephemeral, created per frame)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+struct HistogramPassNode<'a> {
+    input_tex: TextureNodeId,
+    params: BufferNodeId,
+    histogram: BufferNodeId,
+    width: u32,
+    height: u32,
+    pipeline: &'a wgpu::ComputePipeline,
+    layout: &'a Tracked<wgpu::BindGroupLayout>,
+    bind_group: Option<&'a wgpu::BindGroup>,
+}
+
+impl<'a> PassNode<'a> for HistogramPassNode<'a> {
+    fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
+        self.bind_group = Some(
+            ctx.build_bind_group(self.layout, Some("AutoExposure Histogram BG"))
+                .bind_buffer(0, self.params)
+                .bind_buffer(1, self.histogram)
+                .bind_texture(2, self.input_tex)
+                .build(),
+        );
+    }
+
+    fn execute(&self, _ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("AutoExposure Histogram"),
+            timestamp_writes: None,
+        });
+        cpass.set_pipeline(self.pipeline);
+        cpass.set_bind_group(0, self.bind_group.expect("histogram BG missing"), &[]);
+        cpass.dispatch_workgroups(
+            self.width.div_ceil(HISTOGRAM_WORKGROUP_SIZE),
+            self.height.div_ceil(HISTOGRAM_WORKGROUP_SIZE),
+            1,
+        );
+    }
+}
+
+struct AdaptPassNode<'a> {
+    params: BufferNodeId,
+    histogram: BufferNodeId,
+    state: BufferNodeId,
+    state_buffer: &'a Tracked<wgpu::Buffer>,
+    tone_mapping_buffer: Option<&'a wgpu::Buffer>,
+    pipeline: &'a wgpu::ComputePipeline,
+    layout: &'a Tracked<wgpu::BindGroupLayout>,
+    bind_group: Option<&'a wgpu::BindGroup>,
+}
+
+impl<'a> PassNode<'a> for AdaptPassNode<'a> {
+    fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
+        self.bind_group = Some(
+            ctx.build_bind_group(self.layout, Some("AutoExposure Adapt BG"))
+                .bind_buffer(0, self.params)
+                .bind_buffer(1, self.histogram)
+                .bind_buffer(2, self.state)
+                .build(),
+        );
+    }
+
+    fn execute(&self, _ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        {
+            let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("AutoExposure Adapt"),
+                timestamp_writes: None,
+            });
+            cpass.set_pipeline(self.pipeline);
+            cpass.set_bind_group(0, self.bind_group.expect("adapt BG missing"), &[]);
+            cpass.dispatch_workgroups(1, 1, 1);
+        }
+
+        if let Some(tone_mapping_buffer) = self.tone_mapping_buffer {
+            encoder.copy_buffer_to_buffer(
+                self.state_buffer,
+                STATE_EXPOSURE_OFFSET,
+                tone_mapping_buffer,
+                std::mem::offset_of!(ToneMappingUniforms, auto_exposure) as u64,
+                4,
+            );
+        }
+    }
+}
@@ -4,8 +4,9 @@
 //! technique within the RDG framework using fully flattened per-mip passes
 //! and a strict two-layer binding separation:
 //!
-//! - **Group 0 (Static)**: Sampler + uniform buffer — built and owned by
-//!   [`BloomFeature`], lifetime matches the engine.
+//! - **Group 0 (Static)**: Sampler + uniform buffer(s), plus the lens dirt
+//!   mask for the composite — built and owned by [`BloomFeature`], lifetime
+//!   matches the engine.
 //! - **Group 1 (Transient)**: RDG texture views — built by each micro-pass
 //!   node during `prepare`, cached via [`GlobalBindGroupCache`].
 //!
@@ -23,7 +24,7 @@
 //! # RDG Topology (Flattened)
 //!
 //! ```text
-//! Bloom_Extract (Scene HDR → Mip_0)
+//! Bloom_Extract (Scene HDR → Mip_0, threshold + soft knee)
 //!   → Bloom_Downsample_1 (Mip_0 → Mip_1)
 //!   → Bloom_Downsample_2 (Mip_1 → Mip_2)
 //!   → ...
@@ -34,7 +35,7 @@
 //! ```
 
 use crate::HDR_TEXTURE_FORMAT;
-use crate::core::gpu::{CommonSampler, Tracked};
+use crate::core::gpu::{CommonSampler, ResourceState, Tracked};
 use crate::graph::composer::GraphBuilderContext;
 use crate::graph::core::{
     ExecuteContext, ExtractContext, PassNode, PrepareContext, RenderTargetOps, TextureDesc,
@@ -43,9 +44,11 @@ use crate::graph::core::{
 use crate::pipeline::{
     ColorTargetKey, FullscreenPipelineKey, RenderPipelineId, ShaderCompilationOptions, ShaderSource,
 };
-use myth_resources::bloom::{CompositeUniforms, UpsampleUniforms};
+use myth_assets::TextureHandle;
+use myth_resources::bloom::{CompositeUniforms, PrefilterUniforms, UpsampleUniforms};
 use myth_resources::buffer::CpuBuffer;
 use myth_resources::gpu_struct;
+use myth_resources::texture::TextureSource;
 use myth_resources::uniforms::WgslStruct;
 
 /// Internal GPU uniform for the downsample shader (karis on/off flag).
@@ -75,19 +78,23 @@ pub struct DownsampleUniforms {
 pub struct BloomFeature {
     // ─── Pipelines ─────────────────────────────────────────────────
     downsample_pipeline: Option<RenderPipelineId>,
+    /// Downsample variant with the luminance threshold, used for the extract step.
+    prefilter_pipeline: Option<RenderPipelineId>,
     upsample_pipeline: Option<RenderPipelineId>,
     composite_pipeline: Option<RenderPipelineId>,
 
     // ─── Bind Group Layouts ────────────────────────────────────────
     /// Group 0 layout for downsample: sampler + DownsampleUniforms.
     ds_static_layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    /// Group 0 layout for the extract step: sampler + DownsampleUniforms + PrefilterUniforms.
+    prefilter_static_layout: Option<Tracked<wgpu::BindGroupLayout>>,
     /// Group 1 layout for downsample: single input texture.
     ds_transient_layout: Option<Tracked<wgpu::BindGroupLayout>>,
     /// Group 0 layout for upsample: sampler + UpsampleUniforms.
     us_static_layout: Option<Tracked<wgpu::BindGroupLayout>>,
     /// Group 1 layout for upsample: single source mip texture.
     us_transient_layout: Option<Tracked<wgpu::BindGroupLayout>>,
-    /// Group 0 layout for composite: sampler + CompositeUniforms.
+    /// Group 0 layout for composite: sampler + CompositeUniforms + dirt texture.
     comp_static_layout: Option<Tracked<wgpu::BindGroupLayout>>,
     /// Group 1 layout for composite: original + bloom textures.
     comp_transient_layout: Option<Tracked<wgpu::BindGroupLayout>>,
@@ -99,18 +106,23 @@ pub struct BloomFeature {
     karis_off_buffer: Option<Tracked<wgpu::Buffer>>,
 
     // ─── Pre-Built Static BindGroups (Group 0) ─────────────────────
-    /// Downsample Group 0 with karis averaging enabled.
-    karis_on_static_bg: Option<wgpu::BindGroup>,
+    /// Extract Group 0 with karis averaging enabled.
+    prefilter_karis_on_bg: Option<wgpu::BindGroup>,
+    /// Extract Group 0 with karis averaging disabled.
+    prefilter_karis_off_bg: Option<wgpu::BindGroup>,
     /// Downsample Group 0 with karis averaging disabled.
     karis_off_static_bg: Option<wgpu::BindGroup>,
     /// Upsample Group 0 (sampler + UpsampleUniforms buffer).
     upsample_static_bg: Option<wgpu::BindGroup>,
-    /// Composite Group 0 (sampler + CompositeUniforms buffer).
+    /// Composite Group 0 (sampler + CompositeUniforms buffer + dirt texture).
     composite_static_bg: Option<wgpu::BindGroup>,
 
     // ─── Staleness tracking for externally-managed GPU buffers ─────
+    last_prefilter_buffer_id: u64,
     last_upsample_buffer_id: u64,
     last_composite_buffer_id: u64,
+    /// View id of the bound dirt texture, 0 for the black fallback.
+    last_dirt_view_id: u64,
 }
 
 impl Default for BloomFeature {
@@ -125,10 +137,12 @@ impl BloomFeature {
     pub fn new() -> Self {
         Self {
             downsample_pipeline: None,
+            prefilter_pipeline: None,
             upsample_pipeline: None,
             composite_pipeline: None,
 
             ds_static_layout: None,
+            prefilter_static_layout: None,
             ds_transient_layout: None,
             us_static_layout: None,
             us_transient_layout: None,
@@ -138,13 +152,16 @@ impl BloomFeature {
             karis_on_buffer: None,
             karis_off_buffer: None,
 
-            karis_on_static_bg: None,
+            prefilter_karis_on_bg: None,
+            prefilter_karis_off_bg: None,
             karis_off_static_bg: None,
             upsample_static_bg: None,
             composite_static_bg: None,
 
+            last_prefilter_buffer_id: 0,
             last_upsample_buffer_id: 0,
             last_composite_buffer_id: 0,
+            last_dirt_view_id: 0,
         }
     }
 
@@ -156,22 +173,39 @@ impl BloomFeature {
     /// are initialised. Build or rebuild static bind groups (Group 0) when the
     /// underlying GPU buffer identity changes.
     ///
-    /// `upsample_uniform` and `composite_uniform` are the CpuBuffer IDs
-    /// whose GPU mirrors have already been uploaded via `ensure_buffer()`.
+    /// `prefilter_uniform`, `upsample_uniform` and `composite_uniform` are
+    /// uploaded here via `ensure_buffer()`. A missing or still loading
+    /// `dirt_texture` binds a black texture, which adds no dirt.
     pub fn extract_and_prepare(
         &mut self,
         ctx: &mut ExtractContext,
+        prefilter_uniform: &CpuBuffer<PrefilterUniforms>,
         upsample_uniform: &CpuBuffer<UpsampleUniforms>,
         composite_uniform: &CpuBuffer<CompositeUniforms>,
+        dirt_texture: Option<TextureHandle>,
     ) {
         self.ensure_layouts(ctx.device);
         self.ensure_internal_buffers(ctx.device, ctx.queue);
         self.ensure_pipelines(ctx);
 
+        ctx.resource_manager.ensure_buffer(prefilter_uniform);
         ctx.resource_manager.ensure_buffer(upsample_uniform);
         ctx.resource_manager.ensure_buffer(composite_uniform);
 
-        self.build_static_bind_groups(ctx, upsample_uniform, composite_uniform);
+        let dirt_texture = dirt_texture.filter(|&handle| {
+            matches!(
+                ctx.resource_manager.prepare_texture(ctx.assets, handle),
+                ResourceState::Ready
+            )
+        });
+
+        self.build_static_bind_groups(
+            ctx,
+            prefilter_uniform,
+            upsample_uniform,
+            composite_uniform,
+            dirt_texture,
+        );
     }
 
     // =========================================================================
@@ -200,6 +234,10 @@ impl BloomFeature {
             },
             count: None,
         };
+        let prefilter_entry = wgpu::BindGroupLayoutEntry {
+            binding: 2,
+            ..uniform_entry
+        };
         let texture_entry = |binding: u32| wgpu::BindGroupLayoutEntry {
             binding,
             visibility: wgpu::ShaderStages::FRAGMENT,
@@ -218,6 +256,12 @@ impl BloomFeature {
                 entries: &[sampler_entry, uniform_entry],
             },
         )));
+        self.prefilter_static_layout = Some(Tracked::new(device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("Bloom Prefilter Static Layout (G0)"),
+                entries: &[sampler_entry, uniform_entry, prefilter_entry],
+            },
+        )));
         self.ds_transient_layout = Some(Tracked::new(device.create_bind_group_layout(
             &wgpu::BindGroupLayoutDescriptor {
                 label: Some("Bloom DS Transient Layout (G1)"),
@@ -243,7 +287,7 @@ impl BloomFeature {
         self.comp_static_layout = Some(Tracked::new(device.create_bind_group_layout(
             &wgpu::BindGroupLayoutDescriptor {
                 label: Some("Bloom Comp Static Layout (G0)"),
-                entries: &[sampler_entry, uniform_entry],
+                entries: &[sampler_entry, uniform_entry, texture_entry(2)],
             },
         )));
         self.comp_transient_layout = Some(Tracked::new(device.create_bind_group_layout(
@@ -312,6 +356,7 @@ impl BloomFeature {
         });
 
         let ds_static = self.ds_static_layout.as_deref();
+        let prefilter_static = self.prefilter_static_layout.as_deref();
         let ds_trans = self.ds_transient_layout.as_deref();
         let us_static = self.us_static_layout.as_deref();
         let us_trans = self.us_transient_layout.as_deref();
@@ -353,6 +398,47 @@ impl BloomFeature {
             ));
         }
 
+        // ─── Prefilter Pipeline (extract step) ─────────────────────
+        {
+            let mut options = ShaderCompilationOptions::default();
+            options.add_define("PREFILTER", "1");
+            options.add_define(
+                "struct_definitions",
+                format!(
+                    "{}\n{}",
+                    DownsampleUniforms::wgsl_struct_def("DownsampleUniforms"),
+                    PrefilterUniforms::wgsl_struct_def("PrefilterUniforms"),
+                )
+                .as_str(),
+            );
+
+            let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Bloom Prefilter Pipeline Layout"),
+                bind_group_layouts: &[prefilter_static, ds_trans],
+                immediate_size: 0,
+            });
+
+            let (module, hash) = ctx.shader_manager.get_or_compile(
+                device,
+                ShaderSource::File("entry/post_process/bloom/downsample"),
+                &options,
+            );
+
+            let key = FullscreenPipelineKey::fullscreen(
+                hash,
+                smallvec::smallvec![color_target_replace.clone()],
+                None,
+            );
+
+            self.prefilter_pipeline = Some(ctx.pipeline_cache.get_or_create_fullscreen(
+                device,
+                module,
+                &layout,
+                &key,
+                "Bloom Prefilter Pipeline",
+            ));
+        }
+
         // ─── Upsample Pipeline (additive blend) ───────────────────
         {
             let mut options = ShaderCompilationOptions::default();
@@ -425,14 +511,17 @@ impl BloomFeature {
     }
 
     /// Build all static bind groups (Group 0) that pair samplers with
-    /// persistent uniform buffers. Karis BGs are built once; upsample
-    /// and composite BGs are rebuilt only when the underlying GPU buffer
-    /// identity changes (e.g. after an `ensure_buffer` resize).
+    /// persistent uniform buffers. The downsample BG is built once;
+    /// prefilter, upsample and composite BGs are rebuilt only when the
+    /// underlying GPU buffer identity changes (e.g. after an `ensure_buffer`
+    /// resize), or for the composite, when the dirt texture changes.
     fn build_static_bind_groups(
         &mut self,
         ctx: &mut ExtractContext,
+        prefilter_uniform: &CpuBuffer<PrefilterUniforms>,
         upsample_uniform: &CpuBuffer<UpsampleUniforms>,
         composite_uniform: &CpuBuffer<CompositeUniforms>,
+        dirt_texture: Option<TextureHandle>,
     ) {
         let sampler = ctx
             .resource_manager
@@ -440,14 +529,13 @@ impl BloomFeature {
             .get_common(CommonSampler::LinearClamp);
         let ds_layout = self.ds_static_layout.as_ref().unwrap();
 
-        // ─── Karis BGs (eternal, built once) ───────────────────────
-        if self.karis_on_static_bg.is_none() {
-            let karis_on = self.karis_on_buffer.as_ref().unwrap();
+        // ─── Downsample BG (eternal, built once) ───────────────────
+        if self.karis_off_static_bg.is_none() {
             let karis_off = self.karis_off_buffer.as_ref().unwrap();
 
-            self.karis_on_static_bg =
+            self.karis_off_static_bg =
                 Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("Bloom DS G0 (karis on)"),
+                    label: Some("Bloom DS G0 (karis off)"),
                     layout: ds_layout,
                     entries: &[
                         wgpu::BindGroupEntry {
@@ -456,15 +544,22 @@ impl BloomFeature {
                         },
                         wgpu::BindGroupEntry {
                             binding: 1,
-                            resource: karis_on.as_entire_binding(),
+                            resource: karis_off.as_entire_binding(),
                         },
                     ],
                 }));
+        }
 
-            self.karis_off_static_bg =
-                Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
-                    label: Some("Bloom DS G0 (karis off)"),
-                    layout: ds_layout,
+        // ─── Prefilter BGs (rebuild on buffer identity change) ─────
+        if let Some(handle) = prefilter_uniform.gpu_handle()
+            && let Some(g) = ctx.resource_manager.gpu_buffers.get(handle)
+            && (self.prefilter_karis_on_bg.is_none() || self.last_prefilter_buffer_id != g.id)
+        {
+            let prefilter_layout = self.prefilter_static_layout.as_ref().unwrap();
+            let build = |label: &str, karis: &wgpu::Buffer| {
+                ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                    label: Some(label),
+                    layout: prefilter_layout,
                     entries: &[
                         wgpu::BindGroupEntry {
                             binding: 0,
@@ -472,10 +567,25 @@ impl BloomFeature {
                         },
                         wgpu::BindGroupEntry {
                             binding: 1,
-                            resource: karis_off.as_entire_binding(),
+                            resource: karis.as_entire_binding(),
+                        },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: g.buffer.as_entire_binding(),
                         },
                     ],
-                }));
+                })
+            };
+
+            self.prefilter_karis_on_bg = Some(build(
+                "Bloom Prefilter G0 (karis on)",
+                self.karis_on_buffer.as_ref().unwrap(),
+            ));
+            self.prefilter_karis_off_bg = Some(build(
+                "Bloom Prefilter G0 (karis off)",
+                self.karis_off_buffer.as_ref().unwrap(),
+            ));
+            self.last_prefilter_buffer_id = g.id;
         }
 
         // ─── Upsample static BG (rebuild on buffer identity change) ──
@@ -502,10 +612,23 @@ impl BloomFeature {
             self.last_upsample_buffer_id = g.id;
         }
 
-        // ─── Composite static BG (rebuild on buffer identity change) ──
+        // ─── Composite static BG (rebuild on buffer or dirt change) ──
+        let (dirt_view, dirt_view_id) = match dirt_texture
+            .and_then(|handle| Some((handle, ctx.resource_manager.get_texture_binding(handle)?)))
+        {
+            Some((handle, binding)) => (
+                ctx.resource_manager
+                    .get_texture_view(&TextureSource::Asset(handle)),
+                binding.view_id,
+            ),
+            None => (&*ctx.resource_manager.system_textures.black_2d, 0),
+        };
+
         if let Some(handle) = composite_uniform.gpu_handle()
             && let Some(g) = ctx.resource_manager.gpu_buffers.get(handle)
-            && (self.composite_static_bg.is_none() || self.last_composite_buffer_id != g.id)
+            && (self.composite_static_bg.is_none()
+                || self.last_composite_buffer_id != g.id
+                || self.last_dirt_view_id != dirt_view_id)
         {
             let comp_layout = self.comp_static_layout.as_ref().unwrap();
             self.composite_static_bg =
@@ -521,9 +644,14 @@ impl BloomFeature {
                             binding: 1,
                             resource: g.buffer.as_entire_binding(),
                         },
+                        wgpu::BindGroupEntry {
+                            binding: 2,
+                            resource: wgpu::BindingResource::TextureView(dirt_view),
+                        },
                     ],
                 }));
             self.last_composite_buffer_id = g.id;
+            self.last_dirt_view_id = dirt_view_id;
         }
     }
 
@@ -558,6 +686,10 @@ impl BloomFeature {
         let max_possible = ((bloom_w.max(bloom_h) as f32).log2().floor() as u32) + 1;
         let mip_count = max_mip_levels.min(max_possible).max(1) as usize;
 
+        let prefilter_pipeline = ctx.pipeline_cache.get_render_pipeline(
+            self.prefilter_pipeline
+                .expect("BloomFeature: prefilter pipeline not initialised"),
+        );
         let ds_pipeline = ctx.pipeline_cache.get_render_pipeline(
             self.downsample_pipeline
                 .expect("BloomFeature: downsample pipeline not initialised"),
@@ -571,10 +703,14 @@ impl BloomFeature {
                 .expect("BloomFeature: composite pipeline not initialised"),
         );
 
-        let karis_on_bg = self
-            .karis_on_static_bg
+        let prefilter_karis_on_bg = self
+            .prefilter_karis_on_bg
+            .as_ref()
+            .expect("BloomFeature: prefilter karis_on BG not built");
+        let prefilter_karis_off_bg = self
+            .prefilter_karis_off_bg
             .as_ref()
-            .expect("BloomFeature: karis_on static BG not built");
+            .expect("BloomFeature: prefilter karis_off BG not built");
         let karis_off_bg = self
             .karis_off_static_bg
             .as_ref()
@@ -604,9 +740,9 @@ impl BloomFeature {
             );
 
             let static_bg = if karis_average {
-                karis_on_bg
+                prefilter_karis_on_bg
             } else {
-                karis_off_bg
+                prefilter_karis_off_bg
             };
 
             let mut current_mip: TextureNodeId = ctx.graph.add_pass("Bloom_Extract", |builder| {
@@ -615,7 +751,7 @@ impl BloomFeature {
                 let node = BloomDownsampleNode {
                     input_tex: input_color,
                     output_tex: out,
-                    pipeline: ds_pipeline,
+                    pipeline: prefilter_pipeline,
                     static_bg,
                     transient_layout: ds_transient_layout,
                     transient_bg: None,
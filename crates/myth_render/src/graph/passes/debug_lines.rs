@@ -0,0 +1,258 @@
+//! RDG Debug Lines Pass
+//!
+//! Draws the immediate-mode line list collected through `Scene::debug()`
+//! (see [`myth_scene::DebugDraw`]). Runs after the opaque geometry and the
+//! skybox so lines can optionally be occluded by the scene depth.
+//!
+//! # RDG Slots
+//!
+//! - `scene_color`: HDR/surface color buffer (read + write, LoadOp::Load)
+//! - `scene_depth`: Depth buffer (read, LoadOp::Load)
+//!
+//! The vertex buffer is owned by the feature and re-uploaded every frame;
+//! it only grows, so steady-state frames perform a single `write_buffer`.
+
+use rustc_hash::FxHashMap;
+
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    ExecuteContext, ExtractContext, PassNode, RenderTargetOps, TextureNodeId,
+};
+use crate::graph::frame::PreparedDebugLinesDraw;
+use crate::pipeline::{
+    ColorTargetKey, DepthStencilKey, PipelineCache, RenderPipelineId, ShaderCompilationOptions,
+    ShaderSource, SimpleGeometryPipelineKey,
+};
+use myth_scene::DebugVertex;
+
+/// Stable layout id for the debug vertex format (position + color).
+const DEBUG_VERTEX_LAYOUT_ID: u64 = 0x00de_b061_13e5;
+
+/// Minimum vertex buffer capacity, in vertices.
+const MIN_VERTEX_CAPACITY: usize = 1024;
+
+const DEBUG_VERTEX_ATTRIBUTES: [wgpu::VertexAttribute; 2] =
+    wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x4];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DebugLinesPipelineKey {
+    color_format: wgpu::TextureFormat,
+    depth_format: wgpu::TextureFormat,
+    msaa_samples: u32,
+    depth_test: bool,
+}
+
+/// Persistent debug line renderer.
+pub struct DebugLinesFeature {
+    vertex_buffer: Option<wgpu::Buffer>,
+    capacity: usize,
+    vertex_count: u32,
+    local_cache: FxHashMap<DebugLinesPipelineKey, RenderPipelineId>,
+    current_pipeline: Option<RenderPipelineId>,
+}
+
+impl Default for DebugLinesFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugLinesFeature {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            vertex_buffer: None,
+            capacity: 0,
+            vertex_count: 0,
+            local_cache: FxHashMap::default(),
+            current_pipeline: None,
+        }
+    }
+
+    /// Upload this frame's debug vertices and resolve the pipeline.
+    ///
+    /// Called every frame; an empty line list disables the pass.
+    pub(crate) fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        global_state_key: (u32, u32),
+        color_format: wgpu::TextureFormat,
+    ) {
+        let vertices = &ctx.extracted_scene.debug_lines;
+        if vertices.is_empty() {
+            self.vertex_count = 0;
+            self.current_pipeline = None;
+            return;
+        }
+
+        if self.vertex_buffer.is_none() || vertices.len() > self.capacity {
+            let capacity = vertices.len().next_power_of_two().max(MIN_VERTEX_CAPACITY);
+            self.vertex_buffer = Some(ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Debug Lines Vertex Buffer"),
+                size: (capacity * size_of::<DebugVertex>()) as u64,
+                usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }));
+            self.capacity = capacity;
+        }
+        if let Some(buffer) = &self.vertex_buffer {
+            ctx.queue
+                .write_buffer(buffer, 0, bytemuck::cast_slice(vertices));
+        }
+        self.vertex_count = vertices.len() as u32;
+
+        let key = DebugLinesPipelineKey {
+            color_format,
+            depth_format: ctx.wgpu_ctx.depth_format,
+            msaa_samples: ctx.wgpu_ctx.msaa_samples,
+            depth_test: ctx.extracted_scene.debug_lines_depth_test,
+        };
+        self.current_pipeline = Some(self.get_or_create_pipeline(ctx, key, global_state_key));
+    }
+
+    fn get_or_create_pipeline(
+        &mut self,
+        ctx: &mut ExtractContext,
+        key: DebugLinesPipelineKey,
+        global_state_key: (u32, u32),
+    ) -> RenderPipelineId {
+        if let Some(&pipeline_id) = self.local_cache.get(&key) {
+            return pipeline_id;
+        }
+
+        let gpu_world = ctx
+            .resource_manager
+            .get_global_state(global_state_key.0, global_state_key.1)
+            .expect("Global state must exist");
+
+        let mut options = ShaderCompilationOptions::default();
+        options.inject_code("binding_code", &gpu_world.binding_wgsl);
+
+        let (shader_module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/debug_lines"),
+            &options,
+        );
+
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Debug Lines Pipeline Layout"),
+                bind_group_layouts: &[Some(&gpu_world.layout)],
+                immediate_size: 0,
+            });
+
+        // Reverse-Z: nearer fragments have larger depth values.
+        let depth_compare = if key.depth_test {
+            wgpu::CompareFunction::GreaterEqual
+        } else {
+            wgpu::CompareFunction::Always
+        };
+
+        let pipeline_key = SimpleGeometryPipelineKey {
+            shader_hash,
+            vertex_layout_id: DEBUG_VERTEX_LAYOUT_ID,
+            color_targets: smallvec::smallvec![ColorTargetKey::from(wgpu::ColorTargetState {
+                format: key.color_format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            depth_stencil: DepthStencilKey::from(wgpu::DepthStencilState {
+                format: key.depth_format,
+                depth_write_enabled: Some(false),
+                depth_compare: Some(depth_compare),
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            topology: wgpu::PrimitiveTopology::LineList,
+            cull_mode: None,
+            front_face: wgpu::FrontFace::Ccw,
+            sample_count: key.msaa_samples,
+        };
+
+        let vertex_layout = wgpu::VertexBufferLayout {
+            array_stride: size_of::<DebugVertex>() as u64,
+            step_mode: wgpu::VertexStepMode::Vertex,
+            attributes: &DEBUG_VERTEX_ATTRIBUTES,
+        };
+
+        let pipeline_id = ctx.pipeline_cache.get_or_create_simple_geometry(
+            ctx.device,
+            shader_module,
+            &pipeline_layout,
+            &pipeline_key,
+            "Debug Lines Pipeline",
+            &[vertex_layout],
+        );
+
+        self.local_cache.insert(key, pipeline_id);
+        pipeline_id
+    }
+
+    /// Resolve the draw for embedding in another render pass
+    /// (used by the `BasicForward` path).
+    pub(crate) fn prepared_draw<'a>(
+        &'a self,
+        pipeline_cache: &'a PipelineCache,
+    ) -> Option<PreparedDebugLinesDraw<'a>> {
+        if self.vertex_count == 0 {
+            return None;
+        }
+        Some(PreparedDebugLinesDraw {
+            pipeline: pipeline_cache.get_render_pipeline(self.current_pipeline?),
+            vertex_buffer: self.vertex_buffer.as_ref()?,
+            vertex_count: self.vertex_count,
+        })
+    }
+
+    /// Insert the debug lines pass into the graph. Returns `scene_color`
+    /// unchanged when there is nothing to draw.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        scene_color: TextureNodeId,
+        scene_depth: TextureNodeId,
+    ) -> TextureNodeId {
+        let Some(draw) = self.prepared_draw(ctx.pipeline_cache) else {
+            return scene_color;
+        };
+        ctx.graph.add_pass("Debug_Lines_Pass", |builder| {
+            let out_color = builder.mutate_texture(scene_color, "Scene_Color_Debug_Lines");
+            builder.read_texture(scene_depth);
+            let node = DebugLinesPassNode {
+                out_color,
+                scene_depth,
+                draw,
+            };
+            (node, out_color)
+        })
+    }
+}
+
+// ─── Debug Lines Pass Node ────────────────────────────────────────────────────
+
+/// Ephemeral per-frame debug lines render pass node.
+pub struct DebugLinesPassNode<'a> {
+    out_color: TextureNodeId,
+    scene_depth: TextureNodeId,
+    draw: PreparedDebugLinesDraw<'a>,
+}
+
+impl<'a> PassNode<'a> for DebugLinesPassNode<'a> {
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let color_att = ctx.get_color_attachment(self.out_color, RenderTargetOps::Load, None);
+        let depth_att = ctx.get_depth_stencil_attachment(self.scene_depth, 0.0);
+
+        let pass_desc = wgpu::RenderPassDescriptor {
+            label: Some("Debug Lines Pass"),
+            color_attachments: &[color_att],
+            depth_stencil_attachment: depth_att,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        };
+
+        let mut pass = encoder.begin_render_pass(&pass_desc);
+        self.draw.draw(&mut pass, ctx.baked_lists.global_bind_group);
+    }
+}
@@ -0,0 +1,379 @@
+//! RDG Decal Pass
+//!
+//! Projects the decals placed with `Scene::add_decal()` (see
+//! [`myth_scene::Decal`]) onto the opaque surfaces of the `HighFidelity`
+//! path. Each decal draws the back faces of its projector box; the shader
+//! reads the prepass depth under every covered pixel, rebuilds its world
+//! position, and blends the decal texture over the lit color when that
+//! position lies inside the box.
+//!
+//! # RDG Slots
+//!
+//! - `scene_color`: HDR color buffer (read + write, LoadOp::Load)
+//! - `scene_depth`: Single-sample prepass depth (sampled, not attached)
+//!
+//! Per-decal parameters live in a storage buffer owned by the feature,
+//! indexed by the instance index, and re-uploaded every frame that has
+//! decals. Decals sharing a texture share a bind group.
+
+use glam::{Mat4, Vec3, Vec4};
+use rustc_hash::FxHashMap;
+
+use crate::core::gpu::{ResourceState, Tracked};
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    ExecuteContext, ExtractContext, PassNode, PrepareContext, RenderTargetOps, TextureNodeId,
+};
+use crate::pipeline::{
+    ColorTargetKey, FullscreenPipelineKey, MultisampleKey, RenderPipelineId,
+    ShaderCompilationOptions, ShaderSource,
+};
+use myth_resources::gpu_struct;
+use myth_resources::uniforms::WgslStruct;
+
+/// Minimum storage buffer capacity, in decals.
+const MIN_DECAL_CAPACITY: usize = 16;
+
+/// GPU data of one decal, an element of the decal storage buffer.
+#[gpu_struct]
+pub struct DecalUniforms {
+    /// Maps the unit projector box to world space.
+    pub world_matrix: Mat4,
+    /// Maps world positions into the projector box.
+    pub world_matrix_inverse: Mat4,
+    pub color: Vec4,
+    /// World-space projection direction (the node's -Z axis).
+    pub direction: Vec3,
+    pub cos_max_angle: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct DecalPipelineKey {
+    color_format: wgpu::TextureFormat,
+    msaa_samples: u32,
+}
+
+/// Persistent decal renderer.
+pub struct DecalFeature {
+    depth_layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    decal_layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    storage_buffer: Option<Tracked<wgpu::Buffer>>,
+    capacity: usize,
+    /// Texture bind group of each uploaded decal, in instance order.
+    draws: Vec<wgpu::BindGroup>,
+    local_cache: FxHashMap<DecalPipelineKey, RenderPipelineId>,
+    current_pipeline: Option<RenderPipelineId>,
+}
+
+impl Default for DecalFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DecalFeature {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            depth_layout: None,
+            decal_layout: None,
+            storage_buffer: None,
+            capacity: 0,
+            draws: Vec::new(),
+            local_cache: FxHashMap::default(),
+            current_pipeline: None,
+        }
+    }
+
+    fn ensure_layouts(&mut self, device: &wgpu::Device) {
+        if self.depth_layout.is_some() {
+            return;
+        }
+
+        let depth_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Decal Depth Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Depth,
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+        let decal_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Decal Layout"),
+            entries: &[
+                // binding 0: u_decals (storage)
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::VERTEX_FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                // binding 1: t_decal
+                wgpu::BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                // binding 2: s_decal
+                wgpu::BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+        });
+
+        self.depth_layout = Some(Tracked::new(depth_layout));
+        self.decal_layout = Some(Tracked::new(decal_layout));
+    }
+
+    /// Upload this frame's decals and resolve the pipeline.
+    ///
+    /// Called every frame on the `HighFidelity` path; decals whose texture
+    /// is still loading are skipped, and a frame without decals disables the
+    /// pass.
+    pub(crate) fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        global_state_key: (u32, u32),
+        color_format: wgpu::TextureFormat,
+    ) {
+        self.draws.clear();
+        self.current_pipeline = None;
+        if ctx.extracted_scene.decals.is_empty() {
+            return;
+        }
+
+        self.ensure_layouts(ctx.device);
+
+        // Resolve textures first; only decals with a loaded 2D texture are
+        // uploaded, so instance indices stay contiguous.
+        let mut uniforms = Vec::with_capacity(ctx.extracted_scene.decals.len());
+        let mut views = Vec::with_capacity(ctx.extracted_scene.decals.len());
+        for decal in &ctx.extracted_scene.decals {
+            if matches!(
+                ctx.resource_manager
+                    .prepare_texture(ctx.assets, decal.texture),
+                ResourceState::Pending | ResourceState::Unknown
+            ) {
+                continue;
+            }
+            let Some(binding) = ctx.resource_manager.texture_bindings.get(decal.texture) else {
+                continue;
+            };
+            let Some(image) = ctx.resource_manager.gpu_images.get(binding.image_handle) else {
+                continue;
+            };
+            if image.default_view_dimension != wgpu::TextureViewDimension::D2 {
+                continue;
+            }
+            views.push((
+                image.default_view.clone(),
+                binding.view_id,
+                binding.sampler_id,
+            ));
+
+            uniforms.push(DecalUniforms {
+                world_matrix: decal.world_matrix,
+                world_matrix_inverse: decal.world_matrix.inverse(),
+                color: decal.options.color,
+                direction: decal
+                    .world_matrix
+                    .transform_vector3(-Vec3::Z)
+                    .normalize_or_zero(),
+                cos_max_angle: decal.options.max_angle.cos(),
+                ..Default::default()
+            });
+        }
+        if uniforms.is_empty() {
+            return;
+        }
+
+        if self.storage_buffer.is_none() || self.capacity < uniforms.len() {
+            self.capacity = uniforms.len().next_power_of_two().max(MIN_DECAL_CAPACITY);
+            let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("Decal Storage"),
+                size: (self.capacity * size_of::<DecalUniforms>()) as u64,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            });
+            self.storage_buffer = Some(Tracked::new(buffer));
+        }
+        let (Some(layout), Some(buffer)) = (&self.decal_layout, &self.storage_buffer) else {
+            return;
+        };
+        ctx.queue
+            .write_buffer(buffer, 0, bytemuck::cast_slice(&uniforms));
+
+        for (view, view_id, sampler_id) in &views {
+            let bind_group = ctx
+                .build_bind_group(layout, Some("Decal BindGroup"))
+                .bind_tracked_buffer(0, buffer)
+                .bind_texture_view_with_id(1, view, *view_id)
+                .bind_sampler_by_id(2, *sampler_id)
+                .build()
+                .clone();
+            self.draws.push(bind_group);
+        }
+
+        let key = DecalPipelineKey {
+            color_format,
+            msaa_samples: ctx.wgpu_ctx.msaa_samples,
+        };
+        self.current_pipeline = Some(self.get_or_create_pipeline(ctx, key, global_state_key));
+    }
+
+    fn get_or_create_pipeline(
+        &mut self,
+        ctx: &mut ExtractContext,
+        key: DecalPipelineKey,
+        global_state_key: (u32, u32),
+    ) -> RenderPipelineId {
+        if let Some(&pipeline_id) = self.local_cache.get(&key) {
+            return pipeline_id;
+        }
+
+        let gpu_world = ctx
+            .resource_manager
+            .get_global_state(global_state_key.0, global_state_key.1)
+            .expect("Global state must exist");
+
+        let mut options = ShaderCompilationOptions::default();
+        options.add_define(
+            "struct_definitions",
+            DecalUniforms::wgsl_struct_def("DecalUniforms").as_str(),
+        );
+        options.inject_code("binding_code", &gpu_world.binding_wgsl);
+
+        let (shader_module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/decal"),
+            &options,
+        );
+
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Decal Pipeline Layout"),
+                bind_group_layouts: &[
+                    Some(&gpu_world.layout),
+                    self.depth_layout.as_deref(),
+                    self.decal_layout.as_deref(),
+                ],
+                immediate_size: 0,
+            });
+
+        // No depth attachment: the depth buffer is sampled instead, and the
+        // shader keeps one face per pixel by discarding front faces.
+        let fullscreen_key = FullscreenPipelineKey {
+            shader_hash,
+            color_targets: smallvec::smallvec![ColorTargetKey::from(wgpu::ColorTargetState {
+                format: key.color_format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::COLOR,
+            })],
+            depth_stencil: None,
+            multisample: MultisampleKey::from(wgpu::MultisampleState {
+                count: key.msaa_samples,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            }),
+        };
+
+        let pipeline_id = ctx.pipeline_cache.get_or_create_fullscreen(
+            ctx.device,
+            shader_module,
+            &pipeline_layout,
+            &fullscreen_key,
+            "Decal Pipeline",
+        );
+
+        self.local_cache.insert(key, pipeline_id);
+        pipeline_id
+    }
+
+    /// Insert the decal pass into the graph. Returns `scene_color`
+    /// unchanged when there is nothing to draw.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        scene_color: TextureNodeId,
+        scene_depth: TextureNodeId,
+    ) -> TextureNodeId {
+        let (Some(pipeline_id), Some(depth_layout)) = (self.current_pipeline, &self.depth_layout)
+        else {
+            return scene_color;
+        };
+        let pipeline = ctx.pipeline_cache.get_render_pipeline(pipeline_id);
+        ctx.graph.add_pass("Decal_Pass", |builder| {
+            let out_color = builder.mutate_texture(scene_color, "Scene_Color_Decals");
+            builder.read_texture(scene_depth);
+            let node = DecalPassNode {
+                out_color,
+                scene_depth,
+                pipeline,
+                depth_layout,
+                depth_bind_group: None,
+                draws: &self.draws,
+            };
+            (node, out_color)
+        })
+    }
+}
+
+// ─── Decal Pass Node ──────────────────────────────────────────────────────────
+
+/// Ephemeral per-frame decal render pass node.
+pub struct DecalPassNode<'a> {
+    out_color: TextureNodeId,
+    scene_depth: TextureNodeId,
+    pipeline: &'a wgpu::RenderPipeline,
+    depth_layout: &'a Tracked<wgpu::BindGroupLayout>,
+    depth_bind_group: Option<&'a wgpu::BindGroup>,
+    draws: &'a [wgpu::BindGroup],
+}
+
+impl<'a> PassNode<'a> for DecalPassNode<'a> {
+    fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
+        self.depth_bind_group = Some(crate::myth_bind_group!(
+            ctx,
+            self.depth_layout,
+            Some("Decal Depth BindGroup"),
+            [0 => self.scene_depth]
+        ));
+    }
+
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let color_att = ctx.get_color_attachment(self.out_color, RenderTargetOps::Load, None);
+
+        let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Decal Pass"),
+            color_attachments: &[color_att],
+            depth_stencil_attachment: None,
+            ..Default::default()
+        });
+
+        pass.set_pipeline(self.pipeline);
+        pass.set_bind_group(0, ctx.baked_lists.global_bind_group, &[]);
+        pass.set_bind_group(1, self.depth_bind_group.unwrap(), &[]);
+        for (instance, bind_group) in (0u32..).zip(self.draws) {
+            pass.set_bind_group(2, bind_group, &[]);
+            pass.draw(0..36, instance..instance + 1);
+        }
+    }
+}
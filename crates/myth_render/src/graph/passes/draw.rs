@@ -16,7 +16,8 @@
 //! All tracking state lives in local variables (registers), eliminating
 //! the need for a wrapper struct.
 
-use crate::graph::frame::DrawCommand;
+use crate::graph::frame::{DrawCommand, IndirectDraw, MultiDraw};
+use crate::graph::stats::RenderStats;
 
 /// Submit a batch of pre-baked [`DrawCommand`]s through a raw render pass.
 ///
@@ -36,12 +37,24 @@ use crate::graph::frame::DrawCommand;
 ///   [`DrawCommand::bind_group_2`] with its dynamic offset.
 /// * **Group 3** (screen / transient) — set per-command from
 ///   [`DrawCommand::bind_group_3`] when present.
+///
+/// # Merged Draws
+///
+/// A command whose [`DrawCommand::indirect`] is [`IndirectDraw::Multi`]
+/// issues its whole run with one `multi_draw_*indirect(_count)` call; the
+/// [`IndirectDraw::Merged`] commands after it are skipped.
+///
+/// Returns the draw counters of the submitted batch (the culling fields of
+/// [`RenderStats`] are left at zero). Indirect draws are counted as if
+/// they were visible, since GPU culling results never reach the CPU; a
+/// multi-draw counts as one draw call.
 pub fn submit_draw_commands<'pass, 'cmd: 'pass>(
     pass: &mut wgpu::RenderPass<'pass>,
     commands: &'pass [DrawCommand<'cmd>],
-) {
+) -> RenderStats {
+    let mut stats = RenderStats::default();
     if commands.is_empty() {
-        return;
+        return stats;
     }
 
     // ── Bare state registers — zero allocation, zero indirection ────
@@ -54,11 +67,18 @@ pub fn submit_draw_commands<'pass, 'cmd: 'pass>(
     let mut cur_stencil: Option<u32> = None;
 
     for cmd in commands {
+        // ── Merged into the preceding multi-draw ────────────────────
+        if let Some(IndirectDraw::Merged) = cmd.indirect {
+            stats.triangles += u64::from(vertex_count(cmd) / 3) * cmd.instance_range.len() as u64;
+            continue;
+        }
+
         // ── Pipeline ────────────────────────────────────────────────
         let pp = cmd.pipeline as *const wgpu::RenderPipeline;
         if pp != cur_pipeline {
             pass.set_pipeline(cmd.pipeline);
             cur_pipeline = pp;
+            stats.pipeline_switches += 1;
         }
 
         // ── Bind Group 1: Material ──────────────────────────────────
@@ -67,6 +87,7 @@ pub fn submit_draw_commands<'pass, 'cmd: 'pass>(
             if p != cur_bg1 {
                 pass.set_bind_group(1, bg1, &[]);
                 cur_bg1 = p;
+                stats.bind_group_switches += 1;
             }
         }
 
@@ -81,6 +102,7 @@ pub fn submit_draw_commands<'pass, 'cmd: 'pass>(
                 cur_bg2 = p;
             }
             pass.set_bind_group(2, *bg2, &[*offset]);
+            stats.bind_group_switches += 1;
         }
 
         // ── Bind Group 3: Screen / Transient ────────────────────────
@@ -89,6 +111,7 @@ pub fn submit_draw_commands<'pass, 'cmd: 'pass>(
             if p != cur_bg3 {
                 pass.set_bind_group(3, bg3, &[]);
                 cur_bg3 = p;
+                stats.bind_group_switches += 1;
             }
         }
 
@@ -110,15 +133,57 @@ pub fn submit_draw_commands<'pass, 'cmd: 'pass>(
         }
 
         // ── Index Buffer + Draw ─────────────────────────────────────
-        if let Some((buf, fmt, count)) = cmd.index_buffer {
-            let p = buf as *const wgpu::Buffer;
+        if let Some((buf, fmt, indices)) = &cmd.index_buffer {
+            let p = *buf as *const wgpu::Buffer;
             if p != cur_index_b {
-                pass.set_index_buffer(buf.slice(..), fmt);
+                pass.set_index_buffer(buf.slice(..), *fmt);
                 cur_index_b = p;
             }
-            pass.draw_indexed(0..count, 0, cmd.instance_range.clone());
+            match cmd.indirect {
+                Some(IndirectDraw::Single(args, offset)) => {
+                    pass.draw_indexed_indirect(args, offset);
+                }
+                Some(IndirectDraw::Multi(multi)) => multi_draw(pass, &multi, true),
+                _ => pass.draw_indexed(indices.clone(), 0, cmd.instance_range.clone()),
+            }
         } else {
-            pass.draw(cmd.vertex_range.clone(), cmd.instance_range.clone());
+            match cmd.indirect {
+                Some(IndirectDraw::Single(args, offset)) => pass.draw_indirect(args, offset),
+                Some(IndirectDraw::Multi(multi)) => multi_draw(pass, &multi, false),
+                _ => pass.draw(cmd.vertex_range.clone(), cmd.instance_range.clone()),
+            }
+        }
+
+        stats.draw_calls += 1;
+        stats.triangles += u64::from(vertex_count(cmd) / 3) * cmd.instance_range.len() as u64;
+    }
+
+    stats
+}
+
+/// Vertices (or indices) drawn per instance.
+fn vertex_count(cmd: &DrawCommand) -> u32 {
+    match &cmd.index_buffer {
+        Some((_, _, indices)) => indices.len() as u32,
+        None => cmd.vertex_range.len() as u32,
+    }
+}
+
+/// Issue a merged run, reading its draw count from the GPU when available.
+fn multi_draw(pass: &mut wgpu::RenderPass<'_>, multi: &MultiDraw, indexed: bool) {
+    let (args, offset) = multi.args;
+    match (multi.count, indexed) {
+        (Some((count, count_offset)), true) => pass.multi_draw_indexed_indirect_count(
+            args,
+            offset,
+            count,
+            count_offset,
+            multi.max_count,
+        ),
+        (Some((count, count_offset)), false) => {
+            pass.multi_draw_indirect_count(args, offset, count, count_offset, multi.max_count);
         }
+        (None, true) => pass.multi_draw_indexed_indirect(args, offset, multi.max_count),
+        (None, false) => pass.multi_draw_indirect(args, offset, multi.max_count),
     }
 }
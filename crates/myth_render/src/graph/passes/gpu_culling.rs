@@ -0,0 +1,800 @@
+//! GPU Frustum Culling
+//!
+//! Optional replacement for the main camera's CPU frustum test, enabled by
+//! [`RendererSettings::gpu_culling`](crate::settings::RendererSettings::gpu_culling).
+//!
+//! During culling, every render item with finite bounds is kept and its
+//! world-space bounding sphere is appended to
+//! [`RenderLists::cull_spheres`](crate::graph::frame::RenderLists::cull_spheres).
+//! After baking, each main-camera [`DrawCommand`] referencing a sphere gets
+//! a slot in an indirect argument buffer; a compute pass zeroes the instance
+//! count of draws whose sphere lies outside the frustum, and the scene
+//! passes issue `draw_indexed_indirect` / `draw_indirect` instead.
+//!
+//! Draws keep their pipelines, bind groups and sort order. On devices with
+//! `INDIRECT_FIRST_INSTANCE` and vertex storage access, culled draws of the
+//! built-in surface materials are also GPU-driven: their shaders read the
+//! model data from the model buffer bound as storage, at the slot named by
+//! an instance table entry. This pass writes one table entry per draw and
+//! points the draw's first instance at it, so the object is found by
+//! `instance_index` rather than the dynamic offset of its bind group.
+//! Other draws keep reading their model uniform at that offset.
+//!
+//! GPU-driven draws that read nothing else from their object bind group
+//! ([`DrawCommand::mergeable`]) are merged: a run of consecutive such draws
+//! sharing pipeline, material, geometry buffers and stencil reference
+//! becomes one batch. The compute pass compacts the arguments of each
+//! batch's surviving draws to the front of the batch's range and counts
+//! them, and the scene passes issue the whole run with one
+//! `multi_draw_indexed_indirect_count` / `multi_draw_indirect_count` call
+//! that reads the count back on the GPU. Without `MULTI_DRAW_INDIRECT_COUNT`
+//! the run is issued with `multi_draw_indexed_indirect` /
+//! `multi_draw_indirect` over every slot, and the zeroed slots past the
+//! survivors draw nothing. Sorted transparent draws are never merged, as
+//! compaction does not keep their order. Shadow views are still culled on
+//! the CPU.
+//!
+//! Draws starting at a non-zero instance need `INDIRECT_FIRST_INSTANCE`;
+//! without it they stay direct (and unculled), and without it nothing is
+//! GPU-driven or merged, so every culled draw is issued on its own.
+//!
+//! The shader also counts the objects that pass the test. The count is
+//! copied into a ring of readback buffers and mapped at the start of the
+//! next frame, see [`survivors`](GpuCullingFeature::survivors).
+
+use std::cell::Cell;
+
+use bytemuck::{Pod, Zeroable};
+use glam::Vec4;
+
+use crate::core::gpu::ModelBufferAllocator;
+use crate::graph::core::ExtractContext;
+use crate::graph::frame::{BakedRenderLists, DrawCommand, IndirectDraw, MultiDraw};
+use crate::pipeline::{
+    ComputePipelineId, ComputePipelineKey, PipelineCache, ShaderCompilationOptions, ShaderSource,
+};
+
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Indirect argument stride, sized for `DrawIndexedIndirectArgs`. Plain
+/// draws use the first four words.
+const ARGS_STRIDE: u64 = 5 * size_of::<u32>() as u64;
+
+/// Words per draw in a batch's compacted arguments, which multi-draws
+/// expect tightly packed.
+const INDEXED_ARGS_WORDS: u32 = 5;
+const PLAIN_ARGS_WORDS: u32 = 4;
+
+/// Batch index of draws that are not merged.
+const NO_BATCH: u32 = u32::MAX;
+
+/// Minimum buffer capacity, in spheres / draws.
+const MIN_CAPACITY: usize = 256;
+
+/// Number of survivor counts that may be in flight before readback pauses.
+const READBACK_SLOTS: usize = 3;
+
+const COUNTER_SIZE: u64 = size_of::<u32>() as u64;
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct CullParams {
+    planes: [[f32; 4]; 6],
+    draw_count: u32,
+    _pad: [u32; 3],
+}
+
+/// A run of merged draws and its range in the compacted arguments.
+struct Batch {
+    first_word: u32,
+    len: u32,
+}
+
+/// A storage buffer that only grows.
+struct GrowableBuffer {
+    buffer: Option<wgpu::Buffer>,
+    capacity: usize,
+    label: &'static str,
+    usage: wgpu::BufferUsages,
+    element_size: u64,
+}
+
+impl GrowableBuffer {
+    fn new(label: &'static str, usage: wgpu::BufferUsages, element_size: u64) -> Self {
+        Self {
+            buffer: None,
+            capacity: 0,
+            label,
+            usage,
+            element_size,
+        }
+    }
+
+    /// Ensures room for `len` elements; returns `true` if reallocated.
+    fn reserve(&mut self, device: &wgpu::Device, len: usize) -> bool {
+        if self.buffer.is_some() && len <= self.capacity {
+            return false;
+        }
+        self.capacity = len.next_power_of_two().max(MIN_CAPACITY);
+        self.buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some(self.label),
+            size: self.capacity as u64 * self.element_size,
+            usage: self.usage | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }));
+        true
+    }
+
+    fn get(&self) -> &wgpu::Buffer {
+        self.buffer.as_ref().expect("buffer reserved before use")
+    }
+}
+
+struct ReadbackSlot {
+    buffer: wgpu::Buffer,
+    in_flight: bool,
+}
+
+/// Payload sent from the `map_async` callback.
+struct ReadySlot {
+    slot: usize,
+    result: Result<(), wgpu::BufferAsyncError>,
+}
+
+/// Persistent GPU culling state: compute pipeline and per-frame buffers.
+pub struct GpuCullingFeature {
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_id: Option<ComputePipelineId>,
+    params_buffer: wgpu::Buffer,
+    spheres: GrowableBuffer,
+    visible_objects: GrowableBuffer,
+    /// Per draw: object, batch, first word of the batch, words per draw.
+    draw_records: GrowableBuffer,
+    draw_args: GrowableBuffer,
+    compact_args: GrowableBuffer,
+    batch_counts: GrowableBuffer,
+    survivor_counter: wgpu::Buffer,
+    bind_group: Option<wgpu::BindGroup>,
+    planes: [Vec4; 6],
+    sphere_count: u32,
+    draw_count: u32,
+    /// Object of each draw written this frame, in argument order.
+    draw_object_ids: Vec<u32>,
+    /// Batch of each draw written this frame, [`NO_BATCH`] if unmerged.
+    draw_batches: Vec<u32>,
+    batches: Vec<Batch>,
+    /// Words of `compact_args` used this frame.
+    compact_words: u32,
+    first_instance: bool,
+    multi_draw_count: bool,
+    active: bool,
+
+    readback_slots: Vec<ReadbackSlot>,
+    readback_idx: usize,
+    /// Readback slot receiving this frame's count, `None` when skipped.
+    current_readback: Option<usize>,
+    /// Set once this frame's dispatch was recorded.
+    dispatched: Cell<bool>,
+    sender: flume::Sender<ReadySlot>,
+    receiver: flume::Receiver<ReadySlot>,
+    survivors: Option<u32>,
+}
+
+impl GpuCullingFeature {
+    #[must_use]
+    pub fn new(device: &wgpu::Device) -> Self {
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("GPU Culling BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(size_of::<CullParams>() as u64),
+                    },
+                    count: None,
+                },
+                storage(1, true),
+                storage(2, true),
+                storage(3, false),
+                storage(4, false),
+                storage(5, false),
+                storage(6, false),
+                storage(7, false),
+            ],
+        });
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("GPU Culling Params"),
+            size: size_of::<CullParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let survivor_counter = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("GPU Culling Survivor Counter"),
+            size: COUNTER_SIZE,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let readback_slots = (0..READBACK_SLOTS)
+            .map(|_| ReadbackSlot {
+                buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("GPU Culling Survivor Readback"),
+                    size: COUNTER_SIZE,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                in_flight: false,
+            })
+            .collect();
+
+        let (sender, receiver) = flume::bounded(READBACK_SLOTS);
+
+        Self {
+            bind_group_layout,
+            pipeline_id: None,
+            params_buffer,
+            spheres: GrowableBuffer::new(
+                "GPU Culling Spheres",
+                wgpu::BufferUsages::STORAGE,
+                size_of::<Vec4>() as u64,
+            ),
+            visible_objects: GrowableBuffer::new(
+                "GPU Culling Visible Objects",
+                wgpu::BufferUsages::STORAGE,
+                size_of::<u32>() as u64,
+            ),
+            draw_records: GrowableBuffer::new(
+                "GPU Culling Draws",
+                wgpu::BufferUsages::STORAGE,
+                size_of::<[u32; 4]>() as u64,
+            ),
+            draw_args: GrowableBuffer::new(
+                "GPU Culling Indirect Args",
+                wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::INDIRECT
+                    | wgpu::BufferUsages::COPY_SRC,
+                ARGS_STRIDE,
+            ),
+            compact_args: GrowableBuffer::new(
+                "GPU Culling Compacted Args",
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::INDIRECT,
+                size_of::<u32>() as u64,
+            ),
+            batch_counts: GrowableBuffer::new(
+                "GPU Culling Batch Counts",
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::INDIRECT,
+                size_of::<u32>() as u64,
+            ),
+            survivor_counter,
+            bind_group: None,
+            planes: [Vec4::ZERO; 6],
+            sphere_count: 0,
+            draw_count: 0,
+            draw_object_ids: Vec::new(),
+            draw_batches: Vec::new(),
+            batches: Vec::new(),
+            compact_words: 0,
+            first_instance: false,
+            multi_draw_count: false,
+            active: false,
+
+            readback_slots,
+            readback_idx: 0,
+            current_readback: None,
+            dispatched: Cell::new(false),
+            sender,
+            receiver,
+            survivors: None,
+        }
+    }
+
+    /// Number of objects that passed the GPU frustum test, as of the most
+    /// recently read-back frame.
+    ///
+    /// Typically lags the current frame by one or two frames. `None` until
+    /// the first readback completes, or while GPU culling is inactive.
+    #[must_use]
+    pub fn survivors(&self) -> Option<u32> {
+        self.survivors
+    }
+
+    /// Requests the readback of last frame's survivor count and collects
+    /// finished ones.
+    ///
+    /// The map is requested here rather than right after submission because
+    /// the baked draw lists borrow the feature until the frame ends.
+    fn collect_readbacks(&mut self, device: &wgpu::Device) {
+        if let Some(slot_idx) = self.current_readback.take()
+            && self.dispatched.replace(false)
+        {
+            let tx = self.sender.clone();
+            let slot = &mut self.readback_slots[slot_idx];
+            slot.in_flight = true;
+            slot.buffer
+                .slice(..)
+                .map_async(wgpu::MapMode::Read, move |result| {
+                    let _ = tx.send(ReadySlot {
+                        slot: slot_idx,
+                        result,
+                    });
+                });
+            self.readback_idx = (self.readback_idx + 1) % self.readback_slots.len();
+        }
+
+        if self.readback_slots.iter().any(|slot| slot.in_flight) {
+            let _ = device.poll(wgpu::PollType::Poll);
+        }
+        while let Ok(ready) = self.receiver.try_recv() {
+            let slot = &mut self.readback_slots[ready.slot];
+            slot.in_flight = false;
+            if ready.result.is_ok() {
+                {
+                    let mapped = slot.buffer.slice(..).get_mapped_range();
+                    self.survivors = Some(bytemuck::cast_slice::<u8, u32>(&mapped)[0]);
+                }
+                slot.buffer.unmap();
+            }
+        }
+    }
+
+    fn ensure_pipeline(&mut self, ctx: &mut ExtractContext) {
+        if self.pipeline_id.is_some() {
+            return;
+        }
+
+        let compilation_options = wgpu::PipelineCompilationOptions::default();
+
+        let (module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/gpu_culling"),
+            &ShaderCompilationOptions::default(),
+        );
+
+        let layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("GPU Culling Pipeline Layout"),
+                bind_group_layouts: &[Some(&self.bind_group_layout)],
+                immediate_size: 0,
+            });
+
+        self.pipeline_id = Some(ctx.pipeline_cache.get_or_create_compute(
+            ctx.device,
+            module,
+            &layout,
+            &ComputePipelineKey::new(shader_hash).with_compilation_options(&compilation_options),
+            &compilation_options,
+            "GPU Culling Pipeline",
+        ));
+    }
+
+    /// Upload this frame's bounding spheres and camera frustum.
+    ///
+    /// Must run after `cull_and_sort`; an empty sphere list (or GPU culling
+    /// being off) disables the pass.
+    pub fn extract_and_prepare(&mut self, ctx: &mut ExtractContext) {
+        self.collect_readbacks(ctx.device);
+
+        self.draw_count = 0;
+        self.sphere_count = 0;
+        self.active =
+            ctx.wgpu_ctx.gpu_culling_active() && !ctx.render_lists.cull_spheres.is_empty();
+        if !ctx.wgpu_ctx.gpu_culling_active() {
+            self.survivors = None;
+        }
+        if !self.active {
+            return;
+        }
+
+        self.ensure_pipeline(ctx);
+        self.first_instance = ctx.wgpu_ctx.supports_indirect_first_instance();
+        self.multi_draw_count = ctx.wgpu_ctx.supports_multi_draw_indirect_count();
+
+        let spheres = &ctx.render_lists.cull_spheres;
+        let grew_spheres = self.spheres.reserve(ctx.device, spheres.len());
+        let grew_visible = self.visible_objects.reserve(ctx.device, spheres.len());
+        if grew_spheres || grew_visible {
+            self.bind_group = None;
+        }
+        ctx.queue
+            .write_buffer(self.spheres.get(), 0, bytemuck::cast_slice(spheres));
+        self.sphere_count = spheres.len() as u32;
+
+        self.planes = *ctx.render_camera.frustum.planes();
+    }
+
+    /// Write indirect arguments for every GPU-culled draw in `lists`, the
+    /// `instance_table` entries of the GPU-driven ones, and the batches of
+    /// merged draws.
+    ///
+    /// Call [`attach`](Self::attach) afterwards to point the draws at them.
+    pub fn write_draws(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        lists: &BakedRenderLists,
+        instance_table: Option<(&wgpu::Buffer, usize)>,
+    ) {
+        if !self.active {
+            return;
+        }
+
+        let model_stride = ModelBufferAllocator::uniform_stride().get();
+        let mut objects = Vec::new();
+        let mut args = Vec::new();
+        let mut instances: Vec<u32> = Vec::new();
+        // Run of each draw, and the length and argument words of each run.
+        let mut draw_runs: Vec<Option<usize>> = Vec::new();
+        let mut runs: Vec<(u32, u32)> = Vec::new();
+        let first_instance = self.first_instance;
+        for (list, merge) in culled_lists(lists) {
+            let mut previous: Option<&DrawCommand> = None;
+            for cmd in list {
+                if !is_gpu_culled(cmd, first_instance) {
+                    previous = None;
+                    continue;
+                }
+                objects.push(cmd.cull_object.unwrap_or_default());
+                let instance_count = cmd.instance_range.len() as u32;
+                let first = if cmd.gpu_driven {
+                    instances.push((u64::from(cmd.bind_group_2.1) / model_stride) as u32);
+                    instances.len() as u32 - 1
+                } else {
+                    cmd.instance_range.start
+                };
+                args.extend_from_slice(&match &cmd.index_buffer {
+                    Some((_, _, indices)) => [
+                        indices.len() as u32,
+                        instance_count,
+                        indices.start,
+                        0,
+                        first,
+                    ],
+                    None => [
+                        cmd.vertex_range.len() as u32,
+                        instance_count,
+                        cmd.vertex_range.start,
+                        first,
+                        0,
+                    ],
+                });
+
+                let run = if !(merge && cmd.mergeable) {
+                    previous = None;
+                    None
+                } else if previous.is_some_and(|prev| can_merge(prev, cmd)) {
+                    let run = runs.len() - 1;
+                    runs[run].0 += 1;
+                    previous = Some(cmd);
+                    Some(run)
+                } else {
+                    let words = if cmd.index_buffer.is_some() {
+                        INDEXED_ARGS_WORDS
+                    } else {
+                        PLAIN_ARGS_WORDS
+                    };
+                    runs.push((1, words));
+                    previous = Some(cmd);
+                    Some(runs.len() - 1)
+                };
+                draw_runs.push(run);
+            }
+        }
+
+        self.draw_count = objects.len() as u32;
+        self.draw_object_ids.clone_from(&objects);
+        self.draw_batches.clear();
+        self.batches.clear();
+        self.compact_words = 0;
+        if objects.is_empty() {
+            return;
+        }
+
+        // Runs of a single draw are issued on their own.
+        let mut run_batches = vec![NO_BATCH; runs.len()];
+        for (run, &(len, words)) in runs.iter().enumerate() {
+            if len > 1 {
+                run_batches[run] = self.batches.len() as u32;
+                self.batches.push(Batch {
+                    first_word: self.compact_words,
+                    len,
+                });
+                self.compact_words += len * words;
+            }
+        }
+        let mut records: Vec<[u32; 4]> = Vec::with_capacity(objects.len());
+        for (&object, run) in objects.iter().zip(&draw_runs) {
+            let batch = run.map_or(NO_BATCH, |run| run_batches[run]);
+            self.draw_batches.push(batch);
+            records.push(match (run, self.batches.get(batch as usize)) {
+                (Some(run), Some(b)) => [object, batch, b.first_word, runs[*run].1],
+                _ => [object, NO_BATCH, 0, 0],
+            });
+        }
+
+        let slot = self.readback_idx;
+        self.current_readback = (!self.readback_slots[slot].in_flight).then_some(slot);
+
+        let grew_records = self.draw_records.reserve(device, records.len());
+        let grew_args = self.draw_args.reserve(device, records.len());
+        let grew_compact = self
+            .compact_args
+            .reserve(device, self.compact_words as usize);
+        let grew_counts = self.batch_counts.reserve(device, self.batches.len());
+        if grew_records || grew_args || grew_compact || grew_counts {
+            self.bind_group = None;
+        }
+
+        queue.write_buffer(self.draw_records.get(), 0, bytemuck::cast_slice(&records));
+        queue.write_buffer(self.draw_args.get(), 0, bytemuck::cast_slice(&args));
+        if let Some((table, capacity)) = instance_table
+            && !instances.is_empty()
+        {
+            // At most two draws (prepass and colour) share a model slot.
+            debug_assert!(instances.len() <= capacity, "instance table overflow");
+            let len = instances.len().min(capacity);
+            queue.write_buffer(table, 0, bytemuck::cast_slice(&instances[..len]));
+        }
+
+        let params = CullParams {
+            planes: self.planes.map(|plane| plane.to_array()),
+            draw_count: self.draw_count,
+            _pad: [0; 3],
+        };
+        queue.write_buffer(&self.params_buffer, 0, bytemuck::bytes_of(&params));
+
+        if self.bind_group.is_none() {
+            self.bind_group = Some(device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("GPU Culling BG"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: self.params_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: self.spheres.get().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: self.draw_records.get().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: self.draw_args.get().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 4,
+                        resource: self.visible_objects.get().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 5,
+                        resource: self.survivor_counter.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 6,
+                        resource: self.compact_args.get().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 7,
+                        resource: self.batch_counts.get().as_entire_binding(),
+                    },
+                ],
+            }));
+        }
+    }
+
+    /// Reads back the instance count the compute pass left in each draw's
+    /// indirect arguments, paired with the draw's object index.
+    ///
+    /// Blocks until the GPU is idle, so it is only meant for tests and
+    /// debugging. Call it after the frame has been submitted.
+    pub fn read_instance_counts(
+        &self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+    ) -> Vec<(u32, u32)> {
+        if self.draw_count == 0 {
+            return Vec::new();
+        }
+        let size = u64::from(self.draw_count) * ARGS_STRIDE;
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("GPU Culling Args Readback"),
+            size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+            label: Some("GPU Culling Args Readback"),
+        });
+        encoder.copy_buffer_to_buffer(self.draw_args.get(), 0, &staging, 0, size);
+        queue.submit(Some(encoder.finish()));
+
+        let (tx, rx) = flume::bounded(1);
+        staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(result);
+            });
+        if device.poll(wgpu::PollType::wait_indefinitely()).is_err()
+            || !matches!(rx.try_recv(), Ok(Ok(())))
+        {
+            return Vec::new();
+        }
+
+        let counts = {
+            let mapped = staging.slice(..).get_mapped_range();
+            let words = bytemuck::cast_slice::<u8, u32>(&mapped);
+            self.draw_object_ids
+                .iter()
+                .enumerate()
+                .map(|(i, &object)| (object, words[i * ARGS_STRIDE as usize / 4 + 1]))
+                .collect()
+        };
+        staging.unmap();
+        counts
+    }
+
+    /// Returns `true` if this frame has draws to cull.
+    #[must_use]
+    pub fn has_draws(&self) -> bool {
+        self.draw_count > 0
+    }
+
+    /// Switch the draws written by [`write_draws`](Self::write_draws) to
+    /// indirect drawing, merging each batch into its first draw.
+    pub fn attach<'a>(&'a self, lists: &mut BakedRenderLists<'a>) {
+        if self.draw_count == 0 {
+            return;
+        }
+        let args = self.draw_args.get();
+        let compact_args = self.compact_args.get();
+        let batch_counts = self.multi_draw_count.then(|| self.batch_counts.get());
+        let mut offset = 0;
+        let mut previous = NO_BATCH;
+        let draws = lists
+            .opaque
+            .iter_mut()
+            .chain(lists.transparent.iter_mut())
+            .chain(lists.weighted_oit.iter_mut())
+            .chain(lists.prepass.iter_mut());
+        let culled = draws.filter(|cmd| is_gpu_culled(cmd, self.first_instance));
+        for (cmd, &batch) in culled.zip(&self.draw_batches) {
+            cmd.indirect = Some(if batch == NO_BATCH {
+                IndirectDraw::Single(args, offset)
+            } else if batch == previous {
+                IndirectDraw::Merged
+            } else {
+                let run = &self.batches[batch as usize];
+                IndirectDraw::Multi(MultiDraw {
+                    args: (
+                        compact_args,
+                        u64::from(run.first_word) * size_of::<u32>() as u64,
+                    ),
+                    count: batch_counts.map(|counts| (counts, u64::from(batch) * COUNTER_SIZE)),
+                    max_count: run.len,
+                })
+            });
+            previous = batch;
+            offset += ARGS_STRIDE;
+        }
+    }
+
+    /// Record the culling dispatch. Must be encoded before any scene pass.
+    pub fn dispatch(&self, pipeline_cache: &PipelineCache, encoder: &mut wgpu::CommandEncoder) {
+        if self.draw_count == 0 {
+            return;
+        }
+        let (Some(pipeline_id), Some(bind_group)) = (self.pipeline_id, &self.bind_group) else {
+            return;
+        };
+
+        encoder.clear_buffer(
+            self.visible_objects.get(),
+            0,
+            Some(u64::from(self.sphere_count) * size_of::<u32>() as u64),
+        );
+        encoder.clear_buffer(&self.survivor_counter, 0, None);
+        if !self.batches.is_empty() {
+            // Slots past each batch's survivors must draw nothing.
+            encoder.clear_buffer(
+                self.compact_args.get(),
+                0,
+                Some(u64::from(self.compact_words) * size_of::<u32>() as u64),
+            );
+            encoder.clear_buffer(
+                self.batch_counts.get(),
+                0,
+                Some(self.batches.len() as u64 * COUNTER_SIZE),
+            );
+        }
+
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("GPU Culling"),
+                timestamp_writes: None,
+            });
+            pass.set_pipeline(pipeline_cache.get_compute_pipeline(pipeline_id));
+            pass.set_bind_group(0, bind_group, &[]);
+            pass.dispatch_workgroups(self.draw_count.div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+
+        if let Some(slot) = self.current_readback {
+            encoder.copy_buffer_to_buffer(
+                &self.survivor_counter,
+                0,
+                &self.readback_slots[slot].buffer,
+                0,
+                COUNTER_SIZE,
+            );
+            self.dispatched.set(true);
+        }
+    }
+}
+
+/// Main-camera draw lists in the order [`GpuCullingFeature::attach`]
+/// visits them, and whether their draws may be merged. Compaction does not
+/// keep draw order, so sorted transparent draws stay separate.
+fn culled_lists<'b, 'a>(lists: &'b BakedRenderLists<'a>) -> [(&'b [DrawCommand<'a>], bool); 4] {
+    [
+        (&lists.opaque, true),
+        (&lists.transparent, false),
+        (&lists.weighted_oit, true),
+        (&lists.prepass, true),
+    ]
+}
+
+/// Whether `next` can be issued by the same multi-draw as `prev`: it binds
+/// the same pipeline, material, object bind group, vertex and index buffers
+/// and stencil reference. Only the dynamic offset of the object bind group
+/// may differ, since mergeable draws read their object data by instance.
+fn can_merge(prev: &DrawCommand, next: &DrawCommand) -> bool {
+    let same_index_buffer = match (&prev.index_buffer, &next.index_buffer) {
+        (Some((a, a_format, _)), Some((b, b_format, _))) => {
+            std::ptr::eq(*a, *b) && a_format == b_format
+        }
+        (None, None) => true,
+        _ => false,
+    };
+    let same_material = match (prev.bind_group_1, next.bind_group_1) {
+        (Some(a), Some(b)) => std::ptr::eq(a, b),
+        (None, None) => true,
+        _ => false,
+    };
+    std::ptr::eq(prev.pipeline, next.pipeline)
+        && std::ptr::eq(prev.bind_group_2.0, next.bind_group_2.0)
+        && same_material
+        && same_index_buffer
+        && prev.stencil_reference == next.stencil_reference
+        && prev.vertex_buffers.len() == next.vertex_buffers.len()
+        && prev
+            .vertex_buffers
+            .iter()
+            .zip(&next.vertex_buffers)
+            .all(|(a, b)| std::ptr::eq(*a, *b))
+}
+
+/// Indirect draws cannot offset the first instance without
+/// `INDIRECT_FIRST_INSTANCE`, so such draws stay direct (and unculled).
+fn is_gpu_culled(cmd: &DrawCommand, first_instance: bool) -> bool {
+    cmd.cull_object.is_some() && (first_instance || cmd.instance_range.start == 0)
+}
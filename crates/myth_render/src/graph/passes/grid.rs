@@ -0,0 +1,292 @@
+//! RDG Ground Grid Pass
+//!
+//! Draws the infinite ground grid enabled with `Scene::show_grid()` (see
+//! [`myth_scene::GridOptions`]) as a fullscreen triangle. The shader
+//! intersects each pixel's view ray with the grid plane and writes the hit
+//! depth, so the pass runs after the opaque geometry and the skybox and is
+//! depth-tested against them.
+//!
+//! # RDG Slots
+//!
+//! - `scene_color`: HDR/surface color buffer (read + write, LoadOp::Load)
+//! - `scene_depth`: Depth buffer (read, LoadOp::Load)
+//!
+//! The grid parameters live in a small uniform buffer owned by the feature
+//! and are re-uploaded every frame the grid is shown.
+
+use glam::Vec4;
+use rustc_hash::FxHashMap;
+
+use crate::core::gpu::Tracked;
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    ExecuteContext, ExtractContext, PassNode, RenderTargetOps, TextureNodeId,
+};
+use crate::graph::frame::PreparedGridDraw;
+use crate::pipeline::{
+    ColorTargetKey, DepthStencilKey, FullscreenPipelineKey, MultisampleKey, PipelineCache,
+    RenderPipelineId, ShaderCompilationOptions, ShaderSource,
+};
+use myth_resources::gpu_struct;
+use myth_resources::uniforms::WgslStruct;
+use myth_scene::GridOptions;
+
+/// GPU uniform data for the grid shader.
+#[gpu_struct]
+pub struct GridUniforms {
+    pub minor_color: Vec4,
+    pub major_color: Vec4,
+    pub spacing: f32,
+    pub major_spacing: f32,
+    pub height: f32,
+    pub fade_distance: f32,
+    pub line_width: f32,
+}
+
+impl From<&GridOptions> for GridUniforms {
+    fn from(options: &GridOptions) -> Self {
+        Self {
+            minor_color: options.minor_color,
+            major_color: options.major_color,
+            spacing: options.spacing,
+            major_spacing: options.spacing * options.major_every.max(1) as f32,
+            height: options.height,
+            fade_distance: options.fade_distance,
+            line_width: options.line_width,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GridPipelineKey {
+    color_format: wgpu::TextureFormat,
+    depth_format: wgpu::TextureFormat,
+    msaa_samples: u32,
+}
+
+/// Persistent ground grid renderer.
+pub struct GridFeature {
+    layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    uniform_buffer: Option<Tracked<wgpu::Buffer>>,
+    bind_group: Option<wgpu::BindGroup>,
+    local_cache: FxHashMap<GridPipelineKey, RenderPipelineId>,
+    current_pipeline: Option<RenderPipelineId>,
+}
+
+impl Default for GridFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GridFeature {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            layout: None,
+            uniform_buffer: None,
+            bind_group: None,
+            local_cache: FxHashMap::default(),
+            current_pipeline: None,
+        }
+    }
+
+    fn ensure_resources(&mut self, device: &wgpu::Device) {
+        if self.layout.is_some() {
+            return;
+        }
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Grid Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Grid Uniforms"),
+            size: size_of::<GridUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        self.layout = Some(Tracked::new(layout));
+        self.uniform_buffer = Some(Tracked::new(buffer));
+    }
+
+    /// Upload this frame's grid parameters and resolve the pipeline.
+    ///
+    /// Called every frame; a scene without a grid disables the pass.
+    pub(crate) fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        global_state_key: (u32, u32),
+        color_format: wgpu::TextureFormat,
+    ) {
+        let Some(options) = ctx.extracted_scene.grid else {
+            self.current_pipeline = None;
+            return;
+        };
+
+        self.ensure_resources(ctx.device);
+        let (Some(layout), Some(buffer)) = (&self.layout, &self.uniform_buffer) else {
+            return;
+        };
+
+        ctx.queue
+            .write_buffer(buffer, 0, bytemuck::bytes_of(&GridUniforms::from(&options)));
+        let bind_group = ctx
+            .build_bind_group(layout, Some("Grid BindGroup"))
+            .bind_tracked_buffer(0, buffer)
+            .build()
+            .clone();
+        self.bind_group = Some(bind_group);
+
+        let key = GridPipelineKey {
+            color_format,
+            depth_format: ctx.wgpu_ctx.depth_format,
+            msaa_samples: ctx.wgpu_ctx.msaa_samples,
+        };
+        self.current_pipeline = Some(self.get_or_create_pipeline(ctx, key, global_state_key));
+    }
+
+    fn get_or_create_pipeline(
+        &mut self,
+        ctx: &mut ExtractContext,
+        key: GridPipelineKey,
+        global_state_key: (u32, u32),
+    ) -> RenderPipelineId {
+        if let Some(&pipeline_id) = self.local_cache.get(&key) {
+            return pipeline_id;
+        }
+
+        let gpu_world = ctx
+            .resource_manager
+            .get_global_state(global_state_key.0, global_state_key.1)
+            .expect("Global state must exist");
+
+        let mut options = ShaderCompilationOptions::default();
+        options.add_define(
+            "struct_definitions",
+            GridUniforms::wgsl_struct_def("GridUniforms").as_str(),
+        );
+        options.inject_code("binding_code", &gpu_world.binding_wgsl);
+
+        let (shader_module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/grid"),
+            &options,
+        );
+
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Grid Pipeline Layout"),
+                bind_group_layouts: &[Some(&gpu_world.layout), self.layout.as_deref()],
+                immediate_size: 0,
+            });
+
+        // Reverse-Z: the shader writes the plane depth, nearer geometry wins.
+        let fullscreen_key = FullscreenPipelineKey {
+            shader_hash,
+            color_targets: smallvec::smallvec![ColorTargetKey::from(wgpu::ColorTargetState {
+                format: key.color_format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            depth_stencil: Some(DepthStencilKey::from(wgpu::DepthStencilState {
+                format: key.depth_format,
+                depth_write_enabled: Some(false),
+                depth_compare: Some(wgpu::CompareFunction::GreaterEqual),
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            })),
+            multisample: MultisampleKey::from(wgpu::MultisampleState {
+                count: key.msaa_samples,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            }),
+        };
+
+        let pipeline_id = ctx.pipeline_cache.get_or_create_fullscreen(
+            ctx.device,
+            shader_module,
+            &pipeline_layout,
+            &fullscreen_key,
+            "Grid Pipeline",
+        );
+
+        self.local_cache.insert(key, pipeline_id);
+        pipeline_id
+    }
+
+    /// Resolve the draw for embedding in another render pass
+    /// (used by the `BasicForward` path).
+    pub(crate) fn prepared_draw<'a>(
+        &'a self,
+        pipeline_cache: &'a PipelineCache,
+    ) -> Option<PreparedGridDraw<'a>> {
+        Some(PreparedGridDraw {
+            pipeline: pipeline_cache.get_render_pipeline(self.current_pipeline?),
+            bind_group: self.bind_group.as_ref()?,
+        })
+    }
+
+    /// Insert the grid pass into the graph. Returns `scene_color`
+    /// unchanged when the scene shows no grid.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        scene_color: TextureNodeId,
+        scene_depth: TextureNodeId,
+    ) -> TextureNodeId {
+        let Some(draw) = self.prepared_draw(ctx.pipeline_cache) else {
+            return scene_color;
+        };
+        ctx.graph.add_pass("Grid_Pass", |builder| {
+            let out_color = builder.mutate_texture(scene_color, "Scene_Color_Grid");
+            builder.read_texture(scene_depth);
+            let node = GridPassNode {
+                out_color,
+                scene_depth,
+                draw,
+            };
+            (node, out_color)
+        })
+    }
+}
+
+// ─── Grid Pass Node ───────────────────────────────────────────────────────────
+
+/// Ephemeral per-frame ground grid render pass node.
+pub struct GridPassNode<'a> {
+    out_color: TextureNodeId,
+    scene_depth: TextureNodeId,
+    draw: PreparedGridDraw<'a>,
+}
+
+impl<'a> PassNode<'a> for GridPassNode<'a> {
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let color_att = ctx.get_color_attachment(self.out_color, RenderTargetOps::Load, None);
+        let depth_att = ctx.get_depth_stencil_attachment(self.scene_depth, 0.0);
+
+        let pass_desc = wgpu::RenderPassDescriptor {
+            label: Some("Grid Pass"),
+            color_attachments: &[color_att],
+            depth_stencil_attachment: depth_att,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        };
+
+        let mut pass = encoder.begin_render_pass(&pass_desc);
+        self.draw.draw(&mut pass, ctx.baked_lists.global_bind_group);
+    }
+}
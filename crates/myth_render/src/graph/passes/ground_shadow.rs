@@ -0,0 +1,298 @@
+//! RDG Ground Shadow Pass
+//!
+//! Draws the blob shadow enabled with `Scene::set_ground_shadow()` (see
+//! [`myth_scene::GroundShadow`]) as a fullscreen triangle. Like the ground
+//! grid, the shader intersects each pixel's view ray with the shadow plane
+//! and writes the hit depth, so the pass runs after the opaque geometry and
+//! the skybox, is depth-tested against them, and darkens the color buffer
+//! with alpha blending.
+//!
+//! # RDG Slots
+//!
+//! - `scene_color`: HDR color buffer (read + write, LoadOp::Load)
+//! - `scene_depth`: Depth buffer (read, LoadOp::Load)
+//!
+//! The footprint comes from the shadow casters' bounds of the current
+//! frame (see [`ExtractedGroundShadow`]) and is re-uploaded every frame the
+//! shadow is shown.
+
+use glam::Vec2;
+use rustc_hash::FxHashMap;
+
+use crate::core::gpu::Tracked;
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    ExecuteContext, ExtractContext, PassNode, RenderTargetOps, TextureNodeId,
+};
+use crate::graph::extracted::ExtractedGroundShadow;
+use crate::pipeline::{
+    ColorTargetKey, DepthStencilKey, FullscreenPipelineKey, MultisampleKey, RenderPipelineId,
+    ShaderCompilationOptions, ShaderSource,
+};
+use myth_resources::gpu_struct;
+use myth_resources::uniforms::WgslStruct;
+
+/// GPU uniform data for the ground shadow shader.
+#[gpu_struct]
+pub struct GroundShadowUniforms {
+    /// Centre of the footprint on the XZ plane.
+    pub center: Vec2,
+    /// Half size of the footprint along X and Z.
+    pub half_extent: Vec2,
+    pub plane_y: f32,
+    pub softness: f32,
+    pub opacity: f32,
+    /// Height of the casters' lowest point above the plane.
+    pub height: f32,
+    pub fade_distance: f32,
+}
+
+impl From<&ExtractedGroundShadow> for GroundShadowUniforms {
+    fn from(shadow: &ExtractedGroundShadow) -> Self {
+        let settings = &shadow.settings;
+        let center = shadow.bounds.center();
+        let half_extent = shadow.bounds.size() * 0.5;
+        Self {
+            center: Vec2::new(center.x, center.z),
+            half_extent: Vec2::new(half_extent.x, half_extent.z).max(Vec2::splat(1e-3)),
+            plane_y: settings.plane_y,
+            softness: settings.softness.clamp(0.0, 1.0),
+            opacity: settings.opacity.clamp(0.0, 1.0),
+            height: (shadow.bounds.min.y - settings.plane_y).max(0.0),
+            fade_distance: settings.fade_distance.max(1e-4),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct GroundShadowPipelineKey {
+    color_format: wgpu::TextureFormat,
+    depth_format: wgpu::TextureFormat,
+    msaa_samples: u32,
+}
+
+/// Persistent ground shadow renderer.
+pub struct GroundShadowFeature {
+    layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    uniform_buffer: Option<Tracked<wgpu::Buffer>>,
+    bind_group: Option<wgpu::BindGroup>,
+    local_cache: FxHashMap<GroundShadowPipelineKey, RenderPipelineId>,
+    current_pipeline: Option<RenderPipelineId>,
+}
+
+impl Default for GroundShadowFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GroundShadowFeature {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            layout: None,
+            uniform_buffer: None,
+            bind_group: None,
+            local_cache: FxHashMap::default(),
+            current_pipeline: None,
+        }
+    }
+
+    fn ensure_resources(&mut self, device: &wgpu::Device) {
+        if self.layout.is_some() {
+            return;
+        }
+
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Ground Shadow Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: None,
+                },
+                count: None,
+            }],
+        });
+        let buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Ground Shadow Uniforms"),
+            size: size_of::<GroundShadowUniforms>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        self.layout = Some(Tracked::new(layout));
+        self.uniform_buffer = Some(Tracked::new(buffer));
+    }
+
+    /// Upload this frame's shadow footprint and resolve the pipeline.
+    ///
+    /// Called every frame; a scene without a ground shadow, or without
+    /// any caster above its plane, disables the pass.
+    pub(crate) fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        global_state_key: (u32, u32),
+        color_format: wgpu::TextureFormat,
+    ) {
+        let Some(shadow) = ctx.extracted_scene.ground_shadow else {
+            self.current_pipeline = None;
+            return;
+        };
+
+        self.ensure_resources(ctx.device);
+        let (Some(layout), Some(buffer)) = (&self.layout, &self.uniform_buffer) else {
+            return;
+        };
+
+        ctx.queue.write_buffer(
+            buffer,
+            0,
+            bytemuck::bytes_of(&GroundShadowUniforms::from(&shadow)),
+        );
+        let bind_group = ctx
+            .build_bind_group(layout, Some("Ground Shadow BindGroup"))
+            .bind_tracked_buffer(0, buffer)
+            .build()
+            .clone();
+        self.bind_group = Some(bind_group);
+
+        let key = GroundShadowPipelineKey {
+            color_format,
+            depth_format: ctx.wgpu_ctx.depth_format,
+            msaa_samples: ctx.wgpu_ctx.msaa_samples,
+        };
+        self.current_pipeline = Some(self.get_or_create_pipeline(ctx, key, global_state_key));
+    }
+
+    fn get_or_create_pipeline(
+        &mut self,
+        ctx: &mut ExtractContext,
+        key: GroundShadowPipelineKey,
+        global_state_key: (u32, u32),
+    ) -> RenderPipelineId {
+        if let Some(&pipeline_id) = self.local_cache.get(&key) {
+            return pipeline_id;
+        }
+
+        let gpu_world = ctx
+            .resource_manager
+            .get_global_state(global_state_key.0, global_state_key.1)
+            .expect("Global state must exist");
+
+        let mut options = ShaderCompilationOptions::default();
+        options.add_define(
+            "struct_definitions",
+            GroundShadowUniforms::wgsl_struct_def("GroundShadowUniforms").as_str(),
+        );
+        options.inject_code("binding_code", &gpu_world.binding_wgsl);
+
+        let (shader_module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/ground_shadow"),
+            &options,
+        );
+
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Ground Shadow Pipeline Layout"),
+                bind_group_layouts: &[Some(&gpu_world.layout), self.layout.as_deref()],
+                immediate_size: 0,
+            });
+
+        // Reverse-Z: the shader writes the plane depth, nearer geometry wins.
+        let fullscreen_key = FullscreenPipelineKey {
+            shader_hash,
+            color_targets: smallvec::smallvec![ColorTargetKey::from(wgpu::ColorTargetState {
+                format: key.color_format,
+                blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                write_mask: wgpu::ColorWrites::ALL,
+            })],
+            depth_stencil: Some(DepthStencilKey::from(wgpu::DepthStencilState {
+                format: key.depth_format,
+                depth_write_enabled: Some(false),
+                depth_compare: Some(wgpu::CompareFunction::GreaterEqual),
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            })),
+            multisample: MultisampleKey::from(wgpu::MultisampleState {
+                count: key.msaa_samples,
+                mask: !0,
+                alpha_to_coverage_enabled: false,
+            }),
+        };
+
+        let pipeline_id = ctx.pipeline_cache.get_or_create_fullscreen(
+            ctx.device,
+            shader_module,
+            &pipeline_layout,
+            &fullscreen_key,
+            "Ground Shadow Pipeline",
+        );
+
+        self.local_cache.insert(key, pipeline_id);
+        pipeline_id
+    }
+
+    /// Insert the ground shadow pass into the graph. Returns `scene_color`
+    /// unchanged when there is no shadow to draw.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        scene_color: TextureNodeId,
+        scene_depth: TextureNodeId,
+    ) -> TextureNodeId {
+        let (Some(pipeline_id), Some(bind_group)) = (self.current_pipeline, &self.bind_group)
+        else {
+            return scene_color;
+        };
+        let pipeline = ctx.pipeline_cache.get_render_pipeline(pipeline_id);
+        ctx.graph.add_pass("Ground_Shadow_Pass", |builder| {
+            let out_color = builder.mutate_texture(scene_color, "Scene_Color_Ground_Shadow");
+            builder.read_texture(scene_depth);
+            let node = GroundShadowPassNode {
+                out_color,
+                scene_depth,
+                pipeline,
+                bind_group,
+            };
+            (node, out_color)
+        })
+    }
+}
+
+// ─── Ground Shadow Pass Node ──────────────────────────────────────────────────
+
+/// Ephemeral per-frame ground shadow render pass node.
+pub struct GroundShadowPassNode<'a> {
+    out_color: TextureNodeId,
+    scene_depth: TextureNodeId,
+    pipeline: &'a wgpu::RenderPipeline,
+    bind_group: &'a wgpu::BindGroup,
+}
+
+impl<'a> PassNode<'a> for GroundShadowPassNode<'a> {
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let color_att = ctx.get_color_attachment(self.out_color, RenderTargetOps::Load, None);
+        let depth_att = ctx.get_depth_stencil_attachment(self.scene_depth, 0.0);
+
+        let pass_desc = wgpu::RenderPassDescriptor {
+            label: Some("Ground Shadow Pass"),
+            color_attachments: &[color_att],
+            depth_stencil_attachment: depth_att,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        };
+
+        let mut pass = encoder.begin_render_pass(&pass_desc);
+        pass.set_pipeline(self.pipeline);
+        pass.set_bind_group(0, ctx.baked_lists.global_bind_group, &[]);
+        pass.set_bind_group(1, self.bind_group, &[]);
+        pass.draw(0..3, 0..1);
+    }
+}
@@ -1,10 +1,12 @@
 //! PMREM generation pass.
 //!
-//! Reads the persistent scene-owned base cubemap and writes the persistent
-//! scene-owned PMREM cubemap. Parameter buffers are prepared during the
+//! Reads a persistent base cubemap and writes the matching persistent PMREM
+//! cubemap: the scene environment's, or a reflection probe's after its
+//! capture. Parameter buffers are prepared during the
 //! feature extract stage, while mip-specific bind groups are rebuilt in the
 //! RDG prepare phase so they participate in the shared transient binding path.
 
+use myth_core::NodeHandle;
 use rustc_hash::FxHashMap;
 
 use crate::core::gpu::{CommonSampler, Tracked};
@@ -20,6 +22,15 @@ const SCENE_CACHE_TTL: u64 = 120;
 const STATIC_PMREM_SAMPLE_COUNT: u32 = 4096;
 const DYNAMIC_PMREM_SAMPLE_COUNT: u32 = 64;
 
+/// Cubemap pair a PMREM pass prefilters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IblTarget {
+    /// The environment map of a scene.
+    Scene(u32),
+    /// A reflection probe of a scene.
+    ReflectionProbe(u32, NodeHandle),
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum IblPipelineVariant {
     Static,
@@ -40,7 +51,7 @@ pub struct IblComputeFeature {
     pipeline_ids: FxHashMap<IblPipelineVariant, ComputePipelineId>,
     source_layout: Tracked<wgpu::BindGroupLayout>,
     dest_layout: Tracked<wgpu::BindGroupLayout>,
-    scene_states: FxHashMap<u32, IblSceneState>,
+    scene_states: FxHashMap<IblTarget, IblSceneState>,
 }
 
 impl IblComputeFeature {
@@ -107,38 +118,86 @@ impl IblComputeFeature {
     pub fn extract_and_prepare(&mut self, ctx: &mut ExtractContext, scene_id: u32) {
         self.prune_scene_states(ctx.resource_manager.frame_index());
 
+        let target = IblTarget::Scene(scene_id);
         let Some(pipeline_variant) = ctx
             .resource_manager
             .gpu_environment(scene_id)
             .map(|gpu_env| Self::pipeline_variant(gpu_env.source_type))
         else {
-            self.scene_states.remove(&scene_id);
+            self.scene_states.remove(&target);
             return;
         };
 
         self.ensure_pipeline(ctx, pipeline_variant);
 
         let Some(gpu_env) = ctx.resource_manager.gpu_environment(scene_id) else {
-            self.scene_states.remove(&scene_id);
+            self.scene_states.remove(&target);
             return;
         };
 
-        let frame_index = ctx.resource_manager.frame_index();
-        let pmrem_view_ids: Vec<u64> = gpu_env
-            .pmrem_storage_views
-            .iter()
-            .map(Tracked::id)
-            .collect();
-        let needs_rebuild = self.scene_states.get(&scene_id).is_none_or(|state| {
-            state.base_cube_view_id != gpu_env.base_cube_view.id()
-                || state.pmrem_size != gpu_env.pmrem_texture.width()
+        self.prepare_target(
+            ctx.device,
+            ctx.queue,
+            ctx.resource_manager.frame_index(),
+            target,
+            pipeline_variant,
+            gpu_env.base_cube_view.id(),
+            &gpu_env.pmrem_texture,
+            &gpu_env.pmrem_storage_views,
+        );
+    }
+
+    /// Prepares the prefilter of a reflection probe's capture.
+    ///
+    /// Captures are static content, so they use the high sample count.
+    pub fn extract_and_prepare_probe(
+        &mut self,
+        ctx: &mut ExtractContext,
+        scene_id: u32,
+        node: NodeHandle,
+    ) {
+        let target = IblTarget::ReflectionProbe(scene_id, node);
+        self.ensure_pipeline(ctx, IblPipelineVariant::Static);
+
+        let Some(gpu_probe) = ctx.resource_manager.gpu_reflection_probe(scene_id, node) else {
+            self.scene_states.remove(&target);
+            return;
+        };
+
+        self.prepare_target(
+            ctx.device,
+            ctx.queue,
+            ctx.resource_manager.frame_index(),
+            target,
+            IblPipelineVariant::Static,
+            gpu_probe.base_cube_view.id(),
+            &gpu_probe.pmrem_texture,
+            &gpu_probe.pmrem_storage_views,
+        );
+    }
+
+    fn prepare_target(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        frame_index: u64,
+        target: IblTarget,
+        pipeline_variant: IblPipelineVariant,
+        base_cube_view_id: u64,
+        pmrem_texture: &wgpu::Texture,
+        pmrem_storage_views: &[Tracked<wgpu::TextureView>],
+    ) {
+        let pmrem_view_ids: Vec<u64> = pmrem_storage_views.iter().map(Tracked::id).collect();
+        let needs_rebuild = self.scene_states.get(&target).is_none_or(|state| {
+            state.base_cube_view_id != base_cube_view_id
+                || state.pmrem_size != pmrem_texture.width()
                 || state.pmrem_view_ids != pmrem_view_ids
                 || state.pipeline_variant != pipeline_variant
         });
 
         if needs_rebuild {
-            let mip_levels = gpu_env.pmrem_texture.mip_level_count();
-            let pmrem_size = gpu_env.pmrem_texture.width();
+            let mip_levels = pmrem_texture.mip_level_count();
+            let pmrem_size = pmrem_texture.width();
             let roughness_denominator = (mip_levels.saturating_sub(1)).max(1) as f32;
 
             let mut params_buffers = Vec::with_capacity(mip_levels as usize);
@@ -153,23 +212,22 @@ impl IblComputeFeature {
                     0.0,
                 ];
 
-                let buffer = Tracked::new(ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                let buffer = Tracked::new(device.create_buffer(&wgpu::BufferDescriptor {
                     label: Some("IBL Params"),
                     size: std::mem::size_of::<[f32; 4]>() as u64,
                     usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
                     mapped_at_creation: false,
                 }));
-                ctx.queue
-                    .write_buffer(&buffer, 0, bytemuck::cast_slice(&params));
+                queue.write_buffer(&buffer, 0, bytemuck::cast_slice(&params));
 
                 params_buffers.push(buffer);
-                pmrem_views.push(gpu_env.pmrem_mip_view(mip).clone());
+                pmrem_views.push(pmrem_storage_views[mip as usize].clone());
             }
 
             self.scene_states.insert(
-                scene_id,
+                target,
                 IblSceneState {
-                    base_cube_view_id: gpu_env.base_cube_view.id(),
+                    base_cube_view_id,
                     pmrem_view_ids,
                     pmrem_size,
                     pipeline_variant,
@@ -178,7 +236,7 @@ impl IblComputeFeature {
                     last_used_frame: frame_index,
                 },
             );
-        } else if let Some(state) = self.scene_states.get_mut(&scene_id) {
+        } else if let Some(state) = self.scene_states.get_mut(&target) {
             state.last_used_frame = frame_index;
         }
     }
@@ -248,14 +306,14 @@ impl IblComputeFeature {
     pub fn add_to_graph<'a>(
         &'a self,
         ctx: &mut GraphBuilderContext<'a, '_>,
-        scene_id: u32,
+        target: IblTarget,
         base_cube: TextureNodeId,
         pmrem: TextureNodeId,
     ) {
         let state = self
             .scene_states
-            .get(&scene_id)
-            .expect("scene IBL state must be prepared before graph build");
+            .get(&target)
+            .expect("IBL state must be prepared before graph build");
         let pipeline = self
             .pipeline_ids
             .get(&state.pipeline_variant)
@@ -0,0 +1,248 @@
+//! Clustered Lighting
+//!
+//! Divides the view frustum into a grid of clusters, screen-space tiles
+//! split into exponentially spaced depth slices, and records which lights
+//! reach each cluster, enabled by
+//! [`RendererSettings::clustered_lighting`](crate::settings::RendererSettings::clustered_lighting).
+//! Lit materials then evaluate only the lights of the cluster containing the
+//! shaded point instead of looping over every light in the scene.
+//!
+//! Each cluster stores one bit per light in the scene's light cluster
+//! buffer, so the light list never overflows and the lights of a cluster
+//! are visited in the same order as the plain loop. A cluster's bit is set
+//! when the light's sphere of influence touches the cluster's view-space
+//! bounding box; spot lights are tested by their range only. Directional
+//! lights and lights without a range are set in every cluster, and in an
+//! extra cluster after the grid that is used beyond the reach of all other
+//! lights.
+//!
+//! The grid itself is sized on the CPU while preparing the frame (see
+//! `RenderFrame::extract_and_prepare`). Scenes with at most
+//! [`CLUSTERED_LIGHTING_MIN_LIGHTS`] lights are not clustered.
+//!
+//! Like GPU culling, this is not an RDG pass: the cluster buffer belongs to
+//! the global bind group, so the dispatch is recorded ahead of every graph
+//! pass.
+
+use glam::{Mat4, UVec3, UVec4, Vec4};
+
+use myth_resources::gpu_struct;
+use myth_resources::uniforms::{GpuLightStorage, WgslStruct};
+use myth_scene::Scene;
+
+use crate::graph::core::ExtractContext;
+use crate::pipeline::{
+    ComputePipelineId, ComputePipelineKey, PipelineCache, ShaderCompilationOptions, ShaderSource,
+};
+
+/// Light clusters along screen X, screen Y and view depth.
+pub const LIGHT_CLUSTER_GRID: UVec3 = UVec3::new(16, 9, 24);
+
+/// Scenes with at most this many lights keep the plain all-lights loop.
+pub const CLUSTERED_LIGHTING_MIN_LIGHTS: u32 = 8;
+
+const WORKGROUP_SIZE: u32 = 64;
+
+/// Internal GPU uniform for the light cluster shader.
+#[gpu_struct]
+pub struct LightClusterParams {
+    pub view_matrix: Mat4,
+    pub projection_inverse: Mat4,
+    /// Copy of [`EnvironmentUniforms::light_cluster_grid`](myth_resources::uniforms::EnvironmentUniforms::light_cluster_grid).
+    pub grid: UVec4,
+    /// Copy of [`EnvironmentUniforms::light_cluster_depth`](myth_resources::uniforms::EnvironmentUniforms::light_cluster_depth).
+    pub depth: Vec4,
+    pub num_lights: u32,
+}
+
+/// Persistent light clustering state: compute pipeline, parameters and the
+/// bind group over the scene's light buffers.
+pub struct LightClusterFeature {
+    bind_group_layout: wgpu::BindGroupLayout,
+    pipeline_id: Option<ComputePipelineId>,
+    params_buffer: wgpu::Buffer,
+    bind_group: Option<wgpu::BindGroup>,
+    /// Light and cluster GPU buffer ids the bind group was built with.
+    bound_buffers: (u64, u64),
+    /// Cluster words to compute this frame, 0 when lights are not clustered.
+    word_count: u32,
+}
+
+impl LightClusterFeature {
+    #[must_use]
+    pub fn new(device: &wgpu::Device) -> Self {
+        let storage = |binding, read_only| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+
+        let bind_group_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Light Cluster BGL"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(
+                            size_of::<LightClusterParams>() as u64
+                        ),
+                    },
+                    count: None,
+                },
+                storage(1, true),
+                storage(2, false),
+            ],
+        });
+
+        let params_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Light Cluster Params"),
+            size: size_of::<LightClusterParams>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        Self {
+            bind_group_layout,
+            pipeline_id: None,
+            params_buffer,
+            bind_group: None,
+            bound_buffers: (0, 0),
+            word_count: 0,
+        }
+    }
+
+    fn ensure_pipeline(&mut self, ctx: &mut ExtractContext) {
+        if self.pipeline_id.is_some() {
+            return;
+        }
+
+        let mut options = ShaderCompilationOptions::default();
+        options.inject_code(
+            "struct_definitions",
+            format!(
+                "{}\n{}",
+                GpuLightStorage::wgsl_struct_def("Light"),
+                LightClusterParams::wgsl_struct_def("LightClusterParams"),
+            ),
+        );
+        let compilation_options = wgpu::PipelineCompilationOptions::default();
+
+        let (module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/light_cluster"),
+            &options,
+        );
+
+        let layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Light Cluster Pipeline Layout"),
+                bind_group_layouts: &[Some(&self.bind_group_layout)],
+                immediate_size: 0,
+            });
+
+        self.pipeline_id = Some(ctx.pipeline_cache.get_or_create_compute(
+            ctx.device,
+            module,
+            &layout,
+            &ComputePipelineKey::new(shader_hash).with_compilation_options(&compilation_options),
+            &compilation_options,
+            "Light Cluster Pipeline",
+        ));
+    }
+
+    /// Upload this frame's cluster parameters for `scene`.
+    ///
+    /// Must run after the frame's global resources are prepared, which
+    /// sizes the grid; a cleared grid disables the pass.
+    pub fn extract_and_prepare(&mut self, ctx: &mut ExtractContext, scene: &Scene) {
+        let uniforms = *scene.uniforms_buffer.read();
+        let grid = uniforms.light_cluster_grid;
+        self.word_count = 0;
+        if grid.w == 0 {
+            return;
+        }
+
+        self.ensure_pipeline(ctx);
+
+        let (lights_handle, _) = ctx
+            .resource_manager
+            .ensure_buffer(&scene.light_storage_buffer);
+        let (clusters_handle, _) = ctx
+            .resource_manager
+            .ensure_buffer(&scene.light_cluster_buffer);
+        let buffers = &ctx.resource_manager.gpu_buffers;
+        let (Some(lights), Some(clusters)) =
+            (buffers.get(lights_handle), buffers.get(clusters_handle))
+        else {
+            return;
+        };
+
+        if self.bind_group.is_none() || self.bound_buffers != (lights.id, clusters.id) {
+            self.bind_group = Some(ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: Some("Light Cluster BG"),
+                layout: &self.bind_group_layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: self.params_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: lights.buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: clusters.buffer.as_entire_binding(),
+                    },
+                ],
+            }));
+            self.bound_buffers = (lights.id, clusters.id);
+        }
+
+        let params = LightClusterParams {
+            view_matrix: ctx.render_camera.view_matrix,
+            projection_inverse: ctx.render_camera.projection_matrix.inverse(),
+            grid,
+            depth: uniforms.light_cluster_depth,
+            num_lights: uniforms.num_lights,
+            ..Default::default()
+        };
+        ctx.queue
+            .write_buffer(&self.params_buffer, 0, bytemuck::bytes_of(&params));
+
+        self.word_count = (grid.x * grid.y * grid.z + 1) * grid.w;
+    }
+
+    /// Record the cluster assignment. Must be encoded before any scene pass.
+    pub fn dispatch(&self, pipeline_cache: &PipelineCache, encoder: &mut wgpu::CommandEncoder) {
+        if self.word_count == 0 {
+            return;
+        }
+        let (Some(pipeline_id), Some(bind_group)) = (self.pipeline_id, &self.bind_group) else {
+            return;
+        };
+
+        let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+            label: Some("Light Clusters"),
+            timestamp_writes: None,
+        });
+        pass.set_pipeline(pipeline_cache.get_compute_pipeline(pipeline_id));
+        pass.set_bind_group(0, bind_group, &[]);
+        pass.dispatch_workgroups(self.word_count.div_ceil(WORKGROUP_SIZE), 1, 1);
+    }
+
+    /// Returns `true` if this frame's lights are clustered.
+    #[must_use]
+    pub fn is_active(&self) -> bool {
+        self.word_count > 0
+    }
+}
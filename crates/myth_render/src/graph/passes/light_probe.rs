@@ -0,0 +1,237 @@
+//! RDG Light Probe Feature
+//!
+//! Runs in the frame capturing the last face of a light probe. A single
+//! compute workgroup projects the capture cubemap onto nine spherical
+//! harmonic coefficients in a scratch buffer, which is then copied into the
+//! probe's entry of the scene's light probe buffer.
+
+use crate::core::gpu::{CommonSampler, Tracked};
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    BufferDesc, BufferNodeId, ExecuteContext, ExtractContext, PassNode, PrepareContext,
+    TextureNodeId,
+};
+use crate::pipeline::{
+    ComputePipelineId, ComputePipelineKey, ShaderCompilationOptions, ShaderSource,
+};
+use myth_resources::buffer::CpuBuffer;
+use myth_resources::uniforms::GpuLightProbe;
+
+const PROBE_SIZE: u64 = std::mem::size_of::<GpuLightProbe>() as u64;
+
+/// Long-lived light probe projection feature.
+///
+/// Owns the pipeline, layout and the scratch coefficient buffer.
+pub struct LightProbeFeature {
+    pipeline: Option<ComputePipelineId>,
+    layout: Option<Tracked<wgpu::BindGroupLayout>>,
+    scratch_buffer: Option<Tracked<wgpu::Buffer>>,
+
+    /// Scene light probe buffer receiving the coefficients.
+    probe_buffer: Option<wgpu::Buffer>,
+    /// Byte offset of the captured probe in `probe_buffer`.
+    probe_offset: u64,
+}
+
+impl Default for LightProbeFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LightProbeFeature {
+    /// Creates the feature. GPU resources are created on first use.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            pipeline: None,
+            layout: None,
+            scratch_buffer: None,
+            probe_buffer: None,
+            probe_offset: 0,
+        }
+    }
+
+    fn ensure_resources(&mut self, ctx: &mut ExtractContext) {
+        if self.pipeline.is_some() {
+            return;
+        }
+
+        let layout = Tracked::new(ctx.device.create_bind_group_layout(
+            &wgpu::BindGroupLayoutDescriptor {
+                label: Some("Light Probe SH BGL"),
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Texture {
+                            sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                            view_dimension: wgpu::TextureViewDimension::Cube,
+                            multisampled: false,
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            },
+        ));
+
+        let opts = ShaderCompilationOptions::default();
+        let compilation_options = wgpu::PipelineCompilationOptions::default();
+        let (module, hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/light_probe_sh"),
+            &opts,
+        );
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Light Probe SH Pipeline Layout"),
+                bind_group_layouts: &[Some(&*layout)],
+                immediate_size: 0,
+            });
+        self.pipeline = Some(ctx.pipeline_cache.get_or_create_compute(
+            ctx.device,
+            module,
+            &pipeline_layout,
+            &ComputePipelineKey::new(hash).with_compilation_options(&compilation_options),
+            &compilation_options,
+            "Light Probe SH Pipeline",
+        ));
+        self.layout = Some(layout);
+
+        self.scratch_buffer = Some(Tracked::new(ctx.device.create_buffer(
+            &wgpu::BufferDescriptor {
+                label: Some("Light Probe SH Scratch"),
+                size: PROBE_SIZE,
+                usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            },
+        )));
+    }
+
+    /// Pre-RDG preparation: creates resources and resolves the GPU buffer
+    /// and offset receiving the coefficients of probe `probe_index`.
+    pub fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        light_probes: &CpuBuffer<Vec<GpuLightProbe>>,
+        probe_index: u32,
+    ) {
+        self.ensure_resources(ctx);
+
+        let (handle, _) = ctx.resource_manager.ensure_buffer(light_probes);
+        self.probe_buffer = ctx
+            .resource_manager
+            .gpu_buffers
+            .get(handle)
+            .map(|gpu_buf| gpu_buf.buffer.clone())
+            .filter(|buffer| buffer.size() >= (u64::from(probe_index) + 1) * PROBE_SIZE);
+        self.probe_offset = u64::from(probe_index) * PROBE_SIZE;
+    }
+
+    /// Adds the projection of `capture_cube` into the prepared probe.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        capture_cube: TextureNodeId,
+    ) -> BufferNodeId {
+        let pipeline = self
+            .pipeline
+            .map(|id| ctx.pipeline_cache.get_compute_pipeline(id))
+            .expect("LightProbeFeature not prepared");
+        let layout = self.layout.as_ref().unwrap();
+        let scratch_buffer = self.scratch_buffer.as_ref().unwrap();
+
+        let scratch = ctx.graph.import_external_buffer(
+            "LightProbe_SH_Scratch",
+            BufferDesc::new(
+                PROBE_SIZE,
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            ),
+            scratch_buffer,
+        );
+
+        ctx.graph.add_pass("LightProbe_SH", |builder| {
+            builder.read_texture(capture_cube);
+            let output = builder.write_buffer(scratch);
+            // The coefficient copy targets a buffer the graph does not track.
+            builder.mark_side_effect();
+            let node = LightProbeShPassNode {
+                capture_cube,
+                scratch: output,
+                scratch_buffer,
+                probe_buffer: self.probe_buffer.as_ref(),
+                probe_offset: self.probe_offset,
+                pipeline,
+                layout,
+                bind_group: None,
+            };
+            (node, output)
+        })
+    }
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// PassNode (ephemeral, created per frame)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+struct LightProbeShPassNode<'a> {
+    capture_cube: TextureNodeId,
+    scratch: BufferNodeId,
+    scratch_buffer: &'a Tracked<wgpu::Buffer>,
+    probe_buffer: Option<&'a wgpu::Buffer>,
+    probe_offset: u64,
+    pipeline: &'a wgpu::ComputePipeline,
+    layout: &'a Tracked<wgpu::BindGroupLayout>,
+    bind_group: Option<&'a wgpu::BindGroup>,
+}
+
+impl<'a> PassNode<'a> for LightProbeShPassNode<'a> {
+    fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
+        self.bind_group = Some(
+            ctx.build_bind_group(self.layout, Some("Light Probe SH BG"))
+                .bind_texture(0, self.capture_cube)
+                .bind_common_sampler(1, CommonSampler::LinearClamp)
+                .bind_buffer(2, self.scratch)
+                .build(),
+        );
+    }
+
+    fn execute(&self, _ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        {
+            let mut cpass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("Light Probe SH"),
+                timestamp_writes: None,
+            });
+            cpass.set_pipeline(self.pipeline);
+            cpass.set_bind_group(0, self.bind_group.expect("light probe SH BG missing"), &[]);
+            cpass.dispatch_workgroups(1, 1, 1);
+        }
+
+        if let Some(probe_buffer) = self.probe_buffer {
+            encoder.copy_buffer_to_buffer(
+                self.scratch_buffer,
+                0,
+                probe_buffer,
+                self.probe_offset,
+                PROBE_SIZE,
+            );
+        }
+    }
+}
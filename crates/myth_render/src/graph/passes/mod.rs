@@ -1,18 +1,29 @@
 pub mod atmosphere;
+pub mod auto_exposure;
 pub mod bloom;
 pub mod cas;
 pub mod compute;
+pub mod debug_lines;
 #[cfg(feature = "debug_view")]
 pub mod debug_view;
+pub mod decal;
 pub mod draw;
 pub mod equirect_to_cube;
 pub mod fxaa;
 #[cfg(feature = "3dgs")]
 pub mod gaussian_splatting;
+pub mod gpu_culling;
+pub mod grid;
+pub mod ground_shadow;
 pub mod ibl_compute;
+pub mod light_cluster;
+pub mod light_probe;
 pub mod msaa_sync;
+pub mod occlusion;
+pub mod oit;
 pub mod opaque;
 pub mod prepass;
+pub mod reflection_probe;
 pub mod shadow;
 pub mod simple_forward;
 pub mod skybox;
@@ -25,19 +36,30 @@ pub mod transparent;
 pub mod utils;
 
 pub use atmosphere::AtmosphereFeature;
+pub use auto_exposure::AutoExposureFeature;
 pub use bloom::BloomFeature;
 pub use cas::CasFeature;
 pub use compute::BrdfLutFeature;
+pub use debug_lines::DebugLinesFeature;
 #[cfg(feature = "debug_view")]
 pub use debug_view::DebugViewFeature;
+pub use decal::DecalFeature;
 pub use equirect_to_cube::EquirectToCubeFeature;
 pub use fxaa::FxaaFeature;
 #[cfg(feature = "3dgs")]
 pub use gaussian_splatting::GaussianSplattingFeature;
-pub use ibl_compute::IblComputeFeature;
+pub use gpu_culling::GpuCullingFeature;
+pub use grid::GridFeature;
+pub use ground_shadow::GroundShadowFeature;
+pub use ibl_compute::{IblComputeFeature, IblTarget};
+pub use light_cluster::LightClusterFeature;
+pub use light_probe::LightProbeFeature;
 pub use msaa_sync::MsaaSyncFeature;
+pub use occlusion::OcclusionCullingFeature;
+pub use oit::WeightedOitFeature;
 pub use opaque::OpaqueFeature;
 pub use prepass::PrepassFeature;
+pub use reflection_probe::{ProbeCaptureKind, ProbeCaptureTarget};
 pub use shadow::ShadowFeature;
 pub use simple_forward::SimpleForwardFeature;
 pub use skybox::SkyboxFeature;
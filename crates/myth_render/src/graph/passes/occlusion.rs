@@ -0,0 +1,492 @@
+//! Hardware Occlusion Culling
+//!
+//! Enabled per scene with
+//! [`Scene::enable_occlusion_culling`](myth_scene::Scene::enable_occlusion_culling).
+//!
+//! During culling, every node that survives the main camera's frustum test
+//! is appended to
+//! [`RenderLists::occlusion_candidates`](crate::graph::frame::RenderLists::occlusion_candidates).
+//! After the opaque pass, this feature draws each candidate's world-space
+//! bounding box against the scene depth (no depth or colour writes), wrapped
+//! in an occlusion query. The query results are resolved into a ring of
+//! readback buffers and mapped asynchronously at the start of the next
+//! frame, like [`GpuProfiler`](crate::core::GpuProfiler) readbacks.
+//!
+//! # Latency
+//!
+//! Results are applied by the **next** frame's culling: nodes whose box
+//! produced zero samples are skipped (but still queried, so they reappear as
+//! soon as a query sees them again). An object stepping out from behind an
+//! occluder is therefore missing for one frame — more if the readback is
+//! late. Until the first results arrive, everything is drawn.
+//!
+//! # Query Set Capacity
+//!
+//! The query set, instance buffer and readback slots grow with the number of
+//! candidates (up to [`MAX_QUERIES`]; extra candidates are never culled).
+//! Growing recreates them all, and results still in flight for the old
+//! buffers are dropped. Results are also discarded when the surface is
+//! resized, since they were measured against the old depth buffer.
+//!
+//! # RDG Slots
+//!
+//! - `scene_depth`: Depth buffer after the opaque pass (read, LoadOp::Load)
+
+use std::cell::Cell;
+
+use bytemuck::{Pod, Zeroable};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{ExecuteContext, ExtractContext, PassNode, TextureNodeId};
+use crate::pipeline::{
+    DepthStencilKey, RenderPipelineId, ShaderCompilationOptions, ShaderSource,
+    SimpleGeometryPipelineKey,
+};
+use myth_scene::NodeHandle;
+
+/// Number of frames that may be in flight before queries are skipped.
+const SLOT_COUNT: usize = 3;
+
+/// Upper bound on queries per frame (the wgpu query set limit).
+pub const MAX_QUERIES: usize = wgpu::QUERY_SET_MAX_QUERIES as usize;
+
+/// Minimum query capacity.
+const MIN_CAPACITY: usize = 256;
+
+const RESULT_SIZE: u64 = size_of::<u64>() as u64;
+
+/// Vertices per box (12 triangles, generated in the shader).
+const BOX_VERTEX_COUNT: u32 = 36;
+
+/// Stable layout id for the per-instance box format (min + max corner).
+const BOX_LAYOUT_ID: u64 = 0x0cc1_b0c5;
+
+const BOX_ATTRIBUTES: [wgpu::VertexAttribute; 2] =
+    wgpu::vertex_attr_array![0 => Float32x3, 1 => Float32x3];
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct BoxInstance {
+    min: [f32; 3],
+    max: [f32; 3],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct OcclusionPipelineKey {
+    depth_format: wgpu::TextureFormat,
+    msaa_samples: u32,
+}
+
+struct QuerySlot {
+    resolve_buffer: wgpu::Buffer,
+    readback_buffer: wgpu::Buffer,
+    nodes: Vec<NodeHandle>,
+    in_flight: bool,
+}
+
+/// Payload sent from the `map_async` callback.
+struct ReadySlot {
+    slot: usize,
+    generation: u32,
+    result: Result<(), wgpu::BufferAsyncError>,
+}
+
+/// Persistent occlusion query state: pipeline, query set and readback ring.
+pub struct OcclusionCullingFeature {
+    query_set: Option<wgpu::QuerySet>,
+    instance_buffer: Option<wgpu::Buffer>,
+    capacity: usize,
+    slots: Vec<QuerySlot>,
+    /// Bumped whenever the slots are recreated or results invalidated, so
+    /// stale readbacks are ignored.
+    generation: u32,
+    write_idx: usize,
+    /// Slot recording the current frame, `None` when no queries are issued.
+    current: Option<usize>,
+    query_count: u32,
+    /// Set once the current slot's queries were resolved into the frame's
+    /// encoder, i.e. the frame was actually rendered.
+    resolved: Cell<bool>,
+
+    local_cache: FxHashMap<OcclusionPipelineKey, RenderPipelineId>,
+    current_pipeline: Option<RenderPipelineId>,
+
+    sender: flume::Sender<ReadySlot>,
+    receiver: flume::Receiver<ReadySlot>,
+
+    occluded: FxHashSet<NodeHandle>,
+}
+
+impl Default for OcclusionCullingFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OcclusionCullingFeature {
+    #[must_use]
+    pub fn new() -> Self {
+        let (sender, receiver) = flume::bounded(SLOT_COUNT);
+        Self {
+            query_set: None,
+            instance_buffer: None,
+            capacity: 0,
+            slots: Vec::new(),
+            generation: 0,
+            write_idx: 0,
+            current: None,
+            query_count: 0,
+            resolved: Cell::new(false),
+            local_cache: FxHashMap::default(),
+            current_pipeline: None,
+            sender,
+            receiver,
+            occluded: FxHashSet::default(),
+        }
+    }
+
+    /// Nodes whose bounding box was fully hidden in the latest results.
+    #[must_use]
+    pub fn occluded_nodes(&self) -> &FxHashSet<NodeHandle> {
+        &self.occluded
+    }
+
+    /// Requests the readback of last frame's queries and collects finished
+    /// ones. Call before culling.
+    ///
+    /// The map is requested here rather than right after submission because
+    /// the render graph borrows the feature until the frame ends.
+    pub fn begin_frame(&mut self, device: &wgpu::Device) {
+        if let Some(slot_idx) = self.current.take()
+            && self.resolved.replace(false)
+        {
+            self.request_map(slot_idx);
+        }
+
+        if self.slots.iter().any(|slot| slot.in_flight) {
+            let _ = device.poll(wgpu::PollType::Poll);
+        }
+        while let Ok(ready) = self.receiver.try_recv() {
+            if ready.generation == self.generation {
+                self.collect_slot(ready.slot, ready.result.is_ok());
+            }
+        }
+    }
+
+    /// Forgets all results, so every node is drawn until new ones arrive.
+    pub fn invalidate(&mut self) {
+        self.occluded.clear();
+        self.generation = self.generation.wrapping_add(1);
+        for slot in &mut self.slots {
+            if slot.in_flight {
+                slot.readback_buffer.unmap();
+                slot.in_flight = false;
+            }
+        }
+    }
+
+    /// Upload this frame's candidate boxes and resolve the pipeline.
+    ///
+    /// Must run after `cull_and_sort`; an empty candidate list (or occlusion
+    /// culling being off) disables the pass.
+    pub(crate) fn extract_and_prepare(
+        &mut self,
+        ctx: &mut ExtractContext,
+        global_state_key: (u32, u32),
+    ) {
+        self.current = None;
+        self.query_count = 0;
+        self.resolved.set(false);
+
+        let enabled = ctx.extracted_scene.occlusion_culling
+            && ctx.wgpu_ctx.render_path.supports_post_processing();
+        if !enabled {
+            if !self.occluded.is_empty() || self.slots.iter().any(|slot| slot.in_flight) {
+                self.invalidate();
+            }
+            return;
+        }
+
+        let candidates = &ctx.render_lists.occlusion_candidates;
+        let count = candidates.len().min(MAX_QUERIES);
+        if count == 0 {
+            return;
+        }
+
+        self.reserve(ctx.device, count);
+        if self.slots[self.write_idx].in_flight {
+            return;
+        }
+
+        let instances: Vec<BoxInstance> = candidates[..count]
+            .iter()
+            .map(|(_, aabb)| BoxInstance {
+                min: aabb.min.to_array(),
+                max: aabb.max.to_array(),
+            })
+            .collect();
+        if let Some(buffer) = &self.instance_buffer {
+            ctx.queue
+                .write_buffer(buffer, 0, bytemuck::cast_slice(&instances));
+        }
+
+        let slot = &mut self.slots[self.write_idx];
+        slot.nodes.clear();
+        slot.nodes
+            .extend(candidates[..count].iter().map(|(node, _)| *node));
+
+        let key = OcclusionPipelineKey {
+            depth_format: ctx.wgpu_ctx.depth_format,
+            msaa_samples: ctx.wgpu_ctx.msaa_samples,
+        };
+        self.current_pipeline = Some(self.get_or_create_pipeline(ctx, key, global_state_key));
+        self.current = Some(self.write_idx);
+        self.query_count = count as u32;
+    }
+
+    /// Ensures room for `count` queries, recreating every buffer on growth.
+    fn reserve(&mut self, device: &wgpu::Device, count: usize) {
+        if self.query_set.is_some() && count <= self.capacity {
+            return;
+        }
+        let capacity = count.next_power_of_two().clamp(MIN_CAPACITY, MAX_QUERIES);
+
+        // Pending maps of the old readback buffers fail once they are
+        // dropped; the generation bump discards their callbacks.
+        self.generation = self.generation.wrapping_add(1);
+
+        self.query_set = Some(device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("Occlusion QuerySet"),
+            ty: wgpu::QueryType::Occlusion,
+            count: capacity as u32,
+        }));
+        self.instance_buffer = Some(device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("Occlusion Box Instances"),
+            size: (capacity * size_of::<BoxInstance>()) as u64,
+            usage: wgpu::BufferUsages::VERTEX | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }));
+
+        let size = capacity as u64 * RESULT_SIZE;
+        self.slots = (0..SLOT_COUNT)
+            .map(|_| QuerySlot {
+                resolve_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Occlusion Resolve"),
+                    size,
+                    usage: wgpu::BufferUsages::QUERY_RESOLVE | wgpu::BufferUsages::COPY_SRC,
+                    mapped_at_creation: false,
+                }),
+                readback_buffer: device.create_buffer(&wgpu::BufferDescriptor {
+                    label: Some("Occlusion Readback"),
+                    size,
+                    usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+                    mapped_at_creation: false,
+                }),
+                nodes: Vec::new(),
+                in_flight: false,
+            })
+            .collect();
+        self.write_idx = 0;
+        self.capacity = capacity;
+    }
+
+    fn get_or_create_pipeline(
+        &mut self,
+        ctx: &mut ExtractContext,
+        key: OcclusionPipelineKey,
+        global_state_key: (u32, u32),
+    ) -> RenderPipelineId {
+        if let Some(&pipeline_id) = self.local_cache.get(&key) {
+            return pipeline_id;
+        }
+
+        let gpu_world = ctx
+            .resource_manager
+            .get_global_state(global_state_key.0, global_state_key.1)
+            .expect("Global state must exist");
+
+        let mut options = ShaderCompilationOptions::default();
+        options.inject_code("binding_code", &gpu_world.binding_wgsl);
+
+        let (shader_module, shader_hash) = ctx.shader_manager.get_or_compile(
+            ctx.device,
+            ShaderSource::File("entry/utility/occlusion_query"),
+            &options,
+        );
+
+        let pipeline_layout = ctx
+            .device
+            .create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Occlusion Query Pipeline Layout"),
+                bind_group_layouts: &[Some(&gpu_world.layout)],
+                immediate_size: 0,
+            });
+
+        // Reverse-Z: box fragments in front of the stored depth pass. Both
+        // faces are drawn so a box clipped by the near plane still counts.
+        let pipeline_key = SimpleGeometryPipelineKey {
+            shader_hash,
+            vertex_layout_id: BOX_LAYOUT_ID,
+            color_targets: smallvec::smallvec![],
+            depth_stencil: DepthStencilKey::from(wgpu::DepthStencilState {
+                format: key.depth_format,
+                depth_write_enabled: Some(false),
+                depth_compare: Some(wgpu::CompareFunction::GreaterEqual),
+                stencil: wgpu::StencilState::default(),
+                bias: wgpu::DepthBiasState::default(),
+            }),
+            topology: wgpu::PrimitiveTopology::TriangleList,
+            cull_mode: None,
+            front_face: wgpu::FrontFace::Ccw,
+            sample_count: key.msaa_samples,
+        };
+
+        let vertex_layout = wgpu::VertexBufferLayout {
+            array_stride: size_of::<BoxInstance>() as u64,
+            step_mode: wgpu::VertexStepMode::Instance,
+            attributes: &BOX_ATTRIBUTES,
+        };
+
+        let pipeline_id = ctx.pipeline_cache.get_or_create_simple_geometry(
+            ctx.device,
+            shader_module,
+            &pipeline_layout,
+            &pipeline_key,
+            "Occlusion Query Pipeline",
+            &[vertex_layout],
+        );
+
+        self.local_cache.insert(key, pipeline_id);
+        pipeline_id
+    }
+
+    /// Insert the occlusion query pass into the graph, reading the depth
+    /// left by the opaque pass.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        scene_depth: TextureNodeId,
+    ) {
+        if self.current.is_none() {
+            return;
+        }
+        let (Some(pipeline_id), Some(query_set), Some(instance_buffer)) = (
+            self.current_pipeline,
+            self.query_set.as_ref(),
+            self.instance_buffer.as_ref(),
+        ) else {
+            return;
+        };
+        let pipeline = ctx.pipeline_cache.get_render_pipeline(pipeline_id);
+        let query_count = self.query_count;
+
+        ctx.graph.add_pass("Occlusion_Query_Pass", |builder| {
+            builder.read_texture(scene_depth);
+            builder.mark_side_effect();
+            let node = OcclusionQueryPassNode {
+                scene_depth,
+                pipeline,
+                query_set,
+                instance_buffer,
+                query_count,
+            };
+            (node, ())
+        });
+    }
+
+    /// Resolves this frame's queries into the slot's readback buffer.
+    pub fn resolve(&self, encoder: &mut wgpu::CommandEncoder) {
+        let (Some(slot), Some(query_set)) = (self.current, &self.query_set) else {
+            return;
+        };
+        self.resolved.set(true);
+        let slot = &self.slots[slot];
+        encoder.resolve_query_set(query_set, 0..self.query_count, &slot.resolve_buffer, 0);
+        encoder.copy_buffer_to_buffer(
+            &slot.resolve_buffer,
+            0,
+            &slot.readback_buffer,
+            0,
+            u64::from(self.query_count) * RESULT_SIZE,
+        );
+    }
+
+    fn request_map(&mut self, slot_idx: usize) {
+        let size = u64::from(self.query_count) * RESULT_SIZE;
+        let generation = self.generation;
+        let tx = self.sender.clone();
+        let slot = &mut self.slots[slot_idx];
+        slot.in_flight = true;
+        slot.readback_buffer
+            .slice(..size)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(ReadySlot {
+                    slot: slot_idx,
+                    generation,
+                    result,
+                });
+            });
+        self.write_idx = (self.write_idx + 1) % self.slots.len();
+    }
+
+    fn collect_slot(&mut self, slot: usize, mapped: bool) {
+        let slot = &mut self.slots[slot];
+        slot.in_flight = false;
+        if !mapped {
+            return;
+        }
+
+        {
+            let size = slot.nodes.len() as u64 * RESULT_SIZE;
+            let mapped = slot.readback_buffer.slice(..size).get_mapped_range();
+            let samples: &[u64] = bytemuck::cast_slice(&mapped);
+
+            self.occluded.clear();
+            self.occluded.extend(
+                slot.nodes
+                    .iter()
+                    .zip(samples)
+                    .filter(|&(_, &samples)| samples == 0)
+                    .map(|(node, _)| *node),
+            );
+        }
+        slot.readback_buffer.unmap();
+    }
+}
+
+// ─── Occlusion Query Pass Node ───────────────────────────────────────────────
+
+/// Ephemeral per-frame occlusion query render pass node.
+pub struct OcclusionQueryPassNode<'a> {
+    scene_depth: TextureNodeId,
+    pipeline: &'a wgpu::RenderPipeline,
+    query_set: &'a wgpu::QuerySet,
+    instance_buffer: &'a wgpu::Buffer,
+    query_count: u32,
+}
+
+impl<'a> PassNode<'a> for OcclusionQueryPassNode<'a> {
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let depth_att = ctx.get_depth_stencil_attachment(self.scene_depth, 0.0);
+
+        let pass_desc = wgpu::RenderPassDescriptor {
+            label: Some("Occlusion Query Pass"),
+            color_attachments: &[],
+            depth_stencil_attachment: depth_att,
+            timestamp_writes: None,
+            occlusion_query_set: Some(self.query_set),
+            multiview_mask: None,
+        };
+
+        let mut pass = encoder.begin_render_pass(&pass_desc);
+        pass.set_pipeline(self.pipeline);
+        pass.set_bind_group(0, ctx.baked_lists.global_bind_group, &[]);
+        pass.set_vertex_buffer(0, self.instance_buffer.slice(..));
+        for query in 0..self.query_count {
+            pass.begin_occlusion_query(query);
+            pass.draw(0..BOX_VERTEX_COUNT, query..query + 1);
+            pass.end_occlusion_query();
+        }
+    }
+}
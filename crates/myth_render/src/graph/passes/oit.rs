@@ -0,0 +1,243 @@
+//! Weighted Blended OIT Composite Feature + Ephemeral PassNode
+//!
+//! Resolves the weighted blended order-independent transparency targets
+//! (McGuire & Bavoil, 2013) produced by
+//! [`TransparentFeature::add_weighted_oit_to_graph`](super::TransparentFeature::add_weighted_oit_to_graph)
+//! and blends the result over the HDR scene colour.
+//!
+//! # Data Flow
+//!
+//! ```text
+//!  OIT_Accum ─────┐
+//!  OIT_Revealage ─┼──> WeightedOitPassNode ──(write)──> Scene_Color_OIT
+//!  Scene colour ──┘         (alpha blend)
+//! ```
+//!
+//! The composite runs at the scene colour's sample count, so under MSAA
+//! it draws into the multi-sampled surface and leaves the resolve to the
+//! sorted transparent pass that follows.
+//!
+//! # Binding Layout (Group 0)
+//!
+//! | Binding | Type         | Content                               |
+//! |---------|--------------|---------------------------------------|
+//! | 0       | `texture_2d` | Weighted premultiplied colour + alpha |
+//! | 1       | `texture_2d` | Revealage (product of `1 - alpha`)    |
+
+use crate::HDR_TEXTURE_FORMAT;
+use crate::core::gpu::Tracked;
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::{
+    ExecuteContext, ExtractContext, PassNode, PrepareContext, RenderTargetOps, TextureNodeId,
+};
+use crate::pipeline::{
+    ColorTargetKey, FullscreenPipelineKey, MultisampleKey, RenderPipelineId,
+    ShaderCompilationOptions, ShaderSource,
+};
+use wgpu::CommandEncoder;
+
+/// Format of the revealage target.
+pub const OIT_REVEALAGE_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::R8Unorm;
+
+/// Additive blending for the weighted colour accumulation target.
+pub const OIT_ACCUM_BLEND: wgpu::BlendState = wgpu::BlendState {
+    color: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::One,
+        dst_factor: wgpu::BlendFactor::One,
+        operation: wgpu::BlendOperation::Add,
+    },
+    alpha: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::One,
+        dst_factor: wgpu::BlendFactor::One,
+        operation: wgpu::BlendOperation::Add,
+    },
+};
+
+/// Multiplicative blending for the revealage target: `dst *= 1 - src`.
+pub const OIT_REVEALAGE_BLEND: wgpu::BlendState = wgpu::BlendState {
+    color: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::Zero,
+        dst_factor: wgpu::BlendFactor::OneMinusSrc,
+        operation: wgpu::BlendOperation::Add,
+    },
+    alpha: wgpu::BlendComponent {
+        src_factor: wgpu::BlendFactor::Zero,
+        dst_factor: wgpu::BlendFactor::OneMinusSrc,
+        operation: wgpu::BlendOperation::Add,
+    },
+};
+
+/// Single-sample accumulation targets written by the weighted OIT pass.
+#[derive(Debug, Clone, Copy)]
+pub struct WeightedOitTargets {
+    pub accum: TextureNodeId,
+    pub revealage: TextureNodeId,
+}
+
+/// L1 cache key: pipeline depends on the scene colour's MSAA sample count.
+type OitCompositeCacheKey = u32;
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// Feature (long-lived)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+/// Long-lived OIT composite feature — owns the composite pipeline and
+/// bind group layout.
+pub struct WeightedOitFeature {
+    l1_cache_key: Option<OitCompositeCacheKey>,
+    pipeline_id: Option<RenderPipelineId>,
+    bind_group_layout: Option<Tracked<wgpu::BindGroupLayout>>,
+}
+
+impl Default for WeightedOitFeature {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl WeightedOitFeature {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            l1_cache_key: None,
+            pipeline_id: None,
+            bind_group_layout: None,
+        }
+    }
+
+    /// Pre-RDG resource preparation: create layout, compile the composite
+    /// pipeline for the current sample count.
+    pub fn extract_and_prepare(&mut self, ctx: &mut ExtractContext, msaa_samples: u32) {
+        let device = ctx.device;
+
+        // ── 1. Lazy-create BindGroupLayout (once) ──────────────────
+        if self.bind_group_layout.is_none() {
+            let texture_entry = |binding| wgpu::BindGroupLayoutEntry {
+                binding,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            };
+            let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("OIT Composite BindGroup Layout"),
+                entries: &[texture_entry(0), texture_entry(1)],
+            });
+            self.bind_group_layout = Some(Tracked::new(layout));
+        }
+
+        // ── 2. L1 Cache: recompile pipeline when sample count changes ──
+        if self.l1_cache_key != Some(msaa_samples) {
+            let (shader_module, shader_hash) = ctx.shader_manager.get_or_compile(
+                device,
+                ShaderSource::File("entry/post_process/oit_composite"),
+                &ShaderCompilationOptions::default(),
+            );
+
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("OIT Composite Pipeline Layout"),
+                bind_group_layouts: &[self.bind_group_layout.as_deref()],
+                immediate_size: 0,
+            });
+
+            let key = FullscreenPipelineKey {
+                shader_hash,
+                color_targets: smallvec::smallvec![ColorTargetKey::from(wgpu::ColorTargetState {
+                    format: HDR_TEXTURE_FORMAT,
+                    blend: Some(wgpu::BlendState::ALPHA_BLENDING),
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                depth_stencil: None,
+                multisample: MultisampleKey::from(wgpu::MultisampleState {
+                    count: msaa_samples,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                }),
+            };
+
+            let id = ctx.pipeline_cache.get_or_create_fullscreen(
+                device,
+                shader_module,
+                &pipeline_layout,
+                &key,
+                "OIT Composite Pipeline",
+            );
+            self.pipeline_id = Some(id);
+            self.l1_cache_key = Some(msaa_samples);
+        }
+    }
+
+    /// Blends the resolved OIT targets over `color_target`.
+    ///
+    /// Returns the new SSA alias of the scene colour.
+    pub fn add_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        color_target: TextureNodeId,
+        targets: WeightedOitTargets,
+    ) -> TextureNodeId {
+        let pipeline_id = self.pipeline_id.expect("WeightedOitFeature not prepared");
+        let pipeline = ctx.pipeline_cache.get_render_pipeline(pipeline_id);
+        let layout = self.bind_group_layout.as_ref().unwrap();
+
+        ctx.graph.add_pass("OIT_Composite_Pass", |builder| {
+            builder.read_texture(targets.accum);
+            builder.read_texture(targets.revealage);
+            let out_color = builder.mutate_texture(color_target, "Scene_Color_OIT");
+
+            let node = WeightedOitPassNode {
+                targets,
+                out_color,
+                pipeline,
+                layout,
+                transient_bg: None,
+            };
+            (node, out_color)
+        })
+    }
+}
+
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+// PassNode (ephemeral, created per frame)
+// ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
+
+struct WeightedOitPassNode<'a> {
+    targets: WeightedOitTargets,
+    out_color: TextureNodeId,
+    pipeline: &'a wgpu::RenderPipeline,
+    layout: &'a Tracked<wgpu::BindGroupLayout>,
+    transient_bg: Option<&'a wgpu::BindGroup>,
+}
+
+impl<'a> PassNode<'a> for WeightedOitPassNode<'a> {
+    fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
+        self.transient_bg = Some(
+            crate::myth_bind_group!(ctx, self.layout, Some("OIT Composite BindGroup"), [
+                0 => self.targets.accum,
+                1 => self.targets.revealage,
+            ]),
+        );
+    }
+
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut CommandEncoder) {
+        let bind_group = self.transient_bg.expect("OIT Composite BG not prepared");
+
+        let rtt = ctx.get_color_attachment(self.out_color, RenderTargetOps::Load, None);
+
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("OIT Composite Pass"),
+            color_attachments: &[rtt],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+
+        rpass.set_pipeline(self.pipeline);
+        rpass.set_bind_group(0, bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+}
@@ -363,6 +363,6 @@ impl<'a> PassNode<'a> for OpaquePassNode {
         let screen_bg = self.screen_bind_group.unwrap();
         pass.set_bind_group(3, screen_bg, &[]);
 
-        submit_draw_commands(&mut pass, &ctx.baked_lists.opaque);
+        ctx.record_draws(submit_draw_commands(&mut pass, &ctx.baked_lists.opaque));
     }
 }
@@ -20,6 +20,7 @@ use crate::graph::composer::GraphBuilderContext;
 use crate::graph::core::{
     ExecuteContext, ExtractContext, PassNode, RenderTargetOps, TextureDesc, TextureNodeId,
 };
+use crate::graph::frame::WireframeDraw;
 use crate::graph::passes::draw::submit_draw_commands;
 use crate::pipeline::{
     ColorTargetKey, DepthStencilKey, RenderPipelineId, ShaderCompilationOptions, ShaderSource,
@@ -170,7 +171,12 @@ impl PrepassFeature {
                 continue;
             };
 
-            if material.is_transparent() {
+            // Must mirror the `use_depth_pre` decision in `cull_and_sort`.
+            if material.is_transparent()
+                || material.has_custom_vertex_placement()
+                || cmd.wireframe != WireframeDraw::None
+                || (material.stencil().is_some() && depth_format.has_stencil_aspect())
+            {
                 continue;
             }
 
@@ -215,6 +221,9 @@ impl PrepassFeature {
             if self.needs_feature_id {
                 options.add_define("OUTPUT_FEATURE_ID", "1");
             }
+            if cmd.gpu_driven {
+                options.add_define("GPU_DRIVEN", "1");
+            }
 
             // ── Shader generation ──────────────────────────────────────
             let binding_code = format!(
@@ -507,6 +516,6 @@ impl PassNode<'_> for PrepassPassNode {
 
         pass.set_bind_group(0, gpu_global_bind_group, &[]);
 
-        submit_draw_commands(&mut pass, &ctx.baked_lists.prepass);
+        ctx.record_draws(submit_draw_commands(&mut pass, &ctx.baked_lists.prepass));
     }
 }
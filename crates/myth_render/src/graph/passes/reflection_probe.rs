@@ -0,0 +1,170 @@
+//! Reflection and light probe capture.
+//!
+//! A capture frame renders the scene from the probe's position with the
+//! camera of one cube face. In place of post-processing, the linear HDR
+//! scene colour is copied into that face of the probe's cubemap. For a
+//! reflection probe, the frame capturing the last face also generates the
+//! cubemap's mips, which the PMREM prefilter samples; for a light probe,
+//! it projects the cubemap onto spherical harmonics.
+
+use glam::{Affine3A, Vec3};
+use myth_core::NodeHandle;
+use myth_resources::AntiAliasingMode;
+use myth_scene::camera::{Camera, RenderCamera};
+use wgpu::TextureViewDimension;
+
+use crate::core::ResourceManager;
+use crate::core::gpu::Tracked;
+use crate::graph::composer::GraphBuilderContext;
+use crate::graph::core::TextureNodeId;
+use crate::graph::core::context::ExecuteContext;
+use crate::graph::core::node::PassNode;
+
+/// The probe a capture frame renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProbeCaptureKind {
+    /// The reflection probe of this node.
+    Reflection(NodeHandle),
+    /// The light probe at this index of the scene's grid.
+    LightProbe(u32),
+}
+
+/// The probe face a capture frame renders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProbeCaptureTarget {
+    pub scene_id: u32,
+    pub kind: ProbeCaptureKind,
+    /// Array layer of the face in the probe's cubemap, `0..6`.
+    pub face: u32,
+}
+
+impl ProbeCaptureTarget {
+    /// Whether this frame completes the capture, and so also prefilters or
+    /// projects it.
+    #[inline]
+    #[must_use]
+    pub fn is_last_face(&self) -> bool {
+        self.face == 5
+    }
+
+    /// The cubemap the capture renders into, with its cube view, or `None`
+    /// if it has not been created.
+    #[must_use]
+    pub fn cube<'a>(
+        &self,
+        resource_manager: &'a ResourceManager,
+    ) -> Option<(&'a wgpu::Texture, &'a Tracked<wgpu::TextureView>)> {
+        match self.kind {
+            ProbeCaptureKind::Reflection(node) => resource_manager
+                .gpu_reflection_probe(self.scene_id, node)
+                .map(|gpu_probe| (&gpu_probe.base_cube_texture, &gpu_probe.base_cube_view)),
+            ProbeCaptureKind::LightProbe(_) => resource_manager
+                .gpu_light_probe_volume(self.scene_id)
+                .map(|gpu_volume| (&gpu_volume.capture_texture, &gpu_volume.capture_view)),
+        }
+    }
+
+    /// Creates a view of this frame's face of `cube`, used as the frame's
+    /// render target.
+    #[must_use]
+    pub fn face_view(&self, cube: &wgpu::Texture) -> wgpu::TextureView {
+        cube.create_view(&wgpu::TextureViewDescriptor {
+            label: Some("Probe Capture Face"),
+            dimension: Some(TextureViewDimension::D2),
+            base_mip_level: 0,
+            mip_level_count: Some(1),
+            base_array_layer: self.face,
+            array_layer_count: Some(1),
+            ..Default::default()
+        })
+    }
+}
+
+/// Forward and up directions of the capture camera of each cube face.
+///
+/// Environment lookups mirror the X axis, so the `+X` layer looks down
+/// world `-X` and the `±Y` faces are flipped accordingly.
+const FACE_ORIENTATIONS: [(Vec3, Vec3); 6] = [
+    (Vec3::NEG_X, Vec3::Y),
+    (Vec3::X, Vec3::Y),
+    (Vec3::Y, Vec3::NEG_Z),
+    (Vec3::NEG_Y, Vec3::Z),
+    (Vec3::Z, Vec3::Y),
+    (Vec3::NEG_Z, Vec3::Y),
+];
+
+/// Builds the 90° camera capturing `face` from `position`.
+///
+/// `msaa_samples` should match the main camera's, so that capture frames
+/// reuse its pipelines.
+#[must_use]
+pub fn face_camera(position: Vec3, near: f32, face: u32, msaa_samples: u32) -> RenderCamera {
+    let (forward, up) = FACE_ORIENTATIONS[face as usize];
+    let mut camera = Camera::new_perspective(90.0, 1.0, near);
+    if msaa_samples > 1 {
+        camera.set_aa_mode(AntiAliasingMode::MSAA(msaa_samples));
+    }
+    camera.update_view_projection(&Affine3A::look_to_rh(position, forward, up).inverse());
+    camera.extract_render_camera()
+}
+
+struct ProbeFaceCopyNode {
+    src: TextureNodeId,
+    dst: TextureNodeId,
+    face: u32,
+}
+
+impl PassNode<'_> for ProbeFaceCopyNode {
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let src_texture = ctx.get_texture(self.src);
+        let dst_texture = ctx.get_texture(self.dst);
+
+        encoder.copy_texture_to_texture(
+            wgpu::TexelCopyTextureInfo {
+                texture: src_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d::ZERO,
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::TexelCopyTextureInfo {
+                texture: dst_texture,
+                mip_level: 0,
+                origin: wgpu::Origin3d {
+                    x: 0,
+                    y: 0,
+                    z: self.face,
+                },
+                aspect: wgpu::TextureAspect::All,
+            },
+            wgpu::Extent3d {
+                width: dst_texture.width(),
+                height: dst_texture.height(),
+                depth_or_array_layers: 1,
+            },
+        );
+    }
+}
+
+/// Copies the resolved HDR scene colour into one face of `base_cube`.
+///
+/// `scene_color` must be single-sampled and match the cube's size and
+/// format.
+pub fn add_probe_face_copy_pass(
+    ctx: &mut GraphBuilderContext<'_, '_>,
+    scene_color: TextureNodeId,
+    base_cube: TextureNodeId,
+    face: u32,
+) -> TextureNodeId {
+    ctx.graph.add_pass("Probe_Face_Copy", |builder| {
+        builder.read_texture(scene_color);
+        builder.write_texture(base_cube);
+        (
+            ProbeFaceCopyNode {
+                src: scene_color,
+                dst: base_cube,
+                face,
+            },
+            base_cube,
+        )
+    })
+}
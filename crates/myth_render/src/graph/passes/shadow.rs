@@ -460,7 +460,7 @@ impl<'a> PassNode<'a> for Shadow2DPassNode<'a> {
                 .shadow_queues
                 .get(&(shadow_light.light_id, shadow_light.view_layer_index))
             {
-                submit_draw_commands(&mut pass, commands);
+                ctx.record_draws(submit_draw_commands(&mut pass, commands));
             }
         }
     }
@@ -525,7 +525,7 @@ impl<'a> PassNode<'a> for ShadowCubePassNode<'a> {
                 .shadow_queues
                 .get(&(shadow_light.light_id, shadow_light.view_layer_index))
             {
-                submit_draw_commands(&mut pass, commands);
+                ctx.record_draws(submit_draw_commands(&mut pass, commands));
             }
         }
     }
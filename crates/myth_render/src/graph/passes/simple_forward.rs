@@ -28,7 +28,7 @@ use crate::graph::core::{
     ExecuteContext, PassNode, PrepareContext, RenderTargetOps, TextureDesc, TextureNodeId,
     build_screen_bind_group,
 };
-use crate::graph::frame::PreparedSkyboxDraw;
+use crate::graph::frame::{PreparedDebugLinesDraw, PreparedGridDraw, PreparedSkyboxDraw};
 use crate::graph::passes::draw::submit_draw_commands;
 
 // ─── Feature ───────────────────────────────────────────────────────────
@@ -47,12 +47,15 @@ impl SimpleForwardFeature {
         Self
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn add_to_graph<'a>(
         &'a self,
         ctx: &mut GraphBuilderContext<'a, '_>,
         surface_out: TextureNodeId,
         clear_color: wgpu::Color,
         prepared_skybox: Option<PreparedSkyboxDraw<'a>>,
+        prepared_grid: Option<PreparedGridDraw<'a>>,
+        prepared_debug_lines: Option<PreparedDebugLinesDraw<'a>>,
         shadow_tex: Option<TextureNodeId>,
         shadow_cube_tex: Option<TextureNodeId>,
         env_map_tex: Option<TextureNodeId>,
@@ -115,6 +118,8 @@ impl SimpleForwardFeature {
                 msaa_view,
                 clear_color,
                 prepared_skybox,
+                prepared_grid,
+                prepared_debug_lines,
                 shadow_input: shadow_tex,
                 shadow_cube_input: shadow_cube_tex,
                 screen_bind_group: None,
@@ -139,6 +144,8 @@ pub struct SimpleForwardPassNode<'a> {
     pub msaa_view: Option<TextureNodeId>,
     pub clear_color: wgpu::Color,
     pub prepared_skybox: Option<PreparedSkyboxDraw<'a>>,
+    pub prepared_grid: Option<PreparedGridDraw<'a>>,
+    pub prepared_debug_lines: Option<PreparedDebugLinesDraw<'a>>,
     pub shadow_input: Option<TextureNodeId>,
     pub shadow_cube_input: Option<TextureNodeId>,
     screen_bind_group: Option<&'static wgpu::BindGroup>,
@@ -229,7 +236,7 @@ impl<'a> PassNode<'a> for SimpleForwardPassNode<'a> {
         pass.set_bind_group(3, screen_bg, &[]);
 
         // 1. Opaque (front-to-back)
-        submit_draw_commands(&mut pass, &ctx.baked_lists.opaque);
+        ctx.record_draws(submit_draw_commands(&mut pass, &ctx.baked_lists.opaque));
 
         // 2. Skybox (between opaque and transparent)
         if let Some(skybox) = &self.prepared_skybox {
@@ -239,7 +246,25 @@ impl<'a> PassNode<'a> for SimpleForwardPassNode<'a> {
             pass.set_bind_group(3, screen_bg, &[]);
         }
 
-        // 3. Transparent (back-to-front)
-        submit_draw_commands(&mut pass, &ctx.baked_lists.transparent);
+        // 3. Ground grid and debug lines (after opaque so they can be depth-tested)
+        if let Some(grid) = &self.prepared_grid {
+            grid.draw(&mut pass, gpu_global_bind_group);
+
+            pass.set_bind_group(0, gpu_global_bind_group, &[]);
+            pass.set_bind_group(3, screen_bg, &[]);
+        }
+
+        if let Some(lines) = &self.prepared_debug_lines {
+            lines.draw(&mut pass, gpu_global_bind_group);
+
+            pass.set_bind_group(0, gpu_global_bind_group, &[]);
+            pass.set_bind_group(3, screen_bg, &[]);
+        }
+
+        // 4. Transparent (back-to-front)
+        ctx.record_draws(submit_draw_commands(
+            &mut pass,
+            &ctx.baked_lists.transparent,
+        ));
     }
 }
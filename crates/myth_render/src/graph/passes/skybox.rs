@@ -641,7 +641,7 @@ impl SkyboxFeature {
         } = background_mode
         {
             let state = ctx.resource_manager.prepare_texture(ctx.assets, *handle);
-            if matches!(state, ResourceState::Pending)
+            if matches!(state, ResourceState::Pending | ResourceState::Streaming)
                 && self.current_bind_group.is_some()
                 && self.current_pipeline.is_some()
             {
@@ -655,7 +655,7 @@ impl SkyboxFeature {
             && let Some(TextureSource::Asset(handle)) = params.starbox_texture
         {
             let state = ctx.resource_manager.prepare_texture(ctx.assets, handle);
-            if matches!(state, ResourceState::Pending)
+            if matches!(state, ResourceState::Pending | ResourceState::Streaming)
                 && self.current_bind_group.is_some()
                 && self.current_pipeline.is_some()
             {
@@ -667,7 +667,7 @@ impl SkyboxFeature {
             && let Some(TextureSource::Asset(handle)) = params.moon_albedo_texture
         {
             let state = ctx.resource_manager.prepare_texture(ctx.assets, handle);
-            if matches!(state, ResourceState::Pending)
+            if matches!(state, ResourceState::Pending | ResourceState::Streaming)
                 && self.current_bind_group.is_some()
                 && self.current_pipeline.is_some()
             {
@@ -3,8 +3,8 @@
 //! - **`SsaoFeature`** (long-lived): owns pipelines, bind group layouts,
 //!   noise texture.  `extract_and_prepare()` compiles pipelines and uploads
 //!   persistent GPU data.
-//! - **`SsaoRawNode`** / **`SsaoBlurNode`** (ephemeral per-frame):
-//!   two independent RDG passes created by `SsaoFeature::add_to_graph()`.
+//! - **`SsaoRawNode`** / **`SsaoFilterNode`** (ephemeral per-frame):
+//!   independent RDG passes created by `SsaoFeature::add_to_graph()`.
 //!
 //! Implements production-grade SSAO within the RDG framework.
 //! The output texture is registered by `add_to_graph()` and returned
@@ -14,12 +14,15 @@
 //!
 //! - `depth_tex`: Scene depth buffer (input, from Prepass)
 //! - `normal_tex`: Scene normal buffer (input, from Prepass)
-//! - `output_tex`: Blurred AO texture (output, half-res R8Unorm)
+//! - `output_tex`: Final AO texture (output, full-res R8Unorm)
 //!
 //! # Internal Sub-Passes
 //!
 //! 1. **Raw SSAO**: Hemisphere sampling with kernel, produces noisy R8Unorm
-//! 2. **Cross-Bilateral Blur**: Depth/normal-aware spatial filter
+//!    at half or full resolution
+//! 2. **Cross-Bilateral Blur** × N: Depth/normal-aware spatial filter
+//! 3. **Joint Bilateral Upsample** (half resolution only): Depth/normal-aware
+//!    upsample to full resolution
 //!
 //! # Push Model
 //!
@@ -56,6 +59,7 @@ pub struct SsaoFeature {
     // ─── Pipelines ─────────────────────────────────────────────────
     raw_pipeline: Option<RenderPipelineId>,
     blur_pipeline: Option<RenderPipelineId>,
+    upsample_pipeline: Option<RenderPipelineId>,
 
     // ─── Bind Group Layouts ────────────────────────────────────────
     raw_layout: Option<Tracked<wgpu::BindGroupLayout>>,
@@ -78,6 +82,7 @@ impl SsaoFeature {
         Self {
             raw_pipeline: None,
             blur_pipeline: None,
+            upsample_pipeline: None,
 
             raw_layout: None,
             raw_uniforms_layout: None,
@@ -171,6 +176,7 @@ impl SsaoFeature {
             });
 
         // ─── Blur Layout (Group 0): raw AO + depth + normal + samplers ────
+        // Shared by the upsample pass.
         let blur_layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
             label: Some("SSAO Blur Layout"),
             entries: &[
@@ -346,8 +352,11 @@ impl SsaoFeature {
                 immediate_size: 0,
             });
 
-            let key =
-                FullscreenPipelineKey::fullscreen(hash, smallvec::smallvec![color_target], None);
+            let key = FullscreenPipelineKey::fullscreen(
+                hash,
+                smallvec::smallvec![color_target.clone()],
+                None,
+            );
 
             self.blur_pipeline = Some(ctx.pipeline_cache.get_or_create_fullscreen(
                 device,
@@ -357,6 +366,32 @@ impl SsaoFeature {
                 "SSAO Blur Pipeline",
             ));
         }
+
+        // ─── Upsample Pipeline ─────────────────────────────────────
+        {
+            let (module, hash) = ctx.shader_manager.get_or_compile(
+                device,
+                ShaderSource::File("entry/post_process/ssao_upsample"),
+                &ShaderCompilationOptions::default(),
+            );
+
+            let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("SSAO Upsample Pipeline Layout"),
+                bind_group_layouts: &[Some(blur_layout)],
+                immediate_size: 0,
+            });
+
+            let key =
+                FullscreenPipelineKey::fullscreen(hash, smallvec::smallvec![color_target], None);
+
+            self.upsample_pipeline = Some(ctx.pipeline_cache.get_or_create_fullscreen(
+                device,
+                module,
+                &pipeline_layout,
+                &key,
+                "SSAO Upsample Pipeline",
+            ));
+        }
     }
 
     /// Pre-RDG resource preparation: create layouts, noise texture, compile pipelines,
@@ -393,24 +428,31 @@ impl SsaoFeature {
     }
 
     /// Build the ephemeral pass nodes and insert them into the graph as
-    /// two independent RDG passes within an `"SSAO_System"` group.
+    /// independent RDG passes within an `"SSAO_System"` group.
     ///
-    /// Returns the [`TextureNodeId`] of the half-resolution AO output for
+    /// Returns the [`TextureNodeId`] of the full-resolution AO output for
     /// explicit downstream wiring (Opaque, Transparent).
     ///
     /// # Flattened Pass Chain
     ///
     /// 1. **SSAO_Raw** — hemisphere sampling → noisy R8Unorm
-    /// 2. **SSAO_Blur** — cross-bilateral blur → clean AO output
+    /// 2. **SSAO_Blur** × `blur_passes` — cross-bilateral blur
+    /// 3. **SSAO_Upsample** (with `half_resolution`) — joint bilateral
+    ///    upsample → full-resolution AO output
     pub fn add_to_graph<'a>(
         &'a self,
         ctx: &mut GraphBuilderContext<'a, '_>,
         scene_depth: TextureNodeId,
         scene_normals: TextureNodeId,
+        half_resolution: bool,
+        blur_passes: u32,
     ) -> TextureNodeId {
         let fc = ctx.frame_config;
-        let half_w = (fc.width / 2).max(1);
-        let half_h = (fc.height / 2).max(1);
+        let (ao_w, ao_h) = if half_resolution {
+            ((fc.width / 2).max(1), (fc.height / 2).max(1))
+        } else {
+            (fc.width, fc.height)
+        };
 
         let raw_pipeline = ctx
             .pipeline_cache
@@ -418,6 +460,9 @@ impl SsaoFeature {
         let blur_pipeline = ctx
             .pipeline_cache
             .get_render_pipeline(self.blur_pipeline.expect("SsaoFeature not prepared"));
+        let upsample_pipeline = ctx
+            .pipeline_cache
+            .get_render_pipeline(self.upsample_pipeline.expect("SsaoFeature not prepared"));
         let raw_layout = self.raw_layout.as_ref().unwrap();
         let blur_layout = self.blur_layout.as_ref().unwrap();
         let noise_texture_view = self.noise_texture_view.as_ref().unwrap();
@@ -428,9 +473,9 @@ impl SsaoFeature {
 
         ctx.with_group("SSAO_System", |ctx| {
             // ─── Pass 1: Raw SSAO ──────────────────────────────────
-            let raw_desc = TextureDesc::new_2d(
-                half_w,
-                half_h,
+            let ao_desc = TextureDesc::new_2d(
+                ao_w,
+                ao_h,
                 SSAO_TEXTURE_FORMAT,
                 wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
             );
@@ -438,7 +483,7 @@ impl SsaoFeature {
             let raw_tex: TextureNodeId = ctx.graph.add_pass("SSAO_Raw", |builder| {
                 builder.read_texture(scene_depth);
                 builder.read_texture(scene_normals);
-                let out = builder.create_texture("SSAO_Raw_Tex", raw_desc);
+                let out = builder.create_texture("SSAO_Raw_Tex", ao_desc);
                 let node = SsaoRawNode {
                     depth_tex: scene_depth,
                     normal_tex: scene_normals,
@@ -452,32 +497,58 @@ impl SsaoFeature {
                 (node, out)
             });
 
-            // ─── Pass 2: Cross-Bilateral Blur ──────────────────────
+            // ─── Pass 2: Cross-Bilateral Blur × N ──────────────────
+            let mut ao_tex = raw_tex;
+            for _ in 0..blur_passes {
+                let input = ao_tex;
+                ao_tex = ctx.graph.add_pass("SSAO_Blur", |builder| {
+                    builder.read_texture(input);
+                    builder.read_texture(scene_depth);
+                    builder.read_texture(scene_normals);
+                    let out = builder.create_texture("SSAO_Blur_Tex", ao_desc);
+                    let node = SsaoFilterNode {
+                        label: "SSAO Blur Pass",
+                        input_tex: input,
+                        depth_tex: scene_depth,
+                        normal_tex: scene_normals,
+                        output_tex: out,
+                        pipeline: blur_pipeline,
+                        layout: blur_layout,
+                        transient_bg: None,
+                    };
+                    (node, out)
+                });
+            }
+
+            if !half_resolution {
+                return ao_tex;
+            }
+
+            // ─── Pass 3: Joint Bilateral Upsample ──────────────────
             let output_desc = TextureDesc::new_2d(
-                half_w,
-                half_h,
+                fc.width,
+                fc.height,
                 SSAO_TEXTURE_FORMAT,
                 wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::TEXTURE_BINDING,
             );
 
-            let blur_out: TextureNodeId = ctx.graph.add_pass("SSAO_Blur", |builder| {
-                builder.read_texture(raw_tex);
+            ctx.graph.add_pass("SSAO_Upsample", |builder| {
+                builder.read_texture(ao_tex);
                 builder.read_texture(scene_depth);
                 builder.read_texture(scene_normals);
                 let out = builder.create_texture("SSAO_Output", output_desc);
-                let node = SsaoBlurNode {
-                    raw_tex,
+                let node = SsaoFilterNode {
+                    label: "SSAO Upsample Pass",
+                    input_tex: ao_tex,
                     depth_tex: scene_depth,
                     normal_tex: scene_normals,
                     output_tex: out,
-                    pipeline: blur_pipeline,
-                    blur_layout,
+                    pipeline: upsample_pipeline,
+                    layout: blur_layout,
                     transient_bg: None,
                 };
                 (node, out)
-            });
-
-            blur_out
+            })
         })
     }
 }
@@ -541,30 +612,32 @@ impl<'a> PassNode<'a> for SsaoRawNode<'a> {
 }
 
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
-// Pass 2: SsaoBlurNode (ephemeral, created per frame)
+// Passes 2–3: SsaoFilterNode (ephemeral, created per frame)
 // ━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━
 
-/// Ephemeral per-frame node for the cross-bilateral blur pass.
+/// Ephemeral per-frame node for the cross-bilateral blur and the joint
+/// bilateral upsample, which share a bind group layout.
 ///
-/// Reads the noisy raw AO texture and produces the final clean AO output
-/// using depth/normal-aware spatial filtering.
-struct SsaoBlurNode<'a> {
-    raw_tex: TextureNodeId,
+/// Reads an AO texture and filters it into `output_tex` using
+/// depth/normal-aware weights.
+struct SsaoFilterNode<'a> {
+    label: &'static str,
+    input_tex: TextureNodeId,
     depth_tex: TextureNodeId,
     normal_tex: TextureNodeId,
     output_tex: TextureNodeId,
 
     pipeline: &'a wgpu::RenderPipeline,
-    blur_layout: &'a Tracked<wgpu::BindGroupLayout>,
+    layout: &'a Tracked<wgpu::BindGroupLayout>,
 
     transient_bg: Option<&'a wgpu::BindGroup>,
 }
 
-impl<'a> PassNode<'a> for SsaoBlurNode<'a> {
+impl<'a> PassNode<'a> for SsaoFilterNode<'a> {
     fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
         self.transient_bg = Some(
-            crate::myth_bind_group!(ctx, self.blur_layout, Some("SSAO Blur BG (G0)"), [
-                0 => self.raw_tex,
+            crate::myth_bind_group!(ctx, self.layout, Some("SSAO Filter BG (G0)"), [
+                0 => self.input_tex,
                 1 => self.depth_tex,
                 2 => self.normal_tex,
                 3 => CommonSampler::LinearClamp,
@@ -574,12 +647,12 @@ impl<'a> PassNode<'a> for SsaoBlurNode<'a> {
     }
 
     fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
-        let blur_bg = self.transient_bg.expect("SSAO blur BG not prepared");
+        let filter_bg = self.transient_bg.expect("SSAO filter BG not prepared");
 
         let rtt = ctx.get_color_attachment(self.output_tex, RenderTargetOps::DontCare, None);
 
         let mut pass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-            label: Some("SSAO Blur Pass"),
+            label: Some(self.label),
             color_attachments: &[rtt],
             depth_stencil_attachment: None,
             timestamp_writes: None,
@@ -588,7 +661,7 @@ impl<'a> PassNode<'a> for SsaoBlurNode<'a> {
         });
 
         pass.set_pipeline(self.pipeline);
-        pass.set_bind_group(0, blur_bg, &[]);
+        pass.set_bind_group(0, filter_bg, &[]);
         pass.draw(0..3, 0..1);
     }
 }
@@ -99,15 +99,16 @@ impl TaaFeature {
         height: u32,
         depth_format: wgpu::TextureFormat,
     ) {
-        self.depth_format = depth_format;
-
         if self.history_size == (width, height)
+            && self.depth_format == depth_format
             && self.history_view.is_some()
             && self.history_depth_view.is_some()
         {
             return;
         }
 
+        self.depth_format = depth_format;
+
         // History colour (HDR)
         let color_desc = wgpu::TextureDescriptor {
             label: Some("TAA History Color"),
@@ -144,9 +145,13 @@ impl TaaFeature {
             view_formats: &[],
         };
         let depth_tex = device.create_texture(&depth_desc);
-        self.history_depth_view = Some(Tracked::new(
-            depth_tex.create_view(&wgpu::TextureViewDescriptor::default()),
-        ));
+        // Depth-stencil formats are sampled through their depth aspect.
+        self.history_depth_view = Some(Tracked::new(depth_tex.create_view(
+            &wgpu::TextureViewDescriptor {
+                aspect: wgpu::TextureAspect::DepthOnly,
+                ..Default::default()
+            },
+        )));
 
         self.history_size = (width, height);
     }
@@ -436,6 +441,15 @@ impl TaaFeature {
         });
 
         // Archive scene depth → persistent history depth buffer.
+        // `Depth24Plus*` depth cannot be copied; the history depth then
+        // keeps its initial contents (the resolve does not sample it yet).
+        if matches!(
+            self.depth_format,
+            wgpu::TextureFormat::Depth24Plus | wgpu::TextureFormat::Depth24PlusStencil8
+        ) {
+            return resolved_color;
+        }
+
         ctx.graph.add_pass("TAA_Save_History_Depth", |builder| {
             builder.read_texture(scene_depth);
             let depth_out = builder.write_external_texture(
@@ -22,7 +22,8 @@ use rustc_hash::FxHashMap;
 use crate::core::gpu::{CommonSampler, ResourceState, Tracked};
 use crate::graph::composer::GraphBuilderContext;
 use crate::graph::core::{
-    ExecuteContext, ExtractContext, PassNode, PrepareContext, RenderTargetOps, TextureNodeId,
+    BufferNodeId, ExecuteContext, ExtractContext, PassNode, PrepareContext, RenderTargetOps,
+    TextureNodeId,
 };
 use crate::pipeline::{
     ColorTargetKey, FullscreenPipelineKey, RenderPipelineId, ShaderCompilationOptions, ShaderSource,
@@ -219,7 +220,7 @@ impl ToneMappingFeature {
                 ResourceState::Ready => {
                     has_lut = true;
                 }
-                ResourceState::Pending => {
+                ResourceState::Pending | ResourceState::Streaming => {
                     if self.current_pipeline.is_some() {
                         // LUT is pending but we already have a pipeline (from a previous frame)
                         // keep using it until the LUT is ready to avoid stalling the GPU.
@@ -411,6 +412,7 @@ impl ToneMappingFeature {
         // pipeline_cache: &'a PipelineCache,
         input_hdr: TextureNodeId,
         target_ldr: TextureNodeId,
+        auto_exposure: Option<BufferNodeId>,
     ) -> TextureNodeId {
         let pipeline_id = self.current_pipeline.expect("ToneMapFeature not prepared");
         let pipeline = ctx.pipeline_cache.get_render_pipeline(pipeline_id);
@@ -422,6 +424,11 @@ impl ToneMappingFeature {
 
         ctx.graph.add_pass("ToneMap_Pass", |builder| {
             builder.read_texture(input_hdr);
+            // Orders the draw after the adapted exposure has been copied
+            // into the uniforms.
+            if let Some(auto_exposure) = auto_exposure {
+                builder.read_buffer(auto_exposure);
+            }
 
             let output = builder.write_texture(target_ldr);
 
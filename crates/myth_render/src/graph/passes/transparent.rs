@@ -29,14 +29,26 @@
 //! # Draw Order
 //!
 //! Transparent commands are sorted back-to-front for correct alpha blending.
+//!
+//! # Weighted Blended OIT
+//!
+//! With [`TransparencyMode::WeightedBlended`](myth_scene::TransparencyMode),
+//! blended materials are instead drawn unsorted by
+//! [`TransparentFeature::add_weighted_oit_to_graph`] into the `OIT_Accum`
+//! and `OIT_Revealage` targets, which
+//! [`WeightedOitFeature`](super::WeightedOitFeature) composites over the
+//! scene colour before the sorted pass. Transmissive materials always use
+//! the sorted pass.
 
 use crate::core::gpu::Tracked;
 use crate::graph::composer::GraphBuilderContext;
 use crate::graph::core::{
-    ExecuteContext, PassNode, PrepareContext, RenderTargetOps, TextureNodeId,
+    ExecuteContext, PassNode, PrepareContext, RenderTargetOps, TextureDesc, TextureNodeId,
     build_screen_bind_group,
 };
 use crate::graph::passes::draw::submit_draw_commands;
+use crate::graph::passes::oit::{OIT_REVEALAGE_FORMAT, WeightedOitTargets};
+use smallvec::SmallVec;
 
 // ─── Feature ───────────────────────────────────────────────────────────
 
@@ -107,10 +119,114 @@ impl TransparentFeature {
             let result = resolve_target.unwrap_or(color_output);
 
             let node = TransparentPassNode::new(
-                color_target,
-                color_output,
+                PassTargets::Sorted {
+                    out_color: color_output,
+                    resolve_target,
+                },
+                depth_target,
+                transmission_tex,
+                ssao_tex,
+                shadow_tex,
+                shadow_cube_tex,
+            );
+
+            (node, result)
+        })
+    }
+
+    /// Builds the weighted blended OIT accumulation pass.
+    ///
+    /// Draws the unsorted `weighted_oit` list into freshly cleared
+    /// `OIT_Accum` (HDR) and `OIT_Revealage` targets, depth-tested against
+    /// `depth_target` without writing it. In MSAA mode both targets are
+    /// multi-sampled and resolved to single-sample copies.
+    ///
+    /// Returns the single-sample targets for
+    /// [`WeightedOitFeature`](super::WeightedOitFeature) to composite.
+    pub fn add_weighted_oit_to_graph<'a>(
+        &'a self,
+        ctx: &mut GraphBuilderContext<'a, '_>,
+        depth_target: TextureNodeId,
+        transmission_tex: Option<TextureNodeId>,
+        ssao_tex: Option<TextureNodeId>,
+        shadow_tex: Option<TextureNodeId>,
+        shadow_cube_tex: Option<TextureNodeId>,
+    ) -> WeightedOitTargets {
+        let fc = ctx.frame_config;
+        let target_desc = |format, samples, usage| {
+            TextureDesc::new(
+                fc.width,
+                fc.height,
+                1,
+                1,
+                samples,
+                wgpu::TextureDimension::D2,
+                format,
+                usage | wgpu::TextureUsages::RENDER_ATTACHMENT,
+            )
+        };
+
+        ctx.graph.add_pass("OIT_Accumulate_Pass", |builder| {
+            let (accum, revealage, accum_resolve, revealage_resolve) = if fc.msaa_samples > 1 {
+                let samples = fc.msaa_samples;
+                let usage = wgpu::TextureUsages::empty();
+                let read = wgpu::TextureUsages::TEXTURE_BINDING;
+                (
+                    builder.create_texture(
+                        "OIT_Accum_MSAA",
+                        target_desc(fc.hdr_format, samples, usage),
+                    ),
+                    builder.create_texture(
+                        "OIT_Revealage_MSAA",
+                        target_desc(OIT_REVEALAGE_FORMAT, samples, usage),
+                    ),
+                    Some(builder.create_texture("OIT_Accum", target_desc(fc.hdr_format, 1, read))),
+                    Some(builder.create_texture(
+                        "OIT_Revealage",
+                        target_desc(OIT_REVEALAGE_FORMAT, 1, read),
+                    )),
+                )
+            } else {
+                let usage = wgpu::TextureUsages::TEXTURE_BINDING;
+                (
+                    builder.create_texture("OIT_Accum", target_desc(fc.hdr_format, 1, usage)),
+                    builder.create_texture(
+                        "OIT_Revealage",
+                        target_desc(OIT_REVEALAGE_FORMAT, 1, usage),
+                    ),
+                    None,
+                    None,
+                )
+            };
+
+            builder.read_texture(depth_target);
+
+            if let Some(tx) = transmission_tex {
+                builder.read_texture(tx);
+            }
+            if let Some(ssao) = ssao_tex {
+                builder.read_texture(ssao);
+            }
+            if let Some(shadow) = shadow_tex {
+                builder.read_texture(shadow);
+            }
+            if let Some(shadow_cube) = shadow_cube_tex {
+                builder.read_texture(shadow_cube);
+            }
+
+            let result = WeightedOitTargets {
+                accum: accum_resolve.unwrap_or(accum),
+                revealage: revealage_resolve.unwrap_or(revealage),
+            };
+
+            let node = TransparentPassNode::new(
+                PassTargets::WeightedOit {
+                    accum,
+                    accum_resolve,
+                    revealage,
+                    revealage_resolve,
+                },
                 depth_target,
-                resolve_target,
                 transmission_tex,
                 ssao_tex,
                 shadow_tex,
@@ -124,10 +240,25 @@ impl TransparentFeature {
 
 // ─── Pass Node ─────────────────────────────────────────────────────────
 
+/// Colour attachments of a [`TransparentPassNode`].
+enum PassTargets {
+    /// Back-to-front blending onto the scene colour.
+    Sorted {
+        out_color: TextureNodeId,
+        resolve_target: Option<TextureNodeId>,
+    },
+    /// Weighted blended OIT accumulation.
+    WeightedOit {
+        accum: TextureNodeId,
+        accum_resolve: Option<TextureNodeId>,
+        revealage: TextureNodeId,
+        revealage_resolve: Option<TextureNodeId>,
+    },
+}
+
 pub struct TransparentPassNode {
-    out_color: TextureNodeId,
+    targets: PassTargets,
     depth_target: TextureNodeId,
-    resolve_target: Option<TextureNodeId>,
     transmission_input: Option<TextureNodeId>,
     ssao_input: Option<TextureNodeId>,
     shadow_input: Option<TextureNodeId>,
@@ -138,19 +269,16 @@ pub struct TransparentPassNode {
 impl TransparentPassNode {
     #[must_use]
     fn new(
-        _in_color: TextureNodeId,
-        out_color: TextureNodeId,
+        targets: PassTargets,
         depth_target: TextureNodeId,
-        resolve_target: Option<TextureNodeId>,
         transmission_input: Option<TextureNodeId>,
         ssao_input: Option<TextureNodeId>,
         shadow_input: Option<TextureNodeId>,
         shadow_cube_input: Option<TextureNodeId>,
     ) -> Self {
         Self {
-            out_color,
+            targets,
             depth_target,
-            resolve_target,
             transmission_input,
             ssao_input,
             shadow_input,
@@ -224,13 +352,46 @@ impl<'a> PassNode<'a> for TransparentPassNode {
     fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
         let gpu_global_bind_group = ctx.baked_lists.global_bind_group;
 
-        let color_att =
-            ctx.get_color_attachment(self.out_color, RenderTargetOps::Load, self.resolve_target);
+        let (label, color_atts, commands): (_, SmallVec<[_; 2]>, _) = match self.targets {
+            PassTargets::Sorted {
+                out_color,
+                resolve_target,
+            } => (
+                "Transparent Pass",
+                smallvec::smallvec![ctx.get_color_attachment(
+                    out_color,
+                    RenderTargetOps::Load,
+                    resolve_target
+                )],
+                &ctx.baked_lists.transparent,
+            ),
+            PassTargets::WeightedOit {
+                accum,
+                accum_resolve,
+                revealage,
+                revealage_resolve,
+            } => (
+                "OIT Accumulate Pass",
+                smallvec::smallvec![
+                    ctx.get_color_attachment(
+                        accum,
+                        RenderTargetOps::Clear(wgpu::Color::TRANSPARENT),
+                        accum_resolve,
+                    ),
+                    ctx.get_color_attachment(
+                        revealage,
+                        RenderTargetOps::Clear(wgpu::Color::WHITE),
+                        revealage_resolve,
+                    ),
+                ],
+                &ctx.baked_lists.weighted_oit,
+            ),
+        };
         let depth_att = ctx.get_depth_stencil_attachment(self.depth_target, 0.0);
 
         let pass_desc = wgpu::RenderPassDescriptor {
-            label: Some("Transparent Pass"),
-            color_attachments: &[color_att],
+            label: Some(label),
+            color_attachments: &color_atts,
             depth_stencil_attachment: depth_att,
             timestamp_writes: None,
             occlusion_query_set: None,
@@ -242,11 +403,11 @@ impl<'a> PassNode<'a> for TransparentPassNode {
 
         pass.set_bind_group(0, gpu_global_bind_group, &[]);
 
-        if !ctx.baked_lists.transparent.is_empty() {
+        if !commands.is_empty() {
             let screen_bg = self.screen_bind_group.unwrap();
             pass.set_bind_group(3, screen_bg, &[]);
 
-            submit_draw_commands(&mut pass, &ctx.baked_lists.transparent);
+            ctx.record_draws(submit_draw_commands(&mut pass, commands));
         }
     }
 }
@@ -13,18 +13,25 @@ impl PassNode<'_> for CopyTextureNode {
         let src_texture = ctx.get_texture(self.src);
         let dst_texture = ctx.get_texture(self.dst);
 
+        // Depth-stencil textures can only be copied one aspect at a time.
+        let aspect = if src_texture.format().has_stencil_aspect() {
+            wgpu::TextureAspect::DepthOnly
+        } else {
+            wgpu::TextureAspect::All
+        };
+
         encoder.copy_texture_to_texture(
             wgpu::TexelCopyTextureInfo {
                 texture: src_texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d::ZERO,
-                aspect: wgpu::TextureAspect::All,
+                aspect,
             },
             wgpu::TexelCopyTextureInfo {
                 texture: dst_texture,
                 mip_level: 0,
                 origin: wgpu::Origin3d::ZERO,
-                aspect: wgpu::TextureAspect::All,
+                aspect,
             },
             wgpu::Extent3d {
                 width: src_texture.size().width,
@@ -72,6 +72,14 @@ impl DebugViewTarget {
 
 static NEXT_RENDER_STATE_ID: AtomicU32 = AtomicU32::new(0);
 
+/// Camera values latched by [`RenderState::update`] for the next frame.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CameraHistory {
+    view_projection: glam::Mat4,
+    jitter: glam::Vec2,
+    unjittered_vp: glam::Mat4,
+}
+
 pub struct RenderState {
     pub id: u32,
     uniforms: CpuBuffer<RenderStateUniforms>,
@@ -122,6 +130,23 @@ impl RenderState {
         self.uniforms.write()
     }
 
+    /// Saves the previous-frame camera values, so that frames rendered
+    /// from another viewpoint (reflection probe captures) can be undone
+    /// with [`restore_camera_history`](Self::restore_camera_history).
+    pub(crate) fn camera_history(&self) -> CameraHistory {
+        CameraHistory {
+            view_projection: self.prev_view_projection,
+            jitter: self.prev_jitter,
+            unjittered_vp: self.prev_unjittered_vp,
+        }
+    }
+
+    pub(crate) fn restore_camera_history(&mut self, history: CameraHistory) {
+        self.prev_view_projection = history.view_projection;
+        self.prev_jitter = history.jitter;
+        self.prev_unjittered_vp = history.unjittered_vp;
+    }
+
     pub fn update(
         &mut self,
         camera: &RenderCamera,
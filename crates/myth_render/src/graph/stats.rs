@@ -0,0 +1,58 @@
+//! Per-frame Render Statistics
+//!
+//! [`RenderStats`] summarises how much work the last frame submitted:
+//! culling results come from the main-camera cull, draw counters from
+//! [`submit_draw_commands`](super::passes::draw::submit_draw_commands),
+//! which already tracks redundant pipeline / bind-group state.
+//!
+//! Only baked scene draw commands (opaque, transparent, prepass, shadow) are
+//! counted; fullscreen passes such as the skybox or post-processing are not.
+//!
+//! The upload counters come from the resource manager and the pipeline
+//! cache. They show what per-frame material edits cost: changing uniform
+//! values uploads the material's uniform buffer once per frame, while only
+//! texture or settings changes rebuild bind groups or compile pipelines.
+
+use std::ops::AddAssign;
+
+/// Counters for a single rendered frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderStats {
+    /// Render items that passed the main camera's layer and frustum tests.
+    pub visible_meshes: u32,
+    /// Render items rejected by the main camera's layer mask or frustum.
+    pub culled_meshes: u32,
+    /// Draw calls issued across all scene passes (including shadows).
+    pub draw_calls: u32,
+    /// Submitted triangles, estimated as `vertices / 3 × instances`.
+    pub triangles: u64,
+    /// Pipeline binds after redundant-state elimination.
+    pub pipeline_switches: u32,
+    /// Material, object and screen bind group binds (groups 1–3).
+    pub bind_group_switches: u32,
+    /// Material uniform buffers uploaded because their values changed.
+    pub material_uniform_uploads: u32,
+    /// Material bind groups (re)built, e.g. after a texture swap.
+    pub material_bind_group_rebuilds: u32,
+    /// Render pipelines added while building the frame, including those
+    /// finished by the background compile thread.
+    pub pipelines_compiled: u32,
+    /// Material pipelines still compiling in the background at the end of
+    /// the frame; meshes waiting for them were not drawn.
+    pub pipelines_pending: u32,
+}
+
+impl AddAssign for RenderStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.visible_meshes += rhs.visible_meshes;
+        self.culled_meshes += rhs.culled_meshes;
+        self.draw_calls += rhs.draw_calls;
+        self.triangles += rhs.triangles;
+        self.pipeline_switches += rhs.pipeline_switches;
+        self.bind_group_switches += rhs.bind_group_switches;
+        self.material_uniform_uploads += rhs.material_uniform_uploads;
+        self.material_bind_group_rebuilds += rhs.material_bind_group_rebuilds;
+        self.pipelines_compiled += rhs.pipelines_compiled;
+        self.pipelines_pending += rhs.pipelines_pending;
+    }
+}
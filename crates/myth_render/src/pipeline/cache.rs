@@ -16,6 +16,25 @@
 //! `ComputePipelineKey`, `SimpleGeometryPipelineKey`). A full-state hash is computed
 //! only on L1 miss (or for families without L1).
 //!
+//! # Background Compilation
+//!
+//! On native targets, material pipelines can be compiled on a background
+//! thread ([`request_graphics`](PipelineCache::request_graphics)). Until the
+//! pipeline lands, which [`poll_pending`](PipelineCache::poll_pending) picks
+//! up at the start of each frame, L1 lookups report such keys as
+//! [`PipelineLookup::Pending`] rather than [`PipelineLookup::Missing`],
+//! carrying the fallback pipeline the draw uses in the meantime.
+//! WGSL generation and validation still run on the calling thread. wgpu
+//! has no asynchronous pipeline creation, so on wasm the request compiles
+//! synchronously.
+//!
+//! # Driver Cache
+//!
+//! Where the backend supports it, a `wgpu::PipelineCache` set with
+//! [`set_driver_cache`](PipelineCache::set_driver_cache) is passed to every
+//! pipeline creation, letting the driver reuse compiled state. The renderer
+//! loads and saves its contents across runs (see `disk_cache`).
+//!
 //! # Shader Modules
 //!
 //! Shader module caching has been extracted into [`ShaderManager`] to decouple
@@ -26,12 +45,13 @@
 
 #[cfg(feature = "debug_view")]
 use myth_scene::DebugViewMode;
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use smallvec::SmallVec;
 
 use crate::core::BindGroupContext;
 use crate::core::gpu::{GpuGlobalState, GpuMaterial, Tracked};
 use crate::graph::extracted::SceneFeatures;
+use crate::graph::passes::oit::{OIT_ACCUM_BLEND, OIT_REVEALAGE_BLEND, OIT_REVEALAGE_FORMAT};
 use crate::pipeline::pipeline_id::{ComputePipelineId, RenderPipelineId};
 use crate::pipeline::pipeline_key::{
     ComputePipelineKey, FullscreenPipelineKey, GraphicsPipelineKey, PipelineFlags,
@@ -39,7 +59,7 @@ use crate::pipeline::pipeline_key::{
 };
 use crate::pipeline::shader_gen::ShaderCompilationOptions;
 use crate::pipeline::shader_manager::{ShaderManager, ShaderSource};
-use crate::pipeline::vertex::GeneratedVertexLayout;
+use crate::pipeline::vertex::{GeneratedVertexLayout, VertexBufferLayoutSignature};
 use myth_assets::{GeometryHandle, MaterialHandle};
 
 // ─── L1 Fast Keys ────────────────────────────────────────────────────────────
@@ -55,6 +75,8 @@ pub struct FastPipelineKey {
     pub global_state_id: u32,
     pub scene_variants: SceneFeatures,
     pub taa_enabled: bool,
+    /// Object data is read by instance (`GPU_DRIVEN`).
+    pub gpu_driven: bool,
     pub pipeline_settings_version: u64,
     #[cfg(feature = "debug_view")]
     pub debug_view_mode: DebugViewMode,
@@ -71,6 +93,99 @@ pub struct FastShadowPipelineKey {
     pub pipeline_settings_version: u64,
 }
 
+/// Result of an L1 lookup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipelineLookup {
+    /// The pipeline is compiled.
+    Ready(RenderPipelineId),
+    /// The pipeline is being compiled in the background. Draws use the
+    /// fallback pipeline registered for the key, or are skipped without one.
+    Pending(Option<RenderPipelineId>),
+    /// The key has not been requested yet.
+    Missing,
+}
+
+// ─── Background Compilation ──────────────────────────────────────────────────
+
+/// Everything needed to create a material pipeline, owned so it can move to
+/// the compile thread.
+struct GraphicsPipelineJob {
+    module: wgpu::ShaderModule,
+    layout: wgpu::PipelineLayout,
+    vertex_buffers: Vec<VertexBufferLayoutSignature>,
+    color_targets: Vec<Option<wgpu::ColorTargetState>>,
+    primitive: wgpu::PrimitiveState,
+    depth_stencil: wgpu::DepthStencilState,
+    multisample: wgpu::MultisampleState,
+    driver_cache: Option<wgpu::PipelineCache>,
+}
+
+impl GraphicsPipelineJob {
+    fn create(&self, device: &wgpu::Device) -> wgpu::RenderPipeline {
+        let vertex_buffers: Vec<_> = self
+            .vertex_buffers
+            .iter()
+            .map(|b| wgpu::VertexBufferLayout {
+                array_stride: b.array_stride,
+                step_mode: b.step_mode,
+                attributes: &b.attributes,
+            })
+            .collect();
+
+        device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Scene Render Pipeline"),
+            layout: Some(&self.layout),
+            vertex: wgpu::VertexState {
+                module: &self.module,
+                entry_point: Some("vs_main"),
+                buffers: &vertex_buffers,
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &self.module,
+                entry_point: Some("fs_main"),
+                targets: &self.color_targets,
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: self.primitive,
+            depth_stencil: Some(self.depth_stencil.clone()),
+            multisample: self.multisample,
+            multiview_mask: None,
+            cache: self.driver_cache.as_ref(),
+        })
+    }
+}
+
+/// A background thread creating material pipelines in request order.
+///
+/// The thread exits once the cache (and with it the job sender) is dropped.
+#[cfg(not(target_arch = "wasm32"))]
+struct PipelineCompiler {
+    jobs: flume::Sender<(u64, u64, GraphicsPipelineJob)>,
+    results: flume::Receiver<(u64, u64, wgpu::RenderPipeline)>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl PipelineCompiler {
+    fn spawn(device: &wgpu::Device) -> Self {
+        let (jobs, job_rx) = flume::unbounded::<(u64, u64, GraphicsPipelineJob)>();
+        let (result_tx, results) = flume::unbounded();
+        let device = device.clone();
+        std::thread::Builder::new()
+            .name("myth-pipeline-compiler".into())
+            .spawn(move || {
+                for (hash, generation, job) in job_rx.iter() {
+                    let pipeline = job.create(&device);
+                    if result_tx.send((hash, generation, pipeline)).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn pipeline compiler thread");
+        Self { jobs, results }
+    }
+}
+
 // ─── Pipeline Cache ──────────────────────────────────────────────────────────
 
 /// Central pipeline storage and deduplication cache.
@@ -100,6 +215,23 @@ pub struct PipelineCache {
     // ---- L1 fast lookups (handle+version → Id) ----
     fast_cache: FxHashMap<FastPipelineKey, RenderPipelineId>,
     fast_shadow_cache: FxHashMap<FastShadowPipelineKey, RenderPipelineId>,
+
+    // ---- Background compilation ----
+    #[cfg(not(target_arch = "wasm32"))]
+    compiler: Option<PipelineCompiler>,
+    /// L2 hashes of material pipelines being compiled in the background.
+    pending_graphics: FxHashSet<u64>,
+    /// L1 keys waiting for a pending material pipeline (key → L2 hash and
+    /// fallback pipeline).
+    fast_pending: FxHashMap<FastPipelineKey, (u64, Option<RenderPipelineId>)>,
+    /// Bumped whenever material pipelines are invalidated, so background
+    /// results compiled from stale state are discarded.
+    generation: u64,
+
+    // ---- Driver cache ----
+    /// Backend pipeline cache passed to every pipeline creation, so the
+    /// driver can reuse compiled state (e.g. loaded from disk).
+    driver_cache: Option<wgpu::PipelineCache>,
 }
 
 impl Default for PipelineCache {
@@ -122,6 +254,12 @@ impl PipelineCache {
             compute_lookup: FxHashMap::default(),
             fast_cache: FxHashMap::default(),
             fast_shadow_cache: FxHashMap::default(),
+            #[cfg(not(target_arch = "wasm32"))]
+            compiler: None,
+            pending_graphics: FxHashSet::default(),
+            fast_pending: FxHashMap::default(),
+            generation: 0,
+            driver_cache: None,
         }
     }
 
@@ -222,6 +360,42 @@ impl PipelineCache {
         self.compute_lookup.clear();
         self.fast_cache.clear();
         self.fast_shadow_cache.clear();
+        self.forget_pending();
+    }
+
+    /// Forgets pipelines whose cache key does not capture the shader source.
+    ///
+    /// Material pipelines are keyed on the compilation options rather than
+    /// the final WGSL, so after a shader reload their L2 (and L1) entries
+    /// must be dropped to force recompilation. Fullscreen, simple-geometry
+    /// and compute keys embed the WGSL hash and stay valid. Existing
+    /// pipelines remain addressable by id until their users re-resolve.
+    pub fn invalidate_shader_dependents(&mut self) {
+        self.graphics_lookup.clear();
+        self.fast_cache.clear();
+        self.fast_shadow_cache.clear();
+        self.forget_pending();
+    }
+
+    /// Sets the backend pipeline cache used for every pipeline created from
+    /// now on, or removes it with `None`.
+    pub fn set_driver_cache(&mut self, cache: Option<wgpu::PipelineCache>) {
+        self.driver_cache = cache;
+    }
+
+    /// The backend pipeline cache, if the device supports one and it has
+    /// been set.
+    #[must_use]
+    pub fn driver_cache(&self) -> Option<&wgpu::PipelineCache> {
+        self.driver_cache.as_ref()
+    }
+
+    /// Drops in-flight background compilations; their results are ignored
+    /// when they arrive.
+    fn forget_pending(&mut self) {
+        self.pending_graphics.clear();
+        self.fast_pending.clear();
+        self.generation += 1;
     }
 
     // ── L1 Fast Cache (material geometry pipelines) ──────────────────────────
@@ -231,6 +405,36 @@ impl PipelineCache {
         self.fast_cache.get(&fast_key).copied()
     }
 
+    /// Like [`get_pipeline_fast`](Self::get_pipeline_fast), but tells a key
+    /// whose pipeline is still compiling in the background apart from one
+    /// that was never requested.
+    #[must_use]
+    pub fn lookup_pipeline_fast(&self, fast_key: FastPipelineKey) -> PipelineLookup {
+        if let Some(&id) = self.fast_cache.get(&fast_key) {
+            PipelineLookup::Ready(id)
+        } else if let Some(&(_, fallback)) = self.fast_pending.get(&fast_key) {
+            PipelineLookup::Pending(fallback)
+        } else {
+            PipelineLookup::Missing
+        }
+    }
+
+    /// Marks an L1 key as waiting for the background compilation of
+    /// `canonical_key`, requested with [`request_graphics`](Self::request_graphics).
+    /// Lookups return `fallback` until then.
+    ///
+    /// [`poll_pending`](Self::poll_pending) promotes it to a regular L1
+    /// entry once the pipeline is ready.
+    pub fn insert_pending_fast(
+        &mut self,
+        fast_key: FastPipelineKey,
+        canonical_key: &GraphicsPipelineKey,
+        fallback: Option<RenderPipelineId>,
+    ) {
+        self.fast_pending
+            .insert(fast_key, (fx_hash_key(&canonical_key), fallback));
+    }
+
     pub fn insert_pipeline_fast(&mut self, fast_key: FastPipelineKey, id: RenderPipelineId) {
         self.fast_cache.insert(fast_key, id);
     }
@@ -256,7 +460,7 @@ impl PipelineCache {
     /// Look up or create a material-driven geometry pipeline.
     ///
     /// This is the main entry point for `SceneCullPass`.
-    #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+    #[allow(clippy::too_many_arguments)]
     pub fn get_or_create_graphics(
         &mut self,
         device: &wgpu::Device,
@@ -275,6 +479,186 @@ impl PipelineCache {
             return id;
         }
 
+        let job = self.graphics_job(
+            device,
+            shader_manager,
+            template_name,
+            canonical_key,
+            options,
+            vertex_layout,
+            gpu_material,
+            object_bind_group,
+            gpu_world,
+            screen_bind_group_layout,
+        );
+        let id = self.push_render_pipeline(job.create(device));
+        self.graphics_lookup.insert(hash, id);
+        id
+    }
+
+    /// Like [`get_or_create_graphics`](Self::get_or_create_graphics), but
+    /// creates a missing pipeline on the background compile thread and
+    /// returns `None` until [`poll_pending`](Self::poll_pending) has picked
+    /// it up. Repeated requests for a pending pipeline are free.
+    ///
+    /// On wasm the pipeline is created immediately.
+    #[allow(clippy::too_many_arguments)]
+    pub fn request_graphics(
+        &mut self,
+        device: &wgpu::Device,
+        shader_manager: &mut ShaderManager,
+        template_name: &str,
+        canonical_key: &GraphicsPipelineKey,
+        options: &ShaderCompilationOptions,
+        vertex_layout: &GeneratedVertexLayout,
+        gpu_material: &GpuMaterial,
+        object_bind_group: &BindGroupContext,
+        gpu_world: &GpuGlobalState,
+        screen_bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> Option<RenderPipelineId> {
+        #[cfg(target_arch = "wasm32")]
+        {
+            Some(self.get_or_create_graphics(
+                device,
+                shader_manager,
+                template_name,
+                canonical_key,
+                options,
+                vertex_layout,
+                gpu_material,
+                object_bind_group,
+                gpu_world,
+                screen_bind_group_layout,
+            ))
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let hash = fx_hash_key(&canonical_key);
+            if let Some(&id) = self.graphics_lookup.get(&hash) {
+                return Some(id);
+            }
+            if self.pending_graphics.contains(&hash) {
+                return None;
+            }
+
+            let job = self.graphics_job(
+                device,
+                shader_manager,
+                template_name,
+                canonical_key,
+                options,
+                vertex_layout,
+                gpu_material,
+                object_bind_group,
+                gpu_world,
+                screen_bind_group_layout,
+            );
+            let compiler = self
+                .compiler
+                .get_or_insert_with(|| PipelineCompiler::spawn(device));
+            if let Err(flume::SendError((_, _, job))) =
+                compiler.jobs.send((hash, self.generation, job))
+            {
+                // The compile thread is gone; fall back to compiling here.
+                let id = self.push_render_pipeline(job.create(device));
+                self.graphics_lookup.insert(hash, id);
+                return Some(id);
+            }
+            self.pending_graphics.insert(hash);
+            None
+        }
+    }
+
+    /// Stores the material pipelines finished by the background compile
+    /// thread and promotes the L1 keys waiting for them. Returns how many
+    /// pipelines were added.
+    ///
+    /// Called once per frame before culling.
+    pub fn poll_pending(&mut self) -> usize {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            if self.pending_graphics.is_empty() {
+                return 0;
+            }
+            let Some(compiler) = &self.compiler else {
+                return 0;
+            };
+            let finished: Vec<_> = compiler.results.try_iter().collect();
+            let count = finished.len();
+            for (hash, generation, pipeline) in finished {
+                self.finish_pending(hash, generation, pipeline);
+            }
+            self.promote_fast_pending();
+            count
+        }
+
+        #[cfg(target_arch = "wasm32")]
+        {
+            0
+        }
+    }
+
+    /// Blocks until every background compilation has finished, then stores
+    /// the pipelines like [`poll_pending`](Self::poll_pending).
+    ///
+    /// Useful behind a loading screen after prewarming.
+    pub fn wait_pending(&mut self) {
+        #[cfg(not(target_arch = "wasm32"))]
+        while !self.pending_graphics.is_empty() {
+            let Some(result) = self.compiler.as_ref().and_then(|c| c.results.recv().ok()) else {
+                self.pending_graphics.clear();
+                break;
+            };
+            let (hash, generation, pipeline) = result;
+            self.finish_pending(hash, generation, pipeline);
+        }
+        self.promote_fast_pending();
+    }
+
+    /// Number of material pipelines compiling in the background.
+    #[must_use]
+    pub fn pending_count(&self) -> usize {
+        self.pending_graphics.len()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn finish_pending(&mut self, hash: u64, generation: u64, pipeline: wgpu::RenderPipeline) {
+        if generation != self.generation || !self.pending_graphics.remove(&hash) {
+            return;
+        }
+        let id = self.push_render_pipeline(pipeline);
+        self.graphics_lookup.insert(hash, id);
+    }
+
+    fn promote_fast_pending(&mut self) {
+        let graphics_lookup = &self.graphics_lookup;
+        let fast_cache = &mut self.fast_cache;
+        self.fast_pending.retain(|fast_key, (hash, _)| {
+            let Some(&id) = graphics_lookup.get(hash) else {
+                return true;
+            };
+            fast_cache.insert(*fast_key, id);
+            false
+        });
+    }
+
+    /// Generates the shader and assembles the pipeline state for a material
+    /// pipeline, without creating it.
+    #[allow(clippy::too_many_arguments)]
+    fn graphics_job(
+        &self,
+        device: &wgpu::Device,
+        shader_manager: &mut ShaderManager,
+        template_name: &str,
+        canonical_key: &GraphicsPipelineKey,
+        options: &ShaderCompilationOptions,
+        vertex_layout: &GeneratedVertexLayout,
+        gpu_material: &GpuMaterial,
+        object_bind_group: &BindGroupContext,
+        gpu_world: &GpuGlobalState,
+        screen_bind_group_layout: &wgpu::BindGroupLayout,
+    ) -> GraphicsPipelineJob {
         // Compile shader via ShaderManager
         let binding_code = format!(
             "{}\n{}\n{}",
@@ -299,9 +683,6 @@ impl PipelineCache {
             immediate_size: 0,
         });
 
-        let vertex_buffers_layout: Vec<_> =
-            vertex_layout.buffers.iter().map(|l| l.as_wgpu()).collect();
-
         let blend_state: Option<wgpu::BlendState> =
             canonical_key
                 .blend_state
@@ -325,6 +706,23 @@ impl PipelineCache {
             write_mask: wgpu::ColorWrites::ALL,
         })];
 
+        // Weighted blended OIT: additive accumulation plus a multiplicative
+        // revealage target, replacing the material's own blend state.
+        if canonical_key.flags.contains(PipelineFlags::WEIGHTED_OIT) {
+            color_targets = vec![
+                Some(wgpu::ColorTargetState {
+                    format: canonical_key.color_format,
+                    blend: Some(OIT_ACCUM_BLEND),
+                    write_mask: wgpu::ColorWrites::ALL,
+                }),
+                Some(wgpu::ColorTargetState {
+                    format: OIT_REVEALAGE_FORMAT,
+                    blend: Some(OIT_REVEALAGE_BLEND),
+                    write_mask: wgpu::ColorWrites::ALL,
+                }),
+            ];
+        }
+
         // Specular split requires a second render target for the specular output, which is appended after the main color target.
         if canonical_key.flags.contains(PipelineFlags::SPECULAR_SPLIT) {
             color_targets.push(Some(wgpu::ColorTargetState {
@@ -334,34 +732,40 @@ impl PipelineCache {
             }));
         }
 
-        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
-            label: Some("Scene Render Pipeline"),
-            layout: Some(&layout),
-            vertex: wgpu::VertexState {
-                module: shader_module,
-                entry_point: Some("vs_main"),
-                buffers: &vertex_buffers_layout,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            },
-            fragment: Some(wgpu::FragmentState {
-                module: shader_module,
-                entry_point: Some("fs_main"),
-                targets: &color_targets,
-                compilation_options: wgpu::PipelineCompilationOptions::default(),
-            }),
+        GraphicsPipelineJob {
+            module: shader_module.clone(),
+            layout,
+            vertex_buffers: vertex_layout.to_signature().buffers,
+            color_targets,
             primitive: wgpu::PrimitiveState {
                 topology: canonical_key.topology,
                 front_face: canonical_key.front_face,
                 cull_mode: canonical_key.cull_mode,
+                polygon_mode: canonical_key.polygon_mode,
                 ..Default::default()
             },
-            depth_stencil: Some(wgpu::DepthStencilState {
+            depth_stencil: wgpu::DepthStencilState {
                 format: canonical_key.depth_format,
                 depth_write_enabled: Some(canonical_key.flags.contains(PipelineFlags::DEPTH_WRITE)),
                 depth_compare: Some(canonical_key.depth_compare),
-                stencil: wgpu::StencilState::default(),
+                stencil: wgpu::StencilState {
+                    front: wgpu::StencilFaceState {
+                        compare: canonical_key.stencil.front.compare,
+                        fail_op: canonical_key.stencil.front.fail_op,
+                        depth_fail_op: canonical_key.stencil.front.depth_fail_op,
+                        pass_op: canonical_key.stencil.front.pass_op,
+                    },
+                    back: wgpu::StencilFaceState {
+                        compare: canonical_key.stencil.back.compare,
+                        fail_op: canonical_key.stencil.back.fail_op,
+                        depth_fail_op: canonical_key.stencil.back.depth_fail_op,
+                        pass_op: canonical_key.stencil.back.pass_op,
+                    },
+                    read_mask: canonical_key.stencil.read_mask,
+                    write_mask: canonical_key.stencil.write_mask,
+                },
                 bias: wgpu::DepthBiasState::default(),
-            }),
+            },
             multisample: wgpu::MultisampleState {
                 count: canonical_key.sample_count,
                 mask: !0,
@@ -369,13 +773,8 @@ impl PipelineCache {
                     .flags
                     .contains(PipelineFlags::ALPHA_TO_COVERAGE),
             },
-            multiview_mask: None,
-            cache: None,
-        });
-
-        let id = self.push_render_pipeline(pipeline);
-        self.graphics_lookup.insert(hash, id);
-        id
+            driver_cache: self.driver_cache.clone(),
+        }
     }
 
     // ── L2 Canonical: Fullscreen / Post-Process Pipeline ─────────────────────
@@ -481,7 +880,7 @@ impl PipelineCache {
                 alpha_to_coverage_enabled: canonical_key.multisample.alpha_to_coverage_enabled,
             },
             multiview_mask: None,
-            cache: None,
+            cache: self.driver_cache.as_ref(),
         });
 
         let id = self.push_render_pipeline(pipeline);
@@ -590,7 +989,7 @@ impl PipelineCache {
                 alpha_to_coverage_enabled: false,
             },
             multiview_mask: None,
-            cache: None,
+            cache: self.driver_cache.as_ref(),
         });
 
         let id = self.push_render_pipeline(pipeline);
@@ -621,7 +1020,7 @@ impl PipelineCache {
             module: shader_module,
             entry_point: Some("main"),
             compilation_options: compilation_options.clone(),
-            cache: None,
+            cache: self.driver_cache.as_ref(),
         });
 
         let id = self.push_compute_pipeline(pipeline);
@@ -0,0 +1,126 @@
+//! On-disk persistence of the driver pipeline cache.
+//!
+//! Wraps the opaque blob of a `wgpu::PipelineCache` in a small header that
+//! records which adapter and driver produced it. A file written by another
+//! GPU or driver version is ignored on load rather than handed to the
+//! driver. wgpu validates the blob itself as well and falls back to an
+//! empty cache if it does not match.
+//!
+//! Only backends exposing `wgpu::Features::PIPELINE_CACHE` (currently
+//! Vulkan) have a cache to persist. Shader modules are not persisted:
+//! WGSL generation and naga translation run again at every start, and the
+//! driver cache skips the expensive backend compilation.
+
+use std::path::Path;
+
+const MAGIC: &[u8; 8] = b"MYTHPSO1";
+
+/// Identifies the adapter and driver a cache blob is valid for.
+pub(crate) fn cache_identity(info: &wgpu::AdapterInfo) -> String {
+    format!(
+        "{:?}|{:04x}|{:04x}|{}|{}|{}",
+        info.backend, info.vendor, info.device, info.name, info.driver, info.driver_info
+    )
+}
+
+/// Prefixes `data` with the header for `identity`.
+pub(crate) fn encode(identity: &str, data: &[u8]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(MAGIC.len() + 4 + identity.len() + data.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&(identity.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(identity.as_bytes());
+    bytes.extend_from_slice(data);
+    bytes
+}
+
+/// Returns the cache blob in `bytes` if its header matches `identity`.
+pub(crate) fn decode<'a>(identity: &str, bytes: &'a [u8]) -> Option<&'a [u8]> {
+    let rest = bytes.strip_prefix(MAGIC.as_slice())?;
+    let (len, rest) = rest.split_first_chunk::<4>()?;
+    let len = u32::from_le_bytes(*len) as usize;
+    let (stored, data) = rest.split_at_checked(len)?;
+    (stored == identity.as_bytes()).then_some(data)
+}
+
+/// Reads the cache blob stored at `path` for `identity`.
+///
+/// A missing, unreadable or stale file yields `None`.
+pub(crate) fn read(path: &Path, identity: &str) -> Option<Vec<u8>> {
+    let bytes = std::fs::read(path).ok()?;
+    let data = decode(identity, &bytes);
+    if data.is_none() {
+        log::info!(
+            "Ignoring pipeline cache {}: written by another adapter or driver",
+            path.display()
+        );
+    }
+    data.map(<[u8]>::to_vec)
+}
+
+/// Writes the cache blob for `identity` to `path`, creating parent
+/// directories. The file is replaced atomically where the platform allows.
+pub(crate) fn write(path: &Path, identity: &str, data: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, encode(identity, data))?;
+    std::fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_and_rejects_other_identities() {
+        let bytes = encode("Vulkan|10de|2684|RTX|550.1|", &[1, 2, 3]);
+        assert_eq!(
+            decode("Vulkan|10de|2684|RTX|550.1|", &bytes),
+            Some([1, 2, 3].as_slice())
+        );
+        assert_eq!(decode("Vulkan|10de|2684|RTX|555.0|", &bytes), None);
+        assert_eq!(decode("Vulkan", &bytes[..10]), None);
+        assert_eq!(decode("", b"not a cache"), None);
+    }
+
+    fn adapter(driver_info: &str) -> wgpu::AdapterInfo {
+        wgpu::AdapterInfo {
+            name: "Test GPU".to_string(),
+            vendor: 0x10de,
+            device: 0x2684,
+            device_type: wgpu::DeviceType::DiscreteGpu,
+            device_pci_bus_id: String::new(),
+            driver: "test".to_string(),
+            driver_info: driver_info.to_string(),
+            backend: wgpu::Backend::Vulkan,
+            subgroup_min_size: 32,
+            subgroup_max_size: 32,
+            transient_saves_memory: false,
+        }
+    }
+
+    #[test]
+    fn file_round_trips_for_the_same_adapter_only() {
+        let dir = std::env::temp_dir().join(format!("myth_disk_cache_{}", std::process::id()));
+        let path = dir.join("nested").join("pipelines.bin");
+        let blob = [7u8; 64];
+
+        let current = cache_identity(&adapter("550.1"));
+        write(&path, &current, &blob).unwrap();
+        assert!(!path.with_extension("tmp").exists());
+        assert_eq!(read(&path, &current).as_deref(), Some(blob.as_slice()));
+
+        // A driver update invalidates the file instead of feeding it to the driver.
+        let updated = cache_identity(&adapter("555.0"));
+        assert_ne!(current, updated);
+        assert_eq!(read(&path, &updated), None);
+
+        // Truncated files and missing files are ignored too.
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::write(&path, &bytes[..MAGIC.len() + 2]).unwrap();
+        assert_eq!(read(&path, &current), None);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(read(&path, &current), None);
+    }
+}
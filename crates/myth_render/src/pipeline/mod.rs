@@ -5,6 +5,7 @@
 //! - [`PipelineCache`]: Two-level pipeline cache (L1 fast lookup + L2 canonical cache)
 //! - [`PipelineKey`]: Unique identifier for pipeline configurations
 //! - Vertex layout generation
+//! - Persisting the driver pipeline cache to disk between runs
 //! - Shader template compilation with macro preprocessing
 //!
 //! # Caching Strategy
@@ -31,17 +32,20 @@
 //! are passed to the shader compiler.
 
 pub mod cache;
+pub(crate) mod disk_cache;
 pub mod pipeline_id;
 pub mod pipeline_key;
 pub mod shader_gen;
 pub mod shader_manager;
+#[cfg(all(feature = "shader-hot-reload", not(target_arch = "wasm32")))]
+pub(crate) mod shader_watch;
 pub mod vertex;
 
-pub use cache::{FastPipelineKey, FastShadowPipelineKey, PipelineCache};
+pub use cache::{FastPipelineKey, FastShadowPipelineKey, PipelineCache, PipelineLookup};
 pub use pipeline_id::{ComputePipelineId, RenderPipelineId};
 pub use pipeline_key::{
     BlendStateKey, ColorTargetKey, ComputePipelineKey, DepthStencilKey, FullscreenPipelineKey,
-    GraphicsPipelineKey, MultisampleKey, SimpleGeometryPipelineKey,
+    GraphicsPipelineKey, MultisampleKey, SimpleGeometryPipelineKey, StencilStateKey,
 };
 pub use shader_gen::ShaderCompilationOptions;
 pub use shader_manager::{ShaderManager, ShaderSource};
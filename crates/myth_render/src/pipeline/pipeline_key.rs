@@ -181,6 +181,8 @@ bitflags! {
         const ALPHA_TO_COVERAGE   = 1 << 1;
         /// Indicates if specular is split into a separate buffer.
         const SPECULAR_SPLIT      = 1 << 2;
+        /// Renders into the weighted blended OIT accumulation targets.
+        const WEIGHTED_OIT        = 1 << 3;
     }
 }
 
@@ -198,7 +200,11 @@ pub struct GraphicsPipelineKey {
     pub topology: wgpu::PrimitiveTopology,
     pub cull_mode: Option<wgpu::Face>,
     pub front_face: wgpu::FrontFace,
+    /// `Line` for wireframes on devices with `POLYGON_MODE_LINE`.
+    pub polygon_mode: wgpu::PolygonMode,
     pub depth_compare: wgpu::CompareFunction,
+    /// Material stencil state; the default (disabled) state when unused.
+    pub stencil: StencilStateKey,
     pub blend_state: Option<BlendStateKey>,
     pub color_format: wgpu::TextureFormat,
     pub depth_format: wgpu::TextureFormat,
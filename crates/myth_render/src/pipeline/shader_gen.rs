@@ -138,16 +138,26 @@ impl ShaderGenerator {
     /// Generates WGSL from a **built-in** template registered in the shader environment.
     #[must_use]
     pub fn generate_shader(template_name: &str, options: &ShaderCompilationOptions) -> String {
+        Self::try_generate_shader(template_name, options).expect("Shader render failed")
+    }
+
+    /// Fallible variant of [`generate_shader`](Self::generate_shader).
+    ///
+    /// # Errors
+    ///
+    /// Returns the template error if the template is missing or fails to render.
+    pub fn try_generate_shader(
+        template_name: &str,
+        options: &ShaderCompilationOptions,
+    ) -> Result<String, minijinja::Error> {
         let env = get_env();
         let ctx = Self::build_context(options);
 
-        let template = env
-            .get_template(template_name)
-            .expect("Shader template not found");
-
-        let source = template.render(&ctx).expect("Shader render failed");
+        let source = env.get_template(template_name)?.render(&ctx)?;
 
-        format!("// === Auto-generated Unified Shader ===\n{source}")
+        Ok(format!(
+            "// === Auto-generated Unified Shader ===\n{source}"
+        ))
     }
 
     /// Generates WGSL from a **custom** template source string.
@@ -161,13 +171,28 @@ impl ShaderGenerator {
         template_source: &str,
         options: &ShaderCompilationOptions,
     ) -> String {
+        Self::try_generate_custom_shader(template_name, template_source, options)
+            .expect("Custom shader render failed")
+    }
+
+    /// Fallible variant of [`generate_custom_shader`](Self::generate_custom_shader).
+    ///
+    /// # Errors
+    ///
+    /// Returns the template error if the source or one of its includes fails
+    /// to render.
+    pub fn try_generate_custom_shader(
+        template_name: &str,
+        template_source: &str,
+        options: &ShaderCompilationOptions,
+    ) -> Result<String, minijinja::Error> {
         let env = get_env();
         let ctx = Self::build_context(options);
 
-        let source = env
-            .render_named_str(template_name, template_source, &ctx)
-            .expect("Custom shader render failed");
+        let source = env.render_named_str(template_name, template_source, &ctx)?;
 
-        format!("// === Auto-generated Unified Shader ===\n{source}")
+        Ok(format!(
+            "// === Auto-generated Unified Shader ===\n{source}"
+        ))
     }
 }
@@ -10,10 +10,14 @@
 
 use minijinja::value::{Object, Value};
 use minijinja::{Environment, Error, ErrorKind, syntax::SyntaxConfig};
+#[cfg(feature = "shader-hot-reload")]
+use parking_lot::RwLock;
 use rust_embed::RustEmbed;
 use rustc_hash::FxHashMap;
 use serde::Serialize;
 use std::borrow::Cow;
+#[cfg(feature = "shader-hot-reload")]
+use std::collections::hash_map::Entry;
 use std::sync::Arc;
 use std::sync::OnceLock;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -21,45 +25,118 @@ use xxhash_rust::xxh3::xxh3_128;
 
 use super::shader_gen::{ShaderCompilationOptions, ShaderGenerator};
 
+#[cfg(not(feature = "shader-hot-reload"))]
 pub static SHADER_ENV: OnceLock<Environment<'static>> = OnceLock::new();
 
+/// With hot reloading the environment can be swapped out, so it is shared
+/// through an `Arc` instead of a `'static` reference.
+#[cfg(feature = "shader-hot-reload")]
+static SHADER_ENV: OnceLock<RwLock<Arc<Environment<'static>>>> = OnceLock::new();
+
 #[derive(RustEmbed)]
 #[folder = "src/pipeline/shaders"]
 struct ShaderAssets;
 
+fn build_env() -> Environment<'static> {
+    let mut env = Environment::new();
+
+    let syntax = SyntaxConfig::builder()
+        .block_delimiters("{$", "$}")
+        .variable_delimiters("{{", "}}")
+        .line_statement_prefix("$$")
+        .build()
+        .expect("Failed to configure Jinja2 syntax");
+
+    env.set_syntax(syntax);
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.set_undefined_behavior(minijinja::UndefinedBehavior::SemiStrict);
+
+    env.set_loader(shader_loader);
+
+    env.add_function("next_loc", next_location);
+
+    env
+}
+
+/// Returns the shared shader template environment.
+#[cfg(not(feature = "shader-hot-reload"))]
 pub fn get_env() -> &'static Environment<'static> {
-    SHADER_ENV.get_or_init(|| {
-        let mut env = Environment::new();
+    SHADER_ENV.get_or_init(build_env)
+}
+
+#[cfg(feature = "shader-hot-reload")]
+fn env_slot() -> &'static RwLock<Arc<Environment<'static>>> {
+    SHADER_ENV.get_or_init(|| RwLock::new(Arc::new(build_env())))
+}
 
-        let syntax = SyntaxConfig::builder()
-            .block_delimiters("{$", "$}")
-            .variable_delimiters("{{", "}}")
-            .line_statement_prefix("$$")
-            .build()
-            .expect("Failed to configure Jinja2 syntax");
+/// Returns the shared shader template environment.
+///
+/// With `shader-hot-reload` this is a snapshot: a later
+/// [`ShaderManager::reload`] installs a new environment without affecting
+/// handles already returned.
+#[cfg(feature = "shader-hot-reload")]
+#[must_use]
+pub fn get_env() -> Arc<Environment<'static>> {
+    env_slot().read().clone()
+}
 
-        env.set_syntax(syntax);
-        env.set_trim_blocks(true);
-        env.set_lstrip_blocks(true);
-        env.set_undefined_behavior(minijinja::UndefinedBehavior::SemiStrict);
+/// Replaces the template environment with a fresh one, dropping every
+/// template the loader has cached so far.
+#[cfg(feature = "shader-hot-reload")]
+pub(crate) fn reset_env() {
+    *env_slot().write() = Arc::new(build_env());
+}
 
-        env.set_loader(shader_loader);
+/// Directory searched for templates before the embedded shader assets.
+#[cfg(feature = "shader-hot-reload")]
+static SHADER_DIR: RwLock<Option<std::path::PathBuf>> = RwLock::new(None);
 
-        env.add_function("next_loc", next_location);
+/// Sets (or clears) the directory that overrides built-in shader templates.
+///
+/// Files are looked up by template name relative to `dir`, using the same
+/// layout as `src/pipeline/shaders` (e.g. `entry/main/physical.wgsl`).
+/// Templates missing from `dir` fall back to the embedded copies.
+#[cfg(feature = "shader-hot-reload")]
+pub(crate) fn set_shader_dir(dir: Option<std::path::PathBuf>) {
+    *SHADER_DIR.write() = dir;
+}
 
-        env
+#[cfg(feature = "shader-hot-reload")]
+fn read_override(filename: &str) -> Result<Option<String>, Error> {
+    let Some(path) = SHADER_DIR.read().as_ref().map(|dir| dir.join(filename)) else {
+        return Ok(None);
+    };
+    if !path.is_file() {
+        return Ok(None);
+    }
+    std::fs::read_to_string(&path).map(Some).map_err(|e| {
+        Error::new(
+            ErrorKind::TemplateNotFound,
+            format!("Failed to read {}: {e}", path.display()),
+        )
     })
 }
 
-fn shader_loader(name: &str) -> Result<Option<String>, Error> {
-    let filename = if std::path::Path::new(name)
+/// Maps a template name to its file name (`name` or `name.wgsl`).
+fn template_filename(name: &str) -> Cow<'_, str> {
+    if std::path::Path::new(name)
         .extension()
         .is_some_and(|ext| ext.eq_ignore_ascii_case("wgsl"))
     {
         Cow::Borrowed(name)
     } else {
         Cow::Owned(format!("{name}.wgsl"))
-    };
+    }
+}
+
+fn shader_loader(name: &str) -> Result<Option<String>, Error> {
+    let filename = template_filename(name);
+
+    #[cfg(feature = "shader-hot-reload")]
+    if let Some(source) = read_override(&filename)? {
+        return Ok(Some(source));
+    }
 
     #[cfg(all(debug_assertions, not(target_arch = "wasm32")))]
     {
@@ -169,6 +246,9 @@ pub struct ShaderManager {
     module_cache: FxHashMap<u128, wgpu::ShaderModule>,
     /// User-registered custom shader templates (name → WGSL source).
     custom_templates: FxHashMap<String, String>,
+    /// (template name, options hash) → hash of the last module that compiled.
+    #[cfg(feature = "shader-hot-reload")]
+    last_good: FxHashMap<(String, u64), u128>,
 }
 
 impl Default for ShaderManager {
@@ -183,6 +263,8 @@ impl ShaderManager {
         Self {
             module_cache: FxHashMap::default(),
             custom_templates: FxHashMap::default(),
+            #[cfg(feature = "shader-hot-reload")]
+            last_good: FxHashMap::default(),
         }
     }
 
@@ -208,6 +290,10 @@ impl ShaderManager {
     /// one-off minijinja template, enabling `{$ include $}` directives even in
     /// inline sources.
     ///
+    /// With the `shader-hot-reload` feature, a template or WGSL error in a
+    /// shader that compiled before is logged and the last good module is
+    /// returned instead, so a broken edit never takes the renderer down.
+    ///
     /// Returns `(module_ref, source_hash)`.
     pub fn get_or_compile(
         &mut self,
@@ -215,12 +301,17 @@ impl ShaderManager {
         source: ShaderSource,
         options: &ShaderCompilationOptions,
     ) -> (&wgpu::ShaderModule, u128) {
-        let final_wgsl = match source {
+        let label = match source {
+            ShaderSource::File(path) => path,
+            ShaderSource::Inline { name, .. } => name,
+        };
+
+        let rendered = match source {
             ShaderSource::File(path) => {
                 if let Some(custom_src) = self.custom_templates.get(path) {
-                    ShaderGenerator::generate_custom_shader(path, custom_src, options)
+                    ShaderGenerator::try_generate_custom_shader(path, custom_src, options)
                 } else {
-                    ShaderGenerator::generate_shader(path, options)
+                    ShaderGenerator::try_generate_shader(path, options)
                 }
             }
             ShaderSource::Inline {
@@ -231,25 +322,81 @@ impl ShaderManager {
                     .custom_templates
                     .get(name)
                     .map_or(inline_src, String::as_str);
-                ShaderGenerator::generate_custom_shader(name, src, options)
+                ShaderGenerator::try_generate_custom_shader(name, src, options)
+            }
+        };
+
+        #[cfg(feature = "shader-hot-reload")]
+        let slot = (label.to_string(), options.compute_hash());
+
+        let final_wgsl = match rendered {
+            Ok(wgsl) => wgsl,
+            Err(e) => {
+                #[cfg(feature = "shader-hot-reload")]
+                if let Some(&good) = self.last_good.get(&slot) {
+                    log::error!(
+                        "Shader '{label}' failed to render, keeping last good version: {e:#}"
+                    );
+                    return (&self.module_cache[&good], good);
+                }
+                panic!("Shader render failed ({label}): {e:#}");
             }
         };
 
         let hash = xxh3_128(final_wgsl.as_bytes());
 
-        let label = match source {
-            ShaderSource::File(path) => path,
-            ShaderSource::Inline { name, .. } => name,
-        };
+        #[cfg(feature = "shader-hot-reload")]
+        {
+            if let Entry::Vacant(entry) = self.module_cache.entry(hash) {
+                match create_validated_module(device, label, final_wgsl) {
+                    Ok(module) => {
+                        entry.insert(module);
+                    }
+                    Err(msg) => {
+                        if let Some(&good) = self.last_good.get(&slot) {
+                            log::error!(
+                                "Shader '{label}' failed to compile, keeping last good version:\n{msg}"
+                            );
+                            return (&self.module_cache[&good], good);
+                        }
+                        panic!("Shader '{label}' failed to compile:\n{msg}");
+                    }
+                }
+            }
+            self.last_good.insert(slot, hash);
+            (&self.module_cache[&hash], hash)
+        }
 
-        let module = self.module_cache.entry(hash).or_insert_with(|| {
-            device.create_shader_module(wgpu::ShaderModuleDescriptor {
-                label: Some(&format!("Shader Module {label}")),
-                source: wgpu::ShaderSource::Wgsl(final_wgsl.into()),
-            })
-        });
+        #[cfg(not(feature = "shader-hot-reload"))]
+        {
+            let module = self.module_cache.entry(hash).or_insert_with(|| {
+                device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: Some(&format!("Shader Module {label}")),
+                    source: wgpu::ShaderSource::Wgsl(final_wgsl.into()),
+                })
+            });
 
-        (module, hash)
+            (module, hash)
+        }
+    }
+
+    /// Re-reads shader sources so the next compile picks up edits.
+    ///
+    /// Drops all cached templates (built-in and included chunks) and re-reads
+    /// every registered custom template that has a file of the same name in
+    /// the shader directory. Compiled modules stay cached by source hash, so
+    /// unchanged shaders are not recompiled.
+    #[cfg(feature = "shader-hot-reload")]
+    pub fn reload(&mut self) {
+        reset_env();
+
+        for (name, source) in &mut self.custom_templates {
+            match read_override(&template_filename(name)) {
+                Ok(Some(updated)) => *source = updated,
+                Ok(None) => {}
+                Err(e) => log::error!("Failed to reload custom shader '{name}': {e}"),
+            }
+        }
     }
 
     /// Returns the number of cached shader modules.
@@ -258,3 +405,25 @@ impl ShaderManager {
         self.module_cache.len()
     }
 }
+
+/// Creates a shader module, returning the validation error instead of
+/// raising it through the device's uncaptured-error handler.
+#[cfg(feature = "shader-hot-reload")]
+fn create_validated_module(
+    device: &wgpu::Device,
+    label: &str,
+    wgsl: String,
+) -> Result<wgpu::ShaderModule, String> {
+    let scope = device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some(&format!("Shader Module {label}")),
+        source: wgpu::ShaderSource::Wgsl(wgsl.into()),
+    });
+
+    // Wait for the scope to resolve: treating a pending result as success
+    // would replace the last good shader with a broken one.
+    match pollster::block_on(scope.pop()) {
+        Some(error) => Err(error.to_string()),
+        None => Ok(module),
+    }
+}
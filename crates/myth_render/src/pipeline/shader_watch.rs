@@ -0,0 +1,132 @@
+//! Shader Directory Watcher
+//!
+//! Polls a shader template directory for `.wgsl` edits so the renderer can
+//! reload shaders at runtime (`shader-hot-reload` feature, native only).
+//! Scans are throttled to [`SCAN_INTERVAL`] and a change is only reported
+//! once the directory has been quiet for [`DEBOUNCE`], so an editor's
+//! save sequence triggers a single reload.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+use rustc_hash::FxHashMap;
+
+/// Minimum time between two directory scans.
+pub const SCAN_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Quiet period required after the last observed change.
+pub const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Modification-time snapshot of every `.wgsl` file under a directory.
+pub(crate) struct ShaderWatcher {
+    dir: PathBuf,
+    stamps: FxHashMap<PathBuf, SystemTime>,
+    last_scan: Option<Instant>,
+    pending_since: Option<Instant>,
+}
+
+impl ShaderWatcher {
+    pub(crate) fn new(dir: PathBuf) -> Self {
+        let stamps = scan(&dir);
+        log::info!(
+            "Watching {} shader templates in {}",
+            stamps.len(),
+            dir.display()
+        );
+        Self {
+            dir,
+            stamps,
+            last_scan: None,
+            pending_since: None,
+        }
+    }
+
+    /// Returns `true` once a batch of edits has settled.
+    pub(crate) fn poll(&mut self, now: Instant) -> bool {
+        if self
+            .last_scan
+            .is_some_and(|last| now.duration_since(last) < SCAN_INTERVAL)
+        {
+            return false;
+        }
+        self.last_scan = Some(now);
+
+        let stamps = scan(&self.dir);
+        if stamps != self.stamps {
+            self.stamps = stamps;
+            self.pending_since = Some(now);
+            return false;
+        }
+
+        if self
+            .pending_since
+            .is_some_and(|since| now.duration_since(since) >= DEBOUNCE)
+        {
+            self.pending_since = None;
+            return true;
+        }
+        false
+    }
+}
+
+fn scan(dir: &Path) -> FxHashMap<PathBuf, SystemTime> {
+    let mut stamps = FxHashMap::default();
+    let mut stack = vec![dir.to_path_buf()];
+
+    while let Some(current) = stack.pop() {
+        let Ok(entries) = std::fs::read_dir(&current) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let Ok(meta) = entry.metadata() else {
+                continue;
+            };
+            if meta.is_dir() {
+                stack.push(path);
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("wgsl"))
+                && let Ok(modified) = meta.modified()
+            {
+                stamps.insert(path, modified);
+            }
+        }
+    }
+
+    stamps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_edit_after_debounce() {
+        let dir = std::env::temp_dir().join("myth_shader_watch_test");
+        std::fs::create_dir_all(dir.join("chunks")).unwrap();
+        let file = dir.join("chunks/test.wgsl");
+        std::fs::write(&file, "// v1").unwrap();
+
+        let start = Instant::now();
+        let mut watcher = ShaderWatcher::new(dir.clone());
+        assert!(!watcher.poll(start));
+
+        std::fs::File::options()
+            .write(true)
+            .open(&file)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(10))
+            .unwrap();
+
+        // Throttled: no scan inside the interval.
+        assert!(!watcher.poll(start + SCAN_INTERVAL / 2));
+        // Change observed, debounce starts.
+        assert!(!watcher.poll(start + SCAN_INTERVAL));
+        let settled = start + SCAN_INTERVAL * 2 + DEBOUNCE;
+        assert!(watcher.poll(settled));
+        assert!(!watcher.poll(settled + SCAN_INTERVAL));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,8 +1,9 @@
 // ── Alpha Test Function ─────────────────────────────────────────────────
 //
 // Conditionally discards fragments based on opacity threshold.
-// Include this file at module scope; call apply_alpha_test() from the
-// fragment shader body.
+// Include this file at module scope; call apply_alpha_test() (or
+// apply_alpha_hash() for ALPHA_MODE == "HASHED") from the fragment shader
+// body.
 
 $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
 
@@ -29,3 +30,30 @@ fn apply_alpha_test(opacity: ptr<function, f32>, alpha_threshold: f32) {
 }
 
 $$ endif
+
+$$ if ALPHA_MODE == "HASHED"
+
+fn alpha_hash_pcg(v: u32) -> u32 {
+    let state = v * 747796405u + 2891336453u;
+    let word = ((state >> ((state >> 28u) + 4u)) ^ state) * 277803737u;
+    return (word >> 22u) ^ word;
+}
+
+/// Hashed alpha test (Wyman & McGuire): discards the fragment when opacity
+/// is below a per-pixel random threshold, so coverage matches opacity on
+/// average without sorting. `seed` varies the pattern per frame (the TAA
+/// jitter, zero when TAA is off) for temporal accumulation to resolve.
+/// Surviving fragments are fully opaque.
+fn apply_alpha_hash(opacity: ptr<function, f32>, frag_coord: vec2<f32>, seed: vec2<f32>) {
+    let pixel = vec2<u32>(frag_coord);
+    let frame = bitcast<u32>(seed.x) ^ (bitcast<u32>(seed.y) * 0x9E3779B9u);
+    let h = alpha_hash_pcg(pixel.x + alpha_hash_pcg(pixel.y + alpha_hash_pcg(frame)));
+    let threshold = f32(h >> 8u) / 16777216.0;
+
+    if *opacity <= threshold {
+        discard;
+    }
+    *opacity = 1.0;
+}
+
+$$ endif
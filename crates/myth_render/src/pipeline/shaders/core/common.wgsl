@@ -16,6 +16,17 @@ const PI: f32 = 3.141592653589793;
 const RECIPROCAL_PI: f32 = 0.3183098861837907;
 const EPSILON = 1e-6;
 
+// Samples a material texture with the material's mip bias. Positive bias
+// blurs, negative bias sharpens (and aliases). Defining FORCED_MIP_LEVEL
+// samples that mip level instead, for debugging texture LODs.
+fn sample_material_map(t: texture_2d<f32>, s: sampler, uv: vec2<f32>, bias: f32) -> vec4<f32> {
+    $$ if FORCED_MIP_LEVEL is defined
+    return textureSampleLevel(t, s, uv, f32({{ FORCED_MIP_LEVEL }}));
+    $$ else
+    return textureSampleBias(t, s, uv, bias);
+    $$ endif
+}
+
 
 fn getDistanceAttenuation(light_distance: f32, cutoff_distance: f32, decay_exponent: f32) -> f32 {
     var distance_falloff: f32 = 1.0 / max( pow( light_distance, decay_exponent ), 0.01 );
@@ -0,0 +1,71 @@
+// ── Distance Fog ────────────────────────────────────────────────────────
+//
+// Blends a shaded color toward the fog color by view-space depth. The
+// parameters live in the environment uniforms; materials include this file
+// at module scope and call `apply_fog` (or `compute_fog` when they keep
+// diffuse and specular apart) right before packing their output, so
+// blended surfaces are fogged before they are composited. Every function
+// is a no-op unless the scene defines `FOG`.
+//
+// Fog color sources (`fog_color_source`):
+//   0 - constant `fog_color`
+//   1 - background gradient (`fog_color` top, `fog_ground_color` bottom),
+//       using the same blend as the skybox pass
+//   2 - environment cube along the view ray, matching a procedural sky or
+//       a skybox that shows the environment map
+
+/// Fog amount in [0, 1] at the given view-space depth.
+fn fog_factor(view_depth: f32) -> f32 {
+    let depth = max(view_depth, 0.0);
+    switch u_environment.fog_mode {
+        case 1u: {
+            return 1.0 - exp(-u_environment.fog_density * depth);
+        }
+        case 2u: {
+            let d = u_environment.fog_density * depth;
+            return 1.0 - exp(-d * d);
+        }
+        default: {
+            return smoothstep(u_environment.fog_near, u_environment.fog_far, depth);
+        }
+    }
+}
+
+/// Fog color seen along the world-space view direction `world_dir`.
+fn fog_color(world_dir: vec3<f32>) -> vec3<f32> {
+    switch u_environment.fog_color_source {
+        case 1u: {
+            let t = smoothstep(-0.5, 0.5, world_dir.y);
+            return mix(u_environment.fog_ground_color, u_environment.fog_color, t);
+        }
+        case 2u: {
+            let dir = u_environment.env_map_rotation * world_dir;
+            let env = textureSampleLevel(t_env_map, s_env_map, vec3<f32>(-dir.x, dir.yz), 0.0).rgb;
+            return env * u_environment.env_map_intensity;
+        }
+        default: {
+            return u_environment.fog_color;
+        }
+    }
+}
+
+/// Fog color (rgb) and amount (a) for a fragment at `world_position`.
+fn compute_fog(world_position: vec3<f32>) -> vec4<f32> {
+$$ if FOG is defined
+    let view_depth = -(u_render_state.view_matrix * vec4<f32>(world_position, 1.0)).z;
+    let world_dir = normalize(world_position - u_render_state.camera_position);
+    return vec4<f32>(fog_color(world_dir), fog_factor(view_depth));
+$$ else
+    return vec4<f32>(0.0);
+$$ endif
+}
+
+/// Returns `color` fogged for a fragment at `world_position`.
+fn apply_fog(color: vec3<f32>, world_position: vec3<f32>) -> vec3<f32> {
+$$ if FOG is defined
+    let fog = compute_fog(world_position);
+    return mix(color, fog.rgb, fog.a);
+$$ else
+    return color;
+$$ endif
+}
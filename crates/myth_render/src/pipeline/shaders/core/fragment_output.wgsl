@@ -9,12 +9,20 @@
 // `@location(1)` target for SSSS specular data.  Materials that do not
 // perform their own specular split should call `pack_fragment_output`
 // which fills extra MRT targets with safe default values.
+//
+// When `WEIGHTED_OIT` is defined the material is drawn into the weighted
+// blended OIT accumulation targets instead: `color` receives weighted,
+// premultiplied colour and `@location(1)` the fragment's revealage.
+// Shaders finish with `apply_weighted_oit`, a no-op in every other pass.
 
 struct FragmentOutput {
     @location(0) color: vec4<f32>,
 $$ if HAS_MRT_SSSS is defined
     @location(1) specular: vec4<f32>,
 $$ endif
+$$ if WEIGHTED_OIT is defined
+    @location(1) revealage: f32,
+$$ endif
 };
 
 /// Packs a single main color into a valid `FragmentOutput`.
@@ -26,6 +34,23 @@ fn pack_fragment_output(main_color: vec4<f32>) -> FragmentOutput {
     out.color = main_color;
 $$ if HAS_MRT_SSSS is defined
     out.specular = vec4<f32>(0.0);
+$$ endif
+$$ if WEIGHTED_OIT is defined
+    out.revealage = main_color.a;
 $$ endif
     return out;
 }
+
+/// Converts a straight-alpha output into weighted blended OIT form.
+///
+/// Uses the depth weight from McGuire & Bavoil (2013), equation 10, on the
+/// view depth recovered from the reverse-Z `frag_coord.z`.
+fn apply_weighted_oit(out: ptr<function, FragmentOutput>, frag_coord: vec4<f32>) {
+$$ if WEIGHTED_OIT is defined
+    let alpha = clamp((*out).color.a, 0.0, 1.0);
+    let z = u_render_state.camera_near / max(frag_coord.z, 1e-6);
+    let weight = alpha * clamp(10.0 / (1e-5 + pow(z / 5.0, 2.0) + pow(z / 200.0, 6.0)), 1e-2, 3e3);
+    (*out).color = vec4<f32>((*out).color.rgb * alpha, alpha) * weight;
+    (*out).revealage = alpha;
+$$ endif
+}
@@ -0,0 +1,37 @@
+// ── GPU-Driven Object Data ──────────────────────────────────────────────
+//
+// GPU-driven draws (`GPU_DRIVEN`) read their object data by instance rather
+// than through the dynamic offset of `u_model`.  The instance table maps
+// `instance_index` to a slot of the model buffer, which is bound a second
+// time as an array of vec4s.  Each slot holds one `DynamicModelUniforms`
+// padded to 256 bytes, i.e. 16 vec4s.
+
+$$ if GPU_DRIVEN is defined
+const OBJECT_SLOT_VEC4S: u32 = 16u;
+
+fn load_object_model(instance: u32) -> Struct_model {
+    let base = st_object_instances[instance] * OBJECT_SLOT_VEC4S;
+
+    var model: Struct_model;
+    model.world_matrix = mat4x4<f32>(
+        st_object_models[base],
+        st_object_models[base + 1u],
+        st_object_models[base + 2u],
+        st_object_models[base + 3u],
+    );
+    model.normal_matrix = mat3x3<f32>(
+        st_object_models[base + 4u].xyz,
+        st_object_models[base + 5u].xyz,
+        st_object_models[base + 6u].xyz,
+    );
+    model.previous_world_matrix = mat4x4<f32>(
+        st_object_models[base + 7u],
+        st_object_models[base + 8u],
+        st_object_models[base + 9u],
+        st_object_models[base + 10u],
+    );
+    model.object_tint = st_object_models[base + 11u];
+    model.object_params = st_object_models[base + 12u];
+    return model;
+}
+$$ endif
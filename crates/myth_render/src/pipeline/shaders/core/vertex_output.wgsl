@@ -15,15 +15,21 @@ struct VertexOutput {
     $$ if HAS_COLOR
     @location({{ loc.next() }}) color: vec4<f32>,
     $$ endif
+    $$ if HAS_BARYCENTRIC
+    @location({{ loc.next() }}) barycentric: vec3<f32>,
+    $$ endif
+    $$ if GPU_DRIVEN is defined
+    @location({{ loc.next() }}) @interpolate(flat) object_instance: u32,
+    $$ endif
 
     // *****************************************************
     //  UVs for various material maps
     // *****************************************************
-    $$ if HAS_MAP is defined
+    $$ if HAS_MAP is defined and USE_TRIPLANAR is not defined
         @location({{ loc.next() }}) map_uv: vec2<f32>,
     $$ endif
 
-    $$ if HAS_NORMAL_MAP is defined
+    $$ if HAS_NORMAL_MAP is defined and USE_TRIPLANAR is not defined
         @location({{ loc.next() }}) normal_map_uv: vec2<f32>,
     $$ endif
 
@@ -39,6 +45,10 @@ struct VertexOutput {
         @location({{ loc.next() }}) ao_map_uv: vec2<f32>,
     $$ endif
 
+    $$ if HAS_HEIGHT_MAP is defined
+        @location({{ loc.next() }}) height_map_uv: vec2<f32>,
+    $$ endif
+
     $$ if HAS_LIGHT_MAP is defined
         @location({{ loc.next() }}) light_map_uv: vec2<f32>,
     $$ endif
@@ -47,11 +57,11 @@ struct VertexOutput {
         @location({{ loc.next() }}) emissive_map_uv: vec2<f32>,
     $$ endif
 
-    $$ if HAS_METALNESS_MAP is defined
+    $$ if HAS_METALNESS_MAP is defined and USE_TRIPLANAR is not defined
         @location({{ loc.next() }}) metalness_map_uv: vec2<f32>,
     $$ endif
 
-    $$ if HAS_ROUGHNESS_MAP is defined
+    $$ if HAS_ROUGHNESS_MAP is defined and USE_TRIPLANAR is not defined
         @location({{ loc.next() }}) roughness_map_uv: vec2<f32>,
     $$ endif
 
@@ -0,0 +1,20 @@
+// ── Barycentric Wireframe ───────────────────────────────────────────────
+//
+// Fallback wireframe for devices without line polygon mode (e.g. WebGPU).
+// The geometry is de-indexed and carries a per-corner `barycentric`
+// attribute; fragments away from the triangle edges are discarded.
+// Include this file at module scope; call apply_wireframe() from the
+// fragment shader body.
+
+$$ if HAS_BARYCENTRIC
+
+/// Discards fragments more than about one pixel away from any edge.
+fn apply_wireframe(barycentric: vec3<f32>) {
+    let width = fwidth(barycentric);
+    let edge = step(width, barycentric);
+    if min(min(edge.x, edge.y), edge.z) > 0.5 {
+        discard;
+    }
+}
+
+$$ endif
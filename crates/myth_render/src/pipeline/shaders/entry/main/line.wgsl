@@ -0,0 +1,60 @@
+// ── Line Material Entry Point ────────────────────────────────────────────
+//
+// Unlit rendering for line-list / line-strip geometry: uniform color ×
+// optional per-vertex color.  No lighting, normals or textures.
+
+{{ vertex_input_code }}
+{{ binding_code }}
+{$ include 'core/vertex_output' $}
+{$ include 'core/fragment_output' $}
+
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    let world_pos = u_model.world_matrix * vec4<f32>(in.position.xyz, 1.0);
+
+    $$ if IN_TRANSPARENT_PASS is defined
+        out.position = u_render_state.unjittered_view_projection * world_pos;
+    $$ else
+        out.position = u_render_state.view_projection * world_pos;
+    $$ endif
+
+    out.world_position = world_pos.xyz / world_pos.w;
+
+    $$ if HAS_COLOR
+        out.color = in.color;
+    $$ endif
+
+    $$ if HAS_UV
+    out.uv = in.uv;
+    $$ endif
+
+    $$ if HAS_NORMAL
+    out.geometry_normal = in.normal.xyz;
+    out.normal = normalize(u_model.normal_matrix * in.normal.xyz);
+    $$ endif
+
+    return out;
+}
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> FragmentOutput {
+    var line_color = u_material.color;
+
+    $$ if HAS_COLOR
+        line_color *= in.color;
+    $$ endif
+
+    $$ if USE_OBJECT_OVERRIDE is defined
+        line_color *= u_model.object_tint;
+    $$ endif
+
+    line_color.a *= u_material.opacity;
+
+    var out = pack_fragment_output(line_color);
+    apply_weighted_oit(&out, in.position);
+    return out;
+}
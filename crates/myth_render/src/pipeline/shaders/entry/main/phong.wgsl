@@ -5,6 +5,7 @@
 
 {{ vertex_input_code }} 
 {{ binding_code }}
+{$ include 'core/object_data' $}
 {$ include 'core/vertex_output' $}
 {$ include 'core/fragment_output' $}
 
@@ -22,12 +23,25 @@
 
 {$ include 'modules/bsdf/phong' $}
 {$ include 'core/alpha_test' $}
+{$ include 'core/wireframe' $}
+{$ include 'core/fog' $}
 
 
 @vertex
-fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+fn vs_main(
+    in: VertexInput,
+    @builtin(vertex_index) vertex_index: u32,
+    $$ if GPU_DRIVEN is defined
+    @builtin(instance_index) instance_index: u32,
+    $$ endif
+) -> VertexOutput {
     var out: VertexOutput;
 
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(instance_index);
+    out.object_instance = instance_index;
+    $$ endif
+
     var local_position = vec3<f32>(in.position.xyz);
     var local_normal = vec3<f32>(in.normal.xyz);
 
@@ -90,6 +104,10 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
         out.color = in.color;
     $$ endif
 
+    $$ if HAS_BARYCENTRIC
+        out.barycentric = in.barycentric;
+    $$ endif
+
     $$ if HAS_UV
     out.uv = in.uv;
     $$ endif
@@ -109,6 +127,13 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
 
 @fragment
 fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> FragmentOutput {
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(varyings.object_instance);
+    $$ endif
+    $$ if HAS_BARYCENTRIC
+    apply_wireframe(varyings.barycentric);
+    $$ endif
+
     var normal = normalize(varyings.normal);
     $$ if FLAT_SHADING
         let u = dpdx(varyings.world_position);
@@ -124,8 +149,12 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
         diffuse_color *= varyings.color;
     $$ endif
 
+    $$ if USE_OBJECT_OVERRIDE is defined
+        diffuse_color *= u_model.object_tint;
+    $$ endif
+
     {$ if HAS_MAP $}
-        let tex_color = textureSample(t_map, s_map, varyings.map_uv);
+        let tex_color = sample_material_map(t_map, s_map, varyings.map_uv, u_material.texture_lod_bias);
         diffuse_color *= tex_color;
     {$ endif $}
 
@@ -136,6 +165,8 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     // Alpha test
     $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
     apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    apply_alpha_hash(&opacity, varyings.position.xy, u_render_state.jitter);
     $$ endif
 
     let view = normalize(u_render_state.camera_position - varyings.world_position);
@@ -144,14 +175,14 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
 
         let tbn = getTangentFrame(view, normal, varyings.normal_map_uv );
 
-        let normal_map = textureSample( t_normal_map, s_normal_map, varyings.normal_map_uv ) * 2.0 - 1.0;
+        let normal_map = sample_material_map(t_normal_map, s_normal_map, varyings.normal_map_uv, u_material.texture_lod_bias) * 2.0 - 1.0;
         let map_n = vec3f(normal_map.xy * u_material.normal_scale, normal_map.z);
         normal = normalize(tbn * map_n);
     $$ endif
 
 
     $$ if HAS_SPECULAR_MAP is defined
-        let specular_map = textureSample( t_specular_map, s_specular_map, varyings.specular_map_uv );
+        let specular_map = sample_material_map(t_specular_map, s_specular_map, varyings.specular_map_uv, u_material.texture_lod_bias);
         let specular_strength = specular_map.r;
     $$ else
         let specular_strength = 1.0;
@@ -173,7 +204,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     var irradiance = getAmbientLightIrradiance( ambient_color );
 
     $$ if HAS_LIGHT_MAP is defined
-        let light_map_color = textureSample(t_light_map, s_light_map, varyings.light_map_uv ).rgb;
+        let light_map_color = sample_material_map(t_light_map, s_light_map, varyings.light_map_uv, u_material.texture_lod_bias).rgb;
         irradiance += light_map_color * u_material.light_map_intensity;
     $$ endif
 
@@ -182,7 +213,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     // Ambient occlusion
     $$ if HAS_AO_MAP is defined
         let ao_map_intensity = u_material.ao_map_intensity;
-        let ambient_occlusion = ( textureSample( t_ao_map, s_ao_map, varyings.ao_map_uv ).r - 1.0 ) * ao_map_intensity + 1.0;
+        let ambient_occlusion = ( sample_material_map(t_ao_map, s_ao_map, varyings.ao_map_uv, u_material.texture_lod_bias).r - 1.0 ) * ao_map_intensity + 1.0;
         reflected_light.indirect_diffuse *= ambient_occlusion;
     $$ endif
 
@@ -190,9 +221,13 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
 
     var emissive_color = u_material.emissive.rgb * u_material.emissive_intensity;
     $$ if HAS_EMISSIVE_MAP is defined
-        emissive_color *= textureSample(t_emissive_map, s_emissive_map, varyings.emissive_map_uv).rgb;
+        emissive_color *= sample_material_map(t_emissive_map, s_emissive_map, varyings.emissive_map_uv, u_material.texture_lod_bias).rgb;
     $$ endif
     out_color += emissive_color;
 
-    return pack_fragment_output(vec4<f32>(out_color, diffuse_color.a));
+    out_color = apply_fog(out_color, varyings.world_position);
+
+    var out = pack_fragment_output(vec4<f32>(out_color, diffuse_color.a));
+    apply_weighted_oit(&out, varyings.position);
+    return out;
 }
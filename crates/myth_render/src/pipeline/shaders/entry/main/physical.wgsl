@@ -2,15 +2,19 @@
 //
 // Forward-rendered physically-based material with Cook-Torrance GGX BRDF.
 // Supports: IBL, transmission, clearcoat, iridescence, sheen, anisotropy,
-// SSAO integration, debug view overrides, and MRT specular split (SSSS).
+// tri-planar mapping, SSAO integration, debug view overrides, and MRT
+// specular split (SSSS).
 
 {{ vertex_input_code }} 
 {{ binding_code }}
+{$ include 'core/object_data' $}
 {$ include 'core/vertex_output' $}
 {$ include 'core/fragment_output' $}
 
 {$ include 'modules/geometry/morphing' $}
 {$ include 'modules/geometry/skinning' $}
+{$ include 'modules/geometry/triplanar' $}
+{$ include 'modules/geometry/parallax' $}
 {$ include 'core/common' $}
 {$ include 'modules/lighting/punctual' $}
 {$ include 'modules/bsdf/physical' $}
@@ -22,6 +26,8 @@ $$ if USE_TRANSMISSION is defined
 $$ endif
 
 {$ include 'core/alpha_test' $}
+{$ include 'core/wireframe' $}
+{$ include 'core/fog' $}
 {$ include 'modules/bsdf/pbr_tone_mapping' $}
 
 // ── Screen / Transient BindGroup (Group 3) ──────────────────────────
@@ -36,9 +42,20 @@ $$ endif
 @group(3) @binding(5) var s_shadow_map_compare: sampler_comparison;
 
 @vertex
-fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+fn vs_main(
+    in: VertexInput,
+    @builtin(vertex_index) vertex_index: u32,
+    $$ if GPU_DRIVEN is defined
+    @builtin(instance_index) instance_index: u32,
+    $$ endif
+) -> VertexOutput {
     var out: VertexOutput;
 
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(instance_index);
+    out.object_instance = instance_index;
+    $$ endif
+
     var local_position = vec3<f32>(in.position.xyz);
 
     $$ if HAS_NORMAL is defined
@@ -108,6 +125,10 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
     out.color = in.color;
     $$ endif
 
+    $$ if HAS_BARYCENTRIC
+    out.barycentric = in.barycentric;
+    $$ endif
+
     $$ if HAS_UV
     out.uv = in.uv;
     $$ endif
@@ -131,7 +152,19 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
 
 
 @fragment
+$$ if USE_PARALLAX is defined and HAS_HEIGHT_MAP is defined and USE_TRIPLANAR is not defined
+fn fs_main(in_varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> FragmentOutput {
+    // Parallax shifts the texture coordinates below.
+    var varyings = in_varyings;
+$$ else
 fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> FragmentOutput {
+$$ endif
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(varyings.object_instance);
+    $$ endif
+    $$ if HAS_BARYCENTRIC
+    apply_wireframe(varyings.barycentric);
+    $$ endif
 
     let face_direction = f32(is_front) * 2.0 - 1.0;
 
@@ -144,14 +177,100 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
         surface_normal = surface_normal * face_direction;
     $$ endif
 
+    $$ if USE_TRIPLANAR is defined
+        let triplanar_pos = varyings.world_position * u_material.triplanar_scale;
+        let triplanar_w = triplanar_weights(surface_normal);
+    $$ endif
+
+    // Parallax occlusion mapping: march the height field along the view ray
+    // and move every UV set by the offset found in height-map space.
+    $$ if USE_PARALLAX is defined and HAS_HEIGHT_MAP is defined and USE_TRIPLANAR is not defined
+        $$ if HAS_TANGENT is defined
+            let parallax_tbn = mat3x3f(normalize(varyings.v_tangent), normalize(varyings.v_bitangent), surface_normal);
+        $$ else
+            let parallax_tbn = getTangentFrame(varyings.world_position, surface_normal, varyings.height_map_uv);
+        $$ endif
+        let parallax_view = normalize(u_render_state.camera_position - varyings.world_position);
+        // The bitangent column points along -v (glTF convention).
+        let parallax_view_ts = normalize(vec3<f32>(
+            dot(parallax_view, parallax_tbn[0]),
+            -dot(parallax_view, parallax_tbn[1]),
+            dot(parallax_view, parallax_tbn[2]),
+        ));
+        let parallax_uv = parallax_occlusion_uv(t_height_map, s_height_map, varyings.height_map_uv, parallax_view_ts, u_material.parallax_scale, u_material.parallax_steps);
+        let parallax_offset = parallax_uv - varyings.height_map_uv;
+        $$ if HAS_MAP is defined
+            varyings.map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_NORMAL_MAP is defined
+            varyings.normal_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_SPECULAR_MAP is defined
+            varyings.specular_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_SPECULAR_INTENSITY_MAP is defined
+            varyings.specular_intensity_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_AO_MAP is defined
+            varyings.ao_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_EMISSIVE_MAP is defined
+            varyings.emissive_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_METALNESS_MAP is defined
+            varyings.metalness_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_ROUGHNESS_MAP is defined
+            varyings.roughness_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_CLEARCOAT_MAP is defined
+            varyings.clearcoat_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_CLEARCOAT_NORMAL_MAP is defined
+            varyings.clearcoat_normal_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_CLEARCOAT_ROUGHNESS_MAP is defined
+            varyings.clearcoat_roughness_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_IRIDESCENCE_MAP is defined
+            varyings.iridescence_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_IRIDESCENCE_THICKNESS_MAP is defined
+            varyings.iridescence_thickness_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_ANISOTROPY_MAP is defined
+            varyings.anisotropy_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_SHEEN_COLOR_MAP is defined
+            varyings.sheen_color_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_SHEEN_ROUGHNESS_MAP is defined
+            varyings.sheen_roughness_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_TRANSMISSION_MAP is defined
+            varyings.transmission_map_uv += parallax_offset;
+        $$ endif
+        $$ if HAS_THICKNESS_MAP is defined
+            varyings.thickness_map_uv += parallax_offset;
+        $$ endif
+    $$ endif
+
     var diffuse_color = u_material.color;
 
     $$ if HAS_COLOR
         diffuse_color *= varyings.color;
     $$ endif
 
+    $$ if USE_OBJECT_OVERRIDE is defined
+        diffuse_color *= u_model.object_tint;
+    $$ endif
+
     $$ if HAS_MAP
-        let tex_color = textureSample(t_map, s_map, varyings.map_uv);
+        $$ if USE_TRIPLANAR is defined
+            let tex_color = triplanar_sample(t_map, s_map, triplanar_pos, triplanar_w);
+        $$ else
+            let tex_color = sample_material_map(t_map, s_map, varyings.map_uv, u_material.texture_lod_bias);
+        $$ endif
         diffuse_color *= tex_color;
     $$ endif
 
@@ -161,20 +280,24 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     // Alpha test
     $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
     apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    apply_alpha_hash(&opacity, varyings.position.xy, u_render_state.jitter);
     $$ endif
 
     let view = normalize(u_render_state.camera_position - varyings.world_position);
 
-    $$ if HAS_NORMAL_MAP is defined or USE_ANISOTROPY is defined
+    $$ if (HAS_NORMAL_MAP is defined and USE_TRIPLANAR is not defined) or USE_ANISOTROPY is defined
         $$ if HAS_TANGENT is defined
             var tbn = mat3x3f(normalize(varyings.v_tangent), normalize(varyings.v_bitangent), surface_normal);
         $$ else
-            $$ if HAS_NORMAL_MAP is defined
+            $$ if HAS_NORMAL_MAP is defined and USE_TRIPLANAR is not defined
                 let n_uv = varyings.normal_map_uv; 
             $$ elif HAS_CLEARCOAT_NORMAL_MAP is defined
                 let n_uv = varyings.clearcoat_normal_map_uv;
             $$ elif HAS_MAP_UV is defined
                 let n_uv = varyings.map_uv;
+            $$ elif USE_TRIPLANAR is defined
+                let n_uv = triplanar_pos.xz;
             $$ else
                 let n_uv = varyings.uv;
             $$ endif
@@ -185,8 +308,10 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
         tbn[1] = tbn[1] * face_direction;
     $$ endif
 
-    $$ if HAS_NORMAL_MAP is defined
-        let normal_map = textureSample( t_normal_map, s_normal_map, varyings.normal_map_uv ) * 2.0 - 1.0;
+    $$ if HAS_NORMAL_MAP is defined and USE_TRIPLANAR is defined
+        let normal = triplanar_normal(t_normal_map, s_normal_map, triplanar_pos, surface_normal, triplanar_w, u_material.normal_scale);
+    $$ elif HAS_NORMAL_MAP is defined
+        let normal_map = sample_material_map(t_normal_map, s_normal_map, varyings.normal_map_uv, u_material.texture_lod_bias) * 2.0 - 1.0;
         let map_n = vec3f(normal_map.xy * u_material.normal_scale, normal_map.z);
         let normal = normalize(tbn * map_n);
     $$ else
@@ -204,7 +329,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
             tbn_cc[0] = tbn_cc[0] * face_direction;
             tbn_cc[1] = tbn_cc[1] * face_direction;
 
-            var clearcoat_normal_map = textureSample(t_clearcoat_normal_map, s_clearcoat_normal_map, varyings.clearcoat_normal_map_uv ) * 2.0 - 1.0;
+            var clearcoat_normal_map = sample_material_map(t_clearcoat_normal_map, s_clearcoat_normal_map, varyings.clearcoat_normal_map_uv, u_material.texture_lod_bias) * 2.0 - 1.0;
             let clearcoat_map_n = vec3f(clearcoat_normal_map.xy * u_material.clearcoat_normal_scale, clearcoat_normal_map.z);
             let clearcoat_normal = normalize(tbn_cc * clearcoat_map_n);
         $$ else
@@ -213,7 +338,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     $$ endif
 
     $$ if HAS_SPECULAR_MAP is defined
-        let specular_map = textureSample( t_specular_map, s_specular_map, varyings.specular_map_uv );
+        let specular_map = sample_material_map(t_specular_map, s_specular_map, varyings.specular_map_uv, u_material.texture_lod_bias);
         let specular_strength = specular_map.r;
     $$ else
         let specular_strength = 1.0;
@@ -250,7 +375,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     var irradiance = getAmbientLightIrradiance( ambient_color );
 
     $$ if HAS_LIGHT_MAP is defined
-        let light_map_color = textureSample(t_light_map, s_light_map, varyings.light_map_uv ).rgb;
+        let light_map_color = sample_material_map(t_light_map, s_light_map, varyings.light_map_uv, u_material.texture_lod_bias).rgb;
         irradiance += light_map_color * u_material.light_map_intensity;
     $$ endif
 
@@ -258,31 +383,30 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
 
     $$ if USE_IBL is defined
 
-        let s = sin(u_environment.env_map_rotation);
-        let c = cos(u_environment.env_map_rotation);
-
-        let ibl_rotated_view = vec3<f32>(
-            view.x * c - view.z * s,
-            view.y,
-            view.x * s + view.z * c
-        );
+        // Lookups happen in the environment map's frame; rotating every
+        // direction keeps reflections consistent with the rotated skybox.
+        let ibl_rotation = u_environment.env_map_rotation;
+        let ibl_rotated_view = ibl_rotation * view;
+        let ibl_rotated_normal = ibl_rotation * normal;
+        ibl_probe_weight = getReflectionProbeWeight( varyings.world_position );
+        // Baked probes replace the environment's irradiance; they are
+        // captured in world space, so they take the unrotated normal.
+        light_probe_weight = getLightProbeWeight( varyings.world_position );
+        if ( light_probe_weight > 0.0 ) {
+            light_probe_irradiance = getLightProbeIrradiance( varyings.world_position, normal );
+        }
 
         $$ if USE_ANISOTROPY is defined
-            let ibl_radiance = getIBLAnisotropyRadiance( ibl_rotated_view, normal, material.roughness, material.anisotropy_b, material.anisotropy );
+            let ibl_radiance = getIBLAnisotropyRadiance( ibl_rotated_view, ibl_rotated_normal, material.roughness, ibl_rotation * material.anisotropy_b, material.anisotropy );
         $$ else
-            let ibl_radiance = getIBLRadiance( ibl_rotated_view, normal, material.roughness);
+            let ibl_radiance = getIBLRadiance( ibl_rotated_view, ibl_rotated_normal, material.roughness);
         $$ endif
 
         var clearcoat_ibl_radiance = vec3<f32>(0.0);
         $$ if USE_CLEARCOAT is defined
-            clearcoat_ibl_radiance += getIBLRadiance( ibl_rotated_view, clearcoat_normal, material.clearcoat_roughness );
+            clearcoat_ibl_radiance += getIBLRadiance( ibl_rotated_view, ibl_rotation * clearcoat_normal, material.clearcoat_roughness );
         $$ endif
 
-        let ibl_rotated_normal = vec3<f32>(
-            normal.x * c - normal.z * s,
-            normal.y,
-            normal.x * s + normal.z * c
-        );
         let ibl_irradiance = getIBLIrradiance( ibl_rotated_normal );
         RE_IndirectSpecular(ibl_radiance, ibl_irradiance, clearcoat_ibl_radiance, geometry, material, &reflected_light);
     $$ endif
@@ -301,7 +425,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
 
     $$ if HAS_AO_MAP is defined
         let ao_map_intensity = u_material.ao_map_intensity;
-        let material_ao = ( textureSample( t_ao_map, s_ao_map, varyings.ao_map_uv ).r - 1.0 ) * ao_map_intensity + 1.0;
+        let material_ao = ( sample_material_map(t_ao_map, s_ao_map, varyings.ao_map_uv, u_material.texture_lod_bias).r - 1.0 ) * ao_map_intensity + 1.0;
         ambient_occlusion *= material_ao;
     $$ endif
 
@@ -355,7 +479,7 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
     // Emissive
     var emissive_color = u_material.emissive.rgb * u_material.emissive_intensity;
     $$ if HAS_EMISSIVE_MAP is defined
-        emissive_color *= textureSample(t_emissive_map, s_emissive_map, varyings.emissive_map_uv).rgb;
+        emissive_color *= sample_material_map(t_emissive_map, s_emissive_map, varyings.emissive_map_uv, u_material.texture_lod_bias).rgb;
     $$ endif
     out_diffuse += emissive_color;
 
@@ -385,6 +509,14 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
         opacity *= material.transmission_alpha;
     $$ endif
 
+    // Distance fog: the diffuse part takes the fog color and the specular
+    // part fades out, so the SSSS split still sums to the fogged color.
+    $$ if FOG is defined
+        let fog = compute_fog(varyings.world_position);
+        out_diffuse = mix(out_diffuse, fog.rgb, fog.a);
+        out_specular *= 1.0 - fog.a;
+    $$ endif
+
     // Output
     var out: FragmentOutput;
 
@@ -409,5 +541,10 @@ fn fs_main(varyings: VertexOutput, @builtin(front_facing) is_front: bool) -> Fra
         out.color = vec4<f32>(out_color, opacity);
     $$ endif
 
+    $$ if WEIGHTED_OIT is defined
+        out.revealage = opacity;
+    $$ endif
+    apply_weighted_oit(&out, varyings.position);
+
     return out;
 }
@@ -0,0 +1,98 @@
+// ── Points Material Entry Point ──────────────────────────────────────────
+//
+// Point sprites: every vertex of the geometry is an instance, expanded here
+// into a screen-aligned quad (6 vertices, triangle list).  WebGPU has no
+// point size, so this replaces `gl_PointSize`.
+
+{{ vertex_input_code }}
+{{ binding_code }}
+{$ include 'core/fragment_output' $}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) sprite_coord: vec2<f32>,
+    $$ if HAS_COLOR
+    @location(1) color: vec4<f32>,
+    $$ endif
+};
+
+@vertex
+fn vs_main(in: VertexInput, @builtin(vertex_index) corner_index: u32) -> VertexOutput {
+    var out: VertexOutput;
+
+    var corners = array<vec2<f32>, 6>(
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, -1.0),
+        vec2<f32>(1.0, 1.0),
+        vec2<f32>(-1.0, 1.0),
+    );
+    let corner = corners[corner_index];
+    let world_pos = u_model.world_matrix * vec4<f32>(in.position.xyz, 1.0);
+
+    $$ if IN_TRANSPARENT_PASS is defined
+        var clip = u_render_state.unjittered_view_projection * world_pos;
+    $$ else
+        var clip = u_render_state.view_projection * world_pos;
+    $$ endif
+
+    var size = u_material.size;
+    $$ if HAS_SIZE
+        // Per-point size attribute (e.g. particles) scales the material size.
+        size *= in.size;
+    $$ endif
+
+    var offset: vec2<f32>;
+    if (u_material.size_attenuation != 0u) {
+        // World-space size: project the half-extent so it shrinks with depth.
+        let proj = u_render_state.projection_matrix;
+        offset = corner * 0.5 * size * vec2<f32>(proj[0][0], proj[1][1]);
+    } else {
+        // Pixel size: constant in NDC, so pre-multiply by w.
+        let viewport = max(u_render_state.viewport, vec2<f32>(1.0));
+        offset = corner * size / viewport * clip.w;
+    }
+    clip = vec4<f32>(clip.xy + offset, clip.zw);
+
+    out.position = clip;
+    out.sprite_coord = corner;
+
+    $$ if HAS_COLOR
+        out.color = in.color;
+    $$ endif
+
+    return out;
+}
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> FragmentOutput {
+    var point_color = u_material.color;
+
+    $$ if HAS_COLOR
+        point_color *= in.color;
+    $$ endif
+
+    $$ if USE_OBJECT_OVERRIDE is defined
+        point_color *= u_model.object_tint;
+    $$ endif
+
+    point_color.a *= u_material.opacity;
+
+    if (u_material.round_sprite != 0u) {
+        let dist = length(in.sprite_coord);
+        $$ if ALPHA_MODE == "BLEND"
+            let aa = max(fwidth(dist), 0.0001);
+            point_color.a *= 1.0 - smoothstep(1.0 - aa, 1.0, dist);
+        $$ else
+            if (dist > 1.0) {
+                discard;
+            }
+        $$ endif
+    }
+
+    var out = pack_fragment_output(point_color);
+    apply_weighted_oit(&out, in.position);
+    return out;
+}
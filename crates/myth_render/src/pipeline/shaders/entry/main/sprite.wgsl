@@ -0,0 +1,89 @@
+// ── Sprite Material Entry Point ──────────────────────────────────────────
+//
+// Unlit textured quad.  The quad is shifted so that the material's `center`
+// pivot lands on the node origin.  Without size attenuation the quad is
+// drawn screen-aligned at the node origin with a constant pixel size
+// instead of going through the model matrix.
+
+{{ vertex_input_code }}
+{{ binding_code }}
+{$ include 'core/vertex_output' $}
+{$ include 'core/fragment_output' $}
+
+{$ include 'core/alpha_test' $}
+
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    $$ if IN_TRANSPARENT_PASS is defined
+        let view_projection = u_render_state.unjittered_view_projection;
+    $$ else
+        let view_projection = u_render_state.view_projection;
+    $$ endif
+
+    // Quad-local position relative to the pivot.
+    let corner = in.position.xy + vec2<f32>(0.5) - u_material.center;
+
+    var world_pos: vec4<f32>;
+    var clip: vec4<f32>;
+    if (u_material.size_attenuation != 0u) {
+        world_pos = u_model.world_matrix * vec4<f32>(corner, in.position.z, 1.0);
+        clip = view_projection * world_pos;
+    } else {
+        // Pixel size: constant in NDC, so pre-multiply by w.
+        world_pos = u_model.world_matrix * vec4<f32>(0.0, 0.0, 0.0, 1.0);
+        clip = view_projection * world_pos;
+        let viewport = max(u_render_state.viewport, vec2<f32>(1.0));
+        let offset = corner * 2.0 * u_material.pixel_size / viewport * clip.w;
+        clip = vec4<f32>(clip.xy + offset, clip.zw);
+    }
+
+    out.position = clip;
+    out.world_position = world_pos.xyz / world_pos.w;
+
+    $$ if HAS_COLOR
+        out.color = in.color;
+    $$ endif
+
+    $$ if HAS_UV
+    out.uv = in.uv;
+    $$ endif
+
+    {$ include 'mixins/uv_vertex' $}
+    return out;
+}
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> FragmentOutput {
+    var sprite_color = u_material.color;
+
+    $$ if HAS_COLOR
+        sprite_color *= in.color;
+    $$ endif
+
+    $$ if USE_OBJECT_OVERRIDE is defined
+        sprite_color *= u_model.object_tint;
+    $$ endif
+
+    {$ if HAS_MAP $}
+    sprite_color *= textureSample(t_map, s_map, in.map_uv);
+    {$ endif $}
+
+    sprite_color.a *= u_material.opacity;
+
+    $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
+    var opacity = sprite_color.a;
+    apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    var opacity = sprite_color.a;
+    apply_alpha_hash(&opacity, in.position.xy, u_render_state.jitter);
+    sprite_color.a = opacity;
+    $$ endif
+
+    var out = pack_fragment_output(sprite_color);
+    apply_weighted_oit(&out, in.position);
+    return out;
+}
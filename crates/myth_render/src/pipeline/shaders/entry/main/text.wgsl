@@ -0,0 +1,100 @@
+// ── Text Material Entry Point ────────────────────────────────────────────
+//
+// Signed-distance-field glyph quads.  The map stores distance to the glyph
+// edge (0.5 on the edge, larger inside), so coverage is a smoothstep
+// around 0.5 whose width follows the screen-space derivative.  An outline
+// band sits between `0.5 - outline_width` and the edge.  Without size
+// attenuation the quads are drawn screen-aligned at the node origin, one
+// text unit spanning `pixel_scale` pixels.
+
+{{ vertex_input_code }}
+{{ binding_code }}
+{$ include 'core/vertex_output' $}
+{$ include 'core/fragment_output' $}
+
+{$ include 'core/alpha_test' $}
+
+
+@vertex
+fn vs_main(in: VertexInput) -> VertexOutput {
+    var out: VertexOutput;
+
+    $$ if IN_TRANSPARENT_PASS is defined
+        let view_projection = u_render_state.unjittered_view_projection;
+    $$ else
+        let view_projection = u_render_state.view_projection;
+    $$ endif
+
+    var world_pos: vec4<f32>;
+    var clip: vec4<f32>;
+    if (u_material.size_attenuation != 0u) {
+        world_pos = u_model.world_matrix * vec4<f32>(in.position, 1.0);
+        clip = view_projection * world_pos;
+    } else {
+        // Pixel size: constant in NDC, so pre-multiply by w.
+        world_pos = u_model.world_matrix * vec4<f32>(0.0, 0.0, 0.0, 1.0);
+        clip = view_projection * world_pos;
+        let viewport = max(u_render_state.viewport, vec2<f32>(1.0));
+        let offset = in.position.xy * 2.0 * u_material.pixel_scale / viewport * clip.w;
+        clip = vec4<f32>(clip.xy + offset, clip.zw);
+    }
+
+    out.position = clip;
+    out.world_position = world_pos.xyz / world_pos.w;
+
+    $$ if HAS_COLOR
+        out.color = in.color;
+    $$ endif
+
+    $$ if HAS_UV
+    out.uv = in.uv;
+    $$ endif
+
+    {$ include 'mixins/uv_vertex' $}
+    return out;
+}
+
+
+@fragment
+fn fs_main(in: VertexOutput) -> FragmentOutput {
+    var fill = u_material.color;
+
+    $$ if HAS_COLOR
+        fill *= in.color;
+    $$ endif
+
+    $$ if USE_OBJECT_OVERRIDE is defined
+        fill *= u_model.object_tint;
+    $$ endif
+
+    {$ if HAS_MAP $}
+    let sdf = textureSample(t_map, s_map, in.map_uv).r;
+    {$ else $}
+    let sdf = 1.0;
+    {$ endif $}
+
+    let aa = max(fwidth(sdf) * 0.5, 1e-4) + u_material.softness;
+    let fill_coverage = smoothstep(0.5 - aa, 0.5 + aa, sdf);
+
+    var text_color = fill;
+    var coverage = fill_coverage;
+    if (u_material.outline_width > 0.0) {
+        let edge = 0.5 - u_material.outline_width;
+        coverage = smoothstep(edge - aa, edge + aa, sdf);
+        text_color = mix(u_material.outline_color, fill, fill_coverage);
+    }
+    text_color.a *= coverage * u_material.opacity;
+
+    $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
+    var opacity = text_color.a;
+    apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    var opacity = text_color.a;
+    apply_alpha_hash(&opacity, in.position.xy, u_render_state.jitter);
+    text_color.a = opacity;
+    $$ endif
+
+    var out = pack_fragment_output(text_color);
+    apply_weighted_oit(&out, in.position);
+    return out;
+}
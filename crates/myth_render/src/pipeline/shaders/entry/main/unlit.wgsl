@@ -5,18 +5,32 @@
 
 {{ vertex_input_code }} 
 {{ binding_code }}      
+{$ include 'core/object_data' $}
 {$ include 'core/vertex_output' $}
 {$ include 'core/fragment_output' $}
 
 {$ include 'modules/geometry/morphing' $}
 {$ include 'modules/geometry/skinning' $}
 {$ include 'core/alpha_test' $}
+{$ include 'core/wireframe' $}
+{$ include 'core/fog' $}
 
 
 @vertex
-fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+fn vs_main(
+    in: VertexInput,
+    @builtin(vertex_index) vertex_index: u32,
+    $$ if GPU_DRIVEN is defined
+    @builtin(instance_index) instance_index: u32,
+    $$ endif
+) -> VertexOutput {
     var out: VertexOutput;
 
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(instance_index);
+    out.object_instance = instance_index;
+    $$ endif
+
     var local_position = vec3<f32>(in.position.xyz);
 
     $$ if HAS_NORMAL
@@ -70,6 +84,10 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
         out.color = in.color;
     $$ endif
 
+    $$ if HAS_BARYCENTRIC
+        out.barycentric = in.barycentric;
+    $$ endif
+
     $$ if HAS_UV
     out.uv = in.uv;
     $$ endif
@@ -86,7 +104,17 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
 
 @fragment
 fn fs_main(in: VertexOutput) -> FragmentOutput {
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(in.object_instance);
+    $$ endif
+    $$ if HAS_BARYCENTRIC
+    apply_wireframe(in.barycentric);
+    $$ endif
+
     var diffuse_color = u_material.color;
+    $$ if USE_OBJECT_OVERRIDE is defined
+        diffuse_color *= u_model.object_tint;
+    $$ endif
     {$ if HAS_MAP $}
     let tex_color = textureSample(t_map, s_map, in.map_uv);
     diffuse_color = diffuse_color * tex_color;
@@ -95,7 +123,15 @@ fn fs_main(in: VertexOutput) -> FragmentOutput {
     $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
     var opacity = diffuse_color.a;
     apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    var opacity = diffuse_color.a;
+    apply_alpha_hash(&opacity, in.position.xy, u_render_state.jitter);
+    diffuse_color.a = opacity;
     $$ endif
 
-    return pack_fragment_output(diffuse_color);
+    diffuse_color = vec4<f32>(apply_fog(diffuse_color.rgb, in.world_position), diffuse_color.a);
+
+    var out = pack_fragment_output(diffuse_color);
+    apply_weighted_oit(&out, in.position);
+    return out;
 }
@@ -0,0 +1,34 @@
+// ============================================================================
+// Auto Exposure - shared definitions
+// ============================================================================
+//
+// Scene EVs are log2 of the luminance. The histogram spans
+// [min_ev, min_ev + ev_range]: bin 0 collects (near-)black pixels, which
+// carry no exposure information, and bins 1..255 split the range evenly.
+
+const HISTOGRAM_BINS: u32 = 256u;
+const MIDDLE_GREY: f32 = 0.18;
+
+struct AutoExposureParams {
+    min_ev: f32,
+    ev_range: f32,
+    speed_up: f32,
+    speed_down: f32,
+    delta_time: f32,
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+};
+
+struct ExposureState {
+    ev: f32,
+    exposure: f32,
+    initialized: u32,
+    _pad: u32,
+};
+
+@group(0) @binding(0)
+var<uniform> params: AutoExposureParams;
+
+@group(0) @binding(1)
+var<storage, read_write> histogram: array<atomic<u32>, HISTOGRAM_BINS>;
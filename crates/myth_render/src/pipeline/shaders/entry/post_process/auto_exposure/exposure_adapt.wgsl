@@ -0,0 +1,60 @@
+// ============================================================================
+// Auto Exposure - Adaptation
+// ============================================================================
+//
+// Reduces the histogram to the mean scene EV (the geometric mean
+// luminance), eases the persistent adapted EV toward it and clears the
+// histogram for the next frame. Mirrors `AutoExposure::{target_ev, adapt,
+// exposure_for_ev}` on the CPU.
+//
+// Dispatch: (1, 1, 1) workgroup
+
+{$ include "entry/post_process/auto_exposure/auto_exposure_common" $}
+
+@group(0) @binding(2)
+var<storage, read_write> state: ExposureState;
+
+var<workgroup> counts: array<f32, HISTOGRAM_BINS>;
+var<workgroup> weighted: array<f32, HISTOGRAM_BINS>;
+
+@compute @workgroup_size(256, 1, 1)
+fn main(@builtin(local_invocation_index) local_index: u32) {
+    let count = f32(atomicLoad(&histogram[local_index]));
+    atomicStore(&histogram[local_index], 0u);
+
+    // Black pixels (bin 0) are left out of the average.
+    let bin_count = select(count, 0.0, local_index == 0u);
+    counts[local_index] = bin_count;
+    weighted[local_index] = bin_count * f32(local_index);
+    workgroupBarrier();
+
+    for (var stride = HISTOGRAM_BINS / 2u; stride > 0u; stride = stride >> 1u) {
+        if (local_index < stride) {
+            counts[local_index] += counts[local_index + stride];
+            weighted[local_index] += weighted[local_index + stride];
+        }
+        workgroupBarrier();
+    }
+
+    if (local_index != 0u || counts[0] < 1.0) {
+        // An all-black frame holds the current exposure.
+        return;
+    }
+
+    // Bin b covers [(b - 1) / 254, b / 254) of the EV range.
+    let mean_bin = weighted[0] / counts[0];
+    let t = clamp((mean_bin - 0.5) / 254.0, 0.0, 1.0);
+    let target_ev = params.min_ev + t * params.ev_range;
+
+    var ev = target_ev;
+    if (state.initialized != 0u) {
+        // Exponential approach: frame-rate independent, never overshoots.
+        let speed = select(params.speed_down, params.speed_up, target_ev > state.ev);
+        let k = 1.0 - exp(-max(params.delta_time, 0.0) * max(speed, 0.0));
+        ev = state.ev + (target_ev - state.ev) * k;
+    }
+
+    state.ev = ev;
+    state.exposure = MIDDLE_GREY / exp2(ev);
+    state.initialized = 1u;
+}
@@ -0,0 +1,46 @@
+// ============================================================================
+// Auto Exposure - Luminance Histogram
+// ============================================================================
+//
+// Bins the log luminance of every HDR scene pixel. Each workgroup counts
+// into shared memory first and flushes one atomic per bin, which keeps
+// contention on the global histogram low.
+//
+// Dispatch: (width / 16, height / 16, 1) workgroups
+
+{$ include "entry/post_process/auto_exposure/auto_exposure_common" $}
+
+@group(0) @binding(2)
+var scene_color: texture_2d<f32>;
+
+var<workgroup> local_bins: array<atomic<u32>, HISTOGRAM_BINS>;
+
+fn luminance_bin(luminance: f32) -> u32 {
+    if (luminance < 1e-5) {
+        return 0u;
+    }
+    let t = clamp((log2(luminance) - params.min_ev) / params.ev_range, 0.0, 1.0);
+    return min(u32(t * 254.0) + 1u, HISTOGRAM_BINS - 1u);
+}
+
+@compute @workgroup_size(16, 16, 1)
+fn main(
+    @builtin(global_invocation_id) global_id: vec3<u32>,
+    @builtin(local_invocation_index) local_index: u32,
+) {
+    atomicStore(&local_bins[local_index], 0u);
+    workgroupBarrier();
+
+    let size = textureDimensions(scene_color);
+    if (global_id.x < size.x && global_id.y < size.y) {
+        let color = textureLoad(scene_color, vec2<i32>(global_id.xy), 0).rgb;
+        let luminance = max(dot(color, vec3<f32>(0.2126, 0.7152, 0.0722)), 0.0);
+        atomicAdd(&local_bins[luminance_bin(luminance)], 1u);
+    }
+    workgroupBarrier();
+
+    let count = atomicLoad(&local_bins[local_index]);
+    if (count > 0u) {
+        atomicAdd(&histogram[local_index], count);
+    }
+}
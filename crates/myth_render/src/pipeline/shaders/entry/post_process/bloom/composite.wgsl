@@ -1,7 +1,8 @@
 // Bloom Composite Pass
 //
 // Blends the accumulated bloom result (from mip 0 of the bloom chain)
-// with the original HDR scene color using linear interpolation.
+// with the original HDR scene color using linear interpolation. The lens
+// dirt mask (black when unset) brightens the bloom where it is dirty.
 // Output replaces the scene color buffer for subsequent passes (e.g., tone mapping).
 
 {$ include 'core/full_screen_vertex' $}
@@ -11,6 +12,7 @@
 // Group 0: Persistent feature resources (Feature-owned, long-lived)
 @group(0) @binding(0) var tex_sampler: sampler;
 @group(0) @binding(1) var<uniform> u_bloom: CompositeUniforms;
+@group(0) @binding(2) var dirt_texture: texture_2d<f32>;
 
 // Group 1: Transient RDG textures (PassNode-owned, per-frame)
 @group(1) @binding(0) var original_texture: texture_2d<f32>;
@@ -22,8 +24,10 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let original = textureSampleLevel(original_texture, tex_sampler, uv, 0.0);
     let bloom = textureSampleLevel(bloom_texture, tex_sampler, uv, 0.0);
 
-    // Additive blend: original + bloom × strength
-    let result = original.rgb + bloom.rgb * u_bloom.bloom_strength;
+    let dirt = textureSampleLevel(dirt_texture, tex_sampler, uv, 0.0).rgb;
+
+    // Additive blend: original + bloom × (strength + dirt × dirt_intensity)
+    let result = original.rgb + bloom.rgb * (u_bloom.bloom_strength + dirt * u_bloom.dirt_intensity);
 
     // The original LearnOpenGL text(https://learnopengl.com/Guest-Articles/2022/Phys.-Based-Bloom) uses "mix", 
     // but I believe "additive" seems to be more appropriate. 
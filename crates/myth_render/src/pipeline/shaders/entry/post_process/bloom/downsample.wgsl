@@ -3,6 +3,8 @@
 // Implements the progressive downsample from "Next Generation Post Processing
 // in Call of Duty: Advanced Warfare". The 13-tap sampling pattern minimizes
 // aliasing while the optional Karis average on the first mip suppresses fireflies.
+// The PREFILTER variant (first mip only) also applies the luminance threshold
+// with a quadratic soft knee and multiplies the result by the bloom tint.
 
 {$ include 'core/full_screen_vertex' $}
 
@@ -11,6 +13,9 @@
 // Group 0: Persistent feature resources (Feature-owned, long-lived)
 @group(0) @binding(0) var src_sampler: sampler;
 @group(0) @binding(1) var<uniform> u_bloom: DownsampleUniforms;
+$$ if PREFILTER
+@group(0) @binding(2) var<uniform> u_prefilter: PrefilterUniforms;
+$$ endif
 
 // Group 1: Transient RDG textures (PassNode-owned, per-frame)
 @group(1) @binding(0) var src_texture: texture_2d<f32>;
@@ -23,6 +28,17 @@ fn karis_weight(color: vec3<f32>) -> f32 {
     return 1.0 / (1.0 + rgb_to_luminance(color));
 }
 
+$$ if PREFILTER
+fn prefilter(color: vec3<f32>) -> vec3<f32> {
+    let luminance = rgb_to_luminance(color);
+    let knee = u_prefilter.knee;
+    var soft = clamp(luminance - u_prefilter.threshold + knee, 0.0, 2.0 * knee);
+    soft = soft * soft / (4.0 * knee + 1e-5);
+    let contribution = max(soft, luminance - u_prefilter.threshold) / max(luminance, 1e-5);
+    return color * contribution * u_prefilter.tint;
+}
+$$ endif
+
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     let uv = in.uv;
@@ -83,5 +99,9 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     }
 
     result = max(result, vec3<f32>(0.0));
+
+    $$ if PREFILTER
+    result = prefilter(result);
+    $$ endif
     return vec4<f32>(result, 1.0);
 }
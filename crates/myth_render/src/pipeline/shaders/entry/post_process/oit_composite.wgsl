@@ -0,0 +1,27 @@
+// Weighted blended OIT composite.
+//
+// Resolves the accumulation targets written by the weighted OIT pass into
+// a single straight-alpha colour, blended over the scene with standard
+// alpha blending (`1 - revealage` is the total coverage).
+
+{$ include 'core/full_screen_vertex' $}
+
+@group(0) @binding(0) var t_accum: texture_2d<f32>;
+@group(0) @binding(1) var t_revealage: texture_2d<f32>;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let coord = vec2<i32>(in.position.xy);
+    let revealage = textureLoad(t_revealage, coord, 0).r;
+
+    // Nothing blended here.
+    if (revealage >= 1.0) {
+        discard;
+    }
+
+    // Half-float accumulation can overflow under heavy overdraw.
+    let accum = min(textureLoad(t_accum, coord, 0), vec4<f32>(65000.0));
+    let average = accum.rgb / max(accum.a, 1e-5);
+
+    return vec4<f32>(average, 1.0 - revealage);
+}
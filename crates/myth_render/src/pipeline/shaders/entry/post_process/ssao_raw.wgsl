@@ -118,7 +118,8 @@ fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
     var occlusion = 1.0 - (occlusion_sum / f32(u_ssao.sample_count));
 
     occlusion = max(occlusion, 0.0);
-    let ao = pow(occlusion, u_ssao.intensity);
+    // pow(0, 0) is undefined, so intensity 0 is handled explicitly.
+    let ao = select(pow(occlusion, u_ssao.intensity), 1.0, u_ssao.intensity <= 0.0);
 
     return vec4<f32>(ao, ao, ao, 1.0);
 }
\ No newline at end of file
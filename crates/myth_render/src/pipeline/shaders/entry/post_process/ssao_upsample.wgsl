@@ -0,0 +1,73 @@
+{$ include 'core/full_screen_vertex' $}
+
+// --- Group 0: Joint Bilateral Upsample Inputs ---
+// Same layout as the blur pass: low-resolution AO, full-resolution
+// depth and normals.
+@group(0) @binding(0) var t_ao: texture_2d<f32>;
+@group(0) @binding(1) var t_depth: texture_depth_2d;
+@group(0) @binding(2) var t_normal: texture_2d<f32>;
+@group(0) @binding(3) var s_linear: sampler;
+@group(0) @binding(4) var s_point: sampler;
+
+// Relative depth difference at which a tap's weight halves. With reverse-Z
+// the depth is proportional to 1/z, so the relative difference of two depth
+// values is also the relative difference of their view distances.
+const DEPTH_TOLERANCE: f32 = 0.02;
+
+@fragment
+fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
+    let uv = in.uv;
+
+    let center_depth = textureSampleLevel(t_depth, s_point, uv, 0u);
+    let center_normal_packed = textureSampleLevel(t_normal, s_linear, uv, 0.0);
+
+    // If no geometry (skybox), return full-lit
+    if (center_normal_packed.a < 0.5 || center_depth <= 0.0) {
+        return vec4<f32>(1.0);
+    }
+
+    let center_normal = normalize(center_normal_packed.xyz * 2.0 - 1.0);
+
+    // The four low-resolution texels surrounding this pixel, weighted
+    // bilinearly and by their similarity to the full-resolution centre.
+    let low_size = vec2<i32>(textureDimensions(t_ao, 0));
+    let low_pos = uv * vec2<f32>(low_size) - 0.5;
+    let base = vec2<i32>(floor(low_pos));
+    let f = fract(low_pos);
+
+    var ao_sum: f32 = 0.0;
+    var weight_sum: f32 = 0.0;
+
+    for (var y: i32 = 0; y <= 1; y++) {
+        for (var x: i32 = 0; x <= 1; x++) {
+            let texel = clamp(base + vec2<i32>(x, y), vec2<i32>(0), low_size - 1);
+            let tap_uv = (vec2<f32>(texel) + 0.5) / vec2<f32>(low_size);
+
+            let tap_depth = textureSampleLevel(t_depth, s_point, tap_uv, 0u);
+            let tap_normal_packed = textureSampleLevel(t_normal, s_point, tap_uv, 0.0);
+
+            // Skip background texels
+            if (tap_normal_packed.a < 0.5 || tap_depth <= 0.0) {
+                continue;
+            }
+
+            let bilinear = select(1.0 - f.x, f.x, x == 1) * select(1.0 - f.y, f.y, y == 1);
+
+            let relative_depth = abs(center_depth - tap_depth) / max(center_depth, tap_depth);
+            let depth_weight = 1.0 / (1.0 + relative_depth / DEPTH_TOLERANCE);
+
+            let tap_normal = normalize(tap_normal_packed.xyz * 2.0 - 1.0);
+            let normal_weight = pow(max(dot(center_normal, tap_normal), 0.0), 8.0);
+
+            let w = bilinear * depth_weight * normal_weight;
+            ao_sum += textureLoad(t_ao, texel, 0).r * w;
+            weight_sum += w;
+        }
+    }
+
+    // No similar neighbour (thin features): fall back to plain bilinear
+    let fallback = textureSampleLevel(t_ao, s_linear, uv, 0.0).r;
+    let ao = select(fallback, ao_sum / weight_sum, weight_sum > 0.0001);
+
+    return vec4<f32>(ao, ao, ao, 1.0);
+}
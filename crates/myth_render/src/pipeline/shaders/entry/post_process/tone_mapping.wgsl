@@ -47,7 +47,7 @@ fn fs_main(varyings: VertexOutput) -> @location(0) vec4<f32> {
 
 
     // 2. Apply tone mapping to RGB channels
-    var rgb = toneMapping(color_sample.rgb * u_effect.exposure);
+    var rgb = toneMapping(color_sample.rgb * u_effect.exposure * u_effect.auto_exposure);
 
     // 3. Color Grading (3D LUT) - macro-guarded
 $$ if USE_LUT is defined
@@ -79,8 +79,10 @@ $$ endif
 
     // 5. Vignette (edge darkening) - controlled via uniform, no macro needed
     if (u_effect.vignette_intensity > 0.001) {
-        // compute a radial mask that peaks at the center and falls off towards edges
-        var v = uv.x * uv.y * (1.0 - uv.x) * (1.0 - uv.y) * 16.0;
+        // compute a radial mask that peaks at the center and falls off towards edges,
+        // scaled around the center by the radius
+        let vuv = clamp((uv - 0.5) / max(u_effect.vignette_radius, 1e-3) + 0.5, vec2<f32>(0.0), vec2<f32>(1.0));
+        var v = vuv.x * vuv.y * (1.0 - vuv.x) * (1.0 - vuv.y) * 16.0;
 
         // map smoothness to parabola exponent, where higher smoothness means a wider, softer highlight area
         let power = mix(1.0, 0.1, u_effect.vignette_smoothness);
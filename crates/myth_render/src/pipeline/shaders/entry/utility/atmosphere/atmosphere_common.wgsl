@@ -27,6 +27,9 @@ struct AtmosphereParams {
 
     sun_direction: vec3<f32>,
     _pad1: f32,
+
+    ground_albedo: vec3<f32>,
+    _pad2: f32,
 };
 
 @group(0) @binding(0)
@@ -69,3 +72,10 @@ fn hg_phase(cos_theta: f32, g: f32) -> f32 {
     let denom = 1.0 + g2 - 2.0 * g * cos_theta;
     return (1.0 - g2) / (4.0 * PI * denom * sqrt(denom));
 }
+
+// Lambertian sunlight bounced off the planet surface at the end of a ray.
+fn ground_bounce_luminance(ground_pos: vec3<f32>, sun_dir: vec3<f32>, ground_transmittance: vec3<f32>) -> vec3<f32> {
+    let ground_up = normalize(ground_pos);
+    let n_dot_l = saturate(dot(ground_up, sun_dir));
+    return ground_transmittance * n_dot_l * atmo.ground_albedo / PI;
+}
@@ -51,7 +51,8 @@ fn integrate_scattered_luminance(
     }
 
     let ground_isect = ray_sphere_intersect(ray_origin, ray_dir, atmo.planet_radius);
-    if ground_isect.x > 0.0 {
+    let hits_ground = ground_isect.x > 0.0;
+    if hits_ground {
         t_max = ground_isect.x;
     }
 
@@ -77,6 +78,12 @@ fn integrate_scattered_luminance(
         throughput *= sample_optical;
     }
 
+    if hits_ground {
+        let ground_pos = ray_origin + ray_dir * t_max;
+        let ground_transmittance = sample_transmittance_lut(0.0, dot(normalize(ground_pos), sun_dir));
+        luminance += ground_bounce_luminance(ground_pos, sun_dir, ground_transmittance) * throughput;
+    }
+
     return luminance;
 }
 
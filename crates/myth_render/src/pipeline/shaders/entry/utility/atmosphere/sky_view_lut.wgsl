@@ -60,7 +60,8 @@ fn compute_sky_luminance(
     }
 
     let ground_isect = ray_sphere_intersect(ray_origin, ray_dir, atmo.planet_radius);
-    if ground_isect.x > 0.0 {
+    let hits_ground = ground_isect.x > 0.0;
+    if hits_ground {
         t_max = ground_isect.x;
     }
 
@@ -104,6 +105,13 @@ fn compute_sky_luminance(
         throughput *= sample_optical;
     }
 
+    if hits_ground {
+        let ground_pos = ray_origin + ray_dir * t_max;
+        let ground_transmittance = sample_transmittance_lut(0.0, dot(normalize(ground_pos), sun_dir));
+        luminance += ground_bounce_luminance(ground_pos, sun_dir, ground_transmittance)
+            * throughput * atmo.sun_intensity;
+    }
+
     return luminance;
 }
 
@@ -0,0 +1,31 @@
+// === Debug Lines Shader ===
+//
+// Draws the immediate-mode debug lines collected by `Scene::debug()`.
+// Vertices are world-space positions with an unlit RGBA colour; the
+// line list is uploaded fresh every frame.
+
+// Auto-injected global bind group bindings (Group 0: camera, environment, etc.)
+{{ binding_code }}
+
+struct DebugVertexInput {
+    @location(0) position: vec3<f32>,
+    @location(1) color: vec4<f32>,
+};
+
+struct DebugVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+};
+
+@vertex
+fn vs_main(in: DebugVertexInput) -> DebugVertexOutput {
+    var out: DebugVertexOutput;
+    out.position = u_render_state.view_projection * vec4<f32>(in.position, 1.0);
+    out.color = in.color;
+    return out;
+}
+
+@fragment
+fn fs_main(in: DebugVertexOutput) -> @location(0) vec4<f32> {
+    return in.color;
+}
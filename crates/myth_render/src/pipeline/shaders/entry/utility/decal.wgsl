@@ -0,0 +1,83 @@
+// === Decal Projection Shader ===
+//
+// Draws one projector box per instance (the unit cube around the decal
+// node). Only back faces are kept, so each covered pixel is shaded once
+// even with the camera inside the box. The fragment reads the prepass
+// depth, rebuilds the world position, maps it into the box and blends the
+// decal texture where it lies inside. Surfaces turned away from the
+// projection direction fade out instead of smearing the texture.
+
+// Auto-generated struct definition for DecalUniforms
+{{ struct_definitions }}
+
+// Auto-injected global bind group bindings (Group 0: camera, environment, etc.)
+{{ binding_code }}
+
+@group(1) @binding(0) var t_depth: texture_depth_2d;
+
+@group(2) @binding(0) var<storage, read> u_decals: array<DecalUniforms>;
+@group(2) @binding(1) var t_decal: texture_2d<f32>;
+@group(2) @binding(2) var s_decal: sampler;
+
+// 12 triangles, indexing the corners of the unit cube (bit 0 = x, 1 = y, 2 = z).
+const BOX_INDICES = array<u32, 36>(
+    0u, 2u, 1u, 1u, 2u, 3u, // -Z
+    4u, 5u, 6u, 5u, 7u, 6u, // +Z
+    0u, 1u, 4u, 1u, 5u, 4u, // -Y
+    2u, 6u, 3u, 3u, 6u, 7u, // +Y
+    0u, 4u, 2u, 2u, 4u, 6u, // -X
+    1u, 3u, 5u, 3u, 7u, 5u, // +X
+);
+
+struct DecalVertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) @interpolate(flat) instance: u32,
+};
+
+@vertex
+fn vs_main(
+    @builtin(vertex_index) vertex_index: u32,
+    @builtin(instance_index) instance_index: u32,
+) -> DecalVertexOutput {
+    let corner = BOX_INDICES[vertex_index];
+    let local = vec3<f32>(f32(corner & 1u), f32((corner >> 1u) & 1u), f32((corner >> 2u) & 1u)) - 0.5;
+    let world_position = u_decals[instance_index].world_matrix * vec4<f32>(local, 1.0);
+
+    var out: DecalVertexOutput;
+    out.position = u_render_state.view_projection * world_position;
+    out.instance = instance_index;
+    return out;
+}
+
+@fragment
+fn fs_main(in: DecalVertexOutput, @builtin(front_facing) front_facing: bool) -> @location(0) vec4<f32> {
+    let decal = u_decals[in.instance];
+
+    let depth = textureLoad(t_depth, vec2<i32>(in.position.xy), 0);
+    let uv = in.position.xy / max(u_render_state.viewport, vec2<f32>(1.0));
+    let ndc = vec2<f32>(uv.x * 2.0 - 1.0, 1.0 - uv.y * 2.0);
+    let world_h = u_render_state.view_projection_inverse * vec4<f32>(ndc, depth, 1.0);
+    let world_position = world_h.xyz / world_h.w;
+
+    // Surface normal from the reconstructed positions, turned toward the
+    // camera. Derivatives and the texture sample come before any discard.
+    var normal = normalize(cross(dpdx(world_position), dpdy(world_position)));
+    if (dot(normal, u_render_state.camera_position - world_position) < 0.0) {
+        normal = -normal;
+    }
+
+    let local = (decal.world_matrix_inverse * vec4<f32>(world_position, 1.0)).xyz;
+    let decal_uv = vec2<f32>(local.x + 0.5, 0.5 - local.y);
+    let texel = textureSample(t_decal, s_decal, decal_uv);
+
+    // Reverse-Z: 0.0 is the far plane, where nothing was drawn.
+    if (front_facing || depth <= 0.0 || any(abs(local) > vec3<f32>(0.5))) {
+        discard;
+    }
+
+    let facing = dot(normal, -decal.direction);
+    let fade = smoothstep(decal.cos_max_angle, mix(decal.cos_max_angle, 1.0, 0.25), facing);
+
+    let color = texel * decal.color;
+    return vec4<f32>(color.rgb, color.a * fade);
+}
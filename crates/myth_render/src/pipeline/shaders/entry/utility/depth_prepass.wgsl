@@ -5,8 +5,10 @@
 
 {{ vertex_input_code }}
 {{ binding_code }}
+{$ include 'core/object_data' $}
 {$ include 'modules/geometry/morphing' $}
 {$ include 'modules/geometry/skinning' $}
+{$ include 'modules/geometry/triplanar' $}
 {$ include 'core/alpha_test' $}
 
 struct VertexOutput {
@@ -14,6 +16,9 @@ struct VertexOutput {
     $$ if HAS_UV
     @location({{ loc.next() }}) uv: vec2<f32>,
     $$ endif
+    $$ if (HAS_MAP and USE_TRIPLANAR is defined) or (OUTPUT_NORMAL and FLAT_SHADING)
+    @location({{ loc.next() }}) world_position: vec3<f32>,
+    $$ endif
     $$ if OUTPUT_NORMAL and HAS_NORMAL
     @location({{ loc.next() }}) world_normal: vec3<f32>,
     $$ endif
@@ -24,9 +29,19 @@ struct VertexOutput {
 };
 
 @vertex
-fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexOutput {
+fn vs_main(
+    in: VertexInput,
+    @builtin(vertex_index) vertex_index: u32,
+    $$ if GPU_DRIVEN is defined
+    @builtin(instance_index) instance_index: u32,
+    $$ endif
+) -> VertexOutput {
     var out: VertexOutput;
 
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(instance_index);
+    $$ endif
+
     var local_position = vec3<f32>(in.position.xyz);
 
     $$ if HAS_VELOCITY_TARGET is defined
@@ -103,6 +118,10 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
     out.uv = in.uv;
     $$ endif
 
+    $$ if (HAS_MAP and USE_TRIPLANAR is defined) or (OUTPUT_NORMAL and FLAT_SHADING)
+    out.world_position = world_pos.xyz / world_pos.w;
+    $$ endif
+
     $$ if OUTPUT_NORMAL and HAS_NORMAL
     out.world_normal = normalize(u_model.normal_matrix * local_normal);
     $$ endif
@@ -110,6 +129,32 @@ fn vs_main(in: VertexInput, @builtin(vertex_index) vertex_index: u32) -> VertexO
     return out;
 }
 
+// Alpha of the color map, for alpha-tested materials.
+$$ if HAS_MAP
+fn map_alpha(varyings: VertexOutput) -> f32 {
+    $$ if USE_TRIPLANAR is defined
+    let p = varyings.world_position;
+    let n = normalize(cross(dpdx(p), dpdy(p)));
+    let weights = triplanar_weights(n);
+    return triplanar_sample(t_map, s_map, p * u_material.triplanar_scale, weights).a;
+    $$ else
+    return textureSample(t_map, s_map, varyings.uv).a;
+    $$ endif
+}
+$$ endif
+
+$$ if ALPHA_MODE == "HASHED"
+// Shadow maps keep a fixed pattern; camera passes follow the TAA jitter so
+// the prepass discards exactly what the main pass does.
+fn alpha_hash_seed() -> vec2<f32> {
+    $$ if SHADOW_PASS
+    return vec2<f32>(0.0);
+    $$ else
+    return u_render_state.jitter;
+    $$ endif
+}
+$$ endif
+
 $$ if OUTPUT_NORMAL
 
 struct FragmentOutput {
@@ -133,17 +178,23 @@ fn fs_main(varyings: VertexOutput) -> FragmentOutput {
     var opacity = u_material.opacity;
 
     $$ if HAS_MAP
-    let tex_color = textureSample(t_map, s_map, varyings.uv);
-    opacity *= tex_color.a;
+    opacity *= map_alpha(varyings);
     $$ endif
 
     $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
     apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    apply_alpha_hash(&opacity, varyings.position.xy, alpha_hash_seed());
     $$ endif
 
     var out: FragmentOutput;
 
-    $$ if HAS_NORMAL
+    $$ if FLAT_SHADING
+    let p = varyings.world_position;
+    let flat_normal = normalize(cross(dpdx(p), dpdy(p)));
+    let view_normal = normalize((u_render_state.view_matrix * vec4<f32>(flat_normal, 0.0)).xyz);
+    out.normal = vec4<f32>(view_normal * 0.5 + 0.5, 1.0);
+    $$ elif HAS_NORMAL
     let view_normal = normalize((u_render_state.view_matrix * vec4<f32>(varyings.world_normal, 0.0)).xyz);
     out.normal = vec4<f32>(view_normal * 0.5 + 0.5, 1.0);
     $$ else
@@ -174,12 +225,13 @@ fn fs_main(varyings: VertexOutput) -> FragmentOutput {
     var opacity = u_material.opacity;
 
     $$ if HAS_MAP
-    let tex_color = textureSample(t_map, s_map, varyings.uv);
-    opacity *= tex_color.a;
+    opacity *= map_alpha(varyings);
     $$ endif
 
     $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
     apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    apply_alpha_hash(&opacity, varyings.position.xy, alpha_hash_seed());
     $$ endif
 
     var out: FragmentOutput;
@@ -196,12 +248,13 @@ fn fs_main(varyings: VertexOutput) {
     var opacity = u_material.opacity;
 
     $$ if HAS_MAP
-    let tex_color = textureSample(t_map, s_map, varyings.uv);
-    opacity *= tex_color.a;
+    opacity *= map_alpha(varyings);
     $$ endif
 
     $$ if ALPHA_MODE == "MASK" or ALPHA_MODE == "BLEND_MASK"
     apply_alpha_test(&opacity, u_material.alpha_test);
+    $$ elif ALPHA_MODE == "HASHED"
+    apply_alpha_hash(&opacity, varyings.position.xy, alpha_hash_seed());
     $$ endif
 }
 
@@ -0,0 +1,78 @@
+// === GPU Frustum Culling ===
+//
+// One thread per indirect draw. Each draw references the world-space
+// bounding sphere of its object; draws whose sphere lies fully outside a
+// frustum plane get their instance count zeroed. All other indirect
+// arguments are written by the CPU before dispatch.
+//
+// Draws merged into a batch also copy the arguments of each survivor to the
+// next free slot of the batch's range in `compact_args`, tightly packed for
+// multi-draw, and count them in `batch_counts`. Both are zeroed before
+// dispatch, so slots past the survivors draw nothing.
+//
+// Visible objects are also counted once each (several draws, e.g. prepass
+// and opaque, may share an object) for the survivor readback.
+
+struct CullParams {
+    // Left, Right, Bottom, Top, Near, Far as (normal, distance).
+    // Zero planes are disabled (e.g. the infinite far plane).
+    planes: array<vec4<f32>, 6>,
+    draw_count: u32,
+};
+
+@group(0) @binding(0) var<uniform> u_params: CullParams;
+@group(0) @binding(1) var<storage, read> spheres: array<vec4<f32>>;
+// Per draw: object, batch (NO_BATCH if unmerged), first word of the batch,
+// words per draw.
+@group(0) @binding(2) var<storage, read> draws: array<vec4<u32>>;
+@group(0) @binding(3) var<storage, read_write> draw_args: array<u32>;
+@group(0) @binding(4) var<storage, read_write> visible_objects: array<atomic<u32>>;
+@group(0) @binding(5) var<storage, read_write> survivor_count: atomic<u32>;
+@group(0) @binding(6) var<storage, read_write> compact_args: array<u32>;
+@group(0) @binding(7) var<storage, read_write> batch_counts: array<atomic<u32>>;
+
+// Indirect draw stride in u32s (DrawIndexedIndirectArgs; plain draws use
+// the first four words).
+const ARGS_STRIDE: u32 = 5u;
+
+const NO_BATCH: u32 = 0xffffffffu;
+
+fn is_visible(sphere: vec4<f32>) -> bool {
+    for (var i = 0u; i < 6u; i = i + 1u) {
+        let plane = u_params.planes[i];
+        if (all(plane.xyz == vec3<f32>(0.0))) {
+            continue;
+        }
+        if (dot(plane.xyz, sphere.xyz) + plane.w < -sphere.w) {
+            return false;
+        }
+    }
+    return true;
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) id: vec3<u32>) {
+    let index = id.x;
+    if (index >= u_params.draw_count) {
+        return;
+    }
+
+    let draw = draws[index];
+    let object = draw.x;
+    if (!is_visible(spheres[object])) {
+        draw_args[index * ARGS_STRIDE + 1u] = 0u;
+        return;
+    }
+
+    if (draw.y != NO_BATCH) {
+        let slot = atomicAdd(&batch_counts[draw.y], 1u);
+        let dst = draw.z + slot * draw.w;
+        for (var i = 0u; i < draw.w; i = i + 1u) {
+            compact_args[dst + i] = draw_args[index * ARGS_STRIDE + i];
+        }
+    }
+
+    if (atomicExchange(&visible_objects[object], 1u) == 0u) {
+        atomicAdd(&survivor_count, 1u);
+    }
+}
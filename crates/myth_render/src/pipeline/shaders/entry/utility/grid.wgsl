@@ -0,0 +1,70 @@
+// === Ground Grid Shader ===
+//
+// Draws an infinite anti-aliased grid on the horizontal plane shown with
+// `Scene::show_grid()`. Each pixel of a fullscreen triangle reconstructs
+// its view ray from the inverse view-projection matrix, intersects it with
+// the plane, and writes the hit point's depth so that opaque geometry
+// occludes the grid through the regular depth test.
+
+{$ include 'core/full_screen_vertex' $}
+
+// Auto-generated struct definition for GridUniforms
+{{ struct_definitions }}
+
+// Auto-injected global bind group bindings (Group 0: camera, environment, etc.)
+{{ binding_code }}
+
+@group(1) @binding(0) var<uniform> u_grid: GridUniforms;
+
+struct GridOutput {
+    @location(0) color: vec4<f32>,
+    @builtin(frag_depth) depth: f32,
+};
+
+fn unproject(ndc: vec2<f32>, z: f32) -> vec3<f32> {
+    let world_h = u_render_state.view_projection_inverse * vec4<f32>(ndc, z, 1.0);
+    return world_h.xyz / world_h.w;
+}
+
+// Coverage of lines spaced `spacing` apart, with a constant pixel width.
+// Lines fade out before their cells shrink below a few pixels to avoid moiré.
+fn grid_coverage(coord: vec2<f32>, spacing: f32) -> f32 {
+    let cell = coord / spacing;
+    let pixel = max(fwidth(cell), vec2<f32>(1e-6));
+    let dist = abs(fract(cell - 0.5) - 0.5) / pixel;
+    let line = min(dist.x, dist.y);
+    let coverage = clamp(0.5 * u_grid.line_width + 0.5 - line, 0.0, 1.0);
+    return coverage * (1.0 - smoothstep(0.15, 0.3, max(pixel.x, pixel.y)));
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> GridOutput {
+    let ndc = vec2<f32>(in.uv.x * 2.0 - 1.0, 1.0 - in.uv.y * 2.0);
+
+    // Reverse-Z: 1.0 is the near plane, and 0.5 stays finite even with an
+    // infinite far plane. Works for perspective and orthographic cameras.
+    let ray_origin = unproject(ndc, 1.0);
+    let ray_dir = unproject(ndc, 0.5) - ray_origin;
+    let t = (u_grid.height - ray_origin.y) / ray_dir.y;
+    let hit = ray_origin + ray_dir * t;
+
+    // Derivatives must be taken before any discard.
+    let minor_alpha = u_grid.minor_color.a * grid_coverage(hit.xz, u_grid.spacing);
+    let major_alpha = u_grid.major_color.a * grid_coverage(hit.xz, u_grid.major_spacing);
+
+    let dist = distance(hit.xz, u_render_state.camera_position.xz);
+    let fade = 1.0 - smoothstep(0.0, u_grid.fade_distance, dist);
+
+    let alpha = max(minor_alpha, major_alpha) * fade;
+    if (t <= 0.0 || alpha <= 1.0 / 255.0) {
+        discard;
+    }
+
+    let rgb = select(u_grid.minor_color.rgb, u_grid.major_color.rgb, major_alpha >= minor_alpha);
+    let clip = u_render_state.view_projection * vec4<f32>(hit, 1.0);
+
+    var out: GridOutput;
+    out.color = vec4<f32>(rgb, alpha);
+    out.depth = clip.z / clip.w;
+    return out;
+}
@@ -0,0 +1,59 @@
+// === Ground Shadow Shader ===
+//
+// Darkens a soft ellipse on the horizontal plane set with
+// `Scene::set_ground_shadow()`, under the bounds of the scene's shadow
+// casters. Each pixel of a fullscreen triangle intersects its view ray with
+// the plane and writes the hit point's depth, so geometry in front of the
+// plane hides the shadow through the regular depth test.
+
+{$ include 'core/full_screen_vertex' $}
+
+// Auto-generated struct definition for GroundShadowUniforms
+{{ struct_definitions }}
+
+// Auto-injected global bind group bindings (Group 0: camera, environment, etc.)
+{{ binding_code }}
+
+@group(1) @binding(0) var<uniform> u_shadow: GroundShadowUniforms;
+
+struct GroundShadowOutput {
+    @location(0) color: vec4<f32>,
+    @builtin(frag_depth) depth: f32,
+};
+
+fn unproject(ndc: vec2<f32>, z: f32) -> vec3<f32> {
+    let world_h = u_render_state.view_projection_inverse * vec4<f32>(ndc, z, 1.0);
+    return world_h.xyz / world_h.w;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> GroundShadowOutput {
+    let ndc = vec2<f32>(in.uv.x * 2.0 - 1.0, 1.0 - in.uv.y * 2.0);
+
+    // Reverse-Z: 1.0 is the near plane, and 0.5 stays finite even with an
+    // infinite far plane. Works for perspective and orthographic cameras.
+    let ray_origin = unproject(ndc, 1.0);
+    let ray_dir = unproject(ndc, 0.5) - ray_origin;
+    let t = (u_shadow.plane_y - ray_origin.y) / ray_dir.y;
+    let hit = ray_origin + ray_dir * t;
+
+    // Distance from the footprint centre, 1.0 on the ellipse inscribed in
+    // the casters' bounds.
+    let dist = length((hit.xz - u_shadow.center) / u_shadow.half_extent);
+    let softness = max(u_shadow.softness, 1e-3);
+    let edge = 1.0 - smoothstep(1.0 - softness, 1.0 + softness, dist);
+    let fade = 1.0 - min(u_shadow.height / u_shadow.fade_distance, 1.0);
+
+    let alpha = u_shadow.opacity * edge * fade;
+    if (t <= 0.0 || alpha <= 1.0 / 255.0) {
+        discard;
+    }
+
+    let clip = u_render_state.view_projection * vec4<f32>(hit, 1.0);
+
+    var out: GroundShadowOutput;
+    out.color = vec4<f32>(0.0, 0.0, 0.0, alpha);
+    // Nudged toward the camera so a floor mesh on the plane doesn't z-fight.
+    out.depth = min(clip.z / clip.w * (1.0 + 1e-4), 1.0);
+    return out;
+}
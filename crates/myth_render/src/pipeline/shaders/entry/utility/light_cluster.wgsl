@@ -0,0 +1,78 @@
+// Assigns lights to view-frustum clusters.
+//
+// Each invocation handles one 32-light word of one cluster: it builds the
+// cluster's view-space bounding box and sets the bit of every light whose
+// sphere of influence touches it. The cluster after the grid only receives
+// the lights that reach every point.
+
+{{ struct_definitions }}
+
+@group(0) @binding(0) var<uniform> u_params: LightClusterParams;
+@group(0) @binding(1) var<storage, read> lights: array<Light>;
+@group(0) @binding(2) var<storage, read_write> clusters: array<u32>;
+
+fn unproject(ndc: vec3<f32>) -> vec3<f32> {
+    let p = u_params.projection_inverse * vec4<f32>(ndc, 1.0);
+    return p.xyz / p.w;
+}
+
+// View-space point at view depth `depth` on the line through NDC `xy`.
+// Two finite NDC depths define the line for both reverse-Z perspective and
+// orthographic projections.
+fn pointAtDepth(xy: vec2<f32>, depth: f32) -> vec3<f32> {
+    let a = unproject(vec3<f32>(xy, 0.25));
+    let b = unproject(vec3<f32>(xy, 0.75));
+    let t = (-depth - a.z) / (b.z - a.z);
+    return a + (b - a) * t;
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let grid = u_params.grid;
+    let cluster_count = grid.x * grid.y * grid.z;
+    let cluster = gid.x / grid.w;
+    let word = gid.x % grid.w;
+    if (cluster > cluster_count) {
+        return;
+    }
+
+    let bounded = cluster < cluster_count;
+    var aabb_min = vec3<f32>(1e30);
+    var aabb_max = vec3<f32>(-1e30);
+    if (bounded) {
+        let tile = vec2<u32>(cluster % grid.x, cluster / grid.x % grid.y);
+        let slice = cluster / (grid.x * grid.y);
+        let ndc_min = vec2<f32>(tile) / vec2<f32>(grid.xy) * 2.0 - 1.0;
+        let ndc_max = vec2<f32>(tile + 1u) / vec2<f32>(grid.xy) * 2.0 - 1.0;
+
+        // Slice `s` starts at near * (far / near)^(s / slices).
+        let near = u_params.depth.x;
+        let depth_near = near * exp(f32(slice) / u_params.depth.z);
+        let depth_far = near * exp(f32(slice + 1u) / u_params.depth.z);
+
+        for (var i = 0u; i < 8u; i++) {
+            let xy = select(ndc_min, ndc_max, vec2<bool>((i & 1u) != 0u, (i & 2u) != 0u));
+            let p = pointAtDepth(xy, select(depth_near, depth_far, (i & 4u) != 0u));
+            aabb_min = min(aabb_min, p);
+            aabb_max = max(aabb_max, p);
+        }
+    }
+
+    let first = word * 32u;
+    let count = min(u_params.num_lights - first, 32u);
+    var mask = 0u;
+    for (var b = 0u; b < count; b++) {
+        let light = lights[first + b];
+        var hit = light.light_type == 0u || light.range <= 0.0;
+        if (!hit && bounded) {
+            let center = (u_params.view_matrix * vec4<f32>(light.position, 1.0)).xyz;
+            let d = max(max(aabb_min - center, vec3<f32>(0.0)), center - aabb_max);
+            hit = dot(d, d) <= light.range * light.range;
+        }
+        if (hit) {
+            mask |= 1u << b;
+        }
+    }
+
+    clusters[cluster * grid.w + word] = mask;
+}
@@ -0,0 +1,101 @@
+// Projects a light probe capture onto order-2 spherical harmonics.
+//
+// One workgroup walks every texel of the capture cube, weighting each by
+// its solid angle, and reduces the nine RGB coefficients in shared memory.
+
+struct LightProbe {
+  sh: array<vec4<f32>, 9>,
+};
+
+@group(0) @binding(0)
+var captureTex: texture_cube<f32>;
+
+@group(0) @binding(1)
+var s: sampler;
+
+@group(0) @binding(2)
+var<storage, read_write> output: LightProbe;
+
+const WORKGROUP_SIZE: u32 = 64u;
+const PI: f32 = 3.141592653589793;
+
+var<workgroup> partial_sh: array<array<vec3<f32>, 9>, WORKGROUP_SIZE>;
+var<workgroup> partial_weight: array<f32, WORKGROUP_SIZE>;
+
+// Sampling direction of a texel centre, `uv` in [-1, 1]
+fn getCubeDirection(face: u32, uv: vec2<f32>) -> vec3<f32> {
+  switch (face) {
+    case 0u: { return vec3f(1.0, -uv.y, -uv.x); }
+    case 1u: { return vec3f(-1.0, -uv.y, uv.x); }
+    case 2u: { return vec3f(uv.x, 1.0, uv.y); }
+    case 3u: { return vec3f(uv.x, -1.0, -uv.y); }
+    case 4u: { return vec3f(uv.x, -uv.y, 1.0); }
+    default: { return vec3f(-uv.x, -uv.y, -1.0); }
+  }
+}
+
+// Real SH basis up to l = 2
+fn shBasis(d: vec3<f32>) -> array<f32, 9> {
+  return array<f32, 9>(
+    0.282095,
+    0.488603 * d.y,
+    0.488603 * d.z,
+    0.488603 * d.x,
+    1.092548 * d.x * d.y,
+    1.092548 * d.y * d.z,
+    0.315392 * (3.0 * d.z * d.z - 1.0),
+    1.092548 * d.x * d.z,
+    0.546274 * (d.x * d.x - d.y * d.y),
+  );
+}
+
+@compute @workgroup_size(64)
+fn main(@builtin(local_invocation_index) lid: u32) {
+  let size = textureDimensions(captureTex).x;
+  let texel_count = size * size * 6u;
+
+  var sh: array<vec3<f32>, 9>;
+  var weight_sum = 0.0;
+
+  for (var i = lid; i < texel_count; i += WORKGROUP_SIZE) {
+    let face = i / (size * size);
+    let texel = i % (size * size);
+    let uv = (vec2f(f32(texel % size), f32(texel / size)) + 0.5) / f32(size) * 2.0 - 1.0;
+
+    let dir = getCubeDirection(face, uv);
+    // Solid angle of the texel, up to the constant (2 / size)^2 that the
+    // normalization below cancels.
+    let weight = 1.0 / pow(dot(dir, dir), 1.5);
+    let radiance = textureSampleLevel(captureTex, s, dir, 0.0).rgb;
+
+    // Environment lookups mirror X, so the texel holds the radiance
+    // arriving from this world direction.
+    let world_dir = normalize(vec3f(-dir.x, dir.y, dir.z));
+    let basis = shBasis(world_dir);
+    for (var k = 0u; k < 9u; k++) {
+      sh[k] += radiance * (basis[k] * weight);
+    }
+    weight_sum += weight;
+  }
+
+  partial_sh[lid] = sh;
+  partial_weight[lid] = weight_sum;
+  workgroupBarrier();
+
+  for (var stride = WORKGROUP_SIZE / 2u; stride > 0u; stride /= 2u) {
+    if (lid < stride) {
+      for (var k = 0u; k < 9u; k++) {
+        partial_sh[lid][k] += partial_sh[lid + stride][k];
+      }
+      partial_weight[lid] += partial_weight[lid + stride];
+    }
+    workgroupBarrier();
+  }
+
+  if (lid == 0u) {
+    let norm = 4.0 * PI / max(partial_weight[0], 1e-6);
+    for (var k = 0u; k < 9u; k++) {
+      output.sh[k] = vec4f(partial_sh[0][k] * norm, 0.0);
+    }
+  }
+}
@@ -0,0 +1,35 @@
+// === Occlusion Query Shader ===
+//
+// Draws one world-space bounding box per instance against the scene depth.
+// The box corners are generated from the vertex index, so only the per-box
+// min / max corners are uploaded. No colour is written; the occlusion query
+// wrapped around each draw counts the samples passing the depth test.
+
+// Auto-injected global bind group bindings (Group 0: camera, environment, etc.)
+{{ binding_code }}
+
+struct BoxInput {
+    @location(0) box_min: vec3<f32>,
+    @location(1) box_max: vec3<f32>,
+};
+
+// 12 triangles, indexing the corners of the unit cube (bit 0 = x, 1 = y, 2 = z).
+const BOX_INDICES = array<u32, 36>(
+    0u, 2u, 1u, 1u, 2u, 3u, // -Z
+    4u, 5u, 6u, 5u, 7u, 6u, // +Z
+    0u, 1u, 4u, 1u, 5u, 4u, // -Y
+    2u, 6u, 3u, 3u, 6u, 7u, // +Y
+    0u, 4u, 2u, 2u, 4u, 6u, // -X
+    1u, 3u, 5u, 3u, 7u, 5u, // +X
+);
+
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32, in: BoxInput) -> @builtin(position) vec4<f32> {
+    let corner = BOX_INDICES[vertex_index];
+    let t = vec3<f32>(f32(corner & 1u), f32((corner >> 1u) & 1u), f32((corner >> 2u) & 1u));
+    let world_position = mix(in.box_min, in.box_max, t);
+    return u_render_state.view_projection * vec4<f32>(world_position, 1.0);
+}
+
+@fragment
+fn fs_main() {}
@@ -0,0 +1,107 @@
+// ── Pipeline Fallback Entry Point ───────────────────────────────────────
+//
+// Stand-in for a material whose pipeline is still compiling in the
+// background.  Shares the material's vertex layout, bind groups and pass
+// state, but only transforms the geometry and shades it as a neutral,
+// camera-lit surface.  Compiled with `IS_PREPASS` so the geometry modules
+// skip tangents, and with `OUTPUT_NORMAL` so they keep normals.
+
+{{ vertex_input_code }}
+{{ binding_code }}
+{$ include 'core/object_data' $}
+{$ include 'core/fragment_output' $}
+{$ include 'modules/geometry/morphing' $}
+{$ include 'modules/geometry/skinning' $}
+
+struct VertexOutput {
+    @builtin(position) @invariant position: vec4<f32>,
+    @location({{ loc.next() }}) world_position: vec3<f32>,
+    $$ if HAS_NORMAL
+    @location({{ loc.next() }}) world_normal: vec3<f32>,
+    $$ endif
+};
+
+@vertex
+fn vs_main(
+    in: VertexInput,
+    @builtin(vertex_index) vertex_index: u32,
+    $$ if GPU_DRIVEN is defined
+    @builtin(instance_index) instance_index: u32,
+    $$ endif
+) -> VertexOutput {
+    var out: VertexOutput;
+
+    $$ if GPU_DRIVEN is defined
+    let u_model = load_object_model(instance_index);
+    $$ endif
+
+    var local_position = vec3<f32>(in.position.xyz);
+
+    $$ if HAS_NORMAL
+    var local_normal = vec3<f32>(in.normal.xyz);
+    $$ endif
+
+    $$ if HAS_MORPH_TARGETS
+        let morphed = apply_morph_targets(
+            vertex_index,
+            local_position,
+            $$ if HAS_MORPH_NORMALS and HAS_NORMAL
+            local_normal,
+            $$ endif
+        );
+        local_position = morphed.position;
+        $$ if HAS_MORPH_NORMALS and HAS_NORMAL
+        local_normal = morphed.normal;
+        $$ endif
+    $$ endif
+
+    var local_pos = vec4<f32>(local_position, 1.0);
+
+    $$ if HAS_SKINNING and SUPPORT_SKINNING
+        let skinned = compute_skinned_vertex(
+            local_pos,
+            $$ if HAS_NORMAL
+            local_normal,
+            $$ endif
+            vec4<u32>(in.joints),
+            in.weights,
+        );
+        local_pos = skinned.position;
+        $$ if HAS_NORMAL
+        local_normal = skinned.normal;
+        $$ endif
+    $$ endif
+
+    let world_pos = u_model.world_matrix * local_pos;
+
+    $$ if IN_TRANSPARENT_PASS is defined
+    out.position = u_render_state.unjittered_view_projection * world_pos;
+    $$ else
+    out.position = u_render_state.view_projection * world_pos;
+    $$ endif
+
+    out.world_position = world_pos.xyz / world_pos.w;
+
+    $$ if HAS_NORMAL
+    out.world_normal = normalize(u_model.normal_matrix * local_normal);
+    $$ endif
+
+    return out;
+}
+
+@fragment
+fn fs_main(in: VertexOutput) -> FragmentOutput {
+    $$ if HAS_NORMAL
+    let normal = normalize(in.world_normal);
+    $$ else
+    let normal = normalize(cross(dpdx(in.world_position), dpdy(in.world_position)));
+    $$ endif
+
+    // Headlight: faces toward the camera are brightest.
+    let to_camera = normalize(u_render_state.camera_position - in.world_position);
+    let shade = 0.25 + 0.5 * abs(dot(normal, to_camera));
+
+    var out = pack_fragment_output(vec4<f32>(vec3<f32>(shade), 1.0));
+    apply_weighted_oit(&out, in.position);
+    return out;
+}
@@ -9,6 +9,10 @@
 //   SKYBOX_CUBE          - Cubemap sampling
 //   SKYBOX_EQUIRECT      - Equirectangular (lat-long) 2D texture sampling
 //   SKYBOX_PLANAR        - Screen-space planar 2D texture sampling
+//
+// `u_environment.background_blur` (non-zero only when the background shows
+// the environment) swaps the procedural/cube/equirect lookup for the PMREM
+// chain at the matching roughness level.
 
 {$ include 'core/full_screen_vertex' $}
 {$ include "entry/utility/atmosphere/atmosphere_math" $}
@@ -98,6 +102,16 @@ $$ if SKYBOX_PROCEDURAL
         u_render_state.time,
     );
 
+    if (u_environment.background_blur > 0.0) {
+        // Procedural IBL is baked in world orientation (no env rotation).
+        let lod = u_environment.background_blur * u_environment.env_map_max_mip_level;
+        procedural_color = textureSampleLevel(
+            t_pmrem_map, s_pmrem_map, vec3<f32>(-world_dir.x, world_dir.yz), lod
+        ).rgb;
+    }
+
+    // The procedural sky is the environment: keep it as bright as its IBL.
+    procedural_color *= u_environment.env_map_intensity;
     procedural_color = clamp(procedural_color, vec3<f32>(0.0), vec3<f32>(65000.0));
     color = vec4<f32>(procedural_color, 1.0);
 $$ endif
@@ -113,13 +127,14 @@ $$ if SKYBOX_GRADIENT
 $$ endif
 
 $$ if SKYBOX_CUBE or SKYBOX_EQUIRECT
-    // Apply Y-axis rotation
+    // Apply the environment rotation, then the background's own Y-axis rotation
+    let env_dir = u_params.env_rotation * world_dir;
     let s = sin(u_params.rotation);
     let c = cos(u_params.rotation);
     let rot_dir = vec3<f32>(
-        -(world_dir.x * c - world_dir.z * s), // Negate X to convert from left-handed to right-handed coordinates for cubemap sampling
-        world_dir.y,
-        world_dir.x * s + world_dir.z * c
+        -(env_dir.x * c - env_dir.z * s), // Negate X to convert from left-handed to right-handed coordinates for cubemap sampling
+        env_dir.y,
+        env_dir.x * s + env_dir.z * c
     );
 $$ endif
 
@@ -137,6 +152,15 @@ $$ if SKYBOX_EQUIRECT
     color = clamp(color, vec4<f32>(0.0), vec4<f32>(65000.0));
 $$ endif
 
+$$ if SKYBOX_CUBE or SKYBOX_EQUIRECT
+    // Blurred background: the texture is the environment map, so its
+    // prefiltered chain shares the lookup direction.
+    if (u_environment.background_blur > 0.0) {
+        let lod = u_environment.background_blur * u_environment.env_map_max_mip_level;
+        color = vec4<f32>(textureSampleLevel(t_pmrem_map, s_pmrem_map, rot_dir, lod).rgb, 1.0);
+    }
+$$ endif
+
 $$ if SKYBOX_PLANAR
     // --- Planar mode (screen-space mapping) ---
     color = textureSample(t_skybox_2d, s_skybox, in.uv);
@@ -12,6 +12,7 @@
 //   - view: vec3<f32>              (normalized view direction)
 //   - varyings: VertexOutput       (interpolated vertex data)
 //   - tbn: mat3x3<f32>            (tangent frame, when USE_ANISOTROPY)
+//   - triplanar_pos, triplanar_w   (projection inputs, when USE_TRIPLANAR)
 //
 // Required global resources:
 //   - u_material (uniform buffer with metalness, roughness, IOR, etc.)
@@ -23,13 +24,17 @@
 //   - material: SurfaceContext
 
 var metalness_factor: f32 = u_material.metalness;
-$$ if HAS_METALNESS_MAP is defined
-    metalness_factor *= textureSample( t_metalness_map, s_metalness_map, varyings.metalness_map_uv ).b;
+$$ if HAS_METALNESS_MAP is defined and USE_TRIPLANAR is defined
+    metalness_factor *= triplanar_sample( t_metalness_map, s_metalness_map, triplanar_pos, triplanar_w ).b;
+$$ elif HAS_METALNESS_MAP is defined
+    metalness_factor *= sample_material_map(t_metalness_map, s_metalness_map, varyings.metalness_map_uv, u_material.texture_lod_bias).b;
 $$ endif
 
 var roughness_factor: f32 = u_material.roughness;
-$$ if HAS_ROUGHNESS_MAP is defined
-    roughness_factor *= textureSample( t_roughness_map, s_roughness_map, varyings.roughness_map_uv ).g;
+$$ if HAS_ROUGHNESS_MAP is defined and USE_TRIPLANAR is defined
+    roughness_factor *= triplanar_sample( t_roughness_map, s_roughness_map, triplanar_pos, triplanar_w ).g;
+$$ elif HAS_ROUGHNESS_MAP is defined
+    roughness_factor *= sample_material_map(t_roughness_map, s_roughness_map, varyings.roughness_map_uv, u_material.texture_lod_bias).g;
 $$ endif
 
 
@@ -53,11 +58,11 @@ $$ if USE_IOR is defined
         var specular_color = u_material.specular_color.rgb;
         
         $$ if HAS_SPECULAR_MAP is defined
-            specular_color *= textureSample( t_specular_map, s_specular_map, varyings.specular_map_uv ).rgb;
+            specular_color *= sample_material_map(t_specular_map, s_specular_map, varyings.specular_map_uv, u_material.texture_lod_bias).rgb;
         $$ endif
 
         $$ if HAS_SPECULAR_INTENSITY_MAP is defined
-            specular_intensity *= textureSample( t_specular_intensity_map, s_specular_intensity_map, varyings.specular_intensity_map_uv ).a;
+            specular_intensity *= sample_material_map(t_specular_intensity_map, s_specular_intensity_map, varyings.specular_intensity_map_uv, u_material.texture_lod_bias).a;
         $$ endif
 
         material.specular_f90 = mix( specular_intensity, 1.0, metalness_factor );
@@ -87,11 +92,11 @@ $$ if USE_CLEARCOAT is defined
     material.clearcoat_f90 = 1.0;
 
     $$ if HAS_CLEARCOAT_MAP is defined
-        material.clearcoat *= textureSample( t_clearcoat_map, s_clearcoat_map, varyings.clearcoat_map_uv ).r;
+        material.clearcoat *= sample_material_map(t_clearcoat_map, s_clearcoat_map, varyings.clearcoat_map_uv, u_material.texture_lod_bias).r;
     $$ endif
 
     $$ if HAS_CLEARCOAT_ROUGHNESS_MAP is defined
-        material.clearcoat_roughness *= textureSample( t_clearcoat_roughness_map, s_clearcoat_roughness_map, varyings.clearcoat_roughness_map_uv ).g;
+        material.clearcoat_roughness *= sample_material_map(t_clearcoat_roughness_map, s_clearcoat_roughness_map, varyings.clearcoat_roughness_map_uv, u_material.texture_lod_bias).g;
     $$ endif
 
     material.clearcoat = saturate( material.clearcoat );
@@ -113,11 +118,11 @@ $$ if USE_TRANSMISSION is defined
     $$ endif
 
     $$ if HAS_TRANSMISSION_MAP is defined
-        material.transmission *= textureSample(t_transmission_map, s_transmission_map, varyings.transmission_map_uv ).r;
+        material.transmission *= sample_material_map(t_transmission_map, s_transmission_map, varyings.transmission_map_uv, u_material.texture_lod_bias).r;
     $$ endif
 
     $$ if HAS_THICKNESS_MAP is defined
-        material.thickness *= textureSample(t_thickness_map, s_thickness_map, varyings.thickness_map_uv).g;
+        material.thickness *= sample_material_map(t_thickness_map, s_thickness_map, varyings.thickness_map_uv, u_material.texture_lod_bias).g;
     $$ endif
 
 $$ endif
@@ -127,13 +132,13 @@ $$ if USE_IRIDESCENCE is defined
     material.iridescence_ior = u_material.iridescence_ior;
 
     $$ if HAS_IRIDESCENCE_MAP is defined
-        material.iridescence *= textureSample(t_iridescence_map, s_iridescence_map, varyings.iridescence_map_uv).r;
+        material.iridescence *= sample_material_map(t_iridescence_map, s_iridescence_map, varyings.iridescence_map_uv, u_material.texture_lod_bias).r;
     $$ endif
 
     let iridescence_thickness_minimum = u_material.iridescence_thickness_min;
     let iridescence_thickness_maximum = u_material.iridescence_thickness_max;
     $$ if HAS_IRIDESCENCE_THICKNESS_MAP is defined
-        material.iridescence_thickness = (iridescence_thickness_maximum - iridescence_thickness_minimum) * textureSample(t_iridescence_thickness_map, s_iridescence_thickness_map, varyings.iridescence_thickness_map_uv).g + iridescence_thickness_minimum;
+        material.iridescence_thickness = (iridescence_thickness_maximum - iridescence_thickness_minimum) * sample_material_map(t_iridescence_thickness_map, s_iridescence_thickness_map, varyings.iridescence_thickness_map_uv, u_material.texture_lod_bias).g + iridescence_thickness_minimum;
     $$ else
         material.iridescence_thickness = iridescence_thickness_maximum;
     $$ endif
@@ -157,12 +162,12 @@ $$ if USE_SHEEN is defined
     material.sheen_color = u_material.sheen_color.rgb;
 
     $$ if HAS_SHEEN_COLOR_MAP is defined
-        material.sheen_color *= textureSample( t_sheen_color_map, s_sheen_color_map, varyings.sheen_color_map_uv).rgb;
+        material.sheen_color *= sample_material_map(t_sheen_color_map, s_sheen_color_map, varyings.sheen_color_map_uv, u_material.texture_lod_bias).rgb;
     $$ endif
 
     material.sheen_roughness = clamp( u_material.sheen_roughness, 0.07, 1.0 );
     $$ if HAS_SHEEN_ROUGHNESS_MAP is defined
-        material.sheen_roughness *= textureSample( t_sheen_roughness_map, s_sheen_roughness_map, varyings.sheen_roughness_map_uv).a;
+        material.sheen_roughness *= sample_material_map(t_sheen_roughness_map, s_sheen_roughness_map, varyings.sheen_roughness_map_uv, u_material.texture_lod_bias).a;
     $$ endif
 
 $$ endif
@@ -170,7 +175,7 @@ $$ endif
 $$ if USE_ANISOTROPY is defined
     let anisotropy_vector = u_material.anisotropy_vector;
     $$ if HAS_ANISOTROPY_MAP is defined
-        let anisotropy_polar = textureSample( t_anisotropy_map, s_anisotropy_map, varyings.anisotropy_map_uv ).rgb;
+        let anisotropy_polar = sample_material_map(t_anisotropy_map, s_anisotropy_map, varyings.anisotropy_map_uv, u_material.texture_lod_bias).rgb;
         let anisotropy_mat = mat2x2f( anisotropy_vector.x, anisotropy_vector.y, -anisotropy_vector.y, anisotropy_vector.x );
         var anisotropy_v = anisotropy_mat * normalize( 2.0 * anisotropy_polar.rg - vec2f( 1.0 ) ) * anisotropy_polar.b;
     $$ else
@@ -2,7 +2,9 @@
 //
 // Applies per-map UV transforms for all texture maps using the material's
 // transform matrices.  Uses template variables (e.g. MAP_UV) to select
-// which UV channel each map reads from.
+// which UV channel each map reads from.  With USE_TRIPLANAR the color,
+// normal, metalness and roughness maps are projected in the fragment
+// shader instead and get no UV varyings.
 //
 // This is a template mixin because it directly assigns to struct fields
 // of the caller's `out: VertexOutput` variable.
@@ -14,11 +16,11 @@
 // Required global resources:
 //   - u_material.*_transform: mat3x3<f32> (UV transform matrices)
 
-$$ if HAS_MAP is defined
+$$ if HAS_MAP is defined and USE_TRIPLANAR is not defined
     out.map_uv = (u_material.map_transform * vec3<f32>(in.uv{{MAP_UV or ''}}, 1.0)).xy;
 $$ endif
 
-$$ if HAS_NORMAL_MAP is defined
+$$ if HAS_NORMAL_MAP is defined and USE_TRIPLANAR is not defined
     out.normal_map_uv = (u_material.normal_map_transform * vec3<f32>(in.uv{{NORMAL_MAP_UV or ''}}, 1.0)).xy;
 $$ endif
 
@@ -34,6 +36,10 @@ $$ if HAS_AO_MAP is defined
     out.ao_map_uv = (u_material.ao_map_transform * vec3<f32>(in.uv{{AO_MAP_UV or ''}}, 1.0)).xy;
 $$ endif
 
+$$ if HAS_HEIGHT_MAP is defined
+    out.height_map_uv = (u_material.height_map_transform * vec3<f32>(in.uv{{HEIGHT_MAP_UV or ''}}, 1.0)).xy;
+$$ endif
+
 $$ if HAS_LIGHT_MAP is defined
     out.light_map_uv = (u_material.light_map_transform * vec3<f32>(in.uv{{LIGHT_MAP_UV or ''}}, 1.0)).xy;
 $$ endif
@@ -42,11 +48,11 @@ $$ if HAS_EMISSIVE_MAP is defined
     out.emissive_map_uv = (u_material.emissive_map_transform * vec3<f32>(in.uv{{EMISSIVE_MAP_UV or ''}}, 1.0)).xy;
 $$ endif
 
-$$ if HAS_METALNESS_MAP is defined
+$$ if HAS_METALNESS_MAP is defined and USE_TRIPLANAR is not defined
     out.metalness_map_uv = (u_material.metalness_map_transform * vec3<f32>(in.uv{{METALNESS_MAP_UV or ''}}, 1.0)).xy;
 $$ endif
 
-$$ if HAS_ROUGHNESS_MAP is defined
+$$ if HAS_ROUGHNESS_MAP is defined and USE_TRIPLANAR is not defined
     out.roughness_map_uv = (u_material.roughness_map_transform * vec3<f32>(in.uv{{ROUGHNESS_MAP_UV or ''}}, 1.0)).xy;
 $$ endif
 
@@ -198,17 +198,94 @@ fn EnvironmentBRDF(normal: vec3f, view_dir: vec3f, specular_color: vec3f, specul
 
 $$ if USE_IBL is defined
 
+// Weight of the bound reflection probe at the shaded point, set by the
+// entry point before the IBL lookups.
+var<private> ibl_probe_weight: f32 = 0.0;
+
+fn getReflectionProbeWeight( world_position: vec3<f32> ) -> f32 {
+    let radius = u_environment.probe_radius;
+    if ( radius <= 0.0 ) {
+        return 0.0;
+    }
+    let blend = max( u_environment.probe_blend_distance, 1e-4 );
+    let dist = distance( world_position, u_environment.probe_position );
+    return 1.0 - smoothstep( radius - blend, radius, dist );
+}
+
+// Samples the prefiltered lighting in direction `dir` at `lod_fraction` of
+// the mip chain, blending the environment map into the reflection probe.
+// `dir` is in the environment map's frame; probes are captured in world
+// space, so their lookup undoes the rotation.
+fn sampleIBL( dir: vec3<f32>, lod_fraction: f32 ) -> vec3<f32> {
+    let env_lod = lod_fraction * u_environment.env_map_max_mip_level;
+    let env_color = textureSampleLevel( t_pmrem_map, s_pmrem_map, vec3<f32>( -dir.x, dir.yz ), env_lod ).rgb;
+    let env = env_color * u_environment.env_map_intensity;
+
+    let probe_dir = transpose( u_environment.env_map_rotation ) * dir;
+    let probe_lod = lod_fraction * u_environment.probe_max_mip_level;
+    let probe_color = textureSampleLevel( t_probe_map, s_probe_map, vec3<f32>( -probe_dir.x, probe_dir.yz ), probe_lod ).rgb;
+    let probe = probe_color * u_environment.probe_intensity;
+
+    return mix( env, probe, ibl_probe_weight );
+}
+
+// Irradiance of the baked light probe grid at the shaded point and its
+// weight, set by the entry point before the IBL lookups.
+var<private> light_probe_irradiance: vec3<f32> = vec3<f32>( 0.0 );
+var<private> light_probe_weight: f32 = 0.0;
+
+fn getLightProbeWeight( world_position: vec3<f32> ) -> f32 {
+    if ( u_environment.light_probe_grid.w == 0u ) {
+        return 0.0;
+    }
+    let below = u_environment.light_probe_min - world_position;
+    let above = world_position - u_environment.light_probe_max;
+    let outside = length( max( max( below, above ), vec3<f32>( 0.0 ) ) );
+    let blend = max( u_environment.light_probe_blend_distance, 1e-4 );
+    return 1.0 - smoothstep( 0.0, blend, outside );
+}
+
+// Irradiance for a surface facing `n` from one probe's SH radiance,
+// convolved with the clamped cosine lobe (band factors pi, 2pi/3, pi/4).
+fn evalLightProbeSH( index: u32, n: vec3<f32> ) -> vec3<f32> {
+    let sh = st_light_probes[ index ].sh;
+    let band0 = 0.886227 * sh[ 0 ].xyz;
+    let band1 = 1.023328 * ( sh[ 1 ].xyz * n.y + sh[ 2 ].xyz * n.z + sh[ 3 ].xyz * n.x );
+    let band2 = 0.858086 * ( sh[ 4 ].xyz * n.x * n.y + sh[ 5 ].xyz * n.y * n.z + sh[ 7 ].xyz * n.x * n.z )
+        + 0.247708 * sh[ 6 ].xyz * ( 3.0 * n.z * n.z - 1.0 )
+        + 0.429043 * sh[ 8 ].xyz * ( n.x * n.x - n.y * n.y );
+    return band0 + band1 + band2;
+}
+
+// Trilinear blend of the eight probes around `world_position`, clamped to
+// the grid.
+fn getLightProbeIrradiance( world_position: vec3<f32>, normal: vec3<f32> ) -> vec3<f32> {
+    let grid = u_environment.light_probe_grid.xyz;
+    let extent = max( u_environment.light_probe_max - u_environment.light_probe_min, vec3<f32>( 1e-4 ) );
+    let local = saturate( ( world_position - u_environment.light_probe_min ) / extent );
+    let cell = local * vec3<f32>( grid - 1u );
+    let base = min( vec3<u32>( cell ), max( grid, vec3<u32>( 2u ) ) - 2u );
+    let t = cell - vec3<f32>( base );
+
+    var irradiance = vec3<f32>( 0.0 );
+    for ( var corner = 0u; corner < 8u; corner++ ) {
+        let offset = vec3<u32>( corner & 1u, ( corner >> 1u ) & 1u, corner >> 2u );
+        let probe = min( base + offset, grid - 1u );
+        let weights = select( 1.0 - t, t, offset == vec3<u32>( 1u ) );
+        let index = probe.x + ( probe.y + probe.z * grid.y ) * grid.x;
+        irradiance += evalLightProbeSH( index, normal ) * ( weights.x * weights.y * weights.z );
+    }
+    return max( irradiance, vec3<f32>( 0.0 ) ) * u_environment.light_probe_intensity;
+}
+
 fn getIBLIrradiance( normal: vec3<f32> ) -> vec3<f32> {
-    let envMapColor_srgb = textureSampleLevel( t_pmrem_map, s_pmrem_map, vec3<f32>( -normal.x, normal.yz), u_environment.env_map_max_mip_level );
-    return envMapColor_srgb.rgb * u_environment.env_map_intensity * PI;
+    return mix( sampleIBL( normal, 1.0 ) * PI, light_probe_irradiance, light_probe_weight );
 }
 
 fn getIBLRadiance(view_dir: vec3<f32>, normal: vec3<f32>, roughness: f32) -> vec3<f32> {
     var reflectVec = reflect( -view_dir, normal );
     reflectVec = normalize(mix(reflectVec, normal, roughness*roughness));
-    let lod = roughness * u_environment.env_map_max_mip_level; 
-    let envMapColor_srgb = textureSampleLevel( t_pmrem_map, s_pmrem_map, vec3<f32>( -reflectVec.x, reflectVec.yz), lod ).rgb;
-    return envMapColor_srgb.rgb * u_environment.env_map_intensity;
+    return sampleIBL( reflectVec, roughness );
 }
 
 $$ if USE_ANISOTROPY is defined
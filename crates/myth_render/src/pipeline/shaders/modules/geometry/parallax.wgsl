@@ -0,0 +1,56 @@
+// ── Parallax Occlusion Mapping Module (Pure Function Library) ───────────
+//
+// Ray-marches a height field in tangent space and returns the UV where the
+// view ray first hits it, so flat surfaces show depth without geometry.
+// Height convention: white (1.0) = top surface, black (0.0) = deepest.
+//
+// Required global resources: none (textures are passed as arguments).
+
+$$ if USE_PARALLAX is defined
+
+// Keeps the march bounded at grazing angles.
+const PARALLAX_MIN_VIEW_Z: f32 = 0.05;
+
+fn parallax_depth(t: texture_2d<f32>, s: sampler, uv: vec2<f32>, ddx: vec2<f32>, ddy: vec2<f32>) -> f32 {
+    return 1.0 - textureSampleGrad(t, s, uv, ddx, ddy).r;
+}
+
+// `view_ts` is the surface-to-camera direction in tangent space.
+fn parallax_occlusion_uv(
+    t: texture_2d<f32>,
+    s: sampler,
+    uv: vec2<f32>,
+    view_ts: vec3<f32>,
+    scale: f32,
+    steps: u32,
+) -> vec2<f32> {
+    // Gradients from the unshifted UV keep mip selection stable in the loop.
+    let ddx = dpdx(uv);
+    let ddy = dpdy(uv);
+
+    let layer_count = f32(max(steps, 1u));
+    let layer_depth = 1.0 / layer_count;
+    let delta_uv = view_ts.xy / max(view_ts.z, PARALLAX_MIN_VIEW_Z) * scale / layer_count;
+
+    var current_uv = uv;
+    var current_layer = 0.0;
+    var current_depth = parallax_depth(t, s, current_uv, ddx, ddy);
+
+    for (var i = 0u; i < max(steps, 1u); i = i + 1u) {
+        if (current_layer >= current_depth) {
+            break;
+        }
+        current_uv = current_uv - delta_uv;
+        current_layer = current_layer + layer_depth;
+        current_depth = parallax_depth(t, s, current_uv, ddx, ddy);
+    }
+
+    // Linear refinement between the last two layers.
+    let previous_uv = current_uv + delta_uv;
+    let after = current_depth - current_layer;
+    let before = parallax_depth(t, s, previous_uv, ddx, ddy) - current_layer + layer_depth;
+    let weight = after / min(after - before, -1e-5);
+    return mix(current_uv, previous_uv, weight);
+}
+
+$$ endif
@@ -0,0 +1,54 @@
+// ── Tri-planar Mapping Module (Pure Function Library) ──────────────────
+//
+// Projects textures along the world X/Y/Z axes and blends the three
+// samples by the surface normal, so meshes without UVs can still be
+// textured.  Projection planes: X → (z, y), Y → (x, z), Z → (x, y).
+//
+// Required global resources: none (textures are passed as arguments).
+
+$$ if USE_TRIPLANAR is defined
+
+// Sharpness of the blend between projections; higher = narrower seams.
+const TRIPLANAR_BLEND_POWER: f32 = 4.0;
+
+// ── Blend Weights ───────────────────────────────────────────────────────
+
+fn triplanar_weights(n: vec3<f32>) -> vec3<f32> {
+    let w = pow(abs(n), vec3<f32>(TRIPLANAR_BLEND_POWER));
+    return w / max(w.x + w.y + w.z, 1e-5);
+}
+
+// ── Sampling ────────────────────────────────────────────────────────────
+
+fn triplanar_sample(
+    t: texture_2d<f32>,
+    s: sampler,
+    p: vec3<f32>,
+    weights: vec3<f32>,
+) -> vec4<f32> {
+    let x = textureSample(t, s, p.zy);
+    let y = textureSample(t, s, p.xz);
+    let z = textureSample(t, s, p.xy);
+    return x * weights.x + y * weights.y + z * weights.z;
+}
+
+// Samples a tangent-space normal map along each axis and reorients the
+// results into world space with a whiteout blend.
+fn triplanar_normal(
+    t: texture_2d<f32>,
+    s: sampler,
+    p: vec3<f32>,
+    n: vec3<f32>,
+    weights: vec3<f32>,
+    scale: vec2<f32>,
+) -> vec3<f32> {
+    var tx = textureSample(t, s, p.zy).xyz * 2.0 - 1.0;
+    var ty = textureSample(t, s, p.xz).xyz * 2.0 - 1.0;
+    var tz = textureSample(t, s, p.xy).xyz * 2.0 - 1.0;
+    tx = vec3<f32>(tx.xy * scale + n.zy, abs(tx.z) * n.x);
+    ty = vec3<f32>(ty.xy * scale + n.xz, abs(ty.z) * n.y);
+    tz = vec3<f32>(tz.xy * scale + n.xy, abs(tz.z) * n.z);
+    return normalize(tx.zyx * weights.x + ty.xzy * weights.y + tz.xyz * weights.z);
+}
+
+$$ endif
@@ -3,6 +3,8 @@
 // Resolves a light's type (directional / point / spot) and computes
 // incident light direction, color, and distance/spot attenuation.
 // Dispatches shadow queries to modules/lighting/shadow when enabled.
+// With many lights, only the lights of the shaded point's light cluster are
+// evaluated (see graph/passes/light_cluster.rs).
 //
 // Depends on:
 //   - core/common.wgsl (getDistanceAttenuation, getSpotAttenuation, IncidentLight)
@@ -10,12 +12,25 @@
 
 {$ include 'modules/lighting/shadow' $}
 
+// Physical-unit lights (lux / candela) are scaled by the camera exposure
+// and fall off with the inverse-square law.
+fn get_light_intensity( light: Struct_lights ) -> f32 {
+    if ( light.units == 1u ) {
+        return light.intensity * u_environment.physical_light_scale;
+    }
+    return light.intensity;
+}
+
+fn get_light_decay( light: Struct_lights ) -> f32 {
+    return select( light.decay, 2.0, light.units == 1u );
+}
+
 fn get_light_info( light: Struct_lights, geometry: GeometricContext ) -> IncidentLight {
     let light_type = light.light_type;
     var light_info: IncidentLight;
 
     light_info.visible = true;
-    light_info.color = light.color.rgb * light.intensity;
+    light_info.color = light.color.rgb * get_light_intensity( light );
 
     if ( light_type == 0u ) {
         light_info.direction = -light.direction.xyz;
@@ -23,7 +38,7 @@ fn get_light_info( light: Struct_lights, geometry: GeometricContext ) -> Inciden
         let i_vector = light.position - geometry.position;
         light_info.direction = normalize(i_vector);
         let light_distance = length(i_vector);
-        light_info.color *= getDistanceAttenuation( light_distance, light.range, light.decay );
+        light_info.color *= getDistanceAttenuation( light_distance, light.range, get_light_decay( light ) );
         light_info.visible = any(light_info.color != vec3<f32>(0.0));
     } else if ( light_type == 2u ) {
         let i_vector = light.position - geometry.position;
@@ -32,9 +47,9 @@ fn get_light_info( light: Struct_lights, geometry: GeometricContext ) -> Inciden
         let spot_attenuation = getSpotAttenuation(light.outer_cone_cos, light.inner_cone_cos, angle_cos);
         if ( spot_attenuation > 0.0 ) {
             let light_distance = length( i_vector );
-            light_info.color = light.color.rgb * light.intensity;
+            light_info.color = light.color.rgb * get_light_intensity( light );
             light_info.color *= spot_attenuation;
-            light_info.color *= getDistanceAttenuation( light_distance, light.range, light.decay );
+            light_info.color *= getDistanceAttenuation( light_distance, light.range, get_light_decay( light ) );
             light_info.visible = any(light_info.color != vec3<f32>(0.0));
         } else {
             light_info.color = vec3<f32>( 0.0 );
@@ -104,17 +119,56 @@ fn evaluate_light_visibility(
 }
 
 
+// Index of the light cluster containing a world position. Positions beyond
+// the last depth slice use the extra cluster after the grid, which holds the
+// lights reaching every point.
+fn get_light_cluster( world_position: vec3<f32> ) -> u32 {
+    let grid = u_environment.light_cluster_grid;
+    let depth_params = u_environment.light_cluster_depth;
+    let cluster_count = grid.x * grid.y * grid.z;
+
+    let depth = -( u_render_state.view_matrix * vec4<f32>( world_position, 1.0 ) ).z;
+    if ( depth >= depth_params.y ) {
+        return cluster_count;
+    }
+
+    let clip = u_render_state.view_projection * vec4<f32>( world_position, 1.0 );
+    let screen_uv = clamp( clip.xy / clip.w * 0.5 + 0.5, vec2<f32>( 0.0 ), vec2<f32>( 1.0 ) );
+    let tile = min( vec2<u32>( screen_uv * vec2<f32>( grid.xy ) ), grid.xy - 1u );
+    let slice = min( u32( max( log( depth / depth_params.x ) * depth_params.z, 0.0 ) ), grid.z - 1u );
+
+    return ( slice * grid.y + tile.y ) * grid.x + tile.x;
+}
+
 fn evaluate_punctual_lights(
     geometry: GeometricContext,
     material: SurfaceContext,
     reflected_light: ptr<function, ReflectedLight>
 ) {
-
-    for (var i = 0u; i < u_environment.num_lights; i ++ ) {
-        let punctual_light = evaluate_light_visibility(i, geometry);
-        if (punctual_light.visible) {
-            RE_Direct( punctual_light, geometry, material, reflected_light );
+    let words = u_environment.light_cluster_grid.w;
+
+    // Few lights: loop over all of them.
+    if ( words == 0u ) {
+        for (var i = 0u; i < u_environment.num_lights; i ++ ) {
+            let punctual_light = evaluate_light_visibility(i, geometry);
+            if (punctual_light.visible) {
+                RE_Direct( punctual_light, geometry, material, reflected_light );
+            }
         }
+        return;
     }
 
+    // Clustered: visit the lights whose bit is set in this cluster's masks.
+    let base = get_light_cluster( geometry.position ) * words;
+    for (var word = 0u; word < words; word ++ ) {
+        var mask = st_light_clusters[base + word];
+        while ( mask != 0u ) {
+            let bit = countTrailingZeros( mask );
+            mask &= mask - 1u;
+            let punctual_light = evaluate_light_visibility(word * 32u + bit, geometry);
+            if (punctual_light.visible) {
+                RE_Direct( punctual_light, geometry, material, reflected_light );
+            }
+        }
+    }
 }
@@ -2,10 +2,12 @@
 //!
 //! The main [`Renderer`] struct orchestrating GPU rendering operations.
 
+use glam::Vec3;
 use raw_window_handle::{HasDisplayHandle, HasWindowHandle};
 
 use crate::core::binding::GlobalBindGroupCache;
 use crate::graph::composer::ComposerContext;
+use crate::graph::core::HookAnchorWarnings;
 use crate::graph::core::allocator::TransientPool;
 use crate::graph::core::arena::FrameArena;
 use crate::graph::core::graph::GraphStorage;
@@ -14,22 +16,27 @@ use crate::graph::frame::RenderLists;
 use crate::graph::passes::DebugViewFeature;
 #[cfg(feature = "3dgs")]
 use crate::graph::passes::GaussianSplattingFeature;
+use crate::graph::passes::reflection_probe;
 use crate::graph::passes::{
-    AtmosphereFeature, BloomFeature, BrdfLutFeature, CasFeature, EquirectToCubeFeature,
-    FxaaFeature, IblComputeFeature, MsaaSyncFeature, OpaqueFeature, PrepassFeature, ShadowFeature,
-    SimpleForwardFeature, SkyboxFeature, SsaoFeature, SsssFeature, TaaFeature, ToneMappingFeature,
-    TransmissionCopyFeature, TransparentFeature,
+    AtmosphereFeature, AutoExposureFeature, BloomFeature, BrdfLutFeature, CasFeature,
+    DebugLinesFeature, DecalFeature, EquirectToCubeFeature, FxaaFeature, GpuCullingFeature,
+    GridFeature, GroundShadowFeature, IblComputeFeature, LightClusterFeature, LightProbeFeature,
+    MsaaSyncFeature, OcclusionCullingFeature, OpaqueFeature, PrepassFeature, ProbeCaptureKind,
+    ProbeCaptureTarget, ShadowFeature, SimpleForwardFeature, SkyboxFeature, SsaoFeature,
+    SsssFeature, TaaFeature, ToneMappingFeature, TransmissionCopyFeature, TransparentFeature,
+    WeightedOitFeature,
 };
-use myth_assets::AssetServer;
+use myth_assets::{AssetServer, GeometryHandle, MaterialHandle};
 use myth_core::Result;
 use myth_scene::Scene;
 use myth_scene::background::BackgroundMode;
 use myth_scene::camera::RenderCamera;
 
-use crate::core::{ResourceManager, WgpuContext};
-use crate::graph::{FrameComposer, RenderFrame};
+use crate::core::{GpuProfiler, ResourceManager, WgpuContext};
+use crate::graph::{FrameComposer, RenderFrame, RenderStats};
 use crate::pipeline::PipelineCache;
 use crate::pipeline::ShaderManager;
+use crate::pipeline::disk_cache;
 use crate::settings::{RenderPath, RendererInitConfig, RendererSettings};
 
 /// The main renderer responsible for GPU rendering operations.
@@ -51,6 +58,8 @@ pub struct Renderer {
     init_config: RendererInitConfig,
     settings: RendererSettings,
     context: Option<RendererState>,
+    /// Pipelines to compile on the next frame (see [`prewarm`](Self::prewarm)).
+    prewarm_queue: Vec<(MaterialHandle, GeometryHandle)>,
 }
 
 /// Internal renderer state
@@ -69,6 +78,7 @@ struct RendererState {
 
     // ===== RDG (Declarative Render Graph) =====
     pub(crate) graph_storage: GraphStorage,
+    pub(crate) hook_anchor_warnings: HookAnchorWarnings,
     // pub(crate) sampler_registry: SamplerRegistry,
     pub(crate) transient_pool: TransientPool,
     pub(crate) frame_arena: FrameArena,
@@ -78,6 +88,7 @@ struct RendererState {
     pub(crate) taa_pass: TaaFeature,
     pub(crate) cas_pass: CasFeature,
     pub(crate) tone_map_pass: ToneMappingFeature,
+    pub(crate) auto_exposure_pass: AutoExposureFeature,
     pub(crate) bloom_pass: BloomFeature,
     pub(crate) ssao_pass: SsaoFeature,
 
@@ -85,7 +96,12 @@ struct RendererState {
     pub(crate) prepass: PrepassFeature,
     pub(crate) opaque_pass: OpaqueFeature,
     pub(crate) skybox_pass: SkyboxFeature,
+    pub(crate) debug_lines_pass: DebugLinesFeature,
+    pub(crate) grid_pass: GridFeature,
+    pub(crate) ground_shadow_pass: GroundShadowFeature,
+    pub(crate) decal_pass: DecalFeature,
     pub(crate) transparent_pass: TransparentFeature,
+    pub(crate) weighted_oit_pass: WeightedOitFeature,
     pub(crate) transmission_copy_pass: TransmissionCopyFeature,
     pub(crate) simple_forward_pass: SimpleForwardFeature,
     pub(crate) ssss_pass: SsssFeature,
@@ -94,8 +110,12 @@ struct RendererState {
     // Shadow + Compute passes (migrated from old system)
     pub(crate) shadow_pass: ShadowFeature,
     pub(crate) brdf_pass: BrdfLutFeature,
+    pub(crate) gpu_culling_pass: GpuCullingFeature,
+    pub(crate) light_cluster_pass: LightClusterFeature,
+    pub(crate) occlusion_pass: OcclusionCullingFeature,
     pub(crate) equirect_to_cube_pass: EquirectToCubeFeature,
     pub(crate) ibl_pass: IblComputeFeature,
+    pub(crate) light_probe_pass: LightProbeFeature,
     pub(crate) atmosphere_pass: AtmosphereFeature,
 
     #[cfg(feature = "3dgs")]
@@ -106,11 +126,20 @@ struct RendererState {
     #[cfg(feature = "debug_view")]
     pub(crate) debug_view_pass: DebugViewFeature,
 
+    /// Statistics of the most recently rendered frame.
+    last_frame_stats: RenderStats,
+    /// Per-pass GPU timestamps (`None` without timestamp query support).
+    gpu_profiler: Option<GpuProfiler>,
+
     /// Cached staging buffer for synchronous `readback_pixels()`.
     /// Re-used across calls when the required size has not changed.
     cached_readback_buffer: Option<wgpu::Buffer>,
     /// Size (in bytes) of the cached readback buffer.
     cached_readback_buffer_size: u64,
+
+    /// Watches [`RendererSettings::shader_watch_dir`] for template edits.
+    #[cfg(all(feature = "shader-hot-reload", not(target_arch = "wasm32")))]
+    shader_watcher: Option<crate::pipeline::shader_watch::ShaderWatcher>,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -137,9 +166,28 @@ impl Renderer {
             settings,
             context: None,
             size: (0, 0),
+            prewarm_queue: Vec::new(),
         }
     }
 
+    /// Creates a renderer that draws into an owned offscreen texture.
+    ///
+    /// Shorthand for [`new`](Self::new) with the default init config followed
+    /// by [`init_headless`](Self::init_headless) with the default `Rgba8Unorm`
+    /// (sRGB) target. No window or surface is required, so this works on
+    /// servers and in CLI tools. Render with
+    /// [`render_to_texture`](Self::render_to_texture) and fetch the pixels
+    /// with [`readback_pixels`](Self::readback_pixels).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no suitable adapter or device is available.
+    pub async fn new_headless(settings: RendererSettings, width: u32, height: u32) -> Result<Self> {
+        let mut renderer = Self::new(RendererInitConfig::default(), settings);
+        renderer.init_headless(width, height, None).await?;
+        Ok(renderer)
+    }
+
     /// Returns the current surface size in pixels as `(width, height)`.
     #[inline]
     #[must_use]
@@ -168,6 +216,8 @@ impl Renderer {
             WgpuContext::new(window, &self.init_config, &self.settings, width, height).await?;
 
         self.assemble_state(wgpu_ctx);
+        let cache_path = self.settings.pipeline_cache_path.clone();
+        self.open_pipeline_cache(cache_path.as_deref());
         log::info!("Renderer initialized (windowed)");
         Ok(())
     }
@@ -208,25 +258,32 @@ impl Renderer {
         .await?;
 
         self.assemble_state(wgpu_ctx);
+        let cache_path = self.settings.pipeline_cache_path.clone();
+        self.open_pipeline_cache(cache_path.as_deref());
         log::info!("Renderer initialized (headless {width}×{height})");
         Ok(())
     }
 
     /// Assembles the internal renderer state from a fully initialised GPU context.
     fn assemble_state(&mut self, wgpu_ctx: WgpuContext) {
-        let resource_manager = ResourceManager::new(
+        let mut resource_manager = ResourceManager::new(
             wgpu_ctx.device.clone(),
             wgpu_ctx.queue.clone(),
             self.settings.anisotropy_clamp,
+            wgpu_ctx.supports_gpu_driven_draws(),
         );
+        resource_manager.set_texture_upload_budget(self.settings.texture_upload_budget);
 
         let render_frame = RenderFrame::new();
         let global_bind_group_cache = GlobalBindGroupCache::new();
 
         let shadow_pass = ShadowFeature::new(&wgpu_ctx.device);
         let brdf_pass = BrdfLutFeature::new(&wgpu_ctx.device);
+        let gpu_culling_pass = GpuCullingFeature::new(&wgpu_ctx.device);
+        let light_cluster_pass = LightClusterFeature::new(&wgpu_ctx.device);
         let equirect_to_cube_pass = EquirectToCubeFeature::new(&wgpu_ctx.device);
         let ibl_pass = IblComputeFeature::new(&wgpu_ctx.device);
+        let gpu_profiler = GpuProfiler::new(&wgpu_ctx.device, &wgpu_ctx.queue);
 
         self.context = Some(RendererState {
             wgpu_ctx,
@@ -239,19 +296,26 @@ impl Renderer {
             global_bind_group_cache,
 
             graph_storage: GraphStorage::new(),
+            hook_anchor_warnings: HookAnchorWarnings::default(),
             transient_pool: TransientPool::new(),
             frame_arena: FrameArena::new(),
             fxaa_pass: FxaaFeature::new(),
             taa_pass: TaaFeature::new(),
             cas_pass: CasFeature::new(),
             tone_map_pass: ToneMappingFeature::new(),
+            auto_exposure_pass: AutoExposureFeature::new(),
             bloom_pass: BloomFeature::new(),
             ssao_pass: SsaoFeature::new(),
 
             prepass: PrepassFeature::new(),
             opaque_pass: OpaqueFeature::new(),
             skybox_pass: SkyboxFeature::new(),
+            debug_lines_pass: DebugLinesFeature::new(),
+            grid_pass: GridFeature::new(),
+            ground_shadow_pass: GroundShadowFeature::new(),
+            decal_pass: DecalFeature::new(),
             transparent_pass: TransparentFeature::new(),
+            weighted_oit_pass: WeightedOitFeature::new(),
             transmission_copy_pass: TransmissionCopyFeature::new(),
             simple_forward_pass: SimpleForwardFeature::new(),
             ssss_pass: SsssFeature::new(),
@@ -259,8 +323,12 @@ impl Renderer {
 
             shadow_pass,
             brdf_pass,
+            gpu_culling_pass,
+            light_cluster_pass,
+            occlusion_pass: OcclusionCullingFeature::new(),
             equirect_to_cube_pass,
             ibl_pass,
+            light_probe_pass: LightProbeFeature::new(),
             atmosphere_pass: AtmosphereFeature::new(),
 
             #[cfg(feature = "3dgs")]
@@ -269,9 +337,85 @@ impl Renderer {
             #[cfg(feature = "debug_view")]
             debug_view_pass: DebugViewFeature::new(),
 
+            last_frame_stats: RenderStats::default(),
+            gpu_profiler,
             cached_readback_buffer: None,
             cached_readback_buffer_size: 0,
+
+            #[cfg(all(feature = "shader-hot-reload", not(target_arch = "wasm32")))]
+            shader_watcher: self
+                .settings
+                .shader_watch_dir
+                .clone()
+                .map(crate::pipeline::shader_watch::ShaderWatcher::new),
         });
+
+        #[cfg(feature = "shader-hot-reload")]
+        crate::pipeline::shader_manager::set_shader_dir(self.settings.shader_watch_dir.clone());
+    }
+
+    /// Reloads all shader templates and rebuilds the pipelines that use them.
+    ///
+    /// Templates are re-read from [`RendererSettings::shader_watch_dir`] (or
+    /// the embedded copies), the material pipeline cache is invalidated, and
+    /// every built-in pass re-requests its pipelines on the next frame.
+    /// Pipelines whose final WGSL is unchanged are reused from the cache.
+    ///
+    /// A shader that fails to render or compile is logged and keeps its last
+    /// good version. Called automatically when the watched directory
+    /// changes; use this as a manual trigger.
+    #[cfg(feature = "shader-hot-reload")]
+    pub fn reload_shaders(&mut self) {
+        let Some(old) = self.context.take() else {
+            return;
+        };
+
+        // Rebuild the pass features (they hold their own pipeline ids) while
+        // keeping the GPU context, resources and the shader/pipeline caches.
+        let RendererState {
+            wgpu_ctx,
+            resource_manager,
+            mut pipeline_cache,
+            mut shader_manager,
+            render_frame,
+            render_lists,
+            global_bind_group_cache,
+            graph_storage,
+            transient_pool,
+            frame_arena,
+            cached_readback_buffer,
+            cached_readback_buffer_size,
+            #[cfg(not(target_arch = "wasm32"))]
+            shader_watcher,
+            ..
+        } = old;
+
+        shader_manager.reload();
+        pipeline_cache.invalidate_shader_dependents();
+
+        self.assemble_state(wgpu_ctx);
+        let state = self
+            .context
+            .as_mut()
+            .expect("renderer state was just assembled");
+        state.resource_manager = resource_manager;
+        state.pipeline_cache = pipeline_cache;
+        state.shader_manager = shader_manager;
+        state.render_frame = render_frame;
+        state.render_lists = render_lists;
+        state.global_bind_group_cache = global_bind_group_cache;
+        state.graph_storage = graph_storage;
+        state.transient_pool = transient_pool;
+        state.frame_arena = frame_arena;
+        state.cached_readback_buffer = cached_readback_buffer;
+        state.cached_readback_buffer_size = cached_readback_buffer_size;
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            state.shader_watcher = shader_watcher;
+        }
+        state.wgpu_ctx.pipeline_settings_version += 1;
+
+        log::info!("Shaders reloaded");
     }
 
     pub fn resize(&mut self, width: u32, height: u32) {
@@ -280,6 +424,8 @@ impl Renderer {
             state.wgpu_ctx.resize(width, height);
             // Invalidate all cached bind groups — texture views are now stale.
             state.global_bind_group_cache.clear();
+            // Occlusion results were measured against the old depth buffer.
+            state.occlusion_pass.invalidate();
         }
     }
 
@@ -322,7 +468,199 @@ impl Renderer {
             return None;
         }
 
+        #[cfg(all(feature = "shader-hot-reload", not(target_arch = "wasm32")))]
+        if self
+            .context
+            .as_mut()
+            .and_then(|state| state.shader_watcher.as_mut())
+            .is_some_and(|watcher| watcher.poll(std::time::Instant::now()))
+        {
+            self.reload_shaders();
+        }
+
+        self.capture_reflection_probes(scene, &camera, assets, frame_time);
+        self.bake_light_probes(scene, &camera, assets, frame_time);
+        self.prepare_frame(scene, camera, assets, frame_time, None)
+    }
+
+    /// Renders the faces of the scene's reflection probes whose capture is
+    /// pending.
+    ///
+    /// Runs before the main frame, so its materials already see the new
+    /// captures. Each face is a full scene frame without post-processing.
+    /// The main camera's history and occlusion results are preserved
+    /// across them.
+    fn capture_reflection_probes(
+        &mut self,
+        scene: &mut Scene,
+        camera: &RenderCamera,
+        assets: &AssetServer,
+        frame_time: FrameTime,
+    ) {
+        let Some(state) = self.context.as_mut() else {
+            return;
+        };
+        // Probes rely on the HDR scene colour of the HighFidelity path.
+        if !state.wgpu_ctx.render_path.supports_post_processing() {
+            return;
+        }
+
+        let scene_id = scene.id();
+        let pending: Vec<_> = scene
+            .iter_active_reflection_probes()
+            .filter(|(node, probe, _)| {
+                state
+                    .resource_manager
+                    .reflection_probe_needs_capture(scene_id, *node, probe)
+            })
+            .map(|(node, probe, position)| (node, *probe, position))
+            .collect();
+        if pending.is_empty() {
+            return;
+        }
+
+        let history = state.render_frame.render_state.camera_history();
+        let msaa_samples = camera.aa_mode.msaa_sample_count();
+
+        for (node, probe, position) in pending {
+            if let Some(state) = self.context.as_mut() {
+                state
+                    .resource_manager
+                    .ensure_reflection_probe(scene_id, node, probe.resolution);
+            }
+
+            self.render_probe_faces(
+                scene,
+                ProbeCaptureKind::Reflection(node),
+                position,
+                probe.near,
+                msaa_samples,
+                assets,
+                frame_time,
+            );
+
+            if let Some(state) = self.context.as_mut() {
+                state.resource_manager.mark_reflection_probe_captured(
+                    scene_id,
+                    node,
+                    probe.capture_version(),
+                );
+            }
+        }
+
+        if let Some(state) = self.context.as_mut() {
+            state
+                .render_frame
+                .render_state
+                .restore_camera_history(history);
+            state.occlusion_pass.invalidate();
+        }
+    }
+
+    /// Renders the scene's light probes if their bake is pending.
+    ///
+    /// Like reflection probe captures, each probe renders six faces before
+    /// the main frame, preserving the main camera's history and occlusion
+    /// results.
+    fn bake_light_probes(
+        &mut self,
+        scene: &mut Scene,
+        camera: &RenderCamera,
+        assets: &AssetServer,
+        frame_time: FrameTime,
+    ) {
+        let Some(state) = self.context.as_mut() else {
+            return;
+        };
+        // Probes rely on the HDR scene colour of the HighFidelity path.
+        if !state.wgpu_ctx.render_path.supports_post_processing() {
+            return;
+        }
+
+        let scene_id = scene.id();
+        let Some(volume) = scene.light_probes().copied() else {
+            return;
+        };
+        if !state
+            .resource_manager
+            .light_probes_need_bake(scene_id, &volume)
+        {
+            return;
+        }
+
+        state
+            .resource_manager
+            .ensure_light_probe_capture(scene_id, volume.capture_resolution);
+        let history = state.render_frame.render_state.camera_history();
+        let msaa_samples = camera.aa_mode.msaa_sample_count();
+
+        for index in 0..volume.probe_count() {
+            self.render_probe_faces(
+                scene,
+                ProbeCaptureKind::LightProbe(index as u32),
+                volume.probe_position(index),
+                volume.near,
+                msaa_samples,
+                assets,
+                frame_time,
+            );
+        }
+
+        if let Some(state) = self.context.as_mut() {
+            state
+                .resource_manager
+                .mark_light_probes_baked(scene_id, volume.bake_version());
+            state
+                .render_frame
+                .render_state
+                .restore_camera_history(history);
+            state.occlusion_pass.invalidate();
+        }
+    }
+
+    /// Renders the six capture frames of one probe at `position`.
+    fn render_probe_faces(
+        &mut self,
+        scene: &mut Scene,
+        kind: ProbeCaptureKind,
+        position: Vec3,
+        near: f32,
+        msaa_samples: u32,
+        assets: &AssetServer,
+        frame_time: FrameTime,
+    ) {
+        let scene_id = scene.id();
+        for face in 0..6 {
+            // Occlusion results belong to the previous viewpoint.
+            if let Some(state) = self.context.as_mut() {
+                state.occlusion_pass.invalidate();
+            }
+            let face_camera = reflection_probe::face_camera(position, near, face, msaa_samples);
+            let target = ProbeCaptureTarget {
+                scene_id,
+                kind,
+                face,
+            };
+            if let Some(composer) =
+                self.prepare_frame(scene, face_camera, assets, frame_time, Some(target))
+            {
+                composer.render();
+            }
+        }
+    }
+
+    /// Extracts and prepares a frame, for the main camera or, with
+    /// `probe_capture`, for one reflection or light probe face.
+    fn prepare_frame<'a>(
+        &'a mut self,
+        scene: &'a mut Scene,
+        camera: RenderCamera,
+        assets: &'a AssetServer,
+        frame_time: FrameTime,
+        probe_capture: Option<ProbeCaptureTarget>,
+    ) -> Option<FrameComposer<'a>> {
         let state = self.context.as_mut()?;
+        let pipelines_at_frame_start = state.pipeline_cache.render_pipeline_count();
 
         // ── Frame Arena Lifecycle ───────────────────────────────────────
         // Reset the arena in O(1) — all previous PassNodes are trivially
@@ -334,7 +672,12 @@ impl Renderer {
 
         // ── Phase 1: Extract scene, build shadow views, prepare global ──
 
-        let surface_size = state.wgpu_ctx.size();
+        let (frame_size, surface_size) = if let Some(capture) = probe_capture {
+            let resolution = capture.cube(&state.resource_manager)?.0.width();
+            ((resolution, resolution), (resolution, resolution))
+        } else {
+            (self.size, state.wgpu_ctx.size())
+        };
         state.render_frame.extract_and_prepare(
             &mut state.resource_manager,
             scene,
@@ -343,15 +686,46 @@ impl Renderer {
             frame_time,
             &mut state.render_lists,
             surface_size,
+            probe_capture.is_none(),
+            state.wgpu_ctx.clustered_lighting_active(),
         );
 
-        let requested_msaa = camera.aa_mode.msaa_sample_count();
+        let requested_msaa = state
+            .wgpu_ctx
+            .supported_msaa_samples(camera.aa_mode.msaa_sample_count());
         if state.wgpu_ctx.msaa_samples != requested_msaa {
             state.wgpu_ctx.msaa_samples = requested_msaa;
             state.wgpu_ctx.pipeline_settings_version += 1;
         }
 
+        // Stencil materials need a stencil aspect in the scene depth buffer.
+        // The switch is one-way, so the scan stops once it has happened.
+        if !state.wgpu_ctx.depth_format.has_stencil_aspect() {
+            let materials = assets.materials.read_lock();
+            let uses_stencil = state
+                .render_frame
+                .extracted_scene
+                .render_items
+                .iter()
+                .any(|item| {
+                    materials
+                        .get_loaded(item.material)
+                        .is_some_and(|material| material.stencil().is_some())
+                });
+            if uses_stencil {
+                state.wgpu_ctx.depth_format = state.wgpu_ctx.stencil_depth_format();
+                state.wgpu_ctx.pipeline_settings_version += 1;
+            }
+        }
+
+        state.render_frame.extracted_scene.extract_prewarm_items(
+            &mut self.prewarm_queue,
+            assets,
+            &mut state.resource_manager,
+        );
+
         // ── Phase 2: Cull + sort + command generation ───────────────────
+        state.occlusion_pass.begin_frame(&state.wgpu_ctx.device);
         crate::graph::culling::cull_and_sort(
             &state.render_frame.extracted_scene,
             &state.render_frame.render_state,
@@ -362,6 +736,7 @@ impl Renderer {
             &mut state.render_lists,
             &camera,
             assets,
+            state.occlusion_pass.occluded_nodes(),
         );
 
         // ── Phase 2.5: Feature extract & prepare ────────────────────────
@@ -405,12 +780,15 @@ impl Renderer {
             #[cfg(not(feature = "debug_view"))]
             let (dbg_needs_normal, dbg_needs_velocity) = (false, false);
 
-            let needs_normal = ssao_enabled || needs_feature_id || dbg_needs_normal;
+            let needs_normal =
+                ssao_enabled || needs_feature_id || dbg_needs_normal || self.settings.scene_normals;
             let needs_velocity = camera.aa_mode.is_taa() || dbg_needs_velocity;
 
             // let needs_normal = ssao_enabled || needs_feature_id;
             let needs_skybox = scene.background.needs_skybox_pass();
-            let bloom_enabled = scene.bloom.enabled && is_hf;
+            // Probe captures store linear HDR colour, before post-processing.
+            let post_process = is_hf && probe_capture.is_none();
+            let bloom_enabled = scene.bloom.enabled && post_process;
 
             let mut extract_ctx = ExtractContext {
                 device: &state.wgpu_ctx.device,
@@ -429,6 +807,13 @@ impl Renderer {
 
             // Always: compute + shadow
             state.brdf_pass.extract_and_prepare(&mut extract_ctx);
+            state.gpu_culling_pass.extract_and_prepare(&mut extract_ctx);
+            state
+                .light_cluster_pass
+                .extract_and_prepare(&mut extract_ctx, scene);
+            state
+                .occlusion_pass
+                .extract_and_prepare(&mut extract_ctx, global_state_key);
             if scene.environment.has_env_map() {
                 state
                     .equirect_to_cube_pass
@@ -437,6 +822,24 @@ impl Renderer {
             state
                 .ibl_pass
                 .extract_and_prepare(&mut extract_ctx, scene.id());
+            if let Some(capture) = probe_capture.filter(ProbeCaptureTarget::is_last_face) {
+                match capture.kind {
+                    ProbeCaptureKind::Reflection(node) => {
+                        state.ibl_pass.extract_and_prepare_probe(
+                            &mut extract_ctx,
+                            capture.scene_id,
+                            node,
+                        );
+                    }
+                    ProbeCaptureKind::LightProbe(index) => {
+                        state.light_probe_pass.extract_and_prepare(
+                            &mut extract_ctx,
+                            &scene.light_probe_buffer,
+                            index,
+                        );
+                    }
+                }
+            }
             state.shadow_pass.extract_and_prepare(&mut extract_ctx);
 
             // Procedural atmosphere (LUT + cubemap + PMREM compute)
@@ -469,6 +872,35 @@ impl Renderer {
                 );
             }
 
+            // Debug lines and ground grid (both pipelines; each resets itself
+            // when there is nothing to draw)
+            {
+                let color_format = if is_hf {
+                    HDR_TEXTURE_FORMAT
+                } else {
+                    view_format
+                };
+                state.debug_lines_pass.extract_and_prepare(
+                    &mut extract_ctx,
+                    global_state_key,
+                    color_format,
+                );
+                state.grid_pass.extract_and_prepare(
+                    &mut extract_ctx,
+                    global_state_key,
+                    color_format,
+                );
+            }
+
+            // Ground shadow (HighFidelity only)
+            if is_hf {
+                state.ground_shadow_pass.extract_and_prepare(
+                    &mut extract_ctx,
+                    global_state_key,
+                    HDR_TEXTURE_FORMAT,
+                );
+            }
+
             #[cfg(feature = "3dgs")]
             // Gaussian Splatting
             if scene.has_gaussian_clouds() {
@@ -490,6 +922,14 @@ impl Renderer {
             }
 
             if is_hf {
+                // Decals need the prepass depth, so only the HighFidelity
+                // path draws them.
+                state.decal_pass.extract_and_prepare(
+                    &mut extract_ctx,
+                    global_state_key,
+                    HDR_TEXTURE_FORMAT,
+                );
+
                 if let Some(taa_settins) = camera.aa_mode.taa_settings() {
                     state.taa_pass.extract_and_prepare(
                         &mut extract_ctx,
@@ -522,6 +962,7 @@ impl Renderer {
                 );
 
                 if ssao_enabled {
+                    scene.ssao.update_noise_scale(frame_size.0, frame_size.1);
                     state
                         .ssao_pass
                         .extract_and_prepare(&mut extract_ctx, &scene.ssao.uniforms);
@@ -539,22 +980,41 @@ impl Renderer {
                         .extract_and_prepare(&mut extract_ctx, msaa);
                 }
 
+                if !extract_ctx.render_lists.weighted_oit.is_empty() {
+                    state
+                        .weighted_oit_pass
+                        .extract_and_prepare(&mut extract_ctx, msaa);
+                }
+
                 if bloom_enabled {
                     state.bloom_pass.extract_and_prepare(
                         &mut extract_ctx,
+                        &scene.bloom.prefilter_uniforms,
                         &scene.bloom.upsample_uniforms,
                         &scene.bloom.composite_uniforms,
+                        scene.bloom.dirt_texture,
                     );
                 }
 
-                state.tone_map_pass.extract_and_prepare(
-                    &mut extract_ctx,
-                    scene.tone_mapping.mode,
-                    view_format,
-                    global_state_key,
-                    &scene.tone_mapping.uniforms,
-                    scene.tone_mapping.lut_texture,
-                );
+                if post_process {
+                    state.tone_map_pass.extract_and_prepare(
+                        &mut extract_ctx,
+                        scene.tone_mapping.mode,
+                        view_format,
+                        global_state_key,
+                        &scene.tone_mapping.uniforms,
+                        scene.tone_mapping.lut_texture,
+                    );
+                }
+
+                if post_process && scene.tone_mapping.auto_exposure.enabled {
+                    state.auto_exposure_pass.extract_and_prepare(
+                        &mut extract_ctx,
+                        &scene.tone_mapping.auto_exposure,
+                        &scene.tone_mapping.uniforms,
+                        frame_time.delta_time,
+                    );
+                }
 
                 // Debug View — prepare pipeline & uniforms when active
                 #[cfg(feature = "debug_view")]
@@ -606,22 +1066,39 @@ impl Renderer {
             camera,
             assets,
             frame_time,
+            scene_normals: self.settings.scene_normals,
+            probe_capture,
 
             graph_storage: &mut state.graph_storage,
+            hook_anchor_warnings: &mut state.hook_anchor_warnings,
             transient_pool: &mut state.transient_pool,
             // sampler_registry: &mut state.sampler_registry,
             frame_arena: &state.frame_arena,
+            frame_stats: &mut state.last_frame_stats,
+            pipelines_at_frame_start,
+            // Only the main frame is profiled.
+            gpu_profiler: if probe_capture.is_none() {
+                state.gpu_profiler.as_mut()
+            } else {
+                None
+            },
             fxaa_pass: &mut state.fxaa_pass,
             taa_pass: &mut state.taa_pass,
             cas_pass: &mut state.cas_pass,
             tone_map_pass: &mut state.tone_map_pass,
+            auto_exposure_pass: &mut state.auto_exposure_pass,
             bloom_pass: &mut state.bloom_pass,
             ssao_pass: &mut state.ssao_pass,
 
             prepass: &mut state.prepass,
             opaque_pass: &mut state.opaque_pass,
             skybox_pass: &mut state.skybox_pass,
+            debug_lines_pass: &mut state.debug_lines_pass,
+            grid_pass: &mut state.grid_pass,
+            ground_shadow_pass: &mut state.ground_shadow_pass,
+            decal_pass: &mut state.decal_pass,
             transparent_pass: &mut state.transparent_pass,
+            weighted_oit_pass: &mut state.weighted_oit_pass,
             transmission_copy_pass: &mut state.transmission_copy_pass,
             simple_forward_pass: &mut state.simple_forward_pass,
             ssss_pass: &mut state.ssss_pass,
@@ -629,8 +1106,12 @@ impl Renderer {
 
             shadow_pass: &mut state.shadow_pass,
             brdf_pass: &mut state.brdf_pass,
+            gpu_culling_pass: &mut state.gpu_culling_pass,
+            light_cluster_pass: &mut state.light_cluster_pass,
+            occlusion_pass: &mut state.occlusion_pass,
             equirect_to_cube_pass: &mut state.equirect_to_cube_pass,
             ibl_pass: &mut state.ibl_pass,
+            light_probe_pass: &mut state.light_probe_pass,
             atmosphere_pass: &mut state.atmosphere_pass,
 
             #[cfg(feature = "3dgs")]
@@ -641,7 +1122,7 @@ impl Renderer {
         };
 
         // Return FrameComposer, defer Surface acquisition to render() call
-        Some(FrameComposer::new(ctx, self.size))
+        Some(FrameComposer::new(ctx, frame_size))
     }
 
     /// Performs periodic resource cleanup.
@@ -702,6 +1183,30 @@ impl Renderer {
                 log::info!("RenderPath changed to {:?}", self.settings.path);
             }
 
+            // Wireframe
+            if old.wireframe != self.settings.wireframe
+                || old.barycentric_wireframe != self.settings.barycentric_wireframe
+            {
+                state.wgpu_ctx.wireframe = self.settings.wireframe;
+                state.wgpu_ctx.barycentric_wireframe = self.settings.barycentric_wireframe;
+                state.wgpu_ctx.pipeline_settings_version += 1;
+            }
+
+            // GPU culling
+            if old.gpu_culling != self.settings.gpu_culling {
+                state.wgpu_ctx.gpu_culling = self.settings.gpu_culling;
+            }
+
+            // Clustered lighting
+            if old.clustered_lighting != self.settings.clustered_lighting {
+                state.wgpu_ctx.clustered_lighting = self.settings.clustered_lighting;
+            }
+
+            // Background pipeline compilation
+            if old.async_pipeline_compilation != self.settings.async_pipeline_compilation {
+                state.wgpu_ctx.async_pipelines = self.settings.async_pipeline_compilation;
+            }
+
             // Anisotropy
             if old.anisotropy_clamp != self.settings.anisotropy_clamp {
                 state
@@ -713,6 +1218,25 @@ impl Renderer {
                     self.settings.anisotropy_clamp
                 );
             }
+
+            // Streaming texture upload budget
+            if old.texture_upload_budget != self.settings.texture_upload_budget {
+                state
+                    .resource_manager
+                    .set_texture_upload_budget(self.settings.texture_upload_budget);
+            }
+        }
+
+        // Shader watch directory
+        #[cfg(feature = "shader-hot-reload")]
+        if old.shader_watch_dir != self.settings.shader_watch_dir {
+            let dir = self.settings.shader_watch_dir.clone();
+            crate::pipeline::shader_manager::set_shader_dir(dir.clone());
+            #[cfg(not(target_arch = "wasm32"))]
+            if let Some(state) = &mut self.context {
+                state.shader_watcher = dir.map(crate::pipeline::shader_watch::ShaderWatcher::new);
+            }
+            self.reload_shaders();
         }
     }
 
@@ -785,6 +1309,194 @@ impl Renderer {
         self.context.as_ref().map(|s| &s.wgpu_ctx)
     }
 
+    /// Returns the GPU duration of each render graph pass in milliseconds,
+    /// in execution order.
+    ///
+    /// Results are read back asynchronously and typically describe a frame
+    /// rendered one or two frames ago. Returns an empty list until the first
+    /// readback completes, or always when the adapter does not support
+    /// `TIMESTAMP_QUERY` and `TIMESTAMP_QUERY_INSIDE_ENCODERS`.
+    #[must_use]
+    pub fn pass_timings(&self) -> Vec<(String, f32)> {
+        self.context
+            .as_ref()
+            .and_then(|s| s.gpu_profiler.as_ref())
+            .map(|p| p.latest().to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Returns how many objects passed the GPU frustum test (see
+    /// [`RendererSettings::gpu_culling`]).
+    ///
+    /// The count is read back asynchronously and typically describes a
+    /// frame rendered one or two frames ago. Returns `None` until the first
+    /// readback completes and while GPU culling is off or unsupported.
+    #[must_use]
+    pub fn gpu_culling_survivors(&self) -> Option<u32> {
+        self.context
+            .as_ref()
+            .and_then(|s| s.gpu_culling_pass.survivors())
+    }
+
+    /// Returns `(object, instance_count)` for every GPU-culled draw of the
+    /// last frame, as left in the indirect arguments by the culling pass.
+    /// Culled draws have an instance count of 0. Objects index the frame's
+    /// bounding spheres, so draws of the same mesh share one.
+    ///
+    /// Blocks until the GPU is idle; intended for tests and debugging.
+    /// Empty while GPU culling is off or unsupported.
+    #[must_use]
+    pub fn gpu_culling_instance_counts(&self) -> Vec<(u32, u32)> {
+        self.context.as_ref().map_or_else(Vec::new, |s| {
+            s.gpu_culling_pass
+                .read_instance_counts(&s.wgpu_ctx.device, &s.wgpu_ctx.queue)
+        })
+    }
+
+    /// Returns `true` if GPU-culled draws read their object data by
+    /// instance from storage buffers (see
+    /// [`RendererSettings::gpu_culling`](crate::settings::RendererSettings::gpu_culling)).
+    #[must_use]
+    pub fn gpu_driven_draws_active(&self) -> bool {
+        self.context
+            .as_ref()
+            .is_some_and(|s| s.wgpu_ctx.gpu_driven_active())
+    }
+
+    /// Returns culling and draw statistics of the most recently rendered
+    /// frame (all zeros before the first frame).
+    #[must_use]
+    pub fn last_frame_stats(&self) -> RenderStats {
+        self.context
+            .as_ref()
+            .map(|s| s.last_frame_stats)
+            .unwrap_or_default()
+    }
+
+    /// Compiles the main-camera pipeline for drawing `geometry` with
+    /// `material` during the next frame, before any mesh uses them.
+    ///
+    /// The pipeline matches a plain mesh (unskinned, receiving shadows)
+    /// rendered into the scene of that frame, so prewarm while the target
+    /// scene is active. With
+    /// [`async_pipeline_compilation`](RendererSettings::async_pipeline_compilation)
+    /// it is compiled in the background; otherwise during the next
+    /// `begin_frame`. Requests for assets that are still loading wait until
+    /// they have loaded.
+    pub fn prewarm(&mut self, material: MaterialHandle, geometry: GeometryHandle) {
+        self.prewarm_queue.push((material, geometry));
+    }
+
+    /// Number of material pipelines still compiling in the background.
+    #[must_use]
+    pub fn pending_pipelines(&self) -> usize {
+        self.context
+            .as_ref()
+            .map_or(0, |s| s.pipeline_cache.pending_count())
+    }
+
+    /// Blocks until every background pipeline compilation has finished,
+    /// e.g. behind a loading screen after [`prewarm`](Self::prewarm).
+    ///
+    /// Meshes waiting for those pipelines are drawn from the next frame on.
+    pub fn wait_for_pipelines(&mut self) {
+        if let Some(state) = &mut self.context {
+            state.pipeline_cache.wait_pending();
+        }
+    }
+
+    /// Returns `true` if the backend supports driver pipeline caches, so
+    /// [`save_pipeline_cache`](Self::save_pipeline_cache) has something to
+    /// write.
+    #[must_use]
+    pub fn supports_pipeline_cache(&self) -> bool {
+        self.context
+            .as_ref()
+            .is_some_and(|s| s.wgpu_ctx.supports_pipeline_cache())
+    }
+
+    /// Replaces the driver pipeline cache with the one saved at `path`.
+    ///
+    /// Pipelines created afterwards reuse the driver's compiled state from
+    /// the file; pipelines that already exist are unaffected, so call this
+    /// right after initialization (or set
+    /// [`RendererSettings::pipeline_cache_path`], which does so
+    /// automatically).
+    ///
+    /// Returns `true` if the file held a cache for this adapter and driver.
+    /// A missing or stale file starts an empty cache instead; on backends
+    /// without pipeline cache support nothing happens.
+    pub fn load_pipeline_cache(&mut self, path: impl AsRef<std::path::Path>) -> bool {
+        self.open_pipeline_cache(Some(path.as_ref()))
+    }
+
+    /// Writes the driver pipeline cache to `path`, tagged with the adapter
+    /// and driver so other machines and driver updates ignore it.
+    ///
+    /// Returns `Ok(false)` without writing when the backend has no pipeline
+    /// cache or the renderer is not initialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save_pipeline_cache(&self, path: impl AsRef<std::path::Path>) -> std::io::Result<bool> {
+        let Some(state) = &self.context else {
+            return Ok(false);
+        };
+        let Some(data) = state
+            .pipeline_cache
+            .driver_cache()
+            .and_then(wgpu::PipelineCache::get_data)
+        else {
+            return Ok(false);
+        };
+        let identity = disk_cache::cache_identity(&state.wgpu_ctx.adapter_info);
+        disk_cache::write(path.as_ref(), &identity, &data)?;
+        log::debug!(
+            "Saved {} bytes of pipeline cache to {}",
+            data.len(),
+            path.as_ref().display()
+        );
+        Ok(true)
+    }
+
+    /// Creates the driver pipeline cache, seeded from `path` when it holds
+    /// a cache for this adapter. Returns whether data was loaded.
+    fn open_pipeline_cache(&mut self, path: Option<&std::path::Path>) -> bool {
+        let Some(state) = &mut self.context else {
+            return false;
+        };
+        if !state.wgpu_ctx.supports_pipeline_cache() {
+            return false;
+        }
+
+        let identity = disk_cache::cache_identity(&state.wgpu_ctx.adapter_info);
+        let data = path.and_then(|path| disk_cache::read(path, &identity));
+        // SAFETY: the data was written by `save_pipeline_cache` for this
+        // adapter and driver, and with `fallback` wgpu validates it and
+        // starts empty instead if it does not match.
+        let cache = unsafe {
+            state
+                .wgpu_ctx
+                .device
+                .create_pipeline_cache(&wgpu::PipelineCacheDescriptor {
+                    label: Some("Driver Pipeline Cache"),
+                    data: data.as_deref(),
+                    fallback: true,
+                })
+        };
+        state.pipeline_cache.set_driver_cache(Some(cache));
+
+        if let (Some(data), Some(path)) = (&data, path) {
+            log::info!(
+                "Loaded {} bytes of pipeline cache from {}",
+                data.len(),
+                path.display()
+            );
+        }
+        data.is_some()
+    }
+
     pub fn dump_graph_mermaid(&self) -> Option<String> {
         self.context
             .as_ref()
@@ -831,6 +1543,40 @@ impl Renderer {
             .is_some_and(|s| s.wgpu_ctx.is_headless())
     }
 
+    /// Renders one frame into the headless render target and returns it.
+    ///
+    /// Runs the same pass graph as [`begin_frame`](Self::begin_frame), but
+    /// without custom hooks. The returned texture is the internal offscreen
+    /// target; it stays valid until the next frame or resize. Use
+    /// [`readback_pixels`](Self::readback_pixels) to copy it to the CPU.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the renderer has not been initialised, has a zero
+    /// size, or is not in headless mode.
+    pub fn render_to_texture(
+        &mut self,
+        scene: &mut Scene,
+        camera: RenderCamera,
+        assets: &AssetServer,
+        frame_time: FrameTime,
+    ) -> Result<&wgpu::Texture> {
+        if !self.is_headless() {
+            return Err(if self.context.is_some() {
+                myth_core::RenderError::NoHeadlessTarget.into()
+            } else {
+                myth_core::RenderError::NotInitialized.into()
+            });
+        }
+
+        self.begin_frame(scene, camera, assets, frame_time)
+            .ok_or(myth_core::RenderError::NotInitialized)?
+            .render();
+
+        self.headless_texture()
+            .ok_or_else(|| myth_core::RenderError::NoHeadlessTarget.into())
+    }
+
     /// Reads back the current headless render target as raw pixel data.
     ///
     /// The returned `Vec<u8>` contains tightly-packed pixel data whose per-pixel
@@ -1017,6 +1763,19 @@ impl Renderer {
     }
 }
 
+impl Drop for Renderer {
+    /// Persists the driver pipeline cache to
+    /// [`RendererSettings::pipeline_cache_path`], if set.
+    fn drop(&mut self) {
+        let Some(path) = self.settings.pipeline_cache_path.clone() else {
+            return;
+        };
+        if let Err(e) = self.save_pipeline_cache(&path) {
+            log::warn!("Failed to save pipeline cache to {}: {e}", path.display());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1292,4 +2051,21 @@ mod tests {
             "adding a procedural starbox should trigger an environment rebake"
         );
     }
+
+    #[test]
+    fn render_to_texture_returns_headless_target() {
+        let mut renderer =
+            pollster::block_on(Renderer::new_headless(RendererSettings::default(), 64, 32))
+                .expect("headless renderer init failed");
+        let assets = AssetServer::new();
+        let mut scene = Scene::new();
+
+        let texture = renderer
+            .render_to_texture(&mut scene, make_camera(), &assets, FrameTime::default())
+            .expect("render_to_texture failed");
+        assert_eq!((texture.width(), texture.height()), (64, 32));
+
+        let pixels = renderer.readback_pixels().expect("readback failed");
+        assert_eq!(pixels.len(), 64 * 32 * 4);
+    }
 }
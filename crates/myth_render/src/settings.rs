@@ -253,6 +253,106 @@ pub struct RendererSettings {
     /// Higher values produce sharper textures at oblique angles at a
     /// modest GPU cost. Common values: 1 (disabled), 4, 8, 16.
     pub anisotropy_clamp: u16,
+
+    /// Bytes of streaming-texture data uploaded per frame.
+    ///
+    /// Only textures with [`Texture::streaming`](myth_resources::Texture::streaming)
+    /// set are spread across frames; lower values trade load time for
+    /// smoother frame times.
+    pub texture_upload_budget: u64,
+
+    /// Draw every mesh as a wireframe, regardless of its material's
+    /// [`wireframe`](myth_resources::material::MaterialSettings::wireframe)
+    /// setting.
+    ///
+    /// Uses line polygon mode where the device supports it and falls back to
+    /// a barycentric-coordinate shader otherwise (e.g. on WebGPU). The
+    /// fallback only handles triangle lists; triangle strips are drawn
+    /// filled on such devices.
+    pub wireframe: bool,
+
+    /// Draw wireframes with the barycentric-coordinate fallback even when
+    /// the device supports line polygon mode.
+    ///
+    /// Lets native builds preview and test the path WebGPU takes.
+    pub barycentric_wireframe: bool,
+
+    /// Frustum-cull the main camera's draws in a compute shader.
+    ///
+    /// The CPU only applies layer masks; a compute pass tests each object's
+    /// world bounding sphere against the camera frustum and writes indirect
+    /// draw arguments, so culled objects are drawn with zero instances.
+    /// Ignored on devices without compute shaders or indirect execution
+    /// (e.g. WebGL 2). Instanced draws starting at a non-zero instance are
+    /// only GPU-culled with `INDIRECT_FIRST_INSTANCE`. Shadow views are
+    /// always culled on the CPU.
+    ///
+    /// With `INDIRECT_FIRST_INSTANCE` and vertex storage access, culled
+    /// Unlit, Phong and Physical draws are GPU-driven: their shaders read the
+    /// model matrices from a storage buffer, through an instance table the
+    /// culling pass fills. Runs of such draws sharing pipeline, material and
+    /// geometry are compacted on the GPU and issued as one
+    /// `multi_draw_indirect` call (with a GPU-side count when
+    /// `MULTI_DRAW_INDIRECT_COUNT` is available); other culled draws get one
+    /// indirect draw each.
+    ///
+    /// [`Renderer::gpu_culling_survivors`](crate::Renderer::gpu_culling_survivors)
+    /// reports how many objects passed the test.
+    pub gpu_culling: bool,
+
+    /// Assign lights to a grid of view-frustum clusters in a compute pass.
+    ///
+    /// Lit materials then only evaluate the lights whose range reaches
+    /// their cluster instead of every light in the scene, which keeps
+    /// scenes with dozens or hundreds of point and spot lights fast.
+    /// Scenes with eight lights or fewer always use the plain loop. Ignored
+    /// on devices without compute shaders (e.g. WebGL 2).
+    pub clustered_lighting: bool,
+
+    /// Always render the view-space normal buffer in the `HighFidelity`
+    /// prepass, even when no built-in effect needs it.
+    ///
+    /// Custom passes read it from the
+    /// [`GraphBlackboard`](crate::graph::core::GraphBlackboard) as
+    /// [`SceneAttachment::Normal`](crate::graph::core::SceneAttachment::Normal);
+    /// without this setting it is only present while SSAO, SSS or SSR is
+    /// enabled.
+    pub scene_normals: bool,
+
+    /// Compile new material pipelines on a background thread instead of
+    /// stalling the frame.
+    ///
+    /// Until its pipeline is ready, a mesh is drawn with a small fallback
+    /// pipeline: the same geometry, depth and blending, shaded as a flat
+    /// gray surface. One fallback is compiled in place per vertex layout,
+    /// material layout and pass, and then shared by every material that
+    /// matches. Point sprites, wireframes and materials that place their
+    /// own vertices (sprites, text) have no fallback and are skipped until
+    /// ready. Shadows always use the material's own depth pipeline. Use
+    /// [`Renderer::prewarm`](crate::Renderer::prewarm) to compile ahead of
+    /// time. Native only; on wasm pipelines are always compiled in place.
+    pub async_pipeline_compilation: bool,
+
+    /// File persisting the driver's compiled pipelines between runs.
+    ///
+    /// Read when the renderer initializes and written when it is dropped,
+    /// so pipelines compiled in an earlier run start warm. The file is
+    /// tagged with the adapter and driver and ignored if either changed.
+    /// Only backends with pipeline cache support (currently Vulkan) use it;
+    /// elsewhere the setting has no effect. See
+    /// [`Renderer::save_pipeline_cache`](crate::Renderer::save_pipeline_cache)
+    /// to write it at other times.
+    pub pipeline_cache_path: Option<std::path::PathBuf>,
+
+    /// Directory of WGSL templates to watch and load at runtime.
+    ///
+    /// Files mirror the built-in layout (`entry/main/physical.wgsl`,
+    /// `chunks/...`) and override the embedded copies; a file named after a
+    /// template registered with [`Renderer::register_shader_template`] replaces
+    /// that template. Edits are picked up automatically and the affected
+    /// pipelines are rebuilt. Native only; `None` disables watching.
+    #[cfg(feature = "shader-hot-reload")]
+    pub shader_watch_dir: Option<std::path::PathBuf>,
 }
 
 impl Default for RendererSettings {
@@ -261,6 +361,16 @@ impl Default for RendererSettings {
             path: RenderPath::default(),
             vsync: true,
             anisotropy_clamp: 1,
+            texture_upload_budget: crate::core::ResourceManager::DEFAULT_TEXTURE_UPLOAD_BUDGET,
+            wireframe: false,
+            barycentric_wireframe: false,
+            gpu_culling: false,
+            clustered_lighting: true,
+            scene_normals: false,
+            async_pipeline_compilation: false,
+            pipeline_cache_path: None,
+            #[cfg(feature = "shader-hot-reload")]
+            shader_watch_dir: None,
         }
     }
 }
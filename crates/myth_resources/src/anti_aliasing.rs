@@ -21,6 +21,8 @@ pub enum AntiAliasingMode {
     FXAA(FxaaSettings),
     /// Hardware multi-sampling (e.g. 4×).  Crisp geometric edges but may
     /// exhibit PBR specular flickering.  Best for non-PBR / toon styles.
+    /// Counts the adapter cannot render and resolve (for the HDR target,
+    /// the surface and depth) are clamped down at the start of each frame.
     MSAA(u32),
     /// MSAA + FXAA.  MSAA resolves geometric edges, FXAA removes specular
     /// shimmer.  Best static image quality with zero temporal ghosting.
@@ -5,14 +5,21 @@
 //!
 //! The bloom implementation is based on the physically-based bloom technique from
 //! *Call of Duty: Advanced Warfare*, which uses progressive downsampling with a
-//! 13-tap filter and upsampling with a 3×3 tent filter. Unlike threshold-based
-//! bloom, this approach naturally preserves energy and produces realistic results
-//! in HDR pipelines.
+//! 13-tap filter and upsampling with a 3×3 tent filter. The first downsample
+//! applies a luminance threshold with a soft knee, so only radiance above the
+//! threshold (e.g. strong emissive surfaces) blooms, multiplied by a color
+//! tint. The default threshold of `0.0` disables the cut and keeps the
+//! energy-preserving behaviour.
+//!
+//! An optional lens dirt texture brightens the bloom where the mask is
+//! bright during composition, the smudges lighting up only around strong
+//! light sources.
 //!
 //! # GPU Uniform Structs
 //!
+//! - [`PrefilterUniforms`]: Luminance threshold, soft knee and tint for the first downsample.
 //! - [`UpsampleUniforms`]: Controls the tent filter radius during upsampling.
-//! - [`CompositeUniforms`]: Controls bloom strength during final composition.
+//! - [`CompositeUniforms`]: Controls bloom strength and lens dirt during final composition.
 //!
 //! These structs are defined here (rather than in the render pass) so that
 //! `BloomSettings` can own the `CpuBuffer<T>` instances. User-facing setters
@@ -26,14 +33,29 @@
 //! - [Physically Based Bloom (LearnOpenGL)](https://learnopengl.com/Guest-Articles/2022/Phys.-Based-Bloom)
 //! - *Next Generation Post Processing in Call of Duty: Advanced Warfare* (SIGGRAPH 2014)
 
+use glam::Vec3;
 use myth_macros::gpu_struct;
 
+use crate::TextureHandle;
 use crate::buffer::CpuBuffer;
 
 // ============================================================================
 // GPU Uniform Structs
 // ============================================================================
 
+/// GPU uniform data for the bloom prefilter (first downsample).
+///
+/// Pixels with luminance below `threshold` are cut; `knee` is the absolute
+/// width of the quadratic transition below the threshold. What remains is
+/// multiplied by `tint` before entering the bloom chain.
+#[gpu_struct(crate_path = "crate")]
+pub struct PrefilterUniforms {
+    pub threshold: f32,
+    pub knee: f32,
+    #[default(Vec3::ONE)]
+    pub tint: Vec3,
+}
+
 /// GPU uniform data for the upsample shader.
 ///
 /// Controls the tent filter radius used during the upsampling phase.
@@ -44,10 +66,12 @@ pub struct UpsampleUniforms {
 
 /// GPU uniform data for the composite shader.
 ///
-/// Controls how much bloom contributes to the final image.
+/// Controls how much bloom contributes to the final image. The dirt mask
+/// adds `bloom * dirt * dirt_intensity` on top.
 #[gpu_struct(crate_path = "crate")]
 pub struct CompositeUniforms {
     pub bloom_strength: f32,
+    pub dirt_intensity: f32,
 }
 
 // ============================================================================
@@ -72,6 +96,13 @@ pub struct CompositeUniforms {
 /// bloom.set_enabled(true);
 /// bloom.set_strength(0.04);
 /// bloom.set_radius(0.005);
+/// bloom.set_threshold(1.0);
+/// bloom.set_soft_knee(0.5);
+/// bloom.set_tint(Vec3::new(1.0, 0.8, 0.6));
+///
+/// // Lens dirt over the glow
+/// bloom.set_dirt_texture(Some(dirt_handle));
+/// bloom.set_dirt_intensity(2.0);
 /// ```
 #[derive(Debug, Clone)]
 pub struct BloomSettings {
@@ -96,19 +127,41 @@ pub struct BloomSettings {
     /// Default: `true`
     pub karis_average: bool,
 
+    /// Soft knee as a fraction of the threshold (`0.0`–`1.0`).
+    ///
+    /// Default: `0.5`
+    soft_knee: f32,
+
+    /// Optional lens dirt mask, stretched over the screen.
+    ///
+    /// Sampled during composition only; switching it rebuilds a bind
+    /// group but never the pipeline. `None` disables the dirt.
+    pub dirt_texture: Option<TextureHandle>,
+
+    /// Prefilter uniforms (`threshold`, `knee`, `tint`).
+    /// Updated via `set_threshold()` / `set_soft_knee()` / `set_tint()` — version tracking is automatic.
+    #[doc(hidden)]
+    pub prefilter_uniforms: CpuBuffer<PrefilterUniforms>,
+
     /// Upsample filter uniforms (`filter_radius`).
     /// Updated via `set_radius()` — version tracking is automatic.
     #[doc(hidden)]
     pub upsample_uniforms: CpuBuffer<UpsampleUniforms>,
 
-    /// Composite blend uniforms (`bloom_strength`).
-    /// Updated via `set_strength()` — version tracking is automatic.
+    /// Composite blend uniforms (`bloom_strength`, `dirt_intensity`).
+    /// Updated via `set_strength()` / `set_dirt_intensity()` — version tracking is automatic.
     #[doc(hidden)]
     pub composite_uniforms: CpuBuffer<CompositeUniforms>,
 }
 
 impl Default for BloomSettings {
     fn default() -> Self {
+        let prefilter = PrefilterUniforms {
+            threshold: 0.0,
+            knee: 0.0,
+            ..Default::default()
+        };
+
         let upsample = UpsampleUniforms {
             filter_radius: 0.005,
             ..Default::default()
@@ -123,6 +176,13 @@ impl Default for BloomSettings {
             enabled: false,
             max_mip_levels: 6,
             karis_average: true,
+            soft_knee: 0.5,
+            dirt_texture: None,
+            prefilter_uniforms: CpuBuffer::new(
+                prefilter,
+                wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+                Some("Bloom Prefilter Uniforms"),
+            ),
             upsample_uniforms: CpuBuffer::new(
                 upsample,
                 wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
@@ -158,6 +218,34 @@ impl BloomSettings {
         self.upsample_uniforms.read().filter_radius
     }
 
+    /// Returns the luminance threshold above which pixels bloom.
+    #[inline]
+    #[must_use]
+    pub fn threshold(&self) -> f32 {
+        self.prefilter_uniforms.read().threshold
+    }
+
+    /// Returns the soft knee as a fraction of the threshold.
+    #[inline]
+    #[must_use]
+    pub fn soft_knee(&self) -> f32 {
+        self.soft_knee
+    }
+
+    /// Returns the color multiplied into the bloom.
+    #[inline]
+    #[must_use]
+    pub fn tint(&self) -> Vec3 {
+        self.prefilter_uniforms.read().tint
+    }
+
+    /// Returns the lens dirt intensity.
+    #[inline]
+    #[must_use]
+    pub fn dirt_intensity(&self) -> f32 {
+        self.composite_uniforms.read().dirt_intensity
+    }
+
     /// Returns the maximum number of mip levels.
     #[inline]
     #[must_use]
@@ -191,6 +279,51 @@ impl BloomSettings {
         self.upsample_uniforms.write().filter_radius = radius.max(0.0);
     }
 
+    /// Sets the luminance threshold above which pixels bloom.
+    ///
+    /// HDR radiance is linear, so an emissive surface blooms once
+    /// `emissive * emissive_intensity` exceeds this value (before exposure).
+    /// `0.0` (default) lets every pixel contribute.
+    pub fn set_threshold(&mut self, threshold: f32) {
+        let threshold = threshold.max(0.0);
+        let mut u = self.prefilter_uniforms.write();
+        u.threshold = threshold;
+        u.knee = threshold * self.soft_knee;
+    }
+
+    /// Sets the soft knee as a fraction of the threshold (`0.0`–`1.0`).
+    ///
+    /// `0.0` is a hard cut; larger values fade pixels in over a wider
+    /// luminance range below the threshold.
+    pub fn set_soft_knee(&mut self, soft_knee: f32) {
+        self.soft_knee = soft_knee.clamp(0.0, 1.0);
+        let mut u = self.prefilter_uniforms.write();
+        u.knee = u.threshold * self.soft_knee;
+    }
+
+    /// Sets the color multiplied into the bloom (default white).
+    ///
+    /// Only the glow is tinted; the scene itself keeps its colors.
+    /// Negative components are clamped to zero.
+    pub fn set_tint(&mut self, tint: Vec3) {
+        self.prefilter_uniforms.write().tint = tint.max(Vec3::ZERO);
+    }
+
+    /// Sets the lens dirt mask.
+    ///
+    /// The texture's RGB is stretched over the screen and multiplies the
+    /// bloom, so dirt shows only where the image glows.
+    pub fn set_dirt_texture(&mut self, texture: Option<TextureHandle>) {
+        self.dirt_texture = texture;
+    }
+
+    /// Sets how strongly the dirt mask adds to the bloom (default `0.0`).
+    ///
+    /// Has no effect without a [`dirt_texture`](Self::dirt_texture).
+    pub fn set_dirt_intensity(&mut self, intensity: f32) {
+        self.composite_uniforms.write().dirt_intensity = intensity.max(0.0);
+    }
+
     /// Sets whether Karis average is used on the first downsample.
     pub fn set_karis_average(&mut self, enabled: bool) {
         self.karis_average = enabled;
@@ -258,9 +258,11 @@ impl BoundingBox {
         }
     }
 
+    /// Returns the eight corner points of the box.
     #[must_use]
-    pub fn transform(&self, matrix: &Affine3A) -> Self {
-        let corners = [
+    #[inline]
+    pub fn corners(&self) -> [Vec3; 8] {
+        [
             Vec3::new(self.min.x, self.min.y, self.min.z),
             Vec3::new(self.min.x, self.min.y, self.max.z),
             Vec3::new(self.min.x, self.max.y, self.min.z),
@@ -269,7 +271,12 @@ impl BoundingBox {
             Vec3::new(self.max.x, self.min.y, self.max.z),
             Vec3::new(self.max.x, self.max.y, self.min.z),
             Vec3::new(self.max.x, self.max.y, self.max.z),
-        ];
+        ]
+    }
+
+    #[must_use]
+    pub fn transform(&self, matrix: &Affine3A) -> Self {
+        let corners = self.corners();
 
         let mut new_min = Vec3::splat(f32::INFINITY);
         let mut new_max = Vec3::splat(f32::NEG_INFINITY);
@@ -443,6 +450,23 @@ impl Geometry {
         self.data_version
     }
 
+    /// Advances all version counters past those of `previous`.
+    ///
+    /// Call this when a freshly built geometry replaces `previous` under the
+    /// same handle, so the renderer sees a newer structure and rebuilds the
+    /// GPU buffers and vertex layout instead of reusing the old ones.
+    pub fn supersede(&mut self, previous: &Geometry) {
+        self.layout_version = self
+            .layout_version
+            .max(previous.layout_version)
+            .wrapping_add(1);
+        self.structure_version = self
+            .structure_version
+            .max(previous.structure_version)
+            .wrapping_add(1);
+        self.data_version = self.data_version.max(previous.data_version).wrapping_add(1);
+    }
+
     // Attributes accessors
     #[must_use]
     pub fn attributes(&self) -> &FxHashMap<String, Attribute> {
@@ -813,6 +837,291 @@ impl Geometry {
         self.set_attribute("normal", normal_attr);
     }
 
+    /// Generates a `tangent` attribute (`Float32x4`) from positions, normals
+    /// and the `uv` channel. See [`compute_tangents_for`](Self::compute_tangents_for).
+    pub fn compute_tangents(&mut self) -> bool {
+        self.compute_tangents_for("uv")
+    }
+
+    /// Generates a `tangent` attribute (`Float32x4`) from positions, normals
+    /// and the given UV channel, for normal maps on meshes shipped without
+    /// tangents.
+    ///
+    /// Per-triangle UV gradients are accumulated (area weighted) onto each
+    /// vertex, and vertices sharing the same position, normal and UV are
+    /// averaged together so split seams stay smooth. The result is
+    /// Gram-Schmidt orthonormalized against the normal; `w` is the
+    /// handedness in glTF convention (`cross(normal, tangent) * w` points
+    /// along -V), matching the derivative-based tangent frame used when no
+    /// tangents exist.
+    ///
+    /// Returns `false` (leaving the geometry unchanged) unless the geometry
+    /// is a triangle list with `Float32x3` positions and normals and a
+    /// `Float32x2` UV channel available on the CPU.
+    pub fn compute_tangents_for(&mut self, uv_name: &str) -> bool {
+        if self.topology != PrimitiveTopology::TriangleList {
+            return false;
+        }
+        let (Some(pos_attr), Some(normal_attr), Some(uv_attr)) = (
+            self.attributes.get("position"),
+            self.attributes.get("normal"),
+            self.attributes.get(uv_name),
+        ) else {
+            return false;
+        };
+        if pos_attr.format != VertexFormat::Float32x3
+            || normal_attr.format != VertexFormat::Float32x3
+            || uv_attr.format != VertexFormat::Float32x2
+        {
+            return false;
+        }
+
+        let count = pos_attr.count.min(normal_attr.count).min(uv_attr.count);
+        let mut positions = Vec::with_capacity(count as usize);
+        let mut normals = Vec::with_capacity(count as usize);
+        let mut uvs = Vec::with_capacity(count as usize);
+        for i in 0..count {
+            let (Some(p), Some(n), Some(uv)) = (
+                pos_attr.read_vec3(i),
+                normal_attr.read_vec3(i),
+                uv_attr.read::<[f32; 2]>(i),
+            ) else {
+                return false;
+            };
+            positions.push(p);
+            normals.push(n);
+            uvs.push(glam::Vec2::from_array(uv));
+        }
+
+        let Some(indices) = self.read_indices() else {
+            return false;
+        };
+
+        // Vertices with identical attributes share one accumulator.
+        let mut groups: FxHashMap<[u32; 8], usize> = FxHashMap::default();
+        let group_of: Vec<usize> = (0..count as usize)
+            .map(|i| {
+                let (p, n, uv) = (positions[i], normals[i], uvs[i]);
+                let key = [p.x, p.y, p.z, n.x, n.y, n.z, uv.x, uv.y].map(f32::to_bits);
+                let next = groups.len();
+                *groups.entry(key).or_insert(next)
+            })
+            .collect();
+
+        let mut tan_u = vec![Vec3::ZERO; groups.len()];
+        let mut tan_v = vec![Vec3::ZERO; groups.len()];
+        for tri in indices.chunks_exact(3) {
+            let [i0, i1, i2] = [tri[0] as usize, tri[1] as usize, tri[2] as usize];
+            if i0 >= positions.len() || i1 >= positions.len() || i2 >= positions.len() {
+                continue;
+            }
+
+            let e1 = positions[i1] - positions[i0];
+            let e2 = positions[i2] - positions[i0];
+            let d1 = uvs[i1] - uvs[i0];
+            let d2 = uvs[i2] - uvs[i0];
+            let det = d1.x * d2.y - d2.x * d1.y;
+            if det.abs() <= f32::EPSILON {
+                continue;
+            }
+
+            // dP/du and dP/dv, weighted by the triangle's area.
+            let scale = e1.cross(e2).length() / det;
+            let sdir = (e1 * d2.y - e2 * d1.y) * scale;
+            let tdir = (e2 * d1.x - e1 * d2.x) * scale;
+            for i in [i0, i1, i2] {
+                tan_u[group_of[i]] += sdir;
+                tan_v[group_of[i]] += tdir;
+            }
+        }
+
+        let tangents: Vec<[f32; 4]> = (0..count as usize)
+            .map(|i| {
+                let n = normals[i].normalize_or_zero();
+                let g = group_of[i];
+                let mut t = (tan_u[g] - n * n.dot(tan_u[g])).normalize_or_zero();
+                if t == Vec3::ZERO && n != Vec3::ZERO {
+                    // No usable UV gradient: any direction perpendicular to n.
+                    t = n.any_orthonormal_vector();
+                }
+                let w = if n.cross(t).dot(tan_v[g]) < 0.0 {
+                    1.0
+                } else {
+                    -1.0
+                };
+                [t.x, t.y, t.z, w]
+            })
+            .collect();
+
+        self.set_attribute(
+            "tangent",
+            Attribute::new_planar(&tangents, VertexFormat::Float32x4),
+        );
+        true
+    }
+
+    /// Decodes the index buffer, or `0..vertex_count` for non-indexed
+    /// geometry. Returns `None` if the data isn't available on the CPU.
+    fn read_indices(&self) -> Option<Vec<u32>> {
+        match &self.index_attribute {
+            Some(index_attr) => {
+                let bytes = index_attr.data.as_ref()?;
+                Some(match index_attr.format {
+                    IndexFormat::Uint16 => bytes
+                        .chunks_exact(2)
+                        .map(|b| u32::from(u16::from_le_bytes([b[0], b[1]])))
+                        .collect(),
+                    IndexFormat::Uint32 => bytes
+                        .chunks_exact(4)
+                        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                        .collect(),
+                })
+            }
+            None => Some((0..self.attributes.get("position")?.count).collect()),
+        }
+    }
+
+    /// Concatenates geometries into one indexed geometry, returning it with
+    /// the index range each part occupies (for [`Submesh`](crate::mesh::Submesh)es).
+    ///
+    /// Returns `None` unless every part has the same list topology, the same
+    /// per-vertex attributes (names and formats) with data on the CPU, the
+    /// default draw range, and no morph targets.
+    #[must_use]
+    pub fn merge(parts: &[Geometry]) -> Option<(Geometry, Vec<Range<u32>>)> {
+        let first = parts.first()?;
+        let list_topology = matches!(
+            first.topology,
+            PrimitiveTopology::TriangleList
+                | PrimitiveTopology::LineList
+                | PrimitiveTopology::PointList
+        );
+        let layout = |g: &Geometry| {
+            let mut names: Vec<_> = g
+                .attributes
+                .iter()
+                .map(|(name, attr)| (name.clone(), attr.format, attr.step_mode))
+                .collect();
+            names.sort_unstable_by(|a, b| a.0.cmp(&b.0));
+            names
+        };
+        let first_layout = layout(first);
+        let compatible = parts.iter().all(|g| {
+            g.topology == first.topology
+                && g.draw_range == (0..u32::MAX)
+                && !g.has_morph_targets()
+                && layout(g) == first_layout
+        });
+        if !list_topology
+            || !compatible
+            || first_layout
+                .iter()
+                .any(|(_, _, step)| *step != VertexStepMode::Vertex)
+        {
+            return None;
+        }
+
+        let mut indices = Vec::new();
+        let mut ranges = Vec::with_capacity(parts.len());
+        let mut vertex_base = 0u32;
+        for part in parts {
+            let start = indices.len() as u32;
+            indices.extend(part.read_indices()?.into_iter().map(|i| i + vertex_base));
+            ranges.push(start..indices.len() as u32);
+            vertex_base += part.attributes.get("position")?.count;
+        }
+
+        let mut merged = Geometry::new();
+        merged.topology = first.topology;
+        for (name, format, _) in &first_layout {
+            let size = format.size() as usize;
+            // Vertex buffer strides must be 4-byte aligned.
+            let stride = size.next_multiple_of(4);
+            let mut bytes = Vec::with_capacity(vertex_base as usize * stride);
+            for part in parts {
+                let attr = part.attributes.get(name)?;
+                let data = attr.data.as_ref()?;
+                let count = part.attributes.get("position")?.count;
+                for i in 0..count as usize {
+                    let offset = attr.offset as usize + i * attr.stride as usize;
+                    bytes.extend_from_slice(data.get(offset..offset + size)?);
+                    bytes.resize(bytes.len() + stride - size, 0);
+                }
+            }
+            merged.set_attribute(
+                name,
+                Attribute::new_from_owned_bytes(bytes, *format, stride, vertex_base),
+            );
+        }
+        merged.set_indices_u32(&indices);
+
+        // Quantized positions can't be re-measured on the CPU.
+        if first.attributes.get("position")?.format == VertexFormat::Float32x3 {
+            merged.compute_bounding_volume();
+        } else {
+            let bbox = parts[1..]
+                .iter()
+                .fold(first.bounding_box, |acc, g| acc.union(&g.bounding_box));
+            merged.set_bounding_volume(bbox);
+        }
+
+        Some((merged, ranges))
+    }
+
+    /// Builds a non-indexed copy of a triangle-list geometry with an extra
+    /// `barycentric` attribute (`Float32x3`): `(1,0,0)`, `(0,1,0)` and
+    /// `(0,0,1)` at the three corners of every triangle.
+    ///
+    /// Used to draw wireframes in the fragment shader on devices that cannot
+    /// rasterize polygons as lines. Returns `None` for other topologies or
+    /// when vertex data is not available on the CPU. Morph targets are not
+    /// carried over.
+    #[must_use]
+    pub fn to_barycentric_wireframe(&self) -> Option<Geometry> {
+        if self.topology != PrimitiveTopology::TriangleList {
+            return None;
+        }
+
+        let mut indices = self.read_indices()?;
+
+        let end = (self.draw_range.end as usize).min(indices.len());
+        let start = (self.draw_range.start as usize).min(end);
+        indices.truncate(end);
+        indices.drain(..start);
+        indices.truncate(indices.len() - indices.len() % 3);
+
+        let mut wireframe = Geometry::new();
+        for (name, attr) in &self.attributes {
+            if attr.step_mode == VertexStepMode::Instance {
+                wireframe.set_attribute(name, attr.clone());
+                continue;
+            }
+
+            let data = attr.data.as_ref()?;
+            let size = attr.format.size() as usize;
+            let mut bytes = Vec::with_capacity(indices.len() * size);
+            for &i in &indices {
+                let offset = attr.offset as usize + i as usize * attr.stride as usize;
+                bytes.extend_from_slice(data.get(offset..offset + size)?);
+            }
+            wireframe.set_attribute(
+                name,
+                Attribute::new_from_owned_bytes(bytes, attr.format, size, indices.len() as u32),
+            );
+        }
+
+        let corners = [[1.0f32, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+        let barycentric: Vec<[f32; 3]> = (0..indices.len()).map(|i| corners[i % 3]).collect();
+        wireframe.set_attribute(
+            "barycentric",
+            Attribute::new_planar(&barycentric, VertexFormat::Float32x3),
+        );
+
+        wireframe.bounding_box = self.bounding_box;
+        wireframe.bounding_sphere = self.bounding_sphere;
+        Some(wireframe)
+    }
+
     pub fn compute_bounding_volume(&mut self) {
         let Some(pos_attr) = self.attributes.get("position") else {
             return;
@@ -1023,4 +1332,37 @@ impl Geometry {
             ..Default::default()
         })
     }
+
+    /// Creates a unit quad on the XY plane facing +Z, centered on the origin,
+    /// e.g. for a [`SpriteMaterial`](crate::SpriteMaterial) sprite.
+    #[must_use]
+    pub fn new_quad() -> Self {
+        Self::new_plane(1.0, 1.0)
+    }
+
+    /// Creates a line-list grid on the XZ plane, see [`primitives::create_grid`].
+    #[must_use]
+    pub fn new_grid(size: f32, divisions: u32) -> Self {
+        primitives::create_grid(size, divisions)
+    }
+
+    /// Creates colored X/Y/Z axis lines, see [`primitives::create_axes`].
+    #[must_use]
+    pub fn new_axes(size: f32) -> Self {
+        primitives::create_axes(size)
+    }
+
+    /// Creates a point-list geometry from raw positions, e.g. for a
+    /// [`PointsMaterial`](crate::PointsMaterial) point cloud.
+    #[must_use]
+    pub fn new_points(positions: &[Vec3]) -> Self {
+        let mut geo = Self::new();
+        geo.topology = PrimitiveTopology::PointList;
+        geo.set_attribute(
+            "position",
+            Attribute::new_planar(positions, VertexFormat::Float32x3),
+        );
+        geo.compute_bounding_volume();
+        geo
+    }
 }
@@ -14,6 +14,10 @@
 //!   with `PixelFormat` at GPU upload time to produce the final
 //!   `wgpu::TextureFormat`.
 
+use std::f32::consts::{PI, TAU};
+
+use glam::{Vec3, Vec4};
+use half::f16;
 use uuid::Uuid;
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -62,6 +66,38 @@ impl PixelFormat {
             Self::R8Unorm => 1,
         }
     }
+
+    /// Reads one texel as RGBA; missing channels read as `0` (alpha `1`).
+    fn decode_texel(self, bytes: &[u8]) -> Vec4 {
+        match self {
+            Self::Rgba8Unorm => {
+                Vec4::new(
+                    f32::from(bytes[0]),
+                    f32::from(bytes[1]),
+                    f32::from(bytes[2]),
+                    f32::from(bytes[3]),
+                ) / 255.0
+            }
+            Self::Rgba16Float => Vec4::from_array(std::array::from_fn(|i| {
+                f16::from_le_bytes([bytes[i * 2], bytes[i * 2 + 1]]).to_f32()
+            })),
+            Self::R8Unorm => Vec4::new(f32::from(bytes[0]) / 255.0, 0.0, 0.0, 1.0),
+        }
+    }
+
+    /// Appends one RGBA texel in this format.
+    fn encode_texel(self, color: Vec4, out: &mut Vec<u8>) {
+        let unorm8 = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        match self {
+            Self::Rgba8Unorm => out.extend(color.to_array().map(unorm8)),
+            Self::Rgba16Float => {
+                for channel in color.to_array() {
+                    out.extend_from_slice(&f16::from_f32(channel).to_le_bytes());
+                }
+            }
+            Self::R8Unorm => out.push(unorm8(color.x)),
+        }
+    }
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -210,4 +246,81 @@ impl Image {
             Some(data),
         )
     }
+
+    /// Resamples an equirectangular (latitude-longitude) panorama into a
+    /// six-layer cube image with `face_size`×`face_size` faces.
+    ///
+    /// Faces are ordered +X, −X, +Y, −Y, +Z, −Z and use the same orientation
+    /// and lat-long mapping as the renderer's environment conversion, so the
+    /// result can be bound with
+    /// [`Texture::new_cube`](crate::texture::Texture::new_cube). Texels are
+    /// sampled bilinearly, wrapping horizontally; the pixel format is kept.
+    ///
+    /// Returns `None` if this is not a 2D image with pixel data.
+    #[must_use]
+    pub fn equirect_to_cube(&self, face_size: u32) -> Option<Self> {
+        let data = self.data.as_deref()?;
+        let (width, height) = (self.width as usize, self.height as usize);
+        let texel_size = self.format.block_copy_size() as usize;
+        if self.dimension != ImageDimension::D2
+            || width == 0
+            || height == 0
+            || data.len() < width * height * texel_size
+        {
+            return None;
+        }
+
+        let fetch = |col: usize, row: usize| {
+            let offset = (row * width + col) * texel_size;
+            self.format.decode_texel(&data[offset..offset + texel_size])
+        };
+
+        let face_size = face_size.max(1);
+        let mut out = Vec::with_capacity((face_size * face_size) as usize * 6 * texel_size);
+        for face in 0..6 {
+            for y in 0..face_size {
+                for x in 0..face_size {
+                    let face_u = (x as f32 + 0.5) / face_size as f32 * 2.0 - 1.0;
+                    let face_v = (y as f32 + 0.5) / face_size as f32 * 2.0 - 1.0;
+                    let dir = cube_face_direction(face, face_u, face_v).normalize();
+
+                    // Texel-space coordinates in the panorama.
+                    let su = (dir.z.atan2(dir.x) / TAU + 0.5) * width as f32 - 0.5;
+                    let sv = (dir.y.clamp(-1.0, 1.0).acos() / PI) * height as f32 - 0.5;
+
+                    let fx = su - su.floor();
+                    let x0 = (su.floor().rem_euclid(width as f32) as usize) % width;
+                    let x1 = (x0 + 1) % width;
+                    let sv = sv.clamp(0.0, (height - 1) as f32);
+                    let (y0, fy) = (sv.floor() as usize, sv.fract());
+                    let y1 = (y0 + 1).min(height - 1);
+
+                    let top = fetch(x0, y0).lerp(fetch(x1, y0), fx);
+                    let bottom = fetch(x0, y1).lerp(fetch(x1, y1), fx);
+                    self.format.encode_texel(top.lerp(bottom, fy), &mut out);
+                }
+            }
+        }
+
+        Some(Self::new(
+            face_size,
+            face_size,
+            6,
+            ImageDimension::D2,
+            self.format,
+            Some(out),
+        ))
+    }
+}
+
+/// Direction through the texel at `(u, v)` ∈ [-1, 1]² of a cube face.
+fn cube_face_direction(face: u32, u: f32, v: f32) -> Vec3 {
+    match face {
+        0 => Vec3::new(1.0, -v, -u),
+        1 => Vec3::new(-1.0, -v, u),
+        2 => Vec3::new(u, 1.0, v),
+        3 => Vec3::new(u, -1.0, -v),
+        4 => Vec3::new(u, -v, 1.0),
+        _ => Vec3::new(-u, -v, -1.0),
+    }
 }
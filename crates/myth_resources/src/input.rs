@@ -143,6 +143,140 @@ pub enum ButtonState {
     Released,
 }
 
+/// Identifies a connected gamepad.
+///
+/// Ids are assigned by the platform adapter and stay stable while the pad
+/// is connected; lower ids were connected earlier.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct GamepadId(pub usize);
+
+/// Gamepad button enumeration, using the standard (Xbox-style) layout.
+///
+/// Face buttons are named by position, so `South` is A on Xbox and Cross on
+/// PlayStation pads.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GamepadButton {
+    South,
+    East,
+    North,
+    West,
+    LeftBumper,
+    RightBumper,
+    /// Digital state of the left trigger; see [`GamepadAxis::LeftTrigger`]
+    /// for the analog value.
+    LeftTrigger,
+    /// Digital state of the right trigger; see [`GamepadAxis::RightTrigger`]
+    /// for the analog value.
+    RightTrigger,
+    Select,
+    Start,
+    Mode,
+    LeftStick,
+    RightStick,
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    Other(u16),
+}
+
+/// Gamepad analog axis enumeration.
+///
+/// Stick axes range over `-1.0..=1.0` with +Y pointing up; triggers range
+/// over `0.0..=1.0`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GamepadAxis {
+    LeftStickX,
+    LeftStickY,
+    RightStickX,
+    RightStickY,
+    LeftTrigger,
+    RightTrigger,
+}
+
+/// State of a single connected gamepad.
+///
+/// Buttons follow the same held / just-pressed / just-released semantics
+/// as keyboard keys on [`Input`].
+#[derive(Debug, Clone, Default)]
+pub struct Gamepad {
+    name: String,
+    pressed: FxHashSet<GamepadButton>,
+    just_pressed: FxHashSet<GamepadButton>,
+    just_released: FxHashSet<GamepadButton>,
+    axes: FxHashMap<GamepadAxis, f32>,
+}
+
+impl Gamepad {
+    /// Returns the device name reported by the platform.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Checks whether a button is currently held down
+    #[must_use]
+    pub fn get_button(&self, button: GamepadButton) -> bool {
+        self.pressed.contains(&button)
+    }
+
+    /// Checks whether a button was just pressed this frame
+    #[must_use]
+    pub fn get_button_down(&self, button: GamepadButton) -> bool {
+        self.just_pressed.contains(&button)
+    }
+
+    /// Checks whether a button was just released this frame
+    #[must_use]
+    pub fn get_button_up(&self, button: GamepadButton) -> bool {
+        self.just_released.contains(&button)
+    }
+
+    /// Returns the current value of an axis (`0.0` if never reported)
+    #[must_use]
+    pub fn axis(&self, axis: GamepadAxis) -> f32 {
+        self.axes.get(&axis).copied().unwrap_or(0.0)
+    }
+
+    /// Returns the left stick position (+Y up)
+    #[must_use]
+    pub fn left_stick(&self) -> Vec2 {
+        Vec2::new(
+            self.axis(GamepadAxis::LeftStickX),
+            self.axis(GamepadAxis::LeftStickY),
+        )
+    }
+
+    /// Returns the right stick position (+Y up)
+    #[must_use]
+    pub fn right_stick(&self) -> Vec2 {
+        Vec2::new(
+            self.axis(GamepadAxis::RightStickX),
+            self.axis(GamepadAxis::RightStickY),
+        )
+    }
+
+    fn set_button(&mut self, button: GamepadButton, state: ButtonState) {
+        match state {
+            ButtonState::Pressed => {
+                if self.pressed.insert(button) {
+                    self.just_pressed.insert(button);
+                }
+            }
+            ButtonState::Released => {
+                if self.pressed.remove(&button) {
+                    self.just_released.insert(button);
+                }
+            }
+        }
+    }
+
+    fn start_frame(&mut self) {
+        self.just_pressed.clear();
+        self.just_released.clear();
+    }
+}
+
 /// Platform-agnostic touch phase
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum TouchPhase {
@@ -175,6 +309,10 @@ pub struct Input {
 
     // Touch state
     touches: FxHashMap<u64, Vec2>,
+    pinch_scale: f32,
+
+    // Gamepad state
+    gamepads: FxHashMap<GamepadId, Gamepad>,
 }
 
 impl Input {
@@ -192,6 +330,8 @@ impl Input {
             scroll_delta: Vec2::ZERO,
             screen_size: Vec2::ZERO,
             touches: FxHashMap::default(),
+            pinch_scale: 1.0,
+            gamepads: FxHashMap::default(),
         }
     }
 
@@ -205,6 +345,10 @@ impl Input {
         self.just_released_mouse.clear();
         self.mouse_delta = Vec2::ZERO;
         self.scroll_delta = Vec2::ZERO;
+        self.pinch_scale = 1.0;
+        for pad in self.gamepads.values_mut() {
+            pad.start_frame();
+        }
     }
 
     /// Injects a keyboard event
@@ -296,6 +440,10 @@ impl Input {
                     // multiply by 0.1 as a moderate zoom damping factor
                     let zoom_delta = (new_dist - old_dist) * 0.1;
                     self.scroll_delta.y += zoom_delta;
+
+                    if old_dist > 0.0 && new_dist > 0.0 {
+                        self.pinch_scale *= new_dist / old_dist;
+                    }
                 } else if self.touches.len() == 1 {
                     // single touch move: rotate
                     self.touches.insert(id, current_pos);
@@ -323,6 +471,38 @@ impl Input {
         }
     }
 
+    /// Injects a gamepad connection
+    pub fn inject_gamepad_connected(&mut self, id: GamepadId, name: impl Into<String>) {
+        self.gamepads.entry(id).or_default().name = name.into();
+    }
+
+    /// Injects a gamepad disconnection, dropping its state
+    pub fn inject_gamepad_disconnected(&mut self, id: GamepadId) {
+        self.gamepads.remove(&id);
+    }
+
+    /// Injects a gamepad button event
+    pub fn inject_gamepad_button(
+        &mut self,
+        id: GamepadId,
+        button: GamepadButton,
+        state: ButtonState,
+    ) {
+        self.gamepads
+            .entry(id)
+            .or_default()
+            .set_button(button, state);
+    }
+
+    /// Injects a gamepad axis value
+    pub fn inject_gamepad_axis(&mut self, id: GamepadId, axis: GamepadAxis, value: f32) {
+        self.gamepads
+            .entry(id)
+            .or_default()
+            .axes
+            .insert(axis, value);
+    }
+
     fn calculate_touch_center(&self) -> Vec2 {
         if self.touches.is_empty() {
             return Vec2::ZERO;
@@ -404,6 +584,60 @@ impl Input {
     pub fn screen_size(&self) -> Vec2 {
         self.screen_size
     }
+
+    /// Returns the number of fingers currently touching the screen
+    #[must_use]
+    pub fn touch_count(&self) -> usize {
+        self.touches.len()
+    }
+
+    /// Returns the current position of an active touch
+    #[must_use]
+    pub fn touch_position(&self, id: u64) -> Option<Vec2> {
+        self.touches.get(&id).copied()
+    }
+
+    /// Iterates over active touches as `(id, position)` (in no particular order)
+    pub fn touches(&self) -> impl Iterator<Item = (u64, Vec2)> {
+        self.touches.iter().map(|(&id, &pos)| (id, pos))
+    }
+
+    /// Returns the two-finger pinch scale for this frame.
+    ///
+    /// This is the ratio of the current finger distance to the distance at
+    /// the start of the frame: `> 1.0` when the fingers spread apart,
+    /// `< 1.0` when they pinch together, and `1.0` when no pinch happened.
+    #[must_use]
+    pub fn pinch_scale(&self) -> f32 {
+        self.pinch_scale
+    }
+
+    /// Returns the state of a connected gamepad
+    #[must_use]
+    pub fn gamepad(&self, id: GamepadId) -> Option<&Gamepad> {
+        self.gamepads.get(&id)
+    }
+
+    /// Iterates over connected gamepads (in no particular order)
+    pub fn gamepads(&self) -> impl Iterator<Item = (GamepadId, &Gamepad)> {
+        self.gamepads.iter().map(|(&id, pad)| (id, pad))
+    }
+
+    /// Returns the earliest-connected gamepad, if any
+    #[must_use]
+    pub fn first_gamepad(&self) -> Option<(GamepadId, &Gamepad)> {
+        self.gamepads
+            .iter()
+            .min_by_key(|&(&id, _)| id)
+            .map(|(&id, pad)| (id, pad))
+    }
+
+    /// Returns the first gamepad's left stick, or zero if none is connected
+    #[must_use]
+    pub fn gamepad_left_stick(&self) -> Vec2 {
+        self.first_gamepad()
+            .map_or(Vec2::ZERO, |(_, pad)| pad.left_stick())
+    }
 }
 
 impl Default for Input {
@@ -14,6 +14,8 @@
 //! - [`uniforms`] - Shader uniform data structures
 //! - [`shader_defines`] - Dynamic shader macro system
 //! - [`primitives`] - Built-in geometry primitives
+//! - [`procedural`] - Procedural texture generation (noise)
+//! - [`text`] - SDF font atlases and text layout
 //! - [`handles`] - Strongly-typed resource handles
 //! - [`binding`] - GPU binding resource descriptions
 
@@ -32,10 +34,12 @@ pub mod input;
 pub mod material;
 pub mod mesh;
 pub mod primitives;
+pub mod procedural;
 pub mod screen_space;
 pub mod shader_defines;
 pub mod ssao;
 pub mod taa;
+pub mod text;
 pub mod texture;
 pub mod tone_mapping;
 pub mod uniforms;
@@ -49,10 +53,11 @@ pub use handles::{
 
 // Re-export common resource types
 pub use material::{
-    AlphaMode, Material, MaterialTrait, MaterialType, PhongMaterial, PhysicalFeatures,
-    PhysicalMaterial, RenderableMaterialTrait, Side, TextureSlot, TextureTransform, UnlitMaterial,
+    AlphaMode, LineMaterial, Material, MaterialTrait, MaterialType, PhongMaterial,
+    PhysicalFeatures, PhysicalMaterial, PointsMaterial, RenderableMaterialTrait, Side,
+    SpriteMaterial, StencilSettings, TextMaterial, TextureSlot, TextureTransform, UnlitMaterial,
 };
-pub use mesh::Mesh;
+pub use mesh::{Mesh, Submesh};
 
 // Re-export the material definition macro
 pub use myth_macros::myth_material;
@@ -71,12 +76,16 @@ pub use geometry::{
 };
 pub use image::Image;
 pub use image::{ColorSpace, ImageDimension, PixelFormat};
-pub use input::{ButtonState, Input, Key, MouseButton};
+pub use input::{
+    ButtonState, Gamepad, GamepadAxis, GamepadButton, GamepadId, Input, Key, MouseButton,
+    TouchPhase,
+};
 pub use shader_defines::ShaderDefines;
 pub use ssao::SsaoSettings;
 pub use taa::TaaSettings;
+pub use text::{Font, FontAtlas, FontAtlasSettings, Glyph, TextAlign, TextMesh};
 pub use texture::{Texture, TextureSampler};
-pub use tone_mapping::{AgxLook, ToneMappingMode, ToneMappingSettings};
+pub use tone_mapping::{AgxLook, AutoExposure, ToneMappingMode, ToneMappingSettings};
 pub use uniforms::{Mat3Uniform, WgslType};
 
 // Re-export binding/builder types for myth_render
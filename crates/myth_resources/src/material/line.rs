@@ -0,0 +1,72 @@
+use glam::Vec4;
+use myth_macros::myth_material;
+
+use crate::material::AlphaMode;
+
+/// Unlit material for line-list / line-strip geometry.
+///
+/// Use with a [`Geometry`](crate::Geometry) whose `topology` is
+/// [`PrimitiveTopology::LineList`](wgpu::PrimitiveTopology::LineList) or
+/// [`LineStrip`](wgpu::PrimitiveTopology::LineStrip), e.g.
+/// [`Geometry::new_grid`](crate::Geometry::new_grid). When the geometry
+/// carries a `color` attribute, per-vertex colors are multiplied with
+/// [`color`](LineUniforms::color).
+///
+/// Lines are always rasterized one pixel wide (WebGPU has no line width).
+#[myth_material(shader = "entry/main/line", crate_path = "crate")]
+pub struct LineMaterial {
+    /// Line color.
+    #[uniform(default = "Vec4::ONE")]
+    pub color: Vec4,
+
+    /// Opacity value.
+    #[uniform(default = "1.0")]
+    pub opacity: f32,
+}
+
+impl LineMaterial {
+    /// Creates a new line material with the given color.
+    #[must_use]
+    pub fn new(color: Vec4) -> Self {
+        Self::from_uniforms(LineUniforms {
+            color,
+            ..Default::default()
+        })
+    }
+
+    /// Sets the line color (builder).
+    #[must_use]
+    pub fn with_color(self, color: Vec4) -> Self {
+        self.uniforms.write().color = color;
+        self
+    }
+
+    /// Sets the opacity (builder).
+    ///
+    /// Values below `1.0` only take effect with [`AlphaMode::Blend`].
+    #[must_use]
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        self.uniforms.write().opacity = opacity;
+        self
+    }
+
+    /// Sets the alpha mode (builder).
+    #[must_use]
+    pub fn with_alpha_mode(self, mode: AlphaMode) -> Self {
+        self.set_alpha_mode(mode);
+        self
+    }
+
+    /// Sets depth write (builder).
+    #[must_use]
+    pub fn with_depth_write(self, enabled: bool) -> Self {
+        self.set_depth_write(enabled);
+        self
+    }
+}
+
+impl Default for LineMaterial {
+    fn default() -> Self {
+        Self::new(Vec4::ONE)
+    }
+}
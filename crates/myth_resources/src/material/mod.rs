@@ -1,10 +1,18 @@
+mod line;
 mod phong;
 mod physical;
+mod points;
+mod sprite;
+mod text;
 mod unlit;
 use parking_lot::RwLockWriteGuard;
 
+pub use line::{LineMaterial, LineUniforms};
 pub use phong::{PhongMaterial, PhongUniforms};
 pub use physical::{PhysicalFeatures, PhysicalMaterial, PhysicalUniforms};
+pub use points::{PointsMaterial, PointsUniforms};
+pub use sprite::{SpriteMaterial, SpriteUniforms};
+pub use text::{TextMaterial, TextUniforms};
 pub use unlit::{UnlitMaterial, UnlitUniforms};
 
 use std::{
@@ -322,12 +330,92 @@ pub enum AlphaMode {
     Blend,
     /// Blend with alpha cutoff (discard pixels below threshold)
     BlendMask,
+    /// Stochastic alpha test against a per-pixel hash, drawn in the opaque
+    /// pass without sorting; MSAA/TAA resolve the noise
+    Hashed,
+}
+
+/// Stencil test and write state of a material.
+///
+/// The stencil test compares `reference` against the stored value (both
+/// masked with `read_mask`) and then applies one of the operations, writing
+/// only the bits in `write_mask`. Both faces use the same state.
+///
+/// A typical mask/portal setup draws the mask with
+/// [`StencilSettings::write`] and the masked content with
+/// [`StencilSettings::equal`]; `Mesh::render_order` makes the mask draw
+/// first.
+#[derive(PartialEq, Eq, Clone, Debug, Copy)]
+pub struct StencilSettings {
+    /// Reference value used by the compare function and `Replace`
+    pub reference: u32,
+    /// Comparison between the reference and the stored value
+    pub compare: wgpu::CompareFunction,
+    /// Operation when both the stencil and depth tests pass
+    pub pass_op: wgpu::StencilOperation,
+    /// Operation when the stencil test fails
+    pub fail_op: wgpu::StencilOperation,
+    /// Operation when the stencil test passes but the depth test fails
+    pub depth_fail_op: wgpu::StencilOperation,
+    /// Bits read by the compare function
+    pub read_mask: u32,
+    /// Bits written by the operations
+    pub write_mask: u32,
+}
+
+impl Default for StencilSettings {
+    fn default() -> Self {
+        Self {
+            reference: 0,
+            compare: wgpu::CompareFunction::Always,
+            pass_op: wgpu::StencilOperation::Keep,
+            fail_op: wgpu::StencilOperation::Keep,
+            depth_fail_op: wgpu::StencilOperation::Keep,
+            read_mask: 0xFF,
+            write_mask: 0xFF,
+        }
+    }
+}
+
+impl StencilSettings {
+    /// Always passes and writes `reference` wherever the material is drawn.
+    #[must_use]
+    pub fn write(reference: u32) -> Self {
+        Self {
+            reference,
+            pass_op: wgpu::StencilOperation::Replace,
+            ..Default::default()
+        }
+    }
+
+    /// Draws only where the stored value equals `reference`.
+    #[must_use]
+    pub fn equal(reference: u32) -> Self {
+        Self {
+            reference,
+            compare: wgpu::CompareFunction::Equal,
+            write_mask: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Draws only where the stored value differs from `reference`.
+    #[must_use]
+    pub fn not_equal(reference: u32) -> Self {
+        Self {
+            reference,
+            compare: wgpu::CompareFunction::NotEqual,
+            write_mask: 0,
+            ..Default::default()
+        }
+    }
 }
 
 /// Material render state settings.
 ///
 /// These settings affect the GPU pipeline configuration and may
 /// cause pipeline cache misses when changed.
+#[allow(clippy::struct_excessive_bools)]
 #[derive(PartialEq, Clone, Debug, Copy)]
 pub struct MaterialSettings {
     /// Alpha blending mode
@@ -340,6 +428,16 @@ pub struct MaterialSettings {
     pub depth_test: bool,
     /// Face culling mode
     pub side: Side,
+    /// Draw triangle edges only (debug view of the mesh topology).
+    /// Triangle strips stay filled on devices without line polygon mode.
+    pub wireframe: bool,
+    /// Shade with per-face normals derived from screen-space derivatives
+    pub flat_shading: bool,
+    /// Stencil test and write state, `None` leaves the stencil buffer untouched
+    pub stencil: Option<StencilSettings>,
+    /// Add the color (weighted by alpha) to the target instead of blending
+    /// over it, for glows and sparks. Only applies to blended alpha modes
+    pub additive_blending: bool,
 }
 
 impl Default for MaterialSettings {
@@ -350,6 +448,10 @@ impl Default for MaterialSettings {
             depth_write: true,
             depth_test: true,
             side: Side::Front,
+            wireframe: false,
+            flat_shading: false,
+            stencil: None,
+            additive_blending: false,
         }
     }
 }
@@ -374,6 +476,13 @@ impl MaterialSettings {
             AlphaMode::BlendMask => {
                 defines.set("ALPHA_MODE", "BLEND_MASK");
             }
+            AlphaMode::Hashed => {
+                defines.set("ALPHA_MODE", "HASHED");
+            }
+        }
+
+        if self.flat_shading {
+            defines.set("FLAT_SHADING", "1");
         }
     }
 }
@@ -427,7 +536,7 @@ impl Drop for SettingsGuard<'_> {
 /// Material data enum with hybrid dispatch strategy.
 ///
 /// Uses "static dispatch + dynamic escape hatch" approach:
-/// - Built-in materials (Unlit/Phong/Physical) use static dispatch for performance
+/// - Built-in materials (Unlit/Phong/Physical/Line/Points/Sprite/Text) use static dispatch for performance
 /// - Custom variant allows user-defined materials via dynamic dispatch
 ///
 /// # Built-in Materials
@@ -435,6 +544,10 @@ impl Drop for SettingsGuard<'_> {
 /// - [`UnlitMaterial`]: Unlit, flat-shaded material
 /// - [`PhongMaterial`]: Classic Blinn-Phong shading
 /// - [`PhysicalMaterial`]: PBR material with metallic-roughness workflow, clearcoat, transmission, etc.
+/// - [`LineMaterial`]: Unlit material for line-list / line-strip geometry
+/// - [`PointsMaterial`]: Unlit point sprites for point clouds and particles
+/// - [`SpriteMaterial`]: Unlit textured quads for world-space sprites
+/// - [`TextMaterial`]: Unlit SDF glyph quads for text labels
 #[derive(Debug)]
 #[allow(clippy::large_enum_variant)]
 pub enum MaterialType {
@@ -444,6 +557,14 @@ pub enum MaterialType {
     Phong(PhongMaterial),
     /// Advanced PBR material with additional features
     Physical(PhysicalMaterial),
+    /// Unlit line material
+    Line(LineMaterial),
+    /// Unlit point-sprite material
+    Points(PointsMaterial),
+    /// Unlit textured sprite material
+    Sprite(SpriteMaterial),
+    /// Unlit signed-distance-field text material
+    Text(TextMaterial),
     /// User-defined custom material
     Custom(Box<dyn RenderableMaterialTrait>),
 }
@@ -454,6 +575,10 @@ impl MaterialTrait for MaterialType {
             Self::Unlit(m) => m.as_any(),
             Self::Phong(m) => m.as_any(),
             Self::Physical(m) => m.as_any(),
+            Self::Line(m) => m.as_any(),
+            Self::Points(m) => m.as_any(),
+            Self::Sprite(m) => m.as_any(),
+            Self::Text(m) => m.as_any(),
             Self::Custom(m) => m.as_any(),
         }
     }
@@ -463,6 +588,10 @@ impl MaterialTrait for MaterialType {
             Self::Unlit(m) => m.as_any_mut(),
             Self::Phong(m) => m.as_any_mut(),
             Self::Physical(m) => m.as_any_mut(),
+            Self::Line(m) => m.as_any_mut(),
+            Self::Points(m) => m.as_any_mut(),
+            Self::Sprite(m) => m.as_any_mut(),
+            Self::Text(m) => m.as_any_mut(),
             Self::Custom(m) => m.as_any_mut(),
         }
     }
@@ -474,6 +603,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.shader_name(),
             Self::Phong(m) => m.shader_name(),
             Self::Physical(m) => m.shader_name(),
+            Self::Line(m) => m.shader_name(),
+            Self::Points(m) => m.shader_name(),
+            Self::Sprite(m) => m.shader_name(),
+            Self::Text(m) => m.shader_name(),
             Self::Custom(m) => m.shader_name(),
         }
     }
@@ -483,6 +616,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.version(),
             Self::Phong(m) => m.version(),
             Self::Physical(m) => m.version(),
+            Self::Line(m) => m.version(),
+            Self::Points(m) => m.version(),
+            Self::Sprite(m) => m.version(),
+            Self::Text(m) => m.version(),
             Self::Custom(m) => m.version(),
         }
     }
@@ -492,6 +629,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.shader_defines(),
             Self::Phong(m) => m.shader_defines(),
             Self::Physical(m) => m.shader_defines(),
+            Self::Line(m) => m.shader_defines(),
+            Self::Points(m) => m.shader_defines(),
+            Self::Sprite(m) => m.shader_defines(),
+            Self::Text(m) => m.shader_defines(),
             Self::Custom(m) => m.shader_defines(),
         }
     }
@@ -501,6 +642,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.settings(),
             Self::Phong(m) => m.settings(),
             Self::Physical(m) => m.settings(),
+            Self::Line(m) => m.settings(),
+            Self::Points(m) => m.settings(),
+            Self::Sprite(m) => m.settings(),
+            Self::Text(m) => m.settings(),
             Self::Custom(m) => m.settings(),
         }
     }
@@ -510,6 +655,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.visit_textures(visitor),
             Self::Phong(m) => m.visit_textures(visitor),
             Self::Physical(m) => m.visit_textures(visitor),
+            Self::Line(m) => m.visit_textures(visitor),
+            Self::Points(m) => m.visit_textures(visitor),
+            Self::Sprite(m) => m.visit_textures(visitor),
+            Self::Text(m) => m.visit_textures(visitor),
             Self::Custom(m) => m.visit_textures(visitor),
         }
     }
@@ -519,6 +668,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.define_bindings(builder),
             Self::Phong(m) => m.define_bindings(builder),
             Self::Physical(m) => m.define_bindings(builder),
+            Self::Line(m) => m.define_bindings(builder),
+            Self::Points(m) => m.define_bindings(builder),
+            Self::Sprite(m) => m.define_bindings(builder),
+            Self::Text(m) => m.define_bindings(builder),
             Self::Custom(m) => m.define_bindings(builder),
         }
     }
@@ -528,6 +681,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.uniform_buffer(),
             Self::Phong(m) => m.uniform_buffer(),
             Self::Physical(m) => m.uniform_buffer(),
+            Self::Line(m) => m.uniform_buffer(),
+            Self::Points(m) => m.uniform_buffer(),
+            Self::Sprite(m) => m.uniform_buffer(),
+            Self::Text(m) => m.uniform_buffer(),
             Self::Custom(m) => m.uniform_buffer(),
         }
     }
@@ -537,6 +694,10 @@ impl RenderableMaterialTrait for MaterialType {
             Self::Unlit(m) => m.with_uniform_bytes(visitor),
             Self::Phong(m) => m.with_uniform_bytes(visitor),
             Self::Physical(m) => m.with_uniform_bytes(visitor),
+            Self::Line(m) => m.with_uniform_bytes(visitor),
+            Self::Points(m) => m.with_uniform_bytes(visitor),
+            Self::Sprite(m) => m.with_uniform_bytes(visitor),
+            Self::Text(m) => m.with_uniform_bytes(visitor),
             Self::Custom(m) => m.with_uniform_bytes(visitor),
         }
     }
@@ -587,6 +748,49 @@ impl Material {
         }
     }
 
+    /// Returns an independent copy of this material with a new uuid.
+    ///
+    /// Returns `None` for [`MaterialType::Custom`], which cannot be cloned
+    /// through the trait object.
+    #[must_use]
+    pub fn duplicate(&self) -> Option<Self> {
+        let data = match &self.data {
+            MaterialType::Unlit(m) => MaterialType::Unlit(m.clone()),
+            MaterialType::Phong(m) => MaterialType::Phong(m.clone()),
+            MaterialType::Physical(m) => MaterialType::Physical(m.clone()),
+            MaterialType::Line(m) => MaterialType::Line(m.clone()),
+            MaterialType::Points(m) => MaterialType::Points(m.clone()),
+            MaterialType::Sprite(m) => MaterialType::Sprite(m.clone()),
+            MaterialType::Text(m) => MaterialType::Text(m.clone()),
+            MaterialType::Custom(_) => return None,
+        };
+        Some(Self {
+            name: self.name.clone(),
+            ..Self::new(data)
+        })
+    }
+
+    /// Bumps this material's version past `previous`, so it can replace
+    /// `previous` under the same handle without reusing pipelines cached
+    /// for the old data.
+    ///
+    /// Used by asset hot reload. Custom materials are left unchanged.
+    pub fn supersede(&self, previous: &Material) {
+        let target = previous.data.version();
+        while self.data.version() <= target {
+            match &self.data {
+                MaterialType::Unlit(m) => m.notify_pipeline_dirty(),
+                MaterialType::Phong(m) => m.notify_pipeline_dirty(),
+                MaterialType::Physical(m) => m.notify_pipeline_dirty(),
+                MaterialType::Line(m) => m.notify_pipeline_dirty(),
+                MaterialType::Points(m) => m.notify_pipeline_dirty(),
+                MaterialType::Sprite(m) => m.notify_pipeline_dirty(),
+                MaterialType::Text(m) => m.notify_pipeline_dirty(),
+                MaterialType::Custom(_) => return,
+            }
+        }
+    }
+
     /// Creates a Material from a custom material
     pub fn new_custom<T: RenderableMaterialTrait + 'static>(custom_material: T) -> Self {
         Self::new(MaterialType::Custom(Box::new(custom_material)))
@@ -608,6 +812,26 @@ impl Material {
         Self::from(PhysicalMaterial::new(color))
     }
 
+    #[must_use]
+    pub fn new_line(color: Vec4) -> Self {
+        Self::from(LineMaterial::new(color))
+    }
+
+    #[must_use]
+    pub fn new_points(color: Vec4) -> Self {
+        Self::from(PointsMaterial::new(color))
+    }
+
+    #[must_use]
+    pub fn new_sprite(map: TextureHandle) -> Self {
+        Self::from(SpriteMaterial::new(map))
+    }
+
+    #[must_use]
+    pub fn new_text(atlas: TextureHandle) -> Self {
+        Self::from(TextMaterial::new(atlas))
+    }
+
     /// Exposes the rendering behavior interface
     #[inline]
     pub fn as_renderable(&self) -> &dyn RenderableMaterialTrait {
@@ -667,6 +891,62 @@ impl Material {
         }
     }
 
+    pub fn as_line(&self) -> Option<&LineMaterial> {
+        match &self.data {
+            MaterialType::Line(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_line_mut(&mut self) -> Option<&mut LineMaterial> {
+        match &mut self.data {
+            MaterialType::Line(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_points(&self) -> Option<&PointsMaterial> {
+        match &self.data {
+            MaterialType::Points(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_points_mut(&mut self) -> Option<&mut PointsMaterial> {
+        match &mut self.data {
+            MaterialType::Points(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_sprite(&self) -> Option<&SpriteMaterial> {
+        match &self.data {
+            MaterialType::Sprite(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_sprite_mut(&mut self) -> Option<&mut SpriteMaterial> {
+        match &mut self.data {
+            MaterialType::Sprite(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&TextMaterial> {
+        match &self.data {
+            MaterialType::Text(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    pub fn as_text_mut(&mut self) -> Option<&mut TextMaterial> {
+        match &mut self.data {
+            MaterialType::Text(m) => Some(m),
+            _ => None,
+        }
+    }
+
     pub fn uniforms(&self) -> &dyn Any {
         self.data.as_any()
     }
@@ -719,6 +999,14 @@ impl Material {
         self.settings().depth_write
     }
 
+    /// Whether the material is blended additively (see
+    /// [`MaterialSettings::additive_blending`]).
+    #[inline]
+    #[must_use]
+    pub fn additive_blending(&self) -> bool {
+        self.is_transparent() && self.settings().additive_blending
+    }
+
     #[inline]
     pub fn depth_test(&self) -> bool {
         self.settings().depth_test
@@ -729,6 +1017,21 @@ impl Material {
         self.settings().side
     }
 
+    #[inline]
+    pub fn wireframe(&self) -> bool {
+        self.settings().wireframe
+    }
+
+    #[inline]
+    pub fn flat_shading(&self) -> bool {
+        self.settings().flat_shading
+    }
+
+    #[inline]
+    pub fn stencil(&self) -> Option<StencilSettings> {
+        self.settings().stencil
+    }
+
     /// Defines GPU resource bindings (delegates to internal data)
     #[inline]
     pub fn define_bindings<'a>(&'a self, builder: &mut ResourceBuilder<'a>) {
@@ -741,6 +1044,10 @@ impl Material {
             MaterialType::Unlit(m) => m.auto_sync_texture_to_uniforms,
             MaterialType::Phong(m) => m.auto_sync_texture_to_uniforms,
             MaterialType::Physical(m) => m.auto_sync_texture_to_uniforms,
+            MaterialType::Line(m) => m.auto_sync_texture_to_uniforms,
+            MaterialType::Points(m) => m.auto_sync_texture_to_uniforms,
+            MaterialType::Sprite(m) => m.auto_sync_texture_to_uniforms,
+            MaterialType::Text(m) => m.auto_sync_texture_to_uniforms,
             MaterialType::Custom(_) => false,
         }
     }
@@ -767,6 +1074,37 @@ impl Material {
             _ => false,
         }
     }
+
+    /// Whether each vertex is drawn as an instanced screen-aligned quad
+    /// instead of using the geometry's own topology.
+    #[inline]
+    #[must_use]
+    pub fn renders_point_sprites(&self) -> bool {
+        matches!(self.data, MaterialType::Points(_))
+    }
+
+    /// Whether the vertex shader places vertices itself (point sprites,
+    /// sprite pivots, pixel-sized sprites and text), so the material is left out of the
+    /// depth prepass and shadow passes that share the generic vertex path.
+    #[inline]
+    #[must_use]
+    pub fn has_custom_vertex_placement(&self) -> bool {
+        matches!(
+            self.data,
+            MaterialType::Points(_) | MaterialType::Sprite(_) | MaterialType::Text(_)
+        )
+    }
+
+    /// Whether the material's shaders can read their object data by
+    /// instance (`GPU_DRIVEN`), as GPU-culled draws do when supported.
+    #[inline]
+    #[must_use]
+    pub fn supports_gpu_driven_draws(&self) -> bool {
+        matches!(
+            self.data,
+            MaterialType::Unlit(_) | MaterialType::Phong(_) | MaterialType::Physical(_)
+        )
+    }
 }
 
 // ============================================================================
@@ -791,6 +1129,30 @@ impl From<PhysicalMaterial> for Material {
     }
 }
 
+impl From<LineMaterial> for Material {
+    fn from(data: LineMaterial) -> Self {
+        Material::new(MaterialType::Line(data))
+    }
+}
+
+impl From<PointsMaterial> for Material {
+    fn from(data: PointsMaterial) -> Self {
+        Material::new(MaterialType::Points(data))
+    }
+}
+
+impl From<SpriteMaterial> for Material {
+    fn from(data: SpriteMaterial) -> Self {
+        Material::new(MaterialType::Sprite(data))
+    }
+}
+
+impl From<TextMaterial> for Material {
+    fn from(data: TextMaterial) -> Self {
+        Material::new(MaterialType::Text(data))
+    }
+}
+
 impl Deref for Material {
     type Target = MaterialType;
 
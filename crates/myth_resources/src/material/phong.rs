@@ -1,8 +1,10 @@
 use glam::{Vec2, Vec3, Vec4};
 use myth_macros::myth_material;
+use parking_lot::RwLock;
 
+use crate::ShaderDefines;
 use crate::TextureHandle;
-use crate::material::{AlphaMode, Side};
+use crate::material::{AlphaMode, Side, StencilSettings};
 use crate::uniforms::Mat3Uniform;
 
 #[myth_material(shader = "entry/main/phong", crate_path = "crate")]
@@ -39,6 +41,11 @@ pub struct PhongMaterial {
     #[uniform]
     pub alpha_test: f32,
 
+    /// Mip level bias added when sampling the material's textures.
+    /// Positive values blur, negative values sharpen (and may alias).
+    #[uniform]
+    pub texture_lod_bias: f32,
+
     /// The color map.
     #[texture]
     pub map: TextureSlot,
@@ -54,6 +61,13 @@ pub struct PhongMaterial {
     /// The emissive map.
     #[texture]
     pub emissive_map: TextureSlot,
+
+    /// Debug override sampling every texture at a fixed mip level.
+    #[internal(
+        default = "parking_lot::RwLock::new(None)",
+        clone_with = "|s: &Self| parking_lot::RwLock::new(*s.forced_mip_level.read())"
+    )]
+    pub(crate) forced_mip_level: RwLock<Option<u32>>,
 }
 
 impl PhongMaterial {
@@ -160,6 +174,61 @@ impl PhongMaterial {
         self.set_depth_write(enabled);
         self
     }
+
+    /// Sets wireframe rendering (builder).
+    #[must_use]
+    pub fn with_wireframe(self, enabled: bool) -> Self {
+        self.set_wireframe(enabled);
+        self
+    }
+
+    /// Sets the stencil test and write state (builder).
+    #[must_use]
+    pub fn with_stencil(self, stencil: StencilSettings) -> Self {
+        self.set_stencil(Some(stencil));
+        self
+    }
+
+    /// Sets flat (per-face) shading (builder).
+    #[must_use]
+    pub fn with_flat_shading(self, enabled: bool) -> Self {
+        self.set_flat_shading(enabled);
+        self
+    }
+
+    /// Sets the texture mip level bias (builder).
+    ///
+    /// Positive values blur, negative values sharpen (and may alias).
+    #[must_use]
+    pub fn with_texture_lod_bias(self, bias: f32) -> Self {
+        self.set_texture_lod_bias(bias);
+        self
+    }
+
+    /// Forces every texture to be sampled at a fixed mip level, for
+    /// debugging texture LODs. `None` restores regular filtering.
+    ///
+    /// Changes the shader, so the pipeline is recompiled.
+    pub fn set_forced_mip_level(&self, level: Option<u32>) {
+        let mut guard = self.forced_mip_level.write();
+        if *guard != level {
+            *guard = level;
+            self.version
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the forced mip level, if any.
+    #[must_use]
+    pub fn forced_mip_level(&self) -> Option<u32> {
+        *self.forced_mip_level.read()
+    }
+
+    pub(crate) fn extra_defines(&self, defines: &mut ShaderDefines) {
+        if let Some(level) = *self.forced_mip_level.read() {
+            defines.set("FORCED_MIP_LEVEL", &level.to_string());
+        }
+    }
 }
 
 impl Default for PhongMaterial {
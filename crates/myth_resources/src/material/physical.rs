@@ -5,7 +5,7 @@ use parking_lot::RwLock;
 
 use crate::ShaderDefines;
 use crate::TextureHandle;
-use crate::material::{AlphaMode, Side};
+use crate::material::{AlphaMode, Side, StencilSettings};
 use crate::screen_space::FeatureId;
 use crate::uniforms::Mat3Uniform;
 
@@ -29,6 +29,9 @@ bitflags! {
         const SSS = 1 << 9;
         const SSR = 1 << 10;
 
+        const TRIPLANAR = 1 << 11;
+        const PARALLAX = 1 << 12;
+
         const STANDARD_PBR = Self::IBL.bits() | Self::SPECULAR.bits() | Self::IOR.bits();
     }
 }
@@ -73,6 +76,18 @@ pub struct PhysicalMaterial {
     #[uniform(default = "Vec2::ONE")]
     pub normal_scale: Vec2,
 
+    /// World-space tiling of tri-planar mapping (texture repeats per unit).
+    #[uniform(default = "1.0")]
+    pub triplanar_scale: f32,
+
+    /// Depth of the parallax height field in UV units.
+    #[uniform(default = "0.05")]
+    pub parallax_scale: f32,
+
+    /// Maximum ray-march steps for parallax occlusion mapping.
+    #[uniform(default = "16")]
+    pub parallax_steps: u32,
+
     /// AO map intensity.
     #[uniform(default = "1.0")]
     pub ao_map_intensity: f32,
@@ -149,6 +164,11 @@ pub struct PhysicalMaterial {
     #[uniform]
     pub dispersion: f32,
 
+    /// Mip level bias added when sampling the material's textures.
+    /// Positive values blur, negative values sharpen (and may alias).
+    #[uniform]
+    pub texture_lod_bias: f32,
+
     /// Subsurface scattering feature ID.
     #[uniform(hidden)]
     pub sss_id: u32,
@@ -181,6 +201,10 @@ pub struct PhysicalMaterial {
     #[texture]
     pub ao_map: TextureSlot,
 
+    /// The height map for parallax occlusion mapping (white = high).
+    #[texture]
+    pub height_map: TextureSlot,
+
     /// The specular map.
     #[texture]
     pub specular_map: TextureSlot,
@@ -235,6 +259,13 @@ pub struct PhysicalMaterial {
         clone_with = "|s: &Self| parking_lot::RwLock::new(*s.features.read())"
     )]
     pub(crate) features: RwLock<PhysicalFeatures>,
+
+    /// Debug override sampling every texture at a fixed mip level.
+    #[internal(
+        default = "parking_lot::RwLock::new(None)",
+        clone_with = "|s: &Self| parking_lot::RwLock::new(*s.forced_mip_level.read())"
+    )]
+    pub(crate) forced_mip_level: RwLock<Option<u32>>,
 }
 
 impl PhysicalMaterial {
@@ -337,6 +368,13 @@ impl PhysicalMaterial {
         self
     }
 
+    /// Sets the height map texture (builder).
+    #[must_use]
+    pub fn with_height_map(self, handle: TextureHandle) -> Self {
+        self.set_height_map(Some(handle));
+        self
+    }
+
     /// Sets the face culling side (builder).
     #[must_use]
     pub fn with_side(self, side: Side) -> Self {
@@ -358,6 +396,55 @@ impl PhysicalMaterial {
         self
     }
 
+    /// Sets wireframe rendering (builder).
+    #[must_use]
+    pub fn with_wireframe(self, enabled: bool) -> Self {
+        self.set_wireframe(enabled);
+        self
+    }
+
+    /// Sets the stencil test and write state (builder).
+    #[must_use]
+    pub fn with_stencil(self, stencil: StencilSettings) -> Self {
+        self.set_stencil(Some(stencil));
+        self
+    }
+
+    /// Sets flat (per-face) shading (builder).
+    #[must_use]
+    pub fn with_flat_shading(self, enabled: bool) -> Self {
+        self.set_flat_shading(enabled);
+        self
+    }
+
+    /// Sets the texture mip level bias (builder).
+    ///
+    /// Positive values blur, negative values sharpen (and may alias).
+    #[must_use]
+    pub fn with_texture_lod_bias(self, bias: f32) -> Self {
+        self.set_texture_lod_bias(bias);
+        self
+    }
+
+    /// Forces every texture to be sampled at a fixed mip level, for
+    /// debugging texture LODs. `None` restores regular filtering.
+    ///
+    /// Changes the shader, so the pipeline is recompiled.
+    pub fn set_forced_mip_level(&self, level: Option<u32>) {
+        let mut guard = self.forced_mip_level.write();
+        if *guard != level {
+            *guard = level;
+            self.version
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        }
+    }
+
+    /// Returns the forced mip level, if any.
+    #[must_use]
+    pub fn forced_mip_level(&self) -> Option<u32> {
+        *self.forced_mip_level.read()
+    }
+
     // -- Feature-based shader defines --
 
     pub(crate) fn extra_defines(&self, defines: &mut ShaderDefines) {
@@ -390,6 +477,16 @@ impl PhysicalMaterial {
         if features.contains(PhysicalFeatures::DISPERSION) {
             defines.set("USE_DISPERSION", "1");
         }
+        if features.contains(PhysicalFeatures::TRIPLANAR) {
+            defines.set("USE_TRIPLANAR", "1");
+        }
+        if features.contains(PhysicalFeatures::PARALLAX) {
+            defines.set("USE_PARALLAX", "1");
+        }
+
+        if let Some(level) = *self.forced_mip_level.read() {
+            defines.set("FORCED_MIP_LEVEL", &level.to_string());
+        }
     }
 
     // -- Feature toggle --
@@ -508,6 +605,34 @@ impl PhysicalMaterial {
         self
     }
 
+    /// Enables tri-planar mapping (builder).
+    ///
+    /// The color, normal, metalness and roughness maps are projected along
+    /// world X/Y/Z and blended by the surface normal, so the mesh needs no
+    /// UVs. `scale` is the number of texture repeats per world unit. Other
+    /// maps still read UVs.
+    #[must_use]
+    pub fn with_triplanar(self, scale: f32) -> Self {
+        self.uniforms_mut().triplanar_scale = scale;
+        self.toggle_feature(PhysicalFeatures::TRIPLANAR, true);
+        self
+    }
+
+    /// Enables parallax occlusion mapping (builder).
+    ///
+    /// The fragment shader ray-marches `height_map` in tangent space and
+    /// shifts the UVs of the other maps to where the view ray hits the
+    /// height field. `scale` is the depth of the height field in UV units
+    /// (typically 0.02–0.1). Uses vertex tangents when present, otherwise
+    /// screen-space derivatives, like normal mapping.
+    #[must_use]
+    pub fn with_parallax(self, height_map: TextureHandle, scale: f32) -> Self {
+        self.set_height_map(Some(height_map));
+        self.uniforms_mut().parallax_scale = scale;
+        self.toggle_feature(PhysicalFeatures::PARALLAX, true);
+        self
+    }
+
     // -- Screen-space effects --
 
     /// Sets the subsurface scattering feature ID.
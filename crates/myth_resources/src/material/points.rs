@@ -0,0 +1,146 @@
+use glam::Vec4;
+use myth_macros::myth_material;
+
+use crate::material::AlphaMode;
+
+/// Unlit material that draws every vertex of a geometry as a screen-aligned
+/// square sprite, for point clouds and simple particle effects.
+///
+/// WebGPU has no point-size control, so sprites are expanded in the vertex
+/// shader: each vertex becomes an instanced quad of [`size`](PointsUniforms::size).
+/// Typically paired with a [`PrimitiveTopology::PointList`](wgpu::PrimitiveTopology::PointList)
+/// geometry such as [`Geometry::new_points`](crate::Geometry::new_points) or
+/// a glTF `POINTS` primitive; index buffers are ignored.
+///
+/// - Without size attenuation, `size` is in **pixels**.
+/// - With size attenuation, `size` is in **world units**, so sprites shrink
+///   with view depth.
+///
+/// When the geometry carries a `color` attribute, per-vertex colors are
+/// multiplied with [`color`](PointsUniforms::color); a `size` (`Float32`)
+/// attribute likewise scales [`size`](PointsUniforms::size) per point. Points do not cast
+/// shadows and are not rendered in the depth prepass.
+#[myth_material(shader = "entry/main/points", crate_path = "crate")]
+pub struct PointsMaterial {
+    /// Point color.
+    #[uniform(default = "Vec4::ONE")]
+    pub color: Vec4,
+
+    /// Opacity value.
+    #[uniform(default = "1.0")]
+    pub opacity: f32,
+
+    /// Sprite size: pixels, or world units when size attenuation is enabled.
+    #[uniform(default = "4.0")]
+    pub size: f32,
+
+    /// Non-zero to scale sprites by view depth.
+    #[uniform(hidden)]
+    pub size_attenuation: u32,
+
+    /// Non-zero to discard fragments outside the inscribed circle.
+    #[uniform(hidden)]
+    pub round_sprite: u32,
+}
+
+impl PointsMaterial {
+    /// Creates a new points material with the given color.
+    #[must_use]
+    pub fn new(color: Vec4) -> Self {
+        Self::from_uniforms(PointsUniforms {
+            color,
+            ..Default::default()
+        })
+    }
+
+    /// Returns whether sprites are scaled by view depth.
+    #[must_use]
+    pub fn size_attenuation(&self) -> bool {
+        self.uniforms.read().size_attenuation != 0
+    }
+
+    /// Enables or disables scaling sprites by view depth.
+    pub fn set_size_attenuation(&self, enabled: bool) {
+        self.uniforms.write().size_attenuation = u32::from(enabled);
+    }
+
+    /// Returns whether sprites are clipped to a circle.
+    #[must_use]
+    pub fn round_sprite(&self) -> bool {
+        self.uniforms.read().round_sprite != 0
+    }
+
+    /// Enables or disables round sprites.
+    ///
+    /// With [`AlphaMode::Blend`] the circle edge is anti-aliased; otherwise
+    /// fragments outside the circle are discarded.
+    pub fn set_round_sprite(&self, enabled: bool) {
+        self.uniforms.write().round_sprite = u32::from(enabled);
+    }
+
+    /// Sets the point color (builder).
+    #[must_use]
+    pub fn with_color(self, color: Vec4) -> Self {
+        self.uniforms.write().color = color;
+        self
+    }
+
+    /// Sets the opacity (builder).
+    ///
+    /// Values below `1.0` only take effect with [`AlphaMode::Blend`].
+    #[must_use]
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        self.uniforms.write().opacity = opacity;
+        self
+    }
+
+    /// Sets the sprite size (builder).
+    #[must_use]
+    pub fn with_size(self, size: f32) -> Self {
+        self.uniforms.write().size = size;
+        self
+    }
+
+    /// Enables or disables size attenuation (builder).
+    #[must_use]
+    pub fn with_size_attenuation(self, enabled: bool) -> Self {
+        self.set_size_attenuation(enabled);
+        self
+    }
+
+    /// Enables or disables round sprites (builder).
+    #[must_use]
+    pub fn with_round_sprite(self, enabled: bool) -> Self {
+        self.set_round_sprite(enabled);
+        self
+    }
+
+    /// Sets the alpha mode (builder).
+    #[must_use]
+    pub fn with_alpha_mode(self, mode: AlphaMode) -> Self {
+        self.set_alpha_mode(mode);
+        self
+    }
+
+    /// Sets depth write (builder).
+    #[must_use]
+    pub fn with_depth_write(self, enabled: bool) -> Self {
+        self.set_depth_write(enabled);
+        self
+    }
+
+    /// Enables or disables additive blending (builder).
+    ///
+    /// Only takes effect with a blended alpha mode.
+    #[must_use]
+    pub fn with_additive_blending(self, enabled: bool) -> Self {
+        self.set_additive_blending(enabled);
+        self
+    }
+}
+
+impl Default for PointsMaterial {
+    fn default() -> Self {
+        Self::new(Vec4::ONE)
+    }
+}
@@ -0,0 +1,160 @@
+use glam::{Vec2, Vec4};
+use myth_macros::myth_material;
+
+use crate::TextureHandle;
+use crate::material::AlphaMode;
+use crate::uniforms::Mat3Uniform;
+
+/// Unlit, alpha-blended textured quad for world-space sprites, labels and
+/// particle billboards.
+///
+/// Drawn on a quad geometry such as [`Geometry::new_quad`](crate::Geometry::new_quad).
+/// The quad is shifted so that [`center`](SpriteUniforms::center) (in quad
+/// UV space, `(0.5, 0.5)` by default) lands on the node origin, which makes
+/// it the pivot for rotation, scaling and placement.
+///
+/// - With size attenuation (the default) the sprite is an ordinary mesh:
+///   its world size comes from the geometry and the node scale, and it faces
+///   wherever the node faces. Pair it with a billboard
+///   (`Scene::set_billboard`) to keep it turned toward the camera.
+/// - Without size attenuation the sprite is drawn screen-aligned at the
+///   node origin with a constant [`pixel_size`](SpriteUniforms::pixel_size),
+///   regardless of distance. The node rotation and scale are ignored.
+///
+/// The color map is multiplied with [`color`](SpriteUniforms::color), the
+/// per-vertex `color` attribute when present, and the per-node tint
+/// (`Scene::set_node_tint`), so many sprites can share one material. Sprites
+/// do not cast shadows and are not rendered in the depth prepass.
+#[myth_material(shader = "entry/main/sprite", crate_path = "crate")]
+pub struct SpriteMaterial {
+    /// Tint color.
+    #[uniform(default = "Vec4::ONE")]
+    pub color: Vec4,
+
+    /// Opacity value.
+    #[uniform(default = "1.0")]
+    pub opacity: f32,
+
+    /// Alpha test threshold.
+    #[uniform]
+    pub alpha_test: f32,
+
+    /// Pivot in quad UV space: `(0.5, 0.5)` is the center, `(0.5, 0.0)`
+    /// the bottom edge.
+    #[uniform(default = "Vec2::splat(0.5)")]
+    pub center: Vec2,
+
+    /// Sprite size in pixels, used when size attenuation is disabled.
+    #[uniform(default = "Vec2::splat(32.0)")]
+    pub pixel_size: Vec2,
+
+    /// Non-zero to size the sprite in world units.
+    #[uniform(hidden, default = "1")]
+    pub size_attenuation: u32,
+
+    /// The color map.
+    #[texture]
+    pub map: TextureSlot,
+}
+
+impl SpriteMaterial {
+    /// Creates a sprite material with the given color map, alpha-blended
+    /// and without depth writes.
+    #[must_use]
+    pub fn new(map: TextureHandle) -> Self {
+        Self::from_color(Vec4::ONE).with_map(map)
+    }
+
+    /// Creates an untextured sprite material with the given tint,
+    /// alpha-blended and without depth writes.
+    #[must_use]
+    pub fn from_color(color: Vec4) -> Self {
+        let material = Self::from_uniforms(SpriteUniforms {
+            color,
+            ..Default::default()
+        });
+        material.set_alpha_mode(AlphaMode::Blend);
+        material.set_depth_write(false);
+        material
+    }
+
+    /// Returns whether the sprite is sized in world units.
+    #[must_use]
+    pub fn size_attenuation(&self) -> bool {
+        self.uniforms.read().size_attenuation != 0
+    }
+
+    /// Enables world-unit sizing, or constant [`pixel_size`](Self::pixel_size)
+    /// sizing when disabled.
+    pub fn set_size_attenuation(&self, enabled: bool) {
+        self.uniforms.write().size_attenuation = u32::from(enabled);
+    }
+
+    /// Sets the tint color (builder).
+    #[must_use]
+    pub fn with_color(self, color: Vec4) -> Self {
+        self.uniforms.write().color = color;
+        self
+    }
+
+    /// Sets the opacity (builder).
+    #[must_use]
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        self.uniforms.write().opacity = opacity;
+        self
+    }
+
+    /// Sets the color map texture (builder).
+    #[must_use]
+    pub fn with_map(self, handle: TextureHandle) -> Self {
+        self.set_map(Some(handle));
+        self
+    }
+
+    /// Sets the pivot in quad UV space (builder).
+    #[must_use]
+    pub fn with_center(self, center: Vec2) -> Self {
+        self.uniforms.write().center = center;
+        self
+    }
+
+    /// Draws the sprite at a constant size in pixels (builder).
+    ///
+    /// Disables size attenuation.
+    #[must_use]
+    pub fn with_pixel_size(self, size: Vec2) -> Self {
+        {
+            let mut uniforms = self.uniforms.write();
+            uniforms.pixel_size = size;
+            uniforms.size_attenuation = 0;
+        }
+        self
+    }
+
+    /// Enables or disables size attenuation (builder).
+    #[must_use]
+    pub fn with_size_attenuation(self, enabled: bool) -> Self {
+        self.set_size_attenuation(enabled);
+        self
+    }
+
+    /// Sets the alpha mode (builder).
+    #[must_use]
+    pub fn with_alpha_mode(self, mode: AlphaMode) -> Self {
+        self.set_alpha_mode(mode);
+        self
+    }
+
+    /// Sets depth write (builder).
+    #[must_use]
+    pub fn with_depth_write(self, enabled: bool) -> Self {
+        self.set_depth_write(enabled);
+        self
+    }
+}
+
+impl Default for SpriteMaterial {
+    fn default() -> Self {
+        Self::from_color(Vec4::ONE)
+    }
+}
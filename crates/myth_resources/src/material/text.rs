@@ -0,0 +1,150 @@
+use glam::Vec4;
+use myth_macros::myth_material;
+
+use crate::TextureHandle;
+use crate::material::AlphaMode;
+use crate::uniforms::Mat3Uniform;
+
+/// Unlit signed-distance-field text, drawn on the glyph quads of a
+/// [`TextMesh`](crate::text::TextMesh) with a
+/// [`FontAtlas`](crate::text::FontAtlas) texture as the map.
+///
+/// The atlas stores distance to the glyph edge, so the edge stays sharp at
+/// any scale. [`outline_width`](TextUniforms::outline_width) draws an
+/// outline outside the edge and [`softness`](TextUniforms::softness)
+/// blurs it, e.g. for a glow. Both are in atlas distance units: `0.5`
+/// reaches the atlas spread (`FontAtlas::spread`) away from the edge.
+///
+/// - With size attenuation (the default) the text is an ordinary mesh:
+///   its size comes from the text mesh and the node scale. Pair it with a
+///   billboard (`Scene::set_billboard`) to keep it turned to the camera.
+/// - Without size attenuation the text is drawn screen-aligned at the node
+///   origin, one text unit spanning [`pixel_scale`](TextUniforms::pixel_scale)
+///   pixels regardless of distance. The node rotation and scale are ignored.
+///
+/// Text does not cast shadows and is not rendered in the depth prepass.
+#[myth_material(shader = "entry/main/text", crate_path = "crate")]
+pub struct TextMaterial {
+    /// Fill color.
+    #[uniform(default = "Vec4::ONE")]
+    pub color: Vec4,
+
+    /// Outline color.
+    #[uniform(default = "Vec4::new(0.0, 0.0, 0.0, 1.0)")]
+    pub outline_color: Vec4,
+
+    /// Opacity value.
+    #[uniform(default = "1.0")]
+    pub opacity: f32,
+
+    /// Alpha test threshold.
+    #[uniform]
+    pub alpha_test: f32,
+
+    /// Outline thickness outside the glyph edge, in atlas distance units
+    /// (`0.0` disables the outline).
+    #[uniform]
+    pub outline_width: f32,
+
+    /// Extra edge blur in atlas distance units, added to the screen-space
+    /// antialiasing width.
+    #[uniform]
+    pub softness: f32,
+
+    /// Pixels per text unit, used when size attenuation is disabled.
+    #[uniform(default = "24.0")]
+    pub pixel_scale: f32,
+
+    /// Non-zero to size the text in world units.
+    #[uniform(hidden, default = "1")]
+    pub size_attenuation: u32,
+
+    /// The SDF font atlas.
+    #[texture]
+    pub map: TextureSlot,
+}
+
+impl TextMaterial {
+    /// Creates a white text material reading the given font atlas,
+    /// alpha-blended and without depth writes.
+    #[must_use]
+    pub fn new(atlas: TextureHandle) -> Self {
+        let material = Self::from_uniforms(TextUniforms::default());
+        material.set_map(Some(atlas));
+        material.set_alpha_mode(AlphaMode::Blend);
+        material.set_depth_write(false);
+        material
+    }
+
+    /// Returns whether the text is sized in world units.
+    #[must_use]
+    pub fn size_attenuation(&self) -> bool {
+        self.uniforms.read().size_attenuation != 0
+    }
+
+    /// Enables world-unit sizing, or constant
+    /// [`pixel_scale`](Self::pixel_scale) sizing when disabled.
+    pub fn set_size_attenuation(&self, enabled: bool) {
+        self.uniforms.write().size_attenuation = u32::from(enabled);
+    }
+
+    /// Sets the fill color (builder).
+    #[must_use]
+    pub fn with_color(self, color: Vec4) -> Self {
+        self.uniforms.write().color = color;
+        self
+    }
+
+    /// Sets the opacity (builder).
+    #[must_use]
+    pub fn with_opacity(self, opacity: f32) -> Self {
+        self.uniforms.write().opacity = opacity;
+        self
+    }
+
+    /// Adds an outline of the given color and width (builder).
+    #[must_use]
+    pub fn with_outline(self, color: Vec4, width: f32) -> Self {
+        {
+            let mut uniforms = self.uniforms.write();
+            uniforms.outline_color = color;
+            uniforms.outline_width = width;
+        }
+        self
+    }
+
+    /// Sets the edge softness (builder).
+    #[must_use]
+    pub fn with_softness(self, softness: f32) -> Self {
+        self.uniforms.write().softness = softness;
+        self
+    }
+
+    /// Draws the text screen-aligned at a constant size of `pixel_scale`
+    /// pixels per text unit (builder).
+    ///
+    /// Disables size attenuation.
+    #[must_use]
+    pub fn with_screen_space(self, pixel_scale: f32) -> Self {
+        {
+            let mut uniforms = self.uniforms.write();
+            uniforms.pixel_scale = pixel_scale;
+            uniforms.size_attenuation = 0;
+        }
+        self
+    }
+
+    /// Sets the alpha mode (builder).
+    #[must_use]
+    pub fn with_alpha_mode(self, mode: AlphaMode) -> Self {
+        self.set_alpha_mode(mode);
+        self
+    }
+
+    /// Sets depth write (builder).
+    #[must_use]
+    pub fn with_depth_write(self, enabled: bool) -> Self {
+        self.set_depth_write(enabled);
+        self
+    }
+}
@@ -2,7 +2,7 @@ use glam::Vec4;
 use myth_macros::myth_material;
 
 use crate::TextureHandle;
-use crate::material::{AlphaMode, Side};
+use crate::material::{AlphaMode, Side, StencilSettings};
 use crate::uniforms::Mat3Uniform;
 
 #[myth_material(shader = "entry/main/unlit", crate_path = "crate")]
@@ -75,6 +75,20 @@ impl UnlitMaterial {
         self.set_depth_write(enabled);
         self
     }
+
+    /// Sets wireframe rendering (builder).
+    #[must_use]
+    pub fn with_wireframe(self, enabled: bool) -> Self {
+        self.set_wireframe(enabled);
+        self
+    }
+
+    /// Sets the stencil test and write state (builder).
+    #[must_use]
+    pub fn with_stencil(self, stencil: StencilSettings) -> Self {
+        self.set_stencil(Some(stencil));
+        self
+    }
 }
 
 impl Default for UnlitMaterial {
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use uuid::Uuid;
 
 use crate::buffer::{BufferReadGuard, CpuBuffer};
@@ -7,6 +9,23 @@ use crate::{GeometryHandle, MaterialHandle};
 pub const MAX_MORPH_TARGETS: usize = 128;
 pub const MORPH_WEIGHT_THRESHOLD: f32 = 0.0000;
 
+/// A range of a mesh's geometry drawn with its own material.
+///
+/// `range` indexes the geometry's index buffer, or its vertices when the
+/// geometry is not indexed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submesh {
+    pub range: Range<u32>,
+    pub material: MaterialHandle,
+}
+
+impl Submesh {
+    #[must_use]
+    pub fn new(range: Range<u32>, material: MaterialHandle) -> Self {
+        Self { range, material }
+    }
+}
+
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub struct Mesh {
@@ -16,6 +35,10 @@ pub struct Mesh {
     // === Rescources ===
     pub geometry: GeometryHandle,
     pub material: MaterialHandle,
+    /// Per-range materials. When empty, the whole geometry is drawn with
+    /// `material`; otherwise one draw is issued per submesh, all sharing the
+    /// node's transform and object bind group.
+    pub submeshes: Vec<Submesh>,
 
     // === Instance-specific rendering settings ===
     pub visible: bool,
@@ -56,6 +79,7 @@ impl Mesh {
             name: format!("Mesh_{uuid}"),
             geometry,
             material,
+            submeshes: Vec::new(),
             visible: true,
             cast_shadows: true,
             receive_shadows: true,
@@ -68,6 +92,27 @@ impl Mesh {
         }
     }
 
+    /// Splits the mesh into submeshes (builder).
+    ///
+    /// `material` is set to the first submesh's material so code that only
+    /// knows about a single material still sees a sensible one.
+    #[must_use]
+    pub fn with_submeshes(mut self, submeshes: Vec<Submesh>) -> Self {
+        if let Some(first) = submeshes.first() {
+            self.material = first.material;
+        }
+        self.submeshes = submeshes;
+        self
+    }
+
+    /// Returns every material the mesh draws with.
+    pub fn materials(&self) -> impl Iterator<Item = MaterialHandle> + '_ {
+        let single = self.submeshes.is_empty().then_some(self.material);
+        single
+            .into_iter()
+            .chain(self.submeshes.iter().map(|s| s.material))
+    }
+
     /// Returns the morph target influences as a slice
     #[inline]
     pub fn morph_target_influences(&self) -> &[f32] {
@@ -103,9 +148,35 @@ impl Mesh {
 
     /// set influence for a single morph target
     pub fn set_morph_target_influence(&mut self, index: usize, weight: f32) {
-        if index < self.morph_target_influences.len() {
-            self.morph_target_influences[index] = weight;
+        self.set_morph_weight(index, weight);
+    }
+
+    /// Sets the weight of a single morph target.
+    ///
+    /// The GPU buffer is refreshed on the next frame if the value changed.
+    /// Returns `false` (and logs a warning) if `index` is past the mesh's
+    /// target count.
+    pub fn set_morph_weight(&mut self, index: usize, value: f32) -> bool {
+        let Some(weight) = self.morph_target_influences.get_mut(index) else {
+            log::warn!(
+                "Morph target index {index} out of range (mesh has {})",
+                self.morph_target_influences.len()
+            );
+            return false;
+        };
+        if weight.to_bits() != value.to_bits() {
+            *weight = value;
+            self.morph_update_frames = 2;
         }
+        true
+    }
+
+    /// Returns the morph target weights for in-place editing.
+    ///
+    /// The GPU buffer is conservatively marked for refresh.
+    pub fn morph_weights_mut(&mut self) -> &mut [f32] {
+        self.morph_update_frames = 2;
+        &mut self.morph_target_influences
     }
 
     /// batch set morph target influences
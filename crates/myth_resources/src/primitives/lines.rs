@@ -0,0 +1,64 @@
+use crate::geometry::{Attribute, Geometry};
+use wgpu::{PrimitiveTopology, VertexFormat};
+
+/// Creates a square grid of lines on the XZ plane, centered at the origin.
+///
+/// `size` is the full side length and `divisions` the number of cells along
+/// each axis, producing `divisions + 1` lines per direction.
+#[must_use]
+pub fn create_grid(size: f32, divisions: u32) -> Geometry {
+    let divisions = divisions.max(1);
+    let half = size / 2.0;
+    let step = size / divisions as f32;
+
+    let mut positions = Vec::with_capacity((divisions as usize + 1) * 4);
+    for i in 0..=divisions {
+        let k = i as f32 * step - half;
+        positions.push([-half, 0.0, k]);
+        positions.push([half, 0.0, k]);
+        positions.push([k, 0.0, -half]);
+        positions.push([k, 0.0, half]);
+    }
+
+    let mut geo = Geometry::new();
+    geo.topology = PrimitiveTopology::LineList;
+    geo.set_attribute(
+        "position",
+        Attribute::new_planar(&positions, VertexFormat::Float32x3),
+    );
+    geo.compute_bounding_volume();
+
+    geo
+}
+
+/// Creates the three world axes as colored line segments of length `size`:
+/// X in red, Y in green and Z in blue.
+#[must_use]
+pub fn create_axes(size: f32) -> Geometry {
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [size, 0.0, 0.0],
+        [0.0, 0.0, 0.0],
+        [0.0, size, 0.0],
+        [0.0, 0.0, 0.0],
+        [0.0, 0.0, size],
+    ];
+    let red = [1.0, 0.0, 0.0, 1.0];
+    let green = [0.0, 1.0, 0.0, 1.0];
+    let blue = [0.0, 0.0, 1.0, 1.0];
+    let colors = [red, red, green, green, blue, blue];
+
+    let mut geo = Geometry::new();
+    geo.topology = PrimitiveTopology::LineList;
+    geo.set_attribute(
+        "position",
+        Attribute::new_planar(&positions, VertexFormat::Float32x3),
+    );
+    geo.set_attribute(
+        "color",
+        Attribute::new_planar(&colors, VertexFormat::Float32x4),
+    );
+    geo.compute_bounding_volume();
+
+    geo
+}
@@ -1,7 +1,9 @@
 pub mod box_shape;
+pub mod lines;
 pub mod plane;
 pub mod sphere;
 
 pub use box_shape::create_box;
+pub use lines::{create_axes, create_grid};
 pub use plane::{PlaneOptions, create_plane};
 pub use sphere::{SphereOptions, create_sphere};
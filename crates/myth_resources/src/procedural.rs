@@ -0,0 +1,396 @@
+//! Procedural texture generation.
+//!
+//! Generates tileable gradient-noise [`Image`]s on the CPU, so terrain,
+//! cloud and dissolve effects don't need to ship large noise PNGs, and
+//! simple colour ramps via [`gradient`].
+//!
+//! Both noise kinds are periodic over the unit square: Perlin noise wraps
+//! its gradient lattice, and simplex noise is sampled on a 4D torus. A
+//! texture generated with any [`NoiseParams`] therefore tiles seamlessly
+//! with `Repeat` addressing.
+//!
+//! Lattice gradients are derived from an integer hash of the seed rather
+//! than a random number generator, so the same parameters produce the same
+//! bytes on every platform and crate version.
+//!
+//! ```rust,ignore
+//! use myth::resources::procedural::{self, NoiseKind, NoiseParams};
+//!
+//! let image = procedural::noise_texture(256, 256, NoiseParams {
+//!     kind: NoiseKind::Simplex,
+//!     seed: 7,
+//!     octaves: 5,
+//!     ..Default::default()
+//! });
+//! let image_handle = assets.images.add(image);
+//! let texture = assets.textures.add(Texture::new_2d(Some("noise"), image_handle));
+//! ```
+
+use std::f32::consts::TAU;
+
+use glam::Vec4;
+use half::f16;
+
+use crate::image::{Image, ImageDimension, PixelFormat};
+
+/// Gradient noise algorithm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum NoiseKind {
+    /// Classic Perlin noise on a square lattice.
+    #[default]
+    Perlin,
+    /// Simplex noise. Fewer directional artifacts than Perlin, at a higher
+    /// per-sample cost (tiling requires a 4D evaluation).
+    Simplex,
+}
+
+/// Parameters for [`noise_texture`] and [`fbm`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseParams {
+    /// Noise algorithm.
+    pub kind: NoiseKind,
+    /// Seed for the gradient lattice. Equal seeds give identical output.
+    pub seed: u32,
+    /// Noise cells across the texture for the first octave.
+    ///
+    /// Integer so that every octave tiles; values below 1 are treated as 1.
+    pub frequency: u32,
+    /// Number of octaves summed by [`fbm`]. `1` gives plain noise.
+    pub octaves: u32,
+    /// Frequency multiplier between octaves. Each octave's cell count is
+    /// rounded to an integer to stay tileable.
+    pub lacunarity: f32,
+    /// Amplitude multiplier between octaves.
+    pub persistence: f32,
+    /// Output format: `R8Unorm`, `Rgba8Unorm` (grey, opaque) or
+    /// `Rgba16Float` (grey, opaque).
+    pub format: PixelFormat,
+}
+
+impl Default for NoiseParams {
+    fn default() -> Self {
+        Self {
+            kind: NoiseKind::Perlin,
+            seed: 0,
+            frequency: 4,
+            octaves: 4,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            format: PixelFormat::R8Unorm,
+        }
+    }
+}
+
+/// Generates a tileable 2D noise image.
+///
+/// Each texel holds [`fbm`] at the texel centre, remapped from `[-1, 1]`
+/// to `[0, 1]`. Upload it with [`ColorSpace::Linear`](crate::image::ColorSpace::Linear)
+/// when the values are used as data (height, dissolve threshold).
+#[must_use]
+pub fn noise_texture(width: u32, height: u32, params: NoiseParams) -> Image {
+    let width = width.max(1);
+    let height = height.max(1);
+    let texel_size = params.format.block_copy_size() as usize;
+    let mut data = Vec::with_capacity(width as usize * height as usize * texel_size);
+
+    for y in 0..height {
+        let v = (y as f32 + 0.5) / height as f32;
+        for x in 0..width {
+            let u = (x as f32 + 0.5) / width as f32;
+            let value = (fbm(u, v, params) * 0.5 + 0.5).clamp(0.0, 1.0);
+            match params.format {
+                PixelFormat::R8Unorm => data.push(unorm8(value)),
+                PixelFormat::Rgba8Unorm => {
+                    let c = unorm8(value);
+                    data.extend_from_slice(&[c, c, c, 255]);
+                }
+                PixelFormat::Rgba16Float => {
+                    let c = f16::from_f32(value);
+                    for channel in [c, c, c, f16::ONE] {
+                        data.extend_from_slice(&channel.to_le_bytes());
+                    }
+                }
+            }
+        }
+    }
+
+    Image::new(
+        width,
+        height,
+        1,
+        ImageDimension::D2,
+        params.format,
+        Some(data),
+    )
+}
+
+/// Samples fractal Brownian motion at normalised coordinates `(u, v)`.
+///
+/// Sums `params.octaves` layers of noise, each with `lacunarity` times the
+/// frequency and `persistence` times the amplitude of the previous one.
+/// The result is normalised to roughly `[-1, 1]` and is periodic over the
+/// unit square.
+#[must_use]
+pub fn fbm(u: f32, v: f32, params: NoiseParams) -> f32 {
+    let mut sum = 0.0;
+    let mut norm = 0.0;
+    let mut amplitude = 1.0;
+    let mut frequency = params.frequency.max(1) as f32;
+
+    for octave in 0..params.octaves.max(1) {
+        let period = (frequency.round() as u32).max(1);
+        let seed = params.seed.wrapping_add(octave.wrapping_mul(0x9E37_79B9));
+        sum += amplitude * noise(u, v, params.kind, period, seed);
+        norm += amplitude;
+        amplitude *= params.persistence;
+        frequency *= params.lacunarity;
+    }
+
+    if norm > 0.0 { sum / norm } else { 0.0 }
+}
+
+/// Samples a single octave of noise at normalised coordinates `(u, v)`.
+///
+/// The unit square holds `period` × `period` noise cells and the result,
+/// roughly in `[-1, 1]`, repeats every 1.0 in both directions.
+#[must_use]
+pub fn noise(u: f32, v: f32, kind: NoiseKind, period: u32, seed: u32) -> f32 {
+    let period = period.max(1);
+    match kind {
+        NoiseKind::Perlin => perlin(u * period as f32, v * period as f32, period, seed),
+        NoiseKind::Simplex => {
+            // Map each axis onto a circle whose circumference is `period`
+            // cells, so the 4D sample point wraps around a torus.
+            let radius = period as f32 / TAU;
+            let (su, cu) = (u * TAU).sin_cos();
+            let (sv, cv) = (v * TAU).sin_cos();
+            simplex4([cu * radius, su * radius, cv * radius, sv * radius], seed)
+        }
+    }
+}
+
+/// Generates a vertical colour ramp, 1 texel wide and `size` tall.
+///
+/// `stops` are `(position, colour)` pairs with positions in `[0, 1]`, where
+/// `0` is the top row. They need not be sorted. Colours are linear RGBA
+/// (like material colours), interpolated in linear space and stored as
+/// sRGB-encoded `Rgba8Unorm`, so the image should be uploaded with
+/// [`ColorSpace::Srgb`](crate::image::ColorSpace::Srgb). An empty `stops`
+/// slice gives opaque white.
+#[must_use]
+pub fn gradient(stops: &[(f32, Vec4)], size: u32) -> Image {
+    let size = size.max(1);
+    let mut sorted = stops.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut data = Vec::with_capacity(size as usize * 4);
+    for y in 0..size {
+        let t = if size == 1 {
+            0.0
+        } else {
+            y as f32 / (size - 1) as f32
+        };
+        data.extend_from_slice(&encode_srgb8(sample_stops(&sorted, t)));
+    }
+
+    Image::new(
+        1,
+        size,
+        1,
+        ImageDimension::D2,
+        PixelFormat::Rgba8Unorm,
+        Some(data),
+    )
+}
+
+/// Encodes a linear RGBA colour as sRGB bytes. Alpha stays linear.
+#[must_use]
+pub fn encode_srgb8(color: Vec4) -> [u8; 4] {
+    let encode = |c: f32| {
+        let c = c.clamp(0.0, 1.0);
+        if c <= 0.003_130_8 {
+            c * 12.92
+        } else {
+            1.055 * c.powf(1.0 / 2.4) - 0.055
+        }
+    };
+    [
+        unorm8(encode(color.x)),
+        unorm8(encode(color.y)),
+        unorm8(encode(color.z)),
+        unorm8(color.w.clamp(0.0, 1.0)),
+    ]
+}
+
+/// Linearly interpolates sorted colour stops at `t`, holding the end colours.
+fn sample_stops(stops: &[(f32, Vec4)], t: f32) -> Vec4 {
+    let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+        return Vec4::ONE;
+    };
+    if t <= first.0 {
+        return first.1;
+    }
+    if t >= last.0 {
+        return last.1;
+    }
+    for pair in stops.windows(2) {
+        let ((t0, c0), (t1, c1)) = (pair[0], pair[1]);
+        if t <= t1 {
+            let span = t1 - t0;
+            let mix = if span > 0.0 { (t - t0) / span } else { 1.0 };
+            return c0.lerp(c1, mix);
+        }
+    }
+    last.1
+}
+
+#[inline]
+fn unorm8(value: f32) -> u8 {
+    (value * 255.0 + 0.5) as u8
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Hashing
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Avalanching integer hash (lowbias32).
+#[inline]
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7FEB_352D);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846C_A68B);
+    h ^= h >> 16;
+    h
+}
+
+#[inline]
+fn hash(coords: &[i32], seed: u32) -> u32 {
+    coords.iter().fold(mix(seed), |h, &c| mix(h ^ (c as u32)))
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Perlin
+// ────────────────────────────────────────────────────────────────────────────
+
+/// Periodic 2D Perlin noise with unit-length hashed gradients.
+fn perlin(x: f32, y: f32, period: u32, seed: u32) -> f32 {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let fx = x - x0;
+    let fy = y - y0;
+
+    let period = i32::try_from(period).unwrap_or(i32::MAX);
+    let ix0 = (x0 as i32).rem_euclid(period);
+    let iy0 = (y0 as i32).rem_euclid(period);
+    let ix1 = (ix0 + 1) % period;
+    let iy1 = (iy0 + 1) % period;
+
+    let corner = |ix: i32, iy: i32, dx: f32, dy: f32| {
+        let angle = hash(&[ix, iy], seed) as f32 * (TAU / 4_294_967_296.0);
+        let (gy, gx) = angle.sin_cos();
+        gx * dx + gy * dy
+    };
+
+    let n00 = corner(ix0, iy0, fx, fy);
+    let n10 = corner(ix1, iy0, fx - 1.0, fy);
+    let n01 = corner(ix0, iy1, fx, fy - 1.0);
+    let n11 = corner(ix1, iy1, fx - 1.0, fy - 1.0);
+
+    let sx = fade(fx);
+    let sy = fade(fy);
+    let nx0 = n00 + (n10 - n00) * sx;
+    let nx1 = n01 + (n11 - n01) * sx;
+
+    // Unit gradients peak at ±√½; rescale to roughly [-1, 1].
+    (nx0 + (nx1 - nx0) * sy) * std::f32::consts::SQRT_2
+}
+
+#[inline]
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+// ────────────────────────────────────────────────────────────────────────────
+// Simplex (4D)
+// ────────────────────────────────────────────────────────────────────────────
+
+/// The 32 edge midpoints of a 4D hypercube, used as simplex gradients.
+const GRAD4: [[f32; 4]; 32] = [
+    [0.0, 1.0, 1.0, 1.0],
+    [0.0, 1.0, 1.0, -1.0],
+    [0.0, 1.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0, -1.0],
+    [0.0, -1.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0, 1.0],
+    [0.0, -1.0, -1.0, -1.0],
+    [1.0, 0.0, 1.0, 1.0],
+    [1.0, 0.0, 1.0, -1.0],
+    [1.0, 0.0, -1.0, 1.0],
+    [1.0, 0.0, -1.0, -1.0],
+    [-1.0, 0.0, 1.0, 1.0],
+    [-1.0, 0.0, 1.0, -1.0],
+    [-1.0, 0.0, -1.0, 1.0],
+    [-1.0, 0.0, -1.0, -1.0],
+    [1.0, 1.0, 0.0, 1.0],
+    [1.0, 1.0, 0.0, -1.0],
+    [1.0, -1.0, 0.0, 1.0],
+    [1.0, -1.0, 0.0, -1.0],
+    [-1.0, 1.0, 0.0, 1.0],
+    [-1.0, 1.0, 0.0, -1.0],
+    [-1.0, -1.0, 0.0, 1.0],
+    [-1.0, -1.0, 0.0, -1.0],
+    [1.0, 1.0, 1.0, 0.0],
+    [1.0, 1.0, -1.0, 0.0],
+    [1.0, -1.0, 1.0, 0.0],
+    [1.0, -1.0, -1.0, 0.0],
+    [-1.0, 1.0, 1.0, 0.0],
+    [-1.0, 1.0, -1.0, 0.0],
+    [-1.0, -1.0, 1.0, 0.0],
+    [-1.0, -1.0, -1.0, 0.0],
+];
+
+/// 4D simplex noise (Gustavson), roughly in `[-1, 1]`.
+fn simplex4(point: [f32; 4], seed: u32) -> f32 {
+    // Skewing / unskewing factors for 4D.
+    const F4: f32 = 0.309_017; // (√5 − 1) / 4
+    const G4: f32 = 0.138_196_6; // (5 − √5) / 20
+
+    let skew = point.iter().sum::<f32>() * F4;
+    let cell = point.map(|c| (c + skew).floor());
+    let unskew = cell.iter().sum::<f32>() * G4;
+    let origin = [0, 1, 2, 3].map(|axis| point[axis] - (cell[axis] - unskew));
+
+    // Rank each axis by magnitude to find which simplex we're in.
+    let mut rank = [0u8; 4];
+    for a in 0..4 {
+        for b in (a + 1)..4 {
+            if origin[a] > origin[b] {
+                rank[a] += 1;
+            } else {
+                rank[b] += 1;
+            }
+        }
+    }
+
+    let base = cell.map(|c| c as i32);
+    let mut total = 0.0;
+    for corner in 0..5u8 {
+        // Corner offsets step from (0,0,0,0) to (1,1,1,1), one axis at a time.
+        let offset = rank.map(|r| i32::from(r + corner >= 4));
+        let corner_unskew = f32::from(corner) * G4;
+        let delta = [0, 1, 2, 3].map(|axis| origin[axis] - offset[axis] as f32 + corner_unskew);
+
+        let falloff = 0.6 - delta.iter().map(|c| c * c).sum::<f32>();
+        if falloff > 0.0 {
+            let lattice = [0, 1, 2, 3].map(|axis| base[axis] + offset[axis]);
+            let grad = GRAD4[(hash(&lattice, seed) & 31) as usize];
+            let dot: f32 = grad.iter().zip(delta).map(|(g, d)| g * d).sum();
+            let falloff2 = falloff * falloff;
+            total += falloff2 * falloff2 * dot;
+        }
+    }
+
+    (27.0 * total).clamp(-1.0, 1.0)
+}
@@ -24,8 +24,10 @@
 //! 2. A 4×4 tiled rotation noise texture that randomizes the kernel orientation
 //!    per-pixel, breaking banding artifacts while keeping sample count low
 //! 3. Range-checked occlusion with smooth distance falloff
-//! 4. A cross-bilateral blur pass (depth-aware + normal-aware) that smooths
+//! 4. Cross-bilateral blur passes (depth-aware + normal-aware) that smooth
 //!    the noisy raw AO while preserving geometric edges
+//! 5. In half-resolution mode, a joint bilateral upsample to full resolution
+//!    that keeps the AO from bleeding across depth discontinuities
 
 use glam::{Vec2, Vec4};
 
@@ -52,6 +54,9 @@ pub struct SsaoUniforms {
 // SsaoSettings
 // ============================================================================
 
+/// Upper bound of [`SsaoSettings::blur_passes`].
+pub const MAX_SSAO_BLUR_PASSES: u32 = 4;
+
 /// SSAO post-processing configuration (pure data + automatic version control).
 ///
 /// This struct holds all parameters for the screen-space ambient occlusion pass.
@@ -73,12 +78,25 @@ pub struct SsaoUniforms {
 /// ssao.set_radius(0.5);
 /// ssao.set_intensity(1.5);
 /// ssao.set_sample_count(32);
+///
+/// // Trade quality for speed
+/// ssao.set_half_resolution(true);
+/// ssao.set_blur_passes(2);
 /// ```
 #[derive(Debug, Clone)]
 pub struct SsaoSettings {
     /// Whether SSAO is enabled.
     pub enabled: bool,
 
+    /// Whether the AO is computed at half resolution and bilaterally
+    /// upsampled (default). Full resolution is sharper but about four
+    /// times as expensive.
+    pub half_resolution: bool,
+
+    /// Number of cross-bilateral blur passes applied to the raw AO
+    /// (`0..=MAX_SSAO_BLUR_PASSES`, default 1).
+    pub blur_passes: u32,
+
     /// GPU uniform buffer containing sample kernel and parameters.
     /// Updated via setter methods — version tracking is automatic.
     #[doc(hidden)]
@@ -106,6 +124,8 @@ impl Default for SsaoSettings {
 
         Self {
             enabled: false,
+            half_resolution: true,
+            blur_passes: 1,
             uniforms: CpuBuffer::new(
                 uniforms,
                 wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
@@ -135,10 +155,12 @@ impl SsaoSettings {
         self.uniforms.write()
     }
 
-    /// Sets the sampling radius in view-space units (meters).
+    /// Sets the sampling radius in world units (meters).
     ///
-    /// Larger values detect occlusion from more distant geometry;
-    /// typical range is 0.1–2.0.
+    /// The hemisphere is sampled in view space, so its footprint on screen
+    /// shrinks with depth and the occlusion distance stays the same at any
+    /// distance from the camera. Larger values detect occlusion from more
+    /// distant geometry; typical range is 0.1–2.0.
     pub fn set_radius(&mut self, radius: f32) {
         self.uniforms.write().radius = radius.max(0.01);
     }
@@ -166,7 +188,8 @@ impl SsaoSettings {
 
     /// Sets the AO intensity (exponent applied to the final occlusion value).
     ///
-    /// Higher values produce stronger, darker shadows. Typical range is 1.0–3.0.
+    /// Higher values produce stronger, darker shadows. Typical range is 1.0–3.0;
+    /// 0 disables the occlusion while keeping the passes running.
     pub fn set_intensity(&mut self, intensity: f32) {
         self.uniforms.write().intensity = intensity.max(0.0);
     }
@@ -202,11 +225,36 @@ impl SsaoSettings {
         self.uniforms.read().sample_count
     }
 
-    /// Updates the noise tiling scale based on screen resolution.
+    /// Sets whether the AO is computed at half resolution.
+    pub fn set_half_resolution(&mut self, half_resolution: bool) {
+        self.half_resolution = half_resolution;
+    }
+
+    /// Sets the number of blur passes, clamped to `0..=MAX_SSAO_BLUR_PASSES`.
+    ///
+    /// Each pass is a 5×5 cross-bilateral filter; more passes give smoother
+    /// AO at the cost of fine contact detail.
+    pub fn set_blur_passes(&mut self, passes: u32) {
+        self.blur_passes = passes.min(MAX_SSAO_BLUR_PASSES);
+    }
+
+    /// Returns the size of the raw AO buffer for a `width`×`height` frame.
+    #[must_use]
+    pub fn buffer_size(&self, width: u32, height: u32) -> (u32, u32) {
+        if self.half_resolution {
+            ((width / 2).max(1), (height / 2).max(1))
+        } else {
+            (width.max(1), height.max(1))
+        }
+    }
+
+    /// Updates the noise tiling scale for a `width`×`height` frame, so that
+    /// the 4×4 noise tile repeats once per 4×4 block of AO texels.
     ///
-    /// Called by the render pass during `prepare()` when the screen
-    /// size changes. `noise_scale = (width / 4.0, height / 4.0)`.
+    /// Called by the renderer before the SSAO pass is prepared.
+    /// `noise_scale = buffer_size / 4.0`.
     pub fn update_noise_scale(&mut self, width: u32, height: u32) {
+        let (width, height) = self.buffer_size(width, height);
         let scale = glam::Vec2::new(width as f32 / 4.0, height as f32 / 4.0);
         let current = self.uniforms.read().noise_scale;
         if (current - scale).length_squared() > f32::EPSILON {
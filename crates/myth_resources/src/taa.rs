@@ -30,6 +30,13 @@ pub struct TaaSettings {
     /// filtering.  `0.0` disables sharpening; `1.0` is maximum.
     /// Typical range: `0.4..=0.8`.
     pub sharpen_intensity: f32,
+
+    /// Scale applied to the per-frame sub-pixel jitter (`0.0..=1.0`).
+    ///
+    /// `1.0` covers the full pixel footprint for the sharpest convergence.
+    /// Lower values reduce visible shimmer on high-frequency content at the
+    /// cost of weaker anti-aliasing; `0.0` disables jitter entirely.
+    pub jitter_scale: f32,
 }
 
 impl Default for TaaSettings {
@@ -37,6 +44,7 @@ impl Default for TaaSettings {
         Self {
             feedback_weight: 0.90,
             sharpen_intensity: 0.5,
+            jitter_scale: 1.0,
         }
     }
 }
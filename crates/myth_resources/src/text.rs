@@ -0,0 +1,579 @@
+//! Signed-distance-field text.
+//!
+//! A [`FontAtlas`] packs one distance-field bitmap per glyph into a single
+//! `R8Unorm` image together with the glyph metrics. A [`TextMesh`] lays out
+//! a string against an atlas and builds a geometry with one quad per glyph,
+//! which is drawn with a [`TextMaterial`](crate::TextMaterial) reading the
+//! atlas texture.
+//!
+//! Atlases are baked from TrueType / OpenType fonts with
+//! [`FontAtlas::from_ttf`], available with the `text` feature. Without it an
+//! atlas can still be assembled from a pre-baked bitmap with
+//! [`FontAtlas::new`] and [`FontAtlas::insert_glyph`].
+//!
+//! All metrics are in em units (the font size is 1), so a [`TextMesh`] of
+//! [`size`](TextMesh::size) `s` is `s` units per em.
+
+use glam::{Vec2, Vec3};
+use rustc_hash::FxHashMap;
+use wgpu::PrimitiveTopology;
+
+use crate::TextureHandle;
+use crate::geometry::{Attribute, Geometry, VertexFormat};
+use crate::image::{Image, ImageDimension, PixelFormat};
+
+/// Placement of one glyph in a [`FontAtlas`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    /// Top-left corner of the glyph's atlas rectangle, in UV space.
+    pub uv_min: Vec2,
+    /// Bottom-right corner of the glyph's atlas rectangle, in UV space.
+    pub uv_max: Vec2,
+    /// Quad size in em units, including the distance-field padding.
+    /// Zero for glyphs without ink, such as the space.
+    pub size: Vec2,
+    /// Bottom-left corner of the quad relative to the pen position on the
+    /// baseline, in em units (Y up).
+    pub offset: Vec2,
+    /// Horizontal pen advance in em units.
+    pub advance: f32,
+}
+
+/// Settings for [`FontAtlas::from_ttf`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FontAtlasSettings {
+    /// Size the glyphs are rasterized at, in pixels per em. Larger sizes
+    /// keep sharper corners when the text is magnified.
+    pub px_size: f32,
+    /// Distance-field range in pixels on each side of the glyph edge.
+    pub spread: f32,
+    /// Characters to bake.
+    pub characters: Vec<char>,
+}
+
+impl Default for FontAtlasSettings {
+    /// 48 px per em, a spread of 6 px and printable ASCII.
+    fn default() -> Self {
+        Self {
+            px_size: 48.0,
+            spread: 6.0,
+            characters: (' '..='~').collect(),
+        }
+    }
+}
+
+/// Distance-field glyph bitmaps packed into one image, plus the metrics
+/// needed to lay out text with them.
+///
+/// Texel values store distance to the glyph edge: 0.5 on the edge, rising
+/// to 1.0 [`spread`](Self::spread) em inside and falling to 0.0 as far
+/// outside.
+#[derive(Debug, Clone)]
+pub struct FontAtlas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+    glyphs: FxHashMap<char, Glyph>,
+    line_height: f32,
+    ascent: f32,
+    spread: f32,
+}
+
+impl FontAtlas {
+    /// Creates an atlas from a pre-baked `width`×`height` distance-field
+    /// bitmap (one byte per texel, rows top to bottom) without glyphs.
+    ///
+    /// `line_height` is the baseline-to-baseline distance, `ascent` the
+    /// height above the baseline and `spread` the distance-field range,
+    /// all in em units.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` does not hold `width * height` bytes.
+    #[must_use]
+    pub fn new(
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+        line_height: f32,
+        ascent: f32,
+        spread: f32,
+    ) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize,
+            "atlas bitmap must hold width * height bytes"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+            glyphs: FxHashMap::default(),
+            line_height,
+            ascent,
+            spread,
+        }
+    }
+
+    /// Bakes a distance-field atlas from TrueType / OpenType font data.
+    ///
+    /// Characters the font has no glyph for are skipped; text using them
+    /// falls back to `?` when laid out.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the font data cannot be parsed.
+    #[cfg(feature = "text")]
+    pub fn from_ttf(bytes: &[u8], settings: &FontAtlasSettings) -> myth_core::Result<Self> {
+        use myth_core::AssetError;
+
+        /// One rasterized glyph awaiting packing.
+        struct Raster {
+            c: char,
+            metrics: fontdue::Metrics,
+            width: usize,
+            height: usize,
+            sdf: Vec<u8>,
+        }
+
+        let font = fontdue::Font::from_bytes(bytes, fontdue::FontSettings::default())
+            .map_err(|e| AssetError::Format(format!("Invalid font: {e}")))?;
+        let px = settings.px_size.max(1.0);
+        let pad = settings.spread.max(1.0).ceil() as usize;
+        let (line_height, ascent) = font
+            .horizontal_line_metrics(px)
+            .map_or((1.2, 0.8), |m| (m.new_line_size / px, m.ascent / px));
+
+        // Rasterize and convert every glyph before packing.
+        let mut rasters = Vec::with_capacity(settings.characters.len());
+        for &c in &settings.characters {
+            if c != ' ' && font.lookup_glyph_index(c) == 0 {
+                continue;
+            }
+            let (metrics, coverage) = font.rasterize(c, px);
+            let mut raster = Raster {
+                c,
+                metrics,
+                width: 0,
+                height: 0,
+                sdf: Vec::new(),
+            };
+            if metrics.width > 0 && metrics.height > 0 {
+                raster.width = metrics.width + 2 * pad;
+                raster.height = metrics.height + 2 * pad;
+                let mut padded = vec![0u8; raster.width * raster.height];
+                for row in 0..metrics.height {
+                    let src = &coverage[row * metrics.width..(row + 1) * metrics.width];
+                    let start = (row + pad) * raster.width + pad;
+                    padded[start..start + metrics.width].copy_from_slice(src);
+                }
+                raster.sdf = sdf::from_coverage(
+                    &padded,
+                    raster.width,
+                    raster.height,
+                    settings.spread.max(1.0),
+                );
+            }
+            rasters.push(raster);
+        }
+
+        // Shelf packing, tallest glyphs first, 1 texel apart.
+        rasters.sort_by_key(|r| std::cmp::Reverse(r.height));
+        let area: usize = rasters.iter().map(|r| (r.width + 1) * (r.height + 1)).sum();
+        let widest = rasters.iter().map(|r| r.width + 1).max().unwrap_or(1);
+        let atlas_width = ((area as f32).sqrt().ceil() as usize)
+            .max(widest)
+            .next_power_of_two();
+        let mut placements = Vec::with_capacity(rasters.len());
+        let (mut cursor_x, mut cursor_y, mut shelf) = (0usize, 0usize, 0usize);
+        for raster in &rasters {
+            if cursor_x + raster.width > atlas_width {
+                cursor_x = 0;
+                cursor_y += shelf + 1;
+                shelf = 0;
+            }
+            placements.push((cursor_x, cursor_y));
+            cursor_x += raster.width + 1;
+            shelf = shelf.max(raster.height);
+        }
+        let atlas_height = (cursor_y + shelf).max(1).next_power_of_two();
+
+        let mut atlas = Self::new(
+            atlas_width as u32,
+            atlas_height as u32,
+            vec![0; atlas_width * atlas_height],
+            line_height,
+            ascent,
+            settings.spread.max(1.0) / px,
+        );
+        let texels = Vec2::new(atlas_width as f32, atlas_height as f32);
+        for (raster, (left, top)) in rasters.into_iter().zip(placements) {
+            for row in 0..raster.height {
+                let dst = (top + row) * atlas_width + left;
+                let src = row * raster.width;
+                atlas.pixels[dst..dst + raster.width]
+                    .copy_from_slice(&raster.sdf[src..src + raster.width]);
+            }
+            let corner = Vec2::new(left as f32, top as f32);
+            let extent = Vec2::new(raster.width as f32, raster.height as f32);
+            atlas.insert_glyph(
+                raster.c,
+                Glyph {
+                    uv_min: corner / texels,
+                    uv_max: (corner + extent) / texels,
+                    size: extent / px,
+                    offset: Vec2::new(
+                        raster.metrics.xmin as f32 - pad as f32,
+                        raster.metrics.ymin as f32 - pad as f32,
+                    ) / px,
+                    advance: raster.metrics.advance_width / px,
+                },
+            );
+        }
+        Ok(atlas)
+    }
+
+    /// Adds or replaces the glyph for `c`.
+    pub fn insert_glyph(&mut self, c: char, glyph: Glyph) {
+        self.glyphs.insert(c, glyph);
+    }
+
+    /// Returns the glyph for `c`, if the atlas has one.
+    #[inline]
+    #[must_use]
+    pub fn glyph(&self, c: char) -> Option<&Glyph> {
+        self.glyphs.get(&c)
+    }
+
+    /// Atlas width in texels.
+    #[inline]
+    #[must_use]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Atlas height in texels.
+    #[inline]
+    #[must_use]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Distance-field texels, one byte each, rows top to bottom.
+    #[inline]
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+
+    /// Baseline-to-baseline distance in em units.
+    #[inline]
+    #[must_use]
+    pub fn line_height(&self) -> f32 {
+        self.line_height
+    }
+
+    /// Height above the baseline in em units.
+    #[inline]
+    #[must_use]
+    pub fn ascent(&self) -> f32 {
+        self.ascent
+    }
+
+    /// Distance-field range in em units: texels this far inside the edge
+    /// read 1.0, this far outside 0.0.
+    #[inline]
+    #[must_use]
+    pub fn spread(&self) -> f32 {
+        self.spread
+    }
+
+    /// Copies the bitmap into an `R8Unorm` image for upload.
+    #[must_use]
+    pub fn to_image(&self) -> Image {
+        Image::new(
+            self.width,
+            self.height,
+            1,
+            ImageDimension::D2,
+            PixelFormat::R8Unorm,
+            Some(self.pixels.clone()),
+        )
+    }
+
+    /// Glyph used for `c`: its own, else `?`.
+    fn glyph_or_fallback(&self, c: char) -> Option<&Glyph> {
+        self.glyph(c).or_else(|| self.glyph('?'))
+    }
+}
+
+/// A [`FontAtlas`] registered with the asset server: the metrics for
+/// layout and the texture for drawing. Cheap to clone.
+#[derive(Debug, Clone)]
+pub struct Font {
+    /// Glyph metrics.
+    pub atlas: std::sync::Arc<FontAtlas>,
+    /// The atlas texture, sampled linearly.
+    pub texture: TextureHandle,
+}
+
+/// Horizontal alignment of the lines within a text block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    /// Lines start at the left edge of the block.
+    Left,
+    /// Lines are centered.
+    #[default]
+    Center,
+    /// Lines end at the right edge of the block.
+    Right,
+}
+
+/// A string laid out with a [`FontAtlas`] into one quad per glyph.
+///
+/// The text lies in the local XY plane facing +Z, lines running along +X
+/// and stacking down -Y. Kerning is not applied.
+///
+/// ```rust,ignore
+/// let geometry = TextMesh::new("Hello\nworld")
+///     .with_size(0.5)
+///     .with_align(TextAlign::Left)
+///     .with_anchor(Vec2::new(0.0, 1.0)) // top-left corner at the origin
+///     .build_geometry(&font.atlas);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextMesh {
+    /// The text; `\n` starts a new line.
+    pub text: String,
+    /// Units per em.
+    pub size: f32,
+    /// Line alignment within the block.
+    pub align: TextAlign,
+    /// Point of the block placed at the origin, as a fraction of its
+    /// extent: `(0, 0)` is the bottom-left corner, `(0.5, 0.5)` the center.
+    pub anchor: Vec2,
+    /// Multiplier on the font's line height.
+    pub line_spacing: f32,
+}
+
+impl TextMesh {
+    /// Lays out `text` at 1 unit per em, centered on the origin.
+    #[must_use]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            size: 1.0,
+            align: TextAlign::Center,
+            anchor: Vec2::splat(0.5),
+            line_spacing: 1.0,
+        }
+    }
+
+    /// Sets the size in units per em (builder).
+    #[must_use]
+    pub fn with_size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the line alignment (builder).
+    #[must_use]
+    pub fn with_align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Sets the anchor (builder).
+    #[must_use]
+    pub fn with_anchor(mut self, anchor: Vec2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Sets the line spacing multiplier (builder).
+    #[must_use]
+    pub fn with_line_spacing(mut self, spacing: f32) -> Self {
+        self.line_spacing = spacing;
+        self
+    }
+
+    /// Returns the width and height of the text block in local units.
+    ///
+    /// The width is the longest line's advance; the height is one line
+    /// height per line.
+    #[must_use]
+    pub fn measure(&self, atlas: &FontAtlas) -> Vec2 {
+        let width = self
+            .text
+            .split('\n')
+            .map(|line| Self::line_advance(line, atlas))
+            .fold(0.0, f32::max);
+        let lines = self.text.split('\n').count();
+        Vec2::new(
+            width,
+            lines as f32 * atlas.line_height() * self.line_spacing,
+        ) * self.size
+    }
+
+    /// Builds an indexed triangle-list geometry with `position` and `uv`
+    /// attributes, one quad per visible glyph.
+    ///
+    /// Characters missing from the atlas are drawn as `?`, or skipped when
+    /// the atlas has no `?` either. Text without visible glyphs yields a
+    /// single degenerate quad so the geometry is never empty.
+    #[must_use]
+    pub fn build_geometry(&self, atlas: &FontAtlas) -> Geometry {
+        let block = self.measure(atlas);
+        let origin = -self.anchor * block;
+        let line_step = atlas.line_height() * self.line_spacing * self.size;
+
+        let mut positions: Vec<Vec3> = Vec::new();
+        let mut uvs: Vec<Vec2> = Vec::new();
+        let mut indices: Vec<u32> = Vec::new();
+
+        for (row, line) in self.text.split('\n').enumerate() {
+            let width = Self::line_advance(line, atlas) * self.size;
+            let free = block.x - width;
+            let start = match self.align {
+                TextAlign::Left => 0.0,
+                TextAlign::Center => free * 0.5,
+                TextAlign::Right => free,
+            };
+            let baseline = block.y - atlas.ascent() * self.size - row as f32 * line_step;
+            let mut pen = origin + Vec2::new(start, baseline);
+
+            for c in line.chars() {
+                let Some(glyph) = atlas.glyph_or_fallback(c) else {
+                    continue;
+                };
+                if glyph.size.x > 0.0 && glyph.size.y > 0.0 {
+                    let min = pen + glyph.offset * self.size;
+                    let max = min + glyph.size * self.size;
+                    let base = positions.len() as u32;
+                    positions.extend([
+                        Vec3::new(min.x, min.y, 0.0),
+                        Vec3::new(max.x, min.y, 0.0),
+                        Vec3::new(max.x, max.y, 0.0),
+                        Vec3::new(min.x, max.y, 0.0),
+                    ]);
+                    uvs.extend([
+                        Vec2::new(glyph.uv_min.x, glyph.uv_max.y),
+                        glyph.uv_max,
+                        Vec2::new(glyph.uv_max.x, glyph.uv_min.y),
+                        glyph.uv_min,
+                    ]);
+                    indices.extend([base, base + 1, base + 2, base, base + 2, base + 3]);
+                }
+                pen.x += glyph.advance * self.size;
+            }
+        }
+
+        if positions.is_empty() {
+            positions.extend([Vec3::ZERO; 4]);
+            uvs.extend([Vec2::ZERO; 4]);
+            indices.extend([0, 1, 2, 0, 2, 3]);
+        }
+
+        let mut geometry = Geometry::new();
+        geometry.topology = PrimitiveTopology::TriangleList;
+        geometry.set_attribute(
+            "position",
+            Attribute::new_planar(&positions, VertexFormat::Float32x3),
+        );
+        geometry.set_attribute("uv", Attribute::new_planar(&uvs, VertexFormat::Float32x2));
+        if u16::try_from(positions.len()).is_ok() {
+            let indices: Vec<u16> = indices.iter().map(|&i| i as u16).collect();
+            geometry.set_indices(&indices);
+        } else {
+            geometry.set_indices_u32(&indices);
+        }
+        geometry
+    }
+
+    fn line_advance(line: &str, atlas: &FontAtlas) -> f32 {
+        line.chars()
+            .filter_map(|c| atlas.glyph_or_fallback(c))
+            .map(|glyph| glyph.advance)
+            .sum()
+    }
+}
+
+/// Signed distance fields from glyph coverage bitmaps.
+#[cfg(feature = "text")]
+mod sdf {
+    /// Converts 8-bit coverage to distance-field bytes: 128 on the edge,
+    /// 255 `spread` texels inside and 0 as far outside.
+    pub(super) fn from_coverage(
+        coverage: &[u8],
+        width: usize,
+        height: usize,
+        spread: f32,
+    ) -> Vec<u8> {
+        let inside: Vec<bool> = coverage.iter().map(|&c| c >= 128).collect();
+        let to_inside = distance_to(&inside, width, height, true);
+        let to_outside = distance_to(&inside, width, height, false);
+        inside
+            .iter()
+            .enumerate()
+            .map(|(i, &is_inside)| {
+                // Texel centers sit half a texel from the edge between them.
+                let signed = if is_inside {
+                    to_outside[i] - 0.5
+                } else {
+                    0.5 - to_inside[i]
+                };
+                ((0.5 + signed / (2.0 * spread)).clamp(0.0, 1.0) * 255.0).round() as u8
+            })
+            .collect()
+    }
+
+    /// Euclidean distance from every texel to the nearest texel whose mask
+    /// equals `target` (8SSEDT: two sweeps propagating nearest offsets).
+    fn distance_to(mask: &[bool], width: usize, height: usize, target: bool) -> Vec<f32> {
+        const FAR: (i32, i32) = (1 << 14, 1 << 14);
+        let mut grid: Vec<(i32, i32)> = mask
+            .iter()
+            .map(|&m| if m == target { (0, 0) } else { FAR })
+            .collect();
+
+        let (width, height) = (width as i32, height as i32);
+        let len_sq = |(x, y): (i32, i32)| x * x + y * y;
+        let compare = |grid: &mut [(i32, i32)], x: i32, y: i32, dx: i32, dy: i32| {
+            let (nx, ny) = (x + dx, y + dy);
+            if nx < 0 || ny < 0 || nx >= width || ny >= height {
+                return;
+            }
+            let other = grid[(ny * width + nx) as usize];
+            let candidate = (other.0 + dx, other.1 + dy);
+            let here = &mut grid[(y * width + x) as usize];
+            if len_sq(candidate) < len_sq(*here) {
+                *here = candidate;
+            }
+        };
+
+        for y in 0..height {
+            for x in 0..width {
+                compare(&mut grid, x, y, -1, 0);
+                compare(&mut grid, x, y, 0, -1);
+                compare(&mut grid, x, y, -1, -1);
+                compare(&mut grid, x, y, 1, -1);
+            }
+            for x in (0..width).rev() {
+                compare(&mut grid, x, y, 1, 0);
+            }
+        }
+        for y in (0..height).rev() {
+            for x in (0..width).rev() {
+                compare(&mut grid, x, y, 1, 0);
+                compare(&mut grid, x, y, 0, 1);
+                compare(&mut grid, x, y, -1, 1);
+                compare(&mut grid, x, y, 1, 1);
+            }
+            for x in 0..width {
+                compare(&mut grid, x, y, -1, 0);
+            }
+        }
+
+        grid.iter().map(|&o| (len_sq(o) as f32).sqrt()).collect()
+    }
+}
@@ -157,6 +157,13 @@ pub struct Texture {
     pub generate_mipmaps: bool,
     /// Colour-space intent — determines the sRGB / Linear GPU format variant.
     pub color_space: ColorSpace,
+    /// Upload the image progressively across frames instead of all at once.
+    ///
+    /// Rows are uploaded within the renderer's per-frame texture upload
+    /// budget.  Until the image (and its mip chain) is fully resident,
+    /// materials keep sampling the previous image or a fallback.  Intended
+    /// for large, static images; each data change restarts the upload.
+    pub streaming: bool,
 }
 
 impl Texture {
@@ -190,6 +197,7 @@ impl Texture {
             sampler: TextureSampler::default(),
             generate_mipmaps: false,
             color_space: ColorSpace::Srgb,
+            streaming: false,
         }
     }
 
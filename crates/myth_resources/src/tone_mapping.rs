@@ -8,6 +8,8 @@
 //! also supports:
 //! - **Vignette**: Edge darkening effect controlled by intensity and smoothness
 //! - **Color Grading (LUT)**: 3D lookup table for color manipulation
+//! - **Auto Exposure**: eye adaptation driven by a luminance histogram of the
+//!   HDR scene color
 
 use glam::Vec3;
 
@@ -17,6 +19,82 @@ use crate::ShaderDefines;
 use crate::TextureHandle;
 use crate::buffer::{BufferGuard, BufferReadGuard, CpuBuffer};
 
+/// Default [`ToneMappingSettings::ev100`]: f/16, 1/125 s, ISO 100.
+pub const DEFAULT_EV100: f32 = 15.0;
+
+/// Eye adaptation settings (see [`ToneMappingSettings::set_auto_exposure`]).
+///
+/// Exposure values here are scene EVs: `log2` of the average scene
+/// luminance, so EV 0 is an average luminance of 1.0. The renderer measures
+/// the geometric mean luminance of the HDR scene color each frame, eases the
+/// adapted EV toward it and scales the image by
+/// [`exposure_for_ev`](Self::exposure_for_ev) before tone mapping. The
+/// methods below mirror that shader math on the CPU.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoExposure {
+    /// Whether the exposure follows the scene (default: `false`).
+    pub enabled: bool,
+    /// Darkest scene EV the exposure adapts to (default: -8). Darker scenes
+    /// stay underexposed instead of having their noise boosted.
+    pub min_ev: f32,
+    /// Brightest scene EV the exposure adapts to (default: 12).
+    pub max_ev: f32,
+    /// Adaptation rate, per second, when the scene gets brighter
+    /// (default: 3.0).
+    pub speed_up: f32,
+    /// Adaptation rate, per second, when the scene gets darker
+    /// (default: 1.0).
+    pub speed_down: f32,
+}
+
+impl Default for AutoExposure {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_ev: -8.0,
+            max_ev: 12.0,
+            speed_up: 3.0,
+            speed_down: 1.0,
+        }
+    }
+}
+
+impl AutoExposure {
+    /// Luminance an adapted image maps its average to.
+    pub const MIDDLE_GREY: f32 = 0.18;
+
+    /// Returns the clamped EV the exposure adapts toward for a scene whose
+    /// geometric mean luminance is `average_luminance`.
+    #[must_use]
+    pub fn target_ev(&self, average_luminance: f32) -> f32 {
+        average_luminance
+            .max(1e-6)
+            .log2()
+            .clamp(self.min_ev, self.max_ev.max(self.min_ev))
+    }
+
+    /// Advances the adapted EV by `delta_time` seconds toward `target_ev`.
+    ///
+    /// The approach is exponential and frame-rate independent, so the
+    /// exposure settles without overshooting or oscillating.
+    #[must_use]
+    pub fn adapt(&self, current_ev: f32, target_ev: f32, delta_time: f32) -> f32 {
+        let speed = if target_ev > current_ev {
+            self.speed_up
+        } else {
+            self.speed_down
+        };
+        let t = 1.0 - (-delta_time.max(0.0) * speed.max(0.0)).exp();
+        current_ev + (target_ev - current_ev) * t
+    }
+
+    /// Exposure multiplier that maps a scene at `ev` to middle grey.
+    #[must_use]
+    pub fn exposure_for_ev(ev: f32) -> f32 {
+        Self::MIDDLE_GREY / ev.exp2()
+    }
+}
+
 /// Tone mapping algorithm selection.
 ///
 /// Different algorithms provide different looks and performance characteristics:
@@ -129,6 +207,14 @@ pub struct ToneMappingUniforms {
     pub vignette_smoothness: f32,
     pub vignette_color: Vec3,
     pub vignette_intensity: f32,
+    /// Scale of the unvignetted area; 1.0 reaches the screen edges.
+    #[default(1.0)]
+    pub vignette_radius: f32,
+
+    /// Adapted exposure, written on the GPU by the auto-exposure pass.
+    /// Stays 1.0 while auto exposure is off.
+    #[default(1.0)]
+    pub auto_exposure: f32,
 }
 
 /// Tone mapping configuration.
@@ -176,6 +262,16 @@ pub struct ToneMappingSettings {
     /// Optional 3D LUT texture handle. When `Some`, the `USE_LUT` shader macro is enabled
     /// and the pipeline is recompiled. When `None`, no LUT is applied.
     pub lut_texture: Option<TextureHandle>,
+
+    /// Camera exposure value at ISO 100 (default: 15, a sunny day).
+    ///
+    /// Scales lights in physical units (lux / candela) by
+    /// `1 / (1.2 * 2^ev100)` before tone mapping; legacy lights and the
+    /// environment are unaffected. Lower values brighten the image.
+    pub ev100: f32,
+
+    /// Eye adaptation (see [`set_auto_exposure`](Self::set_auto_exposure)).
+    pub auto_exposure: AutoExposure,
     // /// LUT contribution weight: 0.0 = original color, 1.0 = fully LUT-graded (default: 1.0)
     // // pub lut_contribution: f32,
 
@@ -193,6 +289,8 @@ impl Default for ToneMappingSettings {
                 Some("ToneMappingUniforms"),
             ),
             lut_texture: None,
+            ev100: DEFAULT_EV100,
+            auto_exposure: AutoExposure::default(),
         }
     }
 }
@@ -221,11 +319,64 @@ impl ToneMappingSettings {
         }
     }
 
+    /// Sets the exposure value for physical-unit lights.
+    pub fn set_ev100(&mut self, ev100: f32) {
+        self.ev100 = ev100;
+    }
+
+    /// Sets [`ev100`](Self::ev100) from camera settings: f-number,
+    /// shutter time in seconds and ISO sensitivity.
+    pub fn set_camera_exposure(&mut self, aperture: f32, shutter_time: f32, iso: f32) {
+        self.ev100 = (aperture * aperture / shutter_time * 100.0 / iso).log2();
+    }
+
+    /// Scale applied to physical-unit light intensities, derived from
+    /// [`ev100`](Self::ev100) (saturation-based sensitivity).
+    #[must_use]
+    pub fn physical_light_scale(&self) -> f32 {
+        1.0 / (1.2 * 2f32.powf(self.ev100))
+    }
+
     /// Sets the exposure value.
     pub fn set_exposure(&mut self, exposure: f32) {
         self.uniforms.write().exposure = exposure;
     }
 
+    /// Enables or disables auto exposure (eye adaptation).
+    ///
+    /// While enabled, the renderer measures the HDR scene luminance every
+    /// frame and eases the exposure toward it, so bright and dark
+    /// environments both land near middle grey. The manual
+    /// [`exposure`](Self::set_exposure) still applies on top, as exposure
+    /// compensation. Only the `HighFidelity` path (which tone maps) adapts.
+    pub fn set_auto_exposure(&mut self, enabled: bool) {
+        self.auto_exposure.enabled = enabled;
+        if !enabled {
+            // The GPU overwrote this field while adapting; re-upload 1.0.
+            self.uniforms.write().auto_exposure = 1.0;
+        }
+    }
+
+    /// Clamps the scene EVs auto exposure adapts to (see [`AutoExposure`]).
+    pub fn set_auto_exposure_range(&mut self, min_ev: f32, max_ev: f32) {
+        self.auto_exposure.min_ev = min_ev.min(max_ev);
+        self.auto_exposure.max_ev = max_ev.max(min_ev);
+    }
+
+    /// Sets how fast auto exposure adapts, per second, to brighter and to
+    /// darker scenes.
+    pub fn set_auto_exposure_speed(&mut self, speed_up: f32, speed_down: f32) {
+        self.auto_exposure.speed_up = speed_up.max(0.0);
+        self.auto_exposure.speed_down = speed_down.max(0.0);
+    }
+
+    /// Returns whether auto exposure is enabled.
+    #[inline]
+    #[must_use]
+    pub fn auto_exposure_enabled(&self) -> bool {
+        self.auto_exposure.enabled
+    }
+
     /// Sets the final display gamma compensation.
     pub fn set_gamma(&mut self, gamma: f32) {
         self.uniforms.write().gamma = gamma;
@@ -272,6 +423,15 @@ impl ToneMappingSettings {
         self.uniforms.write().vignette_color = color;
     }
 
+    /// Sets the vignette radius.
+    ///
+    /// Scales the falloff around the screen centre: values below 1.0 pull
+    /// the darkening inwards, values above 1.0 push it past the screen
+    /// edges. Default is 1.0.
+    pub fn set_vignette_radius(&mut self, radius: f32) {
+        self.uniforms.write().vignette_radius = radius.max(1e-3);
+    }
+
     /// Sets the 3D LUT texture for color grading.
     ///
     /// Pass `Some(handle)` to enable LUT-based color grading, or `None` to disable it.
@@ -366,6 +526,13 @@ impl ToneMappingSettings {
         self.uniforms.read().vignette_color
     }
 
+    /// Returns the current vignette radius.
+    #[inline]
+    #[must_use]
+    pub fn vignette_radius(&self) -> f32 {
+        self.uniforms.read().vignette_radius
+    }
+
     /// Returns the current LUT contribution.
     #[inline]
     #[must_use]
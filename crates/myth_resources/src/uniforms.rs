@@ -264,14 +264,62 @@ pub use crate::material::UnlitUniforms;
 /// Per-object dynamic uniforms uploaded to the GPU each draw call.
 ///
 /// Uses `dynamic_offset = true` to enforce 256-byte alignment, as required
-/// by wgpu's dynamic uniform buffer binding.
+/// by wgpu's dynamic uniform buffer binding. The fields fit in one 256-byte
+/// slot; shaders that need the inverse transform derive it themselves.
 #[gpu_struct(dynamic_offset = true, crate_path = "crate")]
 pub struct DynamicModelUniforms {
     pub world_matrix: Mat4,
-    pub world_matrix_inverse: Mat4,
     pub normal_matrix: Mat3Uniform,
     pub previous_world_matrix: Mat4,
-    pub instance_tint: Vec4,
+    /// Per-object base color multiplier (`USE_OBJECT_OVERRIDE`).
+    #[default(Vec4::ONE)]
+    pub object_tint: Vec4,
+    /// Per-object user scalars (`USE_OBJECT_OVERRIDE`).
+    pub object_params: Vec4,
+}
+
+impl DynamicModelUniforms {
+    /// Builds the per-object uniforms from the current and previous world
+    /// transforms and the node's optional material overrides.
+    #[must_use]
+    pub fn new(
+        world_matrix: Mat4,
+        previous_world_matrix: Mat4,
+        overrides: Option<&ObjectOverride>,
+    ) -> Self {
+        let overrides = overrides.copied().unwrap_or_default();
+        Self {
+            world_matrix,
+            normal_matrix: Mat3Uniform::from_mat4(world_matrix.inverse().transpose()),
+            previous_world_matrix,
+            object_tint: overrides.tint,
+            object_params: Vec4::from_array(overrides.params),
+            ..Default::default()
+        }
+    }
+}
+
+/// Per-node material property overrides.
+///
+/// Uploaded in [`DynamicModelUniforms`] alongside the model transform, so
+/// nodes sharing one material can still vary without duplicating it.
+/// Built-in shaders multiply `tint` into the base color; `params` are free
+/// for custom shaders (`u_model.object_params`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ObjectOverride {
+    /// Multiplied into the material's base color (RGBA).
+    pub tint: Vec4,
+    /// Four user scalars.
+    pub params: [f32; 4],
+}
+
+impl Default for ObjectOverride {
+    fn default() -> Self {
+        Self {
+            tint: Vec4::ONE,
+            params: [0.0; 4],
+        }
+    }
 }
 
 /// Global render state uniforms updated once per frame.
@@ -328,8 +376,65 @@ pub struct EnvironmentUniforms {
 
     #[default(1.0)]
     pub env_map_intensity: f32,
-    pub env_map_rotation: f32,
     pub env_map_max_mip_level: f32,
+
+    /// Exposure applied to physical-unit lights, `1 / (1.2 * 2^EV100)`.
+    #[default(1.0)]
+    pub physical_light_scale: f32,
+
+    /// Rotates world-space lookup directions into the environment map
+    /// (inverse of the environment orientation).
+    #[default(Mat3Uniform::IDENTITY)]
+    pub env_map_rotation: Mat3Uniform,
+
+    /// Prefiltered mip the skybox samples, as a fraction of
+    /// `env_map_max_mip_level`. 0 unless the background shows the environment.
+    pub background_blur: f32,
+
+    /// Fog color, or the gradient's top color when `fog_color_source` is 1.
+    pub fog_color: Vec3,
+    /// 0 = linear, 1 = exponential, 2 = squared exponential.
+    pub fog_mode: u32,
+    /// Gradient bottom color when `fog_color_source` is 1.
+    pub fog_ground_color: Vec3,
+    /// 0 = `fog_color`, 1 = background gradient, 2 = environment map.
+    pub fog_color_source: u32,
+    pub fog_near: f32,
+    pub fog_far: f32,
+    pub fog_density: f32,
+
+    /// World position of the bound reflection probe.
+    pub probe_position: Vec3,
+    /// Radius of influence of the bound probe, 0 when none is bound.
+    pub probe_radius: f32,
+    /// Width of the fade from the probe into the environment map.
+    pub probe_blend_distance: f32,
+    pub probe_intensity: f32,
+    pub probe_max_mip_level: f32,
+
+    /// Minimum corner of the baked light probe grid.
+    pub light_probe_min: Vec3,
+    pub light_probe_intensity: f32,
+    /// Maximum corner of the baked light probe grid.
+    pub light_probe_max: Vec3,
+    /// Width of the fade outside the grid bounds.
+    pub light_probe_blend_distance: f32,
+    /// Probes along each axis in `xyz`; `w` is 0 when no grid is bound.
+    pub light_probe_grid: UVec4,
+
+    /// Light clusters along X, Y and depth in `xyz`; `w` is the number of
+    /// 32-bit mask words per cluster, 0 when lights are not clustered.
+    pub light_cluster_grid: UVec4,
+    /// Near and far view depth of the cluster slices, and the slice count
+    /// over `ln(far / near)`.
+    pub light_cluster_depth: Vec4,
+}
+
+/// Irradiance of one baked light probe as order-2 (9-coefficient)
+/// spherical harmonics of the incoming radiance, RGB in `xyz`.
+#[gpu_struct(crate_path = "crate")]
+pub struct GpuLightProbe {
+    pub sh: UniformArray<Vec4, 9>,
 }
 
 /// Per-light GPU data including shadow cascade parameters.
@@ -357,6 +462,9 @@ pub struct GpuLightStorage {
     /// Base cube index into the cube array shadow map (−1 if no point shadow).
     pub point_shadow_index: i32,
 
+    /// Intensity units: 0 = legacy, 1 = physical (lux / candela).
+    pub units: u32,
+
     /// Cascade split distances (view-space depth thresholds).
     pub cascade_splits: Vec4,
 
@@ -24,6 +24,9 @@ use glam::{Vec3, Vec4};
 use myth_resources::buffer::CpuBuffer;
 use myth_resources::gpu_struct;
 use myth_resources::texture::TextureSource;
+use myth_resources::uniforms::Mat3Uniform;
+
+use crate::environment::Environment;
 
 // ============================================================================
 // GPU Uniform Struct
@@ -38,6 +41,10 @@ use myth_resources::texture::TextureSource;
 pub struct SkyboxParamsUniforms {
     pub color_top: Vec4,
     pub color_bottom: Vec4,
+    /// Environment sampling rotation, applied before `rotation` when the
+    /// background displays the environment map (identity otherwise).
+    #[default(Mat3Uniform::IDENTITY)]
+    pub env_rotation: Mat3Uniform,
     #[default(0.0)]
     pub rotation: f32,
     #[default(1.0)]
@@ -126,6 +133,20 @@ pub struct ProceduralSkyParams {
     /// Earth default: `6_460_000.0`.
     pub atmosphere_radius: f32,
 
+    /// Linear (diffuse) albedo of the planet surface.
+    ///
+    /// Sunlight bounced off the ground feeds the multiple-scattering term and
+    /// lights the ground visible below the horizon. `Vec3::ZERO` (default)
+    /// disables the bounce; Earth's average is roughly `0.3`.
+    pub ground_albedo: Vec3,
+
+    /// Aerosol turbidity multiplier applied to the Mie scattering and
+    /// absorption coefficients.
+    ///
+    /// `1.0` (default) keeps the configured coefficients, larger values give
+    /// a hazier sky with a wider, brighter sun halo.
+    pub turbidity: f32,
+
     /// Optional user-provided orthographic moon albedo texture.
     ///
     /// The texture should represent the visible lunar face in sRGB without
@@ -177,6 +198,8 @@ impl ProceduralSkyParams {
             ozone_absorption: Vec3::new(0.65e-6, 1.881e-6, 0.085e-6),
             planet_radius: 6_360_000.0,
             atmosphere_radius: 6_460_000.0,
+            ground_albedo: Vec3::ZERO,
+            turbidity: 1.0,
             moon_albedo_texture: None,
             starbox_texture: None,
             star_intensity: 1.0,
@@ -319,6 +342,24 @@ impl ProceduralSkyParams {
         self.version = self.version.wrapping_add(1);
     }
 
+    /// Sets the ground albedo and increments the version.
+    pub fn set_ground_albedo(&mut self, albedo: Vec3) {
+        let albedo = albedo.clamp(Vec3::ZERO, Vec3::ONE);
+        if self.ground_albedo != albedo {
+            self.ground_albedo = albedo;
+            self.version = self.version.wrapping_add(1);
+        }
+    }
+
+    /// Sets the aerosol turbidity multiplier and increments the version.
+    pub fn set_turbidity(&mut self, turbidity: f32) {
+        let turbidity = turbidity.max(0.0);
+        if (self.turbidity - turbidity).abs() > f32::EPSILON {
+            self.turbidity = turbidity;
+            self.version = self.version.wrapping_add(1);
+        }
+    }
+
     /// Sets the Mie phase function anisotropy and increments the version.
     pub fn set_mie_anisotropy(&mut self, g: f32) {
         if (self.mie_anisotropy - g).abs() > f32::EPSILON {
@@ -533,6 +574,43 @@ impl BackgroundMode {
     pub fn needs_skybox_pass(&self) -> bool {
         !matches!(self, Self::Color(_))
     }
+
+    /// Returns the average linear RGB color of a solid or gradient background,
+    /// or `None` for texture and procedural modes.
+    ///
+    /// A gradient averages its top and bottom colors: the skybox blend is
+    /// symmetric around the horizon, so this is its mean over the sphere.
+    #[must_use]
+    pub fn average_color(&self) -> Option<Vec3> {
+        match self {
+            Self::Color(c) => Some(c.truncate()),
+            Self::Gradient { top, bottom } => Some((top.truncate() + bottom.truncate()) * 0.5),
+            Self::Texture { .. } | Self::Procedural(_) => None,
+        }
+    }
+
+    /// Whether this is a cube or equirectangular texture background showing
+    /// the same texture as `environment`'s map.
+    #[must_use]
+    pub fn shows_environment_map(&self, environment: &Environment) -> bool {
+        match self {
+            Self::Texture {
+                source, mapping, ..
+            } => {
+                *mapping != BackgroundMapping::Planar
+                    && environment.source_env_map() == Some(source)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the background displays the environment itself: a procedural
+    /// sky (which is baked into the environment) or a texture background
+    /// that [shows the environment map](Self::shows_environment_map).
+    #[must_use]
+    pub fn shows_environment(&self, environment: &Environment) -> bool {
+        matches!(self, Self::Procedural(_)) || self.shows_environment_map(environment)
+    }
 }
 
 // ============================================================================
@@ -621,10 +699,11 @@ impl BackgroundSettings {
         self.uniforms.write().intensity = intensity;
     }
 
-    /// Sets gradient colors (top and bottom).
+    /// Sets a vertical gradient from `top` (straight up) to `bottom`
+    /// (straight down), rendered by the skybox pass.
     ///
     /// Switches the mode to `Gradient` if it isn't already.
-    pub fn set_gradient_colors(&mut self, top: Vec4, bottom: Vec4) {
+    pub fn set_gradient(&mut self, top: Vec4, bottom: Vec4) {
         self.mode = BackgroundMode::Gradient { top, bottom };
         let mut p = self.uniforms.write();
         p.color_top = top;
@@ -633,6 +712,19 @@ impl BackgroundSettings {
         p.intensity = 1.0;
     }
 
+    /// Sets gradient colors (top and bottom).
+    #[deprecated(note = "Use `set_gradient`")]
+    pub fn set_gradient_colors(&mut self, top: Vec4, bottom: Vec4) {
+        self.set_gradient(top, bottom);
+    }
+
+    /// Sets a solid clear color.
+    ///
+    /// Switches the mode to `Color` if it isn't already.
+    pub fn set_color(&mut self, color: Vec4) {
+        self.set_mode(BackgroundMode::Color(color));
+    }
+
     // === Delegate methods from BackgroundMode ===
 
     /// Returns the clear color for the RenderPass.
@@ -675,6 +767,49 @@ impl BackgroundSettings {
 
     // === Internal ===
 
+    /// Applies the environment rotation and intensity on top of the
+    /// background's own values when the background displays the environment
+    /// map (same texture source, cube or equirectangular mapping).
+    ///
+    /// Called by `Scene::update` so the skybox and the IBL lookups always use
+    /// the same orientation and brightness. The per-background yaw and
+    /// intensity then act as relative adjustments.
+    pub(crate) fn sync_environment_link(&mut self, environment: &Environment) {
+        let BackgroundMode::Texture {
+            rotation,
+            intensity,
+            ..
+        } = &self.mode
+        else {
+            return;
+        };
+
+        let linked = self.mode.shows_environment_map(environment);
+
+        let (env_rotation, intensity) = if linked {
+            (
+                Mat3Uniform::from(environment.sampling_matrix()),
+                *intensity * environment.intensity(),
+            )
+        } else {
+            (Mat3Uniform::IDENTITY, *intensity)
+        };
+
+        let needs_update = {
+            let p = self.uniforms.read();
+            (p.rotation - *rotation).abs() > f32::EPSILON
+                || (p.intensity - intensity).abs() > f32::EPSILON
+                || p.env_rotation != env_rotation
+        };
+
+        if needs_update {
+            let mut p = self.uniforms.write();
+            p.rotation = *rotation;
+            p.intensity = intensity;
+            p.env_rotation = env_rotation;
+        }
+    }
+
     /// Derives uniform values from the current `BackgroundMode` and writes
     /// them into the `CpuBuffer`.
     fn sync_uniforms_from_mode(&mut self) {
@@ -0,0 +1,44 @@
+//! Camera-facing billboards.
+//!
+//! A node with a [`BillboardMode`] is turned toward the active camera every
+//! frame by [`Scene::update_billboards`](crate::Scene::update_billboards),
+//! right after the hierarchy update. Only the node's world rotation is
+//! replaced: its position and scale still follow the parent, and its local
+//! rotation is applied on top of the facing rotation, so a sprite can still
+//! be rolled around its view axis.
+
+use glam::{Mat3, Quat, Vec3};
+
+/// How a billboard node is oriented toward the camera.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BillboardMode {
+    /// The node's +Z axis points at the camera (sprites, particles).
+    #[default]
+    Full,
+    /// The node only turns around the world Y axis so it stays upright
+    /// (trees, health bars, impostors).
+    Cylindrical,
+}
+
+impl BillboardMode {
+    /// Returns the world rotation that turns a node at `position` toward
+    /// `camera_position`, or `None` when the direction is degenerate.
+    #[must_use]
+    pub fn facing_rotation(self, position: Vec3, camera_position: Vec3) -> Option<Quat> {
+        let mut to_camera = camera_position - position;
+        if self == Self::Cylindrical {
+            to_camera.y = 0.0;
+        }
+        let forward = to_camera.try_normalize()?;
+
+        // Seen from straight above or below, fall back to +Z as the up axis.
+        let up = if forward.y.abs() > 0.999 {
+            Vec3::Z
+        } else {
+            Vec3::Y
+        };
+        let right = up.cross(forward).normalize();
+        let up = forward.cross(right);
+        Some(Quat::from_mat3(&Mat3::from_cols(right, up, forward)))
+    }
+}
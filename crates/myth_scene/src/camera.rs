@@ -2,6 +2,7 @@ use glam::{Affine3A, Mat4, Vec2, Vec3, Vec3A, Vec4};
 use std::borrow::Cow;
 use uuid::Uuid;
 
+use crate::node::ALL_LAYERS;
 use myth_resources::AntiAliasingMode;
 use myth_resources::BoundingBox;
 
@@ -116,6 +117,17 @@ pub fn halton(index: u32, base: u32) -> f32 {
     r
 }
 
+/// Lower bound for the near plane chosen by [`Camera::auto_clip`].
+pub const AUTO_CLIP_MIN_NEAR: f32 = 0.01;
+
+/// Smallest `near / far` ratio allowed by [`Camera::auto_clip`], so a camera
+/// inside a huge scene does not collapse its near plane to the minimum.
+const AUTO_CLIP_MIN_DEPTH_RATIO: f32 = 1e-4;
+
+/// Relative slack added around the fitted depth range so geometry lying
+/// exactly on the bounds is not clipped.
+const AUTO_CLIP_PADDING: f32 = 0.05;
+
 /// Pure stack-based render camera snapshot (POD).
 ///
 /// Extracted from [`Camera`] once per frame and consumed by the renderer.
@@ -143,6 +155,9 @@ pub struct RenderCamera {
     /// Anti-aliasing mode (MSAA sample count, TAA feedback weight, etc.).
     pub aa_mode: AntiAliasingMode,
 
+    /// Layers this camera renders (see [`Node::layers`](crate::Node::layers)).
+    pub layer_mask: u32,
+
     /// Per-camera debug view settings (compile-time gated).
     #[cfg(feature = "debug_view")]
     pub debug_view: DebugViewSettings,
@@ -165,6 +180,11 @@ pub struct Camera {
     /// Per-camera anti-aliasing mode carrying its own payload.
     pub aa_mode: AntiAliasingMode,
 
+    /// Layers this camera renders. A mesh is drawn only if its node's
+    /// [`layers`](crate::Node::layers) share at least one bit with this mask.
+    /// Defaults to [`ALL_LAYERS`].
+    pub layer_mask: u32,
+
     // === Debug View (compile-time gated) ===
     #[cfg(feature = "debug_view")]
     pub debug_view: DebugViewSettings,
@@ -303,6 +323,7 @@ impl Camera {
             ortho_size: 10.0,
 
             aa_mode: AntiAliasingMode::default(),
+            layer_mask: ALL_LAYERS,
             #[cfg(feature = "debug_view")]
             debug_view: DebugViewSettings::default(),
             frame_index: 0,
@@ -334,6 +355,7 @@ impl Camera {
             ortho_size,
 
             aa_mode: AntiAliasingMode::default(),
+            layer_mask: ALL_LAYERS,
             #[cfg(feature = "debug_view")]
             debug_view: DebugViewSettings::default(),
             frame_index: 0,
@@ -421,9 +443,10 @@ impl Camera {
         };
 
         // 2. Apply sub-pixel jitter when TAA is active.
-        if self.aa_mode.is_taa() {
-            let jitter_x = halton(self.frame_index + 1, 2) - 0.5;
-            let jitter_y = halton(self.frame_index + 1, 3) - 0.5;
+        if let Some(taa) = self.aa_mode.taa_settings() {
+            let jitter_scale = taa.jitter_scale.clamp(0.0, 1.0);
+            let jitter_x = (halton(self.frame_index + 1, 2) - 0.5) * jitter_scale;
+            let jitter_y = (halton(self.frame_index + 1, 3) - 0.5) * jitter_scale;
 
             self.jitter = Vec2::new(
                 jitter_x * 2.0 / self.viewport_size.x,
@@ -480,11 +503,64 @@ impl Camera {
             near: self.near,
             far: self.far,
             aa_mode: self.aa_mode,
+            layer_mask: self.layer_mask,
             #[cfg(feature = "debug_view")]
             debug_view: self.debug_view,
         }
     }
 
+    /// Fits the near/far clip planes tightly around `scene_bounds`.
+    ///
+    /// The box corners are projected onto the current view direction (using
+    /// the view matrix from the last transform update) and the resulting
+    /// depth range, padded by a small margin, becomes the new clip range.
+    /// The near plane is clamped to [`AUTO_CLIP_MIN_NEAR`] and to a minimum
+    /// fraction of the far plane, which prevents both z-fighting on large
+    /// scenes and clipping on tiny ones. The projection matrix is rebuilt.
+    ///
+    /// Call it once per frame (after the camera has moved) for a continuous
+    /// fit, or after loading a model for a one-off adjustment.
+    ///
+    /// # Shadow cascades
+    ///
+    /// Directional shadow cascades are split between `near.max(0.1)` and
+    /// `min(max_shadow_distance, far)`. A tighter far plane therefore also
+    /// concentrates the cascades on the visible scene, but because the split
+    /// distances follow the clip planes, fitting to bounds that change every
+    /// frame (e.g. animated objects) can make the cascade boundaries shimmer.
+    /// Prefer stable bounds such as the static scene extent.
+    ///
+    /// Returns `false` (leaving the camera untouched) when the bounds are not
+    /// finite or lie entirely behind the camera.
+    pub fn auto_clip(&mut self, scene_bounds: &BoundingBox) -> bool {
+        if !scene_bounds.is_finite() {
+            return false;
+        }
+
+        let mut min_depth = f32::INFINITY;
+        let mut max_depth = f32::NEG_INFINITY;
+        for corner in scene_bounds.corners() {
+            // View space looks down -Z.
+            let depth = -self.view_matrix.transform_point3(corner).z;
+            min_depth = min_depth.min(depth);
+            max_depth = max_depth.max(depth);
+        }
+
+        if max_depth <= 0.0 {
+            return false;
+        }
+
+        let far = max_depth * (1.0 + AUTO_CLIP_PADDING);
+        let near = (min_depth * (1.0 - AUTO_CLIP_PADDING))
+            .max(AUTO_CLIP_MIN_NEAR)
+            .max(far * AUTO_CLIP_MIN_DEPTH_RATIO);
+
+        self.near = near;
+        self.far = far.max(near);
+        self.update_projection_matrix();
+        true
+    }
+
     /// Fits the camera to view a bounding box.
     ///
     /// Adjusts the near plane and camera position so the bounding box
@@ -501,6 +577,42 @@ impl Camera {
             center + Vec3::new(0.0, 0.0, distance),
         ));
     }
+
+    /// Returns the eight world-space corners of the view frustum between the
+    /// near and far clip planes.
+    ///
+    /// See [`frustum_corners_in_range`](Self::frustum_corners_in_range) for
+    /// the corner order. Perspective cameras default to an infinite far
+    /// plane, in which case the four far corners are not finite.
+    #[must_use]
+    pub fn frustum_corners(&self) -> [Vec3; 8] {
+        self.frustum_corners_in_range(self.near, self.far)
+    }
+
+    /// Returns the eight world-space corners of the frustum slice between the
+    /// view-space distances `near` and `far`, e.g. one shadow cascade.
+    ///
+    /// The corners are unprojected from NDC through the inverse of the
+    /// unjittered view-projection matrix of the last transform update.
+    /// Order: near plane first, then far plane, each as bottom-left,
+    /// bottom-right, top-right, top-left.
+    #[must_use]
+    pub fn frustum_corners_in_range(&self, near: f32, far: f32) -> [Vec3; 8] {
+        let inverse = (self.unjittered_projection * self.view_matrix).inverse();
+        let ndc_depth = |distance: f32| {
+            let clip = self.unjittered_projection * Vec4::new(0.0, 0.0, -distance, 1.0);
+            clip.z / clip.w
+        };
+        let (near_z, far_z) = (ndc_depth(near), ndc_depth(far));
+
+        let mut corners = [Vec3::ZERO; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let (x, y) = [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)][i % 4];
+            let z = if i < 4 { near_z } else { far_z };
+            *corner = inverse.project_point3(Vec3::new(x, y, z));
+        }
+        corners
+    }
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -594,6 +706,14 @@ impl Frustum {
         }
     }
 
+    /// Normalized planes (Left, Right, Bottom, Top, Near, Far) as
+    /// `(normal, distance)`; disabled planes are zero.
+    #[must_use]
+    #[inline]
+    pub fn planes(&self) -> &[Vec4; 6] {
+        &self.planes
+    }
+
     // Simple sphere intersection test
     #[must_use]
     #[inline]
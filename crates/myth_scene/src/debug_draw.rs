@@ -0,0 +1,332 @@
+//! Immediate-mode debug line drawing.
+//!
+//! [`DebugDraw`] collects transient line segments (bounding volumes, normals,
+//! bone axes, …) that are drawn for a single frame without creating scene
+//! nodes. Access it through [`Scene::debug`](crate::Scene::debug):
+//!
+//! ```rust,ignore
+//! scene.debug()
+//!     .line(Vec3::ZERO, Vec3::Y, Vec4::new(0.0, 1.0, 0.0, 1.0))
+//!     .aabb(&bbox, Vec4::ONE)
+//!     .sphere(center, radius, Vec4::new(1.0, 1.0, 0.0, 1.0));
+//! ```
+//!
+//! The renderer takes the accumulated vertices during extraction, so every
+//! frame starts with an empty list. Primitives must be re-submitted each
+//! frame they should stay visible.
+//!
+//! Skeletons queued with [`DebugDraw::skeleton`] are turned into bone lines
+//! at extraction time, after animation has run, so they follow the pose that
+//! is actually rendered. The same applies to the light helpers enabled with
+//! [`Scene::show_light_helpers`](crate::Scene::show_light_helpers).
+
+use bytemuck::{Pod, Zeroable};
+use glam::{Affine3A, Quat, Vec3, Vec4};
+use myth_core::SkeletonKey;
+use myth_resources::BoundingBox;
+
+use crate::light::{Light, LightKind};
+
+/// Number of segments used for each circle of a debug sphere.
+const SPHERE_SEGMENTS: usize = 32;
+
+/// Length of directional light arrows, and the size used for point and spot
+/// lights without a finite range.
+const LIGHT_HELPER_SIZE: f32 = 1.0;
+
+/// Appearance of the ground grid shown with
+/// [`Scene::show_grid`](crate::Scene::show_grid).
+///
+/// The grid lies on the horizontal plane `y = height` and is drawn
+/// procedurally, so it covers the whole plane at any camera distance.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GridOptions {
+    /// World-space distance between minor lines (default 1.0).
+    pub spacing: f32,
+    /// Number of minor cells between major lines (default 10). `0` or `1`
+    /// draws every line as a major line.
+    pub major_every: u32,
+    /// Color of the minor lines; alpha controls their opacity.
+    pub minor_color: Vec4,
+    /// Color of the major lines; alpha controls their opacity.
+    pub major_color: Vec4,
+    /// Height of the grid plane (default 0.0).
+    pub height: f32,
+    /// Distance from the camera at which the grid has fully faded out
+    /// (default 100.0).
+    pub fade_distance: f32,
+    /// Line width in pixels (default 1.0).
+    pub line_width: f32,
+}
+
+impl Default for GridOptions {
+    fn default() -> Self {
+        Self {
+            spacing: 1.0,
+            major_every: 10,
+            minor_color: Vec4::new(0.5, 0.5, 0.5, 0.4),
+            major_color: Vec4::new(0.7, 0.7, 0.7, 0.8),
+            height: 0.0,
+            fade_distance: 100.0,
+            line_width: 1.0,
+        }
+    }
+}
+
+/// One endpoint of a debug line (line-list topology, two per segment).
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Pod, Zeroable)]
+pub struct DebugVertex {
+    pub position: [f32; 3],
+    pub color: [f32; 4],
+}
+
+/// Per-frame debug line accumulator.
+#[derive(Debug, Clone)]
+pub struct DebugDraw {
+    vertices: Vec<DebugVertex>,
+    skeletons: Vec<(SkeletonKey, Vec4)>,
+    /// Whether debug lines are hidden behind opaque geometry (default: `true`).
+    ///
+    /// Set to `false` to draw them as an overlay on top of the scene.
+    pub depth_test: bool,
+}
+
+impl Default for DebugDraw {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DebugDraw {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            vertices: Vec::new(),
+            skeletons: Vec::new(),
+            depth_test: true,
+        }
+    }
+
+    /// Adds a line segment from `a` to `b`.
+    pub fn line(&mut self, a: Vec3, b: Vec3, color: Vec4) -> &mut Self {
+        let color = color.to_array();
+        self.vertices.push(DebugVertex {
+            position: a.to_array(),
+            color,
+        });
+        self.vertices.push(DebugVertex {
+            position: b.to_array(),
+            color,
+        });
+        self
+    }
+
+    /// Adds the 12 edges of an axis-aligned bounding box.
+    ///
+    /// Infinite boxes are skipped.
+    pub fn aabb(&mut self, bbox: &BoundingBox, color: Vec4) -> &mut Self {
+        if !bbox.is_finite() {
+            return self;
+        }
+        let (min, max) = (bbox.min, bbox.max);
+        let corner = |i: usize| {
+            Vec3::new(
+                if i & 1 == 0 { min.x } else { max.x },
+                if i & 2 == 0 { min.y } else { max.y },
+                if i & 4 == 0 { min.z } else { max.z },
+            )
+        };
+        for i in 0..8 {
+            // Connect each corner to its neighbours along +X, +Y and +Z.
+            for axis in [1, 2, 4] {
+                if i & axis == 0 {
+                    self.line(corner(i), corner(i | axis), color);
+                }
+            }
+        }
+        self
+    }
+
+    /// Adds the 12 edges of a frustum given its eight corners, in the order
+    /// returned by [`Camera::frustum_corners`](crate::Camera::frustum_corners).
+    ///
+    /// Edges with a non-finite endpoint (e.g. the far plane of an infinite
+    /// perspective camera) are skipped.
+    pub fn frustum(&mut self, corners: &[Vec3; 8], color: Vec4) -> &mut Self {
+        for i in 0..4 {
+            let j = (i + 1) % 4;
+            // Near ring, far ring and the connecting edge.
+            for (a, b) in [(i, j), (i + 4, j + 4), (i, i + 4)] {
+                if corners[a].is_finite() && corners[b].is_finite() {
+                    self.line(corners[a], corners[b], color);
+                }
+            }
+        }
+        self
+    }
+
+    /// Adds a wire circle around `center` spanned by the (orthogonal) radius
+    /// vectors `u` and `v`.
+    pub fn circle(&mut self, center: Vec3, u: Vec3, v: Vec3, color: Vec4) -> &mut Self {
+        let step = std::f32::consts::TAU / SPHERE_SEGMENTS as f32;
+        for i in 0..SPHERE_SEGMENTS {
+            let (s0, c0) = (i as f32 * step).sin_cos();
+            let (s1, c1) = ((i + 1) as f32 * step).sin_cos();
+            self.line(center + u * c0 + v * s0, center + u * c1 + v * s1, color);
+        }
+        self
+    }
+
+    /// Adds a wire sphere drawn as three axis-aligned great circles.
+    pub fn sphere(&mut self, center: Vec3, radius: f32, color: Vec4) -> &mut Self {
+        let (x, y, z) = (Vec3::X * radius, Vec3::Y * radius, Vec3::Z * radius);
+        // XY, YZ and ZX planes.
+        self.circle(center, x, y, color)
+            .circle(center, y, z, color)
+            .circle(center, z, x, color)
+    }
+
+    /// Adds an arrow from `from` to `to` with a four-line head.
+    pub fn arrow(&mut self, from: Vec3, to: Vec3, color: Vec4) -> &mut Self {
+        self.line(from, to, color);
+        let Some(dir) = (to - from).try_normalize() else {
+            return self;
+        };
+        let head = (to - from).length() * 0.2;
+        let side = dir.any_orthonormal_vector();
+        let up = dir.cross(side);
+        let base = to - dir * head;
+        for offset in [side, -side, up, -up] {
+            self.line(to, base + offset * head * 0.5, color);
+        }
+        self
+    }
+
+    /// Adds a cone opening from `apex` along `direction`, with the given
+    /// slant `length` and half-angle `angle` (radians): the base circle
+    /// plus four lines from the apex.
+    pub fn cone(
+        &mut self,
+        apex: Vec3,
+        direction: Vec3,
+        length: f32,
+        angle: f32,
+        color: Vec4,
+    ) -> &mut Self {
+        let Some(dir) = direction.try_normalize() else {
+            return self;
+        };
+        let (sin, cos) = angle.sin_cos();
+        let center = apex + dir * (length * cos);
+        let u = dir.any_orthonormal_vector() * (length * sin);
+        let v = dir.cross(u);
+        self.circle(center, u, v, color);
+        for offset in [u, -u, v, -v] {
+            self.line(apex, center + offset, color);
+        }
+        self
+    }
+
+    /// Adds a helper for `light` placed by its node's `world` matrix, in the
+    /// light's color.
+    ///
+    /// Directional lights draw an arrow along the light direction (local
+    /// -Z), point lights a sphere sized by their range and spot lights their
+    /// outer cone. Lights with a non-positive range use a unit size.
+    pub fn light(&mut self, light: &Light, world: &Affine3A) -> &mut Self {
+        let color = light.color.extend(1.0);
+        let position: Vec3 = world.translation.into();
+        let direction = world.transform_vector3(-Vec3::Z);
+        let size = |range: f32| {
+            if range > 0.0 {
+                range
+            } else {
+                LIGHT_HELPER_SIZE
+            }
+        };
+        match &light.kind {
+            LightKind::Directional(_) => {
+                let dir = direction.normalize_or_zero();
+                self.arrow(position, position + dir * LIGHT_HELPER_SIZE, color)
+            }
+            LightKind::Point(point) => self.sphere(position, size(point.range), color),
+            LightKind::Spot(spot) => self.cone(
+                position,
+                direction,
+                size(spot.range),
+                spot.outer_cone,
+                color,
+            ),
+        }
+    }
+
+    /// Adds the X (red), Y (green) and Z (blue) axes of a frame.
+    pub fn axes(&mut self, origin: Vec3, rotation: Quat, size: f32) -> &mut Self {
+        self.line(
+            origin,
+            origin + rotation * Vec3::X * size,
+            Vec4::new(1.0, 0.0, 0.0, 1.0),
+        );
+        self.line(
+            origin,
+            origin + rotation * Vec3::Y * size,
+            Vec4::new(0.0, 1.0, 0.0, 1.0),
+        );
+        self.line(
+            origin,
+            origin + rotation * Vec3::Z * size,
+            Vec4::new(0.0, 0.0, 1.0, 1.0),
+        )
+    }
+
+    /// Queues the bones of a skeleton, drawn from each bone to its parent.
+    ///
+    /// A skeleton shared by several skinned meshes is only drawn once per
+    /// frame, in the color of the first request. See
+    /// [`Scene::debug_draw_skeleton`](crate::Scene::debug_draw_skeleton).
+    pub fn skeleton(&mut self, skeleton: SkeletonKey, color: Vec4) -> &mut Self {
+        if !self.skeletons.iter().any(|(key, _)| *key == skeleton) {
+            self.skeletons.push((skeleton, color));
+        }
+        self
+    }
+
+    /// Returns the vertices accumulated so far (two per segment).
+    #[must_use]
+    pub fn vertices(&self) -> &[DebugVertex] {
+        &self.vertices
+    }
+
+    /// Returns the skeletons queued for this frame.
+    #[must_use]
+    pub fn skeletons(&self) -> &[(SkeletonKey, Vec4)] {
+        &self.skeletons
+    }
+
+    /// Returns `true` if no lines or skeletons have been added since the last reset.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty() && self.skeletons.is_empty()
+    }
+
+    /// Discards all accumulated lines and queued skeletons.
+    pub fn clear(&mut self) {
+        self.vertices.clear();
+        self.skeletons.clear();
+    }
+
+    /// Removes and returns the queued skeleton requests.
+    pub(crate) fn take_skeletons(&mut self) -> Vec<(SkeletonKey, Vec4)> {
+        std::mem::take(&mut self.skeletons)
+    }
+
+    /// Moves the accumulated vertices into `out` and resets the list.
+    ///
+    /// `out` is cleared first; the two allocations are swapped so neither
+    /// side reallocates in steady state.
+    pub fn drain_into(&mut self, out: &mut Vec<DebugVertex>) {
+        out.clear();
+        std::mem::swap(&mut self.vertices, out);
+    }
+}
@@ -0,0 +1,87 @@
+//! Projected decals.
+//!
+//! A node with a [`Decal`] projects a texture onto the opaque surfaces
+//! inside its box: the unit cube centered on the node, so the node scale
+//! sets the decal size (x, y) and projection depth (z). The texture is
+//! projected along the node's -Z axis, with +X and +Y as its right and up
+//! directions, like a camera looking through the box.
+//!
+//! The renderer applies decals after the opaque pass of the `HighFidelity`
+//! path, reading back world positions from the prepass depth. Lighting is
+//! already resolved at that point, so a decal changes surface color only:
+//! it is blended over the lit result and is not itself lit or shadowed.
+//! Transparent meshes and the `BasicForward` path do not receive decals.
+
+use glam::Vec4;
+use myth_resources::TextureHandle;
+
+/// Appearance options of a [`Decal`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecalOptions {
+    /// Tint multiplied with the texture; the alpha scales its opacity.
+    pub color: Vec4,
+    /// Largest angle, in radians, between a surface and the projection
+    /// direction that still receives the decal. The decal fades out toward
+    /// this angle instead of stretching across steep faces.
+    pub max_angle: f32,
+}
+
+impl Default for DecalOptions {
+    fn default() -> Self {
+        Self {
+            color: Vec4::ONE,
+            max_angle: std::f32::consts::FRAC_PI_3,
+        }
+    }
+}
+
+impl DecalOptions {
+    /// Sets the tint (builder).
+    #[must_use]
+    pub fn with_color(mut self, color: Vec4) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Sets the opacity, the alpha of the tint (builder).
+    #[must_use]
+    pub fn with_opacity(mut self, opacity: f32) -> Self {
+        self.color.w = opacity;
+        self
+    }
+
+    /// Sets the largest receiving angle in radians (builder).
+    #[must_use]
+    pub fn with_max_angle(mut self, max_angle: f32) -> Self {
+        self.max_angle = max_angle;
+        self
+    }
+}
+
+/// A texture projected onto the surfaces inside its node's box.
+///
+/// ```rust,ignore
+/// let hole = scene.add_decal(
+///     Transform::builder()
+///         .position(Vec3::new(0.0, 1.0, 0.51))
+///         .scale(Vec3::new(0.2, 0.2, 0.1))
+///         .build(),
+///     bullet_hole_texture,
+///     DecalOptions::default(),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Decal {
+    /// Projected texture; its alpha masks the decal shape.
+    pub texture: TextureHandle,
+    /// Tint and angle fade.
+    pub options: DecalOptions,
+}
+
+impl Decal {
+    /// Creates a decal projecting `texture`.
+    #[must_use]
+    pub fn new(texture: TextureHandle, options: DecalOptions) -> Self {
+        Self { texture, options }
+    }
+}
@@ -4,8 +4,11 @@
 //! Internal GPU textures (processed cube map, PMREM, BRDF LUT) are managed
 //! by `ResourceManager` and are **not** stored here.
 
+use glam::Quat;
 use myth_resources::texture::TextureSource;
 
+use crate::fog::Fog;
+
 pub const DEFAULT_ENV_BASE_CUBE_SIZE: u32 = 1024;
 pub const DEFAULT_ENV_PMREM_SIZE: u32 = 512;
 
@@ -31,11 +34,21 @@ pub struct Environment {
     #[doc(hidden)]
     pub source_env_map: Option<TextureSource>,
     /// Environment light intensity
+    ///
+    /// Scales both the IBL contribution and any background that displays
+    /// this environment (see [`Environment::set_intensity`]).
     pub intensity: f32,
-    /// Environment map rotation angle (radians)
-    pub rotation: f32,
+    /// Environment map orientation
+    pub rotation: Quat,
     /// Environment ambient light
     pub ambient: glam::Vec3,
+    /// Derive the ambient light from a solid or gradient background
+    /// (see [`Environment::set_ambient_from_background`]).
+    pub ambient_from_background: bool,
+    /// Distance fog, if any (see [`Environment::set_fog`]).
+    pub fog: Option<Fog>,
+    /// Skybox blur in `[0, 1]` (see [`Environment::set_background_blur`]).
+    pub background_blur: f32,
 
     /// Persistent GPU environment texture sizing.
     pub map_config: EnvironmentMapConfig,
@@ -59,6 +72,9 @@ impl PartialEq for Environment {
             && self.intensity == other.intensity
             && self.rotation == other.rotation
             && self.ambient == other.ambient
+            && self.ambient_from_background == other.ambient_from_background
+            && self.fog == other.fog
+            && self.background_blur == other.background_blur
             && self.map_config == other.map_config
     }
 }
@@ -69,8 +85,11 @@ impl Environment {
         Self {
             source_env_map: None,
             intensity: 1.0,
-            rotation: 0.0,
+            rotation: Quat::IDENTITY,
             ambient: glam::Vec3::ZERO,
+            ambient_from_background: false,
+            fog: None,
+            background_blur: 0.0,
             map_config: EnvironmentMapConfig::default(),
             version: 0,
             source_version: 0,
@@ -108,16 +127,112 @@ impl Environment {
         }
     }
 
-    /// Sets the environment light intensity
+    /// Sets the environment light intensity.
+    ///
+    /// Applies to the diffuse irradiance and specular prefilter lookups, and to
+    /// the skybox when the background shows the same texture as the
+    /// environment map (or a procedural sky), so reflections and background
+    /// stay equally bright.
     pub fn set_intensity(&mut self, intensity: f32) {
         self.intensity = intensity;
     }
 
+    /// Returns the environment light intensity.
+    #[inline]
+    #[must_use]
+    pub fn intensity(&self) -> f32 {
+        self.intensity
+    }
+
+    /// Sets the environment orientation.
+    ///
+    /// The rotation is applied at sampling time, so changing it never
+    /// triggers a re-bake. It affects the diffuse irradiance, the specular
+    /// prefilter lookups and the skybox when the background shows the same
+    /// texture as the environment map. Procedural skies are oriented by their
+    /// sun direction instead, so the rotation is ignored in that mode.
+    ///
+    /// Rotating the environment by `rotation` moves a feature seen in
+    /// direction `d` of the source map to `rotation * d`, e.g. to line up the
+    /// sun of an HDR with a directional light.
+    pub fn set_rotation(&mut self, rotation: Quat) {
+        self.rotation = rotation.normalize();
+    }
+
+    /// Sets the environment orientation to a rotation around the Y axis
+    /// (yaw), in radians.
+    pub fn set_rotation_y(&mut self, radians: f32) {
+        self.set_rotation(Quat::from_rotation_y(radians));
+    }
+
+    /// Returns the environment orientation.
+    #[inline]
+    #[must_use]
+    pub fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    /// Returns the matrix that maps a world-space lookup direction into the
+    /// source map, i.e. the inverse of [`rotation`](Self::rotation).
+    #[inline]
+    #[must_use]
+    pub fn sampling_matrix(&self) -> glam::Mat3 {
+        glam::Mat3::from_quat(self.rotation.inverse())
+    }
+
     /// Sets the environment ambient light
     pub fn set_ambient_light(&mut self, color: glam::Vec3) {
         self.ambient = color;
     }
 
+    /// Uses the average color of a solid or gradient background as a flat
+    /// ambient term, so Phong and PBR materials are lit without an HDR.
+    ///
+    /// While enabled, this replaces [`ambient`](Self::ambient) for
+    /// [`BackgroundMode::Color`](crate::BackgroundMode::Color) and
+    /// [`BackgroundMode::Gradient`](crate::BackgroundMode::Gradient)
+    /// backgrounds and follows their colors as they change. Texture and
+    /// procedural backgrounds keep the configured ambient, since they light
+    /// the scene through the environment map instead.
+    pub fn set_ambient_from_background(&mut self, enabled: bool) {
+        self.ambient_from_background = enabled;
+    }
+
+    /// Sets (or clears) the distance fog.
+    ///
+    /// Fog is applied in the Phong, Physical and Unlit fragment shaders,
+    /// which compile a `FOG` variant while it is set. Changing the mode,
+    /// color or distances afterwards only updates the environment uniforms.
+    pub fn set_fog(&mut self, fog: Option<Fog>) {
+        self.fog = fog;
+    }
+
+    /// Returns the distance fog, if any.
+    #[inline]
+    #[must_use]
+    pub fn fog(&self) -> Option<&Fog> {
+        self.fog.as_ref()
+    }
+
+    /// Blurs the background, from `0.0` (sharp) to `1.0` (fully blurred).
+    ///
+    /// The skybox samples the prefiltered environment at the matching
+    /// roughness level, so this is free to animate and never re-bakes.
+    /// Reflections and diffuse lighting are unaffected. Only backgrounds
+    /// that show the environment (a procedural sky, or a cube/equirect
+    /// texture that is also the environment map) can be blurred; other
+    /// backgrounds ignore it.
+    pub fn set_background_blur(&mut self, blur: f32) {
+        self.background_blur = blur.clamp(0.0, 1.0);
+    }
+
+    /// Returns the background blur in `[0, 1]`.
+    #[inline]
+    #[must_use]
+    pub fn background_blur(&self) -> f32 {
+        self.background_blur
+    }
+
     pub fn set_base_cube_size(&mut self, size: u32) {
         let size = size.max(1);
         if self.map_config.base_cube_size != size {
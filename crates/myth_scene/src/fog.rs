@@ -0,0 +1,171 @@
+//! Distance fog.
+//!
+//! A [`Fog`] set with [`Environment::set_fog`](crate::Environment::set_fog)
+//! fades Phong, Physical and Unlit surfaces toward a fog color by their
+//! view-space depth. The blend happens in each material's fragment shader,
+//! before the color is written or blended, so transparent surfaces are
+//! fogged by their own depth like opaque ones.
+
+use glam::Vec3;
+use myth_resources::uniforms::EnvironmentUniforms;
+
+use crate::background::BackgroundMode;
+use crate::environment::Environment;
+
+/// How the fog amount grows with view-space depth.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FogMode {
+    /// No fog closer than `near`, full fog at `far` and beyond, with a
+    /// smooth ramp in between.
+    Linear {
+        /// Depth where the fog starts.
+        near: f32,
+        /// Depth where the fog becomes opaque.
+        far: f32,
+    },
+    /// `1 - exp(-density · depth)`: thickens steadily with distance.
+    Exponential {
+        /// Fog density per world unit.
+        density: f32,
+    },
+    /// `1 - exp(-(density · depth)²)`: clear near the camera, then closing
+    /// in quickly.
+    ExponentialSquared {
+        /// Fog density per world unit.
+        density: f32,
+    },
+}
+
+impl FogMode {
+    /// Returns the fog amount in `[0, 1]` at `depth` along the view axis.
+    ///
+    /// Mirrors `fog_factor` in `core/fog.wgsl`.
+    #[must_use]
+    pub fn factor(self, depth: f32) -> f32 {
+        let depth = depth.max(0.0);
+        match self {
+            Self::Linear { near, far } => {
+                if far <= near {
+                    return if depth >= far { 1.0 } else { 0.0 };
+                }
+                let t = ((depth - near) / (far - near)).clamp(0.0, 1.0);
+                t * t * (3.0 - 2.0 * t)
+            }
+            Self::Exponential { density } => 1.0 - (-density * depth).exp(),
+            Self::ExponentialSquared { density } => {
+                let d = density * depth;
+                1.0 - (-d * d).exp()
+            }
+        }
+    }
+}
+
+/// Scene fog settings.
+///
+/// ```rust,ignore
+/// scene.environment.set_fog(Some(Fog::linear(Vec3::splat(0.7), 10.0, 60.0)));
+/// scene.environment.set_fog(Some(Fog::exponential_squared(Vec3::ONE, 0.03).with_background_color()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fog {
+    /// Depth falloff.
+    pub mode: FogMode,
+    /// Fog color (linear RGB).
+    pub color: Vec3,
+    /// Take the fog color from the background instead of `color`.
+    ///
+    /// Solid and gradient backgrounds are matched exactly, per view
+    /// direction. Procedural skies, and textures that also serve as the
+    /// environment map, are matched by sampling the environment cube along
+    /// the view ray. Other backgrounds fall back to `color`.
+    pub use_background_color: bool,
+}
+
+impl Fog {
+    /// Linear fog from `near` to `far` view-space depth.
+    #[must_use]
+    pub fn linear(color: Vec3, near: f32, far: f32) -> Self {
+        Self {
+            mode: FogMode::Linear { near, far },
+            color,
+            use_background_color: false,
+        }
+    }
+
+    /// Exponential fog with the given density.
+    #[must_use]
+    pub fn exponential(color: Vec3, density: f32) -> Self {
+        Self {
+            mode: FogMode::Exponential { density },
+            color,
+            use_background_color: false,
+        }
+    }
+
+    /// Squared exponential fog with the given density.
+    #[must_use]
+    pub fn exponential_squared(color: Vec3, density: f32) -> Self {
+        Self {
+            mode: FogMode::ExponentialSquared { density },
+            color,
+            use_background_color: false,
+        }
+    }
+
+    /// Takes the fog color from the background, so distant geometry fades
+    /// into it (see [`use_background_color`](Self::use_background_color)).
+    #[must_use]
+    pub fn with_background_color(mut self) -> Self {
+        self.use_background_color = true;
+        self
+    }
+
+    /// Returns the fog amount in `[0, 1]` at `depth` along the view axis.
+    #[inline]
+    #[must_use]
+    pub fn factor(&self, depth: f32) -> f32 {
+        self.mode.factor(depth)
+    }
+
+    /// Writes the fog fields of the environment uniforms, resolving
+    /// [`use_background_color`](Self::use_background_color) against the
+    /// current background.
+    pub(crate) fn write_uniforms(
+        &self,
+        uniforms: &mut EnvironmentUniforms,
+        background: &BackgroundMode,
+        environment: &Environment,
+    ) {
+        let (mode, near, far, density) = match self.mode {
+            FogMode::Linear { near, far } => (0, near, far, 0.0),
+            FogMode::Exponential { density } => (1, 0.0, 0.0, density),
+            FogMode::ExponentialSquared { density } => (2, 0.0, 0.0, density),
+        };
+        uniforms.fog_mode = mode;
+        uniforms.fog_near = near;
+        uniforms.fog_far = far;
+        uniforms.fog_density = density;
+
+        uniforms.fog_color = self.color;
+        uniforms.fog_ground_color = self.color;
+        uniforms.fog_color_source = 0;
+        if !self.use_background_color {
+            return;
+        }
+        match background {
+            BackgroundMode::Color(color) => uniforms.fog_color = color.truncate(),
+            BackgroundMode::Gradient { top, bottom } => {
+                uniforms.fog_color = top.truncate();
+                uniforms.fog_ground_color = bottom.truncate();
+                uniforms.fog_color_source = 1;
+            }
+            // Only a background showing the environment can be matched
+            // through the environment cube.
+            BackgroundMode::Procedural(_) | BackgroundMode::Texture { .. } => {
+                if background.shows_environment(environment) {
+                    uniforms.fog_color_source = 2;
+                }
+            }
+        }
+    }
+}
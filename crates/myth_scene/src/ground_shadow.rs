@@ -0,0 +1,67 @@
+//! Ground contact shadow.
+//!
+//! A cheap grounding shadow for turntable-style displays that don't need
+//! shadow maps, enabled with
+//! [`Scene::set_ground_shadow`](crate::Scene::set_ground_shadow). The
+//! renderer darkens a soft ellipse on the horizontal plane `y = plane_y`
+//! under the combined bounds of the scene's shadow-casting meshes. The
+//! bounds are taken every frame, so the blob follows animated and skinned
+//! models. Meshes centred on or below the plane, such as the floor itself,
+//! are left out. The shadow fades as the models rise above the plane.
+
+/// Settings of the blob shadow drawn under the scene's meshes.
+///
+/// ```rust,ignore
+/// scene.set_ground_shadow(true, 0.0, 0.5);
+/// if let Some(shadow) = scene.ground_shadow_mut() {
+///     shadow.opacity = 0.8;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroundShadow {
+    /// Height of the ground plane (default 0.0).
+    pub plane_y: f32,
+    /// Width of the blurred edge as a fraction of the footprint radius,
+    /// `0.0` for a hard edge and `1.0` for a shadow that is soft all the
+    /// way to the centre (default 0.5).
+    pub softness: f32,
+    /// Darkness at the centre of the shadow (default 0.6).
+    pub opacity: f32,
+    /// Height of the models' lowest point above the plane at which the
+    /// shadow has faded out (default 2.0).
+    pub fade_distance: f32,
+}
+
+impl Default for GroundShadow {
+    fn default() -> Self {
+        Self {
+            plane_y: 0.0,
+            softness: 0.5,
+            opacity: 0.6,
+            fade_distance: 2.0,
+        }
+    }
+}
+
+impl GroundShadow {
+    /// Shadow strength, from `0.0` to `opacity`, at the normalized
+    /// footprint distance `distance` (`1.0` on the bounds' ellipse) for
+    /// models whose lowest point is `height` above the plane.
+    ///
+    /// CPU mirror of the ground shadow shader.
+    #[must_use]
+    pub fn strength(&self, distance: f32, height: f32) -> f32 {
+        let softness = self.softness.clamp(0.0, 1.0);
+        let edge = 1.0 - smoothstep(1.0 - softness, 1.0 + softness, distance);
+        let fade = 1.0 - (height.max(0.0) / self.fade_distance.max(1e-4)).min(1.0);
+        self.opacity.clamp(0.0, 1.0) * edge * fade
+    }
+}
+
+fn smoothstep(edge0: f32, edge1: f32, x: f32) -> f32 {
+    if edge1 <= edge0 {
+        return if x < edge0 { 0.0 } else { 1.0 };
+    }
+    let t = ((x - edge0) / (edge1 - edge0)).clamp(0.0, 1.0);
+    t * t * (3.0 - 2.0 * t)
+}
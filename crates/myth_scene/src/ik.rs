@@ -0,0 +1,125 @@
+//! Analytic two-bone inverse kinematics.
+//!
+//! A [`TwoBoneIk`] chain (e.g. thigh → shin → foot, or upper arm → forearm →
+//! hand) is solved every frame by [`Scene::update_ik`](crate::Scene::update_ik),
+//! after the animation mixers and the hierarchy update, so it overrides the
+//! clip pose of the root and mid bones. Only world matrices are replaced:
+//! the bones' local transforms keep the animated pose, so fading the
+//! [`weight`](TwoBoneIk::weight) out returns smoothly to the clip.
+
+use glam::{Quat, Vec3};
+use myth_core::NodeHandle;
+
+/// Keeps the solved chain slightly short of full extension, where the bend
+/// plane becomes undefined.
+const EXTENSION_EPSILON: f32 = 1e-4;
+
+/// A two-bone IK chain reaching for a world-space target.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TwoBoneIk {
+    /// Upper bone (hip, shoulder); rotates the whole chain.
+    pub root: NodeHandle,
+    /// Middle joint (knee, elbow); bends the lower bone.
+    pub mid: NodeHandle,
+    /// End effector (foot, hand) placed on the target.
+    pub tip: NodeHandle,
+    /// World-space position the tip reaches for.
+    pub target: Vec3,
+    /// World-space direction the middle joint bends toward.
+    ///
+    /// `None` keeps the bend plane of the current pose.
+    pub pole: Option<Vec3>,
+    /// Blend between the animated pose (`0.0`) and the solved pose (`1.0`).
+    pub weight: f32,
+}
+
+impl TwoBoneIk {
+    /// Creates a fully weighted chain without a pole vector.
+    #[must_use]
+    pub fn new(root: NodeHandle, mid: NodeHandle, tip: NodeHandle, target: Vec3) -> Self {
+        Self {
+            root,
+            mid,
+            tip,
+            target,
+            pole: None,
+            weight: 1.0,
+        }
+    }
+
+    /// Sets the pole vector (builder).
+    #[must_use]
+    pub fn with_pole(mut self, pole: Vec3) -> Self {
+        self.pole = Some(pole);
+        self
+    }
+
+    /// Sets the blend weight (builder).
+    #[must_use]
+    pub fn with_weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Solves the chain for the world-space joint positions `root`, `mid`
+    /// and `tip`.
+    ///
+    /// Returns `(chain, bend)`: `bend` rotates the lower bone around the mid
+    /// joint, then `chain` rotates the whole chain around the root joint.
+    /// The root's new world rotation is `chain * root_rotation` and the
+    /// mid's is `chain * bend * mid_rotation`. Targets out of reach are
+    /// clamped, so the chain stretches toward them. Returns `None` for a
+    /// degenerate chain (zero-length bones or a target on the root).
+    #[must_use]
+    pub fn solve(&self, root: Vec3, mid: Vec3, tip: Vec3) -> Option<(Quat, Quat)> {
+        let upper = mid - root;
+        let lower = tip - mid;
+        let (upper_len, lower_len) = (upper.length(), lower.length());
+        let to_target = self.target - root;
+        let target_dir = to_target.try_normalize()?;
+        if upper_len <= f32::EPSILON || lower_len <= f32::EPSILON {
+            return None;
+        }
+
+        let reach = to_target.length().clamp(
+            (upper_len - lower_len).abs() + EXTENSION_EPSILON,
+            upper_len + lower_len - EXTENSION_EPSILON,
+        );
+
+        // Bend axis: normal of the plane holding the chain, taken from the
+        // pole when given, else from the current pose.
+        let root_to_tip = tip - root;
+        let bend_axis = self
+            .pole
+            .and_then(|pole| root_to_tip.cross(pole).try_normalize())
+            .or_else(|| root_to_tip.cross(upper).try_normalize())
+            .unwrap_or_else(|| root_to_tip.any_orthonormal_vector());
+
+        // Law of cosines for the interior angle at the mid joint.
+        let current = (-upper).angle_between(lower);
+        let cos_desired = (upper_len * upper_len + lower_len * lower_len - reach * reach)
+            / (2.0 * upper_len * lower_len);
+        let desired = cos_desired.clamp(-1.0, 1.0).acos();
+        let bend = Quat::from_axis_angle(bend_axis, desired - current);
+
+        // Swing the bent chain so the tip points at the target.
+        let bent_tip = upper + bend * lower;
+        let mut chain = Quat::from_rotation_arc(bent_tip.try_normalize()?, target_dir);
+
+        // Twist around the root→target axis so the mid joint faces the pole.
+        if let Some(pole) = self.pole {
+            let current_dir = (chain * upper).reject_from_normalized(target_dir);
+            let pole_dir = pole.reject_from_normalized(target_dir);
+            if current_dir.length_squared() > f32::EPSILON
+                && pole_dir.length_squared() > f32::EPSILON
+            {
+                let angle = target_dir
+                    .dot(current_dir.cross(pole_dir))
+                    .atan2(current_dir.dot(pole_dir));
+                chain = Quat::from_axis_angle(target_dir, angle) * chain;
+            }
+        }
+
+        Some((chain, bend))
+    }
+}
@@ -7,12 +7,23 @@
 //! environment/post-processing settings, and GPU synchronisation buffers.
 
 pub mod background;
+pub mod billboard;
 pub mod camera;
 pub mod day_night;
+pub mod debug_draw;
+pub mod decal;
 pub mod environment;
+pub mod fog;
+pub mod ground_shadow;
+pub mod ik;
 pub mod light;
+pub mod light_probe;
+pub mod lod;
 pub mod node;
+pub mod particles;
+pub mod reflection_probe;
 pub mod scene;
+pub mod serde;
 pub mod skeleton;
 pub mod transform_system;
 pub mod wrapper;
@@ -34,13 +45,30 @@ pub trait GeometryQuery {
 
 // Re-exports from this crate
 pub use background::{BackgroundMapping, BackgroundMode, BackgroundSettings, ProceduralSkyParams};
+pub use billboard::BillboardMode;
 pub use camera::{Camera, Frustum, ProjectionType, RenderCamera};
 #[cfg(feature = "debug_view")]
 pub use camera::{DebugViewMode, DebugViewSettings};
 pub use day_night::DayNightCycle;
+pub use debug_draw::{DebugDraw, DebugVertex, GridOptions};
+pub use decal::{Decal, DecalOptions};
 pub use environment::Environment;
-pub use light::{DirectionalLight, Light, LightKind, PointLight, ShadowConfig, SpotLight};
-pub use node::Node;
-pub use scene::{CallbackLogic, NodeBuilder, Scene, SceneLogic, SplitPrimitiveTag};
+pub use fog::{Fog, FogMode};
+pub use ground_shadow::GroundShadow;
+pub use ik::TwoBoneIk;
+pub use light::{
+    DirectionalLight, Light, LightKind, LightUnits, PointLight, ShadowConfig, SpotLight,
+};
+pub use light_probe::LightProbeVolume;
+pub use lod::{Lod, LodLevel};
+pub use myth_resources::uniforms::ObjectOverride;
+pub use node::{ALL_LAYERS, Node};
+pub use particles::{Particle, ParticleSystem};
+pub use reflection_probe::ReflectionProbe;
+pub use scene::{
+    CallbackLogic, MorphNormalization, NodeBuilder, Scene, SceneLogic, SplitPrimitiveTag,
+    TransparencyMode,
+};
+pub use serde::{AssetIdResolver, SceneDescriptor};
 pub use skeleton::{BindMode, Skeleton, SkinBinding};
 pub use wrapper::SceneNode;
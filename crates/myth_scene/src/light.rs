@@ -2,6 +2,8 @@ use glam::Vec3;
 use std::hash::{Hash, Hasher};
 use uuid::Uuid;
 
+use crate::node::ALL_LAYERS;
+
 #[derive(Debug, Clone)]
 pub struct ShadowConfig {
     pub bias: f32,
@@ -47,6 +49,23 @@ pub struct SpotLight {
     pub outer_cone: f32,
 }
 
+/// Unit system of [`Light::intensity`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LightUnits {
+    /// Arbitrary intensity multiplier. Point and spot lights fade out over
+    /// `range` without inverse-square falloff, and exposure is ignored.
+    #[default]
+    Legacy,
+    /// Photometric units: lux for directional lights, candela for point and
+    /// spot lights.
+    ///
+    /// Point and spot lights follow the inverse-square law, and the result is
+    /// scaled by the scene's exposure
+    /// ([`ToneMappingSettings::ev100`](myth_resources::tone_mapping::ToneMappingSettings::ev100)).
+    /// `KHR_lights_punctual` lights from glTF are imported in this mode.
+    PhysicalUnits,
+}
+
 // High-level abstraction: light component in the scene
 #[derive(Debug, Clone)]
 pub enum LightKind {
@@ -60,11 +79,16 @@ pub struct Light {
     uuid: Uuid,
     id: u64,
     pub color: Vec3,
-    pub intensity: f32, // Suggestion: specify units, e.g. in PBR: Point uses Candela, Directional uses Lux
+    /// Brightness, interpreted according to [`units`](Self::units).
+    pub intensity: f32,
+    pub units: LightUnits,
     pub kind: LightKind,
 
     pub cast_shadows: bool,
     pub shadow: Option<ShadowConfig>,
+    /// Layers whose nodes cast shadows from this light (see
+    /// [`Node::layers`](crate::Node::layers)). Defaults to [`ALL_LAYERS`].
+    pub shadow_layers: u32,
 }
 
 impl Light {
@@ -82,6 +106,84 @@ impl Light {
         self.id
     }
 
+    /// Returns a copy of this light with a fresh uuid and id.
+    ///
+    /// Renderer state such as shadow maps is keyed by [`id`](Self::id), so
+    /// a light placed in the scene twice must be duplicated rather than
+    /// cloned.
+    #[must_use]
+    pub fn duplicate(&self) -> Self {
+        let uuid = Uuid::new_v4();
+        Self {
+            uuid,
+            id: Self::generate_id_from_uuid(&uuid),
+            ..self.clone()
+        }
+    }
+
+    /// Sets the unit system of `intensity` (builder).
+    #[must_use]
+    pub fn with_units(mut self, units: LightUnits) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Sets the intensity as illuminance in lux and switches to
+    /// [`LightUnits::PhysicalUnits`]. Meant for directional lights
+    /// (direct sunlight is roughly 100 000 lux).
+    pub fn set_intensity_lux(&mut self, lux: f32) {
+        self.intensity = lux;
+        self.units = LightUnits::PhysicalUnits;
+    }
+
+    /// Sets the intensity as luminous intensity in candela and switches to
+    /// [`LightUnits::PhysicalUnits`]. Meant for point and spot lights; this
+    /// is the unit `KHR_lights_punctual` uses for them.
+    pub fn set_intensity_candela(&mut self, candela: f32) {
+        self.intensity = candela;
+        self.units = LightUnits::PhysicalUnits;
+    }
+
+    /// Sets the intensity from luminous power in lumens and switches to
+    /// [`LightUnits::PhysicalUnits`].
+    ///
+    /// Point and spot lights spread the power over the full sphere
+    /// (`candela = lumens / 4π`), so narrowing a spot cone masks light
+    /// rather than concentrating it. Directional lights have no finite
+    /// power; for them the value is taken as lux.
+    pub fn set_intensity_lumens(&mut self, lumens: f32) {
+        let intensity = match self.kind {
+            LightKind::Directional(_) => lumens,
+            LightKind::Point(_) | LightKind::Spot(_) => lumens / (4.0 * std::f32::consts::PI),
+        };
+        self.set_intensity_candela(intensity);
+    }
+
+    /// Returns the irradiance this light delivers at `distance`, before
+    /// exposure and shadows (and on the axis of a spot light).
+    ///
+    /// Mirrors the shader's distance attenuation: physical-unit point and
+    /// spot lights follow the inverse-square law (candela / d² = lux), legacy
+    /// ones keep their intensity, and both fade out smoothly toward a
+    /// non-zero `range`. Directional lights ignore `distance`.
+    #[must_use]
+    pub fn irradiance_at(&self, distance: f32) -> f32 {
+        let range = match &self.kind {
+            LightKind::Directional(_) => return self.intensity,
+            LightKind::Point(point) => point.range,
+            LightKind::Spot(spot) => spot.range,
+        };
+        let mut falloff = match self.units {
+            LightUnits::Legacy => 1.0,
+            LightUnits::PhysicalUnits => 1.0 / (distance * distance).max(0.01),
+        };
+        if range > 0.0 {
+            let window = (1.0 - (distance / range).powi(4)).clamp(0.0, 1.0);
+            falloff *= window * window;
+        }
+        self.intensity * falloff
+    }
+
     fn generate_id_from_uuid(uuid: &Uuid) -> u64 {
         let mut hasher = std::collections::hash_map::DefaultHasher::new();
         uuid.hash(&mut hasher);
@@ -96,11 +198,13 @@ impl Light {
             id: Self::generate_id_from_uuid(&uuid),
             color,
             intensity,
+            units: LightUnits::Legacy,
             kind: LightKind::Directional(DirectionalLight {
                 // cascades: 4,
             }),
             cast_shadows: false,
             shadow: Some(ShadowConfig::default()),
+            shadow_layers: ALL_LAYERS,
         }
     }
 
@@ -112,9 +216,11 @@ impl Light {
             id: Self::generate_id_from_uuid(&uuid),
             color,
             intensity,
+            units: LightUnits::Legacy,
             kind: LightKind::Point(PointLight { range }),
             cast_shadows: false,
             shadow: Some(ShadowConfig::default()),
+            shadow_layers: ALL_LAYERS,
         }
     }
 
@@ -132,6 +238,7 @@ impl Light {
             id: Self::generate_id_from_uuid(&uuid),
             color,
             intensity,
+            units: LightUnits::Legacy,
             kind: LightKind::Spot(SpotLight {
                 range,
                 inner_cone,
@@ -139,6 +246,7 @@ impl Light {
             }),
             cast_shadows: false,
             shadow: Some(ShadowConfig::default()),
+            shadow_layers: ALL_LAYERS,
         }
     }
 }
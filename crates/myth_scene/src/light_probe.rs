@@ -0,0 +1,133 @@
+//! Baked light probes.
+//!
+//! A [`LightProbeVolume`] places a regular grid of irradiance probes over a
+//! box, set up by [`Scene::bake_light_probes`](crate::Scene::bake_light_probes).
+//! The renderer captures the scene around each probe and projects the
+//! capture onto order-2 spherical harmonics (nine RGB coefficients), stored
+//! per probe in a storage buffer. PBR materials inside the box take their
+//! indirect diffuse lighting from the eight probes around the shaded point,
+//! interpolated trilinearly, instead of the environment map's irradiance;
+//! just outside the box they fade back to the environment over
+//! [`blend_distance`](LightProbeVolume::blend_distance).
+//!
+//! Baking renders the scene six times per probe, so a volume is baked once
+//! and again only after each call to [`LightProbeVolume::bake`]. Probes see
+//! the sky, lights and surfaces around them, which gives indirect lighting
+//! one bounce of colour from nearby walls. Only the `HighFidelity` path
+//! bakes probes.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use glam::{UVec3, Vec3};
+use myth_resources::BoundingBox;
+
+/// Source of bake versions, unique across volumes so that a grid replacing
+/// a cleared one is never mistaken for its bake.
+static NEXT_BAKE_VERSION: AtomicU64 = AtomicU64::new(1);
+
+/// A grid of irradiance probes over a box.
+///
+/// ```rust,ignore
+/// let bounds = BoundingBox { min: Vec3::new(-4.0, 0.0, -4.0), max: Vec3::new(4.0, 4.0, 4.0) };
+/// scene.bake_light_probes(bounds, UVec3::new(4, 2, 4));
+///
+/// // Later, after the room has changed:
+/// scene.light_probes_mut().unwrap().bake();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct LightProbeVolume {
+    bounds: BoundingBox,
+    resolution: UVec3,
+    /// Multiplier applied to the baked irradiance.
+    pub intensity: f32,
+    /// Width of the band outside the bounds over which the probes fade
+    /// into the environment map's irradiance.
+    pub blend_distance: f32,
+    /// Edge length in pixels of each cube face captured per probe.
+    pub capture_resolution: u32,
+    /// Near clip plane of the capture cameras.
+    pub near: f32,
+    bake_version: u64,
+}
+
+impl LightProbeVolume {
+    /// Creates a volume of `resolution` probes along each axis of
+    /// `bounds`, with its first bake pending.
+    ///
+    /// Each axis has at least one probe; an axis with a single probe
+    /// places it at the centre of the box.
+    #[must_use]
+    pub fn new(bounds: BoundingBox, resolution: UVec3) -> Self {
+        Self {
+            bounds: BoundingBox {
+                min: bounds.min.min(bounds.max),
+                max: bounds.min.max(bounds.max),
+            },
+            resolution: resolution.max(UVec3::ONE),
+            intensity: 1.0,
+            blend_distance: 1.0,
+            capture_resolution: 32,
+            near: 0.05,
+            bake_version: NEXT_BAKE_VERSION.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn bounds(&self) -> BoundingBox {
+        self.bounds
+    }
+
+    /// Number of probes along each axis.
+    #[inline]
+    #[must_use]
+    pub fn resolution(&self) -> UVec3 {
+        self.resolution
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn probe_count(&self) -> usize {
+        (self.resolution.x * self.resolution.y * self.resolution.z) as usize
+    }
+
+    /// World position of the probe at `index`, with X varying fastest and
+    /// Z slowest, matching the layout of the baked coefficients.
+    #[must_use]
+    pub fn probe_position(&self, index: usize) -> Vec3 {
+        let index = index as u32;
+        let cell = UVec3::new(
+            index % self.resolution.x,
+            index / self.resolution.x % self.resolution.y,
+            index / (self.resolution.x * self.resolution.y),
+        );
+        let steps = (self.resolution - UVec3::ONE).max(UVec3::ONE).as_vec3();
+        let t = Vec3::select(
+            self.resolution.cmpgt(UVec3::ONE),
+            cell.as_vec3() / steps,
+            Vec3::splat(0.5),
+        );
+        self.bounds.min + self.bounds.size() * t
+    }
+
+    /// Requests a new bake the next time the scene is rendered.
+    pub fn bake(&mut self) {
+        self.bake_version = NEXT_BAKE_VERSION.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Version renewed by [`bake`](Self::bake). The renderer bakes the
+    /// volume again whenever it differs from the last baked value.
+    #[inline]
+    #[must_use]
+    pub fn bake_version(&self) -> u64 {
+        self.bake_version
+    }
+
+    /// Replaces the grid and requests a bake, keeping the other settings.
+    pub(crate) fn set_grid(&mut self, bounds: BoundingBox, resolution: UVec3) {
+        let grid = Self::new(bounds, resolution);
+        self.bounds = grid.bounds;
+        self.resolution = grid.resolution;
+        self.bake();
+    }
+}
@@ -0,0 +1,127 @@
+//! Distance-based level of detail.
+//!
+//! An [`Lod`] component swaps a node's mesh geometry according to its
+//! distance from the camera. Selection happens once per frame during render
+//! extraction, so every view (including shadow cascades) draws the same level.
+
+use myth_resources::GeometryHandle;
+
+/// One level of an [`Lod`] group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LodLevel {
+    /// Geometry drawn at this level. The mesh's material is kept.
+    pub geometry: GeometryHandle,
+    /// Camera distance from which this level is used.
+    pub min_distance: f32,
+}
+
+/// Level-of-detail group attached to a mesh node.
+///
+/// Levels are kept sorted by `min_distance`; the first level is used for
+/// everything closer than the second level's distance. Switching between
+/// levels is delayed by a hysteresis band around each threshold so objects
+/// hovering at a boundary don't pop back and forth every frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lod {
+    levels: Vec<LodLevel>,
+    /// Width of the switching band, as a fraction of each threshold.
+    ///
+    /// With `0.1`, a level starting at 100 is entered beyond 110 and left
+    /// again below 90.
+    pub hysteresis: f32,
+    /// Distance beyond which the node isn't drawn at all.
+    pub cull_distance: Option<f32>,
+    /// Index of the current level; `levels.len()` while culled.
+    current: usize,
+}
+
+impl Lod {
+    /// Creates a group from `(geometry, min_distance)` pairs, in any order.
+    #[must_use]
+    pub fn new(levels: impl IntoIterator<Item = (GeometryHandle, f32)>) -> Self {
+        let mut levels: Vec<LodLevel> = levels
+            .into_iter()
+            .map(|(geometry, min_distance)| LodLevel {
+                geometry,
+                min_distance,
+            })
+            .collect();
+        levels.sort_by(|a, b| a.min_distance.total_cmp(&b.min_distance));
+        Self {
+            levels,
+            hysteresis: 0.1,
+            cull_distance: None,
+            current: 0,
+        }
+    }
+
+    /// Sets the hysteresis band (builder).
+    #[must_use]
+    pub fn with_hysteresis(mut self, hysteresis: f32) -> Self {
+        self.hysteresis = hysteresis;
+        self
+    }
+
+    /// Stops drawing the node beyond `distance` (builder).
+    #[must_use]
+    pub fn with_cull_distance(mut self, distance: f32) -> Self {
+        self.cull_distance = Some(distance);
+        self
+    }
+
+    /// The levels, sorted by `min_distance`.
+    #[must_use]
+    pub fn levels(&self) -> &[LodLevel] {
+        &self.levels
+    }
+
+    /// The level selected by the last [`select`](Self::select), or `None`
+    /// while culled.
+    #[must_use]
+    pub fn current_level(&self) -> Option<usize> {
+        (self.current < self.levels.len()).then_some(self.current)
+    }
+
+    /// Geometry of the current level, or `None` while culled.
+    #[must_use]
+    pub fn current_geometry(&self) -> Option<GeometryHandle> {
+        self.levels.get(self.current).map(|level| level.geometry)
+    }
+
+    /// Picks the level for a squared camera distance, starting from the
+    /// previous selection so thresholds are crossed with hysteresis.
+    ///
+    /// Returns `None` when the node is beyond the cull distance.
+    pub fn select(&mut self, distance_sq: f32) -> Option<usize> {
+        let count = self.levels.len();
+        if count == 0 {
+            return None;
+        }
+        let distance = distance_sq.sqrt();
+
+        if let Some(cull_distance) = self.cull_distance {
+            let band = if self.current == count {
+                1.0 - self.hysteresis
+            } else {
+                1.0 + self.hysteresis
+            };
+            if distance >= cull_distance * band {
+                self.current = count;
+                return None;
+            }
+        }
+
+        let mut level = self.current.min(count - 1);
+        while level + 1 < count
+            && distance >= self.levels[level + 1].min_distance * (1.0 + self.hysteresis)
+        {
+            level += 1;
+        }
+        while level > 0 && distance < self.levels[level].min_distance * (1.0 - self.hysteresis) {
+            level -= 1;
+        }
+
+        self.current = level;
+        Some(level)
+    }
+}
@@ -1,6 +1,10 @@
 use glam::Affine3A;
 use myth_core::{NodeHandle, Transform};
 
+/// Layer mask with every layer enabled (the default for nodes, cameras and
+/// shadow-casting lights).
+pub const ALL_LAYERS: u32 = u32::MAX;
+
 /// A minimal scene node containing only essential hot data.
 ///
 /// # Design Principles
@@ -34,8 +38,18 @@ pub struct Node {
     pub transform: Transform,
 
     // === Core State ===
-    /// Visibility flag for culling
+    /// Authored visibility flag. Hiding a node also hides its descendants
+    /// (see [`Node::is_visible_in_hierarchy`]).
     pub visible: bool,
+    /// Effective visibility: `visible` and every ancestor's `visible`.
+    /// Recomputed by the hierarchy update.
+    pub(crate) visible_in_hierarchy: bool,
+    /// Layer membership bitmask (bit `n` = layer `n`).
+    ///
+    /// A mesh is drawn by a camera only if `layers & camera.layer_mask != 0`,
+    /// and casts shadows from a light only if
+    /// `layers & light.shadow_layers != 0`. Defaults to [`ALL_LAYERS`].
+    pub layers: u32,
 }
 
 impl Node {
@@ -47,6 +61,8 @@ impl Node {
             children: Vec::new(),
             transform: Transform::new(),
             visible: true,
+            visible_in_hierarchy: true,
+            layers: ALL_LAYERS,
         }
     }
 
@@ -64,6 +80,17 @@ impl Node {
         &self.children
     }
 
+    /// Returns `true` if this node and all of its ancestors are visible.
+    ///
+    /// This is the flag culling reads. It is refreshed by the scene's
+    /// hierarchy update, so changes to `visible` take effect on the next
+    /// [`Scene::update`](crate::Scene::update).
+    #[inline]
+    #[must_use]
+    pub fn is_visible_in_hierarchy(&self) -> bool {
+        self.visible_in_hierarchy
+    }
+
     /// Sets the parent of this node. Prefer using [`crate::Scene::attach`] which
     /// keeps both parent and child in sync. This is exposed for low-level
     /// construction (e.g., building hierarchies outside of a `Scene`).
@@ -80,6 +107,43 @@ impl Node {
         self.children.push(child);
     }
 
+    /// Adds this node to layer `layer` (0..32).
+    ///
+    /// Out-of-range layers are ignored with a warning.
+    #[inline]
+    pub fn enable_layer(&mut self, layer: u32) {
+        if layer >= 32 {
+            log::warn!("Node::enable_layer: layer {layer} is out of range (0..32)");
+            return;
+        }
+        self.layers |= 1 << layer;
+    }
+
+    /// Removes this node from layer `layer` (0..32).
+    ///
+    /// Out-of-range layers are ignored with a warning.
+    #[inline]
+    pub fn disable_layer(&mut self, layer: u32) {
+        if layer >= 32 {
+            log::warn!("Node::disable_layer: layer {layer} is out of range (0..32)");
+            return;
+        }
+        self.layers &= !(1 << layer);
+    }
+
+    /// Replaces the whole layer bitmask.
+    #[inline]
+    pub fn set_layers(&mut self, mask: u32) {
+        self.layers = mask;
+    }
+
+    /// Returns `true` if this node belongs to layer `layer`.
+    #[inline]
+    #[must_use]
+    pub fn is_in_layer(&self, layer: u32) -> bool {
+        layer < 32 && self.layers & (1 << layer) != 0
+    }
+
     /// Returns a reference to the world transformation matrix.
     ///
     /// This matrix transforms local coordinates to world coordinates.
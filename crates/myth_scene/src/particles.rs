@@ -0,0 +1,306 @@
+//! CPU particle systems.
+//!
+//! A [`ParticleSystem`] attached with
+//! [`Scene::set_particle_system`](crate::Scene::set_particle_system) is
+//! stepped by [`Scene::update`](crate::Scene::update): it emits particles at
+//! a steady rate, moves them under gravity and recycles their slots when
+//! their lifetime ends. Particles live in the node's local space, so they
+//! follow the node.
+//!
+//! Drawing is left to a point-sprite geometry:
+//! [`build_geometry`](ParticleSystem::build_geometry) creates one sized for
+//! the pool and [`write_geometry`](ParticleSystem::write_geometry) copies the
+//! live particles into it. `SceneExt::spawn_particles` in `myth_assets` sets
+//! both up with a `PointsMaterial`.
+
+use glam::{Vec3, Vec4};
+use myth_resources::geometry::{Attribute, Geometry, VertexFormat};
+use wgpu::PrimitiveTopology;
+
+/// A single live particle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Particle {
+    /// Position in the emitter node's local space.
+    pub position: Vec3,
+    /// Velocity in local units per second.
+    pub velocity: Vec3,
+    /// Seconds since the particle was emitted.
+    pub age: f32,
+}
+
+/// Emitter parameters and the particle pool of one effect.
+///
+/// ```rust,ignore
+/// let sparks = ParticleSystem::new(512)
+///     .with_rate(120.0)
+///     .with_lifetime(1.2)
+///     .with_velocity(Vec3::new(-1.0, 3.0, -1.0), Vec3::new(1.0, 5.0, 1.0))
+///     .with_colors(Vec4::new(1.0, 0.8, 0.3, 1.0), Vec4::new(1.0, 0.2, 0.0, 0.0))
+///     .with_sizes(0.15, 0.02);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ParticleSystem {
+    /// Particles emitted per second while [`emitting`](Self::emitting).
+    pub rate: f32,
+    /// Seconds a particle lives.
+    pub lifetime: f32,
+    /// Lower corner of the initial velocity box; each component is drawn
+    /// uniformly between `velocity_min` and `velocity_max`.
+    pub velocity_min: Vec3,
+    /// Upper corner of the initial velocity box.
+    pub velocity_max: Vec3,
+    /// Constant acceleration in local units per second squared.
+    pub gravity: Vec3,
+    /// Color (linear RGBA) at emission.
+    pub start_color: Vec4,
+    /// Color at the end of the lifetime, interpolated linearly.
+    pub end_color: Vec4,
+    /// Sprite size at emission, multiplied with the material size.
+    pub start_size: f32,
+    /// Sprite size at the end of the lifetime.
+    pub end_size: f32,
+    /// Whether new particles are emitted. Live particles keep simulating.
+    pub emitting: bool,
+
+    particles: Vec<Particle>,
+    capacity: usize,
+    emit_accumulator: f32,
+    rng_state: u32,
+}
+
+impl ParticleSystem {
+    /// Creates an emitter with room for `capacity` live particles.
+    ///
+    /// Defaults: 20 particles per second living 2 seconds, rising at 1–2
+    /// units per second with a little sideways spread, gravity of -2 on Y,
+    /// white fading to transparent, size 1.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            rate: 20.0,
+            lifetime: 2.0,
+            velocity_min: Vec3::new(-0.3, 1.0, -0.3),
+            velocity_max: Vec3::new(0.3, 2.0, 0.3),
+            gravity: Vec3::new(0.0, -2.0, 0.0),
+            start_color: Vec4::ONE,
+            end_color: Vec4::new(1.0, 1.0, 1.0, 0.0),
+            start_size: 1.0,
+            end_size: 1.0,
+            emitting: true,
+            particles: Vec::with_capacity(capacity),
+            capacity,
+            emit_accumulator: 0.0,
+            rng_state: 0x9E37_79B9,
+        }
+    }
+
+    /// Sets the emission rate in particles per second (builder).
+    #[must_use]
+    pub fn with_rate(mut self, rate: f32) -> Self {
+        self.rate = rate;
+        self
+    }
+
+    /// Sets the particle lifetime in seconds (builder).
+    #[must_use]
+    pub fn with_lifetime(mut self, lifetime: f32) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    /// Sets the initial velocity range (builder).
+    #[must_use]
+    pub fn with_velocity(mut self, min: Vec3, max: Vec3) -> Self {
+        self.velocity_min = min;
+        self.velocity_max = max;
+        self
+    }
+
+    /// Sets the constant acceleration (builder).
+    #[must_use]
+    pub fn with_gravity(mut self, gravity: Vec3) -> Self {
+        self.gravity = gravity;
+        self
+    }
+
+    /// Sets the start and end colors (builder).
+    #[must_use]
+    pub fn with_colors(mut self, start: Vec4, end: Vec4) -> Self {
+        self.start_color = start;
+        self.end_color = end;
+        self
+    }
+
+    /// Sets the start and end sizes (builder).
+    #[must_use]
+    pub fn with_sizes(mut self, start: f32, end: f32) -> Self {
+        self.start_size = start;
+        self.end_size = end;
+        self
+    }
+
+    /// Seeds the random generator used for initial velocities (builder),
+    /// so two systems with the same seed and inputs behave identically.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.rng_state = seed.max(1);
+        self
+    }
+
+    /// Maximum number of live particles.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Live particles, in no particular order.
+    #[inline]
+    #[must_use]
+    pub fn particles(&self) -> &[Particle] {
+        &self.particles
+    }
+
+    /// Number of live particles.
+    #[inline]
+    #[must_use]
+    pub fn alive_count(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Removes every live particle.
+    pub fn clear(&mut self) {
+        self.particles.clear();
+        self.emit_accumulator = 0.0;
+    }
+
+    /// Emits up to `count` particles at once (e.g. an explosion), limited by
+    /// the free capacity. Returns how many were emitted.
+    pub fn burst(&mut self, count: usize) -> usize {
+        let count = count.min(self.capacity - self.particles.len());
+        for _ in 0..count {
+            let velocity = Vec3::new(
+                self.random_between(self.velocity_min.x, self.velocity_max.x),
+                self.random_between(self.velocity_min.y, self.velocity_max.y),
+                self.random_between(self.velocity_min.z, self.velocity_max.z),
+            );
+            self.particles.push(Particle {
+                position: Vec3::ZERO,
+                velocity,
+                age: 0.0,
+            });
+        }
+        count
+    }
+
+    /// Advances the simulation by `dt` seconds.
+    ///
+    /// Ages and integrates the live particles, drops those past their
+    /// lifetime (freeing their slots) and then emits new ones at
+    /// [`rate`](Self::rate).
+    pub fn update(&mut self, dt: f32) {
+        if dt <= 0.0 {
+            return;
+        }
+
+        let lifetime = self.lifetime;
+        let gravity = self.gravity;
+        self.particles.retain_mut(|p| {
+            p.age += dt;
+            if p.age >= lifetime {
+                return false;
+            }
+            p.velocity += gravity * dt;
+            p.position += p.velocity * dt;
+            true
+        });
+
+        if self.emitting {
+            self.emit_accumulator += self.rate.max(0.0) * dt;
+            let count = self.emit_accumulator.floor();
+            self.emit_accumulator -= count;
+            self.burst(count as usize);
+        } else {
+            self.emit_accumulator = 0.0;
+        }
+    }
+
+    /// Color of a particle at `age` seconds.
+    #[must_use]
+    pub fn color_at(&self, age: f32) -> Vec4 {
+        self.start_color.lerp(self.end_color, self.progress(age))
+    }
+
+    /// Size of a particle at `age` seconds.
+    #[must_use]
+    pub fn size_at(&self, age: f32) -> f32 {
+        let t = self.progress(age);
+        self.start_size + (self.end_size - self.start_size) * t
+    }
+
+    /// Creates a point-list geometry with one vertex per particle slot and
+    /// `position`, `color` and `size` attributes, ready for
+    /// [`write_geometry`](Self::write_geometry).
+    #[must_use]
+    pub fn build_geometry(&self) -> Geometry {
+        let capacity = self.capacity.max(1);
+        let mut geometry = Geometry::new();
+        geometry.topology = PrimitiveTopology::PointList;
+        geometry.set_attribute(
+            "position",
+            Attribute::new_planar(&vec![Vec3::ZERO; capacity], VertexFormat::Float32x3),
+        );
+        geometry.set_attribute(
+            "color",
+            Attribute::new_planar(&vec![Vec4::ZERO; capacity], VertexFormat::Float32x4),
+        );
+        geometry.set_attribute(
+            "size",
+            Attribute::new_planar(&vec![0.0f32; capacity], VertexFormat::Float32),
+        );
+        self.write_geometry(&mut geometry);
+        geometry
+    }
+
+    /// Copies the live particles into a geometry made by
+    /// [`build_geometry`](Self::build_geometry).
+    ///
+    /// Live particles fill the first slots; the rest get size 0 at the
+    /// origin, so they draw nothing. The bounding volume follows the
+    /// particles.
+    pub fn write_geometry(&self, geometry: &mut Geometry) {
+        let capacity = self.capacity.max(1);
+        let mut positions = vec![Vec3::ZERO; capacity];
+        let mut colors = vec![Vec4::ZERO; capacity];
+        let mut sizes = vec![0.0f32; capacity];
+        for (i, p) in self.particles.iter().enumerate() {
+            positions[i] = p.position;
+            colors[i] = self.color_at(p.age);
+            sizes[i] = self.size_at(p.age);
+        }
+
+        geometry.update_attribute_region("color", 0, &colors);
+        geometry.update_attribute_region("size", 0, &sizes);
+        // Last, since updating positions recomputes the bounding volume.
+        geometry.update_attribute_region("position", 0, &positions);
+    }
+
+    fn progress(&self, age: f32) -> f32 {
+        if self.lifetime > 0.0 {
+            (age / self.lifetime).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// xorshift32, mapped to `[min, max]`.
+    fn random_between(&mut self, min: f32, max: f32) -> f32 {
+        let mut x = self.rng_state;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.rng_state = x;
+        let t = (x >> 8) as f32 / (1u32 << 24) as f32;
+        min + (max - min) * t
+    }
+}
@@ -0,0 +1,98 @@
+//! Reflection probes.
+//!
+//! A node with a [`ReflectionProbe`] captures the scene around its world
+//! position into a cubemap, which the renderer prefilters like the scene
+//! environment map. PBR materials within the probe's radius use it for
+//! their image-based lighting instead of the global environment, which
+//! gives indoor areas and other enclosed spaces local reflections.
+//!
+//! A probe is captured once when it is first rendered, and again after each
+//! call to [`ReflectionProbe::capture`]. A capture renders the scene six
+//! times, so probes suit static or slowly changing surroundings. Probes are
+//! not bound while a probe is captured, so captures reflect the global
+//! environment rather than other probes.
+//!
+//! The renderer binds one probe per frame: the captured probe whose sphere
+//! contains the camera, or the nearest one. Within that probe's radius,
+//! surfaces blend from the probe to the global environment over
+//! [`blend_distance`](ReflectionProbe::blend_distance). Lookups are not
+//! parallax corrected: reflections are correct at the probe's position and
+//! drift slightly away from it. Only the `HighFidelity` path captures
+//! probes.
+
+/// A cubemap captured at its node's position for local reflections.
+///
+/// ```rust,ignore
+/// let probe = scene.add_reflection_probe(Vec3::new(0.0, 1.5, 0.0), 256);
+/// scene.reflection_probe_mut(probe).unwrap().radius = 6.0;
+///
+/// // Later, after the room has changed:
+/// scene.reflection_probe_mut(probe).unwrap().capture();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReflectionProbe {
+    /// Edge length in pixels of each captured cube face.
+    pub resolution: u32,
+    /// Radius of the sphere of influence around the probe.
+    pub radius: f32,
+    /// Width of the band at the edge of the sphere over which the probe
+    /// fades into the global environment.
+    pub blend_distance: f32,
+    /// Multiplier applied to the captured lighting.
+    pub intensity: f32,
+    /// Near clip plane of the capture cameras.
+    pub near: f32,
+    capture_version: u64,
+}
+
+impl ReflectionProbe {
+    /// Creates a probe capturing `resolution`² pixels per cube face.
+    ///
+    /// The first capture is pending, so the probe is captured the next time
+    /// its scene is rendered.
+    #[must_use]
+    pub fn new(resolution: u32) -> Self {
+        Self {
+            resolution: resolution.max(1),
+            radius: 10.0,
+            blend_distance: 2.0,
+            intensity: 1.0,
+            near: 0.05,
+            capture_version: 1,
+        }
+    }
+
+    /// Sets the radius of influence (builder).
+    #[must_use]
+    pub fn with_radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Sets the width of the fade into the global environment (builder).
+    #[must_use]
+    pub fn with_blend_distance(mut self, blend_distance: f32) -> Self {
+        self.blend_distance = blend_distance;
+        self
+    }
+
+    /// Sets the intensity multiplier (builder).
+    #[must_use]
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
+
+    /// Requests a new capture the next time the scene is rendered.
+    pub fn capture(&mut self) {
+        self.capture_version = self.capture_version.wrapping_add(1);
+    }
+
+    /// Counter bumped by [`capture`](Self::capture). The renderer captures
+    /// the probe again whenever it differs from the last captured value.
+    #[inline]
+    #[must_use]
+    pub fn capture_version(&self) -> u64 {
+        self.capture_version
+    }
+}
@@ -2,10 +2,13 @@ use std::borrow::Cow;
 use std::sync::atomic::{AtomicU32, Ordering};
 
 use myth_animation::{AnimationMixer, AnimationTarget};
-use myth_core::{NodeHandle, SkeletonKey, Transform};
+use myth_core::{MAX_MORPH_TARGETS, NodeHandle, SceneError, SkeletonKey, Transform};
+use myth_resources::BoundingBox;
 #[cfg(feature = "3dgs")]
 use myth_resources::GaussianCloudHandle;
+use myth_resources::GeometryHandle;
 use myth_resources::Input;
+use myth_resources::TextureHandle;
 use myth_resources::bloom::BloomSettings;
 use myth_resources::buffer::CpuBuffer;
 use myth_resources::mesh::Mesh;
@@ -13,22 +16,37 @@ use myth_resources::screen_space::ScreenSpaceSettings;
 use myth_resources::shader_defines::ShaderDefines;
 use myth_resources::ssao::SsaoSettings;
 use myth_resources::tone_mapping::ToneMappingSettings;
-use myth_resources::uniforms::{EnvironmentUniforms, GpuLightStorage};
+use myth_resources::uniforms::{
+    EnvironmentUniforms, GpuLightProbe, GpuLightStorage, Mat3Uniform, ObjectOverride,
+};
 
-use crate::background::{BackgroundMode, BackgroundSettings};
+use crate::background::{BackgroundMode, BackgroundSettings, ProceduralSkyParams};
+use crate::billboard::BillboardMode;
 use crate::camera::Camera;
+use crate::debug_draw::{DebugDraw, DebugVertex, GridOptions};
+use crate::decal::{Decal, DecalOptions};
 use crate::environment::Environment;
+use crate::ground_shadow::GroundShadow;
+use crate::ik::TwoBoneIk;
 use crate::light::Light;
-use crate::light::LightKind;
+use crate::light::{LightKind, LightUnits};
+use crate::light_probe::LightProbeVolume;
+use crate::lod::Lod;
 use crate::node::Node;
+use crate::particles::ParticleSystem;
+use crate::reflection_probe::ReflectionProbe;
 use crate::skeleton::{BindMode, Skeleton, SkinBinding};
 use crate::transform_system;
 use crate::wrapper::SceneNode;
-use glam::{Affine3A, Quat, Vec3};
+use glam::{Affine3A, Quat, UVec3, Vec3, Vec3A, Vec4};
+use rustc_hash::FxHashMap;
 use slotmap::{SecondaryMap, SlotMap, SparseSecondaryMap};
 
 static NEXT_SCENE_ID: AtomicU32 = AtomicU32::new(1);
 
+/// Line color of the frustums drawn by [`Scene::show_camera_helper`].
+const CAMERA_HELPER_COLOR: Vec4 = Vec4::new(1.0, 0.6, 0.0, 1.0);
+
 /// Trait for scene update logic.
 ///
 /// Allows users to define custom behavior scripts that update
@@ -70,6 +88,36 @@ where
 #[derive(Debug, Clone, Copy, Default)]
 pub struct SplitPrimitiveTag;
 
+/// How the renderer composites blended (transparent) materials.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum TransparencyMode {
+    /// Draw transparent objects back-to-front with alpha blending.
+    ///
+    /// Exact for non-intersecting objects, but sorting is per object, so
+    /// overlapping or self-intersecting geometry can show ordering errors.
+    #[default]
+    Sorted,
+    /// Weighted blended order-independent transparency (McGuire & Bavoil).
+    ///
+    /// Blended materials accumulate into weighted colour and revealage
+    /// targets which are resolved in a single composite pass, so the result
+    /// does not depend on draw order. Only the `HighFidelity` render path
+    /// supports it; `BasicForward` falls back to [`Sorted`](Self::Sorted).
+    WeightedBlended,
+}
+
+/// How a node's morph weights are adjusted before they reach its meshes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum MorphNormalization {
+    /// Weights are uploaded as set.
+    #[default]
+    None,
+    /// When the absolute weights sum to more than `1.0`, all weights are
+    /// scaled down so they sum to `1.0`. Keeps overlapping blend shapes
+    /// (e.g. two mouth shapes moving the same vertices) from over-deforming.
+    Normalize,
+}
+
 /// The scene graph container.
 ///
 /// Scene is the pure data layer that stores scene graph hierarchy and component data.
@@ -106,8 +154,11 @@ pub struct Scene {
     root_nodes: Vec<NodeHandle>,
 
     // === Dense Components (most nodes have these) ===
-    /// Node names - almost all nodes have a name
-    pub names: SecondaryMap<NodeHandle, Cow<'static, str>>,
+    /// Node names - almost all nodes have a name.
+    /// Private so that [`name_index`](Self::name_index) stays in sync.
+    names: SecondaryMap<NodeHandle, Cow<'static, str>>,
+    /// Name → nodes with that name, in naming order
+    name_index: FxHashMap<String, Vec<NodeHandle>>,
 
     // === Sparse Components (only some nodes have these) ===
     /// Mesh components stored directly on nodes
@@ -120,11 +171,31 @@ pub struct Scene {
     pub skins: SparseSecondaryMap<NodeHandle, SkinBinding>,
     /// Morph target weights
     pub morph_weights: SparseSecondaryMap<NodeHandle, Vec<f32>>,
+    /// Morph weight normalization, edited through [`set_morph_normalization`](Self::set_morph_normalization)
+    morph_normalization: SparseSecondaryMap<NodeHandle, MorphNormalization>,
     /// Animation mixer components (sparse, only character roots have animations)
     pub animation_mixers: SparseSecondaryMap<NodeHandle, AnimationMixer>,
     /// Rest pose transforms recorded before animation takes over.
     /// Used to restore nodes when animations stop or blend with weight < 1.0.
     pub rest_transforms: SparseSecondaryMap<NodeHandle, Transform>,
+    /// User-defined tags, edited through [`add_tag`](Self::add_tag) / [`remove_tag`](Self::remove_tag)
+    tags: SparseSecondaryMap<NodeHandle, Vec<String>>,
+    /// Tag → nodes carrying it, in tagging order
+    tag_index: FxHashMap<String, Vec<NodeHandle>>,
+    /// Per-node material overrides, edited through [`set_node_tint`](Self::set_node_tint) / [`set_node_param`](Self::set_node_param)
+    pub object_overrides: SparseSecondaryMap<NodeHandle, ObjectOverride>,
+    /// Level-of-detail groups driving the node's mesh geometry
+    pub lods: SparseSecondaryMap<NodeHandle, Lod>,
+    /// Nodes turned toward the active camera every frame
+    pub billboards: SparseSecondaryMap<NodeHandle, BillboardMode>,
+    /// CPU particle emitters, stepped every frame
+    pub particle_systems: SparseSecondaryMap<NodeHandle, ParticleSystem>,
+    /// Texture projectors applied after the opaque pass
+    pub decals: SparseSecondaryMap<NodeHandle, Decal>,
+    /// Cubemap captures used for local reflections
+    pub reflection_probes: SparseSecondaryMap<NodeHandle, ReflectionProbe>,
+    /// Two-bone IK chains, keyed by their tip node
+    pub ik_chains: SparseSecondaryMap<NodeHandle, TwoBoneIk>,
     /// Split primitive tags
     pub split_primitive_tags: SparseSecondaryMap<NodeHandle, SplitPrimitiveTag>,
     #[cfg(feature = "3dgs")]
@@ -148,14 +219,36 @@ pub struct Scene {
     pub screen_space: ScreenSpaceSettings,
     /// Background rendering settings (mode + skybox uniform buffer)
     pub background: BackgroundSettings,
+    /// How blended materials are composited
+    pub transparency_mode: TransparencyMode,
     /// Currently active camera for rendering
     pub active_camera: Option<NodeHandle>,
+    /// Directional light whose orientation drives the procedural sky's sun
+    sky_sun_light: Option<NodeHandle>,
+    /// Immediate-mode debug lines for the current frame
+    debug_draw: DebugDraw,
+    /// Whether light helpers are added to the debug lines each frame
+    light_helpers: bool,
+    /// Camera nodes whose frustum is drawn each frame
+    camera_helpers: SparseSecondaryMap<NodeHandle, ()>,
+    /// Ground grid drawn by the renderer, if shown
+    grid: Option<GridOptions>,
+    /// Blob shadow under the scene's meshes, if enabled
+    ground_shadow: Option<GroundShadow>,
+    /// Whether the renderer skips meshes hidden by others last frame
+    occlusion_culling: bool,
+    /// Baked irradiance probe grid, if any
+    light_probes: Option<LightProbeVolume>,
 
     // === GPU Resource Descriptors ===
     #[doc(hidden)]
     pub light_storage_buffer: CpuBuffer<Vec<GpuLightStorage>>,
     #[doc(hidden)]
     pub uniforms_buffer: CpuBuffer<EnvironmentUniforms>,
+    #[doc(hidden)]
+    pub light_probe_buffer: CpuBuffer<Vec<GpuLightProbe>>,
+    #[doc(hidden)]
+    pub light_cluster_buffer: CpuBuffer<Vec<u32>>,
     light_data_cache: Vec<GpuLightStorage>,
 
     shader_defines: ShaderDefines,
@@ -182,6 +275,7 @@ impl Scene {
 
             // Dense components
             names: SecondaryMap::new(),
+            name_index: FxHashMap::default(),
 
             // Sparse components (direct storage)
             meshes: SparseSecondaryMap::new(),
@@ -189,9 +283,19 @@ impl Scene {
             lights: SparseSecondaryMap::new(),
             skins: SparseSecondaryMap::new(),
             morph_weights: SparseSecondaryMap::new(),
+            morph_normalization: SparseSecondaryMap::new(),
             animation_mixers: SparseSecondaryMap::new(),
             rest_transforms: SparseSecondaryMap::new(),
 
+            tags: SparseSecondaryMap::new(),
+            tag_index: FxHashMap::default(),
+            object_overrides: SparseSecondaryMap::new(),
+            lods: SparseSecondaryMap::new(),
+            billboards: SparseSecondaryMap::new(),
+            particle_systems: SparseSecondaryMap::new(),
+            decals: SparseSecondaryMap::new(),
+            reflection_probes: SparseSecondaryMap::new(),
+            ik_chains: SparseSecondaryMap::new(),
             split_primitive_tags: SparseSecondaryMap::new(),
             #[cfg(feature = "3dgs")]
             gaussian_clouds: SparseSecondaryMap::new(),
@@ -205,8 +309,17 @@ impl Scene {
             ssao: SsaoSettings::default(),
             screen_space: ScreenSpaceSettings::default(),
             background: BackgroundSettings::default(),
+            transparency_mode: TransparencyMode::default(),
 
             active_camera: None,
+            sky_sun_light: None,
+            debug_draw: DebugDraw::new(),
+            light_helpers: false,
+            camera_helpers: SparseSecondaryMap::new(),
+            grid: None,
+            ground_shadow: None,
+            occlusion_culling: false,
+            light_probes: None,
 
             light_storage_buffer: CpuBuffer::new(
                 [GpuLightStorage::default(); 16].to_vec(),
@@ -218,6 +331,16 @@ impl Scene {
                 wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
                 Some("SceneEnvironmentUniforms"),
             ),
+            light_probe_buffer: CpuBuffer::new(
+                vec![GpuLightProbe::default()],
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+                Some("SceneLightProbeBuffer"),
+            ),
+            light_cluster_buffer: CpuBuffer::new(
+                vec![0],
+                wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+                Some("SceneLightClusterBuffer"),
+            ),
 
             light_data_cache: Vec::with_capacity(16),
 
@@ -270,7 +393,7 @@ impl Scene {
     /// Creates a new node with a name.
     pub fn create_node_with_name(&mut self, name: &str) -> NodeHandle {
         let handle = self.nodes.insert(Node::new());
-        self.names.insert(handle, Cow::Owned(name.to_string()));
+        self.set_name(handle, name);
         handle
     }
 
@@ -320,14 +443,164 @@ impl Scene {
             self.lights.remove(node_handle);
             self.skins.remove(node_handle);
             self.morph_weights.remove(node_handle);
-            self.names.remove(node_handle);
+            self.morph_normalization.remove(node_handle);
+            self.unindex_name(node_handle);
+            self.unindex_tags(node_handle);
             self.animation_mixers.remove(node_handle);
             self.rest_transforms.remove(node_handle);
+            self.object_overrides.remove(node_handle);
+            self.lods.remove(node_handle);
+            self.billboards.remove(node_handle);
+            self.particle_systems.remove(node_handle);
+            self.decals.remove(node_handle);
+            self.reflection_probes.remove(node_handle);
+            self.ik_chains.remove(node_handle);
+            self.camera_helpers.remove(node_handle);
 
             self.nodes.remove(node_handle);
         }
     }
 
+    /// Deep-copies `root` and all its descendants, returning the new root.
+    ///
+    /// The copy is attached to the same parent as `root` (or becomes a root
+    /// node) and gets copies of every node's transform, visibility, layers,
+    /// name, tags and components. Mesh geometry and material handles are
+    /// shared; see `SceneExt::clone_subtree_with_materials` in `myth_assets`
+    /// for duplicating materials as well.
+    ///
+    /// Skeletons whose bones all lie inside the subtree are duplicated so the
+    /// copy animates independently; skeletons referencing bones outside it
+    /// are shared. Animation mixers are copied with their rig retargeted to
+    /// the new nodes. Lights get a fresh identity.
+    ///
+    /// Returns `None` if `root` does not exist.
+    pub fn clone_subtree(&mut self, root: NodeHandle) -> Option<NodeHandle> {
+        let root_parent = self.nodes.get(root)?.parent;
+
+        let mut sources = Vec::new();
+        self.collect_subtree(root, &mut sources);
+
+        // 1. Create the nodes
+        let mut map: FxHashMap<NodeHandle, NodeHandle> = FxHashMap::default();
+        for &src in &sources {
+            let mut node = self.nodes[src].clone();
+            node.parent = None;
+            node.children.clear();
+            map.insert(src, self.nodes.insert(node));
+        }
+
+        // 2. Rebuild the hierarchy
+        for &src in &sources {
+            let dst = map[&src];
+            let children: Vec<NodeHandle> =
+                self.nodes[src].children.iter().map(|c| map[c]).collect();
+            for &child in &children {
+                self.nodes[child].parent = Some(dst);
+            }
+            self.nodes[dst].children = children;
+        }
+        let new_root = map[&root];
+        match root_parent {
+            Some(parent) => {
+                self.nodes[new_root].parent = Some(parent);
+                self.nodes[parent].children.push(new_root);
+            }
+            None => self.root_nodes.push(new_root),
+        }
+        self.nodes[new_root].transform.mark_dirty();
+
+        // 3. Duplicate skeletons fully contained in the subtree
+        let mut skeletons: FxHashMap<SkeletonKey, SkeletonKey> = FxHashMap::default();
+        for &src in &sources {
+            let Some(skin) = self.skins.get(src) else {
+                continue;
+            };
+            if skeletons.contains_key(&skin.skeleton) {
+                continue;
+            }
+            let key = match self.skeleton_pool.get(skin.skeleton) {
+                Some(skeleton) if skeleton.bones.iter().all(|b| map.contains_key(b)) => {
+                    let bones = skeleton.bones.iter().map(|b| map[b]).collect();
+                    let mut copy = Skeleton::new(
+                        &skeleton.name,
+                        bones,
+                        skeleton.inverse_bind_matrices.clone(),
+                        skeleton.root_bone_index,
+                    );
+                    copy.local_bounds.clone_from(&skeleton.local_bounds);
+                    self.skeleton_pool.insert(copy)
+                }
+                _ => skin.skeleton,
+            };
+            skeletons.insert(skin.skeleton, key);
+        }
+
+        // 4. Copy components
+        for &src in &sources {
+            let dst = map[&src];
+            if let Some(name) = self.names.get(src).cloned() {
+                self.set_name(dst, &name);
+            }
+            for tag in self.tags(src).to_vec() {
+                self.add_tag(dst, &tag);
+            }
+            if let Some(mesh) = self.meshes.get(src).cloned() {
+                self.meshes.insert(dst, mesh);
+            }
+            if let Some(camera) = self.cameras.get(src).cloned() {
+                self.cameras.insert(dst, camera);
+            }
+            if let Some(light) = self.lights.get(src).map(Light::duplicate) {
+                self.lights.insert(dst, light);
+            }
+            if let Some(mut skin) = self.skins.get(src).cloned() {
+                skin.skeleton = skeletons[&skin.skeleton];
+                self.skins.insert(dst, skin);
+            }
+            if let Some(weights) = self.morph_weights.get(src).cloned() {
+                self.morph_weights.insert(dst, weights);
+            }
+            if let Some(mode) = self.morph_normalization.get(src).copied() {
+                self.morph_normalization.insert(dst, mode);
+            }
+            if let Some(mixer) = self.animation_mixers.get(src) {
+                let mixer = mixer.clone_remapped(|handle| map.get(&handle).copied());
+                self.animation_mixers.insert(dst, mixer);
+            }
+            if let Some(rest) = self.rest_transforms.get(src).copied() {
+                self.rest_transforms.insert(dst, rest);
+            }
+            if let Some(overrides) = self.object_overrides.get(src).copied() {
+                self.object_overrides.insert(dst, overrides);
+            }
+            if let Some(lod) = self.lods.get(src).cloned() {
+                self.lods.insert(dst, lod);
+            }
+            if let Some(mode) = self.billboards.get(src).copied() {
+                self.billboards.insert(dst, mode);
+            }
+            if let Some(system) = self.particle_systems.get(src).cloned() {
+                self.particle_systems.insert(dst, system);
+            }
+            if let Some(decal) = self.decals.get(src).copied() {
+                self.decals.insert(dst, decal);
+            }
+            if let Some(probe) = self.reflection_probes.get(src).copied() {
+                self.reflection_probes.insert(dst, probe);
+            }
+            if self.split_primitive_tags.contains_key(src) {
+                self.split_primitive_tags.insert(dst, SplitPrimitiveTag);
+            }
+            #[cfg(feature = "3dgs")]
+            if let Some(cloud) = self.gaussian_clouds.get(src).cloned() {
+                self.gaussian_clouds.insert(dst, cloud);
+            }
+        }
+
+        Some(new_root)
+    }
+
     /// Collects all nodes in a subtree (depth-first).
     fn collect_subtree(&self, handle: NodeHandle, result: &mut Vec<NodeHandle>) {
         result.push(handle);
@@ -372,6 +645,207 @@ impl Scene {
         }
     }
 
+    /// Moves `child` under `new_parent` (or to the scene root for `None`)
+    /// while keeping its world transform unchanged.
+    ///
+    /// Shorthand for [`set_parent`](Self::set_parent) with
+    /// `keep_world_transform = true`. Returns `false` (and leaves the
+    /// hierarchy untouched) if either node does not exist or `new_parent` is
+    /// `child` or one of its descendants.
+    pub fn reparent(&mut self, child: NodeHandle, new_parent: Option<NodeHandle>) -> bool {
+        match self.set_parent(child, new_parent, true) {
+            Ok(()) => true,
+            Err(err) => {
+                log::warn!("Cannot reparent node: {err}");
+                false
+            }
+        }
+    }
+
+    /// Moves `child` under `new_parent` (or to the scene root for `None`).
+    ///
+    /// The child is removed from its old parent's children (or the root
+    /// list) and appended to the new one. With `keep_world_transform` the
+    /// local TRS is recomputed relative to the new parent so the node stays
+    /// put in world space; under non-uniformly scaled, rotated parents the
+    /// result is the closest TRS approximation, since such a world matrix may
+    /// contain shear. Otherwise the local transform is kept and the node
+    /// moves with its new parent. Either way the child is marked dirty, so the
+    /// next hierarchy update refreshes its whole subtree.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SceneError::NodeNotFound`] if either node does not exist,
+    /// and [`SceneError::HierarchyCycle`] if `new_parent` is `child` or one
+    /// of its descendants. The hierarchy is left untouched in both cases.
+    pub fn set_parent(
+        &mut self,
+        child: NodeHandle,
+        new_parent: Option<NodeHandle>,
+        keep_world_transform: bool,
+    ) -> Result<(), SceneError> {
+        if !self.nodes.contains_key(child) {
+            return Err(SceneError::NodeNotFound);
+        }
+        if let Some(parent) = new_parent {
+            if !self.nodes.contains_key(parent) {
+                return Err(SceneError::NodeNotFound);
+            }
+            if self.is_ancestor_or_self(child, parent) {
+                return Err(SceneError::HierarchyCycle);
+            }
+        }
+
+        let local = keep_world_transform.then(|| {
+            let world = self
+                .compute_world_matrix(child)
+                .unwrap_or(Affine3A::IDENTITY);
+            let parent_world = new_parent
+                .and_then(|p| self.compute_world_matrix(p))
+                .unwrap_or(Affine3A::IDENTITY);
+            parent_world.inverse() * world
+        });
+
+        match new_parent {
+            Some(parent) => self.attach(child, parent),
+            None => {
+                if let Some(old_parent) = self.nodes[child].parent.take() {
+                    if let Some(parent) = self.nodes.get_mut(old_parent) {
+                        parent.children.retain(|&h| h != child);
+                    }
+                    self.root_nodes.push(child);
+                }
+            }
+        }
+
+        let transform = &mut self.nodes[child].transform;
+        if let Some(local) = local {
+            let (scale, rotation, position) = local.to_scale_rotation_translation();
+            transform.position = position;
+            transform.rotation = rotation.normalize();
+            transform.scale = scale;
+        }
+        transform.mark_dirty();
+        Ok(())
+    }
+
+    /// Sets the node's position in world space, converting it into the
+    /// parent's local space.
+    pub fn set_world_position(&mut self, handle: NodeHandle, position: Vec3) {
+        let Some(node) = self.nodes.get(handle) else {
+            return;
+        };
+        let parent_world = node
+            .parent
+            .and_then(|p| self.compute_world_matrix(p))
+            .unwrap_or(Affine3A::IDENTITY);
+        let local = parent_world.inverse().transform_point3(position);
+        self.nodes[handle].transform.set_position(local);
+    }
+
+    /// Sets the node's rotation in world space, converting it into the
+    /// parent's local space.
+    pub fn set_world_rotation(&mut self, handle: NodeHandle, rotation: Quat) {
+        let Some(node) = self.nodes.get(handle) else {
+            return;
+        };
+        let parent_rotation = node
+            .parent
+            .and_then(|p| self.compute_world_matrix(p))
+            .map_or(Quat::IDENTITY, |m| m.to_scale_rotation_translation().1);
+        let transform = &mut self.nodes[handle].transform;
+        transform.rotation = (parent_rotation.inverse() * rotation).normalize();
+        transform.mark_dirty();
+    }
+
+    /// Places the node at `world` in world space, converting it into the
+    /// parent's local space (root nodes take it as their local transform).
+    ///
+    /// The local TRS is decomposed from `parent_world⁻¹ · world`; under
+    /// non-uniformly scaled, rotated parents this is the closest TRS
+    /// approximation, since such a matrix may contain shear. The node is
+    /// marked dirty, so the next hierarchy update refreshes its world matrix
+    /// and those of its descendants.
+    pub fn set_world_transform(&mut self, handle: NodeHandle, world: Affine3A) {
+        let Some(node) = self.nodes.get(handle) else {
+            return;
+        };
+        let local = match node.parent.and_then(|p| self.compute_world_matrix(p)) {
+            Some(parent_world) => parent_world.inverse() * world,
+            None => world,
+        };
+
+        let (scale, rotation, position) = local.to_scale_rotation_translation();
+        let transform = &mut self.nodes[handle].transform;
+        transform.position = position;
+        transform.rotation = rotation.normalize();
+        transform.scale = scale;
+        transform.mark_dirty();
+    }
+
+    /// Returns the node's world transform.
+    ///
+    /// Computed from the current local transforms like
+    /// [`compute_world_matrix`](Self::compute_world_matrix), so it already
+    /// reflects [`set_world_transform`](Self::set_world_transform) and other
+    /// edits made this frame.
+    #[must_use]
+    pub fn world_transform(&self, handle: NodeHandle) -> Option<Affine3A> {
+        self.compute_world_matrix(handle)
+    }
+
+    /// Computes a node's world matrix from the current local TRS of it and
+    /// its ancestors.
+    ///
+    /// Unlike [`Node::world_matrix`], this does not depend on the last
+    /// hierarchy update, so it reflects edits made earlier in the frame.
+    #[must_use]
+    pub fn compute_world_matrix(&self, handle: NodeHandle) -> Option<Affine3A> {
+        let mut node = self.nodes.get(handle)?;
+        let local = |n: &Node| {
+            let t = &n.transform;
+            Affine3A::from_scale_rotation_translation(t.scale, t.rotation, t.position)
+        };
+        let mut world = local(node);
+        while let Some(parent) = node.parent.and_then(|p| self.nodes.get(p)) {
+            world = local(parent) * world;
+            node = parent;
+        }
+        Some(world)
+    }
+
+    /// Returns `true` if the node and all of its ancestors are visible.
+    ///
+    /// Unlike [`Node::is_visible_in_hierarchy`], this walks the ancestors
+    /// instead of reading the flag cached by the last hierarchy update.
+    /// Returns `false` for a missing node.
+    #[must_use]
+    pub fn is_visible_in_hierarchy(&self, handle: NodeHandle) -> bool {
+        let mut current = Some(handle);
+        while let Some(h) = current {
+            let Some(node) = self.nodes.get(h) else {
+                return false;
+            };
+            if !node.visible {
+                return false;
+            }
+            current = node.parent;
+        }
+        true
+    }
+
+    /// Returns `true` if `ancestor` is `handle` or one of its ancestors.
+    fn is_ancestor_or_self(&self, ancestor: NodeHandle, handle: NodeHandle) -> bool {
+        let mut current = Some(handle);
+        while let Some(h) = current {
+            if h == ancestor {
+                return true;
+            }
+            current = self.nodes.get(h).and_then(|n| n.parent);
+        }
+        false
+    }
+
     /// Returns a read-only reference to a node.
     #[inline]
     pub fn get_node(&self, handle: NodeHandle) -> Option<&Node> {
@@ -390,7 +864,28 @@ impl Scene {
 
     /// Sets the name for a node.
     pub fn set_name(&mut self, handle: NodeHandle, name: &str) {
+        if !self.nodes.contains_key(handle) {
+            return;
+        }
+        self.unindex_name(handle);
         self.names.insert(handle, Cow::Owned(name.to_string()));
+        self.name_index
+            .entry(name.to_string())
+            .or_default()
+            .push(handle);
+    }
+
+    /// Removes `handle` from the name storage and index.
+    fn unindex_name(&mut self, handle: NodeHandle) {
+        let Some(old) = self.names.remove(handle) else {
+            return;
+        };
+        if let Some(handles) = self.name_index.get_mut(old.as_ref()) {
+            handles.retain(|&h| h != handle);
+            if handles.is_empty() {
+                self.name_index.remove(old.as_ref());
+            }
+        }
     }
 
     /// Returns the name of a node.
@@ -398,6 +893,80 @@ impl Scene {
         self.names.get(handle).map(std::convert::AsRef::as_ref)
     }
 
+    /// Adds a tag to a node. Returns `false` if the node already had it
+    /// or does not exist.
+    pub fn add_tag(&mut self, handle: NodeHandle, tag: &str) -> bool {
+        if !self.nodes.contains_key(handle) {
+            return false;
+        }
+        let Some(tags) = self
+            .tags
+            .entry(handle)
+            .map(slotmap::sparse_secondary::Entry::or_default)
+        else {
+            return false;
+        };
+        if tags.iter().any(|t| t == tag) {
+            return false;
+        }
+        tags.push(tag.to_string());
+        self.tag_index
+            .entry(tag.to_string())
+            .or_default()
+            .push(handle);
+        true
+    }
+
+    /// Removes a tag from a node. Returns `false` if the node did not have it.
+    pub fn remove_tag(&mut self, handle: NodeHandle, tag: &str) -> bool {
+        let Some(tags) = self.tags.get_mut(handle) else {
+            return false;
+        };
+        let Some(pos) = tags.iter().position(|t| t == tag) else {
+            return false;
+        };
+        tags.swap_remove(pos);
+        if tags.is_empty() {
+            self.tags.remove(handle);
+        }
+        if let Some(handles) = self.tag_index.get_mut(tag) {
+            handles.retain(|&h| h != handle);
+            if handles.is_empty() {
+                self.tag_index.remove(tag);
+            }
+        }
+        true
+    }
+
+    /// Returns `true` if the node carries `tag`.
+    #[must_use]
+    pub fn has_tag(&self, handle: NodeHandle, tag: &str) -> bool {
+        self.tags
+            .get(handle)
+            .is_some_and(|tags| tags.iter().any(|t| t == tag))
+    }
+
+    /// Returns the tags of a node.
+    #[must_use]
+    pub fn tags(&self, handle: NodeHandle) -> &[String] {
+        self.tags.get(handle).map_or(&[], Vec::as_slice)
+    }
+
+    /// Removes all tags of `handle` from the tag storage and index.
+    fn unindex_tags(&mut self, handle: NodeHandle) {
+        let Some(tags) = self.tags.remove(handle) else {
+            return;
+        };
+        for tag in tags {
+            if let Some(handles) = self.tag_index.get_mut(&tag) {
+                handles.retain(|&h| h != handle);
+                if handles.is_empty() {
+                    self.tag_index.remove(&tag);
+                }
+            }
+        }
+    }
+
     /// Sets the mesh component for a node.
     pub fn set_mesh(&mut self, handle: NodeHandle, mesh: Mesh) {
         self.meshes.insert(handle, mesh);
@@ -413,6 +982,15 @@ impl Scene {
         self.meshes.get_mut(handle)
     }
 
+    /// Iterates over every node with a Mesh component and its mesh.
+    ///
+    /// The order is unspecified; use [`iter_descendants`](Self::iter_descendants)
+    /// with [`get_mesh`](Self::get_mesh) to visit the meshes of one subtree
+    /// in hierarchy order.
+    pub fn iter_meshes(&self) -> impl Iterator<Item = (NodeHandle, &Mesh)> {
+        self.meshes.iter()
+    }
+
     #[cfg(feature = "3dgs")]
     /// Attaches a Gaussian splatting point cloud handle to a node.
     pub fn set_gaussian_cloud(&mut self, handle: NodeHandle, cloud: GaussianCloudHandle) {
@@ -507,21 +1085,281 @@ impl Scene {
         self.skins.get(handle)
     }
 
-    /// Sets morph weights
-    pub fn set_morph_weights(&mut self, handle: NodeHandle, weights: Vec<f32>) {
+    /// Sets the node's morph weights, replacing any previous ones.
+    ///
+    /// Accepts a `Vec<f32>` or a `&[f32]`. Weights past
+    /// [`MAX_MORPH_TARGETS`] are dropped with a warning, and weights past
+    /// the mesh's target count are ignored when uploading. The weights are
+    /// pushed to the node's mesh (or its split primitives) on the next
+    /// [`update`](Self::update), which schedules the GPU morph buffer
+    /// refresh when they changed.
+    pub fn set_morph_weights(&mut self, handle: NodeHandle, weights: impl Into<Vec<f32>>) {
+        let mut weights = weights.into();
+        if weights.len() > MAX_MORPH_TARGETS {
+            log::warn!(
+                "{} morph weights exceed the limit of {MAX_MORPH_TARGETS}; extra weights are ignored",
+                weights.len()
+            );
+            weights.truncate(MAX_MORPH_TARGETS);
+        }
         self.morph_weights.insert(handle, weights);
     }
 
     /// Gets morph weights
+    ///
+    /// These are the weights as set, before any
+    /// [`MorphNormalization`] is applied.
     pub fn get_morph_weights(&self, handle: NodeHandle) -> Option<&Vec<f32>> {
         self.morph_weights.get(handle)
     }
 
+    /// Sets how the node's morph weights are normalized before upload.
+    ///
+    /// Applies to node-level weights (set by animation or
+    /// [`set_morph_weights`](Self::set_morph_weights)); the stored weights
+    /// are left unchanged.
+    pub fn set_morph_normalization(&mut self, handle: NodeHandle, mode: MorphNormalization) {
+        if mode == MorphNormalization::None {
+            self.morph_normalization.remove(handle);
+        } else {
+            self.morph_normalization.insert(handle, mode);
+        }
+    }
+
+    /// Returns the node's morph weight normalization mode.
+    #[must_use]
+    pub fn morph_normalization(&self, handle: NodeHandle) -> MorphNormalization {
+        self.morph_normalization
+            .get(handle)
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Gets a mutable reference to morph weights
     pub fn get_morph_weights_mut(&mut self, handle: NodeHandle) -> Option<&mut Vec<f32>> {
         self.morph_weights.get_mut(handle)
     }
 
+    /// Sets a color multiplied into the node's material base color.
+    ///
+    /// Unlike editing the material, this doesn't affect other nodes sharing
+    /// it and doesn't create a new pipeline per tint.
+    pub fn set_node_tint(&mut self, handle: NodeHandle, tint: Vec4) {
+        if let Some(overrides) = self.node_override_entry(handle) {
+            overrides.tint = tint;
+        }
+    }
+
+    /// Sets one of the four per-node scalars exposed to shaders as
+    /// `u_model.object_params`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= 4`.
+    pub fn set_node_param(&mut self, handle: NodeHandle, index: usize, value: f32) {
+        assert!(index < 4, "node param index {index} out of range (0..4)");
+        if let Some(overrides) = self.node_override_entry(handle) {
+            overrides.params[index] = value;
+        }
+    }
+
+    /// Returns the node's material overrides, if any were set.
+    #[must_use]
+    pub fn node_override(&self, handle: NodeHandle) -> Option<&ObjectOverride> {
+        self.object_overrides.get(handle)
+    }
+
+    /// Removes the node's material overrides.
+    pub fn clear_node_override(&mut self, handle: NodeHandle) {
+        self.object_overrides.remove(handle);
+    }
+
+    /// Attaches a level-of-detail group built from `(geometry, min_distance)`
+    /// pairs to a mesh node, replacing any previous one.
+    ///
+    /// While the group is attached, the mesh's geometry is switched to the
+    /// level matching the camera distance every frame. An empty list removes
+    /// the group.
+    pub fn set_lod(&mut self, handle: NodeHandle, levels: Vec<(GeometryHandle, f32)>) {
+        if levels.is_empty() {
+            self.lods.remove(handle);
+            return;
+        }
+        if !self.nodes.contains_key(handle) {
+            return;
+        }
+        let lod = Lod::new(levels);
+        if let Some(mesh) = self.meshes.get_mut(handle)
+            && let Some(geometry) = lod.current_geometry()
+        {
+            mesh.geometry = geometry;
+        }
+        self.lods.insert(handle, lod);
+    }
+
+    /// Makes the node face the active camera every frame.
+    ///
+    /// See [`update_billboards`](Self::update_billboards) for how the facing
+    /// rotation composes with the node's own transform.
+    pub fn set_billboard(&mut self, handle: NodeHandle, mode: BillboardMode) {
+        if self.nodes.contains_key(handle) {
+            self.billboards.insert(handle, mode);
+        }
+    }
+
+    /// Stops the node from facing the camera.
+    ///
+    /// Its world matrix is recomputed from the local transform on the next
+    /// hierarchy update.
+    pub fn clear_billboard(&mut self, handle: NodeHandle) {
+        if self.billboards.remove(handle).is_some()
+            && let Some(node) = self.nodes.get_mut(handle)
+        {
+            node.transform.mark_dirty();
+        }
+    }
+
+    /// Returns the node's billboard mode.
+    #[must_use]
+    pub fn billboard(&self, handle: NodeHandle) -> Option<BillboardMode> {
+        self.billboards.get(handle).copied()
+    }
+
+    /// Attaches a particle emitter to the node, replacing any previous one.
+    ///
+    /// The system is stepped by [`update_particles`](Self::update_particles).
+    /// Its particles are drawn by the node's mesh once that mesh uses a
+    /// geometry from [`ParticleSystem::build_geometry`] kept up to date with
+    /// [`ParticleSystem::write_geometry`]; `SceneExt::spawn_particles` in
+    /// `myth_assets` does both.
+    pub fn set_particle_system(&mut self, handle: NodeHandle, system: ParticleSystem) {
+        if self.nodes.contains_key(handle) {
+            self.particle_systems.insert(handle, system);
+        }
+    }
+
+    /// Returns the node's particle emitter.
+    #[must_use]
+    pub fn particle_system(&self, handle: NodeHandle) -> Option<&ParticleSystem> {
+        self.particle_systems.get(handle)
+    }
+
+    /// Returns the node's particle emitter for editing its parameters.
+    pub fn particle_system_mut(&mut self, handle: NodeHandle) -> Option<&mut ParticleSystem> {
+        self.particle_systems.get_mut(handle)
+    }
+
+    /// Detaches and returns the node's particle emitter.
+    pub fn remove_particle_system(&mut self, handle: NodeHandle) -> Option<ParticleSystem> {
+        self.particle_systems.remove(handle)
+    }
+
+    /// Steps every particle system by `dt` seconds. Called by
+    /// [`update`](Self::update).
+    pub fn update_particles(&mut self, dt: f32) {
+        for (_, system) in &mut self.particle_systems {
+            system.update(dt);
+        }
+    }
+
+    /// Adds a two-bone IK chain, replacing any chain ending at the same tip.
+    ///
+    /// See [`update_ik`](Self::update_ik) for when it is solved.
+    pub fn set_two_bone_ik(&mut self, ik: TwoBoneIk) {
+        if [ik.root, ik.mid, ik.tip]
+            .iter()
+            .all(|&h| self.nodes.contains_key(h))
+        {
+            self.ik_chains.insert(ik.tip, ik);
+        }
+    }
+
+    /// Removes the IK chain ending at `tip`, returning the bones to their
+    /// animated pose on the next hierarchy update.
+    pub fn clear_two_bone_ik(&mut self, tip: NodeHandle) -> Option<TwoBoneIk> {
+        let ik = self.ik_chains.remove(tip)?;
+        if let Some(node) = self.nodes.get_mut(ik.root) {
+            node.transform.mark_dirty();
+        }
+        Some(ik)
+    }
+
+    /// Returns the IK chain ending at `tip`.
+    #[must_use]
+    pub fn two_bone_ik(&self, tip: NodeHandle) -> Option<&TwoBoneIk> {
+        self.ik_chains.get(tip)
+    }
+
+    /// Returns the IK chain ending at `tip` for editing its target, pole or
+    /// weight.
+    pub fn two_bone_ik_mut(&mut self, tip: NodeHandle) -> Option<&mut TwoBoneIk> {
+        self.ik_chains.get_mut(tip)
+    }
+
+    /// Returns the node's level-of-detail group.
+    #[must_use]
+    pub fn lod(&self, handle: NodeHandle) -> Option<&Lod> {
+        self.lods.get(handle)
+    }
+
+    /// Returns the node's level-of-detail group for tuning hysteresis or the
+    /// cull distance.
+    pub fn lod_mut(&mut self, handle: NodeHandle) -> Option<&mut Lod> {
+        self.lods.get_mut(handle)
+    }
+
+    /// Selects each LOD group's level from its distance to `camera_position`
+    /// and writes the level's geometry into the node's mesh.
+    ///
+    /// Called by the renderer during extraction; nodes whose group is past
+    /// its cull distance are skipped there.
+    pub fn update_lods(&mut self, camera_position: Vec3A) {
+        for (handle, lod) in &mut self.lods {
+            let Some(node) = self.nodes.get(handle) else {
+                continue;
+            };
+            let distance_sq =
+                camera_position.distance_squared(node.transform.world_matrix.translation);
+            if lod.select(distance_sq).is_some()
+                && let Some(mesh) = self.meshes.get_mut(handle)
+                && let Some(geometry) = lod.current_geometry()
+            {
+                mesh.geometry = geometry;
+            }
+        }
+    }
+
+    fn node_override_entry(&mut self, handle: NodeHandle) -> Option<&mut ObjectOverride> {
+        if !self.nodes.contains_key(handle) {
+            return None;
+        }
+        self.object_overrides
+            .entry(handle)
+            .map(slotmap::sparse_secondary::Entry::or_default)
+    }
+
+    /// Sets a single morph target weight for a node.
+    ///
+    /// Node-level weights (set by animation or [`set_morph_weights`](Self::set_morph_weights))
+    /// are pushed to the mesh every frame and take precedence, so they are
+    /// updated when present; otherwise the node's mesh is written directly.
+    /// Returns `false` if the node has neither, or `index` is out of range.
+    pub fn set_morph_weight(&mut self, handle: NodeHandle, index: usize, value: f32) -> bool {
+        if let Some(weights) = self.morph_weights.get_mut(handle) {
+            if index >= MAX_MORPH_TARGETS {
+                return false;
+            }
+            if weights.len() <= index {
+                weights.resize(index + 1, 0.0);
+            }
+            weights[index] = value;
+            return true;
+        }
+        self.meshes
+            .get_mut(handle)
+            .is_some_and(|mesh| mesh.set_morph_weight(index, value))
+    }
+
     /// Sets morph weights for a node (from POD data)
     pub fn set_morph_weights_from_pod(
         &mut self,
@@ -543,7 +1381,8 @@ impl Scene {
     pub fn iter_active_lights(&self) -> impl Iterator<Item = (&Light, &Affine3A)> {
         self.lights.iter().filter_map(move |(node_handle, light)| {
             let node = self.nodes.get(node_handle)?;
-            if node.visible {
+            // Hidden ancestors are picked up by the last hierarchy update.
+            if node.visible && node.visible_in_hierarchy {
                 Some((light, &node.transform.world_matrix))
             } else {
                 None
@@ -551,6 +1390,36 @@ impl Scene {
         })
     }
 
+    /// Iterates over the decals of visible nodes with their world matrices.
+    pub fn iter_active_decals(&self) -> impl Iterator<Item = (&Decal, &Affine3A)> {
+        self.decals.iter().filter_map(move |(node_handle, decal)| {
+            let node = self.nodes.get(node_handle)?;
+            if node.visible && node.visible_in_hierarchy {
+                Some((decal, &node.transform.world_matrix))
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Iterates over the reflection probes of visible nodes with their
+    /// handles and world positions.
+    pub fn iter_active_reflection_probes(
+        &self,
+    ) -> impl Iterator<Item = (NodeHandle, &ReflectionProbe, Vec3)> {
+        self.reflection_probes
+            .iter()
+            .filter_map(move |(node_handle, probe)| {
+                let node = self.nodes.get(node_handle)?;
+                if node.visible && node.visible_in_hierarchy {
+                    let position = Vec3::from(node.transform.world_matrix.translation);
+                    Some((node_handle, probe, position))
+                } else {
+                    None
+                }
+            })
+    }
+
     // ========================================================================
     // Component Query API
     // ========================================================================
@@ -605,7 +1474,7 @@ impl Scene {
     // Matrix Update Pipeline
     // ========================================================================
 
-    /// Updates world matrices for the entire scene
+    /// Updates world matrices and inherited visibility for the entire scene
     pub fn update_matrix_world(&mut self) {
         transform_system::update_hierarchy_iterative(
             &mut self.nodes,
@@ -619,6 +1488,166 @@ impl Scene {
         transform_system::update_subtree(&mut self.nodes, &mut self.cameras, root_handle);
     }
 
+    /// Solves every two-bone IK chain and overrides the world matrices of
+    /// its root and mid bones.
+    ///
+    /// Runs after the animation mixers and
+    /// [`update_matrix_world`](Self::update_matrix_world) in
+    /// [`update`](Self::update), and before the skeletons are synced, so the
+    /// solved pose reaches the GPU bone matrices. The chain is solved from the
+    /// pose given by the bones' local transforms, blended toward the solution
+    /// by [`TwoBoneIk::weight`], and the bones' descendants are re-derived
+    /// from the new world matrices. Chains whose mid is not below the root,
+    /// or tip below the mid, are skipped.
+    pub fn update_ik(&mut self) {
+        if self.ik_chains.is_empty() {
+            return;
+        }
+        let chains: Vec<TwoBoneIk> = self.ik_chains.values().copied().collect();
+        for ik in chains {
+            let Some(root_world) = self.local_world_matrix(ik.root) else {
+                continue;
+            };
+            let Some(mid_world) = self
+                .world_below(ik.mid, ik.root)
+                .map(|rel| root_world * rel)
+            else {
+                continue;
+            };
+            let Some(tip_world) = self.world_below(ik.tip, ik.mid).map(|rel| mid_world * rel)
+            else {
+                continue;
+            };
+
+            let Some((chain, bend)) = ik.solve(
+                root_world.translation.into(),
+                mid_world.translation.into(),
+                tip_world.translation.into(),
+            ) else {
+                continue;
+            };
+            let weight = ik.weight.clamp(0.0, 1.0);
+            let chain = Quat::IDENTITY.slerp(chain, weight);
+            let bend = Quat::IDENTITY.slerp(bend, weight);
+
+            let (root_scale, root_rotation, root_translation) =
+                root_world.to_scale_rotation_translation();
+            transform_system::override_world_matrix(
+                &mut self.nodes,
+                &mut self.cameras,
+                ik.root,
+                Affine3A::from_scale_rotation_translation(
+                    root_scale,
+                    chain * root_rotation,
+                    root_translation,
+                ),
+            );
+
+            // The mid joint has moved with the root; only its rotation changes.
+            let Some(moved_mid) = self.nodes.get(ik.mid).map(|n| n.transform.world_matrix) else {
+                continue;
+            };
+            let (mid_scale, mid_rotation, _) = mid_world.to_scale_rotation_translation();
+            transform_system::override_world_matrix(
+                &mut self.nodes,
+                &mut self.cameras,
+                ik.mid,
+                Affine3A::from_scale_rotation_translation(
+                    mid_scale,
+                    chain * bend * mid_rotation,
+                    moved_mid.translation.into(),
+                ),
+            );
+        }
+    }
+
+    /// World matrix of a node from its parent's world matrix and its own
+    /// local transform, ignoring any override applied this frame.
+    fn local_world_matrix(&self, handle: NodeHandle) -> Option<Affine3A> {
+        let node = self.nodes.get(handle)?;
+        let parent_world = node
+            .parent
+            .and_then(|p| self.nodes.get(p))
+            .map_or(Affine3A::IDENTITY, |p| p.transform.world_matrix);
+        Some(parent_world * node.transform.local_matrix)
+    }
+
+    /// Product of the local matrices from `ancestor` (exclusive) down to
+    /// `handle`, or `None` if `handle` is not a descendant of `ancestor`.
+    fn world_below(&self, handle: NodeHandle, ancestor: NodeHandle) -> Option<Affine3A> {
+        let mut relative = Affine3A::IDENTITY;
+        let mut current = handle;
+        while current != ancestor {
+            let node = self.nodes.get(current)?;
+            relative = node.transform.local_matrix * relative;
+            current = node.parent?;
+        }
+        (handle != ancestor).then_some(relative)
+    }
+
+    /// Turns every billboard node toward the active camera.
+    ///
+    /// Runs after [`update_matrix_world`](Self::update_matrix_world) in
+    /// [`update`](Self::update). Each billboard keeps the position and scale
+    /// inherited from its parent; its world rotation becomes the facing
+    /// rotation followed by its local rotation, and its descendants are
+    /// re-derived from the new world matrix. Does nothing without an active
+    /// camera.
+    pub fn update_billboards(&mut self) {
+        if self.billboards.is_empty() {
+            return;
+        }
+        let Some(camera_position) = self
+            .active_camera
+            .and_then(|h| self.nodes.get(h))
+            .map(|n| Vec3::from(n.transform.world_matrix.translation))
+        else {
+            return;
+        };
+
+        // Parents first, so a nested billboard faces from its final position.
+        let mut ordered: Vec<(usize, NodeHandle, BillboardMode)> = self
+            .billboards
+            .iter()
+            .map(|(handle, &mode)| {
+                let mut depth = 0;
+                let mut current = self.nodes.get(handle).and_then(|n| n.parent);
+                while let Some(parent) = current {
+                    depth += 1;
+                    current = self.nodes.get(parent).and_then(|n| n.parent);
+                }
+                (depth, handle, mode)
+            })
+            .collect();
+        ordered.sort_by_key(|&(depth, ..)| depth);
+
+        for (_, handle, mode) in ordered {
+            let Some(node) = self.nodes.get(handle) else {
+                continue;
+            };
+            let parent_world = node
+                .parent
+                .and_then(|p| self.nodes.get(p))
+                .map_or(Affine3A::IDENTITY, |p| p.transform.world_matrix);
+            let base = parent_world * node.transform.local_matrix;
+            let (scale, _, translation) = base.to_scale_rotation_translation();
+            let Some(facing) = mode.facing_rotation(translation, camera_position) else {
+                continue;
+            };
+            let world = Affine3A::from_scale_rotation_translation(
+                scale,
+                facing * node.transform.rotation,
+                translation,
+            );
+            transform_system::override_world_matrix(
+                &mut self.nodes,
+                &mut self.cameras,
+                handle,
+                world,
+            );
+        }
+    }
+
     // ========================================================================
     // Resource Management API
     // ========================================================================
@@ -669,6 +1698,108 @@ impl Scene {
         node_handle
     }
 
+    /// Adds a root node projecting `texture` onto the surfaces inside its
+    /// box.
+    ///
+    /// `transform` places the box: the unit cube around the node, scaled
+    /// to the decal size (x, y) and projection depth (z), projecting along
+    /// the node's -Z axis. See [`crate::decal`] for what receives decals.
+    pub fn add_decal(
+        &mut self,
+        transform: Transform,
+        texture: TextureHandle,
+        options: DecalOptions,
+    ) -> NodeHandle {
+        let node_handle = self.create_node_with_name("Decal");
+        if let Some(node) = self.nodes.get_mut(node_handle) {
+            node.transform = transform;
+        }
+        self.decals
+            .insert(node_handle, Decal::new(texture, options));
+        self.root_nodes.push(node_handle);
+        node_handle
+    }
+
+    /// Returns the node's decal.
+    #[must_use]
+    pub fn decal(&self, handle: NodeHandle) -> Option<&Decal> {
+        self.decals.get(handle)
+    }
+
+    /// Returns the node's decal for editing its texture or options.
+    pub fn decal_mut(&mut self, handle: NodeHandle) -> Option<&mut Decal> {
+        self.decals.get_mut(handle)
+    }
+
+    /// Adds a root node at `position` carrying a [`ReflectionProbe`] with
+    /// `resolution`² pixels per cube face.
+    ///
+    /// The probe is captured the next time the scene is rendered; see
+    /// [`crate::reflection_probe`] for how materials use it.
+    pub fn add_reflection_probe(&mut self, position: Vec3, resolution: u32) -> NodeHandle {
+        let node_handle = self.create_node_with_name("ReflectionProbe");
+        if let Some(node) = self.nodes.get_mut(node_handle) {
+            node.transform.position = position;
+        }
+        self.reflection_probes
+            .insert(node_handle, ReflectionProbe::new(resolution));
+        self.root_nodes.push(node_handle);
+        node_handle
+    }
+
+    /// Returns the node's reflection probe.
+    #[must_use]
+    pub fn reflection_probe(&self, handle: NodeHandle) -> Option<&ReflectionProbe> {
+        self.reflection_probes.get(handle)
+    }
+
+    /// Returns the node's reflection probe, for editing its settings or
+    /// requesting a new [`capture`](ReflectionProbe::capture).
+    pub fn reflection_probe_mut(&mut self, handle: NodeHandle) -> Option<&mut ReflectionProbe> {
+        self.reflection_probes.get_mut(handle)
+    }
+
+    /// Places a grid of `resolution` irradiance probes over `bounds` and
+    /// requests a bake, replacing any previous grid but keeping its
+    /// settings.
+    ///
+    /// The probes are baked the next time the scene is rendered; see
+    /// [`crate::light_probe`] for how materials use them.
+    pub fn bake_light_probes(
+        &mut self,
+        bounds: BoundingBox,
+        resolution: UVec3,
+    ) -> &mut LightProbeVolume {
+        let volume = self
+            .light_probes
+            .get_or_insert_with(|| LightProbeVolume::new(bounds, resolution));
+        volume.set_grid(bounds, resolution);
+
+        let count = volume.probe_count();
+        if self.light_probe_buffer.read().len() != count {
+            *self.light_probe_buffer.write() = vec![GpuLightProbe::default(); count];
+        }
+        volume
+    }
+
+    /// Returns the scene's light probe grid.
+    #[must_use]
+    pub fn light_probes(&self) -> Option<&LightProbeVolume> {
+        self.light_probes.as_ref()
+    }
+
+    /// Returns the scene's light probe grid, for editing its settings or
+    /// requesting a new [`bake`](LightProbeVolume::bake).
+    pub fn light_probes_mut(&mut self) -> Option<&mut LightProbeVolume> {
+        self.light_probes.as_mut()
+    }
+
+    /// Removes the light probe grid; indirect diffuse lighting comes from
+    /// the environment map again.
+    pub fn clear_light_probes(&mut self) {
+        self.light_probes = None;
+    }
+
     pub fn mark_as_split_primitive(&mut self, handle: NodeHandle) {
         self.split_primitive_tags.insert(handle, SplitPrimitiveTag);
     }
@@ -734,7 +1865,11 @@ impl Scene {
         }
 
         // 3. Execute internal engine systems (Transform, Skeleton, Morph)
+        self.update_particles(dt);
         self.update_matrix_world();
+        self.update_ik();
+        self.update_billboards();
+        self.sync_sky_sun_light();
         self.update_skeletons();
         self.sync_morph_weights();
         self.sync_shader_defines();
@@ -745,6 +1880,7 @@ impl Scene {
     pub fn sync_gpu_buffers(&mut self) {
         self.sync_light_buffer();
         self.sync_environment_buffer();
+        self.background.sync_environment_link(&self.environment);
     }
 
     /// Syncs light data to GPU Buffer
@@ -760,6 +1896,10 @@ impl Scene {
             let mut gpu_light = GpuLightStorage {
                 color: light.color,
                 intensity: light.intensity,
+                units: match light.units {
+                    LightUnits::Legacy => 0,
+                    LightUnits::PhysicalUnits => 1,
+                },
                 position: pos,
                 direction: dir,
                 shadow_layer_index: -1,
@@ -806,16 +1946,54 @@ impl Scene {
         let env = &self.environment;
         let light_count = self.lights.len();
 
-        let new_uniforms = EnvironmentUniforms {
-            ambient_light: env.ambient,
+        // Procedural skies bake their IBL in world orientation (driven by the
+        // sun direction), so rotating the lookups would misalign the lighting.
+        let env_map_rotation = if matches!(self.background.mode, BackgroundMode::Procedural(_)) {
+            Mat3Uniform::IDENTITY
+        } else {
+            Mat3Uniform::from(env.sampling_matrix())
+        };
+
+        let ambient_light = if env.ambient_from_background {
+            self.background.mode.average_color().unwrap_or(env.ambient)
+        } else {
+            env.ambient
+        };
+
+        // env_map_max_mip_level and the probe and light cluster fields are
+        // set by the renderer during the prepare phase, so we preserve the
+        // existing values here.
+        let current = self.uniforms_buffer.read();
+        let mut new_uniforms = EnvironmentUniforms {
+            ambient_light,
             num_lights: light_count as u32,
             env_map_intensity: env.intensity,
-            env_map_rotation: env.rotation,
-            // env_map_max_mip_level is set by ResourceManager::resolve_gpu_environment
-            // during the prepare phase, so we preserve the existing value here.
-            env_map_max_mip_level: self.uniforms_buffer.read().env_map_max_mip_level,
+            env_map_max_mip_level: current.env_map_max_mip_level,
+            physical_light_scale: self.tone_mapping.physical_light_scale(),
+            env_map_rotation,
+            background_blur: if self.background.mode.shows_environment(env) {
+                env.background_blur
+            } else {
+                0.0
+            },
+            probe_position: current.probe_position,
+            probe_radius: current.probe_radius,
+            probe_blend_distance: current.probe_blend_distance,
+            probe_intensity: current.probe_intensity,
+            probe_max_mip_level: current.probe_max_mip_level,
+            light_probe_min: current.light_probe_min,
+            light_probe_intensity: current.light_probe_intensity,
+            light_probe_max: current.light_probe_max,
+            light_probe_blend_distance: current.light_probe_blend_distance,
+            light_probe_grid: current.light_probe_grid,
+            light_cluster_grid: current.light_cluster_grid,
+            light_cluster_depth: current.light_cluster_depth,
             ..Default::default()
         };
+        drop(current);
+        if let Some(fog) = &env.fog {
+            fog.write_uniforms(&mut new_uniforms, &self.background.mode, env);
+        }
 
         let needs_update = *self.uniforms_buffer.read() != new_uniforms;
 
@@ -836,6 +2014,14 @@ impl Scene {
         &self.uniforms_buffer
     }
 
+    pub fn light_probe_storage(&self) -> &CpuBuffer<Vec<GpuLightProbe>> {
+        &self.light_probe_buffer
+    }
+
+    pub fn light_cluster_storage(&self) -> &CpuBuffer<Vec<u32>> {
+        &self.light_cluster_buffer
+    }
+
     pub fn update_skeletons(&mut self) {
         let mut tasks = Vec::new();
 
@@ -861,12 +2047,21 @@ impl Scene {
     }
 
     pub fn sync_morph_weights(&mut self) {
+        let mut normalized = Vec::new();
         for (handle, weights) in &self.morph_weights {
             if weights.is_empty() {
                 continue;
             }
 
-            let weights_slice = weights.as_slice();
+            let mut weights_slice = weights.as_slice();
+            if self.morph_normalization.get(handle) == Some(&MorphNormalization::Normalize) {
+                let total: f32 = weights.iter().map(|w| w.abs()).sum();
+                if total > 1.0 {
+                    normalized.clear();
+                    normalized.extend(weights.iter().map(|w| w / total));
+                    weights_slice = normalized.as_slice();
+                }
+            }
 
             if let Some(mesh) = self.meshes.get_mut(handle) {
                 mesh.set_morph_target_influences(weights_slice);
@@ -904,6 +2099,271 @@ impl Scene {
         self.background.set_mode(BackgroundMode::color(r, g, b));
     }
 
+    /// Sets the background to a procedural sky with the given parameters.
+    ///
+    /// The sky also becomes the IBL source, see [`BackgroundMode::Procedural`].
+    pub fn set_procedural_sky(&mut self, params: ProceduralSkyParams) {
+        self.background
+            .set_mode(BackgroundMode::procedural_with(params));
+    }
+
+    /// Links the procedural sky's sun to a directional light node.
+    ///
+    /// While linked, the sky's sun direction follows the light's world-space
+    /// orientation every [`update`](Self::update), so sky colour, IBL and
+    /// shadows stay consistent when the light is moved or animated. Pass
+    /// `None` to unlink and drive the sun manually again.
+    pub fn set_sky_sun_light(&mut self, light: Option<NodeHandle>) {
+        self.sky_sun_light = light;
+        self.sync_sky_sun_light();
+    }
+
+    /// Returns the light node currently driving the procedural sky's sun.
+    #[must_use]
+    pub fn sky_sun_light(&self) -> Option<NodeHandle> {
+        self.sky_sun_light
+    }
+
+    /// Enables hardware occlusion culling for the main camera.
+    ///
+    /// After the opaque pass, the renderer draws the bounding box of every
+    /// mesh that survived frustum culling against the scene depth with an
+    /// occlusion query. Meshes whose box produced no visible samples are
+    /// skipped in the **next** frame (while still being queried), so results
+    /// lag by one frame and an object coming out from behind an occluder may
+    /// be missing for that frame. Only the high-fidelity render path runs
+    /// the queries; shadow views are unaffected.
+    pub fn enable_occlusion_culling(&mut self, enabled: bool) {
+        self.occlusion_culling = enabled;
+    }
+
+    /// Returns whether occlusion culling is enabled.
+    #[must_use]
+    pub fn occlusion_culling_enabled(&self) -> bool {
+        self.occlusion_culling
+    }
+
+    /// Returns the immediate-mode debug line buffer for this frame.
+    ///
+    /// Lines added here are drawn once and discarded by the renderer; see
+    /// [`DebugDraw`].
+    pub fn debug(&mut self) -> &mut DebugDraw {
+        &mut self.debug_draw
+    }
+
+    /// Returns the debug lines accumulated for this frame.
+    #[must_use]
+    pub fn debug_draw(&self) -> &DebugDraw {
+        &self.debug_draw
+    }
+
+    /// Queues debug lines for the skeleton bound to `node`, connecting every
+    /// bone to its parent bone in world space.
+    ///
+    /// The lines are generated when the renderer extracts the frame, so they
+    /// follow the current animated pose. Root bones (whose parent is not part
+    /// of the skeleton) only act as line endpoints. Requests for several
+    /// meshes sharing one skeleton draw it once.
+    ///
+    /// Returns `false` if `node` has no skeleton binding.
+    pub fn debug_draw_skeleton(&mut self, node: NodeHandle, color: Vec4) -> bool {
+        let Some(binding) = self.skins.get(node) else {
+            return false;
+        };
+        self.debug_draw.skeleton(binding.skeleton, color);
+        true
+    }
+
+    /// Draws the view frustum of the camera attached to `node`.
+    ///
+    /// Uses the camera matrices from the last [`update`](Self::update). The
+    /// far plane is only drawn when the camera has a finite far distance.
+    ///
+    /// Returns `false` if `node` has no camera.
+    pub fn debug_draw_frustum(&mut self, node: NodeHandle, color: Vec4) -> bool {
+        let Some(camera) = self.cameras.get(node) else {
+            return false;
+        };
+        self.debug_draw.frustum(&camera.frustum_corners(), color);
+        true
+    }
+
+    /// Draws the world-space bounding box of `node` and its descendants, as
+    /// computed by [`get_bbox_of_node`](Self::get_bbox_of_node).
+    ///
+    /// Returns `false` if the subtree has no bounds.
+    pub fn debug_draw_bounds(
+        &mut self,
+        node: NodeHandle,
+        query: &impl crate::GeometryQuery,
+        color: Vec4,
+    ) -> bool {
+        let Some(bbox) = self.get_bbox_of_node(node, query) else {
+            return false;
+        };
+        self.debug_draw.aabb(&bbox, color);
+        true
+    }
+
+    /// Draws a helper for every visible light each frame while enabled:
+    /// arrows for directional lights, range spheres for point lights and
+    /// cones for spot lights, in the light's color.
+    ///
+    /// The helpers are generated during extraction, like queued skeletons,
+    /// so they follow the current light transforms. See
+    /// [`DebugDraw::light`].
+    pub fn show_light_helpers(&mut self, enabled: bool) {
+        self.light_helpers = enabled;
+    }
+
+    /// Returns whether light helpers are drawn.
+    #[must_use]
+    pub fn light_helpers_enabled(&self) -> bool {
+        self.light_helpers
+    }
+
+    /// Draws the view frustum of the camera on `node` every frame while
+    /// enabled, like calling [`debug_draw_frustum`](Self::debug_draw_frustum)
+    /// each frame.
+    ///
+    /// The frustum is skipped while the node is the active camera, where it
+    /// would only outline the screen. Returns `false` if `node` has no
+    /// camera.
+    pub fn show_camera_helper(&mut self, node: NodeHandle, enabled: bool) -> bool {
+        if !self.cameras.contains_key(node) {
+            return false;
+        }
+        if enabled {
+            self.camera_helpers.insert(node, ());
+        } else {
+            self.camera_helpers.remove(node);
+        }
+        true
+    }
+
+    /// Returns whether the camera helper of `node` is enabled.
+    #[must_use]
+    pub fn camera_helper_enabled(&self, node: NodeHandle) -> bool {
+        self.camera_helpers.contains_key(node)
+    }
+
+    /// Shows an infinite ground grid on the XZ plane, replacing any previous
+    /// grid options.
+    ///
+    /// Unlike debug lines, the grid persists until [`hide_grid`](Self::hide_grid)
+    /// is called. It is rendered after opaque geometry and depth-tested
+    /// against it, fading out with distance from the camera.
+    pub fn show_grid(&mut self, options: GridOptions) {
+        self.grid = Some(options);
+    }
+
+    /// Hides the ground grid.
+    pub fn hide_grid(&mut self) {
+        self.grid = None;
+    }
+
+    /// Returns the options of the shown ground grid, if any.
+    #[must_use]
+    pub fn grid(&self) -> Option<&GridOptions> {
+        self.grid.as_ref()
+    }
+
+    /// Enables or disables the soft blob shadow drawn on the plane
+    /// `y = plane_y` under the scene's shadow-casting meshes.
+    ///
+    /// Much cheaper than shadow maps and needs no shadow-casting light.
+    /// Settings not covered by the arguments keep their previous values.
+    /// Only the `HighFidelity` path draws the shadow. See [`GroundShadow`].
+    pub fn set_ground_shadow(&mut self, enabled: bool, plane_y: f32, softness: f32) {
+        if !enabled {
+            self.ground_shadow = None;
+            return;
+        }
+        let shadow = self.ground_shadow.get_or_insert_with(GroundShadow::default);
+        shadow.plane_y = plane_y;
+        shadow.softness = softness.clamp(0.0, 1.0);
+    }
+
+    /// Returns the ground shadow settings, if enabled.
+    #[must_use]
+    pub fn ground_shadow(&self) -> Option<&GroundShadow> {
+        self.ground_shadow.as_ref()
+    }
+
+    /// Returns the ground shadow settings for editing, if enabled.
+    pub fn ground_shadow_mut(&mut self) -> Option<&mut GroundShadow> {
+        self.ground_shadow.as_mut()
+    }
+
+    /// Moves this frame's debug lines into `out`, first expanding queued
+    /// skeletons into bone segments and adding light and camera helpers.
+    /// Called by the renderer during extraction.
+    pub fn take_debug_lines(&mut self, out: &mut Vec<DebugVertex>) {
+        for node in self.camera_helpers.keys() {
+            if Some(node) == self.active_camera {
+                continue;
+            }
+            if let Some(camera) = self.cameras.get(node) {
+                self.debug_draw
+                    .frustum(&camera.frustum_corners(), CAMERA_HELPER_COLOR);
+            }
+        }
+        if self.light_helpers {
+            for (handle, light) in &self.lights {
+                let Some(node) = self
+                    .nodes
+                    .get(handle)
+                    .filter(|node| node.visible && node.visible_in_hierarchy)
+                else {
+                    continue;
+                };
+                self.debug_draw.light(light, &node.transform.world_matrix);
+            }
+        }
+        for (key, color) in self.debug_draw.take_skeletons() {
+            let Some(skeleton) = self.skeleton_pool.get(key) else {
+                continue;
+            };
+            for &bone in &skeleton.bones {
+                let Some(node) = self.nodes.get(bone) else {
+                    continue;
+                };
+                let Some(parent) = node.parent().filter(|p| skeleton.bones.contains(p)) else {
+                    continue;
+                };
+                let Some(parent_node) = self.nodes.get(parent) else {
+                    continue;
+                };
+                self.debug_draw.line(
+                    parent_node.world_matrix().translation.into(),
+                    node.world_matrix().translation.into(),
+                    color,
+                );
+            }
+        }
+        self.debug_draw.drain_into(out);
+    }
+
+    /// Copies the linked light's direction into the procedural sky parameters.
+    fn sync_sky_sun_light(&mut self) {
+        let Some(handle) = self.sky_sun_light else {
+            return;
+        };
+        let Some(node) = self.nodes.get(handle) else {
+            return;
+        };
+        // Lights shine along their local -Z; the sky expects the direction
+        // towards the sun.
+        let to_sun = node
+            .transform
+            .world_matrix
+            .transform_vector3(Vec3::Z)
+            .normalize_or_zero();
+        if let Some(params) = self.background.procedural_sky_params_mut() {
+            params.set_sun_direction(to_sun);
+        }
+    }
+
     // ========================================================================
     // High-Level Helpers (node wrapper, builder)
     // ========================================================================
@@ -929,14 +2389,51 @@ impl Scene {
         NodeBuilder::new(self, name)
     }
 
-    /// Finds a node by name
+    /// Finds the first node (in naming order) with the given name.
+    ///
+    /// Backed by a name index, so this is a hash lookup rather than a scan.
+    #[must_use]
+    pub fn find_by_name(&self, name: &str) -> Option<NodeHandle> {
+        self.find_all_by_name(name).first().copied()
+    }
+
+    /// Returns every node with the given name, in naming order.
+    #[must_use]
+    pub fn find_all_by_name(&self, name: &str) -> &[NodeHandle] {
+        self.name_index.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// Finds a node by name. Same as [`find_by_name`](Self::find_by_name).
+    #[must_use]
     pub fn find_node_by_name(&self, name: &str) -> Option<NodeHandle> {
-        for (handle, node_name) in &self.names {
-            if node_name.as_ref() == name {
-                return Some(handle);
+        self.find_by_name(name)
+    }
+
+    /// Iterates over the descendants of `root` (excluding `root` itself),
+    /// depth-first in pre-order, visiting children in their stored order.
+    ///
+    /// Yields nothing if `root` does not exist or has no children. The
+    /// hierarchy is walked lazily with an explicit stack, so deep trees do
+    /// not recurse.
+    pub fn iter_descendants(&self, root: NodeHandle) -> impl Iterator<Item = NodeHandle> + '_ {
+        let mut stack: Vec<NodeHandle> = self
+            .nodes
+            .get(root)
+            .map(|node| node.children.iter().rev().copied().collect())
+            .unwrap_or_default();
+        std::iter::from_fn(move || {
+            let handle = stack.pop()?;
+            if let Some(node) = self.nodes.get(handle) {
+                stack.extend(node.children.iter().rev().copied());
             }
-        }
-        None
+            Some(handle)
+        })
+    }
+
+    /// Returns every node carrying `tag`, in tagging order.
+    #[must_use]
+    pub fn find_by_tag(&self, tag: &str) -> &[NodeHandle] {
+        self.tag_index.get(tag).map_or(&[], Vec::as_slice)
     }
 
     /// Gets the global transform matrix of a node
@@ -979,7 +2476,7 @@ impl Scene {
         query: &impl crate::GeometryQuery,
     ) -> Option<myth_resources::BoundingBox> {
         let node = self.get_node(node_handle)?;
-        if !node.visible {
+        if !self.is_visible_in_hierarchy(node_handle) {
             return None;
         }
         let mesh = self.meshes.get(node_handle)?;
@@ -1020,6 +2517,32 @@ impl Scene {
 
         combined_bbox
     }
+
+    /// Computes the world-space bounding box enclosing every visible mesh in the scene.
+    ///
+    /// `query` implements [`crate::GeometryQuery`] to map geometry handles to local-space bounding boxes.
+    pub fn get_bbox_of_scene(
+        &self,
+        query: &impl crate::GeometryQuery,
+    ) -> Option<myth_resources::BoundingBox> {
+        self.root_nodes
+            .iter()
+            .filter_map(|&root| self.get_bbox_of_node(root, query))
+            .reduce(|a, b| a.union(&b))
+    }
+
+    /// Fits the active camera's near/far planes to the scene bounds.
+    ///
+    /// Convenience wrapper around [`Camera::auto_clip`] intended to be called
+    /// once per frame after the scene update. Returns `false` if there is no
+    /// active camera or nothing to fit.
+    pub fn auto_clip_active_camera(&mut self, query: &impl crate::GeometryQuery) -> bool {
+        let Some(bounds) = self.get_bbox_of_scene(query) else {
+            return false;
+        };
+        self.query_main_camera_bundle()
+            .is_some_and(|(_, camera)| camera.auto_clip(&bounds))
+    }
 }
 
 // ============================================================================
@@ -1036,7 +2559,7 @@ pub struct NodeBuilder<'a> {
 impl<'a> NodeBuilder<'a> {
     pub fn new(scene: &'a mut Scene, name: &str) -> Self {
         let handle = scene.nodes.insert(Node::new());
-        scene.names.insert(handle, Cow::Owned(name.to_string()));
+        scene.set_name(handle, name);
         Self {
             scene,
             handle,
@@ -1148,4 +2671,18 @@ impl AnimationTarget for Scene {
     fn morph_weights_mut(&mut self, handle: NodeHandle) -> &mut Vec<f32> {
         self.morph_weights.entry(handle).unwrap().or_default()
     }
+
+    fn morph_target_count(&self, handle: NodeHandle) -> Option<usize> {
+        if let Some(mesh) = self.meshes.get(handle) {
+            return Some(mesh.morph_target_influences().len());
+        }
+        // Split primitives share the parent's morph weights.
+        let node = self.nodes.get(handle)?;
+        node.children
+            .iter()
+            .filter(|&&child| self.split_primitive_tags.contains_key(child))
+            .filter_map(|&child| self.meshes.get(child))
+            .map(|mesh| mesh.morph_target_influences().len())
+            .max()
+    }
 }
@@ -0,0 +1,713 @@
+//! Scene serialization
+//!
+//! Converts a [`Scene`] to and from a [`SceneDescriptor`]: plain authoring
+//! data (node hierarchy, transforms, mesh / camera / light components,
+//! environment and background) that can be written as JSON and loaded back.
+//!
+//! JSON is used deliberately rather than RON: `serde_json` is already a
+//! workspace dependency and the files are readable by external tools. The
+//! descriptor types only derive `Serialize` / `Deserialize`, so any other
+//! serde format works as well.
+//!
+//! Assets are referenced by their stable UUID rather than by handle, so a
+//! descriptor stays valid across sessions as long as the same assets are
+//! loaded again. Translating between handles and IDs is delegated to an
+//! [`AssetIdResolver`] (implemented by `AssetServer`).
+//!
+//! Animation mixers, skins, morph weights, scene logic and all GPU state are
+//! **not** part of the descriptor.
+
+use ::serde::{Deserialize, Serialize};
+use glam::{Quat, Vec3, Vec4};
+
+use myth_core::{AssetError, NodeHandle, Result};
+use myth_resources::texture::TextureSource;
+use myth_resources::{GeometryHandle, MaterialHandle, Mesh, Submesh, TextureHandle};
+
+use crate::background::{BackgroundMapping, BackgroundMode, ProceduralSkyParams};
+use crate::camera::{Camera, ProjectionType};
+use crate::light::{Light, LightKind, LightUnits, ShadowConfig};
+use crate::{ALL_LAYERS, Node, Scene};
+
+/// Current [`SceneDescriptor::version`].
+pub const SCENE_FORMAT_VERSION: u32 = 1;
+
+/// Maps asset handles to stable IDs and back.
+///
+/// Implement this on your asset storage type (e.g. `AssetServer`) so that
+/// [`Scene::to_scene_desc`] and [`Scene::from_scene_desc`] can translate
+/// asset references without depending on the asset crate directly.
+pub trait AssetIdResolver {
+    fn geometry_id(&self, handle: GeometryHandle) -> Option<String>;
+    fn material_id(&self, handle: MaterialHandle) -> Option<String>;
+    fn texture_id(&self, handle: TextureHandle) -> Option<String>;
+
+    fn resolve_geometry(&self, id: &str) -> Option<GeometryHandle>;
+    fn resolve_material(&self, id: &str) -> Option<MaterialHandle>;
+    fn resolve_texture(&self, id: &str) -> Option<TextureHandle>;
+}
+
+/// Serializable snapshot of a scene's authoring data.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SceneDescriptor {
+    /// Format version, see [`SCENE_FORMAT_VERSION`].
+    pub version: u32,
+    /// Nodes in depth-first order; a parent always precedes its children.
+    pub nodes: Vec<NodeDesc>,
+    /// Index into [`nodes`](Self::nodes) of the active camera.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_camera: Option<usize>,
+    #[serde(default)]
+    pub environment: EnvironmentDesc,
+    #[serde(default)]
+    pub background: BackgroundDesc,
+}
+
+/// A node and its components.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeDesc {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Index of the parent node, `None` for root nodes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<usize>,
+    pub position: [f32; 3],
+    pub rotation: [f32; 4],
+    pub scale: [f32; 3],
+    pub visible: bool,
+    #[serde(default = "all_layers")]
+    pub layers: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mesh: Option<MeshDesc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera: Option<CameraDesc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub light: Option<LightDesc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MeshDesc {
+    pub name: String,
+    /// Geometry asset ID.
+    pub geometry: String,
+    /// Material asset ID.
+    pub material: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub submeshes: Vec<SubmeshDesc>,
+    pub visible: bool,
+    pub cast_shadows: bool,
+    pub receive_shadows: bool,
+    pub render_order: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SubmeshDesc {
+    pub start: u32,
+    pub end: u32,
+    /// Material asset ID.
+    pub material: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ProjectionDesc {
+    Perspective,
+    Orthographic,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CameraDesc {
+    pub name: String,
+    pub projection: ProjectionDesc,
+    /// Vertical field of view in radians.
+    pub fov: f32,
+    pub aspect: f32,
+    pub near: f32,
+    /// Far plane, `None` for an infinite far plane.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub far: Option<f32>,
+    pub ortho_size: f32,
+    #[serde(default = "all_layers")]
+    pub layer_mask: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LightKindDesc {
+    Directional,
+    Point {
+        range: f32,
+    },
+    Spot {
+        range: f32,
+        inner_cone: f32,
+        outer_cone: f32,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowDesc {
+    pub bias: f32,
+    pub normal_bias: f32,
+    pub map_size: u32,
+    pub cascade_count: u32,
+    pub cascade_split_lambda: f32,
+    pub max_shadow_distance: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LightDesc {
+    pub color: [f32; 3],
+    pub intensity: f32,
+    /// `intensity` is in lux / candela ([`LightUnits::PhysicalUnits`]).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub physical_units: bool,
+    pub kind: LightKindDesc,
+    pub cast_shadows: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<ShadowDesc>,
+    #[serde(default = "all_layers")]
+    pub shadow_layers: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvironmentDesc {
+    /// Environment map texture asset ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub env_map: Option<String>,
+    pub intensity: f32,
+    /// Orientation quaternion `[x, y, z, w]`.
+    pub rotation: [f32; 4],
+    pub ambient: [f32; 3],
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub ambient_from_background: bool,
+}
+
+impl Default for EnvironmentDesc {
+    fn default() -> Self {
+        Self {
+            env_map: None,
+            intensity: 1.0,
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            ambient: [0.0; 3],
+            ambient_from_background: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MappingDesc {
+    Cube,
+    Equirectangular,
+    Planar,
+}
+
+/// Background mode.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BackgroundDesc {
+    Color([f32; 4]),
+    Gradient {
+        top: [f32; 4],
+        bottom: [f32; 4],
+    },
+    Texture {
+        /// Texture asset ID.
+        texture: String,
+        rotation: f32,
+        intensity: f32,
+        mapping: MappingDesc,
+    },
+    Procedural(ProceduralSkyDesc),
+}
+
+/// Procedural sky parameters, see [`ProceduralSkyParams`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProceduralSkyDesc {
+    pub sun_direction: [f32; 3],
+    pub moon_direction: [f32; 3],
+    pub star_axis: [f32; 3],
+    pub sun_disk_size: f32,
+    pub moon_disk_size: f32,
+    pub sun_intensity: f32,
+    pub moon_intensity: f32,
+    pub rayleigh_scattering: [f32; 3],
+    pub rayleigh_scale_height: f32,
+    pub mie_scattering: f32,
+    pub mie_absorption: f32,
+    pub mie_scale_height: f32,
+    pub mie_anisotropy: f32,
+    pub ozone_absorption: [f32; 3],
+    pub planet_radius: f32,
+    pub atmosphere_radius: f32,
+    pub ground_albedo: [f32; 3],
+    pub turbidity: f32,
+    /// Moon albedo texture asset ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moon_albedo_texture: Option<String>,
+    /// Star-field texture asset ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub starbox_texture: Option<String>,
+    pub star_intensity: f32,
+    pub star_rotation: f32,
+}
+
+impl Default for BackgroundDesc {
+    fn default() -> Self {
+        Self::Color([0.0, 0.0, 0.0, 1.0])
+    }
+}
+
+impl SceneDescriptor {
+    /// Serializes the descriptor as pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self)
+            .map_err(|e| AssetError::Format(format!("Scene serialization failed: {e}")).into())
+    }
+
+    /// Parses a descriptor from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let desc: Self = serde_json::from_str(json)
+            .map_err(|e| AssetError::Format(format!("Invalid scene descriptor: {e}")))?;
+        if desc.version > SCENE_FORMAT_VERSION {
+            return Err(AssetError::Format(format!(
+                "Unsupported scene format version {} (expected <= {SCENE_FORMAT_VERSION})",
+                desc.version
+            ))
+            .into());
+        }
+        Ok(desc)
+    }
+}
+
+impl Scene {
+    /// Captures the scene's authoring data as a [`SceneDescriptor`].
+    ///
+    /// Only nodes reachable from [`root_nodes`](Self::root_nodes) are
+    /// captured. Fails if a mesh references an asset that `assets` cannot
+    /// identify.
+    /// Environment or background textures that are render-graph attachments
+    /// rather than assets are dropped.
+    pub fn to_scene_desc(&self, assets: &impl AssetIdResolver) -> Result<SceneDescriptor> {
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        let mut active_camera = None;
+
+        let mut stack: Vec<(NodeHandle, Option<usize>)> =
+            self.root_nodes().iter().rev().map(|&h| (h, None)).collect();
+        while let Some((handle, parent)) = stack.pop() {
+            let Some(node) = self.get_node(handle) else {
+                continue;
+            };
+            let index = nodes.len();
+            if self.active_camera == Some(handle) {
+                active_camera = Some(index);
+            }
+
+            let mesh = match self.get_mesh(handle) {
+                Some(mesh) => Some(mesh_desc(mesh, assets)?),
+                None => None,
+            };
+            let transform = &node.transform;
+            nodes.push(NodeDesc {
+                name: self.get_name(handle).map(str::to_owned),
+                parent,
+                position: transform.position.to_array(),
+                rotation: transform.rotation.to_array(),
+                scale: transform.scale.to_array(),
+                visible: node.visible,
+                layers: node.layers,
+                tags: self.tags(handle).to_vec(),
+                mesh,
+                camera: self.get_camera(handle).map(camera_desc),
+                light: self.get_light(handle).map(light_desc),
+            });
+
+            stack.extend(node.children().iter().rev().map(|&c| (c, Some(index))));
+        }
+
+        let env = &self.environment;
+        let environment = EnvironmentDesc {
+            env_map: env
+                .source_env_map
+                .as_ref()
+                .and_then(|source| texture_id(source, assets)),
+            intensity: env.intensity,
+            rotation: env.rotation.to_array(),
+            ambient: env.ambient.to_array(),
+            ambient_from_background: env.ambient_from_background,
+        };
+
+        Ok(SceneDescriptor {
+            version: SCENE_FORMAT_VERSION,
+            nodes,
+            active_camera,
+            environment,
+            background: background_desc(self.background.mode(), assets),
+        })
+    }
+
+    /// Builds a new scene from a [`SceneDescriptor`].
+    ///
+    /// Fails if a referenced asset cannot be resolved or a parent index does
+    /// not point at an earlier node.
+    pub fn from_scene_desc(desc: &SceneDescriptor, assets: &impl AssetIdResolver) -> Result<Self> {
+        let mut scene = Scene::new();
+        let mut handles = Vec::with_capacity(desc.nodes.len());
+
+        for (index, node_desc) in desc.nodes.iter().enumerate() {
+            let mut node = Node::new();
+            node.transform.position = Vec3::from_array(node_desc.position);
+            node.transform.rotation = Quat::from_array(node_desc.rotation);
+            node.transform.scale = Vec3::from_array(node_desc.scale);
+            node.visible = node_desc.visible;
+            node.layers = node_desc.layers;
+
+            let handle = match node_desc.parent {
+                None => scene.add_node(node),
+                Some(parent) if parent < index => scene.add_to_parent(node, handles[parent]),
+                Some(parent) => {
+                    return Err(AssetError::InvalidData(format!(
+                        "Node {index} references parent {parent}, which does not precede it"
+                    ))
+                    .into());
+                }
+            };
+            handles.push(handle);
+
+            if let Some(name) = &node_desc.name {
+                scene.set_name(handle, name);
+            }
+            for tag in &node_desc.tags {
+                scene.add_tag(handle, tag);
+            }
+            if let Some(mesh) = &node_desc.mesh {
+                scene.set_mesh(handle, mesh_from_desc(mesh, assets)?);
+            }
+            if let Some(camera) = &node_desc.camera {
+                scene.set_camera(handle, camera_from_desc(camera));
+            }
+            if let Some(light) = &node_desc.light {
+                scene.set_light(handle, light_from_desc(light));
+            }
+        }
+
+        scene.active_camera = desc.active_camera.and_then(|i| handles.get(i).copied());
+
+        scene
+            .background
+            .set_mode(background_from_desc(&desc.background, assets)?);
+
+        let env = &desc.environment;
+        let env_map = env
+            .env_map
+            .as_deref()
+            .map(|id| resolve(id, "texture", |id| assets.resolve_texture(id)))
+            .transpose()?;
+        scene.environment.set_env_map(env_map);
+        scene.environment.set_intensity(env.intensity);
+        scene
+            .environment
+            .set_rotation(Quat::from_array(env.rotation));
+        scene
+            .environment
+            .set_ambient_light(Vec3::from_array(env.ambient));
+        scene
+            .environment
+            .set_ambient_from_background(env.ambient_from_background);
+
+        Ok(scene)
+    }
+}
+
+fn all_layers() -> u32 {
+    ALL_LAYERS
+}
+
+fn resolve<H>(id: &str, kind: &str, f: impl FnOnce(&str) -> Option<H>) -> Result<H> {
+    f(id).ok_or_else(|| AssetError::NotFound(format!("{kind} asset '{id}'")).into())
+}
+
+fn texture_id(source: &TextureSource, assets: &impl AssetIdResolver) -> Option<String> {
+    match source {
+        TextureSource::Asset(handle) => assets.texture_id(*handle),
+        TextureSource::Attachment(..) => None,
+    }
+}
+
+fn mesh_desc(mesh: &Mesh, assets: &impl AssetIdResolver) -> Result<MeshDesc> {
+    let geometry = assets
+        .geometry_id(mesh.geometry)
+        .ok_or_else(|| AssetError::NotFound(format!("geometry of mesh '{}'", mesh.name)))?;
+    let material = assets
+        .material_id(mesh.material)
+        .ok_or_else(|| AssetError::NotFound(format!("material of mesh '{}'", mesh.name)))?;
+    let submeshes = mesh
+        .submeshes
+        .iter()
+        .map(|submesh| {
+            let material = assets.material_id(submesh.material).ok_or_else(|| {
+                AssetError::NotFound(format!("submesh material of mesh '{}'", mesh.name))
+            })?;
+            Ok(SubmeshDesc {
+                start: submesh.range.start,
+                end: submesh.range.end,
+                material,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(MeshDesc {
+        name: mesh.name.clone(),
+        geometry,
+        material,
+        submeshes,
+        visible: mesh.visible,
+        cast_shadows: mesh.cast_shadows,
+        receive_shadows: mesh.receive_shadows,
+        render_order: mesh.render_order,
+    })
+}
+
+fn mesh_from_desc(desc: &MeshDesc, assets: &impl AssetIdResolver) -> Result<Mesh> {
+    let geometry = resolve(&desc.geometry, "geometry", |id| assets.resolve_geometry(id))?;
+    let material = resolve(&desc.material, "material", |id| assets.resolve_material(id))?;
+    let submeshes = desc
+        .submeshes
+        .iter()
+        .map(|submesh| {
+            let material = resolve(&submesh.material, "material", |id| {
+                assets.resolve_material(id)
+            })?;
+            Ok(Submesh::new(submesh.start..submesh.end, material))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let mut mesh = Mesh::new(geometry, material);
+    mesh.submeshes = submeshes;
+    mesh.name.clone_from(&desc.name);
+    mesh.visible = desc.visible;
+    mesh.cast_shadows = desc.cast_shadows;
+    mesh.receive_shadows = desc.receive_shadows;
+    mesh.render_order = desc.render_order;
+    Ok(mesh)
+}
+
+fn camera_desc(camera: &Camera) -> CameraDesc {
+    CameraDesc {
+        name: camera.name.to_string(),
+        projection: match camera.projection_type() {
+            ProjectionType::Perspective => ProjectionDesc::Perspective,
+            ProjectionType::Orthographic => ProjectionDesc::Orthographic,
+        },
+        fov: camera.fov(),
+        aspect: camera.aspect(),
+        near: camera.near(),
+        far: camera.far().is_finite().then(|| camera.far()),
+        ortho_size: camera.ortho_size(),
+        layer_mask: camera.layer_mask,
+    }
+}
+
+fn camera_from_desc(desc: &CameraDesc) -> Camera {
+    let far = desc.far.unwrap_or(f32::INFINITY);
+    let mut camera = match desc.projection {
+        ProjectionDesc::Perspective => {
+            let mut camera = Camera::new_perspective(desc.fov.to_degrees(), desc.aspect, desc.near);
+            camera.set_far(far);
+            camera.set_ortho_size(desc.ortho_size);
+            camera
+        }
+        ProjectionDesc::Orthographic => {
+            Camera::new_orthographic(desc.ortho_size, desc.aspect, desc.near, far)
+        }
+    };
+    // Restore the exact radians value rather than the degree round-trip.
+    camera.set_fov(desc.fov);
+    camera.name = desc.name.clone().into();
+    camera.layer_mask = desc.layer_mask;
+    camera
+}
+
+fn light_desc(light: &Light) -> LightDesc {
+    LightDesc {
+        color: light.color.to_array(),
+        intensity: light.intensity,
+        physical_units: light.units == LightUnits::PhysicalUnits,
+        kind: match &light.kind {
+            LightKind::Directional(_) => LightKindDesc::Directional,
+            LightKind::Point(point) => LightKindDesc::Point { range: point.range },
+            LightKind::Spot(spot) => LightKindDesc::Spot {
+                range: spot.range,
+                inner_cone: spot.inner_cone,
+                outer_cone: spot.outer_cone,
+            },
+        },
+        cast_shadows: light.cast_shadows,
+        shadow: light.shadow.as_ref().map(|s| ShadowDesc {
+            bias: s.bias,
+            normal_bias: s.normal_bias,
+            map_size: s.map_size,
+            cascade_count: s.cascade_count,
+            cascade_split_lambda: s.cascade_split_lambda,
+            max_shadow_distance: s.max_shadow_distance,
+        }),
+        shadow_layers: light.shadow_layers,
+    }
+}
+
+fn light_from_desc(desc: &LightDesc) -> Light {
+    let color = Vec3::from_array(desc.color);
+    let mut light = match desc.kind {
+        LightKindDesc::Directional => Light::new_directional(color, desc.intensity),
+        LightKindDesc::Point { range } => Light::new_point(color, desc.intensity, range),
+        LightKindDesc::Spot {
+            range,
+            inner_cone,
+            outer_cone,
+        } => Light::new_spot(color, desc.intensity, range, inner_cone, outer_cone),
+    };
+    if desc.physical_units {
+        light.units = LightUnits::PhysicalUnits;
+    }
+    light.cast_shadows = desc.cast_shadows;
+    light.shadow = desc.shadow.as_ref().map(|s| ShadowConfig {
+        bias: s.bias,
+        normal_bias: s.normal_bias,
+        map_size: s.map_size,
+        cascade_count: s.cascade_count,
+        cascade_split_lambda: s.cascade_split_lambda,
+        max_shadow_distance: s.max_shadow_distance,
+    });
+    light.shadow_layers = desc.shadow_layers;
+    light
+}
+
+fn background_desc(mode: &BackgroundMode, assets: &impl AssetIdResolver) -> BackgroundDesc {
+    match mode {
+        BackgroundMode::Color(color) => BackgroundDesc::Color(color.to_array()),
+        BackgroundMode::Gradient { top, bottom } => BackgroundDesc::Gradient {
+            top: top.to_array(),
+            bottom: bottom.to_array(),
+        },
+        BackgroundMode::Texture {
+            source,
+            rotation,
+            intensity,
+            mapping,
+        } => match texture_id(source, assets) {
+            Some(texture) => BackgroundDesc::Texture {
+                texture,
+                rotation: *rotation,
+                intensity: *intensity,
+                mapping: match mapping {
+                    BackgroundMapping::Cube => MappingDesc::Cube,
+                    BackgroundMapping::Equirectangular => MappingDesc::Equirectangular,
+                    BackgroundMapping::Planar => MappingDesc::Planar,
+                },
+            },
+            None => BackgroundDesc::default(),
+        },
+        BackgroundMode::Procedural(params) => {
+            BackgroundDesc::Procedural(procedural_sky_desc(params, assets))
+        }
+    }
+}
+
+fn background_from_desc(
+    desc: &BackgroundDesc,
+    assets: &impl AssetIdResolver,
+) -> Result<BackgroundMode> {
+    Ok(match desc {
+        BackgroundDesc::Color(color) => BackgroundMode::Color(Vec4::from_array(*color)),
+        BackgroundDesc::Gradient { top, bottom } => BackgroundMode::Gradient {
+            top: Vec4::from_array(*top),
+            bottom: Vec4::from_array(*bottom),
+        },
+        BackgroundDesc::Texture {
+            texture,
+            rotation,
+            intensity,
+            mapping,
+        } => BackgroundMode::Texture {
+            source: resolve(texture, "texture", |id| assets.resolve_texture(id))?.into(),
+            rotation: *rotation,
+            intensity: *intensity,
+            mapping: match mapping {
+                MappingDesc::Cube => BackgroundMapping::Cube,
+                MappingDesc::Equirectangular => BackgroundMapping::Equirectangular,
+                MappingDesc::Planar => BackgroundMapping::Planar,
+            },
+        },
+        BackgroundDesc::Procedural(sky) => {
+            BackgroundMode::Procedural(procedural_sky_from_desc(sky, assets)?)
+        }
+    })
+}
+
+fn procedural_sky_desc(
+    params: &ProceduralSkyParams,
+    assets: &impl AssetIdResolver,
+) -> ProceduralSkyDesc {
+    ProceduralSkyDesc {
+        sun_direction: params.sun_direction.to_array(),
+        moon_direction: params.moon_direction.to_array(),
+        star_axis: params.star_axis.to_array(),
+        sun_disk_size: params.sun_disk_size,
+        moon_disk_size: params.moon_disk_size,
+        sun_intensity: params.sun_intensity,
+        moon_intensity: params.moon_intensity,
+        rayleigh_scattering: params.rayleigh_scattering.to_array(),
+        rayleigh_scale_height: params.rayleigh_scale_height,
+        mie_scattering: params.mie_scattering,
+        mie_absorption: params.mie_absorption,
+        mie_scale_height: params.mie_scale_height,
+        mie_anisotropy: params.mie_anisotropy,
+        ozone_absorption: params.ozone_absorption.to_array(),
+        planet_radius: params.planet_radius,
+        atmosphere_radius: params.atmosphere_radius,
+        ground_albedo: params.ground_albedo.to_array(),
+        turbidity: params.turbidity,
+        moon_albedo_texture: params
+            .moon_albedo_texture
+            .as_ref()
+            .and_then(|source| texture_id(source, assets)),
+        starbox_texture: params
+            .starbox_texture
+            .as_ref()
+            .and_then(|source| texture_id(source, assets)),
+        star_intensity: params.star_intensity,
+        star_rotation: params.star_rotation,
+    }
+}
+
+fn procedural_sky_from_desc(
+    desc: &ProceduralSkyDesc,
+    assets: &impl AssetIdResolver,
+) -> Result<ProceduralSkyParams> {
+    let texture = |id: Option<&str>| -> Result<Option<TextureSource>> {
+        id.map(|id| resolve(id, "texture", |id| assets.resolve_texture(id)).map(Into::into))
+            .transpose()
+    };
+
+    let mut params = ProceduralSkyParams::default();
+    params.sun_direction = Vec3::from_array(desc.sun_direction);
+    params.moon_direction = Vec3::from_array(desc.moon_direction);
+    params.star_axis = Vec3::from_array(desc.star_axis);
+    params.sun_disk_size = desc.sun_disk_size;
+    params.moon_disk_size = desc.moon_disk_size;
+    params.sun_intensity = desc.sun_intensity;
+    params.moon_intensity = desc.moon_intensity;
+    params.rayleigh_scattering = Vec3::from_array(desc.rayleigh_scattering);
+    params.rayleigh_scale_height = desc.rayleigh_scale_height;
+    params.mie_scattering = desc.mie_scattering;
+    params.mie_absorption = desc.mie_absorption;
+    params.mie_scale_height = desc.mie_scale_height;
+    params.mie_anisotropy = desc.mie_anisotropy;
+    params.ozone_absorption = Vec3::from_array(desc.ozone_absorption);
+    params.planet_radius = desc.planet_radius;
+    params.atmosphere_radius = desc.atmosphere_radius;
+    params.ground_albedo = Vec3::from_array(desc.ground_albedo);
+    params.turbidity = desc.turbidity;
+    params.moon_albedo_texture = texture(desc.moon_albedo_texture.as_deref())?;
+    params.starbox_texture = texture(desc.starbox_texture.as_deref())?;
+    params.star_intensity = desc.star_intensity;
+    params.star_rotation = desc.star_rotation;
+    Ok(params)
+}
@@ -4,6 +4,9 @@
 //! borrow conflicts. This is an independent system that only needs to borrow
 //! the nodes `SlotMap` and `root_nodes` list.
 //!
+//! The same passes propagate visibility: each node's effective visibility
+//! (`visible_in_hierarchy`) is its own `visible` flag and'ed with its parent's.
+//!
 //! # Parallelization Strategy
 //!
 //! Scene graph updates can be parallelized in batches by level (BFS order):
@@ -126,14 +129,16 @@ pub fn update_hierarchy_batched(
 ) {
     for (level, batch) in batches.batches.iter().enumerate() {
         for &node_handle in batch {
-            // Get parent world matrix
-            let parent_world = if let Some(node) = nodes.get(node_handle) {
+            // Get parent world matrix and visibility
+            let (parent_world, parent_visible) = if let Some(node) = nodes.get(node_handle) {
                 if let Some(parent_handle) = node.parent {
                     nodes
                         .get(parent_handle)
-                        .map_or(Affine3A::IDENTITY, |p| p.transform.world_matrix)
+                        .map_or((Affine3A::IDENTITY, true), |p| {
+                            (p.transform.world_matrix, p.visible_in_hierarchy)
+                        })
                 } else {
-                    Affine3A::IDENTITY
+                    (Affine3A::IDENTITY, true)
                 }
             } else {
                 continue;
@@ -142,6 +147,7 @@ pub fn update_hierarchy_batched(
             // Update current node
             if let Some(node) = nodes.get_mut(node_handle) {
                 node.transform.previous_world_matrix = node.transform.world_matrix;
+                node.visible_in_hierarchy = parent_visible && node.visible;
 
                 let local_changed = node.transform.update_local_matrix();
                 let parent_changed = level > 0;
@@ -175,7 +181,7 @@ pub fn update_hierarchy(
     roots: &[NodeHandle],
 ) {
     for &root_handle in roots {
-        update_transform_recursive(nodes, cameras, root_handle, Affine3A::IDENTITY, false);
+        update_transform_recursive(nodes, cameras, root_handle, Affine3A::IDENTITY, false, true);
     }
 }
 
@@ -188,20 +194,22 @@ pub fn update_hierarchy_iterative(
     cameras: &mut SparseSecondaryMap<NodeHandle, Camera>,
     roots: &[NodeHandle],
 ) {
-    let mut stack: Vec<(NodeHandle, Affine3A, bool)> = Vec::with_capacity(64);
+    let mut stack: Vec<(NodeHandle, Affine3A, bool, bool)> = Vec::with_capacity(64);
 
     for &root_handle in roots.iter().rev() {
-        stack.push((root_handle, Affine3A::IDENTITY, false));
+        stack.push((root_handle, Affine3A::IDENTITY, false, true));
     }
 
-    while let Some((node_handle, parent_world_matrix, parent_changed)) = stack.pop() {
+    while let Some((node_handle, parent_world_matrix, parent_changed, parent_visible)) = stack.pop()
+    {
         // --- Phase 1: Mutable borrow, handle update logic ---
-        let (current_world, world_needs_update) = {
+        let (current_world, world_needs_update, visible) = {
             let Some(node) = nodes.get_mut(node_handle) else {
                 continue;
             };
 
             node.transform.previous_world_matrix = node.transform.world_matrix;
+            node.visible_in_hierarchy = parent_visible && node.visible;
 
             let local_changed = node.transform.update_local_matrix();
             let world_needs_update = local_changed || parent_changed;
@@ -215,7 +223,11 @@ pub fn update_hierarchy_iterative(
                 }
             }
 
-            (node.transform.world_matrix, world_needs_update)
+            (
+                node.transform.world_matrix,
+                world_needs_update,
+                node.visible_in_hierarchy,
+            )
         };
         // Closure/scope ends here, `node`'s mutable borrow lifetime ends
 
@@ -224,12 +236,49 @@ pub fn update_hierarchy_iterative(
         if let Some(node) = nodes.get(node_handle) {
             // Directly iterate over slice, no need for repeated get
             for &child_handle in node.children.iter().rev() {
-                stack.push((child_handle, current_world, world_needs_update));
+                stack.push((child_handle, current_world, world_needs_update, visible));
             }
         }
     }
 }
 
+/// Replaces a node's world matrix after the hierarchy update and re-derives
+/// the world matrices of its descendants from their local matrices.
+///
+/// Used by systems that constrain a node's world transform (e.g. billboards).
+/// `previous_world_matrix` is left alone since the hierarchy pass already
+/// recorded it this frame.
+pub fn override_world_matrix(
+    nodes: &mut SlotMap<NodeHandle, Node>,
+    cameras: &mut SparseSecondaryMap<NodeHandle, Camera>,
+    node_handle: NodeHandle,
+    world: Affine3A,
+) {
+    let mut stack: Vec<(NodeHandle, Affine3A)> = vec![(node_handle, world)];
+    let mut is_root = true;
+
+    while let Some((handle, parent_world)) = stack.pop() {
+        let Some(node) = nodes.get_mut(handle) else {
+            continue;
+        };
+        let new_world = if is_root {
+            is_root = false;
+            parent_world
+        } else {
+            parent_world * *node.transform.local_matrix()
+        };
+        node.transform.set_world_matrix(new_world);
+
+        if let Some(camera) = cameras.get_mut(handle) {
+            camera.update_view_projection(&new_world);
+        }
+
+        for &child in &node.children {
+            stack.push((child, new_world));
+        }
+    }
+}
+
 /// Recursively updates a single node and its subtree (original recursive version kept for reference)
 fn update_transform_recursive(
     nodes: &mut SlotMap<NodeHandle, Node>,
@@ -237,14 +286,16 @@ fn update_transform_recursive(
     node_handle: NodeHandle,
     parent_world_matrix: Affine3A,
     parent_changed: bool,
+    parent_visible: bool,
 ) {
     // Phase 1: Process current node
-    let (current_world_matrix, children_handles, world_needs_update) = {
+    let (current_world_matrix, children_handles, world_needs_update, visible) = {
         let Some(node) = nodes.get_mut(node_handle) else {
             return;
         };
 
         node.transform.previous_world_matrix = node.transform.world_matrix;
+        node.visible_in_hierarchy = parent_visible && node.visible;
 
         // 1. Smartly update local matrix
         let local_changed = node.transform.update_local_matrix();
@@ -266,7 +317,12 @@ fn update_transform_recursive(
         let world = node.transform.world_matrix;
         let children: Vec<NodeHandle> = node.children.clone();
 
-        (world, children, world_needs_update)
+        (
+            world,
+            children,
+            world_needs_update,
+            node.visible_in_hierarchy,
+        )
     };
 
     // Phase 2: Recursively process child nodes
@@ -277,6 +333,7 @@ fn update_transform_recursive(
             child_handle,
             current_world_matrix,
             world_needs_update,
+            visible,
         );
     }
 }
@@ -295,20 +352,29 @@ pub fn update_subtree(
     cameras: &mut SparseSecondaryMap<NodeHandle, Camera>,
     root_handle: NodeHandle,
 ) {
-    // Get parent node's world matrix (if exists)
-    let parent_world = if let Some(node) = nodes.get(root_handle) {
+    // Get parent node's world matrix and visibility (if exists)
+    let (parent_world, parent_visible) = if let Some(node) = nodes.get(root_handle) {
         if let Some(parent_handle) = node.parent {
             nodes
                 .get(parent_handle)
-                .map_or(Affine3A::IDENTITY, |p| p.transform.world_matrix)
+                .map_or((Affine3A::IDENTITY, true), |p| {
+                    (p.transform.world_matrix, p.visible_in_hierarchy)
+                })
         } else {
-            Affine3A::IDENTITY
+            (Affine3A::IDENTITY, true)
         }
     } else {
         return;
     };
 
-    update_transform_recursive(nodes, cameras, root_handle, parent_world, true);
+    update_transform_recursive(
+        nodes,
+        cameras,
+        root_handle,
+        parent_world,
+        true,
+        parent_visible,
+    );
 }
 
 #[cfg(test)]
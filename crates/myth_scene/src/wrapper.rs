@@ -19,6 +19,7 @@
 #![allow(clippy::must_use_candidate)]
 use glam::{Quat, Vec3};
 
+use crate::billboard::BillboardMode;
 use crate::scene::Scene;
 use myth_core::NodeHandle;
 
@@ -124,7 +125,7 @@ impl<'a> SceneNode<'a> {
         self
     }
 
-    /// Sets node visibility.
+    /// Sets node visibility. Hiding a node also hides its descendants.
     #[inline]
     pub fn set_visible(self, visible: bool) -> Self {
         if let Some(node) = self.scene.get_node_mut(self.handle) {
@@ -133,6 +134,15 @@ impl<'a> SceneNode<'a> {
         self
     }
 
+    /// Replaces the node's layer bitmask.
+    #[inline]
+    pub fn set_layers(self, mask: u32) -> Self {
+        if let Some(node) = self.scene.get_node_mut(self.handle) {
+            node.layers = mask;
+        }
+        self
+    }
+
     /// Sets the mesh cast_shadows flag (no-op if node has no mesh).
     #[inline]
     pub fn set_cast_shadows(self, cast: bool) -> Self {
@@ -156,4 +166,15 @@ impl<'a> SceneNode<'a> {
     pub fn set_shadows(self, cast: bool, receive: bool) -> Self {
         self.set_cast_shadows(cast).set_receive_shadows(receive)
     }
+
+    /// Makes the node face the active camera, or stops it with `None`
+    /// (see [`Scene::set_billboard`]).
+    #[inline]
+    pub fn set_billboard(self, mode: Option<BillboardMode>) -> Self {
+        match mode {
+            Some(mode) => self.scene.set_billboard(self.handle, mode),
+            None => self.scene.clear_billboard(self.handle),
+        }
+        self
+    }
 }
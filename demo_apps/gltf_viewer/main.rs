@@ -36,6 +36,7 @@ use myth::RenderableMaterialTrait;
 use myth::assets::SharedPrefab;
 use myth::prelude::*;
 use myth::renderer::core::{BindingResource, ResourceBuilder};
+use myth::resources::Key;
 use myth::resources::texture::TextureSource;
 use myth_dev_utils::{FpsCounter, UiPass, UiPassNode};
 
@@ -270,12 +271,16 @@ struct GltfViewer {
     playback_speed: f32,
     /// Orbit controller
     controls: OrbitControls,
+    /// Transform gizmo for the node selected in the Inspector
+    gizmo: TransformGizmo,
     /// FPS counter
     fps_counter: FpsCounter,
     /// Current FPS
     current_fps: f32,
     /// Model file path or name (for display)
     model_name: Option<String>,
+    /// Bottom of the loaded model's bounds, where the ground shadow lies
+    ground_y: f32,
 
     // === Unified Async Event Channel ===
     /// Sender for all async events (cloned into background tasks).
@@ -310,6 +315,8 @@ struct GltfViewer {
     inspector_materials: Vec<MaterialInfo>,
     /// Collected texture list
     inspector_textures: Vec<TextureInfo>,
+    /// Draw the bones of skinned meshes as debug lines
+    show_skeleton: bool,
 
     // === Render Settings ===
     /// IBL toggle
@@ -455,9 +462,11 @@ impl AppHandler for GltfViewer {
             is_playing: true,
             playback_speed: 1.0,
             controls: OrbitControls::new(Vec3::new(0.0, 1.0, 5.0), Vec3::new(0.0, 1.0, 0.0)),
+            gizmo: TransformGizmo::new(),
             fps_counter: FpsCounter::new(),
             current_fps: 0.0,
             model_name: None,
+            ground_y: 0.0,
 
             // Async events
             event_tx,
@@ -477,6 +486,7 @@ impl AppHandler for GltfViewer {
             inspector_target: None,
             inspector_materials: Vec::new(),
             inspector_textures: Vec::new(),
+            show_skeleton: false,
 
             // Render settings
             ibl_enabled: true,
@@ -576,15 +586,47 @@ impl AppHandler for GltfViewer {
         if let Some(gltf_node) = self.gltf_node
             && let Some(mixer) = scene.animation_mixers.get_mut(gltf_node)
         {
-            mixer.time_scale = self.playback_speed;
+            // Pausing freezes time instead of disabling the mixer, so the
+            // timeline slider can still scrub the pose.
+            mixer.time_scale = if self.is_playing {
+                self.playback_speed
+            } else {
+                0.0
+            };
+        }
+
+        // 3. Transform gizmo (W/E/R: translate/rotate/scale) on the selected node
+        let selected = match self.inspector_target {
+            Some(InspectorTarget::Node(node)) if self.show_inspector => Some(node),
+            _ => None,
+        };
+        self.gizmo.set_target(selected);
+        for (key, mode) in [
+            (Key::W, GizmoMode::Translate),
+            (Key::E, GizmoMode::Rotate),
+            (Key::R, GizmoMode::Scale),
+        ] {
+            if engine.input.get_key_down(key) {
+                self.gizmo.mode = mode;
+            }
         }
+        let gizmo_captured = self.gizmo.update(scene, &engine.input);
 
-        // 3. 相机控制
-        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+        // 4. 相机控制
+        if !gizmo_captured && let Some((transform, camera)) = scene.query_main_camera_bundle() {
             self.controls
                 .update(transform, &engine.input, camera.fov(), frame.dt);
         }
 
+        // Skeleton overlay (shared skeletons are drawn once)
+        if self.show_skeleton {
+            let skinned: Vec<NodeHandle> = scene.skins.keys().collect();
+            for node in skinned {
+                scene.debug_draw_skeleton(node, Vec4::new(0.0, 1.0, 0.5, 1.0));
+            }
+            scene.debug().depth_test = false;
+        }
+
         if self.vignette_breathing {
             let bpm = 30.0;
             let period = 60.0 / bpm;
@@ -597,7 +639,7 @@ impl AppHandler for GltfViewer {
                 .set_vignette_intensity(vignette_intensity);
         }
 
-        // 4. 构建 UI (requires winit window for egui-winit integration)
+        // 5. 构建 UI (requires winit window for egui-winit integration)
         if self.show_ui {
             let winit_window = window
                 .as_any()
@@ -685,10 +727,8 @@ impl GltfViewer {
         };
         scene.background.set_mode(bg_mode);
 
-        // Apply rotation for texture modes
-        if mode == SkyboxMode::Equirectangular {
-            scene.background.set_rotation(rotation_deg.to_radians());
-        }
+        // Texture backgrounds share the environment map and inherit its rotation.
+        scene.environment.set_rotation_y(rotation_deg.to_radians());
     }
 
     // ========================================================================
@@ -733,7 +773,9 @@ impl GltfViewer {
                     log::info!("Environment texture ready: {}", name);
                     self.env_texture = Some(handle);
                     self.skybox_file_name = Some(name);
-                    scene.environment.set_env_map(Some(handle));
+                    if self.ibl_enabled {
+                        scene.environment.set_env_map(Some(handle));
+                    }
                     if self.skybox_mode == SkyboxMode::Equirectangular {
                         Self::apply_skybox(
                             scene,
@@ -815,6 +857,10 @@ impl GltfViewer {
         if let Some(bbox) = scene.get_bbox_of_node(gltf_node, assets) {
             let center = bbox.center();
             let radius = bbox.size().length() * 0.5;
+            self.ground_y = bbox.min.y;
+            if let Some(shadow) = scene.ground_shadow_mut() {
+                shadow.plane_y = self.ground_y;
+            }
             if let Some((_transform, camera)) = scene.query_main_camera_bundle() {
                 camera.set_near(radius / 100.0);
                 self.controls.set_target(center);
@@ -970,14 +1016,8 @@ impl GltfViewer {
         let mut visited_materials = std::collections::HashSet::new();
         let mut visited_textures = std::collections::HashSet::new();
 
-        // 遍历所有节点
-        let mut stack = vec![root];
-        while let Some(node_handle) = stack.pop() {
-            // 收集子节点
-            if let Some(node) = scene.get_node(node_handle) {
-                stack.extend(node.children().iter().cloned());
-            }
-
+        // 遍历根节点及其所有子孙节点
+        for node_handle in std::iter::once(root).chain(scene.iter_descendants(root)) {
             // 收集 Mesh 的材质
             if let Some(mesh) = scene.get_mesh(node_handle) {
                 let mat_handle = mesh.material;
@@ -1348,9 +1388,11 @@ impl GltfViewer {
                                                 )
                                                 .changed()
                                             {
-                                                let radians = self.skybox_rotation.to_radians();
-                                                scene.background.set_rotation(radians);
-                                                scene.environment.rotation = radians;
+                                                // The background shows the environment map, so it
+                                                // follows the environment rotation automatically.
+                                                scene.environment.set_rotation_y(
+                                                    self.skybox_rotation.to_radians(),
+                                                );
                                             }
                                         });
                                     }
@@ -1374,10 +1416,12 @@ impl GltfViewer {
                                 // --- IBL 环境贴图 ---
                                 ui.horizontal(|ui| {
                                     if ui.checkbox(&mut self.ibl_enabled, "IBL").changed() {
-                                        scene.environment.set_intensity(if self.ibl_enabled {
-                                            3.0
+                                        // Detach the map instead of zeroing the intensity: the
+                                        // skybox shares the environment intensity while linked.
+                                        scene.environment.set_env_map(if self.ibl_enabled {
+                                            self.env_texture
                                         } else {
-                                            0.0
+                                            None
                                         });
                                     }
 
@@ -1514,6 +1558,24 @@ impl GltfViewer {
                                         }
                                     }
                                 });
+
+                                // --- Ground Shadow 地面阴影 ---
+                                ui.horizontal(|ui| {
+                                    let mut enabled = scene.ground_shadow().is_some();
+                                    if ui.checkbox(&mut enabled, "Ground Shadow").changed() {
+                                        scene.set_ground_shadow(enabled, self.ground_y, 0.5);
+                                    }
+                                    if let Some(shadow) = scene.ground_shadow_mut() {
+                                        ui.add(
+                                            egui::Slider::new(&mut shadow.softness, 0.0..=1.0)
+                                                .text("Softness"),
+                                        );
+                                        ui.add(
+                                            egui::Slider::new(&mut shadow.opacity, 0.0..=1.0)
+                                                .text("Opacity"),
+                                        );
+                                    }
+                                });
                             });
 
                         ui.separator();
@@ -1536,12 +1598,6 @@ impl GltfViewer {
                                             .clicked()
                                         {
                                             self.is_playing = !self.is_playing;
-                                            if let Some(gltf_node) = self.gltf_node
-                                                && let Some(mixer) =
-                                                    scene.animation_mixers.get_mut(gltf_node)
-                                            {
-                                                mixer.enabled = self.is_playing;
-                                            }
                                         }
 
                                         ui.label("Speed:");
@@ -1558,6 +1614,17 @@ impl GltfViewer {
                                         if let Some(mixer) =
                                             scene.animation_mixers.get_mut(gltf_node)
                                         {
+                                            let playing: Vec<String> = self
+                                                .animations
+                                                .iter()
+                                                .filter(|anim| {
+                                                    mixer
+                                                        .get_control_by_name(anim)
+                                                        .is_some_and(|action| action.is_active())
+                                                })
+                                                .cloned()
+                                                .collect();
+
                                             // checkbox for each animation clip
                                             for anim in &self.animations {
                                                 // if let Some(action) = mixer.get_control_by_name(anim) {
@@ -1587,6 +1654,22 @@ impl GltfViewer {
                                                             .selectable_label(current_active, name)
                                                             .clicked()
                                                         {
+                                                            // Switching clips: crossfade from whatever is playing
+                                                            if !current_active
+                                                                && let Some(from) = playing
+                                                                    .iter()
+                                                                    .find(|other| *other != anim)
+                                                            {
+                                                                mixer.crossfade(from, anim, 0.3);
+                                                                for other in &playing {
+                                                                    if other != from
+                                                                        && other != anim
+                                                                    {
+                                                                        mixer.stop(other);
+                                                                    }
+                                                                }
+                                                                return;
+                                                            }
                                                             mixer.stop_all();
                                                             current_active = !current_active;
                                                         }
@@ -1601,6 +1684,28 @@ impl GltfViewer {
                                                     }
                                                 });
                                             }
+
+                                            // Timeline scrubbing for the first playing clip
+                                            if let Some(current) = playing.first()
+                                                && let Some(action) = mixer.get_action(current)
+                                            {
+                                                let mut time = action.current_time();
+                                                let duration = action.duration();
+                                                ui.separator();
+                                                if ui
+                                                    .add(
+                                                        egui::Slider::new(
+                                                            &mut time,
+                                                            0.0..=duration,
+                                                        )
+                                                        .text("Time")
+                                                        .suffix("s"),
+                                                    )
+                                                    .changed()
+                                                {
+                                                    mixer.set_time(current, time);
+                                                }
+                                            }
                                         }
                                     }
                                 }
@@ -1676,11 +1781,13 @@ impl GltfViewer {
 
                             // --- Apply settings if anything changed ---
                             if settings_changed {
-                                renderer.update_settings(RendererSettings {
+                                let settings = RendererSettings {
                                     path: self.render_path,
                                     vsync: self.vsync,
                                     anisotropy_clamp: self.anisotropy_clamp,
-                                });
+                                    ..renderer.settings().clone()
+                                };
+                                renderer.update_settings(settings);
                             }
 
                             ui.separator();
@@ -1960,6 +2067,17 @@ impl GltfViewer {
                                         )
                                     });
 
+                                    ui.horizontal(|ui| {
+                                        ui.label("Radius:");
+                                        ui.add(
+                                            egui::Slider::new(
+                                                &mut uniforms_mut.vignette_radius,
+                                                0.5..=2.0,
+                                            )
+                                            .step_by(0.01),
+                                        )
+                                    });
+
                                     ui.horizontal(|ui| {
                                         ui.label("Color:");
                                         let mut color_arr = uniforms_mut.vignette_color.to_array();
@@ -2063,6 +2181,47 @@ impl GltfViewer {
                                         }
                                     });
 
+                                    // Threshold
+                                    ui.horizontal(|ui| {
+                                        ui.label("Threshold:");
+                                        let mut threshold = scene.bloom.threshold();
+                                        if ui
+                                            .add(
+                                                egui::Slider::new(&mut threshold, 0.0..=10.0)
+                                                    .step_by(0.05)
+                                                    .fixed_decimals(2),
+                                            )
+                                            .changed()
+                                        {
+                                            scene.bloom.set_threshold(threshold);
+                                        }
+                                    });
+
+                                    // Soft Knee
+                                    ui.horizontal(|ui| {
+                                        ui.label("Soft Knee:");
+                                        let mut soft_knee = scene.bloom.soft_knee();
+                                        if ui
+                                            .add(
+                                                egui::Slider::new(&mut soft_knee, 0.0..=1.0)
+                                                    .step_by(0.01)
+                                                    .fixed_decimals(2),
+                                            )
+                                            .changed()
+                                        {
+                                            scene.bloom.set_soft_knee(soft_knee);
+                                        }
+                                    });
+
+                                    // Tint
+                                    ui.horizontal(|ui| {
+                                        ui.label("Tint:");
+                                        let mut tint = scene.bloom.tint().to_array();
+                                        if ui.color_edit_button_rgb(&mut tint).changed() {
+                                            scene.bloom.set_tint(Vec3::from_array(tint));
+                                        }
+                                    });
+
                                     // Mip Levels
                                     ui.horizontal(|ui| {
                                         ui.label("Mip Levels:");
@@ -2260,6 +2419,20 @@ impl GltfViewer {
 
                         // ===== 信息显示 =====
                         ui.label(format!("FPS: {:.1}", self.current_fps));
+
+                        let stats = renderer.last_frame_stats();
+                        ui.label(format!(
+                            "Meshes: {} visible / {} culled",
+                            stats.visible_meshes, stats.culled_meshes
+                        ));
+                        ui.label(format!(
+                            "Draw calls: {} | Triangles: {}",
+                            stats.draw_calls, stats.triangles
+                        ));
+                        ui.label(format!(
+                            "Pipeline switches: {} | Bind group switches: {}",
+                            stats.pipeline_switches, stats.bind_group_switches
+                        ));
                     });
             });
     }
@@ -2276,6 +2449,9 @@ impl GltfViewer {
             .default_height(500.0)
             .vscroll(false)
             .show(ctx, |ui| {
+                ui.checkbox(&mut self.show_skeleton, "🦴 Show Skeleton");
+                ui.separator();
+
                 ui.columns(2, |columns| {
                     columns[0].push_id("inspector_tree", |ui| {
                         let available_height = ui.available_height();
@@ -2468,6 +2644,7 @@ impl GltfViewer {
                 ui.label(if node_data.visible { "Yes" } else { "No" });
                 ui.end_row();
             });
+        ui.weak("Drag the viewport gizmo to edit (W / E / R: move / rotate / scale).");
 
         // Mesh 信息
         if let Some(mesh) = scene.get_mesh(node) {
@@ -2573,6 +2750,22 @@ impl GltfViewer {
                         ui.add(egui::DragValue::new(&mut uniform_mut.roughness).speed(0.01));
                         ui.end_row();
 
+                        ui.label("Normal Scale:");
+                        // glTF uses one scale for both axes; keep any per-axis sign.
+                        let mut normal_scale = uniform_mut.normal_scale.x.abs();
+                        if ui
+                            .add(
+                                egui::DragValue::new(&mut normal_scale)
+                                    .speed(0.01)
+                                    .range(0.0..=4.0),
+                            )
+                            .changed()
+                        {
+                            let sign = uniform_mut.normal_scale.signum();
+                            uniform_mut.normal_scale = sign * normal_scale;
+                        }
+                        ui.end_row();
+
                         ui.label("Specular Intensity:");
                         ui.add(
                             egui::DragValue::new(&mut uniform_mut.specular_intensity).speed(0.01),
@@ -2732,6 +2925,7 @@ impl GltfViewer {
                                 AlphaMode::Mask => "Mask",
                                 AlphaMode::Blend => "Blend",
                                 AlphaMode::BlendMask => "Blend Mask",
+                                AlphaMode::Hashed => "Hashed",
                             })
                             .show_ui(ui, |ui| {
                                 // 切换模式时，如果是 Mask 需要保留默认阈值
@@ -2776,6 +2970,15 @@ impl GltfViewer {
                                         settings.alpha_mode = AlphaMode::BlendMask;
                                     }
                                 }
+                                if ui
+                                    .selectable_label(
+                                        matches!(settings.alpha_mode, AlphaMode::Hashed),
+                                        "Hashed",
+                                    )
+                                    .clicked()
+                                {
+                                    settings.alpha_mode = AlphaMode::Hashed;
+                                }
                             });
 
                         // 如果是 Mask 或 BlendMask 模式，额外显示阈值滑块
@@ -2799,6 +3002,10 @@ impl GltfViewer {
                             ui.checkbox(&mut settings.depth_write, "Write");
                         });
                         ui.end_row();
+
+                        ui.label("Shading:");
+                        ui.checkbox(&mut settings.flat_shading, "Flat");
+                        ui.end_row();
                     }
                     // 纹理绑定
                     ui.separator();
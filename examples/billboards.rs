@@ -0,0 +1,102 @@
+//! [gallery]
+//! name = "Billboards"
+//! category = "Foundations"
+//! description = "Camera-facing quads: full and cylindrical billboards around an orbiting camera."
+//! order = 37
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+
+/// Billboards Example
+///
+/// The front row of quads uses `BillboardMode::Full` and always points at
+/// the camera; the back row uses `BillboardMode::Cylindrical` and stays
+/// upright. Every billboard carries a small arrow marker as a child to show
+/// that children follow the facing rotation.
+///
+/// - `Space`: pause / resume the camera orbit
+struct BillboardsDemo {
+    camera: NodeHandle,
+    angle: f32,
+    paused: bool,
+}
+
+impl AppHandler for BillboardsDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+
+        let ground = scene.spawn_plane(
+            20.0,
+            20.0,
+            UnlitMaterial::new(Vec4::new(0.2, 0.2, 0.25, 1.0)),
+            &engine.assets,
+        );
+        scene
+            .node(&ground)
+            .set_position(0.0, -1.0, 0.0)
+            .rotate_x(-std::f32::consts::FRAC_PI_2);
+
+        for (row, mode) in [BillboardMode::Full, BillboardMode::Cylindrical]
+            .into_iter()
+            .enumerate()
+        {
+            for i in 0..5 {
+                let t = i as f32 / 4.0;
+                let color = match mode {
+                    BillboardMode::Full => Vec4::new(0.9, 0.4 + 0.4 * t, 0.2, 1.0),
+                    BillboardMode::Cylindrical => Vec4::new(0.2, 0.5 + 0.4 * t, 0.9, 1.0),
+                };
+                let quad = scene.spawn_plane(1.2, 1.6, UnlitMaterial::new(color), &engine.assets);
+                scene.node(&quad).set_position(
+                    (i as f32 - 2.0) * 2.5,
+                    0.0,
+                    row as f32 * -4.0 + 2.0,
+                );
+                scene.set_billboard(quad, mode);
+
+                let marker =
+                    scene.spawn_box(0.2, 0.2, 0.6, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+                scene.attach(marker, quad);
+                scene.node(&marker).set_position(0.0, 0.0, 0.3);
+            }
+        }
+
+        let camera = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene.active_camera = Some(camera);
+
+        println!("Press Space to pause the camera orbit.");
+
+        Self {
+            camera,
+            angle: 0.0,
+            paused: false,
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, _window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        if engine.input.get_key_down(Key::Space) {
+            self.paused = !self.paused;
+        }
+        if !self.paused {
+            self.angle += frame.dt * 0.4;
+        }
+
+        // Orbit on a tilted circle so the full billboards visibly pitch.
+        let height = 3.0 + 2.5 * (self.angle * 0.7).sin();
+        let position = Vec3::new(12.0 * self.angle.sin(), height, 12.0 * self.angle.cos());
+        scene
+            .node(&self.camera)
+            .set_position_vec(position)
+            .look_at(Vec3::ZERO);
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<BillboardsDemo>()
+}
@@ -16,11 +16,14 @@
 //! - 1/2: Decrease/increase bloom strength
 //! - 3/4: Decrease/increase bloom radius
 //! - K: Toggle Karis average
+//! - D: Toggle lens dirt
 //! - B: Toggle bloom on/off
 //! - Up/Down: Adjust exposure
 
 use myth::prelude::*;
 use myth::resources::Key;
+use myth::resources::image::PixelFormat;
+use myth::resources::procedural::{self, NoiseParams};
 use myth_dev_utils::FpsCounter;
 
 const ASSET_PATH: &str = match option_env!("MYTH_ASSET_PATH") {
@@ -34,6 +37,7 @@ struct BloomDemo {
     fps_counter: FpsCounter,
     model_prefab: PrefabHandle,
     model_loaded: bool,
+    dirt_texture: TextureHandle,
 }
 
 impl AppHandler for BloomDemo {
@@ -63,6 +67,23 @@ impl AppHandler for BloomDemo {
         scene.bloom.set_radius(0.005);
         scene.bloom.set_karis_average(true);
 
+        // Lens dirt: blotchy noise that lights up around the bright spots
+        let dirt = engine.assets.images.add(procedural::noise_texture(
+            256,
+            256,
+            NoiseParams {
+                seed: 11,
+                frequency: 6,
+                format: PixelFormat::Rgba8Unorm,
+                ..Default::default()
+            },
+        ));
+        let dirt_texture = engine
+            .assets
+            .textures
+            .add(Texture::new_2d(Some("Lens Dirt"), dirt));
+        scene.bloom.set_dirt_intensity(1.0);
+
         // Setup camera
         let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
         scene
@@ -77,6 +98,7 @@ impl AppHandler for BloomDemo {
         println!("  1/2     - Decrease/increase bloom strength");
         println!("  3/4     - Decrease/increase bloom radius");
         println!("  K       - Toggle Karis average");
+        println!("  D       - Toggle lens dirt");
         println!("  Up/Down - Adjust exposure");
         println!("  Mouse   - Orbit camera");
 
@@ -86,6 +108,7 @@ impl AppHandler for BloomDemo {
             fps_counter: FpsCounter::new(),
             model_prefab,
             model_loaded: false,
+            dirt_texture,
         }
     }
 
@@ -157,6 +180,17 @@ impl AppHandler for BloomDemo {
             println!("Karis average: {}", if toggled { "ON" } else { "OFF" });
         }
 
+        // Toggle lens dirt
+        if input.get_key_down(Key::D) {
+            let dirt = scene
+                .bloom
+                .dirt_texture
+                .is_none()
+                .then_some(self.dirt_texture);
+            scene.bloom.set_dirt_texture(dirt);
+            println!("Lens dirt: {}", if dirt.is_some() { "ON" } else { "OFF" });
+        }
+
         // Exposure: Up = increase, Down = decrease
         if input.get_key_down(Key::ArrowUp) {
             let new_val = scene.tone_mapping.exposure() + 0.1;
@@ -0,0 +1,261 @@
+//! [gallery]
+//! name = "Custom Post-Process Pass"
+//! category = "Post-Processing"
+//! description = "User-defined desaturate pass inserted into the render graph before tone mapping."
+//! order = 320
+//!
+
+use myth::prelude::*;
+use myth::render::graph::{
+    ExecuteContext, HookStage, PassNode, PrepareContext, RenderTargetOps, SceneAttachment,
+    TextureNodeId,
+};
+use myth::renderer::core::gpu::Tracked;
+use myth_dev_utils::FpsCounter;
+use myth_resources::Key;
+
+const DESATURATE_WGSL: &str = r"
+@group(0) @binding(0) var t_input: texture_2d<f32>;
+
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_main(@builtin(position) frag_coord: vec4<f32>) -> @location(0) vec4<f32> {
+    let color = textureLoad(t_input, vec2<i32>(frag_coord.xy), 0);
+    let luma = dot(color.rgb, vec3<f32>(0.2126, 0.7152, 0.0722));
+    return vec4<f32>(vec3<f32>(luma), color.a);
+}
+";
+
+/// Long-lived GPU state of the desaturate effect.
+///
+/// Built once at startup; the per-frame [`DesaturateNode`] only borrows it.
+struct Desaturate {
+    pipeline: wgpu::RenderPipeline,
+    layout: Tracked<wgpu::BindGroupLayout>,
+}
+
+impl Desaturate {
+    fn new(device: &wgpu::Device) -> Self {
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("Desaturate BindGroup Layout"),
+            entries: &[wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                    multisampled: false,
+                },
+                count: None,
+            }],
+        });
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("Desaturate Shader"),
+            source: wgpu::ShaderSource::Wgsl(DESATURATE_WGSL.into()),
+        });
+
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("Desaturate Pipeline Layout"),
+            bind_group_layouts: &[Some(&layout)],
+            immediate_size: 0,
+        });
+
+        let pipeline = device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: Some("Desaturate Pipeline"),
+            layout: Some(&pipeline_layout),
+            vertex: wgpu::VertexState {
+                module: &module,
+                entry_point: Some("vs_main"),
+                buffers: &[],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            },
+            fragment: Some(wgpu::FragmentState {
+                module: &module,
+                entry_point: Some("fs_main"),
+                // The pass runs before tone mapping, so it writes HDR color.
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: myth::renderer::HDR_TEXTURE_FORMAT,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+                compilation_options: wgpu::PipelineCompilationOptions::default(),
+            }),
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            multiview_mask: None,
+            cache: None,
+        });
+
+        Self {
+            pipeline,
+            layout: Tracked::new(layout),
+        }
+    }
+}
+
+/// Per-frame pass node: reads the HDR scene color and writes a desaturated
+/// copy into a transient texture owned by the render graph.
+struct DesaturateNode<'a> {
+    input: TextureNodeId,
+    output: TextureNodeId,
+    effect: &'a Desaturate,
+    bind_group: Option<&'a wgpu::BindGroup>,
+}
+
+impl<'a> PassNode<'a> for DesaturateNode<'a> {
+    fn prepare(&mut self, ctx: &mut PrepareContext<'a>) {
+        self.bind_group = Some(
+            ctx.build_bind_group(&self.effect.layout, Some("Desaturate BindGroup"))
+                .bind_texture(0, self.input)
+                .build(),
+        );
+    }
+
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        let bind_group = self.bind_group.expect("Desaturate BG not prepared!");
+        let rtt = ctx.get_color_attachment(self.output, RenderTargetOps::DontCare, None);
+
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Desaturate Pass"),
+            color_attachments: &[rtt],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+
+        rpass.set_pipeline(&self.effect.pipeline);
+        rpass.set_bind_group(0, bind_group, &[]);
+        rpass.draw(0..3, 0..1);
+    }
+}
+
+/// Custom Post-Process Example
+///
+/// Shows how to insert a user pass between the scene and the built-in
+/// post-processing chain through a `BeforePostProcess` hook.
+struct CustomPostProcessDemo {
+    desaturate: Desaturate,
+    enabled: bool,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for CustomPostProcessDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let wgpu_ctx = engine
+            .renderer
+            .wgpu_ctx()
+            .expect("Renderer not initialized");
+        let desaturate = Desaturate::new(&wgpu_ctx.device);
+
+        let scene = engine.scene_manager.create_active();
+
+        let colors = [
+            Vec4::new(0.9, 0.2, 0.2, 1.0),
+            Vec4::new(0.2, 0.8, 0.3, 1.0),
+            Vec4::new(0.2, 0.4, 0.9, 1.0),
+        ];
+        for (i, color) in colors.into_iter().enumerate() {
+            let node = scene.spawn_box(1.0, 1.0, 1.0, PhysicalMaterial::new(color), &engine.assets);
+            scene
+                .node(&node)
+                .set_position((i as f32 - 1.0) * 1.5, 0.0, 0.0);
+        }
+
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 3.0));
+        scene.environment.set_ambient_light(Vec3::splat(0.1));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 2.0, 6.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Space: Toggle desaturate pass");
+
+        Self {
+            desaturate,
+            enabled: true,
+            controls: OrbitControls::new(Vec3::new(0.0, 2.0, 6.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        if engine.input.get_key_down(Key::Space) {
+            self.enabled = !self.enabled;
+        }
+
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let state = if self.enabled { "On" } else { "Off" };
+            window.set_title(&format!(
+                "Custom Post-Process | Desaturate: {state} | FPS: {fps:.0}"
+            ));
+        }
+    }
+
+    fn render(&mut self, engine: &mut Engine, _window: &dyn Window) {
+        let Some(composer) = engine.compose_frame() else {
+            return;
+        };
+
+        if !self.enabled {
+            composer.render();
+            return;
+        }
+
+        let effect = &self.desaturate;
+        composer
+            .add_custom_pass(HookStage::BeforePostProcess, move |rdg, blackboard| {
+                let Some(input) = blackboard.attachment(SceneAttachment::HdrColor) else {
+                    // The BasicForward path has no HDR stage to hook into.
+                    return blackboard;
+                };
+
+                let output = rdg.add_pass("Desaturate_Pass", |builder| {
+                    builder.read_texture(input);
+                    // Transient target with the same size and format as the
+                    // scene color; the graph recycles it after tone mapping.
+                    let desc = builder.texture_desc(input);
+                    let output = builder.create_texture("Desaturate_Out", desc);
+                    let node = DesaturateNode {
+                        input,
+                        output,
+                        effect,
+                        bind_group: None,
+                    };
+                    (node, output)
+                });
+
+                blackboard.with_attachment(SceneAttachment::HdrColor, output)
+            })
+            .render();
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new()
+        .with_settings(RendererSettings {
+            vsync: false,
+            ..Default::default()
+        })
+        .run::<CustomPostProcessDemo>()
+}
@@ -0,0 +1,114 @@
+//! [gallery]
+//! name = "Debug Draw"
+//! category = "Foundations"
+//! description = "Immediate-mode debug lines: world-space bounding boxes, spheres and axes."
+//! order = 35
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+/// Debug Draw Example
+///
+/// Every frame the bounding box of each mesh node is submitted through
+/// `scene.debug()`; nothing persists between frames.
+///
+/// - `D`: toggle depth testing of the debug lines
+struct DebugDrawDemo {
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+    spinners: Vec<NodeHandle>,
+}
+
+impl AppHandler for DebugDrawDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 2.0));
+
+        let mut spinners = Vec::new();
+        for i in 0..3 {
+            let x = (i as f32 - 1.0) * 3.0;
+            let color = Vec4::new(0.3 + 0.3 * i as f32, 0.6, 0.9 - 0.3 * i as f32, 1.0);
+            let node = if i == 1 {
+                scene.spawn_sphere(1.0, PhongMaterial::new(color), &engine.assets)
+            } else {
+                scene.spawn_box(1.5, 1.0, 0.6, PhongMaterial::new(color), &engine.assets)
+            };
+            scene.node(&node).set_position(x, 0.0, 0.0);
+            spinners.push(node);
+        }
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 4.0, 10.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Press D to toggle depth testing of the debug lines.");
+
+        Self {
+            controls: OrbitControls::new(Vec3::new(0.0, 4.0, 10.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+            spinners,
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let assets = engine.assets.clone();
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        for (i, handle) in self.spinners.iter().enumerate() {
+            if let Some(node) = scene.get_node_mut(*handle) {
+                let speed = 0.5 + 0.4 * i as f32;
+                node.transform.rotation *= Quat::from_rotation_y(speed * frame.dt)
+                    * Quat::from_rotation_x(0.3 * speed * frame.dt);
+            }
+        }
+
+        if engine.input.get_key_down(Key::D) {
+            let depth_test = !scene.debug().depth_test;
+            scene.debug().depth_test = depth_test;
+            println!(
+                "Debug line depth test: {}",
+                if depth_test { "ON" } else { "OFF" }
+            );
+        }
+
+        // World-space AABB of every mesh node (uses last frame's transforms).
+        let mut boxes = Vec::new();
+        for (handle, mesh) in &scene.meshes {
+            let (Some(node), Some(geometry)) =
+                (scene.get_node(handle), assets.geometries.get(mesh.geometry))
+            else {
+                continue;
+            };
+            boxes.push(geometry.bounding_box.transform(node.world_matrix()));
+        }
+
+        let debug = scene.debug();
+        for bbox in &boxes {
+            debug.aabb(bbox, Vec4::new(1.0, 1.0, 0.0, 1.0));
+        }
+        debug
+            .sphere(Vec3::ZERO, 5.0, Vec4::new(0.4, 0.4, 0.4, 1.0))
+            .axes(Vec3::ZERO, Quat::IDENTITY, 1.0);
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            window.set_title(&format!("Debug Draw - FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<DebugDrawDemo>()
+}
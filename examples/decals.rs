@@ -0,0 +1,164 @@
+//! [gallery]
+//! name = "Decals"
+//! category = "Materials"
+//! description = "Projected decals: splats on the ground and walls, and a rotating sign."
+//! order = 157
+//!
+
+use myth::prelude::*;
+use myth::resources::image::{ImageDimension, PixelFormat};
+use myth_dev_utils::FpsCounter;
+
+/// A soft round splat with a ragged edge, white so decals can tint it.
+fn splat_image(size: u32) -> Image {
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    let half = size as f32 * 0.5;
+    for y in 0..size {
+        for x in 0..size {
+            let dx = (x as f32 + 0.5 - half) / half;
+            let dy = (y as f32 + 0.5 - half) / half;
+            let angle = dy.atan2(dx);
+            let edge = 0.75 + 0.12 * (angle * 7.0).sin() + 0.06 * (angle * 13.0).cos();
+            let r = (dx * dx + dy * dy).sqrt();
+            let alpha = ((edge - r) * 12.0).clamp(0.0, 1.0);
+            data.extend_from_slice(&[255, 255, 255, (alpha * 255.0) as u8]);
+        }
+    }
+    Image::new(
+        size,
+        size,
+        1,
+        ImageDimension::D2,
+        PixelFormat::Rgba8Unorm,
+        Some(data),
+    )
+}
+
+/// Decals Example
+///
+/// Paint splats projected onto the ground and the faces of a few boxes,
+/// plus a checkerboard "sign" that slowly spins on the wall. Decals need the
+/// depth prepass of the `HighFidelity` render path (the default).
+struct DecalsDemo {
+    sign: NodeHandle,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for DecalsDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let splat = engine.assets.images.add(splat_image(128));
+        let splat = engine
+            .assets
+            .textures
+            .add(Texture::new_2d(Some("Splat"), splat));
+        let checker = engine.assets.images.add(Image::checkerboard(128, 128, 16));
+        let checker = engine
+            .assets
+            .textures
+            .add(Texture::new_2d(Some("Sign"), checker));
+
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_color(Vec4::new(0.05, 0.06, 0.08, 1.0));
+
+        let ground = scene.spawn_plane(
+            12.0,
+            12.0,
+            PhysicalMaterial::new(Vec4::new(0.6, 0.6, 0.62, 1.0)).with_roughness(0.8),
+            &engine.assets,
+        );
+        scene
+            .node(&ground)
+            .set_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2));
+
+        let wall = scene.spawn_box(
+            8.0,
+            3.0,
+            0.3,
+            PhysicalMaterial::new(Vec4::new(0.8, 0.78, 0.72, 1.0)).with_roughness(0.9),
+            &engine.assets,
+        );
+        scene.node(&wall).set_position(0.0, 1.5, -3.0);
+
+        for x in [-2.0, 0.0, 2.0] {
+            let cube = scene.spawn_box(
+                1.0,
+                1.0,
+                1.0,
+                PhysicalMaterial::new(Vec4::new(0.7, 0.7, 0.7, 1.0)),
+                &engine.assets,
+            );
+            scene.node(&cube).set_position(x, 0.5, 0.0);
+        }
+
+        // Ground splats project straight down; their boxes are tall enough
+        // to wrap over the cubes' edges.
+        let colors = [
+            Vec4::new(0.9, 0.2, 0.2, 1.0),
+            Vec4::new(0.2, 0.7, 0.3, 1.0),
+            Vec4::new(0.2, 0.4, 0.9, 1.0),
+            Vec4::new(0.9, 0.7, 0.1, 1.0),
+        ];
+        for (i, color) in colors.into_iter().enumerate() {
+            let x = i as f32 * 1.6 - 2.4;
+            scene.add_decal(
+                Transform::builder()
+                    .position(Vec3::new(x, 0.5, 1.0 - (i % 2) as f32))
+                    .rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+                    .scale(Vec3::new(1.5, 1.5, 2.0))
+                    .build(),
+                splat,
+                DecalOptions::default().with_color(color),
+            );
+        }
+
+        // The sign projects onto the wall's front face, along -Z.
+        let sign = scene.add_decal(
+            Transform::builder()
+                .position(Vec3::new(0.0, 1.6, -2.85))
+                .scale(Vec3::new(1.6, 1.6, 0.5))
+                .build(),
+            checker,
+            DecalOptions::default().with_opacity(0.85),
+        );
+
+        scene.add_light(Light::new_directional(Vec3::ONE, 2.0));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 4.0, 8.0)
+            .look_at(Vec3::new(0.0, 0.5, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            sign,
+            controls: OrbitControls::new(Vec3::new(0.0, 4.0, 8.0), Vec3::new(0.0, 0.5, 0.0)),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        scene
+            .node(&self.sign)
+            .set_rotation(Quat::from_rotation_z(frame.time * 0.3));
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            window.set_title(&format!("Decals | FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<DecalsDemo>()
+}
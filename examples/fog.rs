@@ -0,0 +1,150 @@
+//! [gallery]
+//! name = "Fog"
+//! category = "Environment"
+//! description = "Linear and exponential distance fog fading a ground plane into the sky."
+//! order = 260
+//!
+
+use std::f32::consts::FRAC_PI_2;
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+const SKY_TOP: Vec4 = Vec4::new(0.35, 0.55, 0.85, 1.0);
+const SKY_HORIZON: Vec4 = Vec4::new(0.75, 0.8, 0.85, 1.0);
+
+/// Fog Example
+///
+/// A ground plane and two rows of pillars run off toward the horizon. The fog
+/// takes its color from the gradient background, so the far edge of the
+/// ground disappears into the sky instead of ending in a hard line.
+///
+/// - `1` / `2` / `3`: linear / exponential / squared exponential fog
+/// - `F`: toggle fog
+/// - `B`: toggle background-matched fog color
+struct FogDemo {
+    mode: FogMode,
+    enabled: bool,
+    match_background: bool,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl FogDemo {
+    fn fog(&self) -> Option<Fog> {
+        if !self.enabled {
+            return None;
+        }
+        Some(Fog {
+            mode: self.mode,
+            color: Vec3::new(0.9, 0.85, 0.8),
+            use_background_color: self.match_background,
+        })
+    }
+}
+
+impl AppHandler for FogDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_gradient(SKY_TOP, SKY_HORIZON);
+
+        let ground = scene.spawn_plane(
+            400.0,
+            400.0,
+            PhysicalMaterial::new(Vec4::new(0.35, 0.45, 0.3, 1.0)).with_roughness(0.9),
+            &engine.assets,
+        );
+        scene
+            .node(&ground)
+            .set_rotation(Quat::from_rotation_x(-FRAC_PI_2));
+
+        for i in 0..20 {
+            for x in [-4.0, 4.0] {
+                let pillar = scene.spawn_box(
+                    1.0,
+                    4.0,
+                    1.0,
+                    PhysicalMaterial::new(Vec4::new(0.8, 0.75, 0.7, 1.0)),
+                    &engine.assets,
+                );
+                scene.node(&pillar).set_position(x, 2.0, -(i as f32) * 8.0);
+            }
+        }
+
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 3.0));
+        scene.environment.set_ambient_light(Vec3::splat(0.3));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(50.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 3.0, 12.0)
+            .look_at(Vec3::new(0.0, 2.0, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        println!("1/2/3: Linear/Exponential/Exp2 | F: Toggle fog | B: Toggle background color");
+
+        let demo = Self {
+            mode: FogMode::Linear {
+                near: 10.0,
+                far: 120.0,
+            },
+            enabled: true,
+            match_background: true,
+            controls: OrbitControls::new(Vec3::new(0.0, 3.0, 12.0), Vec3::new(0.0, 2.0, 0.0)),
+            fps_counter: FpsCounter::new(),
+        };
+        scene.environment.set_fog(demo.fog());
+        demo
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+        let input = &engine.input;
+
+        let previous = (self.mode, self.enabled, self.match_background);
+        if input.get_key_down(Key::Key1) {
+            self.mode = FogMode::Linear {
+                near: 10.0,
+                far: 120.0,
+            };
+        }
+        if input.get_key_down(Key::Key2) {
+            self.mode = FogMode::Exponential { density: 0.025 };
+        }
+        if input.get_key_down(Key::Key3) {
+            self.mode = FogMode::ExponentialSquared { density: 0.02 };
+        }
+        if input.get_key_down(Key::F) {
+            self.enabled = !self.enabled;
+        }
+        if input.get_key_down(Key::B) {
+            self.match_background = !self.match_background;
+        }
+        if previous != (self.mode, self.enabled, self.match_background) {
+            scene.environment.set_fog(self.fog());
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let mode = match (self.enabled, self.mode) {
+                (false, _) => "Off",
+                (true, FogMode::Linear { .. }) => "Linear",
+                (true, FogMode::Exponential { .. }) => "Exponential",
+                (true, FogMode::ExponentialSquared { .. }) => "Exp2",
+            };
+            window.set_title(&format!("Fog | {mode} | FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<FogDemo>()
+}
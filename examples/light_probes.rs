@@ -0,0 +1,164 @@
+//! [gallery]
+//! name = "Light Probes"
+//! category = "Materials"
+//! description = "Colored bounce light in a room from a baked grid of irradiance probes."
+//! order = 159
+//!
+
+use myth::math::UVec3;
+use myth::prelude::*;
+use myth::resources::Key;
+use myth::resources::geometry::BoundingBox;
+use myth_dev_utils::FpsCounter;
+
+/// Light Probes Example
+///
+/// A room with red and green walls, lit by a lamp and a sky seen through
+/// the open side. Without probes the white spheres only pick up the sky's
+/// ambient light; the baked probe grid adds the colored light bouncing off
+/// the walls. Press `L` to toggle the probes and `B` to bake them again
+/// after the cube has moved.
+struct LightProbesDemo {
+    cube: NodeHandle,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+fn bake_probes(scene: &mut Scene) {
+    let bounds = BoundingBox {
+        min: Vec3::new(-3.6, 0.3, -3.6),
+        max: Vec3::new(3.6, 3.6, 3.6),
+    };
+    scene
+        .bake_light_probes(bounds, UVec3::new(4, 3, 4))
+        .blend_distance = 0.5;
+}
+
+impl AppHandler for LightProbesDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_mode(BackgroundMode::procedural_with(
+            ProceduralSkyParams::midday(),
+        ));
+
+        let walls = [
+            // (size, position, color)
+            (
+                Vec3::new(8.0, 0.2, 8.0),
+                Vec3::new(0.0, -0.1, 0.0),
+                Vec4::new(0.8, 0.8, 0.8, 1.0),
+            ),
+            (
+                Vec3::new(8.0, 0.2, 8.0),
+                Vec3::new(0.0, 4.1, 0.0),
+                Vec4::new(0.8, 0.8, 0.8, 1.0),
+            ),
+            (
+                Vec3::new(0.2, 4.0, 8.0),
+                Vec3::new(-4.0, 2.0, 0.0),
+                Vec4::new(0.9, 0.1, 0.1, 1.0),
+            ),
+            (
+                Vec3::new(0.2, 4.0, 8.0),
+                Vec3::new(4.0, 2.0, 0.0),
+                Vec4::new(0.1, 0.8, 0.2, 1.0),
+            ),
+            (
+                Vec3::new(8.0, 4.0, 0.2),
+                Vec3::new(0.0, 2.0, -4.0),
+                Vec4::new(0.9, 0.9, 0.85, 1.0),
+            ),
+        ];
+        for (size, position, color) in walls {
+            let wall = scene.spawn_box(
+                size.x,
+                size.y,
+                size.z,
+                PhysicalMaterial::new(color).with_roughness(0.9),
+                &engine.assets,
+            );
+            scene
+                .node(&wall)
+                .set_position(position.x, position.y, position.z);
+        }
+
+        for x in [-2.5, 0.0, 2.5] {
+            let sphere = scene.spawn_sphere(
+                0.7,
+                PhysicalMaterial::new(Vec4::ONE).with_roughness(0.8),
+                &engine.assets,
+            );
+            scene.node(&sphere).set_position(x, 0.7, 0.5);
+        }
+
+        let cube = scene.spawn_box(
+            0.8,
+            0.8,
+            0.8,
+            PhysicalMaterial::new(Vec4::new(0.2, 0.3, 0.9, 1.0)),
+            &engine.assets,
+        );
+        scene.node(&cube).set_position(0.0, 0.4, -2.5);
+
+        let lamp = scene.add_light(Light::new_point(Vec3::new(1.0, 0.9, 0.7), 6.0, 12.0));
+        scene.node(&lamp).set_position(0.0, 3.5, 0.0);
+
+        bake_probes(scene);
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 2.0, 9.0)
+            .look_at(Vec3::new(0.0, 1.5, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            cube,
+            controls: OrbitControls::new(Vec3::new(0.0, 2.0, 9.0), Vec3::new(0.0, 1.5, 0.0)),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        // The probes keep their last bake while the cube moves.
+        scene
+            .node(&self.cube)
+            .set_position((frame.time * 0.5).sin() * 2.5, 0.4, -2.5);
+
+        if engine.input.get_key_down(Key::L) {
+            if scene.light_probes().is_some() {
+                scene.clear_light_probes();
+            } else {
+                bake_probes(scene);
+            }
+        }
+        if engine.input.get_key_down(Key::B)
+            && let Some(probes) = scene.light_probes_mut()
+        {
+            probes.bake();
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let probes = if scene.light_probes().is_some() {
+                "on"
+            } else {
+                "off"
+            };
+            window.set_title(&format!("Light Probes | probes {probes} | FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<LightProbesDemo>()
+}
@@ -0,0 +1,64 @@
+//! [gallery]
+//! name = "Lines"
+//! category = "Materials"
+//! description = "Line-list geometry with LineMaterial: a ground grid and colored world axes."
+//! order = 140
+//!
+
+use myth::prelude::*;
+
+/// Draws a ground grid and RGB world axes around a rotating cube.
+struct Lines {
+    controls: OrbitControls,
+}
+
+impl AppHandler for Lines {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let grid_geo = engine.assets.geometries.add(Geometry::new_grid(10.0, 20));
+        let grid_mat = engine
+            .assets
+            .materials
+            .add(LineMaterial::new(Vec4::new(0.5, 0.5, 0.5, 1.0)));
+
+        // Axes carry per-vertex colors, so the material color stays white.
+        let axes_geo = engine.assets.geometries.add(Geometry::new_axes(3.0));
+        let axes_mat = engine.assets.materials.add(LineMaterial::default());
+
+        let scene = engine.scene_manager.create_active();
+        scene.add_mesh(Mesh::new(grid_geo, grid_mat));
+        scene.add_mesh(Mesh::new(axes_geo, axes_mat));
+
+        let cube = scene.spawn_box(
+            1.0,
+            1.0,
+            1.0,
+            UnlitMaterial::new(Vec4::new(0.9, 0.6, 0.2, 1.0)),
+            &engine.assets,
+        );
+        scene.node(&cube).set_position(0.0, 0.5, 0.0);
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(6.0, 5.0, 8.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            controls: OrbitControls::new(Vec3::new(6.0, 5.0, 8.0), Vec3::ZERO),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, _window: &dyn Window, frame: &FrameState) {
+        let scene = engine.scene_manager.active_scene_mut().unwrap();
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<Lines>()
+}
@@ -0,0 +1,124 @@
+//! [gallery]
+//! name = "Many Lights"
+//! category = "Advanced"
+//! description = "256 moving point lights shaded through clustered forward lighting."
+//! order = 505
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+/// Lights per side of the square grid (16² = 256).
+const GRID_SIDE: u32 = 16;
+
+/// Many Lights Example
+///
+/// A field of pillars lit by 256 small point lights drifting over the
+/// floor. Each fragment only evaluates the lights whose range reaches its
+/// light cluster. Press `C` to switch between clustered lighting and the
+/// plain loop over every light and compare the frame rate.
+struct ManyLightsDemo {
+    lights: Vec<(NodeHandle, Vec3)>,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for ManyLightsDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.environment.set_ambient_light(Vec3::splat(0.02));
+
+        let floor = scene.spawn_box(
+            60.0,
+            0.2,
+            60.0,
+            PhysicalMaterial::new(Vec4::new(0.8, 0.8, 0.8, 1.0)).with_roughness(0.5),
+            &engine.assets,
+        );
+        scene.node(&floor).set_position(0.0, -0.1, 0.0);
+
+        for x in -4..=4 {
+            for z in -4..=4 {
+                let pillar = scene.spawn_box(
+                    0.6,
+                    3.0,
+                    0.6,
+                    PhysicalMaterial::new(Vec4::ONE).with_roughness(0.4),
+                    &engine.assets,
+                );
+                scene
+                    .node(&pillar)
+                    .set_position(x as f32 * 6.0, 1.5, z as f32 * 6.0);
+            }
+        }
+
+        let half = GRID_SIDE as f32 / 2.0;
+        let mut lights = Vec::new();
+        for i in 0..GRID_SIDE * GRID_SIDE {
+            let (x, z) = ((i % GRID_SIDE) as f32, (i / GRID_SIDE) as f32);
+            let hue = i as f32 / (GRID_SIDE * GRID_SIDE) as f32;
+            let color = Vec3::new(
+                (hue * std::f32::consts::TAU).sin() * 0.5 + 0.5,
+                (hue * std::f32::consts::TAU + 2.1).sin() * 0.5 + 0.5,
+                (hue * std::f32::consts::TAU + 4.2).sin() * 0.5 + 0.5,
+            );
+            let light = scene.add_light(Light::new_point(color, 4.0, 4.0));
+            let home = Vec3::new((x - half) * 3.0 + 1.5, 0.6, (z - half) * 3.0 + 1.5);
+            scene.node(&light).set_position(home.x, home.y, home.z);
+            lights.push((light, home));
+        }
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(50.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 14.0, 30.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            lights,
+            controls: OrbitControls::new(Vec3::new(0.0, 14.0, 30.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        if engine.input.get_key_down(Key::C) {
+            let mut settings = engine.renderer.settings().clone();
+            settings.clustered_lighting = !settings.clustered_lighting;
+            engine.renderer.update_settings(settings);
+        }
+        let clustered = engine.renderer.settings().clustered_lighting;
+
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        for (i, (light, home)) in self.lights.iter().enumerate() {
+            let phase = frame.time + i as f32 * 0.37;
+            scene.node(light).set_position(
+                home.x + phase.sin() * 1.2,
+                home.y,
+                home.z + (phase * 1.3).cos() * 1.2,
+            );
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let mode = if clustered { "clustered" } else { "all lights" };
+            window.set_title(&format!(
+                "Many Lights | 256 point lights, {mode} | FPS: {fps:.0}"
+            ));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<ManyLightsDemo>()
+}
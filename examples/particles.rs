@@ -0,0 +1,134 @@
+//! [gallery]
+//! name = "Particles"
+//! category = "Materials"
+//! description = "CPU particle systems: an additive spark fountain and alpha-blended smoke."
+//! order = 155
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+/// Particles Example
+///
+/// A spark fountain drawn with additive blending and a slow plume of
+/// alpha-blended smoke. Both are `ParticleSystem`s stepped on the CPU by
+/// `Scene::update`; the engine copies the live particles into their
+/// point-sprite geometries each frame.
+///
+/// - `Space`: burst of 200 sparks
+/// - `E`: toggle emission
+struct ParticlesDemo {
+    sparks: NodeHandle,
+    smoke: NodeHandle,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for ParticlesDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_color(Vec4::new(0.02, 0.02, 0.04, 1.0));
+
+        let sparks = scene.spawn_particles(
+            ParticleSystem::new(2048)
+                .with_rate(300.0)
+                .with_lifetime(1.6)
+                .with_velocity(Vec3::new(-1.2, 4.0, -1.2), Vec3::new(1.2, 6.0, 1.2))
+                .with_gravity(Vec3::new(0.0, -9.8, 0.0))
+                .with_colors(Vec4::new(1.0, 0.8, 0.3, 1.0), Vec4::new(1.0, 0.2, 0.0, 0.0))
+                .with_sizes(0.08, 0.02),
+            PointsMaterial::default()
+                .with_size_attenuation(true)
+                .with_round_sprite(true)
+                .with_alpha_mode(AlphaMode::Blend)
+                .with_additive_blending(true)
+                .with_depth_write(false),
+            &engine.assets,
+        );
+
+        let smoke = scene.spawn_particles(
+            ParticleSystem::new(256)
+                .with_rate(25.0)
+                .with_lifetime(4.0)
+                .with_velocity(Vec3::new(-0.2, 0.6, -0.2), Vec3::new(0.2, 1.0, 0.2))
+                .with_gravity(Vec3::new(0.3, 0.1, 0.0))
+                .with_colors(Vec4::new(0.5, 0.5, 0.5, 0.5), Vec4::new(0.3, 0.3, 0.3, 0.0))
+                .with_sizes(0.4, 1.5)
+                .with_seed(11),
+            PointsMaterial::default()
+                .with_size_attenuation(true)
+                .with_round_sprite(true)
+                .with_alpha_mode(AlphaMode::Blend)
+                .with_depth_write(false),
+            &engine.assets,
+        );
+        scene.node(&smoke).set_position(2.5, 0.0, 0.0);
+
+        let ground = scene.spawn_plane(
+            10.0,
+            10.0,
+            PhysicalMaterial::new(Vec4::new(0.2, 0.2, 0.22, 1.0)).with_roughness(0.8),
+            &engine.assets,
+        );
+        scene
+            .node(&ground)
+            .set_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2));
+        scene.add_light(Light::new_directional(Vec3::ONE, 2.0));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 3.0, 9.0)
+            .look_at(Vec3::new(1.0, 1.5, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Space: Burst | E: Toggle emission");
+
+        Self {
+            sparks,
+            smoke,
+            controls: OrbitControls::new(Vec3::new(0.0, 3.0, 9.0), Vec3::new(1.0, 1.5, 0.0)),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+        let input = &engine.input;
+
+        if input.get_key_down(Key::Space)
+            && let Some(sparks) = scene.particle_system_mut(self.sparks)
+        {
+            sparks.burst(200);
+        }
+        if input.get_key_down(Key::E) {
+            for node in [self.sparks, self.smoke] {
+                if let Some(system) = scene.particle_system_mut(node) {
+                    system.emitting = !system.emitting;
+                }
+            }
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let alive: usize = [self.sparks, self.smoke]
+                .iter()
+                .filter_map(|&node| scene.particle_system(node))
+                .map(ParticleSystem::alive_count)
+                .sum();
+            window.set_title(&format!("Particles | {alive} alive | FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<ParticlesDemo>()
+}
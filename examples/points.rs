@@ -0,0 +1,93 @@
+//! [gallery]
+//! name = "Point Cloud"
+//! category = "Materials"
+//! description = "Loads a colored PLY point cloud and draws it with PointsMaterial round sprites and size attenuation."
+//! order = 150
+//!
+
+use myth::prelude::*;
+
+const ASSET_PATH: &str = match option_env!("MYTH_ASSET_PATH") {
+    Some(path) => path,
+    None => "examples/assets/",
+};
+
+/// Loads a PLY point cloud and renders it as round, depth-attenuated sprites.
+struct PointCloud {
+    controls: OrbitControls,
+    cloud_prefab: PrefabHandle,
+    cloud_node: Option<NodeHandle>,
+    cloud_loaded: bool,
+}
+
+/// Switches the loader's default points material to blended world-space sprites.
+fn style_points(scene: &Scene, assets: &AssetServer) {
+    for mesh in scene.meshes.values() {
+        let Some(material) = assets.materials.get(mesh.material) else {
+            continue;
+        };
+        if let Some(points) = material.as_points() {
+            points.set_size(0.04);
+            points.set_size_attenuation(true);
+            points.set_round_sprite(true);
+            points.set_opacity(0.8);
+            points.set_alpha_mode(AlphaMode::Blend);
+            points.set_depth_write(false);
+        }
+    }
+}
+
+impl AppHandler for PointCloud {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let cloud_source = format!("{}points/galaxy.ply", ASSET_PATH);
+        println!("Loading point cloud from: {cloud_source}");
+        let cloud_prefab = engine.assets.load_ply(cloud_source);
+
+        let scene = engine.scene_manager.create_active();
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 6.0, 10.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            controls: OrbitControls::new(Vec3::new(0.0, 6.0, 10.0), Vec3::ZERO),
+            cloud_prefab,
+            cloud_node: None,
+            cloud_loaded: false,
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, _window: &dyn Window, frame: &FrameState) {
+        let assets = engine.assets.clone();
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        if !self.cloud_loaded {
+            if let Some(prefab) = assets.prefabs.get(self.cloud_prefab) {
+                self.cloud_node = Some(scene.instantiate(prefab.as_ref()));
+                style_points(scene, &assets);
+                self.cloud_loaded = true;
+            } else if let Some(err) = assets.prefabs.get_error(self.cloud_prefab) {
+                eprintln!("Failed to load point cloud: {err}");
+                self.cloud_loaded = true;
+            }
+        }
+
+        if let Some(node) = self.cloud_node.and_then(|id| scene.get_node_mut(id)) {
+            node.transform.rotation *= Quat::from_rotation_y(0.05 * frame.dt);
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<PointCloud>()
+}
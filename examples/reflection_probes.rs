@@ -0,0 +1,163 @@
+//! [gallery]
+//! name = "Reflection Probes"
+//! category = "Materials"
+//! description = "Local reflections in a colored room from a captured cubemap probe."
+//! order = 158
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+/// Reflection Probes Example
+///
+/// A room with red and green walls under an open sky. Without a probe the
+/// metal spheres reflect only the sky; the probe in the middle of the room
+/// captures the walls so the spheres pick them up. Press `P` to toggle the
+/// probe and `C` to capture it again after the cube has moved.
+struct ReflectionProbesDemo {
+    probe: Option<NodeHandle>,
+    cube: NodeHandle,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+fn spawn_probe(scene: &mut Scene) -> NodeHandle {
+    let probe = scene.add_reflection_probe(Vec3::new(0.0, 1.5, 0.0), 256);
+    let settings = scene.reflection_probe_mut(probe).unwrap();
+    settings.radius = 6.0;
+    settings.blend_distance = 1.5;
+    probe
+}
+
+impl AppHandler for ReflectionProbesDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_mode(BackgroundMode::procedural_with(
+            ProceduralSkyParams::midday(),
+        ));
+
+        let walls = [
+            // (size, position, color)
+            (
+                Vec3::new(8.0, 0.2, 8.0),
+                Vec3::new(0.0, -0.1, 0.0),
+                Vec4::new(0.8, 0.8, 0.8, 1.0),
+            ),
+            (
+                Vec3::new(0.2, 4.0, 8.0),
+                Vec3::new(-4.0, 2.0, 0.0),
+                Vec4::new(0.9, 0.1, 0.1, 1.0),
+            ),
+            (
+                Vec3::new(0.2, 4.0, 8.0),
+                Vec3::new(4.0, 2.0, 0.0),
+                Vec4::new(0.1, 0.8, 0.2, 1.0),
+            ),
+            (
+                Vec3::new(8.0, 4.0, 0.2),
+                Vec3::new(0.0, 2.0, -4.0),
+                Vec4::new(0.9, 0.9, 0.85, 1.0),
+            ),
+        ];
+        for (size, position, color) in walls {
+            let wall = scene.spawn_box(
+                size.x,
+                size.y,
+                size.z,
+                PhysicalMaterial::new(color).with_roughness(0.9),
+                &engine.assets,
+            );
+            scene
+                .node(&wall)
+                .set_position(position.x, position.y, position.z);
+        }
+
+        for (i, roughness) in [0.05, 0.25, 0.5].into_iter().enumerate() {
+            let sphere = scene.spawn_sphere(
+                0.6,
+                PhysicalMaterial::new(Vec4::ONE)
+                    .with_metalness(1.0)
+                    .with_roughness(roughness),
+                &engine.assets,
+            );
+            scene
+                .node(&sphere)
+                .set_position(i as f32 * 1.8 - 1.8, 0.6, 0.0);
+        }
+
+        let cube = scene.spawn_box(
+            0.8,
+            0.8,
+            0.8,
+            PhysicalMaterial::new(Vec4::new(0.2, 0.3, 0.9, 1.0)),
+            &engine.assets,
+        );
+        scene.node(&cube).set_position(0.0, 0.4, -2.5);
+
+        let probe = spawn_probe(scene);
+
+        let sun = scene.add_light(Light::new_directional(Vec3::ONE, 2.0));
+        scene.node(&sun).look_at(Vec3::new(-0.5, -1.0, -0.3));
+        let lamp = scene.add_light(Light::new_point(Vec3::new(1.0, 0.9, 0.7), 3.0, 12.0));
+        scene.node(&lamp).set_position(0.0, 3.5, 0.0);
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 2.5, 7.0)
+            .look_at(Vec3::new(0.0, 1.0, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            probe: Some(probe),
+            cube,
+            controls: OrbitControls::new(Vec3::new(0.0, 2.5, 7.0), Vec3::new(0.0, 1.0, 0.0)),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        // The probe keeps its last capture while the cube moves.
+        scene
+            .node(&self.cube)
+            .set_position((frame.time * 0.5).sin() * 2.5, 0.4, -2.5);
+
+        if engine.input.get_key_down(Key::P) {
+            self.probe = match self.probe.take() {
+                Some(probe) => {
+                    scene.remove_node(probe);
+                    None
+                }
+                None => Some(spawn_probe(scene)),
+            };
+        }
+        if engine.input.get_key_down(Key::C)
+            && let Some(probe) = self.probe
+            && let Some(settings) = scene.reflection_probe_mut(probe)
+        {
+            settings.capture();
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let probe = if self.probe.is_some() { "on" } else { "off" };
+            window.set_title(&format!(
+                "Reflection Probes | probe {probe} | FPS: {fps:.0}"
+            ));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<ReflectionProbesDemo>()
+}
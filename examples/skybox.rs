@@ -1,7 +1,7 @@
 //! [gallery]
 //! name = "Skybox & Backgrounds"
 //! category = "Environment"
-//! description = "Compares solid, gradient, panoramic, cubemap, and procedural background modes."
+//! description = "Compares solid, gradient, panoramic, cubemap, and procedural background modes, with adjustable blur."
 //! order = 220
 //!
 
@@ -18,6 +18,9 @@
 //! | `3` | Planar Texture |
 //! | `4` | Equirectangular HDR panorama as skybox |
 //! | `5` | Cubemap texture as skybox |
+//! | `6` | Procedural sky |
+//! | `Up` / `Down` | Background blur ± 0.1 (modes 4–6) |
+//! | `B` | Sweep background blur from 0 to 1 and back |
 //! | `H` | Toggle HDR / LDR rendering path |
 //! | Mouse drag | Orbit camera |
 //! | Scroll | Zoom |
@@ -64,6 +67,10 @@ struct SkyboxDemo {
     mode: DemoMode,
     /// Active render path (cached from renderer)
     render_path: RenderPath,
+    /// Background blur in `[0, 1]`
+    blur: f32,
+    /// Animate the blur back and forth
+    sweep_blur: bool,
     /// HDR environment texture handle (reused for equirectangular skybox)
     env_texture: TextureHandle,
     /// Cube map texture handle (if using CubeMap mode)
@@ -104,6 +111,8 @@ impl SkyboxDemo {
         println!("║  4 — Equirectangular HDR panorama     ║");
         println!("║  5 — Cubemap Skybox                   ║");
         println!("║  6 — Procedural Sky (Hillaire 2020)   ║");
+        println!("║  Up / Down — Background blur ± 0.1    ║");
+        println!("║  B — Sweep background blur            ║");
         println!("║  H — Toggle HighFidelity/BasicForward ║");
         println!("║  Mouse drag / Scroll — Orbit / Zoom   ║");
         println!("╚═══════════════════════════════════════╝");
@@ -169,6 +178,8 @@ impl AppHandler for SkyboxDemo {
             fps_counter: FpsCounter::new(),
             mode,
             render_path,
+            blur: 0.0,
+            sweep_blur: false,
             env_texture,
             cube_env_texture,
             helmet_prefab,
@@ -231,6 +242,25 @@ impl AppHandler for SkyboxDemo {
             println!("[Mode] → {}", self.mode.label());
         }
 
+        // --- Background blur ---
+        if engine.input.get_key_down(Key::B) {
+            self.sweep_blur = !self.sweep_blur;
+        }
+        if engine.input.get_key_down(Key::ArrowUp) {
+            self.sweep_blur = false;
+            self.blur = (self.blur + 0.1).min(1.0);
+        }
+        if engine.input.get_key_down(Key::ArrowDown) {
+            self.sweep_blur = false;
+            self.blur = (self.blur - 0.1).max(0.0);
+        }
+        if self.sweep_blur {
+            // Triangle wave: 0 → 1 → 0 every four seconds.
+            let t = (frame.time * 0.25).fract();
+            self.blur = 1.0 - (2.0 * t - 1.0).abs();
+        }
+        scene.environment.set_background_blur(self.blur);
+
         // --- HighFidelity / BasicForward toggle ---
         if engine.input.get_key_down(Key::H) {
             self.render_path = if self.render_path.supports_post_processing() {
@@ -261,8 +291,9 @@ impl AppHandler for SkyboxDemo {
                 "BasicForward"
             };
             window.set_title(&format!(
-                "Skybox Demo — {} | {} | FPS: {fps:.0}",
+                "Skybox Demo — {} | Blur {:.2} | {} | FPS: {fps:.0}",
                 self.mode.label(),
+                self.blur,
                 path,
             ));
         }
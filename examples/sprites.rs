@@ -0,0 +1,134 @@
+//! [gallery]
+//! name = "Sprites"
+//! category = "Materials"
+//! description = "Textured SpriteMaterial quads: billboarded world-space sprites, a pivoted marker and a constant-pixel-size label."
+//! order = 155
+//!
+
+use myth::prelude::*;
+use myth::resources::image::{ImageDimension, PixelFormat};
+
+const SPRITE_COUNT: usize = 12;
+
+/// Sprites Example
+///
+/// - A ring of glowing sprites sharing one material, each tinted per node
+///   and turned toward the camera by a full billboard.
+/// - A tall marker pivoted at its bottom edge with a cylindrical billboard,
+///   so it stands upright on the ground.
+/// - A label drawn at a constant 48px regardless of distance.
+struct Sprites {
+    controls: OrbitControls,
+}
+
+/// Builds a soft radial glow with alpha falloff.
+fn glow_image(size: u32) -> Image {
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let u = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let v = (y as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+            let falloff = (1.0 - (u * u + v * v).sqrt()).clamp(0.0, 1.0);
+            let alpha = falloff * falloff;
+            let core = (falloff * 2.0).min(1.0);
+            data.extend_from_slice(&[
+                255,
+                (200.0 + 55.0 * core) as u8,
+                (120.0 + 135.0 * core) as u8,
+                (alpha * 255.0) as u8,
+            ]);
+        }
+    }
+    Image::new(
+        size,
+        size,
+        1,
+        ImageDimension::D2,
+        PixelFormat::Rgba8Unorm,
+        Some(data),
+    )
+}
+
+impl AppHandler for Sprites {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let image = engine.assets.images.add(glow_image(64));
+        let mut texture = Texture::new_2d(Some("Glow"), image);
+        texture.color_space = ColorSpace::Srgb;
+        let glow = engine.assets.textures.add(texture);
+
+        let scene = engine.scene_manager.create_active();
+
+        let ground = scene.spawn_plane(
+            16.0,
+            16.0,
+            UnlitMaterial::new(Vec4::new(0.12, 0.12, 0.16, 1.0)),
+            &engine.assets,
+        );
+        scene
+            .node(&ground)
+            .set_position(0.0, -1.0, 0.0)
+            .rotate_x(-std::f32::consts::FRAC_PI_2);
+
+        // One material shared by the whole ring; colors come from node tints.
+        let glow_material = engine.assets.materials.add(SpriteMaterial::new(glow));
+        for i in 0..SPRITE_COUNT {
+            let t = i as f32 / SPRITE_COUNT as f32;
+            let angle = t * std::f32::consts::TAU;
+            let sprite = scene.spawn_sprite(glow_material, &engine.assets);
+            scene
+                .node(&sprite)
+                .set_position(
+                    4.0 * angle.cos(),
+                    0.5 * (angle * 3.0).sin(),
+                    4.0 * angle.sin(),
+                )
+                .set_scale(1.2);
+            let tint = Vec4::new(0.5 + 0.5 * angle.cos(), 0.6, 0.5 + 0.5 * angle.sin(), 1.0);
+            scene.set_node_tint(sprite, tint);
+        }
+
+        // Pivot at the bottom edge, upright: rests on the ground plane.
+        let marker = scene.spawn_sprite_sized(
+            SpriteMaterial::from_color(Vec4::new(0.3, 0.8, 1.0, 0.8))
+                .with_center(Vec2::new(0.5, 0.0)),
+            Vec2::new(0.3, 2.5),
+            &engine.assets,
+        );
+        scene.set_billboard(marker, BillboardMode::Cylindrical);
+        scene.node(&marker).set_position(0.0, -1.0, 0.0);
+
+        // Constant on-screen size: the billboard is not needed here.
+        let label = scene.spawn_sprite(
+            SpriteMaterial::new(glow).with_pixel_size(Vec2::splat(48.0)),
+            &engine.assets,
+        );
+        scene.clear_billboard(label);
+        scene.node(&label).set_position(0.0, 2.5, 0.0);
+
+        let camera = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&camera)
+            .set_position(0.0, 4.0, 12.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(camera);
+
+        Self {
+            controls: OrbitControls::new(Vec3::new(0.0, 4.0, 12.0), Vec3::ZERO),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, _window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<Sprites>()
+}
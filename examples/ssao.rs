@@ -0,0 +1,164 @@
+//! [gallery]
+//! name = "SSAO"
+//! category = "Post-Processing"
+//! description = "Before/after screen-space ambient occlusion on a field of crevices."
+//! order = 330
+//!
+
+//! Screen-Space Ambient Occlusion Example
+//!
+//! A grid of tightly packed blocks of varying height, lit mostly by the sky,
+//! so that the occlusion in the gaps between them is easy to compare with
+//! SSAO on and off.
+//!
+//! Controls:
+//! - Mouse drag: Orbit camera
+//! - Scroll: Zoom
+//! - O: Toggle SSAO (before/after)
+//! - H: Toggle half-resolution AO
+//! - 1/2: Decrease/increase blur passes
+//! - 3/4: Decrease/increase radius
+//! - 5/6: Decrease/increase intensity
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+const GRID: i32 = 9;
+const BLOCK: f32 = 0.5;
+const GAP: f32 = 0.08;
+
+struct SsaoDemo {
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+/// Deterministic pseudo-random height in `0.2..1.6` for a grid cell.
+fn block_height(x: i32, z: i32) -> f32 {
+    let hash = (x.wrapping_mul(73_856_093) ^ z.wrapping_mul(19_349_663)) as u32;
+    0.2 + (hash % 1000) as f32 / 1000.0 * 1.4
+}
+
+impl AppHandler for SsaoDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_mode(BackgroundMode::procedural_with(
+            ProceduralSkyParams::midday(),
+        ));
+
+        let ground = scene.spawn_box(
+            12.0,
+            0.2,
+            12.0,
+            PhysicalMaterial::new(Vec4::new(0.8, 0.8, 0.8, 1.0)).with_roughness(0.9),
+            &engine.assets,
+        );
+        scene.node(&ground).set_position(0.0, -0.1, 0.0);
+
+        let half = GRID / 2;
+        for x in -half..=half {
+            for z in -half..=half {
+                let height = block_height(x, z);
+                let block = scene.spawn_box(
+                    BLOCK,
+                    height,
+                    BLOCK,
+                    PhysicalMaterial::new(Vec4::new(0.85, 0.8, 0.7, 1.0)).with_roughness(0.8),
+                    &engine.assets,
+                );
+                scene.node(&block).set_position(
+                    x as f32 * (BLOCK + GAP),
+                    height * 0.5,
+                    z as f32 * (BLOCK + GAP),
+                );
+            }
+        }
+
+        // A weak sun keeps the sky as the dominant, occludable light.
+        let sun = scene.add_light(Light::new_directional(Vec3::ONE, 0.5));
+        scene.node(&sun).look_at(Vec3::new(-0.4, -1.0, -0.6));
+
+        scene.ssao.set_enabled(true);
+        scene.ssao.set_radius(0.3);
+        scene.ssao.set_intensity(1.5);
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(4.0, 4.0, 6.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        println!("=== SSAO Demo ===");
+        println!("Controls:");
+        println!("  O   - Toggle SSAO (before/after)");
+        println!("  H   - Toggle half-resolution AO");
+        println!("  1/2 - Decrease/increase blur passes");
+        println!("  3/4 - Decrease/increase radius");
+        println!("  5/6 - Decrease/increase intensity");
+
+        Self {
+            controls: OrbitControls::new(Vec3::new(4.0, 4.0, 6.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        let input = &engine.input;
+        let ssao = &mut scene.ssao;
+
+        if input.get_key_down(Key::O) {
+            ssao.set_enabled(!ssao.enabled);
+        }
+        if input.get_key_down(Key::H) {
+            ssao.set_half_resolution(!ssao.half_resolution);
+        }
+        if input.get_key_down(Key::Key1) {
+            ssao.set_blur_passes(ssao.blur_passes.saturating_sub(1));
+        }
+        if input.get_key_down(Key::Key2) {
+            ssao.set_blur_passes(ssao.blur_passes + 1);
+        }
+        if input.get_key_down(Key::Key3) {
+            ssao.set_radius(ssao.radius() - 0.05);
+        }
+        if input.get_key_down(Key::Key4) {
+            ssao.set_radius(ssao.radius() + 0.05);
+        }
+        if input.get_key_down(Key::Key5) {
+            ssao.set_intensity(ssao.intensity() - 0.25);
+        }
+        if input.get_key_down(Key::Key6) {
+            ssao.set_intensity(ssao.intensity() + 0.25);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let status = if ssao.enabled {
+                format!(
+                    "ON {} blur={} r={:.2} i={:.2}",
+                    if ssao.half_resolution { "half" } else { "full" },
+                    ssao.blur_passes,
+                    ssao.radius(),
+                    ssao.intensity()
+                )
+            } else {
+                "OFF".to_string()
+            };
+            window.set_title(&format!("SSAO Demo - FPS: {fps:.0} | SSAO: {status}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<SsaoDemo>()
+}
@@ -0,0 +1,153 @@
+//! [gallery]
+//! name = "Stencil Portal"
+//! category = "Materials"
+//! description = "Stencil-masked portal revealing a second world only through its opening."
+//! order = 160
+//!
+
+use myth::prelude::*;
+use myth_dev_utils::FpsCounter;
+use myth_resources::Key;
+
+/// Stencil value marking the portal opening.
+const PORTAL: u32 = 1;
+
+/// Stencil Portal Example
+///
+/// A mask quad writes `PORTAL` into the stencil buffer. Objects of the
+/// "inside" world only draw where the stencil equals `PORTAL`, objects of the
+/// "outside" world only where it does not, and the frame draws everywhere.
+struct StencilPortalDemo {
+    mask: NodeHandle,
+    mask_enabled: bool,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for StencilPortalDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        let inside = StencilSettings::equal(PORTAL);
+        let outside = StencilSettings::not_equal(PORTAL);
+
+        // Portal opening: drawn before everything else, without depth, so
+        // the inside world behind it still passes the depth test.
+        let mask_material = UnlitMaterial::new(Vec4::new(0.05, 0.02, 0.12, 1.0))
+            .with_depth_write(false)
+            .with_stencil(StencilSettings::write(PORTAL));
+        let mask = scene.spawn_box(2.0, 3.0, 0.01, mask_material, &engine.assets);
+        scene.node(&mask).set_position(0.0, 1.5, 0.0);
+        scene.get_mesh_mut(mask).unwrap().render_order = -1;
+
+        // Frame, visible from both worlds.
+        let frame_color = Vec4::new(0.8, 0.65, 0.3, 1.0);
+        for (x, y, w, h) in [
+            (-1.1, 1.6, 0.2, 3.2),
+            (1.1, 1.6, 0.2, 3.2),
+            (0.0, 3.1, 2.4, 0.2),
+        ] {
+            let post = scene.spawn_box(
+                w,
+                h,
+                0.3,
+                PhysicalMaterial::new(frame_color).with_metalness(1.0),
+                &engine.assets,
+            );
+            scene.node(&post).set_position(x, y, 0.0);
+        }
+
+        // Outside world: grey floor and blue pillars.
+        let floor = scene.spawn_box(
+            20.0,
+            0.1,
+            20.0,
+            PhysicalMaterial::new(Vec4::new(0.5, 0.5, 0.5, 1.0)).with_stencil(outside),
+            &engine.assets,
+        );
+        scene.node(&floor).set_position(0.0, -0.05, 0.0);
+        for x in [-4.0, 4.0] {
+            let pillar = scene.spawn_box(
+                1.0,
+                3.0,
+                1.0,
+                PhysicalMaterial::new(Vec4::new(0.2, 0.4, 0.9, 1.0)).with_stencil(outside),
+                &engine.assets,
+            );
+            scene.node(&pillar).set_position(x, 1.5, -3.0);
+        }
+
+        // Inside world: green floor and glowing spheres, behind the portal.
+        let inner_floor = scene.spawn_box(
+            20.0,
+            0.1,
+            20.0,
+            PhysicalMaterial::new(Vec4::new(0.1, 0.5, 0.2, 1.0)).with_stencil(inside),
+            &engine.assets,
+        );
+        scene.node(&inner_floor).set_position(0.0, -0.04, -10.0);
+        for (i, color) in [
+            Vec4::new(1.0, 0.3, 0.2, 1.0),
+            Vec4::new(1.0, 0.8, 0.2, 1.0),
+            Vec4::new(0.8, 0.3, 1.0, 1.0),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let sphere = scene.spawn_sphere(
+                0.6,
+                PhysicalMaterial::new(color)
+                    .with_emissive(color.truncate(), 0.5)
+                    .with_stencil(inside),
+                &engine.assets,
+            );
+            scene
+                .node(&sphere)
+                .set_position((i as f32 - 1.0) * 1.5, 0.6, -3.0 - i as f32 * 1.5);
+        }
+
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 3.0));
+        scene.environment.set_ambient_light(Vec3::splat(0.15));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(3.0, 2.5, 7.0)
+            .look_at(Vec3::new(0.0, 1.5, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Space: Toggle portal mask");
+
+        Self {
+            mask,
+            mask_enabled: true,
+            controls: OrbitControls::new(Vec3::new(3.0, 2.5, 7.0), Vec3::new(0.0, 1.5, 0.0)),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        if engine.input.get_key_down(Key::Space) {
+            self.mask_enabled = !self.mask_enabled;
+            scene.node(&self.mask).set_visible(self.mask_enabled);
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let state = if self.mask_enabled { "On" } else { "Off" };
+            window.set_title(&format!("Stencil Portal | Mask: {state} | FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<StencilPortalDemo>()
+}
@@ -0,0 +1,120 @@
+//! [gallery]
+//! name = "Sun-Linked Sky"
+//! category = "Environment"
+//! description = "Procedural sky driven by a shadow-casting directional light orbiting overhead."
+//! order = 250
+//!
+
+use myth::prelude::*;
+use myth_dev_utils::FpsCounter;
+
+/// Sun-Linked Sky Demo
+///
+/// A directional light sweeps across the sky; the procedural atmosphere
+/// follows it through `Scene::set_sky_sun_light`, so sky colour, IBL and
+/// shadows change together.
+struct SunSkyLinkDemo {
+    sun_node: NodeHandle,
+    sun_angle: f32,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl SunSkyLinkDemo {
+    fn place_sun(scene: &mut Scene, sun_node: NodeHandle, angle: f32) {
+        // Sweep east → zenith → west, tilted slightly south.
+        let to_sun = Vec3::new(angle.cos(), angle.sin(), 0.3).normalize();
+        scene
+            .node(&sun_node)
+            .set_position(to_sun.x * 20.0, to_sun.y * 20.0, to_sun.z * 20.0)
+            .look_at(Vec3::ZERO);
+    }
+}
+
+impl AppHandler for SunSkyLinkDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+
+        let mut sky = ProceduralSkyParams::midday();
+        sky.set_ground_albedo(Vec3::splat(0.3));
+        sky.set_turbidity(2.0);
+        scene.set_procedural_sky(sky);
+
+        let cube_node = scene.spawn_box(
+            1.5,
+            1.5,
+            1.5,
+            PhysicalMaterial::new(Vec4::new(0.9, 0.3, 0.2, 1.0)),
+            &engine.assets,
+        );
+        scene
+            .node(&cube_node)
+            .set_position(0.0, 0.75, 0.0)
+            .set_shadows(true, true);
+
+        let ground_node = scene.spawn_plane(
+            30.0,
+            30.0,
+            PhysicalMaterial::new(Vec4::new(0.8, 0.8, 0.8, 1.0)).with_side(Side::Double),
+            &engine.assets,
+        );
+        scene
+            .node(&ground_node)
+            .set_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+            .set_cast_shadows(false)
+            .set_receive_shadows(true);
+
+        let mut sun_light = Light::new_directional(Vec3::new(1.0, 0.95, 0.85), 3.0);
+        sun_light.cast_shadows = true;
+        let sun_node = scene.add_light(sun_light);
+        let sun_angle = 0.6;
+        Self::place_sun(scene, sun_node, sun_angle);
+        scene.set_sky_sun_light(Some(sun_node));
+
+        let cam_node = scene.add_camera(Camera::new_perspective(45.0, 16.0 / 9.0, 0.1));
+        scene
+            .node(&cam_node)
+            .set_position(8.0, 4.0, 8.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node);
+
+        Self {
+            sun_node,
+            sun_angle,
+            controls: OrbitControls::new(Vec3::new(8.0, 4.0, 8.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        // Keep the sun between just below the horizon on either side.
+        self.sun_angle += 0.15 * frame.dt;
+        if self.sun_angle > std::f32::consts::PI + 0.1 {
+            self.sun_angle = -0.1;
+        }
+        Self::place_sun(scene, self.sun_node, self.sun_angle);
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            window.set_title(&format!("Sun-Linked Sky | FPS: {fps:.2}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new()
+        .with_settings(RendererSettings {
+            vsync: false,
+            ..Default::default()
+        })
+        .run::<SunSkyLinkDemo>()
+}
@@ -0,0 +1,152 @@
+//! [gallery]
+//! name = "Text"
+//! category = "Materials"
+//! description = "Signed-distance-field text: world-space labels, outlines, glow and screen-space tags."
+//! order = 156
+//!
+
+use myth::prelude::*;
+use myth::resources::FontAtlasSettings;
+use myth_dev_utils::FpsCounter;
+
+const ASSET_PATH: &str = match option_env!("MYTH_ASSET_PATH") {
+    Some(path) => path,
+    None => "examples/assets/",
+};
+
+/// Text Example
+///
+/// Bakes a distance-field atlas from a TrueType font (the first command-line
+/// argument, or `fonts/NotoSans-Regular.ttf` under the asset path) and draws:
+///
+/// - a large outlined title standing on the ground,
+/// - a soft glowing caption,
+/// - billboarded labels above three boxes,
+/// - a screen-space tag that keeps its pixel size at any distance.
+struct TextDemo {
+    title: NodeHandle,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for TextDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let path = std::env::args()
+            .nth(1)
+            .unwrap_or_else(|| format!("{ASSET_PATH}fonts/NotoSans-Regular.ttf"));
+        let bytes = std::fs::read(&path).unwrap_or_else(|e| panic!("cannot read {path}: {e}"));
+        let atlas = FontAtlas::from_ttf(&bytes, &FontAtlasSettings::default())
+            .expect("failed to bake font atlas");
+        let font = engine.assets.create_font(atlas);
+
+        let scene = engine.scene_manager.create_active();
+        scene.background.set_color(Vec4::new(0.05, 0.06, 0.08, 1.0));
+
+        // Outlined title, one em = 0.8 units.
+        let title = scene.spawn_text(
+            &TextMesh::new("Myth Engine")
+                .with_size(0.8)
+                .with_anchor(Vec2::new(0.5, 0.0)),
+            &font,
+            TextMaterial::new(font.texture)
+                .with_color(Vec4::new(1.0, 0.85, 0.4, 1.0))
+                .with_outline(Vec4::new(0.2, 0.05, 0.0, 1.0), 0.15),
+            &engine.assets,
+        );
+        scene.node(&title).set_position(0.0, 2.2, 0.0);
+
+        // Soft glow: a wide, blurred outline in a bright color.
+        let caption = scene.spawn_text(
+            &TextMesh::new("signed distance fields\nstay sharp up close").with_size(0.3),
+            &font,
+            TextMaterial::new(font.texture)
+                .with_outline(Vec4::new(0.3, 0.6, 1.0, 0.8), 0.3)
+                .with_softness(0.1),
+            &engine.assets,
+        );
+        scene.node(&caption).set_position(0.0, 1.5, 0.0);
+
+        // Boxes with billboarded labels.
+        for (i, (name, color)) in [
+            ("Red", Vec4::new(0.9, 0.2, 0.2, 1.0)),
+            ("Green", Vec4::new(0.2, 0.8, 0.3, 1.0)),
+            ("Blue", Vec4::new(0.2, 0.4, 0.9, 1.0)),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let x = (i as f32 - 1.0) * 2.0;
+            let cube = scene.spawn_box(0.8, 0.8, 0.8, PhysicalMaterial::new(color), &engine.assets);
+            scene.node(&cube).set_position(x, 0.4, 0.0);
+
+            let label = scene.add_text(
+                name,
+                &font,
+                Transform::builder()
+                    .position(Vec3::new(x, 1.0, 0.0))
+                    .scale(Vec3::splat(0.3))
+                    .build(),
+                &engine.assets,
+            );
+            scene.set_billboard(label, BillboardMode::Full);
+        }
+
+        // Screen-space tag: 20 px per em wherever the camera is.
+        let tag = scene.spawn_text(
+            &TextMesh::new("origin").with_anchor(Vec2::new(0.5, 1.0)),
+            &font,
+            TextMaterial::new(font.texture).with_screen_space(20.0),
+            &engine.assets,
+        );
+        scene.node(&tag).set_position(0.0, -0.05, 1.5);
+
+        let ground = scene.spawn_plane(
+            10.0,
+            10.0,
+            PhysicalMaterial::new(Vec4::new(0.2, 0.2, 0.22, 1.0)).with_roughness(0.8),
+            &engine.assets,
+        );
+        scene
+            .node(&ground)
+            .set_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2));
+        scene.add_light(Light::new_directional(Vec3::ONE, 2.0));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 2.5, 7.0)
+            .look_at(Vec3::new(0.0, 1.2, 0.0));
+        scene.active_camera = Some(cam_node_id);
+
+        Self {
+            title,
+            controls: OrbitControls::new(Vec3::new(0.0, 2.5, 7.0), Vec3::new(0.0, 1.2, 0.0)),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        let sway = (frame.time * 0.5).sin() * 0.3;
+        scene
+            .node(&self.title)
+            .set_rotation(Quat::from_rotation_y(sway));
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            window.set_title(&format!("Text | FPS: {fps:.0}"));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<TextDemo>()
+}
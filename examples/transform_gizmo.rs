@@ -0,0 +1,142 @@
+//! [gallery]
+//! name = "Transform Gizmo"
+//! category = "Foundations"
+//! description = "Drag translate, rotate and scale handles to edit node transforms."
+//! order = 38
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+/// Transform Gizmo Example
+///
+/// The gizmo handles are drawn on the selected node; dragging one with the
+/// left mouse button edits the node, anywhere else orbits the camera.
+///
+/// - `Tab`: select the next object
+/// - `W` / `E` / `R`: translate / rotate / scale
+/// - `S`: toggle snapping
+struct TransformGizmoDemo {
+    gizmo: TransformGizmo,
+    objects: Vec<NodeHandle>,
+    selected: usize,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for TransformGizmoDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 2.0));
+        scene.environment.set_ambient_light(Vec3::splat(0.2));
+        scene.show_grid(GridOptions::default());
+        // Keep the handles visible through the objects.
+        scene.debug().depth_test = false;
+
+        let objects = vec![
+            scene.spawn_box(
+                1.0,
+                1.0,
+                1.0,
+                PhysicalMaterial::new(Vec4::new(0.9, 0.4, 0.2, 1.0)),
+                &engine.assets,
+            ),
+            scene.spawn_sphere(
+                0.6,
+                PhysicalMaterial::new(Vec4::new(0.2, 0.6, 0.9, 1.0)),
+                &engine.assets,
+            ),
+            scene.spawn_box(
+                1.6,
+                0.4,
+                0.8,
+                PhysicalMaterial::new(Vec4::new(0.4, 0.8, 0.3, 1.0)),
+                &engine.assets,
+            ),
+        ];
+        for (i, node) in objects.iter().enumerate() {
+            scene
+                .node(node)
+                .set_position((i as f32 - 1.0) * 2.5, 0.6, 0.0);
+        }
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 4.0, 9.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Tab: Select next | W/E/R: Translate/Rotate/Scale | S: Toggle snapping");
+
+        let mut gizmo = TransformGizmo::new();
+        gizmo.set_target(objects.first().copied());
+
+        Self {
+            gizmo,
+            objects,
+            selected: 0,
+            controls: OrbitControls::new(Vec3::new(0.0, 4.0, 9.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+        let input = &engine.input;
+
+        if input.get_key_down(Key::Tab) && !self.gizmo.is_dragging() {
+            self.selected = (self.selected + 1) % self.objects.len();
+            self.gizmo.set_target(Some(self.objects[self.selected]));
+        }
+        for (key, mode) in [
+            (Key::W, GizmoMode::Translate),
+            (Key::E, GizmoMode::Rotate),
+            (Key::R, GizmoMode::Scale),
+        ] {
+            if input.get_key_down(key) {
+                self.gizmo.mode = mode;
+            }
+        }
+        if input.get_key_down(Key::S) {
+            self.gizmo.snap = if self.gizmo.snap == GizmoSnap::default() {
+                GizmoSnap {
+                    translate: Some(0.5),
+                    rotate: Some(15f32.to_radians()),
+                    scale: Some(0.25),
+                }
+            } else {
+                GizmoSnap::default()
+            };
+        }
+
+        // The gizmo gets the mouse first; the camera only orbits when no
+        // handle is hovered or dragged.
+        let captured = self.gizmo.update(scene, input);
+        if !captured && let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            let snapping = if self.gizmo.snap == GizmoSnap::default() {
+                "Off"
+            } else {
+                "On"
+            };
+            window.set_title(&format!(
+                "Transform Gizmo | Mode: {:?} | Axis: {:?} | Snap: {snapping} | FPS: {fps:.0}",
+                self.gizmo.mode,
+                self.gizmo.active_axis(),
+            ));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<TransformGizmoDemo>()
+}
@@ -0,0 +1,107 @@
+//! [gallery]
+//! name = "Order-Independent Transparency"
+//! category = "Materials"
+//! description = "Intersecting transparent planes drawn with sorted blending or weighted blended OIT."
+//! order = 165
+//!
+
+use std::f32::consts::FRAC_PI_2;
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+/// Order-Independent Transparency Example
+///
+/// Three transparent planes cut through each other and an opaque sphere.
+/// Per-object sorting cannot order intersecting surfaces, so the sorted
+/// path pops as the camera orbits; weighted blended OIT composites them
+/// without sorting.
+///
+/// - `Space`: toggle sorted blending / weighted blended OIT
+struct TransparentPlanesDemo {
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+}
+
+impl AppHandler for TransparentPlanesDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let scene = engine.scene_manager.create_active();
+        scene.transparency_mode = TransparencyMode::WeightedBlended;
+
+        let planes = [
+            (Vec4::new(1.0, 0.2, 0.2, 0.5), Quat::IDENTITY),
+            (
+                Vec4::new(0.2, 1.0, 0.3, 0.5),
+                Quat::from_rotation_y(FRAC_PI_2),
+            ),
+            (
+                Vec4::new(0.2, 0.4, 1.0, 0.5),
+                Quat::from_rotation_x(FRAC_PI_2),
+            ),
+        ];
+        for (color, rotation) in planes {
+            let material = PhysicalMaterial::new(color)
+                .with_alpha_mode(AlphaMode::Blend)
+                .with_side(Side::Double)
+                .with_roughness(0.4);
+            let plane = scene.spawn_plane(3.0, 3.0, material, &engine.assets);
+            scene.get_node_mut(plane).unwrap().transform.rotation = rotation;
+        }
+
+        // Opaque content the transparent layers must composite over.
+        let sphere = scene.spawn_sphere(
+            0.6,
+            PhysicalMaterial::new(Vec4::new(0.9, 0.8, 0.3, 1.0)).with_metalness(1.0),
+            &engine.assets,
+        );
+        scene.node(&sphere).set_position(0.6, 0.6, 0.6);
+
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 3.0));
+        scene.environment.set_ambient_light(Vec3::splat(0.3));
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(4.0, 3.0, 5.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Space: Toggle sorted blending / weighted blended OIT");
+
+        Self {
+            controls: OrbitControls::new(Vec3::new(4.0, 3.0, 5.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        if engine.input.get_key_down(Key::Space) {
+            scene.transparency_mode = match scene.transparency_mode {
+                TransparencyMode::Sorted => TransparencyMode::WeightedBlended,
+                TransparencyMode::WeightedBlended => TransparencyMode::Sorted,
+            };
+        }
+
+        if let Some((transform, camera)) = scene.query_main_camera_bundle() {
+            self.controls
+                .update(transform, &engine.input, camera.fov(), frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            window.set_title(&format!(
+                "Order-Independent Transparency | {:?} | FPS: {fps:.0}",
+                scene.transparency_mode
+            ));
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new().run::<TransparentPlanesDemo>()
+}
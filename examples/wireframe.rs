@@ -0,0 +1,136 @@
+//! [gallery]
+//! name = "Wireframe"
+//! category = "Scenes & glTF"
+//! description = "Toggles per-material and global wireframe rendering on a glTF model."
+//! order = 615
+//!
+
+use myth::prelude::*;
+use myth::resources::Key;
+use myth_dev_utils::FpsCounter;
+
+const ASSET_PATH: &str = match option_env!("MYTH_ASSET_PATH") {
+    Some(path) => path,
+    None => "examples/assets/",
+};
+
+/// Wireframe Example
+///
+/// - `M`: toggle wireframe on the helmet's materials
+/// - `G`: toggle the global wireframe override
+///
+/// Devices without line polygon mode (WebGPU) fall back to the barycentric
+/// wireframe shader automatically.
+struct WireframeDemo {
+    cam_node_id: NodeHandle,
+    controls: OrbitControls,
+    fps_counter: FpsCounter,
+    helmet_prefab: PrefabHandle,
+    helmet_root: Option<NodeHandle>,
+    material_wireframe: bool,
+}
+
+impl AppHandler for WireframeDemo {
+    fn init(engine: &mut Engine, _window: &dyn Window) -> Self {
+        let map_path = format!("{ASSET_PATH}envs/royal_esplanade_2k.hdr.jpg");
+        let env_texture_handle = engine
+            .assets
+            .load_texture(map_path, ColorSpace::Srgb, false);
+
+        let scene = engine.scene_manager.create_active();
+        scene.environment.set_env_map(Some(env_texture_handle));
+        scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 1.0));
+
+        let helmet_source = format!("{ASSET_PATH}DamagedHelmet/glTF/DamagedHelmet.gltf");
+        let helmet_prefab = engine.assets.load_gltf(helmet_source);
+
+        let cam_node_id = scene.add_camera(Camera::new_perspective(45.0, 1280.0 / 720.0, 0.1));
+        scene
+            .node(&cam_node_id)
+            .set_position(0.0, 0.0, 3.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam_node_id);
+
+        println!("Press M to toggle material wireframe, G for the global override.");
+
+        Self {
+            cam_node_id,
+            controls: OrbitControls::new(Vec3::new(0.0, 0.0, 3.0), Vec3::ZERO),
+            fps_counter: FpsCounter::new(),
+            helmet_prefab,
+            helmet_root: None,
+            material_wireframe: true,
+        }
+    }
+
+    fn update(&mut self, engine: &mut Engine, window: &dyn Window, frame: &FrameState) {
+        if engine.input.get_key_down(Key::G) {
+            let mut settings = engine.renderer.settings().clone();
+            settings.wireframe = !settings.wireframe;
+            println!(
+                "Global wireframe: {}",
+                if settings.wireframe { "ON" } else { "OFF" }
+            );
+            engine.renderer.update_settings(settings);
+        }
+
+        let assets = engine.assets.clone();
+        let Some(scene) = engine.scene_manager.active_scene_mut() else {
+            return;
+        };
+
+        if self.helmet_root.is_none() {
+            if let Some(prefab) = assets.prefabs.get(self.helmet_prefab) {
+                self.helmet_root = Some(scene.instantiate(prefab.as_ref()));
+                set_wireframe(scene, &assets, self.material_wireframe);
+            } else if let Some(err) = assets.prefabs.get_error(self.helmet_prefab) {
+                eprintln!("Failed to load helmet glTF: {err}");
+            }
+        }
+
+        if engine.input.get_key_down(Key::M) {
+            self.material_wireframe = !self.material_wireframe;
+            set_wireframe(scene, &assets, self.material_wireframe);
+            println!(
+                "Material wireframe: {}",
+                if self.material_wireframe { "ON" } else { "OFF" }
+            );
+        }
+
+        if let Some(cam_node) = scene.get_node_mut(self.cam_node_id) {
+            self.controls
+                .update(&mut cam_node.transform, &engine.input, 45.0, frame.dt);
+        }
+
+        if let Some(fps) = self.fps_counter.update() {
+            window.set_title(&format!("Wireframe Demo - FPS: {fps:.0}"));
+        }
+    }
+}
+
+/// Applies the wireframe flag to every material used by the scene's meshes.
+fn set_wireframe(scene: &Scene, assets: &AssetServer, enabled: bool) {
+    for mesh in scene.meshes.values() {
+        let Some(material) = assets.materials.get(mesh.material) else {
+            continue;
+        };
+        if let Some(m) = material.as_physical() {
+            m.set_wireframe(enabled);
+        } else if let Some(m) = material.as_phong() {
+            m.set_wireframe(enabled);
+        } else if let Some(m) = material.as_unlit() {
+            m.set_wireframe(enabled);
+        }
+    }
+}
+
+#[myth::main]
+fn main() -> myth::Result<()> {
+    App::new()
+        .with_settings(RendererSettings {
+            path: RenderPath::BasicForward,
+            vsync: false,
+            ..Default::default()
+        })
+        .run::<WireframeDemo>()
+}
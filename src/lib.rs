@@ -54,6 +54,9 @@
 //! | `gltf` | **yes** | glTF 2.0 model loading |
 //! | `http` | **yes** | HTTP asset loading |
 //! | `gltf-meshopt` | no | Meshopt decompression for glTF |
+//! | `ply` | no | PLY mesh / point-cloud loading |
+//! | `hot-reload` | no | Native file-watch reloading of textures and models |
+//! | `shader-hot-reload` | no | Runtime WGSL template directory with live reload (native) |
 //! | `debug_view` | no | Render graph debug view targets |
 //! | `rdg_inspector` | no | Render graph inspector |
 //! | `3dgs` | no | 3D Gaussian Splatting support |
@@ -98,6 +101,7 @@ pub mod engine {
 
 pub mod utils {
     pub use myth_app::OrbitControls;
+    pub use myth_app::{GizmoAxis, GizmoMode, GizmoSnap, TransformGizmo};
 }
 
 // ============================================================================
@@ -113,10 +117,19 @@ pub mod math {
 // ============================================================================
 
 pub mod render {
-    pub use myth_render::graph::{FrameComposer, RenderState};
+    pub use myth_render::graph::{FrameComposer, RenderState, RenderStats};
     pub use myth_render::renderer::Renderer;
     pub use myth_render::settings::{RenderPath, RendererInitConfig, RendererSettings};
 
+    /// Render graph types for writing custom passes.
+    pub mod graph {
+        pub use myth_render::graph::core::{
+            ExecuteContext, GraphBlackboard, HookOrder, HookStage, PassBuilder, PassNode,
+            PrepareContext, RenderGraph, RenderTargetOps, SceneAttachment, TextureDesc,
+            TextureNodeId, TransientPool,
+        };
+    }
+
     /// Low-level GPU context access.
     pub mod core {
         pub use myth_render::core::ResourceManager;
@@ -135,28 +148,36 @@ pub mod prelude {
     #[cfg(feature = "winit")]
     pub use myth_app::winit::App;
     pub use myth_app::{AppHandler, Window};
-    pub use myth_app::{Engine, FrameState};
+    pub use myth_app::{Engine, FixedTimestep, FrameState};
 
     // Scene graph
-    pub use myth_core::{NodeHandle, SkeletonKey, Transform};
+    pub use myth_core::{NodeHandle, SkeletonKey, Transform, TransformBuilder};
     pub use myth_scene::camera::ProjectionType;
     pub use myth_scene::{
-        BackgroundMapping, BackgroundMode, BackgroundSettings, Camera, DayNightCycle, Light,
-        LightKind, Node, ProceduralSkyParams, Scene, SceneLogic, SceneNode,
+        BackgroundMapping, BackgroundMode, BackgroundSettings, BillboardMode, Camera,
+        DayNightCycle, DecalOptions, Fog, FogMode, GridOptions, Light, LightKind, LightUnits, Lod,
+        MorphNormalization, Node, ParticleSystem, ProceduralSkyParams, Scene, SceneLogic,
+        SceneNode, TransparencyMode, TwoBoneIk,
     };
 
     // Resources
+    pub use myth_resources::text::{Font, FontAtlas, TextAlign, TextMesh};
     pub use myth_resources::{
-        AlphaMode, BloomSettings, FxaaQuality, FxaaSettings, Geometry, Image, Material,
-        MaterialType, Mesh, PhongMaterial, PhysicalMaterial, Side, SsaoSettings, TaaSettings,
-        Texture, TextureSlot, UnlitMaterial,
+        AlphaMode, BloomSettings, FxaaQuality, FxaaSettings, Geometry, Image, LineMaterial,
+        Material, MaterialType, Mesh, PhongMaterial, PhysicalMaterial, PointsMaterial, Side,
+        SpriteMaterial, SsaoSettings, StencilSettings, Submesh, TaaSettings, TextMaterial, Texture,
+        TextureSlot, UnlitMaterial,
     };
 
     // Assets
+    #[cfg(feature = "hot-reload")]
+    pub use myth_assets::AssetReloaded;
     pub use myth_assets::ColorSpace;
     pub use myth_assets::SceneExt;
     #[cfg(feature = "gltf")]
     pub use myth_assets::loaders::gltf::GltfLoader;
+    #[cfg(feature = "ply")]
+    pub use myth_assets::loaders::ply_mesh::PlyLoader;
     pub use myth_assets::{
         AssetServer, GeometryHandle, ImageHandle, MaterialHandle, PrefabHandle, TextureHandle,
     };
@@ -172,6 +193,7 @@ pub mod prelude {
 
     // Utilities
     pub use myth_app::OrbitControls;
+    pub use myth_app::{GizmoAxis, GizmoMode, GizmoSnap, TransformGizmo};
 
     // Renderer
     pub use myth_render::graph::FrameComposer;
@@ -190,26 +212,30 @@ pub mod prelude {
 #[cfg(feature = "winit")]
 pub use myth_app::winit::App;
 pub use myth_app::{AppHandler, Window};
-pub use myth_app::{Engine, FrameState};
+pub use myth_app::{Engine, FixedTimestep, FrameState};
 pub use myth_macros::main;
 
 // Scene
-pub use myth_core::{NodeHandle, Transform};
+pub use myth_core::{NodeHandle, Transform, TransformBuilder};
 pub use myth_scene::{
-    BackgroundMapping, BackgroundMode, BackgroundSettings, Camera, DayNightCycle, Light, Node,
-    ProceduralSkyParams, Scene,
+    ALL_LAYERS, BackgroundMapping, BackgroundMode, BackgroundSettings, BillboardMode, Camera,
+    DayNightCycle, Decal, DecalOptions, Fog, FogMode, GridOptions, Light, LightProbeVolume,
+    LightUnits, Lod, LodLevel, MorphNormalization, Node, ParticleSystem, ProceduralSkyParams,
+    ReflectionProbe, Scene, SceneDescriptor, TransparencyMode, TwoBoneIk,
 };
 
 // Resources
 #[cfg(feature = "3dgs")]
 pub use myth_resources::gaussian_splat::GaussianCloud;
 pub use myth_resources::primitives::{
-    PlaneOptions, SphereOptions, create_box, create_plane, create_sphere,
+    PlaneOptions, SphereOptions, create_axes, create_box, create_grid, create_plane, create_sphere,
 };
+pub use myth_resources::text::{Font, FontAtlas, FontAtlasSettings, Glyph, TextAlign, TextMesh};
 pub use myth_resources::{
-    AgxLook, AlphaMode, AntiAliasingMode, Attribute, FxaaQuality, FxaaSettings, Geometry, Image,
-    IndexFormat, Material, MaterialTrait, MaterialType, Mesh, PhongMaterial, PhysicalMaterial,
-    RenderableMaterialTrait, ShaderDefines, Side, TaaSettings, Texture, TextureSlot,
+    AgxLook, AlphaMode, AntiAliasingMode, Attribute, AutoExposure, FxaaQuality, FxaaSettings,
+    Geometry, Image, IndexFormat, LineMaterial, Material, MaterialTrait, MaterialType, Mesh,
+    PhongMaterial, PhysicalMaterial, PointsMaterial, RenderableMaterialTrait, ShaderDefines, Side,
+    SpriteMaterial, StencilSettings, Submesh, TaaSettings, TextMaterial, Texture, TextureSlot,
     TextureTransform, ToneMappingMode, ToneMappingSettings, UnlitMaterial, VertexFormat,
 };
 
@@ -226,11 +252,11 @@ pub use myth_assets::load_gaussian_ply_from_source_async;
 pub use myth_assets::loaders::npz::load_gaussian_npz;
 #[cfg(feature = "3dgs")]
 pub use myth_assets::loaders::ply::load_gaussian_ply;
-pub use myth_assets::{AssetServer, GeometryHandle, ImageHandle, MaterialHandle, TextureHandle};
 pub use myth_assets::{
-    AssetSource, ColorSpace, GaussianCloudHandle, GeometryQuery, ResolveGeometry, ResolveMaterial,
-    SceneExt,
+    AssetIdResolver, AssetSource, ColorSpace, GaussianCloudHandle, GeometryQuery, ResolveGeometry,
+    ResolveMaterial, SceneExt,
 };
+pub use myth_assets::{AssetServer, GeometryHandle, ImageHandle, MaterialHandle, TextureHandle};
 
 // Animation
 pub use myth_animation::{
@@ -241,12 +267,13 @@ pub use myth_animation::{
 
 // Renderer
 pub use myth_render::Renderer;
-pub use myth_render::graph::FrameComposer;
+pub use myth_render::graph::{FrameComposer, RenderStats};
 pub use myth_render::settings::{RenderPath, RendererInitConfig, RendererSettings};
 
 // Errors
-pub use myth_core::{AssetError, Error, PlatformError, RenderError, Result};
+pub use myth_core::{AssetError, Error, PlatformError, RenderError, Result, SceneError};
 
 // Utilities
 pub use myth_app::OrbitControls;
+pub use myth_app::{GizmoAxis, GizmoMode, GizmoSnap, TransformGizmo};
 pub use myth_core::utils::interner;
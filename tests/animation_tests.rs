@@ -6,17 +6,23 @@
 //! - KeyframeCursor O(1) optimization and binary search fallback
 //! - AnimationAction loop modes (Once, Loop, PingPong)
 //! - AnimationClip duration auto-computation
+//! - AnimationMixer scrubbing with sample_at
+//! - Two-bone IK: target reach, pole, weight blending
 
 use std::f32::consts::{FRAC_PI_2, PI};
 use std::sync::Arc;
 
 use glam::{Quat, Vec3};
 
-use myth::animation::action::{AnimationAction, LoopMode};
-use myth::animation::binding::TargetPath;
+use myth::animation::action::{AnimationAction, AnimationBlendMode, LoopMode};
+use myth::animation::binding::{ClipBinding, Rig, TargetPath, TrackBinding};
 use myth::animation::clip::{AnimationClip, Track, TrackData, TrackMeta};
 use myth::animation::tracks::{InterpolationMode, KeyframeCursor, KeyframeTrack};
 use myth::animation::values::{Interpolatable, MorphWeightData};
+use myth::animation::{AnimationMixer, AnimationTarget};
+use myth::resources::Input;
+use myth::scene::{Node, Scene, TwoBoneIk};
+use myth::{NodeHandle, Transform};
 
 const EPSILON: f32 = 1e-5;
 
@@ -548,6 +554,43 @@ fn action_time_scale() {
     );
 }
 
+#[test]
+fn action_seek_resolves_time_per_loop_mode() {
+    let mut action = AnimationAction::new(make_simple_clip(2.0));
+    assert!(approx(action.duration(), 2.0));
+
+    action.loop_mode = LoopMode::Once;
+    action.seek(3.0);
+    assert!(approx(action.current_time(), 2.0));
+    action.seek(-1.0);
+    assert!(approx(action.current_time(), 0.0));
+
+    action.loop_mode = LoopMode::Loop;
+    action.seek(2.0);
+    assert!(approx(action.current_time(), 2.0), "end of clip is kept");
+    action.seek(5.5);
+    assert!(approx(action.current_time(), 1.5));
+    action.seek(-0.5);
+    assert!(approx(action.current_time(), 1.5));
+
+    action.loop_mode = LoopMode::PingPong;
+    action.seek(2.5);
+    assert!(approx(action.current_time(), 1.5));
+    // Reflected into the return leg: playback continues backwards.
+    action.update(0.5);
+    assert!(approx(action.current_time(), 1.0));
+}
+
+#[test]
+fn action_seek_does_not_advance_or_unpause() {
+    let mut action = AnimationAction::new(make_simple_clip(2.0));
+    action.paused = true;
+    action.seek(1.25);
+    assert!(action.paused);
+    action.update(1.0);
+    assert!(approx(action.current_time(), 1.25));
+}
+
 // ============================================================================
 // AnimationClip Auto-Duration
 // ============================================================================
@@ -594,3 +637,439 @@ fn clip_empty_tracks_zero_duration() {
     let clip = AnimationClip::new("empty".to_string(), vec![]);
     assert!(approx(clip.duration, 0.0));
 }
+
+// ============================================================================
+// AnimationMixer Blending & Crossfade
+// ============================================================================
+
+/// Constant-translation clip named `name` bound to the mixer's only bone.
+fn make_hold_action(name: &str, position: Vec3) -> AnimationAction {
+    let clip = Arc::new(AnimationClip::new(
+        name.to_string(),
+        vec![Track {
+            meta: TrackMeta {
+                path: vec!["node".to_string()],
+                target: TargetPath::Translation,
+            },
+            data: TrackData::Vector3(KeyframeTrack::new(
+                vec![0.0, 1.0],
+                vec![position, position],
+                InterpolationMode::Linear,
+            )),
+        }],
+    ));
+    let mut action = AnimationAction::new(clip);
+    action.clip_binding = ClipBinding {
+        bindings: vec![TrackBinding {
+            track_index: 0,
+            bone_index: 0,
+            target: TargetPath::Translation,
+        }],
+    };
+    action
+}
+
+fn mixer_scene() -> (Scene, NodeHandle, AnimationMixer) {
+    let mut scene = Scene::new();
+    let node = scene.add_node(Node::new());
+    scene.store_rest_transform(node, Transform::new());
+
+    let mut mixer = AnimationMixer::new();
+    mixer.set_rig(Rig {
+        bones: vec![node],
+        bone_paths: vec![vec!["node".to_string()]],
+    });
+    (scene, node, mixer)
+}
+
+fn node_position(scene: &Scene, node: NodeHandle) -> Vec3 {
+    scene.get_node(node).unwrap().transform.position
+}
+
+#[test]
+fn mixer_crossfade_ramps_weights() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("a", Vec3::X));
+    mixer.add_action(make_hold_action("b", Vec3::Y));
+
+    mixer.play("a");
+    mixer.update(0.1, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X, 1e-5));
+
+    mixer.crossfade("a", "b", 1.0);
+    mixer.update(0.5, &mut scene);
+    assert!(approx(mixer.get_action("a").unwrap().weight, 0.5));
+    assert!(approx(mixer.get_action("b").unwrap().weight, 0.5));
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::new(0.5, 0.5, 0.0), 1e-5));
+
+    mixer.update(0.5, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::Y, 1e-5));
+    let a = mixer.get_action("a").unwrap();
+    assert!(!a.enabled, "faded-out action should be stopped");
+    assert!(!mixer.get_action("b").unwrap().is_fading());
+}
+
+#[test]
+fn mixer_fade_in_starts_from_zero_weight() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("a", Vec3::X));
+
+    mixer.action("a").unwrap().fade_in(1.0);
+    mixer.update(0.25, &mut scene);
+    assert!(approx(mixer.get_action("a").unwrap().weight, 0.25));
+    // The rest pose (origin) fills the remaining weight.
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X * 0.25, 1e-5));
+}
+
+#[test]
+fn mixer_additive_action_layers_on_normal_result() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("base", Vec3::X));
+    let mut offset = make_hold_action("offset", Vec3::Y * 2.0);
+    offset.blend_mode = AnimationBlendMode::Additive;
+    mixer.add_action(offset);
+
+    mixer.play("base");
+    mixer.action("offset").unwrap().play().set_weight(0.5);
+    mixer.update(0.1, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::new(1.0, 1.0, 0.0), 1e-5));
+
+    // Additive contributions do not accumulate across frames.
+    mixer.update(0.1, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::new(1.0, 1.0, 0.0), 1e-5));
+
+    // Normal actions with equal weights still average.
+    mixer.stop("offset");
+    mixer.add_action(make_hold_action("other", Vec3::Z));
+    mixer.play("other");
+    mixer.update(0.1, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::new(0.5, 0.0, 0.5), 1e-5));
+}
+
+/// Constant morph-weight clip named `name` bound to the mixer's only bone.
+fn make_morph_action(name: &str, weights: &[f32]) -> AnimationAction {
+    let data = MorphWeightData {
+        weights: weights.iter().copied().collect(),
+    };
+    let clip = Arc::new(AnimationClip::new(
+        name.to_string(),
+        vec![Track {
+            meta: TrackMeta {
+                path: vec!["node".to_string()],
+                target: TargetPath::Weights,
+            },
+            data: TrackData::MorphWeights(KeyframeTrack::new(
+                vec![0.0, 1.0],
+                vec![data.clone(), data],
+                InterpolationMode::Linear,
+            )),
+        }],
+    ));
+    let mut action = AnimationAction::new(clip);
+    action.clip_binding = ClipBinding {
+        bindings: vec![TrackBinding {
+            track_index: 0,
+            bone_index: 0,
+            target: TargetPath::Weights,
+        }],
+    };
+    action
+}
+
+#[test]
+fn mixer_morph_weights_sum_across_actions() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    mixer.add_action(make_morph_action("blink", &[1.0, 0.0]));
+    mixer.add_action(make_morph_action("smile", &[0.0, 1.0]));
+
+    mixer.play("blink");
+    mixer.play("smile");
+    mixer.update(0.1, &mut scene);
+    let weights = scene.get_morph_weights(node).unwrap();
+    assert!(approx(weights[0], 1.0) && approx(weights[1], 1.0));
+
+    mixer.action("smile").unwrap().set_weight(0.5);
+    mixer.update(0.1, &mut scene);
+    let weights = scene.get_morph_weights(node).unwrap();
+    assert!(approx(weights[0], 1.0) && approx(weights[1], 0.5));
+
+    mixer.stop("smile");
+    mixer.action("blink").unwrap().set_weight(0.25);
+    mixer.update(0.1, &mut scene);
+    let weights = scene.get_morph_weights(node).unwrap();
+    assert!(approx(weights[0], 0.25));
+}
+
+// ============================================================================
+// Animation Events
+// ============================================================================
+
+fn fired_names(mixer: &mut AnimationMixer) -> Vec<String> {
+    mixer
+        .drain_events()
+        .into_iter()
+        .map(|event| event.name)
+        .collect()
+}
+
+#[test]
+fn events_fire_once_per_crossing() {
+    let (mut scene, _, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("walk", Vec3::X));
+    assert!(mixer.add_event("walk", 0.25, "step_l"));
+    assert!(mixer.add_event("walk", 0.75, "step_r"));
+    assert!(!mixer.add_event("missing", 0.5, "nope"));
+    mixer.play("walk");
+
+    mixer.update(0.2, &mut scene);
+    assert!(fired_names(&mut mixer).is_empty());
+    mixer.update(0.1, &mut scene);
+    assert_eq!(fired_names(&mut mixer), ["step_l"]);
+    mixer.update(0.1, &mut scene);
+    assert!(fired_names(&mut mixer).is_empty());
+
+    // Both events in one frame, across the loop point.
+    mixer.update(0.9, &mut scene);
+    assert_eq!(fired_names(&mut mixer), ["step_r", "step_l"]);
+}
+
+#[test]
+fn events_fire_for_every_loop_at_high_time_scale() {
+    let (mut scene, _, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("walk", Vec3::X));
+    mixer.add_event("walk", 0.5, "step");
+    mixer.action("walk").unwrap().play().set_time_scale(10.0);
+
+    // 0.35s * 10 = 3.5 clip lengths: crossings at 0.5, 1.5, 2.5 and 3.5.
+    mixer.update(0.35, &mut scene);
+    assert_eq!(fired_names(&mut mixer).len(), 4);
+    assert!(approx(mixer.get_action("walk").unwrap().time, 0.5));
+}
+
+#[test]
+fn events_follow_ping_pong_direction() {
+    let (mut scene, _, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("wave", Vec3::X));
+    mixer.add_event("wave", 0.8, "near_end");
+    mixer.add_event("wave", 0.1, "near_start");
+    mixer
+        .action("wave")
+        .unwrap()
+        .play()
+        .set_loop_mode(LoopMode::PingPong);
+
+    // 0 -> 1 -> 0.5: passes "near_end" on the way out and again on the way back.
+    mixer.update(1.5, &mut scene);
+    assert_eq!(
+        fired_names(&mut mixer),
+        ["near_start", "near_end", "near_end"]
+    );
+
+    // 0.5 -> 0 -> 0.2
+    mixer.update(0.7, &mut scene);
+    assert_eq!(fired_names(&mut mixer), ["near_start", "near_start"]);
+}
+
+#[test]
+fn events_stop_at_end_of_once_clip() {
+    let (mut scene, _, mut mixer) = mixer_scene();
+    mixer.add_action(make_hold_action("hit", Vec3::X));
+    mixer.add_event("hit", 0.5, "impact");
+    mixer
+        .action("hit")
+        .unwrap()
+        .play()
+        .set_loop_mode(LoopMode::Once)
+        .set_time_scale(4.0);
+
+    mixer.update(1.0, &mut scene);
+    assert_eq!(fired_names(&mut mixer), ["impact"]);
+    mixer.update(1.0, &mut scene);
+    assert!(fired_names(&mut mixer).is_empty());
+}
+
+// ============================================================================
+// AnimationMixer Seeking
+// ============================================================================
+
+#[test]
+fn mixer_set_time_updates_pose_of_paused_action() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    let mut action = AnimationAction::new(make_simple_clip(2.0));
+    action.clip_binding = ClipBinding {
+        bindings: vec![TrackBinding {
+            track_index: 0,
+            bone_index: 0,
+            target: TargetPath::Translation,
+        }],
+    };
+    mixer.add_action(action);
+
+    mixer.action("test").unwrap().play().pause();
+    mixer.set_time("test", 1.0);
+    mixer.evaluate(&mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X * 0.5, 1e-5));
+    assert!(approx(
+        mixer.get_action("test").unwrap().current_time(),
+        1.0
+    ));
+
+    // Updates keep holding the paused pose.
+    mixer.update(0.5, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X * 0.5, 1e-5));
+
+    mixer.action("test").unwrap().set_time(1.5);
+    mixer.update(0.0, &mut scene);
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X * 0.75, 1e-5));
+}
+
+#[test]
+fn mixer_seek_applies_pose_without_update() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    let mut action = AnimationAction::new(make_simple_clip(2.0));
+    action.clip_binding = ClipBinding {
+        bindings: vec![TrackBinding {
+            track_index: 0,
+            bone_index: 0,
+            target: TargetPath::Translation,
+        }],
+    };
+    mixer.add_action(action);
+    mixer.add_event("test", 0.5, "half");
+
+    mixer.action("test").unwrap().play().pause();
+    assert!(mixer.seek("test", 1.0, &mut scene));
+    // Read straight back: no `update` or `evaluate` in between.
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X * 0.5, 1e-5));
+    assert!(mixer.events().is_empty());
+
+    // Out-of-range times wrap for a looping action.
+    mixer.action("test").unwrap().set_loop_mode(LoopMode::Loop);
+    assert!(mixer.seek("test", 2.5, &mut scene));
+    assert!(node_position(&scene, node).abs_diff_eq(Vec3::X * 0.25, 1e-5));
+    assert!(mixer.get_action("test").unwrap().paused);
+
+    assert!(!mixer.seek("missing", 0.0, &mut scene));
+}
+
+#[test]
+fn mixer_sample_at_reproduces_keyframe_without_firing_events() {
+    let (mut scene, node, mut mixer) = mixer_scene();
+    let key = Vec3::new(0.3, 2.0, -1.0);
+    let clip = Arc::new(AnimationClip::new(
+        "test".to_string(),
+        vec![Track {
+            meta: TrackMeta {
+                path: vec!["node".to_string()],
+                target: TargetPath::Translation,
+            },
+            data: TrackData::Vector3(KeyframeTrack::new(
+                vec![0.0, 0.7, 2.0],
+                vec![Vec3::ZERO, key, Vec3::X],
+                InterpolationMode::Linear,
+            )),
+        }],
+    ));
+    let mut action = AnimationAction::new(clip);
+    action.clip_binding = ClipBinding {
+        bindings: vec![TrackBinding {
+            track_index: 0,
+            bone_index: 0,
+            target: TargetPath::Translation,
+        }],
+    };
+    mixer.add_action(action);
+    mixer.add_event("test", 0.7, "step");
+
+    // The action was never played: sampling activates it paused.
+    assert!(mixer.sample_at("test", 0.7, &mut scene));
+    assert_eq!(node_position(&scene, node), key);
+    assert!(mixer.events().is_empty());
+    assert!(mixer.get_action("test").unwrap().paused);
+
+    // Further updates hold the sampled pose.
+    mixer.update(0.5, &mut scene);
+    assert_eq!(node_position(&scene, node), key);
+
+    // Times past the end clamp to the last keyframe.
+    assert!(mixer.sample_at("test", 5.0, &mut scene));
+    assert_eq!(node_position(&scene, node), Vec3::X);
+    assert!(mixer.events().is_empty());
+
+    assert!(!mixer.sample_at("missing", 0.0, &mut scene));
+}
+
+// ============================================================================
+// Two-Bone IK
+// ============================================================================
+
+/// Root at the origin with two unit bones along +Y.
+fn ik_chain() -> (Scene, NodeHandle, NodeHandle, NodeHandle) {
+    let mut scene = Scene::new();
+    let root = scene.add_node(Node::new());
+    let mid = scene.add_to_parent(Node::new(), root);
+    scene.node(&mid).set_position(0.0, 1.0, 0.0);
+    let tip = scene.add_to_parent(Node::new(), mid);
+    scene.node(&tip).set_position(0.0, 1.0, 0.0);
+    (scene, root, mid, tip)
+}
+
+fn world_position(scene: &Scene, node: NodeHandle) -> Vec3 {
+    scene
+        .get_node(node)
+        .unwrap()
+        .world_matrix()
+        .translation
+        .into()
+}
+
+#[test]
+fn two_bone_ik_places_tip_on_reachable_target() {
+    let (mut scene, root, mid, tip) = ik_chain();
+    let target = Vec3::new(1.0, 1.0, 0.5);
+    scene.set_two_bone_ik(TwoBoneIk::new(root, mid, tip, target).with_pole(Vec3::Z));
+    scene.update(&Input::default(), 0.0);
+
+    assert!(world_position(&scene, tip).abs_diff_eq(target, 1e-4));
+    // Bone lengths are preserved.
+    let mid_pos = world_position(&scene, mid);
+    assert!((mid_pos.length() - 1.0).abs() < 1e-4);
+    assert!((mid_pos.distance(target) - 1.0).abs() < 1e-4);
+    // The knee bends toward the pole.
+    let axis = target.normalize();
+    assert!(mid_pos.reject_from_normalized(axis).dot(Vec3::Z) > 0.0);
+
+    // Local transforms keep the animated pose.
+    let local = scene.get_node(mid).unwrap().transform.rotation;
+    assert!(local.abs_diff_eq(Quat::IDENTITY, EPSILON));
+}
+
+#[test]
+fn two_bone_ik_weight_fades_back_to_animated_pose() {
+    let (mut scene, root, mid, tip) = ik_chain();
+    scene.set_two_bone_ik(TwoBoneIk::new(root, mid, tip, Vec3::new(1.5, 0.5, 0.0)));
+    scene.update(&Input::default(), 0.0);
+    let solved = world_position(&scene, tip);
+
+    scene.two_bone_ik_mut(tip).unwrap().weight = 0.0;
+    scene.update(&Input::default(), 0.0);
+    assert!(world_position(&scene, tip).abs_diff_eq(Vec3::new(0.0, 2.0, 0.0), 1e-4));
+
+    scene.two_bone_ik_mut(tip).unwrap().weight = 1.0;
+    scene.update(&Input::default(), 0.0);
+    assert!(world_position(&scene, tip).abs_diff_eq(solved, 1e-4));
+
+    assert!(scene.clear_two_bone_ik(tip).is_some());
+    scene.update(&Input::default(), 0.0);
+    assert!(world_position(&scene, tip).abs_diff_eq(Vec3::new(0.0, 2.0, 0.0), 1e-4));
+}
+
+#[test]
+fn two_bone_ik_stretches_toward_unreachable_target() {
+    let (mut scene, root, mid, tip) = ik_chain();
+    scene.set_two_bone_ik(TwoBoneIk::new(root, mid, tip, Vec3::new(5.0, 0.0, 0.0)));
+    scene.update(&Input::default(), 0.0);
+
+    let tip_pos = world_position(&scene, tip);
+    assert!(tip_pos.abs_diff_eq(Vec3::new(2.0, 0.0, 0.0), 1e-2));
+}
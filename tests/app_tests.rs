@@ -0,0 +1,194 @@
+//! Application framework tests
+//!
+//! Tests for:
+//! - FixedTimestep accumulation and interpolation alpha
+//! - Catch-up clamping for long frames
+//! - Engine clock time scale and pause
+//! - OrbitControls touch pinch zoom
+//! - TransformGizmo picking, dragging and snapping
+
+use glam::{Quat, Vec3};
+use myth::resources::input::{ButtonState, Input, MouseButton, TouchPhase};
+use myth::{
+    Camera, Engine, FixedTimestep, GizmoAxis, GizmoMode, NodeHandle, OrbitControls, Scene,
+    Transform, TransformGizmo,
+};
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < 1e-4
+}
+
+#[test]
+fn fixed_timestep_runs_whole_steps_and_carries_remainder() {
+    let mut timestep = FixedTimestep::new(0.1);
+
+    assert_eq!(timestep.advance(0.05), 0);
+    assert!(approx(timestep.alpha(), 0.5));
+
+    assert_eq!(timestep.advance(0.08), 1);
+    assert!(approx(timestep.alpha(), 0.3));
+
+    assert_eq!(timestep.advance(0.25), 2);
+    assert!(approx(timestep.alpha(), 0.8));
+}
+
+#[test]
+fn fixed_timestep_clamps_catch_up_after_long_frame() {
+    let mut timestep = FixedTimestep::new(0.01).with_max_steps(4);
+    assert_eq!(timestep.max_steps(), 4);
+
+    // 1s would be 100 steps; only 4 run and the backlog is dropped.
+    assert_eq!(timestep.advance(1.0), 4);
+    assert!(timestep.alpha() < 1.0);
+    assert_eq!(timestep.advance(0.0), 0);
+}
+
+#[test]
+fn fixed_timestep_ignores_negative_dt() {
+    let mut timestep = FixedTimestep::new(0.1);
+    assert_eq!(timestep.advance(-1.0), 0);
+    assert!(approx(timestep.alpha(), 0.0));
+}
+
+#[test]
+fn engine_time_scale_halves_frame_dt() {
+    let mut engine = Engine::default();
+    engine.set_time_scale(0.5);
+
+    // One second of wall time in 10 frames.
+    let mut scaled_total = 0.0;
+    let mut real_total = 0.0;
+    for _ in 0..10 {
+        let frame = engine.frame_state(0.1);
+        assert!(approx(frame.dt, 0.05));
+        assert!(approx(frame.real_dt, 0.1));
+        scaled_total += frame.dt;
+        real_total += frame.real_dt;
+        engine.update(frame.real_dt);
+        assert!(approx(engine.time(), frame.time));
+    }
+
+    assert!(approx(scaled_total, 0.5));
+    assert!(approx(real_total, 1.0));
+    assert!(approx(engine.time(), 0.5));
+    assert_eq!(engine.frame_count(), 10);
+}
+
+#[test]
+fn engine_pause_freezes_time_and_resume_restores_scale() {
+    let mut engine = Engine::default();
+    engine.set_time_scale(2.0);
+    engine.update(0.1);
+    assert!(approx(engine.time(), 0.2));
+
+    engine.pause();
+    assert!(engine.is_paused());
+    let frame = engine.frame_state(0.1);
+    assert!(approx(frame.dt, 0.0));
+    assert!(approx(frame.real_dt, 0.1));
+    engine.update(0.1);
+    assert!(approx(engine.time(), 0.2));
+    assert_eq!(engine.frame_count(), 2, "frames keep counting while paused");
+
+    engine.resume();
+    assert!(approx(engine.time_scale(), 2.0));
+    engine.update(0.1);
+    assert!(approx(engine.time(), 0.4));
+
+    engine.set_time_scale(-1.0);
+    assert!(approx(engine.time_scale(), 0.0));
+}
+
+#[test]
+fn orbit_controls_pinch_out_reduces_radius() {
+    let mut input = Input::new();
+    input.inject_resize(800, 600);
+    input.inject_touch(0, TouchPhase::Started, 100.0, 100.0);
+    input.inject_touch(1, TouchPhase::Started, 200.0, 100.0);
+
+    // Spread the fingers symmetrically so the two-finger pan stays at zero.
+    input.start_frame();
+    input.inject_touch(0, TouchPhase::Moved, 50.0, 100.0);
+    input.inject_touch(1, TouchPhase::Moved, 250.0, 100.0);
+    assert_eq!(input.touch_count(), 2);
+    assert!(approx(input.pinch_scale(), 2.0));
+
+    let mut controls = OrbitControls::new(Vec3::new(0.0, 0.0, 10.0), Vec3::ZERO);
+    controls.enable_damping = false;
+    let mut transform = Transform::new();
+    controls.update(&mut transform, &input, 45f32.to_radians(), 1.0 / 60.0);
+    assert!(approx(transform.position.length(), 5.0));
+
+    // Lifting a finger ends the pinch; the next frame holds the radius.
+    input.start_frame();
+    input.inject_touch(1, TouchPhase::Ended, 250.0, 100.0);
+    assert_eq!(input.touch_count(), 1);
+    assert!(approx(input.pinch_scale(), 1.0));
+    controls.update(&mut transform, &input, 45f32.to_radians(), 1.0 / 60.0);
+    assert!(approx(transform.position.length(), 5.0));
+}
+
+/// 800x600 viewport looking down -Z at a node in the origin from 10 units.
+///
+/// With a 45° field of view one world unit spans ~72.4 px at the node, and
+/// the default gizmo handles are ~90 px long.
+fn gizmo_scene() -> (Scene, NodeHandle, Input) {
+    let mut scene = Scene::new();
+    let camera = scene.add_camera(Camera::new_perspective(45.0, 800.0 / 600.0, 0.1));
+    scene.node(&camera).set_position(0.0, 0.0, 10.0);
+    scene.active_camera = Some(camera);
+    let node = scene.create_node();
+
+    let mut input = Input::new();
+    input.inject_resize(800, 600);
+    (scene, node, input)
+}
+
+const PIXELS_PER_UNIT: f32 = 300.0 / (10.0 * 0.414_213_57);
+
+#[test]
+fn transform_gizmo_drags_node_along_picked_axis() {
+    let (mut scene, node, mut input) = gizmo_scene();
+    let mut gizmo = TransformGizmo::new();
+    assert!(!gizmo.update(&mut scene, &input), "no target, no capture");
+
+    gizmo.set_target(Some(node));
+    input.inject_mouse_position(445.0, 300.0);
+    input.inject_mouse_button(MouseButton::Left, ButtonState::Pressed);
+    assert!(gizmo.update(&mut scene, &input));
+    assert_eq!(gizmo.active_axis(), Some(GizmoAxis::X));
+
+    input.start_frame();
+    input.inject_mouse_position(445.0 + PIXELS_PER_UNIT, 300.0);
+    assert!(gizmo.update(&mut scene, &input));
+    let position = scene.get_node(node).unwrap().transform.position;
+    assert!((position - Vec3::X).length() < 1e-2, "{position}");
+
+    input.start_frame();
+    input.inject_mouse_button(MouseButton::Left, ButtonState::Released);
+    gizmo.update(&mut scene, &input);
+    assert!(!gizmo.is_dragging());
+}
+
+#[test]
+fn transform_gizmo_snaps_rotation() {
+    let (mut scene, node, mut input) = gizmo_scene();
+    let mut gizmo = TransformGizmo::new();
+    gizmo.mode = GizmoMode::Rotate;
+    gizmo.snap.rotate = Some(30f32.to_radians());
+    gizmo.set_target(Some(node));
+
+    // Grab the Z ring (facing the camera) on its +X side.
+    input.inject_mouse_position(490.0, 300.0);
+    input.inject_mouse_button(MouseButton::Left, ButtonState::Pressed);
+    gizmo.update(&mut scene, &input);
+    assert_eq!(gizmo.active_axis(), Some(GizmoAxis::Z));
+
+    // Drag 40° counter-clockwise on screen; the rotation snaps to 30°.
+    let (sin, cos) = 40f32.to_radians().sin_cos();
+    input.start_frame();
+    input.inject_mouse_position(400.0 + 90.0 * cos, 300.0 - 90.0 * sin);
+    gizmo.update(&mut scene, &input);
+    let rotation = scene.get_node(node).unwrap().transform.rotation;
+    assert!(rotation.angle_between(Quat::from_rotation_z(30f32.to_radians())) < 1e-3);
+}
@@ -5,10 +5,19 @@
 //! - UUID lookup: get_by_uuid, get_handle_by_uuid
 //! - Thread safety: concurrent reads via RwLock
 //! - AssetServer construction and storage access
+//! - Solid-colour and gradient texture factories
+//! - Equirectangular to cubemap conversion
+//! - glTF multi-primitive meshes loaded as submeshes
+//! - glTF `KHR_lights_punctual` lights imported in physical units
 
+use glam::Vec4;
 use myth::assets::AssetServer;
+use myth::assets::GltfLoader;
 use myth::assets::storage::AssetStorage;
 use myth::resources::Geometry;
+use myth::resources::image::{Image, ImageDimension, PixelFormat};
+use myth::resources::texture::Texture;
+use myth::scene::{LightKind, LightUnits};
 use slotmap::new_key_type;
 use uuid::Uuid;
 
@@ -158,3 +167,284 @@ fn asset_server_clone_shares_storage() {
         "Cloned server should share the same storage"
     );
 }
+
+// ============================================================================
+// Color Grading LUTs
+// ============================================================================
+
+fn lut_temp_path(name: &str) -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("myth_lut_{}_{name}", std::process::id()))
+}
+
+#[test]
+fn strip_lut_rejects_wrong_dimensions() {
+    let path = lut_temp_path("bad.png");
+    image::RgbaImage::new(10, 4).save(&path).unwrap();
+
+    let result = myth::assets::load_lut_texture_from_file(&path);
+    std::fs::remove_file(&path).ok();
+
+    assert!(result.is_err());
+}
+
+// ============================================================================
+// Texture Factories
+// ============================================================================
+
+fn texture_bytes(server: &AssetServer, handle: myth::TextureHandle) -> Vec<u8> {
+    let texture = server.textures.get(handle).unwrap();
+    let image = server.images.get(texture.image).unwrap();
+    image.data.clone().unwrap()
+}
+
+#[test]
+fn color_texture_is_deduplicated_by_color() {
+    let server = AssetServer::new();
+    let textures_before = server.textures.read_lock().lookup.len();
+
+    let white: Vec<_> = (0..1000)
+        .map(|_| server.create_color_texture(Vec4::ONE))
+        .collect();
+    assert!(white.iter().all(|&h| h == white[0]));
+    assert_eq!(texture_bytes(&server, white[0]), vec![255, 255, 255, 255]);
+
+    let red = server.create_color_texture(Vec4::new(1.0, 0.0, 0.0, 1.0));
+    assert_ne!(red, white[0]);
+    assert_eq!(
+        server.textures.read_lock().lookup.len(),
+        textures_before + 2
+    );
+
+    // Linear mid-grey is stored sRGB-encoded.
+    let grey = server.create_color_texture(Vec4::new(0.5, 0.5, 0.5, 0.5));
+    assert_eq!(texture_bytes(&server, grey), vec![188, 188, 188, 128]);
+}
+
+#[test]
+fn gradient_texture_interpolates_stops() {
+    let server = AssetServer::new();
+    let black = Vec4::new(0.0, 0.0, 0.0, 1.0);
+    // Unsorted stops, with the ends held past the first/last stop.
+    let handle = server.create_gradient_texture(&[(0.75, Vec4::ONE), (0.25, black)], 5);
+
+    let texture = server.textures.get(handle).unwrap();
+    let image = server.images.get(texture.image).unwrap();
+    assert_eq!((image.width, image.height), (1, 5));
+
+    let bytes = texture_bytes(&server, handle);
+    let reds: Vec<u8> = bytes.chunks_exact(4).map(|texel| texel[0]).collect();
+    // Rows sit at t = 0, 0.25, 0.5, 0.75, 1; t = 0.5 is linear 0.5 → sRGB 188.
+    assert_eq!(reds, vec![0, 0, 188, 255, 255]);
+    assert!(bytes.chunks_exact(4).all(|texel| texel[3] == 255));
+
+    // No stops gives white.
+    let empty = server.create_gradient_texture(&[], 2);
+    assert_eq!(texture_bytes(&server, empty), vec![255; 8]);
+}
+
+/// 8×4 RGBA8 panorama: red sky, blue ground, green columns around +X.
+fn test_panorama() -> Image {
+    let mut data = Vec::new();
+    for row in 0..4 {
+        for col in 0..8 {
+            let sky = if row < 2 { 255 } else { 0 };
+            let marker = if (3..=4).contains(&col) { 255 } else { 0 };
+            data.extend_from_slice(&[sky, marker, 255 - sky, 255]);
+        }
+    }
+    Image::new(
+        8,
+        4,
+        1,
+        ImageDimension::D2,
+        PixelFormat::Rgba8Unorm,
+        Some(data),
+    )
+}
+
+#[test]
+fn equirect_to_cubemap_maps_faces_like_the_gpu_conversion() {
+    let server = AssetServer::new();
+    let image = server.images.add(test_panorama());
+    let source = server
+        .textures
+        .add(Texture::new_2d(Some("Panorama"), image));
+
+    let cube = server.equirect_to_cubemap(source, 1);
+    let texture = server.textures.get(cube).unwrap();
+    assert_eq!(texture.view_dimension, wgpu::TextureViewDimension::Cube);
+    assert!(texture.generate_mipmaps);
+    let cube_image = server.images.get(texture.image).unwrap();
+    assert_eq!(
+        (cube_image.width, cube_image.height, cube_image.depth),
+        (1, 1, 6)
+    );
+
+    // One texel per face, ordered +X, -X, +Y, -Y, +Z, -Z.
+    let faces: Vec<[u8; 4]> = texture_bytes(&server, cube)
+        .chunks_exact(4)
+        .map(|texel| texel.try_into().unwrap())
+        .collect();
+    assert_eq!(faces[0][1], 255, "+X looks at the center column");
+    assert_eq!(faces[1][1], 0, "-X looks at the seam");
+    assert_eq!((faces[2][0], faces[2][2]), (255, 0), "+Y is sky");
+    assert_eq!((faces[3][0], faces[3][2]), (0, 255), "-Y is ground");
+
+    // Deduplicated by source and face size.
+    assert_eq!(server.equirect_to_cubemap(source, 1), cube);
+    assert_ne!(server.equirect_to_cubemap(source, 2), cube);
+}
+
+#[test]
+fn equirect_to_cubemap_waits_for_loading_source() {
+    let server = AssetServer::new();
+    let image = server.images.reserve();
+    let source = server.textures.add(Texture::new_2d(Some("Pending"), image));
+
+    let cube = server.equirect_to_cubemap(source, 4);
+    let cube_image = server.textures.get(cube).unwrap().image;
+    assert!(server.images.is_loading(cube_image));
+
+    server.images.insert_ready(image, test_panorama());
+    server.process_loading_events();
+    let converted = server.images.get(cube_image).unwrap();
+    assert_eq!((converted.width, converted.depth), (4, 6));
+
+    // A failed source fails the cube too.
+    let failed = server.images.reserve();
+    server.images.mark_failed(failed, "missing".to_string());
+    let source = server.textures.add(Texture::new_2d(Some("Failed"), failed));
+    let cube = server.equirect_to_cubemap(source, 4);
+    assert!(
+        server
+            .images
+            .is_failed(server.textures.get(cube).unwrap().image)
+    );
+}
+
+// ============================================================================
+// glTF Submeshes
+// ============================================================================
+
+/// Builds a GLB with one mesh made of two triangles, each its own primitive
+/// with its own material.
+fn two_primitive_glb() -> Vec<u8> {
+    let positions: [[f32; 3]; 6] = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [3.0, 0.0, 0.0],
+        [2.0, 1.0, 0.0],
+    ];
+    let bin: Vec<u8> = bytemuck::cast_slice(&positions).to_vec();
+
+    let accessor = |offset: usize, min_x: f32, max_x: f32| {
+        serde_json::json!({
+            "bufferView": 0, "byteOffset": offset, "componentType": 5126,
+            "count": 3, "type": "VEC3",
+            "min": [min_x, 0.0, 0.0], "max": [max_x, 1.0, 0.0]
+        })
+    };
+    let json = serde_json::json!({
+        "asset": { "version": "2.0" },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": [{ "name": "pair", "mesh": 0 }],
+        "meshes": [{ "primitives": [
+            { "attributes": { "POSITION": 0 }, "material": 0 },
+            { "attributes": { "POSITION": 1 }, "material": 1 }
+        ] }],
+        "materials": [
+            { "pbrMetallicRoughness": { "baseColorFactor": [1.0, 0.0, 0.0, 1.0] } },
+            { "pbrMetallicRoughness": { "baseColorFactor": [0.0, 0.0, 1.0, 1.0] } }
+        ],
+        "accessors": [accessor(0, 0.0, 1.0), accessor(36, 2.0, 3.0)],
+        "bufferViews": [{ "buffer": 0, "byteLength": bin.len() }],
+        "buffers": [{ "byteLength": bin.len() }]
+    });
+
+    let mut json_chunk = serde_json::to_vec(&json).unwrap();
+    json_chunk.resize(json_chunk.len().next_multiple_of(4), b' ');
+    let total = 12 + 8 + json_chunk.len() + 8 + bin.len();
+
+    let mut glb = Vec::with_capacity(total);
+    glb.extend_from_slice(b"glTF");
+    glb.extend_from_slice(&2u32.to_le_bytes());
+    glb.extend_from_slice(&(total as u32).to_le_bytes());
+    glb.extend_from_slice(&(json_chunk.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"JSON");
+    glb.extend_from_slice(&json_chunk);
+    glb.extend_from_slice(&(bin.len() as u32).to_le_bytes());
+    glb.extend_from_slice(b"BIN\0");
+    glb.extend_from_slice(&bin);
+    glb
+}
+
+#[test]
+fn gltf_multi_primitive_mesh_loads_as_submeshes() {
+    let server = AssetServer::new();
+    let prefab = pollster::block_on(GltfLoader::load_from_bytes(
+        two_primitive_glb(),
+        server.clone(),
+    ))
+    .unwrap();
+
+    assert_eq!(prefab.nodes.len(), 1, "primitives should not be split");
+    let mesh = prefab.nodes[0].mesh.as_ref().unwrap();
+    assert_eq!(mesh.submeshes.len(), 2);
+    assert_eq!(mesh.submeshes[0].range, 0..3);
+    assert_eq!(mesh.submeshes[1].range, 3..6);
+    assert_ne!(mesh.submeshes[0].material, mesh.submeshes[1].material);
+    assert_eq!(mesh.material, mesh.submeshes[0].material);
+
+    let geometry = server.geometries.get(mesh.geometry).unwrap();
+    assert_eq!(geometry.get_attribute("position").unwrap().count, 6);
+    let bbox = geometry.bounding_box;
+    assert_eq!(bbox.min, glam::Vec3::ZERO);
+    assert_eq!(bbox.max, glam::Vec3::new(3.0, 1.0, 0.0));
+}
+
+#[test]
+fn gltf_punctual_lights_import_in_physical_units() {
+    let json = serde_json::json!({
+        "asset": { "version": "2.0" },
+        "extensionsUsed": ["KHR_lights_punctual"],
+        "extensions": { "KHR_lights_punctual": { "lights": [
+            { "type": "point", "color": [1.0, 0.5, 0.25], "intensity": 800.0, "range": 12.0 },
+            { "type": "spot", "intensity": 50.0,
+              "spot": { "innerConeAngle": 0.2, "outerConeAngle": 0.6 } }
+        ] } },
+        "scene": 0,
+        "scenes": [{ "nodes": [0, 1] }],
+        "nodes": [
+            { "name": "lamp", "extensions": { "KHR_lights_punctual": { "light": 0 } } },
+            { "name": "spot", "extensions": { "KHR_lights_punctual": { "light": 1 } } }
+        ]
+    });
+
+    let server = AssetServer::new();
+    let prefab = pollster::block_on(GltfLoader::load_from_bytes(
+        serde_json::to_vec(&json).unwrap(),
+        server,
+    ))
+    .unwrap();
+
+    let lamp = prefab.nodes[0].light.as_ref().unwrap();
+    assert_eq!(lamp.units, LightUnits::PhysicalUnits);
+    assert!((lamp.intensity - 800.0).abs() < 1e-6);
+    assert_eq!(lamp.color, glam::Vec3::new(1.0, 0.5, 0.25));
+    let LightKind::Point(ref point) = lamp.kind else {
+        panic!("expected a point light");
+    };
+    assert!((point.range - 12.0).abs() < 1e-6);
+
+    let spot = prefab.nodes[1].light.as_ref().unwrap();
+    assert_eq!(spot.units, LightUnits::PhysicalUnits);
+    let LightKind::Spot(ref cone) = spot.kind else {
+        panic!("expected a spot light");
+    };
+    assert!(cone.range.abs() < 1e-6);
+    assert!((cone.inner_cone - 0.2).abs() < 1e-6);
+    assert!((cone.outer_cone - 0.6).abs() < 1e-6);
+}
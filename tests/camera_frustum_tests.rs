@@ -8,10 +8,12 @@
 //! - Frustum-sphere intersection
 //! - Frustum-AABB intersection
 //! - RenderCamera extraction
+//! - World-space frustum corners
 
 use glam::{Affine3A, Mat4, Vec3};
 
 use myth::resources::geometry::BoundingBox;
+use myth::resources::{AntiAliasingMode, TaaSettings};
 use myth::scene::camera::{Camera, Frustum};
 
 const EPSILON: f32 = 1e-4;
@@ -248,3 +250,170 @@ fn render_camera_has_correct_near() {
     let render_cam = cam.extract_render_camera();
     assert!(approx(render_cam.near, 0.5));
 }
+
+// ============================================================================
+// Auto Clip Planes
+// ============================================================================
+
+fn camera_at(position: Vec3) -> Camera {
+    let mut cam = Camera::new_perspective(60.0, 1.0, 0.1);
+    cam.update_view_projection(&Affine3A::from_translation(position));
+    cam
+}
+
+#[test]
+fn auto_clip_fits_bounds_in_front() {
+    // Camera at z = 10 looking down -Z at a box spanning z ∈ [-1, 1]
+    let mut cam = camera_at(Vec3::new(0.0, 0.0, 10.0));
+    let bbox = BoundingBox {
+        min: Vec3::splat(-1.0),
+        max: Vec3::splat(1.0),
+    };
+
+    assert!(cam.auto_clip(&bbox));
+    assert!(
+        cam.near() > 8.0 && cam.near() <= 9.0,
+        "near = {}",
+        cam.near()
+    );
+    assert!(cam.far() >= 11.0 && cam.far() < 12.0, "far = {}", cam.far());
+
+    // The projection was rebuilt: the new near plane maps to NDC z = 1.0
+    let rc = cam.extract_render_camera();
+    let p = rc.projection_matrix * glam::Vec4::new(0.0, 0.0, -cam.near(), 1.0);
+    assert!(approx(p.z / p.w, 1.0));
+}
+
+#[test]
+fn auto_clip_clamps_near_when_inside_bounds() {
+    let mut cam = camera_at(Vec3::ZERO);
+    let bbox = BoundingBox {
+        min: Vec3::splat(-5.0),
+        max: Vec3::splat(5.0),
+    };
+
+    assert!(cam.auto_clip(&bbox));
+    assert!(cam.near() >= myth::scene::camera::AUTO_CLIP_MIN_NEAR);
+    assert!(cam.far() >= 5.0);
+}
+
+#[test]
+fn auto_clip_limits_depth_ratio_on_large_scenes() {
+    let mut cam = camera_at(Vec3::ZERO);
+    let bbox = BoundingBox {
+        min: Vec3::new(-1.0, -1.0, -100_000.0),
+        max: Vec3::new(1.0, 1.0, 0.0),
+    };
+
+    assert!(cam.auto_clip(&bbox));
+    assert!(cam.near() >= cam.far() * 1e-4 - EPSILON);
+}
+
+#[test]
+fn auto_clip_ignores_bounds_behind_camera() {
+    let mut cam = camera_at(Vec3::new(0.0, 0.0, -10.0));
+    let bbox = BoundingBox {
+        min: Vec3::splat(-1.0),
+        max: Vec3::splat(1.0),
+    };
+
+    assert!(!cam.auto_clip(&bbox));
+    assert!(approx(cam.near(), 0.1));
+    assert!(cam.far().is_infinite());
+}
+
+// ============================================================================
+// TAA Jitter
+// ============================================================================
+
+fn taa_camera(jitter_scale: f32) -> Camera {
+    let mut cam = Camera::new_perspective(60.0, 1.0, 0.1);
+    cam.set_viewport_size(100.0, 100.0);
+    cam.set_aa_mode(AntiAliasingMode::TAA(TaaSettings {
+        jitter_scale,
+        ..TaaSettings::default()
+    }));
+    cam.step_frame();
+    cam
+}
+
+#[test]
+fn taa_jitter_scales_with_settings() {
+    let full = taa_camera(1.0).extract_render_camera().jitter;
+    let half = taa_camera(0.5).extract_render_camera().jitter;
+
+    assert!(full.length() > 0.0);
+    assert!(approx(half.x, full.x * 0.5));
+    assert!(approx(half.y, full.y * 0.5));
+}
+
+#[test]
+fn taa_zero_jitter_scale_keeps_clean_projection() {
+    let rc = taa_camera(0.0).extract_render_camera();
+    assert!(approx(rc.jitter.length(), 0.0));
+    assert!(
+        rc.projection_matrix
+            .abs_diff_eq(rc.unjittered_projection, EPSILON)
+    );
+}
+
+// ============================================================================
+// Frustum Corners
+// ============================================================================
+
+#[test]
+fn frustum_corners_unproject_near_and_far_planes() {
+    // 90° vertical FOV: the half-height of a plane equals its distance.
+    let mut cam = Camera::new_perspective(90.0, 2.0, 1.0);
+    cam.set_far(5.0);
+    cam.update_view_projection(&Affine3A::from_translation(Vec3::new(0.0, 0.0, 10.0)));
+
+    let corners = cam.frustum_corners();
+    let expected = [
+        Vec3::new(-2.0, -1.0, 9.0),
+        Vec3::new(2.0, -1.0, 9.0),
+        Vec3::new(2.0, 1.0, 9.0),
+        Vec3::new(-2.0, 1.0, 9.0),
+        Vec3::new(-10.0, -5.0, 5.0),
+        Vec3::new(10.0, -5.0, 5.0),
+        Vec3::new(10.0, 5.0, 5.0),
+        Vec3::new(-10.0, 5.0, 5.0),
+    ];
+    for (corner, expected) in corners.iter().zip(expected) {
+        assert!(
+            corner.abs_diff_eq(expected, 1e-3),
+            "expected {expected}, got {corner}"
+        );
+    }
+}
+
+#[test]
+fn frustum_corners_in_range_slices_the_frustum() {
+    let cam = camera_at(Vec3::ZERO);
+    let corners = cam.frustum_corners_in_range(2.0, 8.0);
+
+    for corner in &corners[..4] {
+        assert!(
+            (corner.z + 2.0).abs() < 1e-3,
+            "near slice at z = -2, got {corner}"
+        );
+    }
+    for corner in &corners[4..] {
+        assert!(
+            (corner.z + 8.0).abs() < 1e-3,
+            "far slice at z = -8, got {corner}"
+        );
+    }
+    // Same NDC corner scales linearly with distance.
+    assert!(corners[6].abs_diff_eq(corners[2] * 4.0, 1e-3));
+}
+
+#[test]
+fn frustum_corners_orthographic_box() {
+    let mut cam = Camera::new_orthographic(2.0, 1.5, 0.5, 20.0);
+    cam.update_view_projection(&Affine3A::IDENTITY);
+
+    let corners = cam.frustum_corners();
+    assert!(corners[0].abs_diff_eq(Vec3::new(-3.0, -2.0, -0.5), 1e-3));
+    assert!(corners[6].abs_diff_eq(Vec3::new(3.0, 2.0, -20.0), 1e-3));
+}
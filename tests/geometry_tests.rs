@@ -4,11 +4,13 @@
 //! - BoundingBox center, size, union, transform, inflate
 //! - Geometry bounding volume computation
 //! - Vertex normal computation (area-weighted)
-//! - Primitive geometry creation (box, sphere, plane)
+//! - Tangent generation (orthonormal, handedness, seam welding)
+//! - Merging geometries into submesh ranges
+//! - Primitive geometry creation (box, sphere, plane, quad, grid, axes, points)
 //! - Geometry attribute management and versioning
 //! - ShaderDefines auto-generation
 
-use glam::{Affine3A, Vec3};
+use glam::{Affine3A, Vec2, Vec3, Vec4};
 use wgpu::VertexFormat;
 
 use myth::resources::geometry::{Attribute, BoundingBox, Geometry};
@@ -176,6 +178,88 @@ fn geometry_plane_has_attributes() {
     assert!(geom.get_attribute("uv").is_some());
 }
 
+#[test]
+fn geometry_grid_is_line_list_on_xz_plane() {
+    let geom = Geometry::new_grid(10.0, 4);
+    assert_eq!(geom.topology, wgpu::PrimitiveTopology::LineList);
+    assert!(geom.index_attribute().is_none());
+
+    let positions = geom.get_attribute("position").unwrap();
+    assert_eq!(positions.count, (4 + 1) * 4);
+
+    assert!(vec3_approx(
+        geom.bounding_box.min,
+        Vec3::new(-5.0, 0.0, -5.0)
+    ));
+    assert!(vec3_approx(geom.bounding_box.max, Vec3::new(5.0, 0.0, 5.0)));
+}
+
+#[test]
+fn geometry_quad_is_unit_square_facing_z() {
+    let geom = Geometry::new_quad();
+    assert!(vec3_approx(
+        geom.bounding_box.min,
+        Vec3::new(-0.5, -0.5, 0.0)
+    ));
+    assert!(vec3_approx(geom.bounding_box.max, Vec3::new(0.5, 0.5, 0.0)));
+    assert!(geom.get_attribute("uv").is_some());
+}
+
+#[test]
+fn geometry_points_is_point_list() {
+    let geom = Geometry::new_points(&[Vec3::ZERO, Vec3::new(1.0, 2.0, 3.0)]);
+    assert_eq!(geom.topology, wgpu::PrimitiveTopology::PointList);
+    assert_eq!(geom.get_attribute("position").unwrap().count, 2);
+    assert!(vec3_approx(geom.bounding_box.max, Vec3::new(1.0, 2.0, 3.0)));
+}
+
+#[test]
+fn geometry_axes_have_per_vertex_colors() {
+    let geom = Geometry::new_axes(2.0);
+    assert_eq!(geom.topology, wgpu::PrimitiveTopology::LineList);
+    assert_eq!(geom.get_attribute("position").unwrap().count, 6);
+
+    let colors = geom
+        .get_attribute("color")
+        .expect("axes need a color attribute");
+    assert_eq!(colors.format, VertexFormat::Float32x4);
+    assert_eq!(colors.count, 6);
+    assert!(geom.shader_defines().contains("HAS_COLOR"));
+}
+
+#[test]
+fn barycentric_wireframe_de_indexes_triangles() {
+    let geom = Geometry::new_box(1.0, 1.0, 1.0);
+    let index_count = geom.index_attribute().unwrap().count;
+
+    let wire = geom
+        .to_barycentric_wireframe()
+        .expect("triangle lists support the barycentric fallback");
+    assert!(wire.index_attribute().is_none());
+    assert_eq!(wire.get_attribute("position").unwrap().count, index_count);
+    assert!(wire.get_attribute("normal").is_some());
+
+    let barycentric = wire.get_attribute("barycentric").unwrap();
+    assert_eq!(barycentric.format, VertexFormat::Float32x3);
+    assert_eq!(barycentric.count, index_count);
+    assert!(wire.shader_defines().contains("HAS_BARYCENTRIC"));
+    assert!(vec3_approx(wire.bounding_box.min, geom.bounding_box.min));
+}
+
+#[test]
+fn barycentric_wireframe_requires_triangle_list() {
+    assert!(
+        Geometry::new_grid(10.0, 4)
+            .to_barycentric_wireframe()
+            .is_none()
+    );
+    assert!(
+        Geometry::new_points(&[Vec3::ZERO])
+            .to_barycentric_wireframe()
+            .is_none()
+    );
+}
+
 // ============================================================================
 // Geometry Attribute Management Tests
 // ============================================================================
@@ -298,6 +382,151 @@ fn compute_normals_indexed_geometry() {
     }
 }
 
+// ============================================================================
+// Tangent Generation Tests
+// ============================================================================
+
+#[test]
+fn compute_tangents_on_plane_follow_u_with_gltf_handedness() {
+    let mut geom = Geometry::new_plane(2.0, 2.0);
+    assert!(geom.get_attribute("tangent").is_none());
+
+    assert!(geom.compute_tangents());
+    assert!(geom.shader_defines().contains("HAS_TANGENT"));
+
+    // The plane lies in XY facing +Z with U along +X and V along +Y, so
+    // cross(N, T) * w must point along -V (glTF convention).
+    let tangents = geom.get_attribute("tangent").unwrap();
+    for i in 0..tangents.count {
+        let t = tangents.read_vec4(i).unwrap();
+        assert!(vec3_approx(t.truncate(), Vec3::X), "tangent {i}: {t:?}");
+        assert!(approx(t.w, -1.0), "handedness {i}: {t:?}");
+    }
+}
+
+#[test]
+fn compute_tangents_on_sphere_are_unit_and_orthogonal() {
+    let mut geom = Geometry::new_sphere(1.0);
+    assert!(geom.compute_tangents());
+
+    let normals = geom.get_attribute("normal").unwrap();
+    let tangents = geom.get_attribute("tangent").unwrap();
+    for i in 0..tangents.count {
+        let t: Vec4 = tangents.read_vec4(i).unwrap();
+        let n = normals.read_vec3(i).unwrap().normalize();
+        assert!(approx(t.truncate().length(), 1.0), "tangent {i}: {t:?}");
+        assert!(
+            t.truncate().dot(n).abs() < 1e-3,
+            "tangent {i} not orthogonal"
+        );
+        assert!(approx(t.w.abs(), 1.0));
+    }
+}
+
+#[test]
+fn compute_tangents_averages_split_vertices() {
+    let mut geom = Geometry::new();
+
+    // Non-indexed quad: corners 0 and 2 are duplicated (as 3 and 4), and
+    // the second triangle has a skewed UV, so its gradient differs.
+    let p = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(1.0, 1.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+    ];
+    let uv = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 0.5),
+    ];
+    let order = [0, 1, 2, 0, 2, 3];
+    let positions: Vec<Vec3> = order.iter().map(|&i| p[i]).collect();
+    let uvs: Vec<Vec2> = order.iter().map(|&i| uv[i]).collect();
+    geom.set_attribute(
+        "position",
+        Attribute::new_planar(&positions, VertexFormat::Float32x3),
+    );
+    geom.set_attribute(
+        "normal",
+        Attribute::new_planar(&[Vec3::Z; 6], VertexFormat::Float32x3),
+    );
+    geom.set_attribute("uv", Attribute::new_planar(&uvs, VertexFormat::Float32x2));
+
+    assert!(geom.compute_tangents());
+
+    let tangents = geom.get_attribute("tangent").unwrap();
+    let t = |i| tangents.read_vec4(i).unwrap();
+    assert!(vec3_approx(t(0).truncate(), t(3).truncate()));
+    assert!(vec3_approx(t(2).truncate(), t(4).truncate()));
+    assert!(vec3_approx(t(1).truncate(), Vec3::X));
+    // The shared corners blend both triangles' U directions.
+    assert!(vec3_approx(
+        t(0).truncate(),
+        Vec3::new(2.0, -1.0, 0.0).normalize()
+    ));
+}
+
+#[test]
+fn compute_tangents_requires_uvs_and_normals() {
+    let mut geom = Geometry::new();
+    let positions = vec![Vec3::ZERO, Vec3::X, Vec3::Y];
+    geom.set_attribute(
+        "position",
+        Attribute::new_planar(&positions, VertexFormat::Float32x3),
+    );
+    geom.compute_vertex_normals();
+
+    assert!(!geom.compute_tangents());
+    assert!(geom.get_attribute("tangent").is_none());
+}
+
+// ============================================================================
+// Geometry Merge Tests
+// ============================================================================
+
+#[test]
+fn merge_concatenates_vertices_and_offsets_indices() {
+    let parts = [
+        Geometry::new_plane(1.0, 1.0),
+        Geometry::new_box(2.0, 2.0, 2.0),
+    ];
+    let [a, b] = &parts;
+    let a_vertices = a.get_attribute("position").unwrap().count;
+    let a_indices = a.index_attribute().unwrap().count;
+    let b_indices = b.index_attribute().unwrap().count;
+
+    let (merged, ranges) = Geometry::merge(&parts).unwrap();
+
+    assert_eq!(ranges, vec![0..a_indices, a_indices..a_indices + b_indices]);
+    assert_eq!(
+        merged.index_attribute().unwrap().count,
+        a_indices + b_indices
+    );
+    let positions = merged.get_attribute("position").unwrap();
+    assert_eq!(
+        positions.count,
+        a_vertices + b.get_attribute("position").unwrap().count
+    );
+    // The second part's vertices follow the first's.
+    assert!(vec3_approx(
+        positions.read_vec3(a_vertices).unwrap(),
+        b.get_attribute("position").unwrap().read_vec3(0).unwrap()
+    ));
+    assert!(vec3_approx(merged.bounding_box.min, Vec3::splat(-1.0)));
+    assert!(vec3_approx(merged.bounding_box.max, Vec3::splat(1.0)));
+}
+
+#[test]
+fn merge_rejects_mismatched_layouts() {
+    let with_uv = Geometry::new_plane(1.0, 1.0);
+    let positions_only = Geometry::new_points(&[Vec3::ZERO, Vec3::X]);
+
+    assert!(Geometry::merge(&[with_uv, positions_only]).is_none());
+    assert!(Geometry::merge(&[]).is_none());
+}
+
 // ============================================================================
 // Attribute Data Read/Write Tests
 // ============================================================================
@@ -2,12 +2,35 @@
 //!
 //! - Synchronous readback via `Renderer.readback_pixels()`
 //! - Asynchronous readback via `ReadbackStream`
-//! - Material rendering: Physical (PBR), Phong, Unlit
+//! - Material rendering: Physical (PBR), Phong, Unlit, Points, Sprite
 //! - Multi-light scenes (directional + point)
+//! - Clustered lighting matching the all-lights loop with 256 point lights
 //! - Alpha blending and alpha mask
+//! - Weighted blended order-independent transparency
 //! - Multiple geometry types (box, sphere, plane)
+//! - Custom render graph pass reading the HDR attachment before post-processing
+//! - Barycentric wireframe fallback drawing edges only
+//! - Ground grid drawn behind opaque geometry
+//! - Ground shadow darkening the floor under a model
+//! - Node layers: camera layer masks and light shadow layers
+//! - Stencil masking: content drawn only inside a mask
+//! - Material uniform tweaks: uploads counted, no bind group or pipeline rebuilds
+//! - Background pipeline compilation, pipeline prewarming and the on-disk pipeline cache
+//! - Decals projected onto opaque surfaces
+//! - Reflection probes captured and sampled by PBR materials
+//! - Light probe grids baked and lighting diffuse PBR materials
+//! - SSAO intensity 0 leaving ambient lighting unoccluded
+//! - Bloom threshold separating strong from weak emissive surfaces
+//! - Identity color-grading LUTs (`.cube` and strip image) matching grading off
+//! - Prepass velocity buffer: zero for still objects, non-zero for moving ones
+use std::sync::Mutex;
+
+use myth::ALL_LAYERS;
 use myth::prelude::*;
 use myth::render::core::ReadbackStream;
+use myth::render::graph::{
+    ExecuteContext, HookStage, PassNode, RenderTargetOps, SceneAttachment, TextureNodeId,
+};
 
 // Integration tests for synchronous headless readback.
 //
@@ -382,6 +405,1113 @@ fn unlit_material_no_light() {
     assert_not_black(&pixels, "unlit_material_no_light");
 }
 
+// ── Points Material Tests ────────────────────────────────────────────────
+
+/// A single point drawn as a 16px sprite must cover more than one pixel.
+#[test]
+fn points_material_sprite_size() {
+    let (mut engine, expected) = setup_headless(128, 128);
+    let scene = engine.scene_manager.create_active();
+
+    let geometry = engine
+        .assets
+        .geometries
+        .add(Geometry::new_points(&[Vec3::ZERO]));
+    let material = engine
+        .assets
+        .materials
+        .add(PointsMaterial::new(Vec4::ONE).with_size(16.0));
+    scene.add_mesh(Mesh::new(geometry, material));
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let pixels = render_and_capture(&mut engine, 2);
+    assert_eq!(pixels.len(), expected);
+    let lit = pixels.chunks_exact(4).filter(|px| px[0] > 128).count();
+    assert!(
+        lit > 100,
+        "points_material_sprite_size: only {lit} lit pixels"
+    );
+}
+
+// ── Texture Streaming Tests ──────────────────────────────────────────────
+
+/// A streaming texture uploaded one row per frame must only show up once
+/// every row has reached the GPU.
+#[test]
+fn streaming_texture_completes_over_frames() {
+    let (mut engine, expected) = setup_headless(128, 128);
+    engine.renderer.update_settings(RendererSettings {
+        texture_upload_budget: 64 * 4,
+        ..engine.renderer.settings().clone()
+    });
+    let scene = engine.scene_manager.create_active();
+
+    let image = engine.assets.images.add(Image::checkerboard(64, 64, 8));
+    let mut texture = Texture::new_2d(Some("streamed"), image);
+    texture.streaming = true;
+    let texture = engine.assets.textures.add(texture);
+
+    let mat = UnlitMaterial::new(Vec4::ONE).with_map(texture);
+    scene.spawn_box(1.0, 1.0, 1.0, mat, &engine.assets);
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let first = render_and_capture(&mut engine, 0);
+
+    let last = render_and_capture(&mut engine, 70);
+    assert_eq!(last.len(), expected);
+    assert_images_differ(&first, &last, "streaming_texture_completes_over_frames");
+}
+
+// ── Decals ───────────────────────────────────────────────────────────────
+
+/// A decal box around a white plane tints the plane under it, and stops
+/// once the box no longer reaches the surface.
+#[test]
+fn decal_projects_onto_opaque_surface() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let image = engine
+        .assets
+        .images
+        .add(Image::solid_color([255, 0, 0, 255]));
+    let texture = engine
+        .assets
+        .textures
+        .add(Texture::new_2d(Some("decal"), image));
+
+    let scene = engine.scene_manager.create_active();
+    scene.spawn_plane(4.0, 4.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    let decal = scene.add_decal(
+        Transform::builder().scale(Vec3::new(1.0, 1.0, 0.5)).build(),
+        texture,
+        DecalOptions::default(),
+    );
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let center = |pixels: &[u8]| {
+        let i = (32 * 64 + 32) * 4;
+        [pixels[i], pixels[i + 1], pixels[i + 2]]
+    };
+    let corner = |pixels: &[u8]| {
+        let i = (4 * 64 + 4) * 4;
+        [pixels[i], pixels[i + 1], pixels[i + 2]]
+    };
+
+    let pixels = render_and_capture(&mut engine, 1);
+    let px = center(&pixels);
+    assert!(px[0] > 128 && px[1] < 64, "decal center is {px:?}");
+    let px = corner(&pixels);
+    assert!(
+        px.iter().all(|&c| c > 128),
+        "plane outside the decal is {px:?}"
+    );
+
+    // Pushed in front of the plane, the box no longer contains the surface.
+    let scene = engine.scene_manager.active_scene_mut().unwrap();
+    scene.node(&decal).set_position(0.0, 0.0, 1.0);
+    let pixels = render_and_capture(&mut engine, 0);
+    let px = center(&pixels);
+    assert!(
+        px.iter().all(|&c| c > 128),
+        "moved decal still shows: {px:?}"
+    );
+}
+
+// ── Reflection Probes ────────────────────────────────────────────────────
+
+/// A mirror sphere under a solid background has no environment to reflect,
+/// until a probe captures the background around it.
+#[test]
+fn reflection_probe_is_captured_and_reflected() {
+    let (mut engine, _) = setup_headless(64, 64);
+
+    let scene = engine.scene_manager.create_active();
+    scene.background.set_color(Vec4::new(1.0, 0.0, 0.0, 1.0));
+    scene.spawn_sphere(
+        1.0,
+        PhysicalMaterial::new(Vec4::ONE)
+            .with_metalness(1.0)
+            .with_roughness(0.2),
+        &engine.assets,
+    );
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let center = |pixels: &[u8]| {
+        let i = (32 * 64 + 32) * 4;
+        [pixels[i], pixels[i + 1], pixels[i + 2]]
+    };
+
+    let pixels = render_and_capture(&mut engine, 1);
+    let px = center(&pixels);
+    assert!(px[0] < 32, "sphere without a probe is {px:?}");
+
+    let scene = engine.scene_manager.active_scene_mut().unwrap();
+    scene.add_reflection_probe(Vec3::ZERO, 32);
+    let pixels = render_and_capture(&mut engine, 1);
+    let px = center(&pixels);
+    assert!(
+        px[0] > 96 && px[1] < 64,
+        "sphere inside the probe is {px:?}"
+    );
+}
+
+// ── Light Probes ─────────────────────────────────────────────────────────
+
+/// A diffuse sphere under a solid background receives no indirect light,
+/// until a probe grid around it bakes the background's colour.
+#[test]
+fn light_probes_are_baked_and_light_diffuse_surfaces() {
+    let (mut engine, _) = setup_headless(64, 64);
+
+    let scene = engine.scene_manager.create_active();
+    scene.background.set_color(Vec4::new(1.0, 0.0, 0.0, 1.0));
+    scene.environment.set_ambient_light(Vec3::ZERO);
+    scene.spawn_sphere(
+        1.0,
+        PhysicalMaterial::new(Vec4::ONE).with_roughness(1.0),
+        &engine.assets,
+    );
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let center = |pixels: &[u8]| {
+        let i = (32 * 64 + 32) * 4;
+        [pixels[i], pixels[i + 1], pixels[i + 2]]
+    };
+
+    let pixels = render_and_capture(&mut engine, 1);
+    let px = center(&pixels);
+    assert!(px[0] < 32, "sphere without probes is {px:?}");
+
+    let scene = engine.scene_manager.active_scene_mut().unwrap();
+    let bounds = myth::resources::geometry::BoundingBox {
+        min: Vec3::splat(-2.0),
+        max: Vec3::splat(2.0),
+    };
+    scene.bake_light_probes(bounds, myth::math::UVec3::splat(2));
+    let pixels = render_and_capture(&mut engine, 1);
+    let px = center(&pixels);
+    assert!(
+        px[0] > 64 && px[1] < px[0] / 2,
+        "sphere inside the probe grid is {px:?}"
+    );
+}
+
+/// Renders two blocks with a narrow gap between them, lit only by ambient
+/// light, with the given SSAO settings applied.
+fn render_ssao_crevice(engine: &mut Engine, configure: impl FnOnce(&mut SsaoSettings)) -> Vec<u8> {
+    reset_active_scene(engine);
+    let scene = engine.scene_manager.create_active();
+    scene.environment.set_ambient_light(Vec3::ONE);
+    configure(&mut scene.ssao);
+
+    let ground = scene.spawn_box(
+        4.0,
+        0.2,
+        4.0,
+        PhysicalMaterial::new(Vec4::ONE).with_roughness(1.0),
+        &engine.assets,
+    );
+    scene.node(&ground).set_position(0.0, -0.1, 0.0);
+    for x in [-0.55, 0.55] {
+        let block = scene.spawn_box(
+            1.0,
+            1.0,
+            1.0,
+            PhysicalMaterial::new(Vec4::ONE).with_roughness(1.0),
+            &engine.assets,
+        );
+        scene.node(&block).set_position(x, 0.5, 0.0);
+    }
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 2.0, 3.0)
+        .look_at(Vec3::new(0.0, 0.3, 0.0));
+    scene.active_camera = Some(cam);
+
+    render_and_capture(engine, 2)
+}
+
+/// With intensity 0 the AO buffer is uniformly white, at half and at full
+/// resolution, so the image matches one rendered without SSAO. A positive
+/// intensity darkens the crevices.
+#[test]
+fn ssao_intensity_zero_produces_white_ao() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let brightness = |pixels: &[u8]| -> u64 {
+        pixels
+            .chunks_exact(4)
+            .map(|px| u64::from(px[0]) + u64::from(px[1]) + u64::from(px[2]))
+            .sum()
+    };
+    let assert_matches = |a: &[u8], b: &[u8], label: &str| {
+        let max_diff = a.iter().zip(b).map(|(x, y)| x.abs_diff(*y)).max();
+        assert!(max_diff <= Some(2), "{label}: max difference {max_diff:?}");
+    };
+
+    let without_ssao = render_ssao_crevice(&mut engine, |_| {});
+    assert_not_black(&without_ssao, "ssao baseline");
+
+    let half_res = render_ssao_crevice(&mut engine, |ssao| {
+        ssao.set_enabled(true);
+        ssao.set_intensity(0.0);
+    });
+    assert_matches(&without_ssao, &half_res, "half-resolution SSAO");
+
+    let full_res = render_ssao_crevice(&mut engine, |ssao| {
+        ssao.set_enabled(true);
+        ssao.set_half_resolution(false);
+        ssao.set_blur_passes(0);
+        ssao.set_intensity(0.0);
+    });
+    assert_matches(&without_ssao, &full_res, "full-resolution SSAO");
+
+    let occluded = render_ssao_crevice(&mut engine, |ssao| {
+        ssao.set_enabled(true);
+        ssao.set_intensity(3.0);
+    });
+    assert!(
+        brightness(&occluded) < brightness(&without_ssao),
+        "SSAO did not darken the crevices"
+    );
+}
+
+// ── Bloom ───────────────────────────────────────────────────────────────
+
+/// Renders an emissive quad facing the camera on a black background with
+/// the given bloom settings applied.
+fn render_emissive_quad(
+    engine: &mut Engine,
+    intensity: f32,
+    configure: impl FnOnce(&mut BloomSettings),
+) -> Vec<u8> {
+    reset_active_scene(engine);
+    let scene = engine.scene_manager.create_active();
+    configure(&mut scene.bloom);
+
+    let mat =
+        PhysicalMaterial::new(Vec4::new(0.0, 0.0, 0.0, 1.0)).with_emissive(Vec3::ONE, intensity);
+    scene.spawn_plane(0.5, 0.5, mat, &engine.assets);
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 6.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    render_and_capture(engine, 2)
+}
+
+/// With a threshold of 1.0, a quad at emissive intensity 5 glows past its
+/// edges while one at 0.5 leaves its surroundings untouched. Without a
+/// threshold (the default) the dim quad glows too.
+#[test]
+fn bloom_threshold_only_spreads_strong_emissive() {
+    const SIZE: usize = 128;
+    // The quad covers about 13 pixels around the centre; sample the row 7
+    // pixels above its top edge.
+    let halo = |pixels: &[u8]| -> u32 {
+        let row = SIZE / 2 - 14;
+        (SIZE / 2 - 6..SIZE / 2 + 6)
+            .map(|x| {
+                let i = (row * SIZE + x) * 4;
+                u32::from(pixels[i]) + u32::from(pixels[i + 1]) + u32::from(pixels[i + 2])
+            })
+            .sum()
+    };
+    let bloom = |threshold: f32| {
+        move |settings: &mut BloomSettings| {
+            settings.set_enabled(true);
+            settings.set_strength(0.5);
+            settings.set_threshold(threshold);
+        }
+    };
+
+    let (mut engine, _) = setup_headless(SIZE as u32, SIZE as u32);
+
+    let bright_off = render_emissive_quad(&mut engine, 5.0, |_| {});
+    assert_not_black(&bright_off, "bright emissive quad");
+    let bright_on = render_emissive_quad(&mut engine, 5.0, bloom(1.0));
+    assert!(
+        halo(&bright_on) > halo(&bright_off) + 30,
+        "intensity 5 did not bloom: {} vs {}",
+        halo(&bright_on),
+        halo(&bright_off)
+    );
+
+    let dim_off = render_emissive_quad(&mut engine, 0.5, |_| {});
+    let dim_on = render_emissive_quad(&mut engine, 0.5, bloom(1.0));
+    assert!(
+        halo(&dim_on).abs_diff(halo(&dim_off)) <= 6,
+        "intensity 0.5 bloomed above the threshold: {} vs {}",
+        halo(&dim_on),
+        halo(&dim_off)
+    );
+
+    let dim_unthresholded = render_emissive_quad(&mut engine, 0.5, bloom(0.0));
+    assert!(
+        halo(&dim_unthresholded) > halo(&dim_on),
+        "a zero threshold should let the dim quad glow"
+    );
+}
+
+// ── Color Grading ───────────────────────────────────────────────────────
+
+/// Edge length of the test LUTs. With 16 entries the identity strip image
+/// stores exact `i * 17` bytes.
+const LUT_SIZE: u32 = 16;
+
+/// Writes a `.cube` LUT mapping each normalized input through `map`.
+fn write_cube_lut(name: &str, map: impl Fn(f32) -> f32) -> std::path::PathBuf {
+    use std::fmt::Write as _;
+
+    let mut text = format!("LUT_3D_SIZE {LUT_SIZE}\n");
+    let value = |i: u32| map(i as f32 / (LUT_SIZE - 1) as f32);
+    for b in 0..LUT_SIZE {
+        for g in 0..LUT_SIZE {
+            for r in 0..LUT_SIZE {
+                let _ = writeln!(text, "{} {} {}", value(r), value(g), value(b));
+            }
+        }
+    }
+    let path = std::env::temp_dir().join(format!("myth_lut_{}_{name}", std::process::id()));
+    std::fs::write(&path, text).unwrap();
+    path
+}
+
+/// Renders a lit sphere in front of a coloured backdrop, graded by `lut`.
+fn render_graded_scene(engine: &mut Engine, lut: Option<TextureHandle>) -> Vec<u8> {
+    reset_active_scene(engine);
+    let scene = engine.scene_manager.create_active();
+    scene.tone_mapping.set_lut_texture(lut);
+
+    let sphere = PhysicalMaterial::new(Vec4::new(0.9, 0.5, 0.2, 1.0)).with_roughness(0.5);
+    scene.spawn_sphere(1.0, sphere, &engine.assets);
+    let backdrop = scene.spawn_plane(
+        6.0,
+        6.0,
+        PhysicalMaterial::new(Vec4::new(0.2, 0.4, 0.8, 1.0)),
+        &engine.assets,
+    );
+    scene.node(&backdrop).set_position(0.0, 0.0, -2.0);
+    scene.add_light(Light::new_directional(Vec3::new(1.0, 1.0, 1.0), 3.0));
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 5.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    render_and_capture(engine, 2)
+}
+
+/// Largest per-channel RGB difference between two frames.
+fn max_channel_diff(a: &[u8], b: &[u8]) -> u8 {
+    a.chunks_exact(4)
+        .zip(b.chunks_exact(4))
+        .flat_map(|(x, y)| (0..3).map(move |i| x[i].abs_diff(y[i])))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Identity LUTs, loaded from a `.cube` file and from an N²×N strip image,
+/// leave the frame as it is with grading off. An inverting LUT shows that
+/// the grading pass actually samples the LUT.
+#[test]
+fn identity_luts_match_grading_off() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let ungraded = render_graded_scene(&mut engine, None);
+    assert_not_black(&ungraded, "ungraded scene");
+
+    let cube_path = write_cube_lut("identity.cube", |v| v);
+    let strip_path =
+        std::env::temp_dir().join(format!("myth_lut_{}_identity.png", std::process::id()));
+    let step = |i: u32| (i * 255 / (LUT_SIZE - 1)) as u8;
+    image::RgbaImage::from_fn(LUT_SIZE * LUT_SIZE, LUT_SIZE, |x, y| {
+        image::Rgba([step(x % LUT_SIZE), step(y), step(x / LUT_SIZE), 255])
+    })
+    .save(&strip_path)
+    .unwrap();
+    let invert_path = write_cube_lut("invert.cube", |v| 1.0 - v);
+
+    for (path, label) in [(&cube_path, "cube"), (&strip_path, "strip")] {
+        let lut = engine
+            .assets
+            .load_lut_texture_blocking(path.to_str().unwrap())
+            .expect("LUT load failed");
+        let graded = render_graded_scene(&mut engine, Some(lut));
+        let diff = max_channel_diff(&graded, &ungraded);
+        assert!(
+            diff <= 2,
+            "identity {label} LUT changed a channel by {diff}"
+        );
+    }
+
+    let invert = engine
+        .assets
+        .load_lut_texture_blocking(invert_path.to_str().unwrap())
+        .expect("LUT load failed");
+    let inverted = render_graded_scene(&mut engine, Some(invert));
+    assert!(
+        max_channel_diff(&inverted, &ungraded) > 64,
+        "an inverting LUT left the frame unchanged"
+    );
+
+    for path in [cube_path, strip_path, invert_path] {
+        std::fs::remove_file(path).ok();
+    }
+}
+
+// ── Motion Vectors ──────────────────────────────────────────────────────
+
+/// A box that moves between two frames writes non-zero velocity at its
+/// pixels, read back through the `Velocity` debug view. A still box and the
+/// empty background stay at zero. Needs `--features debug_view`.
+#[cfg(feature = "debug_view")]
+#[test]
+fn moving_box_writes_velocity() {
+    const SIZE: usize = 64;
+    let brightness = |pixels: &[u8], x: usize, y: usize| -> u32 {
+        let i = (y * SIZE + x) * 4;
+        u32::from(pixels[i]) + u32::from(pixels[i + 1]) + u32::from(pixels[i + 2])
+    };
+
+    let (mut engine, _) = setup_headless(SIZE as u32, SIZE as u32);
+    let scene = engine.scene_manager.create_active();
+    let cube = scene.spawn_box(
+        1.0,
+        1.0,
+        1.0,
+        PhysicalMaterial::new(Vec4::ONE),
+        &engine.assets,
+    );
+    scene.add_light(Light::new_directional(Vec3::ONE, 3.0));
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 5.0)
+        .look_at(Vec3::ZERO);
+    scene.get_camera_mut(cam).unwrap().debug_view.mode = DebugViewMode::Velocity;
+    scene.active_camera = Some(cam);
+
+    let still = render_and_capture(&mut engine, 2);
+    assert_eq!(
+        brightness(&still, SIZE / 2, SIZE / 2),
+        0,
+        "a still box has velocity"
+    );
+
+    engine
+        .scene_manager
+        .active_scene_mut()
+        .unwrap()
+        .node(&cube)
+        .set_position(0.2, 0.0, 0.0);
+    let moving = render_and_capture(&mut engine, 0);
+    assert!(
+        brightness(&moving, SIZE / 2, SIZE / 2) > 0,
+        "the moving box wrote no velocity"
+    );
+    assert_eq!(
+        brightness(&moving, 2, 2),
+        0,
+        "the empty background has velocity"
+    );
+}
+
+// ── Manual Frame Driving ─────────────────────────────────────────────────
+
+/// `render_once` advances the externally managed clock and renders a frame.
+#[test]
+fn render_once_advances_clock() {
+    let (mut engine, expected) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    engine.set_time(10.0);
+    assert!(engine.render_once(0.5));
+    assert!((engine.time() - 10.5).abs() < 1e-6);
+    assert_eq!(engine.frame_count(), 1);
+
+    let pixels = engine.readback_pixels().expect("readback failed");
+    assert_eq!(pixels.len(), expected);
+    assert_not_black(&pixels, "render_once_advances_clock");
+}
+
+/// Frame statistics report one visible box and one culled behind the camera.
+#[test]
+fn last_frame_stats_reports_culling_and_draws() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    let hidden = scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    scene.node(&hidden).set_position(0.0, 0.0, 20.0);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    assert_eq!(
+        engine.renderer.last_frame_stats(),
+        myth::RenderStats::default()
+    );
+    render_and_capture(&mut engine, 1);
+
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.visible_meshes, 1);
+    assert_eq!(stats.culled_meshes, 1);
+    assert!(stats.draw_calls >= 1);
+    assert!(stats.triangles >= 12);
+    assert!(stats.pipeline_switches >= 1);
+}
+
+/// Animating uniform values on many materials uploads each uniform buffer
+/// once per frame and never rebuilds bind groups or compiles pipelines.
+#[test]
+fn material_uniform_tweaks_upload_without_recompiling() {
+    const MATERIALS: usize = 8;
+
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    let materials: Vec<_> = (0..MATERIALS)
+        .map(|i| {
+            let material = engine
+                .assets
+                .materials
+                .add(PhysicalMaterial::new(Vec4::ONE));
+            let node = scene.spawn_sphere(0.2, material, &engine.assets);
+            scene
+                .node(&node)
+                .set_position(i as f32 * 0.5 - 1.75, 0.0, 0.0);
+            material
+        })
+        .collect();
+    scene.add_light(Light::new_directional(Vec3::ONE, 3.0));
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 6.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    render_and_capture(&mut engine, 2);
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.material_uniform_uploads, 0);
+    assert_eq!(stats.pipelines_compiled, 0);
+
+    for frame in 1..=3 {
+        for &handle in &materials {
+            let material = engine.assets.materials.get(handle).unwrap();
+            let physical = material.as_physical().unwrap();
+            let mut uniforms = physical.uniforms_mut();
+            uniforms.emissive = Vec3::new(frame as f32 * 0.1, 0.0, 0.0);
+            uniforms.emissive_intensity = frame as f32;
+        }
+        engine.update(1.0 / 60.0);
+        engine.render_active_scene();
+
+        let stats = engine.renderer.last_frame_stats();
+        assert_eq!(stats.material_uniform_uploads, MATERIALS as u32);
+        assert_eq!(stats.material_bind_group_rebuilds, 0);
+        assert_eq!(stats.pipelines_compiled, 0);
+    }
+}
+
+/// With background compilation a new mesh is drawn with the fallback
+/// pipeline until its own pipeline is ready, instead of stalling the frame
+/// or popping in late.
+#[test]
+fn async_pipeline_compilation_draws_pending_meshes_with_fallback() {
+    let (mut engine, _) = setup_headless(64, 64);
+    engine.renderer.update_settings(RendererSettings {
+        async_pipeline_compilation: true,
+        ..engine.renderer.settings().clone()
+    });
+    let scene = engine.scene_manager.create_active();
+    scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let fallback_pixels = render_and_capture(&mut engine, 0);
+    let stats = engine.renderer.last_frame_stats();
+    assert!(stats.pipelines_pending >= 1);
+    assert!(engine.renderer.pending_pipelines() >= 1);
+    assert!(stats.draw_calls > 0, "pending mesh was skipped");
+    assert_not_black(&fallback_pixels, "fallback pipeline");
+
+    engine.renderer.wait_for_pipelines();
+    let pixels = render_and_capture(&mut engine, 0);
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.pipelines_pending, 0);
+    assert_eq!(engine.renderer.pending_pipelines(), 0);
+    assert!(stats.draw_calls > 0);
+    assert_not_black(&pixels, "async pipelines");
+}
+
+/// A prewarmed material/geometry pair compiles no pipeline when a mesh
+/// using it first appears.
+#[test]
+fn prewarm_compiles_pipeline_before_first_use() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let material = engine
+        .assets
+        .materials
+        .add(PhysicalMaterial::new(Vec4::new(0.8, 0.3, 0.2, 1.0)));
+    let geometry = engine.assets.geometries.add(Geometry::new_sphere(0.5));
+    let scene = engine.scene_manager.create_active();
+    scene.add_light(Light::new_directional(Vec3::ONE, 3.0));
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+    render_and_capture(&mut engine, 1);
+
+    engine.renderer.prewarm(material, geometry);
+    render_and_capture(&mut engine, 0);
+    let stats = engine.renderer.last_frame_stats();
+    assert!(stats.pipelines_compiled >= 1);
+    assert_eq!(stats.draw_calls, 0, "prewarming must not draw");
+
+    let scene = engine.scene_manager.active_scene_mut().unwrap();
+    scene.add_mesh(Mesh::new(geometry, material));
+    let pixels = render_and_capture(&mut engine, 0);
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.pipelines_compiled, 0);
+    assert_not_black(&pixels, "prewarmed mesh");
+}
+
+/// The driver pipeline cache is written when the renderer is dropped and
+/// seeds the next renderer on the same adapter, which draws the same
+/// image. The seeded cache holds the saved pipelines before anything is
+/// compiled, so the driver accepted the blob. A file from another adapter
+/// or driver is ignored. Header handling on adapters without pipeline
+/// caches is covered by the `disk_cache` unit tests.
+#[test]
+fn pipeline_cache_persists_across_renderers() {
+    let temp = |name: &str| {
+        std::env::temp_dir().join(format!("myth_pipeline_cache_{}_{name}", std::process::id()))
+    };
+    let path = temp("scene.bin");
+    let _ = std::fs::remove_file(&path);
+
+    let init = |path: Option<&std::path::Path>| {
+        let mut engine = Engine::new(
+            RendererInitConfig::default(),
+            RendererSettings {
+                pipeline_cache_path: path.map(std::path::Path::to_path_buf),
+                ..Default::default()
+            },
+        );
+        pollster::block_on(engine.init_headless(32, 32, None)).expect("headless init failed");
+        engine
+    };
+    // Size of the file written by a renderer that has compiled nothing yet.
+    let saved_size_at_init = |engine: &Engine, name: &str| {
+        let file = temp(name);
+        assert!(engine.renderer.save_pipeline_cache(&file).unwrap());
+        let size = std::fs::metadata(&file).unwrap().len();
+        std::fs::remove_file(&file).unwrap();
+        size
+    };
+    let run = |path: &std::path::Path| {
+        let mut engine = init(Some(path));
+        let scene = engine.scene_manager.create_active();
+        scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+        let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+        scene
+            .node(&cam)
+            .set_position(0.0, 0.0, 4.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam);
+        let pixels = render_and_capture(&mut engine, 1);
+        (engine, pixels)
+    };
+
+    let (cold, cold_pixels) = run(&path);
+    if !cold.renderer.supports_pipeline_cache() {
+        // Backends without pipeline caches fall back silently.
+        assert!(!cold.renderer.save_pipeline_cache(&path).unwrap());
+        drop(cold);
+        assert!(!path.exists());
+        return;
+    }
+    drop(cold);
+    assert!(path.exists(), "cache not written on drop");
+
+    let empty_size = saved_size_at_init(&init(None), "empty.bin");
+    let seeded_size = saved_size_at_init(&init(Some(&path)), "seeded.bin");
+    assert!(
+        std::fs::metadata(&path).unwrap().len() > empty_size,
+        "saved cache holds no pipelines"
+    );
+    assert!(
+        seeded_size > empty_size,
+        "the driver rejected the saved cache ({seeded_size} vs {empty_size} bytes)"
+    );
+
+    let (mut warm, warm_pixels) = run(&path);
+    assert_eq!(cold_pixels, warm_pixels);
+    assert!(warm.renderer.load_pipeline_cache(&path));
+
+    let stale = path.with_extension("stale");
+    std::fs::write(&stale, b"MYTHPSO1\x05\x00\x00\x00OtherGPU").unwrap();
+    assert!(!warm.renderer.load_pipeline_cache(&stale));
+
+    drop(warm);
+    let _ = std::fs::remove_file(&path);
+    let _ = std::fs::remove_file(&stale);
+}
+
+/// GPU frustum culling draws the same image as CPU culling, leaving the
+/// frustum test to the compute pass. The hidden box sits beside the
+/// frustum in front of the camera, and its indirect draw must end up with
+/// zero instances.
+#[test]
+fn gpu_culling_matches_cpu_culling() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    let hidden = scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    // The frustum is about 1.7 wide on each side at z = 0.
+    scene.node(&hidden).set_position(6.0, 0.0, 0.0);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let cpu = render_and_capture(&mut engine, 1);
+
+    engine.renderer.update_settings(RendererSettings {
+        gpu_culling: true,
+        ..engine.renderer.settings().clone()
+    });
+    let gpu = render_and_capture(&mut engine, 1);
+
+    assert_not_black(&gpu, "gpu culling");
+    assert_eq!(cpu, gpu, "GPU culling changed the rendered image");
+
+    // The frustum test moved to the GPU, so the CPU sees no culled meshes.
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.visible_meshes, 2);
+    assert_eq!(stats.culled_meshes, 0);
+
+    // One object keeps its instance, the other is zeroed on every draw.
+    let counts = engine.renderer.gpu_culling_instance_counts();
+    assert!(!counts.is_empty(), "no GPU-culled draws were recorded");
+    let drawn: std::collections::HashSet<u32> = counts
+        .iter()
+        .filter(|(_, instances)| *instances > 0)
+        .map(|(object, _)| *object)
+        .collect();
+    let culled: std::collections::HashSet<u32> = counts
+        .iter()
+        .filter(|(_, instances)| *instances == 0)
+        .map(|(object, _)| *object)
+        .collect();
+    assert_eq!(drawn.len(), 1, "expected one visible object: {counts:?}");
+    assert_eq!(culled.len(), 1, "expected one culled object: {counts:?}");
+    assert!(drawn.is_disjoint(&culled));
+}
+
+/// GPU-driven draws find their model matrices and tints through the
+/// instance table, so several objects of each surface material, some
+/// tinted, render exactly as with per-draw model uniforms.
+#[test]
+fn gpu_driven_draws_match_per_draw_uniforms() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    scene.add_light(Light::new_directional(Vec3::ONE, 3.0));
+    for (i, x) in [-1.5_f32, -0.5, 0.5, 1.5].into_iter().enumerate() {
+        let unlit = scene.spawn_box(0.4, 0.4, 0.4, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+        scene.node(&unlit).set_position(x, 0.8, 0.0);
+        let phong = scene.spawn_box(0.4, 0.4, 0.4, PhongMaterial::new(Vec4::ONE), &engine.assets);
+        scene.node(&phong).set_position(x, 0.0, 0.0);
+        let physical = scene.spawn_box(
+            0.4,
+            0.4,
+            0.4,
+            PhysicalMaterial::new(Vec4::ONE),
+            &engine.assets,
+        );
+        scene.node(&physical).set_position(x, -0.8, 0.0);
+        if i % 2 == 0 {
+            scene.set_node_tint(unlit, Vec4::new(1.0, 0.2, 0.2, 1.0));
+            scene.set_node_tint(physical, Vec4::new(0.2, 0.2, 1.0, 1.0));
+        }
+    }
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 5.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let cpu = render_and_capture(&mut engine, 1);
+
+    engine.renderer.update_settings(RendererSettings {
+        gpu_culling: true,
+        ..engine.renderer.settings().clone()
+    });
+    let gpu = render_and_capture(&mut engine, 1);
+    if !engine.renderer.gpu_driven_draws_active() {
+        // Without vertex storage or INDIRECT_FIRST_INSTANCE the draws keep
+        // their model uniforms; `gpu_culling_matches_cpu_culling` covers it.
+        return;
+    }
+
+    assert_not_black(&gpu, "gpu-driven draws");
+    assert_eq!(cpu, gpu, "GPU-driven draws changed the rendered image");
+}
+
+/// Objects sharing a geometry and material are merged into one multi-draw
+/// over their compacted survivors, with the same image as one draw each.
+#[test]
+fn gpu_driven_draws_merge_shared_meshes() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let geometry = engine.assets.geometries.add(Geometry::new_sphere(0.3));
+    let material = engine
+        .assets
+        .materials
+        .add(PhongMaterial::new(Vec4::new(0.8, 0.3, 0.2, 1.0)));
+    let scene = engine.scene_manager.create_active();
+    scene.add_light(Light::new_directional(Vec3::ONE, 3.0));
+    // The outer columns fall outside the frustum.
+    for x in -4..=4 {
+        for y in -1..=1 {
+            let node = scene.add_mesh(Mesh::new(geometry, material));
+            scene
+                .node(&node)
+                .set_position(x as f32 * 0.9, y as f32 * 0.9, 0.0);
+        }
+    }
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 5.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let cpu = render_and_capture(&mut engine, 1);
+    let cpu_stats = engine.renderer.last_frame_stats();
+    assert!(cpu_stats.culled_meshes > 0 && cpu_stats.visible_meshes > 1);
+
+    engine.renderer.update_settings(RendererSettings {
+        gpu_culling: true,
+        ..engine.renderer.settings().clone()
+    });
+    let gpu = render_and_capture(&mut engine, 1);
+    if !engine.renderer.gpu_driven_draws_active() {
+        return;
+    }
+
+    assert_not_black(&gpu, "merged draws");
+    assert_eq!(cpu, gpu, "merged draws changed the rendered image");
+    let gpu_stats = engine.renderer.last_frame_stats();
+    assert!(
+        gpu_stats.draw_calls < cpu_stats.draw_calls,
+        "expected merged draws: {} GPU vs {} CPU draw calls",
+        gpu_stats.draw_calls,
+        cpu_stats.draw_calls
+    );
+}
+
+/// The GPU culling pass keeps exactly the objects the CPU frustum test
+/// keeps, as reported by the survivor count readback.
+#[test]
+fn gpu_culling_survivors_match_cpu_visible_count() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    for x in -5..=5 {
+        for z in -5..=5 {
+            let node =
+                scene.spawn_box(0.5, 0.5, 0.5, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+            scene
+                .node(&node)
+                .set_position(x as f32 * 3.0, 0.0, z as f32 * 3.0);
+        }
+    }
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 2.0, 8.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    render_and_capture(&mut engine, 0);
+    let cpu = engine.renderer.last_frame_stats();
+    assert!(cpu.culled_meshes > 0 && cpu.visible_meshes > 0);
+    assert_eq!(engine.renderer.gpu_culling_survivors(), None);
+
+    engine.renderer.update_settings(RendererSettings {
+        gpu_culling: true,
+        ..engine.renderer.settings().clone()
+    });
+    render_and_capture(&mut engine, 3);
+
+    assert_eq!(
+        engine.renderer.gpu_culling_survivors(),
+        Some(cpu.visible_meshes)
+    );
+}
+
+/// A wall across a corridor hides the boxes behind it; with occlusion
+/// culling they stop being drawn once the first query results come back.
+#[test]
+fn occlusion_culling_skips_objects_behind_a_wall() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    let wall = scene.spawn_box(8.0, 8.0, 0.5, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    scene.node(&wall).set_position(0.0, 0.0, 2.0);
+    for i in 0..4 {
+        let hidden = scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+        scene.node(&hidden).set_position(0.0, 0.0, -3.0 * i as f32);
+    }
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 6.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let frustum_only = render_and_capture(&mut engine, 0);
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.visible_meshes, 5);
+    assert_eq!(stats.culled_meshes, 0);
+
+    engine
+        .scene_manager
+        .active_scene_mut()
+        .unwrap()
+        .enable_occlusion_culling(true);
+    let occlusion = render_and_capture(&mut engine, 3);
+
+    assert_eq!(
+        frustum_only, occlusion,
+        "occlusion culling changed the image"
+    );
+    let stats = engine.renderer.last_frame_stats();
+    assert_eq!(stats.visible_meshes, 1);
+    assert_eq!(stats.culled_meshes, 4);
+}
+
+/// Pass timings are either unavailable (no timestamp queries) or name real
+/// passes with non-negative durations once the async readback lands.
+#[test]
+fn pass_timings_resolve_after_a_few_frames() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    scene.spawn_box(1.0, 1.0, 1.0, UnlitMaterial::new(Vec4::ONE), &engine.assets);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let supported = engine.renderer.device().is_some_and(|d| {
+        d.features()
+            .contains(wgpu::Features::TIMESTAMP_QUERY_INSIDE_ENCODERS)
+    });
+
+    for _ in 0..6 {
+        render_and_capture(&mut engine, 0);
+    }
+
+    let timings = engine.renderer.pass_timings();
+    if supported {
+        assert!(!timings.is_empty());
+        assert!(
+            timings
+                .iter()
+                .all(|(name, ms)| !name.is_empty() && *ms >= 0.0)
+        );
+    } else {
+        assert!(timings.is_empty());
+    }
+}
+
+// ── Sprite Material Tests ────────────────────────────────────────────────
+
+/// A pixel-sized sprite keeps its on-screen size regardless of distance.
+#[test]
+fn sprite_material_constant_pixel_size() {
+    let lit_pixels = |distance: f32| {
+        let (mut engine, expected) = setup_headless(128, 128);
+        let scene = engine.scene_manager.create_active();
+
+        let material = SpriteMaterial::from_color(Vec4::ONE).with_pixel_size(Vec2::splat(32.0));
+        scene.spawn_sprite(material, &engine.assets);
+
+        let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+        scene
+            .node(&cam)
+            .set_position(0.0, 0.0, distance)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam);
+
+        let pixels = render_and_capture(&mut engine, 2);
+        assert_eq!(pixels.len(), expected);
+        pixels.chunks_exact(4).filter(|px| px[0] > 128).count()
+    };
+
+    let near = lit_pixels(3.0);
+    let far = lit_pixels(30.0);
+    assert!(
+        near > 600,
+        "sprite_material_constant_pixel_size: only {near} lit pixels"
+    );
+    assert!(
+        near.abs_diff(far) < near / 10,
+        "sprite_material_constant_pixel_size: {near} px near vs {far} px far"
+    );
+}
+
 // ── Multi-Light Tests ────────────────────────────────────────────────────
 
 /// Scene with directional + point light should differ from directional-only.
@@ -431,6 +1561,58 @@ fn multi_light_scene() {
     assert_images_differ(&dir_only, &dir_plus_point, "multi_light");
 }
 
+/// A floor lit by 256 small point lights looks the same whether each
+/// fragment loops over every light or only over its light cluster.
+#[test]
+fn clustered_lighting_matches_all_lights_loop() {
+    let (mut engine, _) = setup_headless(128, 128);
+    let scene = engine.scene_manager.create_active();
+    scene.environment.set_ambient_light(Vec3::ZERO);
+    let floor = scene.spawn_box(
+        40.0,
+        0.2,
+        40.0,
+        PhysicalMaterial::new(Vec4::ONE).with_roughness(0.6),
+        &engine.assets,
+    );
+    scene.node(&floor).set_position(0.0, -0.1, 0.0);
+    for i in 0..256 {
+        let (x, z) = ((i % 16) as f32, (i / 16) as f32);
+        let color = Vec3::new(x / 15.0, 1.0 - z / 15.0, 0.5);
+        let light = scene.add_light(Light::new_point(color, 2.0, 1.5));
+        scene
+            .node(&light)
+            .set_position(x * 1.2 - 9.0, 0.4, z * -1.2 + 2.0);
+    }
+    scene.add_light(Light::new_directional(Vec3::splat(0.1), 1.0));
+    let cam = scene.add_camera(Camera::new_perspective(60.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 4.0, 5.0)
+        .look_at(Vec3::new(0.0, 0.0, -6.0));
+    scene.active_camera = Some(cam);
+
+    let clustered = render_and_capture(&mut engine, 1);
+    assert_not_black(&clustered, "clustered lighting");
+
+    engine.renderer.update_settings(RendererSettings {
+        clustered_lighting: false,
+        ..engine.renderer.settings().clone()
+    });
+    let all_lights = render_and_capture(&mut engine, 1);
+
+    let max_diff = clustered
+        .iter()
+        .zip(&all_lights)
+        .map(|(a, b)| a.abs_diff(*b))
+        .max()
+        .unwrap_or(0);
+    assert!(
+        max_diff <= 2,
+        "clustered lighting differs from the all-lights loop by {max_diff}"
+    );
+}
+
 // ── Alpha Mode Tests ─────────────────────────────────────────────────────
 
 /// A semi-transparent (AlphaMode::Blend) object in front of a solid
@@ -485,6 +1667,448 @@ fn alpha_blend_vs_opaque() {
     assert_images_differ(&opaque, &blended, "alpha_blend_vs_opaque");
 }
 
+/// Weighted blended OIT composites overlapping transparent layers the same
+/// way whichever order they are drawn in (up to rounding).
+#[test]
+fn weighted_oit_ignores_draw_order() {
+    let (mut engine, _) = setup_headless(128, 128);
+
+    let mut render = |red_first: bool| {
+        reset_active_scene(&mut engine);
+        let scene = engine.scene_manager.create_active();
+        scene.transparency_mode = TransparencyMode::WeightedBlended;
+        let bg = UnlitMaterial::new(Vec4::new(0.0, 0.0, 1.0, 1.0));
+        let bg_node = scene.spawn_plane(4.0, 4.0, bg, &engine.assets);
+        scene.node(&bg_node).set_position(0.0, 0.0, -2.0);
+
+        let layers = [
+            (Vec4::new(1.0, 0.0, 0.0, 0.5), 0.5),
+            (Vec4::new(0.0, 1.0, 0.0, 0.5), 0.0),
+        ];
+        let order = if red_first { [0, 1] } else { [1, 0] };
+        for i in order {
+            let (color, z) = layers[i];
+            let mat = UnlitMaterial::new(color).with_alpha_mode(AlphaMode::Blend);
+            let node = scene.spawn_plane(1.5, 1.5, mat, &engine.assets);
+            scene.node(&node).set_position(0.0, 0.0, z);
+        }
+
+        let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+        scene
+            .node(&cam)
+            .set_position(0.0, 0.0, 3.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam);
+        render_and_capture(&mut engine, 2)
+    };
+
+    let red_first = render(true);
+    let green_first = render(false);
+    assert_not_black(&red_first, "weighted_oit");
+    // Half-float additive blending may round differently per order.
+    let max_diff = red_first
+        .iter()
+        .zip(&green_first)
+        .map(|(a, b)| a.abs_diff(*b))
+        .max()
+        .unwrap_or(0);
+    assert!(
+        max_diff <= 2,
+        "OIT result depends on draw order ({max_diff})"
+    );
+}
+
+// ── Wireframe ───────────────────────────────────────────────────────────
+
+/// The barycentric wireframe fallback (the WebGPU path) lights the edges
+/// of a box face and leaves its interior at the background color. The
+/// fallback is forced, so it runs even where line polygon mode exists.
+#[test]
+fn barycentric_wireframe_draws_edges_only() {
+    const SIZE: usize = 128;
+
+    // Head-on view of a unit box: the front face spans about ±31 pixels
+    // around the centre.
+    fn render_box(engine: &mut Engine, wireframe: bool) -> Vec<u8> {
+        reset_active_scene(engine);
+        let scene = engine.scene_manager.create_active();
+        scene.spawn_box(
+            1.0,
+            1.0,
+            1.0,
+            UnlitMaterial::new(Vec4::ONE).with_wireframe(wireframe),
+            &engine.assets,
+        );
+        let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+        scene
+            .node(&cam)
+            .set_position(0.0, 0.0, 3.0)
+            .look_at(Vec3::ZERO);
+        scene.active_camera = Some(cam);
+        render_and_capture(engine, 2)
+    }
+
+    let brightness = |pixels: &[u8], x: usize, y: usize| {
+        let i = (y * SIZE + x) * 4;
+        u32::from(pixels[i]) + u32::from(pixels[i + 1]) + u32::from(pixels[i + 2])
+    };
+
+    let (mut engine, _) = setup_headless(SIZE as u32, SIZE as u32);
+    engine.renderer.update_settings(RendererSettings {
+        barycentric_wireframe: true,
+        ..engine.renderer.settings().clone()
+    });
+    let filled = render_box(&mut engine, false);
+    let wire = render_box(&mut engine, true);
+
+    let row = SIZE / 2;
+    // Halfway to the face's right edge, well clear of the diagonal.
+    let probe = SIZE / 2 + 16;
+    let background = brightness(&wire, 2, 2);
+    assert!(
+        brightness(&filled, probe, row) > background + 200,
+        "probe pixel is not on the face"
+    );
+
+    // The face's left edge crosses the middle row near x = 33.
+    let edge = (26..40).map(|x| brightness(&wire, x, row)).max().unwrap();
+    assert!(
+        edge > background + 200,
+        "no edge pixel found: {edge} vs background {background}"
+    );
+
+    let interior = brightness(&wire, probe, row);
+    assert!(
+        interior.abs_diff(background) < 30,
+        "face interior is filled: {interior} vs background {background}"
+    );
+}
+
+// ── Ground Grid ──────────────────────────────────────────────────────────
+
+/// The ground grid shows up on screen but is hidden behind opaque geometry.
+#[test]
+fn ground_grid_is_occluded_by_geometry() {
+    let (mut engine, _) = setup_headless(128, 128);
+    let scene = engine.scene_manager.create_active();
+    let mat = UnlitMaterial::new(Vec4::new(1.0, 0.0, 0.0, 1.0));
+    scene.spawn_box(1.0, 1.0, 1.0, mat, &engine.assets);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 3.0, 6.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let without = render_and_capture(&mut engine, 2);
+
+    let scene = engine.scene_manager.active_scene_mut().unwrap();
+    scene.show_grid(GridOptions {
+        minor_color: Vec4::ONE,
+        major_color: Vec4::ONE,
+        ..GridOptions::default()
+    });
+    let with = render_and_capture(&mut engine, 2);
+
+    assert_images_differ(&without, &with, "ground_grid");
+    // The grid line through the origin runs under the box.
+    let center = (64 * 128 + 64) * 4;
+    assert_eq!(
+        without[center..center + 4],
+        with[center..center + 4],
+        "grid drawn on top of the box"
+    );
+}
+
+// ── Ground Shadow ────────────────────────────────────────────────────────
+
+/// The ground shadow darkens the floor around a box resting on the plane,
+/// ignoring the floor itself, and leaves the floor far away untouched.
+#[test]
+fn ground_shadow_darkens_floor_under_model() {
+    let (mut engine, _) = setup_headless(128, 128);
+    let scene = engine.scene_manager.create_active();
+    let floor = scene.spawn_box(
+        20.0,
+        0.2,
+        20.0,
+        UnlitMaterial::new(Vec4::ONE),
+        &engine.assets,
+    );
+    scene.node(&floor).set_position(0.0, -0.1, 0.0);
+    let model = scene.spawn_box(
+        1.0,
+        1.0,
+        1.0,
+        UnlitMaterial::new(Vec4::new(1.0, 0.0, 0.0, 1.0)),
+        &engine.assets,
+    );
+    scene.node(&model).set_position(0.0, 0.5, 0.0);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 4.0, 4.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let without = render_and_capture(&mut engine, 2);
+
+    let scene = engine.scene_manager.active_scene_mut().unwrap();
+    scene.set_ground_shadow(true, 0.0, 1.0);
+    scene.ground_shadow_mut().unwrap().opacity = 1.0;
+    let with = render_and_capture(&mut engine, 2);
+
+    let brightness = |pixels: &[u8]| pixels.iter().map(|&v| u64::from(v)).sum::<u64>();
+    assert!(
+        brightness(&with) < brightness(&without),
+        "ground shadow did not darken the image"
+    );
+    // A floor corner far from the box.
+    let corner = (124 * 128 + 4) * 4;
+    assert_eq!(
+        without[corner..corner + 4],
+        with[corner..corner + 4],
+        "ground shadow reached the far floor"
+    );
+}
+
+// ── Layers ───────────────────────────────────────────────────────────────
+
+/// Renders a red box on layer 1 floating above a white floor, lit from
+/// almost straight above by a shadow-casting directional light. The box's
+/// shadow covers the floor at the centre of the image.
+fn render_layered_caster(engine: &mut Engine, camera_mask: u32, shadow_layers: u32) -> Vec<u8> {
+    reset_active_scene(engine);
+    let scene = engine.scene_manager.create_active();
+
+    let floor = scene.spawn_plane(
+        20.0,
+        20.0,
+        PhongMaterial::new(Vec4::ONE).with_side(Side::Double),
+        &engine.assets,
+    );
+    scene
+        .node(&floor)
+        .set_rotation(Quat::from_rotation_x(-std::f32::consts::FRAC_PI_2))
+        .set_shadows(false, true);
+
+    let caster = scene.spawn_box(
+        1.0,
+        1.0,
+        1.0,
+        PhongMaterial::new(Vec4::new(1.0, 0.0, 0.0, 1.0)),
+        &engine.assets,
+    );
+    scene
+        .node(&caster)
+        .set_position(0.0, 1.5, 0.0)
+        .set_shadows(true, false)
+        .set_layers(1 << 1);
+
+    let mut light = Light::new_directional(Vec3::ONE, 3.0);
+    light.cast_shadows = true;
+    light.shadow_layers = shadow_layers;
+    let light = scene.add_light(light);
+    scene
+        .node(&light)
+        .set_position(1.0, 10.0, 0.0)
+        .look_at(Vec3::ZERO);
+
+    let mut camera = Camera::new_perspective(45.0, 1.0, 0.1);
+    camera.layer_mask = camera_mask;
+    let cam = scene.add_camera(camera);
+    scene
+        .node(&cam)
+        .set_position(0.0, 6.0, 6.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    render_and_capture(engine, 2)
+}
+
+/// A camera whose layer mask excludes the box doesn't draw it, but the box
+/// still casts its shadow. A light whose shadow layers exclude the box
+/// drops the shadow while the box stays visible.
+#[test]
+fn layer_masks_hide_nodes_and_shadow_casters() {
+    let red_pixels = |pixels: &[u8]| {
+        pixels
+            .chunks_exact(4)
+            .filter(|px| px[0] > px[1].saturating_add(60) && px[0] > px[2].saturating_add(60))
+            .count()
+    };
+    let centre = |pixels: &[u8]| {
+        let i = (64 * 128 + 64) * 4;
+        u32::from(pixels[i]) + u32::from(pixels[i + 1]) + u32::from(pixels[i + 2])
+    };
+    let floor_only = !(1 << 1);
+
+    let (mut engine, _) = setup_headless(128, 128);
+    let all = render_layered_caster(&mut engine, ALL_LAYERS, ALL_LAYERS);
+    let hidden = render_layered_caster(&mut engine, floor_only, ALL_LAYERS);
+    let no_shadow = render_layered_caster(&mut engine, ALL_LAYERS, floor_only);
+
+    assert!(red_pixels(&all) > 50, "box is not visible");
+    assert_eq!(
+        red_pixels(&hidden),
+        0,
+        "camera layer mask did not hide the box"
+    );
+    assert!(red_pixels(&no_shadow) > 50, "shadow layers hid the box");
+
+    assert!(
+        centre(&no_shadow) > centre(&all) + 60,
+        "shadow layers did not drop the shadow: {} vs {}",
+        centre(&no_shadow),
+        centre(&all)
+    );
+    assert!(
+        centre(&hidden) + 60 < centre(&no_shadow),
+        "a box hidden from the camera should still cast its shadow"
+    );
+}
+
+/// Geometry with an `Equal` stencil test only appears where an earlier
+/// mask wrote the reference value.
+#[test]
+fn stencil_mask_limits_masked_geometry() {
+    let (mut engine, _) = setup_headless(128, 128);
+    let scene = engine.scene_manager.create_active();
+
+    // A small mask in front, drawn first without writing depth.
+    let mask_mat = UnlitMaterial::new(Vec4::new(0.0, 1.0, 0.0, 1.0))
+        .with_depth_write(false)
+        .with_stencil(StencilSettings::write(1));
+    let mask = scene.spawn_box(1.0, 1.0, 0.01, mask_mat, &engine.assets);
+    scene.node(&mask).set_position(0.0, 0.0, 1.0);
+    scene.get_mesh_mut(mask).unwrap().render_order = -1;
+
+    // A wall filling most of the view, visible only through the mask.
+    let wall_mat =
+        UnlitMaterial::new(Vec4::new(1.0, 0.0, 0.0, 1.0)).with_stencil(StencilSettings::equal(1));
+    scene.spawn_box(4.0, 4.0, 0.1, wall_mat, &engine.assets);
+
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 5.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let pixels = render_and_capture(&mut engine, 2);
+    let pixel = |x: usize, y: usize| &pixels[(y * 128 + x) * 4..(y * 128 + x) * 4 + 4];
+
+    let inside = pixel(64, 64);
+    assert!(
+        inside[0] > 128 && inside[1] < 64,
+        "wall missing inside the mask: {inside:?}"
+    );
+    // Still on the wall, but outside the mask.
+    let outside = pixel(16, 64);
+    assert!(outside[0] < 64, "wall drawn outside the mask: {outside:?}");
+}
+
+// ── Custom Render Graph Passes ───────────────────────────────────────────
+
+/// User pass that records the format of its HDR input and clears a fresh
+/// transient target to black.
+struct BlackoutNode<'a> {
+    input: TextureNodeId,
+    output: TextureNodeId,
+    seen_format: &'a Mutex<Option<wgpu::TextureFormat>>,
+}
+
+impl<'a> PassNode<'a> for BlackoutNode<'a> {
+    fn execute(&self, ctx: &ExecuteContext, encoder: &mut wgpu::CommandEncoder) {
+        *self.seen_format.lock().unwrap() = Some(ctx.get_texture(self.input).format());
+        let target = ctx.get_color_attachment(
+            self.output,
+            RenderTargetOps::Clear(wgpu::Color::BLACK),
+            None,
+        );
+        encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: Some("Blackout Pass"),
+            color_attachments: &[target],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+            multiview_mask: None,
+        });
+    }
+}
+
+/// Renders one frame with a `BeforePostProcess` hook that replaces the HDR
+/// color, returning whether the hook saw scene normals.
+fn render_with_blackout(
+    engine: &mut Engine,
+    seen_format: &Mutex<Option<wgpu::TextureFormat>>,
+) -> bool {
+    let mut saw_normals = false;
+    engine.update(1.0 / 60.0);
+    let composer = engine.compose_frame().expect("no active camera");
+    composer
+        .add_custom_pass(HookStage::BeforePostProcess, |rdg, bb| {
+            saw_normals = bb.attachment(SceneAttachment::Normal).is_some();
+            let input = bb
+                .attachment(SceneAttachment::HdrColor)
+                .expect("HighFidelity publishes the HDR color");
+            let output = rdg.add_pass("Blackout", |builder| {
+                builder.read_texture(input);
+                let desc = builder.texture_desc(input);
+                let output = builder.create_texture("Blackout_HDR", desc);
+                let node = BlackoutNode {
+                    input,
+                    output,
+                    seen_format,
+                };
+                (node, output)
+            });
+            bb.with_attachment(SceneAttachment::HdrColor, output)
+        })
+        .render();
+    saw_normals
+}
+
+/// A custom pass inserted before post-processing runs, reads the HDR scene
+/// color, and its output feeds tone mapping.
+#[test]
+fn custom_pass_before_post_process_sees_hdr_color() {
+    let (mut engine, _) = setup_headless(64, 64);
+    let scene = engine.scene_manager.create_active();
+    let mat = UnlitMaterial::new(Vec4::new(1.0, 0.5, 0.2, 1.0));
+    scene.spawn_box(1.0, 1.0, 1.0, mat, &engine.assets);
+    let cam = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene
+        .node(&cam)
+        .set_position(0.0, 0.0, 3.0)
+        .look_at(Vec3::ZERO);
+    scene.active_camera = Some(cam);
+
+    let baseline = render_and_capture(&mut engine, 2);
+    assert_not_black(&baseline, "custom_pass baseline");
+
+    let seen_format = Mutex::new(None);
+    for _ in 0..3 {
+        assert!(!render_with_blackout(&mut engine, &seen_format));
+    }
+    assert_eq!(
+        *seen_format.lock().unwrap(),
+        Some(myth::renderer::HDR_TEXTURE_FORMAT)
+    );
+    let pixels = engine.readback_pixels().expect("readback failed");
+    assert!(
+        pixels.chunks_exact(4).all(|px| px[..3] == [0, 0, 0]),
+        "tone mapping did not consume the custom pass output"
+    );
+
+    // Normals are published on request even without SSAO.
+    engine.renderer.update_settings(RendererSettings {
+        scene_normals: true,
+        ..engine.renderer.settings().clone()
+    });
+    assert!(render_with_blackout(&mut engine, &seen_format));
+}
+
 // ── Cross-Material Comparison ────────────────────────────────────────────
 
 /// Varying PBR roughness on the same geometry should produce visually
@@ -6,13 +6,36 @@
 //! - TextureSlot: compute_matrix for UV transforms (identity, rotation, scale, offset)
 //! - Mat3Padded / Mat3Uniform: GPU alignment, construction helpers
 //! - FpsCounter: frame counting, 1-second update cycle
+//! - Procedural noise: determinism, tiling, output formats
+//! - Input gamepads: connection, button transitions, axes
+//! - Material normal scale: accessors and change tracking
+//! - Batched uniform writes: one version bump per guard, material version untouched
+//! - Physical tri-planar mapping: feature define and scale uniform
+//! - Physical ambient occlusion map: define and intensity uniform
+//! - Physical parallax occlusion mapping: height map, define, uniforms
+//! - Flat shading: settings define and material version
+//! - Hashed alpha: define and opaque-pass routing
+//! - Stencil settings: presets, material version
+//! - Texture LOD bias: uniform bytes, forced mip define
+//! - Bloom prefilter: threshold / soft knee uniforms, tint, lens dirt
+//! - Sprite material: blended defaults, pixel sizing, pivot
+//! - Text: glyph layout, alignment, anchors, fallback glyph, text material modes
+//! - Auto exposure: bright/dark convergence, EV clamps, manual fallback
 
 use glam::{Mat4, Vec2, Vec3, Vec4};
 
+use myth::resources::PixelFormat;
+use myth::resources::input::{ButtonState, GamepadAxis, GamepadButton, GamepadId, Input};
+use myth::resources::procedural::{self, NoiseKind, NoiseParams};
 use myth::resources::screen_space::{FeatureId, SssProfile, SssProfileData, SssRegistry};
-use myth::resources::uniforms::{Mat3Padded, Mat3Uniform};
+use myth::resources::uniforms::{DynamicModelUniforms, Mat3Padded, Mat3Uniform};
 use myth::resources::version_tracker::{ChangeTracker, MutGuard};
-use myth::resources::{TextureSlot, TextureTransform};
+use myth::resources::{
+    AlphaMode, AutoExposure, BloomSettings, Material, PhongMaterial, PhysicalFeatures,
+    PhysicalMaterial, RenderableMaterialTrait, SpriteMaterial, StencilSettings, TextMaterial,
+    TextureHandle, TextureSlot, TextureTransform, ToneMappingSettings, UnlitMaterial,
+};
+use myth::{FontAtlas, Glyph, TextAlign, TextMesh};
 use myth_dev_utils::FpsCounter;
 
 const EPSILON: f32 = 1e-5;
@@ -272,6 +295,11 @@ fn mat3_padded_size_is_48_bytes() {
     assert_eq!(std::mem::size_of::<Mat3Padded>(), 48);
 }
 
+#[test]
+fn dynamic_model_uniforms_fit_one_256_byte_slot() {
+    assert_eq!(std::mem::size_of::<DynamicModelUniforms>(), 256);
+}
+
 #[test]
 fn mat3_padded_from_cols_array() {
     let m = Mat3Padded::from_cols_array(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0]);
@@ -321,3 +349,666 @@ fn fps_counter_single_frame_returns_none() {
         "Single frame update should not report FPS"
     );
 }
+
+// ============================================================================
+// Procedural Noise Tests
+// ============================================================================
+
+#[test]
+fn noise_texture_is_deterministic_per_seed() {
+    for kind in [NoiseKind::Perlin, NoiseKind::Simplex] {
+        let params = NoiseParams {
+            kind,
+            seed: 42,
+            ..Default::default()
+        };
+        let a = procedural::noise_texture(32, 32, params);
+        let b = procedural::noise_texture(32, 32, params);
+        assert_eq!(a.data, b.data);
+
+        let other = procedural::noise_texture(32, 32, NoiseParams { seed: 43, ..params });
+        assert_ne!(a.data, other.data);
+    }
+}
+
+#[test]
+fn noise_is_periodic_and_in_range() {
+    for kind in [NoiseKind::Perlin, NoiseKind::Simplex] {
+        let params = NoiseParams {
+            kind,
+            seed: 7,
+            frequency: 3,
+            octaves: 3,
+            lacunarity: 2.5,
+            ..Default::default()
+        };
+        for i in 0..16 {
+            let u = i as f32 / 16.0 + 0.013;
+            let v = 0.37;
+            let value = procedural::fbm(u, v, params);
+            assert!(
+                (-1.0..=1.0).contains(&value),
+                "{kind:?} out of range: {value}"
+            );
+            assert!((value - procedural::fbm(u + 1.0, v, params)).abs() < 1e-3);
+            assert!((value - procedural::fbm(u, v - 1.0, params)).abs() < 1e-3);
+        }
+    }
+}
+
+#[test]
+fn noise_texture_formats() {
+    let params = NoiseParams::default();
+    let r8 = procedural::noise_texture(8, 4, params);
+    assert_eq!(r8.format, PixelFormat::R8Unorm);
+    assert_eq!(r8.data.as_ref().unwrap().len(), 8 * 4);
+    // Not a constant image.
+    let bytes = r8.data.as_ref().unwrap();
+    assert!(bytes.iter().any(|&b| b != bytes[0]));
+
+    let rgba = procedural::noise_texture(
+        8,
+        4,
+        NoiseParams {
+            format: PixelFormat::Rgba8Unorm,
+            ..params
+        },
+    );
+    let rgba_bytes = rgba.data.as_ref().unwrap();
+    assert_eq!(rgba_bytes.len(), 8 * 4 * 4);
+    for (texel, &grey) in rgba_bytes.chunks_exact(4).zip(bytes) {
+        assert_eq!(texel, &[grey, grey, grey, 255]);
+    }
+
+    let hdr = procedural::noise_texture(
+        8,
+        4,
+        NoiseParams {
+            format: PixelFormat::Rgba16Float,
+            ..params
+        },
+    );
+    assert_eq!(hdr.data.as_ref().unwrap().len(), 8 * 4 * 8);
+}
+
+// ============================================================================
+// Input Gamepad Tests
+// ============================================================================
+
+#[test]
+fn gamepad_buttons_follow_key_semantics() {
+    let mut input = Input::new();
+    let pad = GamepadId(0);
+    input.inject_gamepad_connected(pad, "Test Pad");
+    assert_eq!(input.gamepad(pad).unwrap().name(), "Test Pad");
+
+    input.inject_gamepad_button(pad, GamepadButton::South, ButtonState::Pressed);
+    let state = input.gamepad(pad).unwrap();
+    assert!(state.get_button(GamepadButton::South));
+    assert!(state.get_button_down(GamepadButton::South));
+
+    input.start_frame();
+    // Repeated presses while held don't re-trigger.
+    input.inject_gamepad_button(pad, GamepadButton::South, ButtonState::Pressed);
+    let state = input.gamepad(pad).unwrap();
+    assert!(state.get_button(GamepadButton::South));
+    assert!(!state.get_button_down(GamepadButton::South));
+
+    input.inject_gamepad_button(pad, GamepadButton::South, ButtonState::Released);
+    let state = input.gamepad(pad).unwrap();
+    assert!(!state.get_button(GamepadButton::South));
+    assert!(state.get_button_up(GamepadButton::South));
+
+    input.inject_gamepad_disconnected(pad);
+    assert!(input.gamepad(pad).is_none());
+}
+
+#[test]
+fn gamepad_left_stick_reads_first_connected_pad() {
+    let mut input = Input::new();
+    assert_eq!(input.gamepad_left_stick(), Vec2::ZERO);
+
+    input.inject_gamepad_connected(GamepadId(3), "Second");
+    input.inject_gamepad_connected(GamepadId(1), "First");
+    input.inject_gamepad_axis(GamepadId(1), GamepadAxis::LeftStickX, 0.5);
+    input.inject_gamepad_axis(GamepadId(1), GamepadAxis::LeftStickY, -0.25);
+    input.inject_gamepad_axis(GamepadId(3), GamepadAxis::LeftStickX, 1.0);
+
+    let (id, _) = input.first_gamepad().unwrap();
+    assert_eq!(id, GamepadId(1));
+    assert_eq!(input.gamepad_left_stick(), Vec2::new(0.5, -0.25));
+    assert_eq!(input.gamepads().count(), 2);
+
+    // Axes persist across frames until the next change.
+    input.start_frame();
+    assert_eq!(input.gamepad_left_stick(), Vec2::new(0.5, -0.25));
+    let pad = input.gamepad(GamepadId(3)).unwrap();
+    assert!(approx(pad.axis(GamepadAxis::RightTrigger), 0.0));
+}
+
+// ============================================================================
+// Material Normal Scale Tests
+// ============================================================================
+
+#[test]
+fn physical_normal_scale_setter_bumps_uniform_version() {
+    let material = PhysicalMaterial::new(Vec4::ONE);
+    assert_eq!(material.normal_scale(), Vec2::ONE);
+
+    let v0 = material.uniform_buffer().version;
+    material.set_normal_scale(Vec2::splat(0.5));
+    assert_eq!(material.normal_scale(), Vec2::splat(0.5));
+    let v1 = material.uniform_buffer().version;
+    assert!(v1 > v0);
+
+    // Writing the same value is not a change.
+    material.set_normal_scale(Vec2::splat(0.5));
+    assert_eq!(material.uniform_buffer().version, v1);
+}
+
+#[test]
+fn phong_normal_scale_builder_and_setter() {
+    let material = PhongMaterial::new(Vec4::ONE).with_normal_scale(Vec2::new(2.0, -2.0));
+    assert_eq!(material.normal_scale(), Vec2::new(2.0, -2.0));
+
+    let v0 = material.uniform_buffer().version;
+    material.set_normal_scale(Vec2::ONE);
+    assert!(material.uniform_buffer().version > v0);
+}
+
+#[test]
+fn uniforms_mut_coalesces_writes_without_touching_material_version() {
+    let material = PhysicalMaterial::new(Vec4::ONE);
+    let uniform_v0 = material.uniform_buffer().version;
+    let material_v0 = material.version();
+
+    {
+        let mut uniforms = material.uniforms_mut();
+        uniforms.emissive = Vec3::new(1.0, 0.5, 0.0);
+        uniforms.emissive_intensity = 4.0;
+        uniforms.roughness = 0.3;
+    }
+    assert_eq!(material.uniform_buffer().version, uniform_v0 + 1);
+    assert_eq!(material.version(), material_v0);
+
+    // A guard that leaves the values as they were is not a change.
+    material.uniforms_mut().roughness = 0.3;
+    assert_eq!(material.uniform_buffer().version, uniform_v0 + 1);
+}
+
+#[test]
+fn physical_triplanar_toggles_define_and_sets_scale() {
+    let material = PhysicalMaterial::new(Vec4::ONE);
+    assert!(!material.shader_defines().contains("USE_TRIPLANAR"));
+    assert!(approx(material.triplanar_scale(), 1.0));
+
+    let material = material.with_triplanar(0.25);
+    assert!(material.shader_defines().contains("USE_TRIPLANAR"));
+    assert!(approx(material.triplanar_scale(), 0.25));
+
+    material.disable_feature(PhysicalFeatures::TRIPLANAR);
+    assert!(!material.shader_defines().contains("USE_TRIPLANAR"));
+}
+
+#[test]
+fn physical_ao_map_sets_define_and_intensity() {
+    let material = PhysicalMaterial::new(Vec4::ONE);
+    assert!(!material.shader_defines().contains("HAS_AO_MAP"));
+    assert!(approx(material.ao_map_intensity(), 1.0));
+
+    let material = material.with_ao_map(TextureHandle::dummy_env_map());
+    assert!(material.shader_defines().contains("HAS_AO_MAP"));
+
+    let v0 = material.uniform_buffer().version;
+    material.set_ao_map_intensity(0.5);
+    assert!(approx(material.ao_map_intensity(), 0.5));
+    assert!(material.uniform_buffer().version > v0);
+}
+
+#[test]
+fn physical_parallax_sets_height_map_define_and_uniforms() {
+    let material = PhysicalMaterial::new(Vec4::ONE);
+    assert!(!material.shader_defines().contains("USE_PARALLAX"));
+    assert!(!material.shader_defines().contains("HAS_HEIGHT_MAP"));
+    assert!(approx(material.parallax_scale(), 0.05));
+    assert_eq!(material.parallax_steps(), 16);
+
+    let material = material.with_parallax(TextureHandle::dummy_env_map(), 0.08);
+    let defines = material.shader_defines();
+    assert!(defines.contains("USE_PARALLAX"));
+    assert!(defines.contains("HAS_HEIGHT_MAP"));
+    assert!(approx(material.parallax_scale(), 0.08));
+
+    let v0 = material.uniform_buffer().version;
+    material.set_parallax_steps(32);
+    assert_eq!(material.parallax_steps(), 32);
+    assert!(material.uniform_buffer().version > v0);
+
+    material.disable_feature(PhysicalFeatures::PARALLAX);
+    assert!(!material.shader_defines().contains("USE_PARALLAX"));
+}
+
+#[test]
+fn flat_shading_sets_define_and_bumps_version() {
+    let material = PhongMaterial::new(Vec4::ONE);
+    assert!(!material.flat_shading());
+    assert!(!material.shader_defines().contains("FLAT_SHADING"));
+
+    let v0 = material.version();
+    material.set_flat_shading(true);
+    assert!(material.flat_shading());
+    assert!(material.shader_defines().contains("FLAT_SHADING"));
+    let v1 = material.version();
+    assert!(v1 > v0, "pipelines must be re-keyed");
+
+    // Re-applying the same setting is not a change.
+    material.set_flat_shading(true);
+    assert_eq!(material.version(), v1);
+
+    let physical = PhysicalMaterial::new(Vec4::ONE).with_flat_shading(true);
+    assert!(physical.settings().flat_shading);
+}
+
+#[test]
+fn hashed_alpha_mode_sets_define_and_stays_opaque() {
+    let material: Material = PhysicalMaterial::new(Vec4::new(1.0, 1.0, 1.0, 0.5))
+        .with_alpha_mode(AlphaMode::Hashed)
+        .into();
+
+    assert_eq!(
+        material.shader_defines().get("ALPHA_MODE").as_deref(),
+        Some("HASHED")
+    );
+    // Hashed surfaces are depth-tested and drawn in the opaque pass.
+    assert!(!material.is_transparent());
+
+    let blended: Material = PhysicalMaterial::new(Vec4::ONE)
+        .with_alpha_mode(AlphaMode::Blend)
+        .into();
+    assert!(blended.is_transparent());
+}
+
+#[test]
+fn stencil_settings_presets_and_material_version() {
+    let mask = StencilSettings::write(1);
+    assert_eq!(mask.compare, wgpu::CompareFunction::Always);
+    assert_eq!(mask.pass_op, wgpu::StencilOperation::Replace);
+    assert_eq!(mask.write_mask, 0xFF);
+
+    // Tests leave the stencil buffer untouched.
+    let inside = StencilSettings::equal(1);
+    assert_eq!(inside.compare, wgpu::CompareFunction::Equal);
+    assert_eq!(inside.write_mask, 0);
+    assert_eq!(
+        StencilSettings::not_equal(1).compare,
+        wgpu::CompareFunction::NotEqual
+    );
+
+    let material = UnlitMaterial::new(Vec4::ONE);
+    assert_eq!(material.stencil(), None);
+
+    let v0 = material.version();
+    material.set_stencil(Some(inside));
+    assert_eq!(material.stencil(), Some(inside));
+    assert!(material.version() > v0, "pipelines must be re-keyed");
+
+    let material: Material = PhysicalMaterial::new(Vec4::ONE).with_stencil(mask).into();
+    assert_eq!(material.stencil(), Some(mask));
+}
+
+#[test]
+fn texture_lod_bias_changes_uniform_bytes() {
+    let bytes = |material: &PhysicalMaterial| {
+        let mut out = Vec::new();
+        material.with_uniform_bytes(&mut |b: &[u8]| out.extend_from_slice(b));
+        out
+    };
+
+    let material = PhysicalMaterial::new(Vec4::ONE);
+    assert!(approx(material.texture_lod_bias(), 0.0));
+    let before = bytes(&material);
+    let v0 = material.uniform_buffer().version;
+
+    material.set_texture_lod_bias(-1.5);
+    assert!(approx(material.texture_lod_bias(), -1.5));
+    assert_ne!(bytes(&material), before);
+    assert!(material.uniform_buffer().version > v0);
+
+    let phong = PhongMaterial::new(Vec4::ONE).with_texture_lod_bias(2.0);
+    assert!(approx(phong.texture_lod_bias(), 2.0));
+}
+
+#[test]
+fn forced_mip_level_sets_define_and_bumps_version() {
+    let material = PhongMaterial::new(Vec4::ONE);
+    assert!(!material.shader_defines().contains("FORCED_MIP_LEVEL"));
+
+    let v0 = material.version();
+    material.set_forced_mip_level(Some(3));
+    assert_eq!(material.forced_mip_level(), Some(3));
+    assert_eq!(
+        material.shader_defines().get("FORCED_MIP_LEVEL").as_deref(),
+        Some("3")
+    );
+    assert!(material.version() > v0, "pipelines must be re-keyed");
+
+    let physical = PhysicalMaterial::new(Vec4::ONE);
+    physical.set_forced_mip_level(Some(0));
+    assert!(physical.shader_defines().contains("FORCED_MIP_LEVEL"));
+    physical.set_forced_mip_level(None);
+    assert!(!physical.shader_defines().contains("FORCED_MIP_LEVEL"));
+}
+
+// ============================================================================
+// Bloom Prefilter Tests
+// ============================================================================
+
+#[test]
+fn bloom_threshold_and_soft_knee_setters() {
+    let mut bloom = BloomSettings::default();
+    assert!(approx(bloom.threshold(), 0.0));
+    assert!(approx(bloom.prefilter_uniforms.read().knee, 0.0));
+    let v0 = bloom.prefilter_uniforms.version();
+
+    // The knee is stored in absolute luminance units.
+    bloom.set_threshold(2.0);
+    assert!(bloom.prefilter_uniforms.version() > v0);
+    assert!(approx(bloom.threshold(), 2.0));
+    assert!(approx(bloom.prefilter_uniforms.read().knee, 1.0));
+
+    bloom.set_soft_knee(1.5);
+    assert!(approx(bloom.soft_knee(), 1.0));
+    assert!(approx(bloom.prefilter_uniforms.read().knee, 2.0));
+
+    bloom.set_threshold(-1.0);
+    assert!(approx(bloom.threshold(), 0.0));
+    assert!(approx(bloom.prefilter_uniforms.read().knee, 0.0));
+}
+
+#[test]
+fn bloom_tint_is_clamped_and_versioned() {
+    let mut bloom = BloomSettings::default();
+    assert_eq!(bloom.tint(), Vec3::ONE);
+
+    let v0 = bloom.prefilter_uniforms.version();
+    bloom.set_tint(Vec3::new(1.0, 0.5, -1.0));
+    assert!(bloom.prefilter_uniforms.version() > v0);
+    assert_eq!(bloom.tint(), Vec3::new(1.0, 0.5, 0.0));
+}
+
+#[test]
+fn bloom_dirt_defaults_off() {
+    let mut bloom = BloomSettings::default();
+    assert!(bloom.dirt_texture.is_none());
+    assert!(approx(bloom.dirt_intensity(), 0.0));
+
+    let v0 = bloom.composite_uniforms.version();
+    bloom.set_dirt_intensity(-1.0);
+    assert!(approx(bloom.dirt_intensity(), 0.0));
+    bloom.set_dirt_intensity(2.0);
+    assert!(bloom.composite_uniforms.version() > v0);
+    assert!(approx(bloom.dirt_intensity(), 2.0));
+}
+
+// ============================================================================
+// Sprite Material Tests
+// ============================================================================
+
+#[test]
+fn sprite_material_defaults_to_blended_world_size() {
+    let sprite = SpriteMaterial::default();
+    assert_eq!(sprite.alpha_mode(), AlphaMode::Blend);
+    assert!(!sprite.depth_write());
+    assert!(sprite.size_attenuation());
+    assert_eq!(sprite.center(), Vec2::splat(0.5));
+
+    let material: Material = sprite.into();
+    assert!(material.is_transparent());
+    assert!(material.has_custom_vertex_placement());
+    assert!(!material.renders_point_sprites());
+}
+
+#[test]
+fn sprite_material_pixel_size_disables_attenuation() {
+    let sprite = SpriteMaterial::from_color(Vec4::ONE)
+        .with_center(Vec2::new(0.5, 0.0))
+        .with_pixel_size(Vec2::new(48.0, 24.0));
+
+    assert!(!sprite.size_attenuation());
+    assert_eq!(sprite.pixel_size(), Vec2::new(48.0, 24.0));
+    assert_eq!(sprite.center(), Vec2::new(0.5, 0.0));
+
+    let v0 = sprite.uniforms.version();
+    sprite.set_size_attenuation(true);
+    assert!(sprite.size_attenuation());
+    assert!(sprite.uniforms.version() > v0);
+}
+
+// ============================================================================
+// Text Tests
+// ============================================================================
+
+/// Atlas with `A` (advance 0.6), `?` (advance 0.5) and an inkless space
+/// (advance 0.25); line height 1.25, ascent 1.0.
+fn test_font_atlas() -> FontAtlas {
+    let mut atlas = FontAtlas::new(4, 4, vec![0; 16], 1.25, 1.0, 0.1);
+    atlas.insert_glyph(
+        'A',
+        Glyph {
+            uv_min: Vec2::ZERO,
+            uv_max: Vec2::splat(0.5),
+            size: Vec2::new(0.5, 0.75),
+            offset: Vec2::new(0.05, 0.0),
+            advance: 0.6,
+        },
+    );
+    atlas.insert_glyph(
+        '?',
+        Glyph {
+            uv_min: Vec2::new(0.5, 0.0),
+            uv_max: Vec2::new(1.0, 0.5),
+            size: Vec2::new(0.4, 0.75),
+            offset: Vec2::ZERO,
+            advance: 0.5,
+        },
+    );
+    atlas.insert_glyph(
+        ' ',
+        Glyph {
+            uv_min: Vec2::ZERO,
+            uv_max: Vec2::ZERO,
+            size: Vec2::ZERO,
+            offset: Vec2::ZERO,
+            advance: 0.25,
+        },
+    );
+    atlas
+}
+
+fn text_positions(text: &TextMesh, atlas: &FontAtlas) -> Vec<Vec3> {
+    let geometry = text.build_geometry(atlas);
+    let positions = geometry.get_attribute("position").unwrap();
+    (0..positions.count)
+        .map(|i| positions.read_vec3(i).unwrap())
+        .collect()
+}
+
+#[test]
+fn text_mesh_measures_advances_and_lines() {
+    let atlas = test_font_atlas();
+
+    let size = TextMesh::new("A A").measure(&atlas);
+    assert!(approx(size.x, 1.45));
+    assert!(approx(size.y, 1.25));
+
+    let size = TextMesh::new("AA\nA")
+        .with_size(2.0)
+        .with_line_spacing(1.5)
+        .measure(&atlas);
+    assert!(approx(size.x, 2.4));
+    assert!(approx(size.y, 7.5));
+}
+
+#[test]
+fn text_mesh_builds_one_quad_per_visible_glyph() {
+    let atlas = test_font_atlas();
+    let geometry = TextMesh::new("A A").build_geometry(&atlas);
+
+    // The space has no ink and gets no quad.
+    assert_eq!(geometry.get_attribute("position").unwrap().count, 8);
+    assert_eq!(geometry.get_attribute("uv").unwrap().count, 8);
+    assert_eq!(geometry.index_attribute().unwrap().count, 12);
+}
+
+#[test]
+fn text_mesh_anchor_places_block_corner_at_origin() {
+    let atlas = test_font_atlas();
+    let text = TextMesh::new("A").with_anchor(Vec2::ZERO);
+    let positions = text_positions(&text, &atlas);
+
+    // Bottom-left anchor: the block spans [0, 0.6] x [0, 1.25] and the
+    // baseline sits one descent (line height - ascent) above the bottom.
+    assert!(positions[0].abs_diff_eq(Vec3::new(0.05, 0.25, 0.0), EPSILON));
+    assert!(positions[2].abs_diff_eq(Vec3::new(0.55, 1.0, 0.0), EPSILON));
+
+    // Centered (default): the same quad shifted by half the block.
+    let positions = text_positions(&TextMesh::new("A"), &atlas);
+    assert!(positions[0].abs_diff_eq(Vec3::new(-0.25, -0.375, 0.0), EPSILON));
+}
+
+#[test]
+fn text_mesh_aligns_lines_within_block() {
+    let atlas = test_font_atlas();
+    let first_x_of_second_line = |align| {
+        let text = TextMesh::new("AA\nA")
+            .with_align(align)
+            .with_anchor(Vec2::ZERO);
+        text_positions(&text, &atlas)[8].x
+    };
+
+    assert!(approx(first_x_of_second_line(TextAlign::Left), 0.05));
+    assert!(approx(first_x_of_second_line(TextAlign::Center), 0.35));
+    assert!(approx(first_x_of_second_line(TextAlign::Right), 0.65));
+}
+
+#[test]
+fn text_mesh_falls_back_to_question_mark() {
+    let atlas = test_font_atlas();
+
+    let size = TextMesh::new("Ω").measure(&atlas);
+    assert!(approx(size.x, 0.5));
+
+    let geometry = TextMesh::new("Ω").build_geometry(&atlas);
+    let uvs = geometry.get_attribute("uv").unwrap();
+    let top_left: Vec2 = uvs.read(3).unwrap();
+    assert!(top_left.abs_diff_eq(Vec2::new(0.5, 0.0), EPSILON));
+}
+
+#[test]
+fn text_mesh_without_ink_is_a_degenerate_quad() {
+    let atlas = test_font_atlas();
+    for text in ["", "   "] {
+        let positions = text_positions(&TextMesh::new(text), &atlas);
+        assert_eq!(positions.len(), 4);
+        assert!(positions.iter().all(|p| *p == Vec3::ZERO));
+    }
+}
+
+#[test]
+fn text_material_defaults_to_blended_world_size() {
+    let text = TextMaterial::new(TextureHandle::dummy_env_map());
+    assert_eq!(text.alpha_mode(), AlphaMode::Blend);
+    assert!(!text.depth_write());
+    assert!(text.size_attenuation());
+    assert!(text.map().is_some());
+    assert!(approx(text.outline_width(), 0.0));
+
+    let material: Material = text.into();
+    assert!(material.is_transparent());
+    assert!(material.has_custom_vertex_placement());
+    assert!(material.as_text().is_some());
+}
+
+#[test]
+fn text_material_screen_space_and_outline() {
+    let text = TextMaterial::new(TextureHandle::dummy_env_map())
+        .with_outline(Vec4::new(0.0, 0.0, 0.0, 1.0), 0.2)
+        .with_screen_space(32.0);
+
+    assert!(!text.size_attenuation());
+    assert!(approx(text.pixel_scale(), 32.0));
+    assert!(approx(text.outline_width(), 0.2));
+
+    let v0 = text.uniforms.version();
+    text.set_size_attenuation(true);
+    assert!(text.size_attenuation());
+    assert!(text.uniforms.version() > v0);
+}
+
+// ============================================================================
+// Auto Exposure Tests
+// ============================================================================
+
+/// Adapts from EV 0 for ten seconds at 60 fps and returns the exposure.
+fn adapted_exposure(settings: &AutoExposure, average_luminance: f32) -> f32 {
+    let target = settings.target_ev(average_luminance);
+    let ev = (0..600).fold(0.0, |ev, _| settings.adapt(ev, target, 1.0 / 60.0));
+    AutoExposure::exposure_for_ev(ev)
+}
+
+#[test]
+fn auto_exposure_bright_scene_converges_below_dark_scene() {
+    let settings = AutoExposure::default();
+    let bright = adapted_exposure(&settings, 8.0);
+    let dark = adapted_exposure(&settings, 0.02);
+    assert!(bright < dark);
+
+    // Both settle close to mapping their average luminance to middle grey.
+    assert!((bright * 8.0 - AutoExposure::MIDDLE_GREY).abs() < 0.02);
+    assert!((dark * 0.02 - AutoExposure::MIDDLE_GREY).abs() < 0.02);
+}
+
+#[test]
+fn auto_exposure_adapts_smoothly_and_clamps() {
+    let settings = AutoExposure::default();
+
+    // One frame moves only part of the way, and never past the target.
+    let step = settings.adapt(0.0, 4.0, 1.0 / 60.0);
+    assert!(step > 0.0 && step < 0.2);
+    assert!(settings.adapt(0.0, 4.0, 100.0) <= 4.0);
+    // Darkening uses the slower rate.
+    assert!(settings.adapt(0.0, -4.0, 1.0 / 60.0).abs() < step);
+
+    let mut tone_mapping = ToneMappingSettings::default();
+    tone_mapping.set_auto_exposure_range(-2.0, 2.0);
+    assert!(approx(tone_mapping.auto_exposure.target_ev(1000.0), 2.0));
+    assert!(approx(tone_mapping.auto_exposure.target_ev(0.0), -2.0));
+}
+
+#[test]
+fn auto_exposure_disabled_keeps_manual_exposure() {
+    let mut tone_mapping = ToneMappingSettings::default();
+    assert!(!tone_mapping.auto_exposure_enabled());
+    tone_mapping.set_exposure(2.0);
+
+    tone_mapping.set_auto_exposure(true);
+    assert!(tone_mapping.auto_exposure_enabled());
+    tone_mapping.set_auto_exposure(false);
+
+    let uniforms = tone_mapping.uniforms.read();
+    assert!(approx(uniforms.exposure, 2.0));
+    assert!(approx(uniforms.auto_exposure, 1.0));
+}
+
+#[test]
+fn vignette_and_film_grain_default_off() {
+    let mut tone_mapping = ToneMappingSettings::default();
+    assert!(approx(tone_mapping.vignette_intensity(), 0.0));
+    assert!(approx(tone_mapping.film_grain(), 0.0));
+    assert!(approx(tone_mapping.vignette_radius(), 1.0));
+
+    tone_mapping.set_vignette_radius(0.7);
+    assert!(approx(tone_mapping.vignette_radius(), 0.7));
+    tone_mapping.set_vignette_radius(0.0);
+    assert!(tone_mapping.vignette_radius() > 0.0);
+}
@@ -1,16 +1,57 @@
 //! Scene Integration Tests
 //!
 //! Tests for:
-//! - Scene: create/remove nodes, attach/detach hierarchy
+//! - Scene: create/remove nodes, attach/detach hierarchy, inherited visibility
+//! - World-space setters, world transform and world-preserving reparenting
+//! - set_parent: local-preserving moves and cycle / missing-node errors
 //! - Component management: set/get mesh, camera, light, morph weights
-//! - Node query: names, root_nodes, subtree collection
+//! - Morph weight truncation and normalization
+//! - Light units synced to the GPU light buffer and exposure, inverse-square irradiance
+//! - Per-node material overrides (tint / params)
+//! - Billboards: full / cylindrical facing, parent composition, children, node wrapper
+//! - Sized sprite spawning: quad geometry, scale and billboard
+//! - Particles: emission rate, recycling, gravity, fades, geometry sync
+//! - Decals: projector nodes, visibility, removal and subtree copies
+//! - Reflection probes: placement, capture requests, visibility, removal and copies
+//! - Light probes: grid placement, bake requests and the coefficient buffer
+//! - Level of detail: distance selection, hysteresis, cull distance
+//! - Node query: names, root_nodes, subtree collection, descendant and mesh iteration
 //! - SceneNode wrapper convenience API
+//! - Light and camera helpers drawn as debug lines, ground grid options, ground shadow
+//! - Environment rotation/intensity linkage with the background, background-derived ambient
+//! - Fog: depth falloff curves and uniform sync, background-derived fog color
+//! - Background blur: clamping and sync for backgrounds that show the environment
+//! - Scene descriptor round-trip (save/load)
 
-use glam::Vec3;
+use std::f32::consts::FRAC_PI_2;
+
+use glam::{Affine3A, Mat3, Mat4, Quat, UVec3, Vec2, Vec3, Vec4};
+use myth::Transform;
+use myth::assets::{AssetServer, SceneExt};
+use myth::errors::SceneError;
+use myth::resources::geometry::BoundingBox;
+use myth::resources::mesh::MAX_MORPH_TARGETS;
+use myth::resources::texture::TextureSource;
+use myth::resources::uniforms::{DynamicModelUniforms, Mat3Uniform};
+use myth::resources::{
+    Geometry, Image, Input, Mesh, PointsMaterial, SpriteMaterial, Texture, UnlitMaterial,
+};
 use myth::scene::Scene;
+use myth::scene::background::{BackgroundMode, ProceduralSkyParams};
 use myth::scene::camera::Camera;
-use myth::scene::light::{Light, LightKind};
-use myth::scene::node::Node;
+use myth::scene::light::{Light, LightKind, LightUnits};
+use myth::scene::node::{ALL_LAYERS, Node};
+use myth::scene::serde::SceneDescriptor;
+use myth::scene::{
+    BillboardMode, BindMode, DecalOptions, Fog, FogMode, GridOptions, LightProbeVolume, Lod,
+    MorphNormalization, NodeHandle, ParticleSystem, ReflectionProbe, Skeleton,
+};
+
+const EPSILON: f32 = 1e-5;
+
+fn approx(a: f32, b: f32) -> bool {
+    (a - b).abs() < EPSILON
+}
 
 fn new_scene() -> Scene {
     Scene::new()
@@ -77,6 +118,277 @@ fn scene_remove_node_removes_subtree() {
     assert!(scene.get_node(grandchild).is_none());
 }
 
+#[test]
+fn scene_hidden_parent_hides_descendants() {
+    let mut scene = new_scene();
+    let parent = scene.add_node(Node::new());
+    let child = scene.add_to_parent(Node::new(), parent);
+    let grandchild = scene.add_to_parent(Node::new(), child);
+    scene.set_light(grandchild, Light::new_point(Vec3::ONE, 1.0, 5.0));
+
+    let cached = |scene: &Scene, handle| scene.get_node(handle).unwrap().is_visible_in_hierarchy();
+
+    scene.node(&parent).set_visible(false);
+    // The walk sees the change right away; the cached flag after the update.
+    assert!(!scene.is_visible_in_hierarchy(grandchild));
+    scene.update(&Input::default(), 0.0);
+
+    for handle in [parent, child, grandchild] {
+        assert!(!cached(&scene, handle));
+    }
+    // The authored flags are left alone.
+    assert!(scene.get_node(child).unwrap().visible);
+    assert!(scene.get_node(grandchild).unwrap().visible);
+    assert_eq!(scene.iter_active_lights().count(), 0);
+
+    scene.node(&parent).set_visible(true);
+    scene.node(&child).set_visible(false);
+    scene.update(&Input::default(), 0.0);
+    assert!(cached(&scene, parent));
+    assert!(!cached(&scene, grandchild));
+
+    scene.node(&child).set_visible(true);
+    scene.update(&Input::default(), 0.0);
+    assert!(cached(&scene, grandchild));
+    assert!(scene.is_visible_in_hierarchy(grandchild));
+    assert_eq!(scene.iter_active_lights().count(), 1);
+}
+
+// ============================================================================
+// Name & Tag Queries
+// ============================================================================
+
+#[test]
+fn find_by_name_handles_duplicates_and_renames() {
+    let mut scene = new_scene();
+    let first = scene.create_node_with_name("Hand");
+    let second = scene.create_node_with_name("Hand");
+    let other = scene.create_node_with_name("Foot");
+
+    assert_eq!(scene.find_by_name("Hand"), Some(first));
+    assert_eq!(scene.find_all_by_name("Hand"), [first, second]);
+    assert_eq!(scene.find_by_name("Foot"), Some(other));
+    assert!(scene.find_all_by_name("Head").is_empty());
+
+    scene.set_name(first, "LeftHand");
+    assert_eq!(scene.find_all_by_name("Hand"), [second]);
+    assert_eq!(scene.find_by_name("LeftHand"), Some(first));
+}
+
+#[test]
+fn iter_descendants_is_depth_first_pre_order() {
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    let a = scene.add_to_parent(Node::new(), root);
+    let a1 = scene.add_to_parent(Node::new(), a);
+    let a2 = scene.add_to_parent(Node::new(), a);
+    let b = scene.add_to_parent(Node::new(), root);
+    let b1 = scene.add_to_parent(Node::new(), b);
+
+    let order: Vec<_> = scene.iter_descendants(root).collect();
+    assert_eq!(order, [a, a1, a2, b, b1]);
+    assert_eq!(scene.iter_descendants(a).collect::<Vec<_>>(), [a1, a2]);
+    assert_eq!(scene.iter_descendants(b1).count(), 0);
+
+    scene.remove_node(b);
+    assert_eq!(scene.iter_descendants(b).count(), 0);
+}
+
+#[test]
+fn iter_meshes_visits_every_mesh_node() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    let first = scene.add_mesh_to_parent(Mesh::new(geometry, material), root);
+    let second = scene.add_mesh_to_parent(Mesh::new(geometry, material), first);
+    scene.add_to_parent(Node::new(), root);
+
+    let mut meshes: Vec<_> = scene.iter_meshes().map(|(handle, _)| handle).collect();
+    meshes.sort();
+    let mut expected = vec![first, second];
+    expected.sort();
+    assert_eq!(meshes, expected);
+    assert!(
+        scene
+            .iter_meshes()
+            .all(|(_, mesh)| mesh.geometry == geometry)
+    );
+}
+
+#[test]
+fn tags_are_unique_per_node_and_queryable() {
+    let mut scene = new_scene();
+    let a = scene.create_node();
+    let b = scene.create_node();
+
+    assert!(scene.add_tag(a, "enemy"));
+    assert!(!scene.add_tag(a, "enemy"));
+    assert!(scene.add_tag(b, "enemy"));
+    assert!(scene.add_tag(b, "boss"));
+
+    assert_eq!(scene.find_by_tag("enemy"), [a, b]);
+    assert_eq!(scene.find_by_tag("boss"), [b]);
+    assert!(scene.has_tag(b, "boss"));
+    assert_eq!(scene.tags(a), ["enemy"]);
+
+    assert!(scene.remove_tag(a, "enemy"));
+    assert!(!scene.remove_tag(a, "enemy"));
+    assert_eq!(scene.find_by_tag("enemy"), [b]);
+    assert!(scene.tags(a).is_empty());
+}
+
+#[test]
+fn remove_node_clears_name_and_tag_indices() {
+    let mut scene = new_scene();
+    let parent = scene.add_node(Node::new());
+    scene.set_name(parent, "Rig");
+    let child = scene.create_node_with_name("Rig");
+    scene.attach(child, parent);
+    let keep = scene.create_node_with_name("Rig");
+    scene.add_tag(child, "bone");
+    scene.add_tag(keep, "bone");
+
+    scene.remove_node(parent);
+
+    assert_eq!(scene.find_all_by_name("Rig"), [keep]);
+    assert_eq!(scene.find_by_tag("bone"), [keep]);
+    assert!(!scene.add_tag(child, "bone"));
+}
+
+// ============================================================================
+// Subtree Cloning
+// ============================================================================
+
+#[test]
+fn clone_subtree_copies_two_levels_with_independent_transforms() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
+    let mut scene = new_scene();
+    let holder = scene.create_node_with_name("holder");
+    scene.push_root_node(holder);
+    let root = scene.create_node_with_name("crate");
+    scene.attach(root, holder);
+    let lid = scene.add_mesh_to_parent(Mesh::new(geometry, material), root);
+    scene.set_name(lid, "lid");
+    scene.add_tag(lid, "openable");
+    scene.node(&lid).set_position(0.0, 1.0, 0.0);
+    let lamp = scene.add_light_to_parent(Light::new_point(Vec3::ONE, 1.0, 5.0), lid);
+
+    let copy = scene.clone_subtree(root).unwrap();
+    assert_ne!(copy, root);
+    assert_eq!(scene.get_node(copy).unwrap().parent(), Some(holder));
+    assert_eq!(scene.get_node(holder).unwrap().children().len(), 2);
+    assert_eq!(scene.find_all_by_name("lid").len(), 2);
+    assert_eq!(scene.find_by_tag("openable").len(), 2);
+
+    let copy_lid = scene.get_node(copy).unwrap().children()[0];
+    assert_ne!(copy_lid, lid);
+    let copy_lamp = scene.get_node(copy_lid).unwrap().children()[0];
+    assert_eq!(scene.get_node(copy_lamp).unwrap().parent(), Some(copy_lid));
+    assert_ne!(
+        scene.get_light(copy_lamp).unwrap().id(),
+        scene.get_light(lamp).unwrap().id()
+    );
+
+    // Resources are shared, transforms are not.
+    let copy_mesh = scene.get_mesh(copy_lid).unwrap();
+    assert_eq!(copy_mesh.geometry, geometry);
+    assert_eq!(copy_mesh.material, material);
+    assert_eq!(
+        scene.get_node(copy_lid).unwrap().transform.position,
+        Vec3::Y
+    );
+    scene.node(&copy_lid).set_position(5.0, 0.0, 0.0);
+    assert_eq!(scene.get_node(lid).unwrap().transform.position, Vec3::Y);
+
+    // Removing the original leaves the copy intact.
+    scene.remove_node(root);
+    assert!(scene.get_node(copy_lamp).is_some());
+    assert_eq!(scene.find_by_name("lid"), Some(copy_lid));
+}
+
+#[test]
+fn clone_subtree_duplicates_contained_skeletons_and_shares_external_ones() {
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    let bone = scene.create_node();
+    let skinned = scene.create_node();
+    scene.attach(bone, root);
+    scene.attach(skinned, root);
+    let inner = scene.add_skeleton(Skeleton::new(
+        "inner",
+        vec![bone],
+        vec![Affine3A::IDENTITY],
+        0,
+    ));
+    scene.bind_skeleton(skinned, inner, BindMode::Attached);
+
+    let outside = scene.add_node(Node::new());
+    let external = scene.add_skeleton(Skeleton::new(
+        "external",
+        vec![outside],
+        vec![Affine3A::IDENTITY],
+        0,
+    ));
+    let borrower = scene.create_node();
+    scene.attach(borrower, root);
+    scene.bind_skeleton(borrower, external, BindMode::Attached);
+
+    let copy = scene.clone_subtree(root).unwrap();
+    let children = scene.get_node(copy).unwrap().children().to_vec();
+    let (copy_bone, copy_skinned, copy_borrower) = (children[0], children[1], children[2]);
+
+    let copy_inner = scene.skins.get(copy_skinned).unwrap().skeleton;
+    assert_ne!(copy_inner, inner);
+    assert_eq!(scene.skeleton_pool[copy_inner].bones, [copy_bone]);
+    assert_eq!(scene.skins.get(copy_borrower).unwrap().skeleton, external);
+}
+
+#[test]
+fn clone_subtree_with_materials_duplicates_each_material_once() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    scene.add_mesh_to_parent(Mesh::new(geometry, material), root);
+    scene.add_mesh_to_parent(Mesh::new(geometry, material), root);
+
+    let copy = scene.clone_subtree_with_materials(root, &assets).unwrap();
+    let children = scene.get_node(copy).unwrap().children().to_vec();
+    let first = scene.get_mesh(children[0]).unwrap().material;
+    let second = scene.get_mesh(children[1]).unwrap().material;
+    assert_ne!(first, material);
+    assert_eq!(first, second);
+    assert_eq!(scene.get_mesh(children[0]).unwrap().geometry, geometry);
+    assert!(assets.materials.get(first).is_some());
+}
+
+#[test]
+fn spawn_sprite_sized_scales_quad_and_faces_camera() {
+    let assets = AssetServer::new();
+    let mut scene = new_scene();
+    let sprite = scene.spawn_sprite_sized(
+        SpriteMaterial::from_color(Vec4::ONE),
+        Vec2::new(2.0, 0.5),
+        &assets,
+    );
+
+    let node = scene.get_node(sprite).unwrap();
+    assert_eq!(node.transform.scale, Vec3::new(2.0, 0.5, 1.0));
+    assert_eq!(scene.billboard(sprite), Some(BillboardMode::Full));
+    assert_eq!(
+        scene.get_mesh(sprite).unwrap().geometry,
+        assets.quad_geometry()
+    );
+}
+
 // ============================================================================
 // Hierarchy: Attach / Detach
 // ============================================================================
@@ -140,6 +452,243 @@ fn scene_add_to_parent() {
 // Component Management: Camera, Light
 // ============================================================================
 
+#[test]
+fn node_layers_default_to_all_and_toggle_per_bit() {
+    let mut node = Node::new();
+    assert_eq!(node.layers, ALL_LAYERS);
+    assert_eq!(
+        Camera::new_perspective(45.0, 1.0, 0.1).layer_mask,
+        ALL_LAYERS
+    );
+
+    node.set_layers(0);
+    node.enable_layer(3);
+    node.enable_layer(31);
+    assert_eq!(node.layers, (1 << 3) | (1 << 31));
+    assert!(node.is_in_layer(3));
+
+    node.disable_layer(3);
+    assert!(!node.is_in_layer(3));
+    assert_eq!(node.layers, 1 << 31);
+
+    // Out-of-range layers are ignored.
+    node.enable_layer(32);
+    node.disable_layer(40);
+    assert_eq!(node.layers, 1 << 31);
+    assert!(!node.is_in_layer(32));
+}
+
+#[test]
+fn debug_draw_accumulates_and_resets_on_drain() {
+    let mut scene = new_scene();
+    let bbox = BoundingBox {
+        min: Vec3::splat(-1.0),
+        max: Vec3::splat(1.0),
+    };
+    scene
+        .debug()
+        .line(Vec3::ZERO, Vec3::X, Vec4::ONE)
+        .aabb(&bbox, Vec4::ONE)
+        .aabb(&BoundingBox::infinite(), Vec4::ONE)
+        .sphere(Vec3::ZERO, 1.0, Vec4::ONE);
+
+    // 1 line + 12 box edges + 3 circles of 32 segments, two vertices each.
+    let expected = (1 + 12 + 3 * 32) * 2;
+    assert_eq!(scene.debug_draw().vertices().len(), expected);
+    assert!(scene.debug_draw().depth_test);
+
+    let mut out = Vec::new();
+    scene.debug().drain_into(&mut out);
+    assert_eq!(out.len(), expected);
+    assert_eq!(Vec3::from_array(out[1].position), Vec3::X);
+    assert!(scene.debug_draw().is_empty());
+}
+
+#[test]
+fn debug_draw_frustum_skips_infinite_far_plane() {
+    let mut scene = new_scene();
+    let camera = scene.add_camera(Camera::new_perspective(60.0, 1.0, 0.1));
+    scene.update(&Input::default(), 0.0);
+
+    // Infinite far plane: only the four near-plane edges are finite.
+    assert!(scene.debug_draw_frustum(camera, Vec4::ONE));
+    assert_eq!(scene.debug_draw().vertices().len(), 4 * 2);
+
+    scene.debug().clear();
+    scene.get_camera_mut(camera).unwrap().set_far(10.0);
+    assert!(scene.debug_draw_frustum(camera, Vec4::ONE));
+    assert_eq!(scene.debug_draw().vertices().len(), 12 * 2);
+
+    let empty = scene.create_node();
+    assert!(!scene.debug_draw_frustum(empty, Vec4::ONE));
+}
+
+#[test]
+fn camera_helper_draws_frustum_of_inactive_camera() {
+    let mut scene = new_scene();
+    let mut camera = Camera::new_perspective(60.0, 1.0, 0.1);
+    camera.set_far(10.0);
+    let shadow_cam = scene.add_camera(camera);
+    let main = scene.add_camera(Camera::new_perspective(60.0, 1.0, 0.1));
+    scene.active_camera = Some(main);
+    scene.update(&Input::default(), 0.0);
+
+    assert!(scene.show_camera_helper(shadow_cam, true));
+    assert!(scene.camera_helper_enabled(shadow_cam));
+    // The active camera's helper is skipped.
+    assert!(scene.show_camera_helper(main, true));
+    let empty = scene.create_node();
+    assert!(!scene.show_camera_helper(empty, true));
+
+    let mut out = Vec::new();
+    scene.take_debug_lines(&mut out);
+    assert_eq!(out.len(), 12 * 2);
+    let corners = scene.get_camera(shadow_cam).unwrap().frustum_corners();
+    assert!(out.iter().all(|v| {
+        let p = Vec3::from_array(v.position);
+        corners.iter().any(|c| c.abs_diff_eq(p, 1e-4))
+    }));
+
+    // Helpers persist across frames until disabled.
+    scene.take_debug_lines(&mut out);
+    assert_eq!(out.len(), 12 * 2);
+    assert!(scene.show_camera_helper(shadow_cam, false));
+    scene.take_debug_lines(&mut out);
+    assert!(out.is_empty());
+}
+
+#[test]
+fn ground_grid_persists_until_hidden() {
+    let mut scene = new_scene();
+    assert!(scene.grid().is_none());
+
+    let options = GridOptions {
+        spacing: 0.5,
+        major_every: 4,
+        ..GridOptions::default()
+    };
+    scene.show_grid(options);
+    assert_eq!(scene.grid(), Some(&options));
+
+    // The grid is drawn by its own pass, not as debug lines.
+    let mut out = Vec::new();
+    scene.take_debug_lines(&mut out);
+    assert!(out.is_empty());
+    assert_eq!(scene.grid(), Some(&options));
+
+    scene.hide_grid();
+    assert!(scene.grid().is_none());
+}
+
+#[test]
+fn ground_shadow_settings_and_falloff() {
+    let mut scene = new_scene();
+    assert!(scene.ground_shadow().is_none());
+
+    scene.set_ground_shadow(true, -1.0, 2.0);
+    let shadow = scene.ground_shadow_mut().unwrap();
+    assert!((shadow.plane_y + 1.0).abs() < 1e-6);
+    assert!((shadow.softness - 1.0).abs() < 1e-6);
+    shadow.opacity = 0.8;
+
+    // Re-enabling moves the plane but keeps the other settings.
+    scene.set_ground_shadow(true, 0.5, 0.25);
+    let shadow = *scene.ground_shadow().unwrap();
+    assert!((shadow.plane_y - 0.5).abs() < 1e-6);
+    assert!((shadow.opacity - 0.8).abs() < 1e-6);
+
+    // Darkest at the centre on the ground, gone outside the soft edge and
+    // once the models have risen past the fade distance.
+    assert!((shadow.strength(0.0, 0.0) - 0.8).abs() < 1e-6);
+    assert!(shadow.strength(1.0, 0.0) > 0.0 && shadow.strength(1.0, 0.0) < 0.8);
+    assert!(shadow.strength(1.3, 0.0).abs() < 1e-6);
+    assert!(shadow.strength(0.0, 1.0) < shadow.strength(0.0, 0.0));
+    assert!(shadow.strength(0.0, shadow.fade_distance).abs() < 1e-6);
+
+    scene.set_ground_shadow(false, 0.0, 0.0);
+    assert!(scene.ground_shadow().is_none());
+}
+
+#[test]
+fn debug_draw_skeleton_connects_bones_once_per_skeleton() {
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    let child = scene.create_node();
+    let tip = scene.create_node();
+    scene.attach(child, root);
+    scene.attach(tip, child);
+    scene.node(&child).set_position(0.0, 1.0, 0.0);
+    scene.node(&tip).set_position(0.0, 1.0, 0.0);
+
+    let skeleton = Skeleton::new(
+        "rig",
+        vec![root, child, tip],
+        vec![Affine3A::IDENTITY; 3],
+        0,
+    );
+    let key = scene.add_skeleton(skeleton);
+    let body = scene.create_node();
+    let armor = scene.create_node();
+    scene.bind_skeleton(body, key, BindMode::Attached);
+    scene.bind_skeleton(armor, key, BindMode::Attached);
+    scene.update(&Input::default(), 0.0);
+
+    assert!(scene.debug_draw_skeleton(body, Vec4::ONE));
+    assert!(scene.debug_draw_skeleton(armor, Vec4::ONE));
+    assert!(!scene.debug_draw_skeleton(root, Vec4::ONE));
+
+    let mut out = Vec::new();
+    scene.take_debug_lines(&mut out);
+    // Two bone segments (root has no parent bone), drawn once despite two skins.
+    assert_eq!(out.len(), 4);
+    assert_eq!(Vec3::from_array(out[2].position), Vec3::Y);
+    assert_eq!(Vec3::from_array(out[3].position), Vec3::new(0.0, 2.0, 0.0));
+    assert!(scene.debug_draw().is_empty());
+}
+
+#[test]
+fn light_helpers_follow_lights_in_their_color() {
+    let mut scene = new_scene();
+    let sun = scene.create_node();
+    scene.set_light(sun, Light::new_directional(Vec3::new(1.0, 0.5, 0.0), 1.0));
+    scene.node(&sun).set_position(0.0, 3.0, 0.0);
+    let lamp = scene.create_node();
+    scene.set_light(lamp, Light::new_point(Vec3::ONE, 1.0, 2.0));
+    let spot = scene.create_node();
+    scene.set_light(spot, Light::new_spot(Vec3::ONE, 1.0, 5.0, 0.2, 0.4));
+    let hidden = scene.create_node();
+    scene.set_light(hidden, Light::new_point(Vec3::ONE, 1.0, 1.0));
+    scene.node(&hidden).set_visible(false);
+    scene.update(&Input::default(), 0.0);
+
+    let mut out = Vec::new();
+    scene.take_debug_lines(&mut out);
+    assert!(out.is_empty());
+
+    scene.show_light_helpers(true);
+    assert!(scene.light_helpers_enabled());
+    scene.take_debug_lines(&mut out);
+    // Arrow (shaft + 4 head lines), sphere (3 circles), cone (circle + 4 sides).
+    assert_eq!(out.len(), (5 + 3 * 32 + 32 + 4) * 2);
+
+    // Lights are visited in arbitrary order; the arrow shaft is the first
+    // segment in the sun's color.
+    let first = out
+        .iter()
+        .position(|v| v.color == [1.0, 0.5, 0.0, 1.0])
+        .unwrap();
+    assert_eq!(
+        Vec3::from_array(out[first].position),
+        Vec3::new(0.0, 3.0, 0.0)
+    );
+    let tip = Vec3::from_array(out[first + 1].position);
+    assert!(tip.abs_diff_eq(Vec3::new(0.0, 3.0, -1.0), 1e-6));
+
+    // Helpers are regenerated every frame while enabled.
+    scene.take_debug_lines(&mut out);
+    assert_eq!(out.len(), (5 + 3 * 32 + 32 + 4) * 2);
+}
+
 #[test]
 fn scene_set_get_camera() {
     let mut scene = new_scene();
@@ -182,55 +731,858 @@ fn scene_set_morph_weights() {
     assert!((weights[0] - 0.5).abs() < 1e-5);
 }
 
+#[test]
+fn scene_set_morph_weight_writes_node_weights_or_mesh() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
+    let mut mesh = Mesh::new(geometry, material);
+    mesh.init_morph_targets(2, 24);
+    assert!(mesh.set_morph_weight(1, 0.25));
+    assert!(!mesh.set_morph_weight(2, 1.0));
+    mesh.morph_weights_mut()[0] = 0.75;
+    assert_eq!(mesh.morph_target_influences(), &[0.75, 0.25]);
+
+    let mut scene = new_scene();
+    let handle = scene.add_mesh(mesh);
+    assert!(scene.set_morph_weight(handle, 0, 0.5));
+    let mesh = scene.get_mesh(handle).unwrap();
+    assert_eq!(mesh.morph_target_influences(), &[0.5, 0.25]);
+
+    // Node-level weights override the mesh, so they are updated instead.
+    scene.set_morph_weights(handle, vec![0.0, 0.0]);
+    assert!(scene.set_morph_weight(handle, 1, 1.0));
+    assert_eq!(scene.get_morph_weights(handle).unwrap(), &vec![0.0, 1.0]);
+    scene.sync_morph_weights();
+    let mesh = scene.get_mesh(handle).unwrap();
+    assert_eq!(mesh.morph_target_influences(), &[0.0, 1.0]);
+
+    let empty = scene.create_node();
+    assert!(!scene.set_morph_weight(empty, 0, 1.0));
+}
+
+#[test]
+fn scene_set_morph_weights_truncates_past_max_targets() {
+    let mut scene = new_scene();
+    let handle = scene.create_node();
+    let weights: Vec<f32> = (0..MAX_MORPH_TARGETS + 8).map(|i| i as f32).collect();
+    scene.set_morph_weights(handle, weights.as_slice());
+
+    let stored = scene.get_morph_weights(handle).unwrap();
+    assert_eq!(stored.len(), MAX_MORPH_TARGETS);
+    assert_eq!(stored.as_slice(), &weights[..MAX_MORPH_TARGETS]);
+    assert!(!scene.set_morph_weight(handle, MAX_MORPH_TARGETS, 1.0));
+}
+
+#[test]
+fn morph_normalization_scales_overlapping_weights() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+    let mut mesh = Mesh::new(geometry, material);
+    mesh.init_morph_targets(3, 24);
+
+    let mut scene = new_scene();
+    let handle = scene.add_mesh(mesh);
+    scene.set_morph_weights(handle, &[0.8, 0.8, 0.4][..]);
+    scene.set_morph_normalization(handle, MorphNormalization::Normalize);
+    assert_eq!(
+        scene.morph_normalization(handle),
+        MorphNormalization::Normalize
+    );
+    scene.sync_morph_weights();
+    let mesh = scene.get_mesh(handle).unwrap();
+    assert_eq!(mesh.morph_target_influences(), &[0.4, 0.4, 0.2]);
+    // The stored weights are left as set.
+    assert_eq!(
+        scene.get_morph_weights(handle).unwrap(),
+        &vec![0.8, 0.8, 0.4]
+    );
+
+    // Weights that already sum to at most 1.0 pass through.
+    scene.set_morph_weights(handle, vec![0.5, 0.25, 0.0]);
+    scene.sync_morph_weights();
+    let mesh = scene.get_mesh(handle).unwrap();
+    assert_eq!(mesh.morph_target_influences(), &[0.5, 0.25, 0.0]);
+
+    scene.set_morph_normalization(handle, MorphNormalization::None);
+    scene.set_morph_weights(handle, vec![1.0, 1.0, 0.0]);
+    scene.sync_morph_weights();
+    let mesh = scene.get_mesh(handle).unwrap();
+    assert_eq!(mesh.morph_target_influences(), &[1.0, 1.0, 0.0]);
+}
+
 // ============================================================================
-// Hierarchy + Transform Dirty Propagation
+// Per-node Material Overrides
 // ============================================================================
 
+fn model_uniforms(scene: &Scene, handle: myth::scene::NodeHandle) -> DynamicModelUniforms {
+    let world = Mat4::from(scene.get_node(handle).unwrap().transform.world_matrix);
+    DynamicModelUniforms::new(world, world, scene.node_override(handle))
+}
+
 #[test]
-fn scene_attach_marks_child_dirty() {
+fn node_tints_on_shared_material_produce_different_uniforms() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
     let mut scene = new_scene();
-    let parent = scene.create_node();
-    let child = scene.create_node();
+    let red = scene.add_mesh(Mesh::new(geometry, material));
+    let blue = scene.add_mesh(Mesh::new(geometry, material));
+    assert_eq!(
+        bytemuck::bytes_of(&model_uniforms(&scene, red)),
+        bytemuck::bytes_of(&model_uniforms(&scene, blue))
+    );
 
-    // Consume dirty flag by calling update_local_matrix
+    scene.set_node_tint(red, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    scene.set_node_tint(blue, Vec4::new(0.0, 0.0, 1.0, 1.0));
+    assert_eq!(
+        scene.get_mesh(red).unwrap().material,
+        scene.get_mesh(blue).unwrap().material
+    );
+
+    let red_uniforms = model_uniforms(&scene, red);
+    let blue_uniforms = model_uniforms(&scene, blue);
+    assert_eq!(red_uniforms.object_tint, Vec4::new(1.0, 0.0, 0.0, 1.0));
+    assert_ne!(
+        bytemuck::bytes_of(&red_uniforms),
+        bytemuck::bytes_of(&blue_uniforms)
+    );
+}
+
+#[test]
+fn node_params_default_tint_and_cleanup() {
+    let mut scene = new_scene();
+    let node = scene.create_node();
+    scene.push_root_node(node);
+    assert!(scene.node_override(node).is_none());
+
+    scene.set_node_param(node, 2, 0.75);
+    let overrides = scene.node_override(node).unwrap();
+    assert_eq!(overrides.tint, Vec4::ONE);
+    assert!(approx(overrides.params[2], 0.75));
+    assert!(approx(model_uniforms(&scene, node).object_params.z, 0.75));
+
+    scene.clear_node_override(node);
+    assert!(scene.node_override(node).is_none());
+    assert_eq!(model_uniforms(&scene, node).object_tint, Vec4::ONE);
+
+    scene.set_node_tint(node, Vec4::splat(0.5));
+    scene.remove_node(node);
+    assert!(scene.node_override(node).is_none());
+}
+
+// ============================================================================
+// Billboards
+// ============================================================================
+
+fn scene_with_camera_at(position: Vec3) -> Scene {
+    let mut scene = new_scene();
+    let camera = scene.add_camera(Camera::new_perspective(45.0, 1.0, 0.1));
+    scene.node(&camera).set_position_vec(position);
+    scene.active_camera = Some(camera);
     scene
-        .get_node_mut(child)
+}
+
+fn world_forward(scene: &Scene, handle: myth::scene::NodeHandle) -> Vec3 {
+    scene
+        .get_node(handle)
         .unwrap()
-        .transform
-        .update_local_matrix();
+        .world_matrix()
+        .transform_vector3(Vec3::Z)
+        .normalize()
+}
 
-    scene.attach(child, parent);
+#[test]
+fn full_billboard_forward_points_at_camera() {
+    let camera_position = Vec3::new(4.0, 6.0, -3.0);
+    let mut scene = scene_with_camera_at(camera_position);
 
-    // attach should mark child transform as dirty, so update_local_matrix returns true
-    let child_node = scene.get_node_mut(child).unwrap();
-    assert!(
-        child_node.transform.update_local_matrix(),
-        "Attach should mark child transform dirty"
-    );
+    // A rotated, offset parent must not change where the billboard looks.
+    let parent = scene.add_node(Node::new());
+    scene
+        .node(&parent)
+        .set_position(1.0, 0.0, 2.0)
+        .rotate_y(1.2)
+        .rotate_x(0.4);
+    let sprite = scene.add_to_parent(Node::new(), parent);
+    scene.node(&sprite).set_position(0.5, 1.0, 0.0);
+    scene.set_billboard(sprite, BillboardMode::Full);
+    assert_eq!(scene.billboard(sprite), Some(BillboardMode::Full));
+
+    scene.update(&Input::default(), 0.0);
+
+    let world = *scene.get_node(sprite).unwrap().world_matrix();
+    let expected = (camera_position - Vec3::from(world.translation)).normalize();
+    assert!(world_forward(&scene, sprite).abs_diff_eq(expected, 1e-4));
+
+    // Position still follows the parent.
+    let parent_world = *scene.get_node(parent).unwrap().world_matrix();
+    let expected_position = parent_world.transform_point3(Vec3::new(0.5, 1.0, 0.0));
+    assert!(Vec3::from(world.translation).abs_diff_eq(expected_position, 1e-4));
+}
+
+#[test]
+fn cylindrical_billboard_stays_upright() {
+    let mut scene = scene_with_camera_at(Vec3::new(0.0, 10.0, 5.0));
+    let sprite = scene.add_node(Node::new());
+    scene.set_billboard(sprite, BillboardMode::Cylindrical);
+
+    scene.update(&Input::default(), 0.0);
+
+    let world = *scene.get_node(sprite).unwrap().world_matrix();
+    assert!(world.transform_vector3(Vec3::Y).abs_diff_eq(Vec3::Y, 1e-4));
+    assert!(world_forward(&scene, sprite).abs_diff_eq(Vec3::Z, 1e-4));
+}
+
+#[test]
+fn billboard_children_follow_and_clearing_restores_rotation() {
+    let mut scene = scene_with_camera_at(Vec3::new(-5.0, 0.0, 0.0));
+    let sprite = scene.add_node(Node::new());
+    let marker = scene.add_to_parent(Node::new(), sprite);
+    scene.node(&marker).set_position(0.0, 0.0, 1.0);
+    scene.node(&sprite).set_billboard(Some(BillboardMode::Full));
+    assert_eq!(scene.billboard(sprite), Some(BillboardMode::Full));
+
+    scene.update(&Input::default(), 0.0);
+    let marker_position = scene.get_node(marker).unwrap().world_matrix().translation;
+    assert!(Vec3::from(marker_position).abs_diff_eq(Vec3::new(-1.0, 0.0, 0.0), 1e-4));
+
+    scene.node(&sprite).set_billboard(None);
+    assert_eq!(scene.billboard(sprite), None);
+    scene.update(&Input::default(), 0.0);
+    assert!(world_forward(&scene, sprite).abs_diff_eq(Vec3::Z, 1e-4));
+    let marker_position = scene.get_node(marker).unwrap().world_matrix().translation;
+    assert!(Vec3::from(marker_position).abs_diff_eq(Vec3::Z, 1e-4));
 }
 
 // ============================================================================
-// Multiple Nodes & Iteration
+// Particles
 // ============================================================================
 
+fn rising_particles(capacity: usize) -> ParticleSystem {
+    ParticleSystem::new(capacity)
+        .with_rate(10.0)
+        .with_lifetime(0.5)
+        .with_velocity(Vec3::Y, Vec3::Y)
+        .with_gravity(Vec3::ZERO)
+}
+
 #[test]
-fn scene_iterate_active_lights() {
-    let mut scene = new_scene();
-    let h1 = scene.create_node();
-    scene.set_light(h1, Light::new_directional(Vec3::ONE, 1.0));
+fn particles_emit_at_rate_and_recycle_dead_slots() {
+    let mut system = rising_particles(64);
+    system.update(0.25);
+    assert_eq!(system.alive_count(), 2);
 
-    let h2 = scene.create_node();
-    scene.set_light(h2, Light::new_point(Vec3::ONE, 1.0, 10.0));
+    system.update(0.25);
+    assert_eq!(system.alive_count(), 5);
+    assert!(
+        system
+            .particles()
+            .iter()
+            .any(|p| approx(p.position.y, 0.25) && approx(p.age, 0.25))
+    );
 
-    // Make h2 invisible
-    scene.get_node_mut(h2).unwrap().visible = false;
+    // Steady state: rate × lifetime particles alive, slots reused.
+    for _ in 0..40 {
+        system.update(0.25);
+        assert!((4..=5).contains(&system.alive_count()));
+    }
 
-    let active_lights: Vec<_> = scene.iter_active_lights().collect();
-    assert_eq!(
-        active_lights.len(),
-        1,
-        "Only visible lights should be iterated"
-    );
+    // The pool never grows past its capacity.
+    let mut full = rising_particles(8).with_rate(1000.0);
+    full.update(0.1);
+    assert_eq!(full.alive_count(), 8);
+    assert_eq!(full.burst(4), 0);
+
+    system.emitting = false;
+    system.update(0.5);
+    assert_eq!(system.alive_count(), 0);
+}
+
+#[test]
+fn particles_fall_under_gravity_and_fade() {
+    let mut system = ParticleSystem::new(4)
+        .with_rate(0.0)
+        .with_lifetime(2.0)
+        .with_velocity(Vec3::ZERO, Vec3::ZERO)
+        .with_gravity(Vec3::new(0.0, -10.0, 0.0))
+        .with_colors(Vec4::ONE, Vec4::new(1.0, 0.0, 0.0, 0.0))
+        .with_sizes(1.0, 0.0);
+    assert_eq!(system.burst(1), 1);
+    system.update(0.5);
+
+    let particle = system.particles()[0];
+    assert!(particle.position.y < 0.0);
+    assert!(particle.velocity.y < 0.0);
+    assert!(
+        system
+            .color_at(1.0)
+            .abs_diff_eq(Vec4::new(1.0, 0.5, 0.5, 0.5), 1e-5)
+    );
+    assert!(approx(system.size_at(1.0), 0.5));
+    assert!(approx(system.size_at(10.0), 0.0));
+}
+
+#[test]
+fn spawned_particles_sync_into_their_geometry() {
+    let assets = AssetServer::new();
+    let mut scene = new_scene();
+    let node = scene.spawn_particles(
+        rising_particles(16),
+        PointsMaterial::new(Vec4::ONE).with_size_attenuation(true),
+        &assets,
+    );
+    let geometry = scene.get_mesh(node).unwrap().geometry;
+    let version_before = assets.geometries.get(geometry).unwrap().data_version();
+
+    scene.update(&Input::default(), 0.25);
+    scene.sync_particles(&assets);
+    assert_eq!(scene.particle_system(node).unwrap().alive_count(), 2);
+
+    let synced = assets.geometries.get(geometry).unwrap();
+    assert!(synced.data_version() > version_before);
+    let sizes = synced.get_attribute("size").unwrap();
+    assert_eq!(sizes.count, 16);
+    assert!(approx(sizes.read::<f32>(0).unwrap(), 1.0));
+    assert!(approx(sizes.read::<f32>(2).unwrap(), 0.0));
+
+    scene.remove_node(node);
+    assert!(scene.particle_system(node).is_none());
+}
+
+// ============================================================================
+// Decals
+// ============================================================================
+
+#[test]
+fn decal_nodes_follow_visibility_removal_and_clones() {
+    let assets = AssetServer::new();
+    let image = assets.images.add(Image::solid_color([255, 0, 0, 255]));
+    let texture = assets.textures.add(Texture::new_2d(Some("decal"), image));
+    let mut scene = new_scene();
+
+    let transform = Transform::builder()
+        .position(Vec3::new(0.0, 1.0, 0.0))
+        .scale(Vec3::new(2.0, 2.0, 0.5))
+        .build();
+    let options = DecalOptions::default()
+        .with_opacity(0.5)
+        .with_max_angle(FRAC_PI_2 / 2.0);
+    let decal = scene.add_decal(transform, texture, options);
+    scene.update(&Input::default(), 0.0);
+
+    let stored = scene.decal(decal).unwrap();
+    assert_eq!(stored.texture, texture);
+    assert!(approx(stored.options.color.w, 0.5));
+    let active: Vec<_> = scene.iter_active_decals().collect();
+    assert_eq!(active.len(), 1);
+    assert!(
+        active[0]
+            .1
+            .translation
+            .abs_diff_eq(Vec3::new(0.0, 1.0, 0.0).into(), EPSILON)
+    );
+
+    scene.node(&decal).set_visible(false);
+    scene.update(&Input::default(), 0.0);
+    assert_eq!(scene.iter_active_decals().count(), 0);
+    scene.node(&decal).set_visible(true);
+
+    let copy = scene.clone_subtree(decal).unwrap();
+    assert_eq!(scene.decal(copy), scene.decal(decal));
+    scene.decal_mut(copy).unwrap().options.color = Vec4::new(0.0, 1.0, 0.0, 1.0);
+    assert_eq!(
+        scene.decal(decal).unwrap().options.color,
+        Vec4::new(1.0, 1.0, 1.0, 0.5)
+    );
+
+    scene.remove_node(decal);
+    assert!(scene.decal(decal).is_none());
+    assert!(scene.decal(copy).is_some());
+}
+
+// ============================================================================
+// Reflection Probes
+// ============================================================================
+
+#[test]
+fn reflection_probes_track_captures_visibility_and_copies() {
+    let mut scene = new_scene();
+    let probe = scene.add_reflection_probe(Vec3::new(1.0, 2.0, 3.0), 128);
+    scene.update(&Input::default(), 0.0);
+
+    let stored = *scene.reflection_probe(probe).unwrap();
+    assert_eq!(stored, ReflectionProbe::new(128));
+    assert!(approx(stored.radius, 10.0));
+    let active: Vec<_> = scene.iter_active_reflection_probes().collect();
+    assert_eq!(active.len(), 1);
+    assert_eq!(active[0].0, probe);
+    assert!(active[0].2.abs_diff_eq(Vec3::new(1.0, 2.0, 3.0), EPSILON));
+
+    // Each capture request bumps the version the renderer compares against.
+    let version = stored.capture_version();
+    scene.reflection_probe_mut(probe).unwrap().capture();
+    assert_eq!(
+        scene.reflection_probe(probe).unwrap().capture_version(),
+        version + 1
+    );
+
+    scene.node(&probe).set_visible(false);
+    scene.update(&Input::default(), 0.0);
+    assert_eq!(scene.iter_active_reflection_probes().count(), 0);
+    scene.node(&probe).set_visible(true);
+
+    let copy = scene.clone_subtree(probe).unwrap();
+    assert_eq!(scene.reflection_probe(copy), scene.reflection_probe(probe));
+    scene.reflection_probe_mut(copy).unwrap().radius = 4.0;
+    assert!(approx(scene.reflection_probe(probe).unwrap().radius, 10.0));
+
+    scene.remove_node(probe);
+    assert!(scene.reflection_probe(probe).is_none());
+    assert!(scene.reflection_probe(copy).is_some());
+}
+
+// ============================================================================
+// Light Probes
+// ============================================================================
+
+#[test]
+fn light_probes_place_grid_and_track_bakes() {
+    let mut scene = new_scene();
+    assert!(scene.light_probes().is_none());
+
+    let bounds = BoundingBox {
+        min: Vec3::new(-2.0, 0.0, -4.0),
+        max: Vec3::new(2.0, 3.0, 4.0),
+    };
+    let volume = *scene.bake_light_probes(bounds, UVec3::new(3, 1, 2));
+    assert_eq!(volume.probe_count(), 6);
+    assert_eq!(scene.light_probe_storage().read().len(), 6);
+
+    // X varies fastest; an axis with a single probe centres it.
+    assert!(
+        volume
+            .probe_position(0)
+            .abs_diff_eq(Vec3::new(-2.0, 1.5, -4.0), EPSILON)
+    );
+    assert!(
+        volume
+            .probe_position(1)
+            .abs_diff_eq(Vec3::new(0.0, 1.5, -4.0), EPSILON)
+    );
+    assert!(
+        volume
+            .probe_position(5)
+            .abs_diff_eq(Vec3::new(2.0, 1.5, 4.0), EPSILON)
+    );
+
+    // Settings survive a new grid, which requests another bake.
+    scene.light_probes_mut().unwrap().intensity = 2.0;
+    let version = scene.light_probes().unwrap().bake_version();
+    scene.bake_light_probes(bounds, UVec3::ZERO);
+    let rebaked = *scene.light_probes().unwrap();
+    assert_eq!(rebaked.resolution(), UVec3::ONE);
+    assert!(rebaked.bake_version() > version);
+    assert!(approx(rebaked.intensity, 2.0));
+    assert_eq!(scene.light_probe_storage().read().len(), 1);
+    assert!(
+        rebaked
+            .probe_position(0)
+            .abs_diff_eq(Vec3::new(0.0, 1.5, 0.0), EPSILON)
+    );
+
+    scene.light_probes_mut().unwrap().bake();
+    assert!(scene.light_probes().unwrap().bake_version() > rebaked.bake_version());
+
+    scene.clear_light_probes();
+    assert!(scene.light_probes().is_none());
+    let fresh = LightProbeVolume::new(bounds, UVec3::splat(2));
+    assert!(approx(fresh.intensity, 1.0));
+}
+
+// ============================================================================
+// Level of Detail
+// ============================================================================
+
+fn lod_geometries(assets: &AssetServer) -> [myth::GeometryHandle; 3] {
+    [
+        assets.geometries.add(Geometry::new_sphere(1.0)),
+        assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0)),
+        assets.geometries.add(Geometry::new_plane(1.0, 1.0)),
+    ]
+}
+
+#[test]
+fn lod_selects_level_by_distance_with_hysteresis() {
+    let assets = AssetServer::new();
+    let [near, mid, far] = lod_geometries(&assets);
+    // Unsorted input is sorted by distance.
+    let mut lod = Lod::new([(far, 150.0), (near, 0.0), (mid, 50.0)]).with_cull_distance(300.0);
+    assert_eq!(lod.levels()[1].geometry, mid);
+
+    assert_eq!(lod.select(100.0 * 100.0), Some(1));
+    assert_eq!(lod.current_geometry(), Some(mid));
+
+    // The default 10% band delays switching in both directions.
+    assert_eq!(lod.select(160.0 * 160.0), Some(1));
+    assert_eq!(lod.select(170.0 * 170.0), Some(2));
+    assert_eq!(lod.select(140.0 * 140.0), Some(2));
+    assert_eq!(lod.select(130.0 * 130.0), Some(1));
+
+    // Jumps across several levels at once.
+    assert_eq!(lod.select(1.0), Some(0));
+    assert_eq!(lod.select(400.0 * 400.0), None);
+    assert_eq!(lod.current_geometry(), None);
+    assert_eq!(lod.select(100.0 * 100.0), Some(1));
+
+    let mut sharp = Lod::new([(near, 0.0), (mid, 50.0)]).with_hysteresis(0.0);
+    assert_eq!(sharp.select(50.0 * 50.0), Some(1));
+    assert_eq!(sharp.select(49.9 * 49.9), Some(0));
+}
+
+#[test]
+fn scene_lod_drives_mesh_geometry() {
+    let assets = AssetServer::new();
+    let [near, mid, far] = lod_geometries(&assets);
+    let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+
+    let mut scene = new_scene();
+    let node = scene.add_mesh(Mesh::new(far, material));
+    scene.set_lod(node, vec![(near, 0.0), (mid, 50.0), (far, 150.0)]);
+    assert_eq!(scene.get_mesh(node).unwrap().geometry, near);
+
+    scene.node(&node).set_position(0.0, 0.0, -100.0);
+    scene.update_matrix_world();
+    scene.update_lods(glam::Vec3A::ZERO);
+    assert_eq!(scene.lod(node).unwrap().current_level(), Some(1));
+    assert_eq!(scene.get_mesh(node).unwrap().geometry, mid);
+    assert_eq!(scene.get_mesh(node).unwrap().material, material);
+
+    scene.lod_mut(node).unwrap().cull_distance = Some(80.0);
+    scene.update_lods(glam::Vec3A::ZERO);
+    assert_eq!(scene.lod(node).unwrap().current_level(), None);
+
+    scene.remove_node(node);
+    assert!(scene.lod(node).is_none());
+}
+
+// ============================================================================
+// Hierarchy + Transform Dirty Propagation
+// ============================================================================
+
+#[test]
+fn scene_attach_marks_child_dirty() {
+    let mut scene = new_scene();
+    let parent = scene.create_node();
+    let child = scene.create_node();
+
+    // Consume dirty flag by calling update_local_matrix
+    scene
+        .get_node_mut(child)
+        .unwrap()
+        .transform
+        .update_local_matrix();
+
+    scene.attach(child, parent);
+
+    // attach should mark child transform as dirty, so update_local_matrix returns true
+    let child_node = scene.get_node_mut(child).unwrap();
+    assert!(
+        child_node.transform.update_local_matrix(),
+        "Attach should mark child transform dirty"
+    );
+}
+
+fn assert_affine_approx(a: Affine3A, b: Affine3A) {
+    assert!(
+        a.abs_diff_eq(b, 1e-4),
+        "world matrices differ:\n{a:?}\n{b:?}"
+    );
+}
+
+#[test]
+fn scene_reparent_preserves_world_transform() {
+    let mut scene = new_scene();
+    let old_parent = scene.add_node(Node::new());
+    scene
+        .node(&old_parent)
+        .set_position(1.0, 2.0, 3.0)
+        .rotate_y(0.7);
+    let new_parent = scene.add_node(Node::new());
+    scene
+        .node(&new_parent)
+        .set_position(-4.0, 0.5, 2.0)
+        .rotate_x(-1.1)
+        .set_scale(2.0);
+    let child = scene.add_to_parent(Node::new(), old_parent);
+    scene
+        .node(&child)
+        .set_position(0.5, -1.0, 0.0)
+        .rotate_y(0.3);
+    scene.update_matrix_world();
+    let before = *scene.get_node(child).unwrap().world_matrix();
+
+    assert!(scene.reparent(child, Some(new_parent)));
+    assert_eq!(scene.get_node(child).unwrap().parent(), Some(new_parent));
+    assert!(
+        !scene
+            .get_node(old_parent)
+            .unwrap()
+            .children()
+            .contains(&child)
+    );
+    assert!(
+        scene
+            .get_node(new_parent)
+            .unwrap()
+            .children()
+            .contains(&child)
+    );
+    assert_affine_approx(scene.compute_world_matrix(child).unwrap(), before);
+
+    // The batch update must pick up the new local transform.
+    scene.update_matrix_world();
+    assert_affine_approx(*scene.get_node(child).unwrap().world_matrix(), before);
+}
+
+#[test]
+fn scene_reparent_to_root() {
+    let mut scene = new_scene();
+    let parent = scene.add_node(Node::new());
+    scene
+        .node(&parent)
+        .set_position(3.0, 0.0, 0.0)
+        .rotate_y(FRAC_PI_2);
+    let child = scene.add_to_parent(Node::new(), parent);
+    scene.node(&child).set_position(0.0, 0.0, 1.0);
+    scene.update_matrix_world();
+    let before = *scene.get_node(child).unwrap().world_matrix();
+
+    assert!(scene.reparent(child, None));
+    assert_eq!(scene.get_node(child).unwrap().parent(), None);
+    assert!(scene.root_nodes().contains(&child));
+    assert!(scene.get_node(parent).unwrap().children().is_empty());
+
+    scene.update_matrix_world();
+    assert_affine_approx(*scene.get_node(child).unwrap().world_matrix(), before);
+}
+
+#[test]
+fn scene_reparent_rejects_cycles() {
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    let child = scene.add_to_parent(Node::new(), root);
+    let grandchild = scene.add_to_parent(Node::new(), child);
+
+    assert!(!scene.reparent(root, Some(grandchild)));
+    assert!(!scene.reparent(child, Some(child)));
+    assert_eq!(scene.get_node(root).unwrap().parent(), None);
+    assert_eq!(scene.get_node(grandchild).unwrap().parent(), Some(child));
+}
+
+#[test]
+fn scene_set_parent_keeps_local_transform() {
+    let mut scene = new_scene();
+    let old_parent = scene.add_node(Node::new());
+    let new_parent = scene.add_node(Node::new());
+    scene.node(&new_parent).set_position(5.0, 0.0, 0.0);
+    let child = scene.add_to_parent(Node::new(), old_parent);
+    scene.node(&child).set_position(0.0, 1.0, 0.0);
+
+    scene.set_parent(child, Some(new_parent), false).unwrap();
+
+    assert_eq!(scene.get_node(child).unwrap().parent(), Some(new_parent));
+    assert!(scene.get_node(old_parent).unwrap().children().is_empty());
+    assert_eq!(scene.get_node(new_parent).unwrap().children(), &[child]);
+    assert_eq!(
+        scene.get_node(child).unwrap().transform.position,
+        Vec3::new(0.0, 1.0, 0.0)
+    );
+
+    // The node now follows its new parent.
+    scene.update_matrix_world();
+    let world = scene.get_node(child).unwrap().world_matrix();
+    assert!(Vec3::from(world.translation).abs_diff_eq(Vec3::new(5.0, 1.0, 0.0), EPSILON));
+}
+
+#[test]
+fn scene_set_parent_errors() {
+    let mut scene = new_scene();
+    let root = scene.add_node(Node::new());
+    let child = scene.add_to_parent(Node::new(), root);
+    let grandchild = scene.add_to_parent(Node::new(), child);
+    let removed = scene.add_node(Node::new());
+    scene.remove_node(removed);
+
+    assert_eq!(
+        scene.set_parent(root, Some(grandchild), true),
+        Err(SceneError::HierarchyCycle)
+    );
+    assert_eq!(
+        scene.set_parent(child, Some(removed), false),
+        Err(SceneError::NodeNotFound)
+    );
+    assert_eq!(
+        scene.set_parent(removed, None, false),
+        Err(SceneError::NodeNotFound)
+    );
+    assert_eq!(scene.get_node(root).unwrap().parent(), None);
+    assert_eq!(scene.get_node(child).unwrap().parent(), Some(root));
+
+    // Detaching to the root is always allowed.
+    scene.set_parent(grandchild, None, true).unwrap();
+    assert_eq!(scene.get_node(grandchild).unwrap().parent(), None);
+    assert!(scene.root_nodes().contains(&grandchild));
+}
+
+#[test]
+fn scene_set_world_position_and_rotation() {
+    let mut scene = new_scene();
+    let parent = scene.add_node(Node::new());
+    scene
+        .node(&parent)
+        .set_position(1.0, 2.0, 3.0)
+        .rotate_y(0.9)
+        .set_scale(0.5);
+    let child = scene.add_to_parent(Node::new(), parent);
+    scene.update_matrix_world();
+
+    let target_rotation = Quat::from_rotation_x(0.4);
+    scene.set_world_position(child, Vec3::new(-2.0, 5.0, 1.0));
+    scene.set_world_rotation(child, target_rotation);
+    scene.update_matrix_world();
+
+    let (_, rotation, translation) = scene
+        .get_node(child)
+        .unwrap()
+        .world_matrix()
+        .to_scale_rotation_translation();
+    assert!(translation.abs_diff_eq(Vec3::new(-2.0, 5.0, 1.0), 1e-4));
+    assert!(rotation.abs_diff_eq(target_rotation, 1e-4));
+}
+
+#[test]
+fn scene_set_world_transform_moves_subtree() {
+    let mut scene = new_scene();
+    let parent = scene.add_node(Node::new());
+    scene
+        .node(&parent)
+        .set_position(1.0, -2.0, 0.5)
+        .rotate_y(0.6)
+        .set_scale(2.0);
+    let child = scene.add_to_parent(Node::new(), parent);
+    let grandchild = scene.add_to_parent(Node::new(), child);
+    scene.node(&grandchild).set_position(0.0, 1.0, 0.0);
+    scene.update_matrix_world();
+
+    let target = Affine3A::from_scale_rotation_translation(
+        Vec3::splat(0.5),
+        Quat::from_rotation_z(0.8),
+        Vec3::new(4.0, 3.0, -1.0),
+    );
+    scene.set_world_transform(child, target);
+    assert_affine_approx(scene.world_transform(child).unwrap(), target);
+
+    // The batched update propagates the change to the descendants.
+    scene.update_matrix_world();
+    assert_affine_approx(*scene.get_node(child).unwrap().world_matrix(), target);
+    assert_affine_approx(
+        *scene.get_node(grandchild).unwrap().world_matrix(),
+        target * Affine3A::from_translation(Vec3::Y),
+    );
+
+    // Root nodes take the world transform as their local transform.
+    scene.set_world_transform(parent, target);
+    let transform = &scene.get_node(parent).unwrap().transform;
+    assert!(
+        transform
+            .position
+            .abs_diff_eq(Vec3::new(4.0, 3.0, -1.0), 1e-4)
+    );
+    assert!(transform.scale.abs_diff_eq(Vec3::splat(0.5), 1e-4));
+    assert!(scene.world_transform(NodeHandle::default()).is_none());
+}
+
+// ============================================================================
+// Multiple Nodes & Iteration
+// ============================================================================
+
+#[test]
+fn scene_iterate_active_lights() {
+    let mut scene = new_scene();
+    let h1 = scene.create_node();
+    scene.set_light(h1, Light::new_directional(Vec3::ONE, 1.0));
+
+    let h2 = scene.create_node();
+    scene.set_light(h2, Light::new_point(Vec3::ONE, 1.0, 10.0));
+
+    // Make h2 invisible
+    scene.get_node_mut(h2).unwrap().visible = false;
+
+    let active_lights: Vec<_> = scene.iter_active_lights().collect();
+    assert_eq!(
+        active_lights.len(),
+        1,
+        "Only visible lights should be iterated"
+    );
+}
+
+#[test]
+fn physical_light_units_reach_gpu_buffers() {
+    let mut scene = new_scene();
+    scene.add_light(Light::new_directional(Vec3::ONE, 1.0));
+    scene.add_light(Light::new_point(Vec3::ONE, 800.0, 0.0).with_units(LightUnits::PhysicalUnits));
+    scene.update_matrix_world();
+
+    // f/16, 1/125 s, ISO 100 is the "sunny 16" exposure, EV100 ≈ 15.
+    scene
+        .tone_mapping
+        .set_camera_exposure(16.0, 1.0 / 125.0, 100.0);
+    assert!((scene.tone_mapping.ev100 - 14.97).abs() < 0.01);
+    scene.tone_mapping.set_ev100(0.0);
+    scene.sync_gpu_buffers();
+
+    let mut units: Vec<u32> = scene
+        .light_storage()
+        .read()
+        .iter()
+        .map(|l| l.units)
+        .collect();
+    units.sort_unstable();
+    assert_eq!(units, [0, 1]);
+    let scale = scene.environment_uniforms().read().physical_light_scale;
+    assert!(approx(scale, 1.0 / 1.2));
+}
+
+#[test]
+fn physical_point_light_follows_inverse_square_law() {
+    let mut light = Light::new_point(Vec3::ONE, 1.0, 0.0);
+    assert_eq!(light.units, LightUnits::Legacy);
+    light.set_intensity_candela(400.0);
+    assert_eq!(light.units, LightUnits::PhysicalUnits);
+
+    // candela / d² = lux
+    assert!(approx(light.irradiance_at(1.0), 400.0));
+    assert!(approx(light.irradiance_at(2.0), 100.0));
+    assert!(approx(light.irradiance_at(10.0), 4.0));
+    for d in [0.5, 1.5, 3.0, 7.0] {
+        let ratio = light.irradiance_at(d) / light.irradiance_at(2.0 * d);
+        assert!((ratio - 4.0).abs() < 1e-3);
+    }
+
+    // Legacy lights keep their intensity; directional lights ignore distance.
+    let legacy = Light::new_point(Vec3::ONE, 3.0, 0.0);
+    assert!(approx(legacy.irradiance_at(5.0), 3.0));
+    let mut sun = Light::new_directional(Vec3::ONE, 1.0);
+    sun.set_intensity_lux(100_000.0);
+    assert!(approx(sun.irradiance_at(1000.0), 100_000.0));
+
+    // 4π lumens from a point light is one candela.
+    light.set_intensity_lumens(4.0 * std::f32::consts::PI);
+    assert!(approx(light.intensity, 1.0));
 }
 
 #[test]
@@ -239,3 +1591,469 @@ fn scene_unique_ids() {
     let s2 = new_scene();
     assert_ne!(s1.id(), s2.id(), "Each scene should have a unique ID");
 }
+
+// ============================================================================
+// Environment Rotation & Intensity
+// ============================================================================
+
+fn env_source() -> TextureSource {
+    TextureSource::Attachment(1, wgpu::TextureViewDimension::D2)
+}
+
+fn to_mat3(m: Mat3Uniform) -> Mat3 {
+    Mat3::from_cols(m.col0.truncate(), m.col1.truncate(), m.col2.truncate())
+}
+
+#[test]
+fn environment_rotation_and_intensity_getters() {
+    let mut scene = new_scene();
+    scene.environment.set_rotation_y(0.75);
+    scene.environment.set_intensity(2.5);
+    assert!(
+        scene
+            .environment
+            .rotation()
+            .abs_diff_eq(Quat::from_rotation_y(0.75), 1e-6)
+    );
+    assert!(approx(scene.environment.intensity(), 2.5));
+}
+
+#[test]
+fn environment_zero_rotation_matches_unrotated_background() {
+    let mut scene = new_scene();
+    scene.environment.set_env_map(Some(env_source()));
+    scene
+        .background
+        .set_mode(BackgroundMode::equirectangular(env_source(), 1.0));
+    scene.background.set_rotation(0.3);
+
+    scene.update(&Input::default(), 0.0);
+
+    assert_eq!(
+        scene.environment_uniforms().read().env_map_rotation,
+        Mat3Uniform::IDENTITY
+    );
+    let params = *scene.background.uniforms.read();
+    assert!(approx(params.rotation, 0.3));
+    assert_eq!(params.env_rotation, Mat3Uniform::IDENTITY);
+    assert!(approx(params.intensity, 1.0));
+}
+
+#[test]
+fn environment_rotation_and_intensity_apply_to_linked_background() {
+    let mut scene = new_scene();
+    scene.environment.set_env_map(Some(env_source()));
+    scene
+        .environment
+        .set_rotation(Quat::from_rotation_x(0.4) * Quat::from_rotation_y(FRAC_PI_2));
+    scene.environment.set_intensity(2.0);
+    scene
+        .background
+        .set_mode(BackgroundMode::equirectangular(env_source(), 1.5));
+    scene.background.set_rotation(0.3);
+
+    scene.update(&Input::default(), 0.0);
+
+    let sampling = scene.environment.sampling_matrix();
+    let env = *scene.environment_uniforms().read();
+    assert!(to_mat3(env.env_map_rotation).abs_diff_eq(sampling, EPSILON));
+    assert!(approx(env.env_map_intensity, 2.0));
+
+    let params = *scene.background.uniforms.read();
+    assert!(to_mat3(params.env_rotation).abs_diff_eq(sampling, EPSILON));
+    assert!(approx(params.rotation, 0.3));
+    assert!(approx(params.intensity, 3.0));
+}
+
+#[test]
+fn gradient_background_drives_ambient_when_enabled() {
+    let mut scene = new_scene();
+    scene.environment.set_ambient_light(Vec3::splat(0.05));
+    scene
+        .background
+        .set_gradient(Vec4::new(0.2, 0.4, 0.8, 1.0), Vec4::new(0.6, 0.4, 0.0, 1.0));
+
+    // Disabled by default: the configured ambient is kept.
+    scene.update(&Input::default(), 0.0);
+    let ambient = scene.environment_uniforms().read().ambient_light;
+    assert!(ambient.abs_diff_eq(Vec3::splat(0.05), EPSILON));
+
+    scene.environment.set_ambient_from_background(true);
+    scene.update(&Input::default(), 0.0);
+    let ambient = scene.environment_uniforms().read().ambient_light;
+    assert!(ambient.abs_diff_eq(Vec3::new(0.4, 0.4, 0.4), EPSILON));
+
+    // Solid colors follow too; texture and procedural modes fall back.
+    scene.background.set_color(Vec4::new(0.1, 0.2, 0.3, 1.0));
+    scene.update(&Input::default(), 0.0);
+    let ambient = scene.environment_uniforms().read().ambient_light;
+    assert!(ambient.abs_diff_eq(Vec3::new(0.1, 0.2, 0.3), EPSILON));
+
+    scene.background.set_mode(BackgroundMode::procedural());
+    scene.update(&Input::default(), 0.0);
+    let ambient = scene.environment_uniforms().read().ambient_light;
+    assert!(ambient.abs_diff_eq(Vec3::splat(0.05), EPSILON));
+}
+
+#[test]
+fn fog_factor_curves() {
+    let linear = FogMode::Linear {
+        near: 10.0,
+        far: 20.0,
+    };
+    assert!(approx(linear.factor(5.0), 0.0));
+    assert!(approx(linear.factor(15.0), 0.5));
+    assert!(approx(linear.factor(25.0), 1.0));
+
+    let exp = FogMode::Exponential { density: 0.1 };
+    assert!(approx(exp.factor(0.0), 0.0));
+    assert!(approx(exp.factor(10.0), 1.0 - (-1.0f32).exp()));
+
+    // Squared falls off slower near the camera, then catches up.
+    let exp2 = FogMode::ExponentialSquared { density: 0.1 };
+    assert!(exp2.factor(5.0) < exp.factor(5.0));
+    assert!(approx(exp2.factor(10.0), exp.factor(10.0)));
+    assert!(exp2.factor(20.0) > exp.factor(20.0));
+}
+
+#[test]
+fn fog_syncs_environment_uniforms() {
+    let mut scene = new_scene();
+    scene.update(&Input::default(), 0.0);
+    assert!(scene.environment.fog().is_none());
+
+    scene
+        .environment
+        .set_fog(Some(Fog::linear(Vec3::new(0.5, 0.6, 0.7), 10.0, 50.0)));
+    scene.update(&Input::default(), 0.0);
+    {
+        let uniforms = scene.environment_uniforms().read();
+        assert_eq!(uniforms.fog_mode, 0);
+        assert_eq!(uniforms.fog_color_source, 0);
+        assert!(approx(uniforms.fog_near, 10.0));
+        assert!(approx(uniforms.fog_far, 50.0));
+        assert!(
+            uniforms
+                .fog_color
+                .abs_diff_eq(Vec3::new(0.5, 0.6, 0.7), EPSILON)
+        );
+    }
+
+    scene
+        .environment
+        .set_fog(Some(Fog::exponential_squared(Vec3::ONE, 0.02)));
+    scene.update(&Input::default(), 0.0);
+    {
+        let uniforms = scene.environment_uniforms().read();
+        assert_eq!(uniforms.fog_mode, 2);
+        assert!(approx(uniforms.fog_density, 0.02));
+    }
+}
+
+#[test]
+fn fog_takes_color_from_background() {
+    let mut scene = new_scene();
+    let fog = Fog::exponential(Vec3::ONE, 0.05).with_background_color();
+    scene.environment.set_fog(Some(fog));
+
+    scene.background.set_color(Vec4::new(0.1, 0.2, 0.3, 1.0));
+    scene.update(&Input::default(), 0.0);
+    {
+        let uniforms = scene.environment_uniforms().read();
+        assert_eq!(uniforms.fog_color_source, 0);
+        assert!(
+            uniforms
+                .fog_color
+                .abs_diff_eq(Vec3::new(0.1, 0.2, 0.3), EPSILON)
+        );
+    }
+
+    // Gradients are matched per view direction in the shader.
+    scene
+        .background
+        .set_gradient(Vec4::new(0.2, 0.4, 0.9, 1.0), Vec4::new(0.8, 0.7, 0.6, 1.0));
+    scene.update(&Input::default(), 0.0);
+    {
+        let uniforms = scene.environment_uniforms().read();
+        assert_eq!(uniforms.fog_color_source, 1);
+        assert!(
+            uniforms
+                .fog_color
+                .abs_diff_eq(Vec3::new(0.2, 0.4, 0.9), EPSILON)
+        );
+        assert!(
+            uniforms
+                .fog_ground_color
+                .abs_diff_eq(Vec3::new(0.8, 0.7, 0.6), EPSILON)
+        );
+    }
+
+    // Procedural skies are matched through the environment cube.
+    scene.background.set_mode(BackgroundMode::procedural());
+    scene.update(&Input::default(), 0.0);
+    assert_eq!(scene.environment_uniforms().read().fog_color_source, 2);
+}
+
+#[test]
+fn background_blur_sweeps_and_follows_linked_background() {
+    let mut scene = new_scene();
+    scene.environment.set_env_map(Some(env_source()));
+    scene
+        .background
+        .set_mode(BackgroundMode::equirectangular(env_source(), 1.0));
+
+    for step in 0..=4 {
+        let blur = step as f32 / 4.0;
+        scene.environment.set_background_blur(blur);
+        scene.update(&Input::default(), 0.0);
+        assert!(approx(
+            scene.environment_uniforms().read().background_blur,
+            blur
+        ));
+    }
+
+    scene.environment.set_background_blur(3.0);
+    assert!(approx(scene.environment.background_blur(), 1.0));
+    scene.environment.set_background_blur(-1.0);
+    assert!(approx(scene.environment.background_blur(), 0.0));
+
+    // Backgrounds that do not show the environment stay sharp.
+    scene.environment.set_background_blur(0.5);
+    scene.background.set_mode(BackgroundMode::equirectangular(
+        TextureSource::Attachment(2, wgpu::TextureViewDimension::D2),
+        1.0,
+    ));
+    scene.update(&Input::default(), 0.0);
+    assert!(approx(
+        scene.environment_uniforms().read().background_blur,
+        0.0
+    ));
+
+    scene.background.set_mode(BackgroundMode::procedural());
+    scene.update(&Input::default(), 0.0);
+    assert!(approx(
+        scene.environment_uniforms().read().background_blur,
+        0.5
+    ));
+}
+
+#[test]
+fn environment_rotation_maps_rotated_direction_back_to_source() {
+    // Rotating the environment moves a source feature at `d` to `rotation * d`,
+    // so sampling that rotated direction must read the feature back at `d`.
+    let mut scene = new_scene();
+    let rotation = Quat::from_rotation_z(0.6) * Quat::from_rotation_y(1.1);
+    scene.environment.set_rotation(rotation);
+
+    scene.update(&Input::default(), 0.0);
+
+    let sampling = to_mat3(scene.environment_uniforms().read().env_map_rotation);
+    let sun = Vec3::new(0.3, 0.8, -0.5).normalize();
+    assert!((sampling * (rotation * sun)).abs_diff_eq(sun, 1e-5));
+}
+
+#[test]
+fn environment_rotation_ignores_unrelated_background() {
+    let mut scene = new_scene();
+    scene.environment.set_env_map(Some(env_source()));
+    scene.environment.set_rotation_y(1.0);
+    scene.environment.set_intensity(4.0);
+    scene.background.set_mode(BackgroundMode::equirectangular(
+        TextureSource::Attachment(2, wgpu::TextureViewDimension::D2),
+        1.0,
+    ));
+
+    scene.update(&Input::default(), 0.0);
+
+    let params = *scene.background.uniforms.read();
+    assert!(approx(params.rotation, 0.0));
+    assert_eq!(params.env_rotation, Mat3Uniform::IDENTITY);
+    assert!(approx(params.intensity, 1.0));
+}
+
+#[test]
+fn environment_rotation_is_ignored_for_procedural_sky() {
+    let mut scene = new_scene();
+    scene.environment.set_rotation_y(1.0);
+    scene.background.set_mode(BackgroundMode::procedural());
+
+    scene.update(&Input::default(), 0.0);
+
+    assert_eq!(
+        scene.environment_uniforms().read().env_map_rotation,
+        Mat3Uniform::IDENTITY
+    );
+}
+
+// ============================================================================
+// Procedural Sky
+// ============================================================================
+
+#[test]
+fn procedural_sky_setters_bump_version_only_on_change() {
+    let mut params = ProceduralSkyParams::default();
+    let v0 = params.version();
+
+    params.set_turbidity(2.0);
+    params.set_ground_albedo(Vec3::splat(0.3));
+    assert_eq!(params.version(), v0 + 2);
+
+    params.set_turbidity(2.0);
+    params.set_ground_albedo(Vec3::splat(0.3));
+    assert_eq!(params.version(), v0 + 2);
+
+    params.set_turbidity(-1.0);
+    assert!(approx(params.turbidity, 0.0));
+}
+
+#[test]
+fn sky_sun_follows_linked_light() {
+    let mut scene = new_scene();
+    scene.set_procedural_sky(ProceduralSkyParams::default());
+
+    let sun = scene.add_light(Light::new_directional(Vec3::ONE, 1.0));
+    scene
+        .node(&sun)
+        .set_position(0.0, 10.0, 10.0)
+        .look_at(Vec3::ZERO);
+    scene.set_sky_sun_light(Some(sun));
+    assert_eq!(scene.sky_sun_light(), Some(sun));
+
+    scene.update(&Input::default(), 0.0);
+
+    let expected = Vec3::new(0.0, 1.0, 1.0).normalize();
+    let dir = scene
+        .background
+        .procedural_sky_params()
+        .unwrap()
+        .sun_direction;
+    assert!(dir.distance(expected) < 1e-4, "sun direction = {dir}");
+
+    // Unlinking leaves the last direction in place.
+    scene.set_sky_sun_light(None);
+    scene
+        .node(&sun)
+        .set_position(10.0, 10.0, 0.0)
+        .look_at(Vec3::ZERO);
+    scene.update(&Input::default(), 0.0);
+    let dir = scene
+        .background
+        .procedural_sky_params()
+        .unwrap()
+        .sun_direction;
+    assert!(dir.distance(expected) < 1e-4);
+}
+
+// ============================================================================
+// Scene Descriptor
+// ============================================================================
+
+#[test]
+fn scene_descriptor_round_trip() {
+    let assets = AssetServer::new();
+    let geometry = assets.geometries.add(Geometry::new_box(1.0, 2.0, 3.0));
+    let material = assets
+        .materials
+        .add(UnlitMaterial::new(Vec4::new(1.0, 0.5, 0.0, 1.0)));
+
+    let mut scene = new_scene();
+    let parent = scene.add_node(Node::new());
+    scene.set_name(parent, "parent");
+    {
+        let node = scene.get_node_mut(parent).unwrap();
+        node.transform.position = Vec3::new(1.0, 2.0, 3.0);
+        node.transform.rotation = Quat::from_rotation_y(0.5);
+        node.transform.scale = Vec3::splat(2.0);
+    }
+    let mut mesh = Mesh::new(geometry, material);
+    mesh.cast_shadows = false;
+    mesh.render_order = 3;
+    let child = scene.add_mesh_to_parent(mesh, parent);
+    scene.set_name(child, "child");
+    scene.get_node_mut(child).unwrap().visible = false;
+    scene.get_node_mut(child).unwrap().set_layers(0b101);
+    scene.add_tag(child, "pickup");
+
+    let mut camera = Camera::new_perspective(60.0, 1.5, 0.1);
+    camera.layer_mask = 0b1;
+    let camera = scene.add_camera(camera);
+    scene.active_camera = Some(camera);
+    let mut light =
+        Light::new_spot(Vec3::ONE, 5.0, 20.0, 0.2, 0.4).with_units(LightUnits::PhysicalUnits);
+    light.cast_shadows = true;
+    light.shadow_layers = 0b100;
+    scene.add_light_to_parent(light, parent);
+    scene.environment.set_intensity(0.7);
+    let starbox_image = assets.images.add(Image::solid_color([0, 0, 32, 255]));
+    let starbox = assets
+        .textures
+        .add(Texture::new_2d(Some("stars"), starbox_image));
+    let mut sky = ProceduralSkyParams::default();
+    sky.set_sun_direction(Vec3::new(0.0, 1.0, 1.0).normalize());
+    sky.turbidity = 2.5;
+    sky.ground_albedo = Vec3::splat(0.3);
+    sky.set_starbox_texture(TextureSource::Asset(starbox));
+    scene
+        .background
+        .set_mode(BackgroundMode::procedural_with(sky));
+
+    let json = scene.to_scene_desc(&assets).unwrap().to_json().unwrap();
+    let desc = SceneDescriptor::from_json(&json).unwrap();
+    let loaded = Scene::from_scene_desc(&desc, &assets).unwrap();
+
+    // Re-serializing the loaded scene reproduces the same descriptor.
+    assert_eq!(loaded.to_scene_desc(&assets).unwrap(), desc);
+
+    let parent = loaded.find_node_by_name("parent").unwrap();
+    let child = loaded.find_node_by_name("child").unwrap();
+    assert_eq!(loaded.get_node(child).unwrap().parent(), Some(parent));
+    let transform = &loaded.get_node(parent).unwrap().transform;
+    assert_eq!(transform.position, Vec3::new(1.0, 2.0, 3.0));
+    assert_eq!(transform.rotation, Quat::from_rotation_y(0.5));
+    assert_eq!(transform.scale, Vec3::splat(2.0));
+    assert!(!loaded.get_node(child).unwrap().visible);
+    assert_eq!(loaded.get_node(child).unwrap().layers, 0b101);
+    assert_eq!(loaded.find_by_tag("pickup"), [child]);
+
+    let mesh = loaded.get_mesh(child).unwrap();
+    assert_eq!(mesh.geometry, geometry);
+    assert_eq!(mesh.material, material);
+    assert!(!mesh.cast_shadows);
+    assert_eq!(mesh.render_order, 3);
+
+    let camera = loaded.get_camera(loaded.active_camera.unwrap()).unwrap();
+    assert!(approx(camera.fov(), 60f32.to_radians()));
+    assert!(camera.far().is_infinite());
+    assert_eq!(camera.layer_mask, 0b1);
+
+    let (light, _) = loaded.iter_active_lights().next().unwrap();
+    assert!(light.cast_shadows);
+    assert_eq!(light.shadow_layers, 0b100);
+    assert_eq!(light.units, LightUnits::PhysicalUnits);
+    assert!(matches!(light.kind, LightKind::Spot(ref s) if approx(s.outer_cone, 0.4)));
+    assert!(approx(loaded.environment.intensity, 0.7));
+
+    let sky = loaded.background.procedural_sky_params().unwrap();
+    assert!(
+        sky.sun_direction
+            .abs_diff_eq(Vec3::new(0.0, 1.0, 1.0).normalize(), 1e-6)
+    );
+    assert!(approx(sky.turbidity, 2.5));
+    assert_eq!(sky.ground_albedo, Vec3::splat(0.3));
+    assert_eq!(sky.starbox_texture, Some(TextureSource::Asset(starbox)));
+}
+
+#[test]
+fn scene_descriptor_rejects_unknown_assets() {
+    let assets = AssetServer::new();
+    let mut desc = {
+        let mut scene = new_scene();
+        let geometry = assets.geometries.add(Geometry::new_box(1.0, 1.0, 1.0));
+        let material = assets.materials.add(UnlitMaterial::new(Vec4::ONE));
+        scene.add_mesh(Mesh::new(geometry, material));
+        scene.to_scene_desc(&assets).unwrap()
+    };
+    desc.nodes[0].mesh.as_mut().unwrap().geometry = uuid::Uuid::new_v4().to_string();
+
+    assert!(Scene::from_scene_desc(&desc, &assets).is_err());
+}
@@ -137,6 +137,7 @@ fn make_render_camera(
         unjittered_projection: proj,
         jitter: Vec2::ZERO,
         aa_mode: AntiAliasingMode::None,
+        layer_mask: u32::MAX,
         #[cfg(feature = "debug_view")]
         debug_view: Default::default(),
     }
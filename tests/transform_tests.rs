@@ -4,6 +4,7 @@
 //! - Transform TRS operations and dirty checking
 //! - Euler angle round-trip conversions
 //! - look_at orientation
+//! - TransformBuilder, from_position_target and rotate_around
 //! - apply_local_matrix decomposition
 //! - Hierarchical matrix propagation (iterative, batched, subtree)
 //! - Level-order batching (BFS)
@@ -171,6 +172,48 @@ fn transform_set_position_marks_dirty() {
     assert!(t.update_local_matrix());
 }
 
+#[test]
+fn transform_builder_sets_trs_and_resolves_look_at_last() {
+    let t = Transform::builder()
+        .scale(Vec3::splat(2.0))
+        .look_at(Vec3::ZERO, Vec3::Y)
+        .position(Vec3::new(0.0, 0.0, 10.0))
+        .build();
+
+    assert!(vec3_approx(t.position, Vec3::new(0.0, 0.0, 10.0)));
+    assert!(vec3_approx(t.scale, Vec3::splat(2.0)));
+    // Facing the origin from +Z is the identity orientation.
+    assert!(t.rotation.angle_between(Quat::IDENTITY) < 1e-4);
+
+    let expected = Transform::from_position_target(Vec3::new(3.0, 4.0, 5.0), Vec3::ZERO, Vec3::Y);
+    let built = Transform::builder()
+        .rotation(Quat::from_rotation_x(1.0))
+        .position(Vec3::new(3.0, 4.0, 5.0))
+        .look_at(Vec3::ZERO, Vec3::Y)
+        .build();
+    assert!(built.rotation.angle_between(expected.rotation) < 1e-4);
+    assert!(vec3_approx(
+        expected.rotation * Vec3::NEG_Z,
+        -Vec3::new(3.0, 4.0, 5.0).normalize()
+    ));
+}
+
+#[test]
+fn transform_rotate_around_orbits_point() {
+    let mut t = Transform::from_position_target(Vec3::new(1.0, 0.0, 5.0), Vec3::X, Vec3::Y);
+    t.rotate_around(Vec3::X, Vec3::Y, FRAC_PI_2);
+
+    // A quarter turn around the vertical line through (1, 0, 0).
+    assert!(vec3_approx(t.position, Vec3::new(6.0, 0.0, 0.0)));
+    // Still facing the pivot.
+    assert!(vec3_approx(t.rotation * Vec3::NEG_Z, Vec3::NEG_X));
+
+    // A zero axis is ignored.
+    let before = t.position;
+    t.rotate_around(Vec3::ZERO, Vec3::ZERO, 1.0);
+    assert!(vec3_approx(t.position, before));
+}
+
 // ============================================================================
 // Hierarchy Setup Helpers
 // ============================================================================